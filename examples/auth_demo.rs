@@ -1,9 +1,17 @@
 // Exemplo de uso dos use cases de autenticação
 
 use nexight_backend::application::services::auth_service::AuthService;
+use nexight_backend::application::services::jwt_keys::JwtKeySet;
+use nexight_backend::application::services::login_throttle_service::LoginThrottleService;
+use nexight_backend::application::services::session_service::SessionService;
 use nexight_backend::application::use_cases::register_user::RegisterUserUseCase;
-use nexight_backend::application::use_cases::login_user::LoginUserUseCase;
+use nexight_backend::application::use_cases::login_user::{LoginContext, LoginOutcome, LoginUserUseCase};
+use nexight_backend::domain::entities::login_throttle::LockoutPolicy;
+use nexight_backend::domain::entities::session::SessionClient;
 use nexight_backend::domain::entities::user::{CreateUserDto, LoginDto};
+use nexight_backend::infrastructure::repositories::in_memory_login_throttle_repository::InMemoryLoginThrottleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_mfa_repository::InMemoryMfaRepository;
+use nexight_backend::infrastructure::repositories::in_memory_session_repository::InMemorySessionRepository;
 use nexight_backend::infrastructure::repositories::in_memory_user_repository::InMemoryUserRepository;
 use std::sync::Arc;
 
@@ -14,14 +22,33 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 1. Setup: Criar dependências
     println!("📦 Configurando dependências...");
     let repository = Arc::new(InMemoryUserRepository::new());
-    let auth_service = Arc::new(AuthService::new(
-        "super_secret_key_change_in_production".to_string(),
+    let auth_service = Arc::new(AuthService::with_keys(
+        JwtKeySet::hmac("super_secret_key_change_in_production"),
         24, // Token expira em 24 horas
     ));
 
     // 2. Criar use cases
-    let register_use_case = RegisterUserUseCase::new(repository.clone(), auth_service.clone());
-    let login_use_case = LoginUserUseCase::new(repository.clone(), auth_service.clone());
+    let session_service = Arc::new(SessionService::new(
+        Arc::new(InMemorySessionRepository::new()),
+        auth_service.clone(),
+    ));
+    let register_use_case = RegisterUserUseCase::new(
+        repository.clone(),
+        auth_service.clone(),
+        session_service.clone(),
+    );
+    let throttle_service = Arc::new(LoginThrottleService::new(
+        Arc::new(InMemoryLoginThrottleRepository::new()),
+        LockoutPolicy::default(),
+    ));
+    let login_use_case = LoginUserUseCase::new(
+        repository.clone(),
+        auth_service.clone(),
+        throttle_service,
+        Arc::new(InMemoryMfaRepository::new()),
+        chrono::Duration::minutes(5),
+        session_service,
+    );
 
     println!("✅ Dependências configuradas!\n");
 
@@ -34,7 +61,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     println!("📝 Registrando usuário: {}", create_dto.email);
-    match register_use_case.execute(create_dto).await {
+    match register_use_case.execute(create_dto, SessionClient::default()).await {
         Ok(response) => {
             println!("✅ Usuário registrado com sucesso!");
             println!("   - ID: {}", response.user.id);
@@ -67,7 +94,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     println!("📝 Tentando registrar com email duplicado...");
-    match register_use_case.execute(duplicate_dto).await {
+    match register_use_case.execute(duplicate_dto, SessionClient::default()).await {
         Ok(_) => println!("❌ Não deveria ter permitido!"),
         Err(e) => println!("✅ Erro esperado: {}", e),
     }
@@ -82,12 +109,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     println!("🔑 Fazendo login com: {}", login_dto.email);
-    match login_use_case.execute(login_dto).await {
-        Ok(response) => {
+    match login_use_case.execute(login_dto, LoginContext::default()).await {
+        Ok(LoginOutcome::Authenticated(response)) => {
             println!("✅ Login realizado com sucesso!");
             println!("   - Nome: {}", response.user.name);
             println!("   - Token JWT: {}...", &response.token[..50]);
         }
+        Ok(LoginOutcome::MfaRequired(_)) => println!("🔐 Login requer o segundo fator (2FA)"),
+        Ok(LoginOutcome::MfaEnrollmentRequired(_)) => println!("🔐 O papel do usuário exige ativar o 2FA"),
         Err(e) => println!("❌ Erro no login: {}", e),
     }
 
@@ -101,7 +130,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     println!("🔑 Tentando login com senha incorreta...");
-    match login_use_case.execute(wrong_password_dto).await {
+    match login_use_case.execute(wrong_password_dto, LoginContext::default()).await {
         Ok(_) => println!("❌ Não deveria ter permitido!"),
         Err(e) => println!("✅ Erro esperado: {}", e),
     }
@@ -116,7 +145,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     println!("🔑 Tentando login com usuário inexistente...");
-    match login_use_case.execute(nonexistent_dto).await {
+    match login_use_case.execute(nonexistent_dto, LoginContext::default()).await {
         Ok(_) => println!("❌ Não deveria ter permitido!"),
         Err(e) => println!("✅ Erro esperado: {}", e),
     }
@@ -134,7 +163,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
     
     println!("📝 Tentando registrar com email inválido...");
-    match register_use_case.execute(invalid_email_dto).await {
+    match register_use_case.execute(invalid_email_dto, SessionClient::default()).await {
         Ok(_) => println!("❌ Não deveria ter permitido!"),
         Err(e) => println!("✅ Erro de validação: {}", e),
     }
@@ -147,7 +176,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
     
     println!("📝 Tentando registrar com senha curta...");
-    match register_use_case.execute(short_password_dto).await {
+    match register_use_case.execute(short_password_dto, SessionClient::default()).await {
         Ok(_) => println!("❌ Não deveria ter permitido!"),
         Err(e) => println!("✅ Erro de validação: {}", e),
     }
@@ -169,7 +198,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             name: name.to_string(),
         };
 
-        match register_use_case.execute(dto).await {
+        match register_use_case.execute(dto, SessionClient::default()).await {
             Ok(response) => {
                 println!("✅ {} registrado(a) - ID: {}", name, response.user.id);
             }
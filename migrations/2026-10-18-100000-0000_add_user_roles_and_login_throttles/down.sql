@@ -0,0 +1,6 @@
+-- Drop login_throttles table and its indexes
+DROP INDEX IF EXISTS idx_login_throttles_locked_until;
+DROP TABLE IF EXISTS login_throttles;
+
+-- Drop role column from users
+ALTER TABLE users DROP COLUMN IF EXISTS role;
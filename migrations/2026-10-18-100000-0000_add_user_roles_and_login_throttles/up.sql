@@ -0,0 +1,19 @@
+-- Add role column to users
+ALTER TABLE users
+    ADD COLUMN role VARCHAR(20) NOT NULL DEFAULT 'reader'
+    CHECK (role IN ('reader', 'editor', 'admin'));
+
+-- Create login_throttles table (failed login counters per account and per IP)
+CREATE TABLE login_throttles (
+    scope VARCHAR(20) NOT NULL CHECK (scope IN ('account', 'ip')),
+    subject VARCHAR(255) NOT NULL,
+    failed_attempts INTEGER NOT NULL DEFAULT 0,
+    lockout_count INTEGER NOT NULL DEFAULT 0,
+    locked_until TIMESTAMPTZ,
+    last_failed_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (scope, subject)
+);
+
+-- Create index on locked_until for cleanup of expired lockouts
+CREATE INDEX idx_login_throttles_locked_until ON login_throttles(locked_until);
@@ -0,0 +1,2 @@
+-- Drop subscriptions table
+DROP TABLE IF EXISTS subscriptions;
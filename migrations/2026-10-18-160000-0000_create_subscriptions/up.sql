@@ -0,0 +1,14 @@
+-- Create subscriptions table (feeds followed by each user, with optional custom title and folder)
+CREATE TABLE subscriptions (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    feed_id UUID NOT NULL REFERENCES feeds(id) ON DELETE CASCADE,
+    title VARCHAR(255),
+    folder VARCHAR(100),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, feed_id)
+);
+
+-- Create index for listing a user's subscriptions by folder
+CREATE INDEX idx_subscriptions_user_id_folder ON subscriptions(user_id, folder);
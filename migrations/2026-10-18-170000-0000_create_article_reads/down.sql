@@ -0,0 +1,2 @@
+-- Drop article_reads table
+DROP TABLE IF EXISTS article_reads;
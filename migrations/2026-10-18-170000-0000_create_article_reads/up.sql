@@ -0,0 +1,10 @@
+-- Create article_reads table (a row means the user has read the article; unread = no row)
+CREATE TABLE article_reads (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    article_id UUID NOT NULL REFERENCES articles(id) ON DELETE CASCADE,
+    read_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, article_id)
+);
+
+-- Create index for removing read states when an article is deleted
+CREATE INDEX idx_article_reads_article_id ON article_reads(article_id);
@@ -0,0 +1,3 @@
+-- Drop saved_articles and collections tables
+DROP TABLE IF EXISTS saved_articles;
+DROP TABLE IF EXISTS collections;
@@ -0,0 +1,34 @@
+-- Create collections table (named groups of saved articles, optionally shared by link)
+CREATE TABLE collections (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    -- Stored in clear so the owner can copy the link again; NULL means private
+    share_token VARCHAR(64) UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, name)
+);
+
+-- Create saved_articles table. Key article fields are copied at save time so the
+-- bookmark survives the article being removed (articles cascade from feeds)
+CREATE TABLE saved_articles (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    article_id UUID REFERENCES articles(id) ON DELETE SET NULL,
+    collection_id UUID REFERENCES collections(id) ON DELETE SET NULL,
+    position INTEGER NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    description TEXT NOT NULL,
+    link VARCHAR(255) NOT NULL,
+    media VARCHAR(255),
+    creator VARCHAR(255) NOT NULL,
+    feed_id UUID NOT NULL,
+    pub_date TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, article_id)
+);
+
+-- Create indexes for listing a user's saved articles and a collection's items
+CREATE INDEX idx_saved_articles_user_id_position ON saved_articles(user_id, position);
+CREATE INDEX idx_saved_articles_collection_id ON saved_articles(collection_id);
@@ -0,0 +1,6 @@
+-- Remove user rating aggregates and drop ratings and reactions tables
+DROP INDEX IF EXISTS idx_articles_user_rating_avg;
+ALTER TABLE articles DROP COLUMN IF EXISTS user_rating_count;
+ALTER TABLE articles DROP COLUMN IF EXISTS user_rating_avg;
+DROP TABLE IF EXISTS article_reactions;
+DROP TABLE IF EXISTS article_ratings;
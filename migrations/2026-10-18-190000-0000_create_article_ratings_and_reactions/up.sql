@@ -0,0 +1,28 @@
+-- Create article_ratings table (one 0-10 rating per user and article, separate from the AI rate)
+CREATE TABLE article_ratings (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    article_id UUID NOT NULL REFERENCES articles(id) ON DELETE CASCADE,
+    rating INTEGER NOT NULL CHECK (rating BETWEEN 0 AND 10),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, article_id)
+);
+
+CREATE INDEX idx_article_ratings_article_id ON article_ratings(article_id);
+
+-- Create article_reactions table ("like", "dislike" or an emoji, several per user)
+CREATE TABLE article_reactions (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    article_id UUID NOT NULL REFERENCES articles(id) ON DELETE CASCADE,
+    reaction VARCHAR(32) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, article_id, reaction)
+);
+
+CREATE INDEX idx_article_reactions_article_id ON article_reactions(article_id);
+
+-- Aggregates of the user ratings, kept on the article so listings can sort and filter by them
+ALTER TABLE articles ADD COLUMN user_rating_avg DOUBLE PRECISION;
+ALTER TABLE articles ADD COLUMN user_rating_count INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX idx_articles_user_rating_avg ON articles(user_rating_avg);
@@ -0,0 +1,22 @@
+-- Create annotations table (user highlights and notes on article content).
+-- The anchor columns are all set for a highlight and all NULL for an article-level note;
+-- offsets are character positions in articles.content, end exclusive
+CREATE TABLE annotations (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    article_id UUID NOT NULL REFERENCES articles(id) ON DELETE CASCADE,
+    quote TEXT,
+    prefix TEXT,
+    suffix TEXT,
+    start_offset INTEGER,
+    end_offset INTEGER,
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((quote IS NULL) = (start_offset IS NULL) AND (quote IS NULL) = (end_offset IS NULL)),
+    CHECK (quote IS NOT NULL OR note IS NOT NULL)
+);
+
+-- Create indexes for listing a user's annotations on an article and exporting all of them
+CREATE INDEX idx_annotations_user_id_article_id ON annotations(user_id, article_id);
+CREATE INDEX idx_annotations_user_id_created_at ON annotations(user_id, created_at);
@@ -0,0 +1,3 @@
+-- Drop article_tags and tags tables
+DROP TABLE IF EXISTS article_tags;
+DROP TABLE IF EXISTS tags;
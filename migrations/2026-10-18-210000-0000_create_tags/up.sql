@@ -0,0 +1,22 @@
+-- Create tags table (user-defined tags, separate from the AI-generated articles.keywords)
+CREATE TABLE tags (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name VARCHAR(50) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Tag names are unique per user regardless of case; the index also serves prefix autocomplete
+CREATE UNIQUE INDEX idx_tags_user_id_lower_name ON tags(user_id, LOWER(name) text_pattern_ops);
+
+-- Create article_tags table (many-to-many between a user's tags and articles)
+CREATE TABLE article_tags (
+    tag_id UUID NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+    article_id UUID NOT NULL REFERENCES articles(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tag_id, article_id)
+);
+
+-- Create index for finding the tags of an article
+CREATE INDEX idx_article_tags_article_id ON article_tags(article_id);
@@ -0,0 +1,4 @@
+-- Per-subscription weight used by the personalized "For you" ranking (0 hides the feed from it)
+ALTER TABLE subscriptions
+    ADD COLUMN weight DOUBLE PRECISION NOT NULL DEFAULT 1.0
+    CHECK (weight >= 0 AND weight <= 5);
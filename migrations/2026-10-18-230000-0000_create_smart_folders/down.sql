@@ -0,0 +1,2 @@
+-- Drop smart_folders table
+DROP TABLE IF EXISTS smart_folders;
@@ -0,0 +1,20 @@
+-- Create smart_folders table (saved searches evaluated against the user's timeline)
+CREATE TABLE smart_folders (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    search VARCHAR(200),
+    -- A folder built on a category or tag makes no sense once it is gone
+    category_id UUID REFERENCES article_categories(id) ON DELETE CASCADE,
+    tag_id UUID REFERENCES tags(id) ON DELETE CASCADE,
+    unread_only BOOLEAN NOT NULL DEFAULT FALSE,
+    min_rate INTEGER,
+    min_user_rating DOUBLE PRECISION,
+    sort VARCHAR(20) NOT NULL DEFAULT 'recent',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, name)
+);
+
+-- Create index for repointing folders when tags are merged
+CREATE INDEX idx_smart_folders_tag_id ON smart_folders(tag_id);
@@ -0,0 +1,3 @@
+-- Drop notifications and alert_rules tables
+DROP TABLE IF EXISTS notifications;
+DROP TABLE IF EXISTS alert_rules;
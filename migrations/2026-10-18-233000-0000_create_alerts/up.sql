@@ -0,0 +1,39 @@
+-- Create alert_rules table (user rules evaluated when articles are ingested or enriched)
+CREATE TABLE alert_rules (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    -- Keyword or phrase matched on whole words of title, description and content
+    query VARCHAR(200),
+    category_id UUID REFERENCES article_categories(id) ON DELETE CASCADE,
+    feed_id UUID REFERENCES feeds(id) ON DELETE CASCADE,
+    min_rate INTEGER,
+    -- Extra delivery channels besides the in-app inbox ('email', 'webhook')
+    channels TEXT[] NOT NULL DEFAULT '{}',
+    webhook_url VARCHAR(500),
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Create indexes for listing a user's rules and finding the candidates for an article
+CREATE INDEX idx_alert_rules_user_id ON alert_rules(user_id);
+CREATE INDEX idx_alert_rules_feed_id ON alert_rules(feed_id) WHERE enabled;
+
+-- Create notifications table (in-app inbox). Title and link are copied so the
+-- inbox reads without joining articles
+CREATE TABLE notifications (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    rule_id UUID NOT NULL REFERENCES alert_rules(id) ON DELETE CASCADE,
+    article_id UUID NOT NULL REFERENCES articles(id) ON DELETE CASCADE,
+    title VARCHAR(255) NOT NULL,
+    link VARCHAR(255) NOT NULL,
+    read_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- One notification per rule and article, even when the article is updated again
+    UNIQUE (rule_id, article_id)
+);
+
+-- Create index for the inbox, newest first
+CREATE INDEX idx_notifications_user_id_created_at ON notifications(user_id, created_at DESC);
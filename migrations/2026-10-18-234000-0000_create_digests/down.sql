@@ -0,0 +1,3 @@
+-- Drop digest tables
+DROP TABLE IF EXISTS sent_digests;
+DROP TABLE IF EXISTS digest_settings;
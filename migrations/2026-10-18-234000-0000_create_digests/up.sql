@@ -0,0 +1,28 @@
+-- Create digest_settings table (per-user email digest schedule, in the user's timezone)
+CREATE TABLE digest_settings (
+    user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    -- 'daily' or 'weekly'
+    frequency VARCHAR(10) NOT NULL,
+    -- Local hour (0-23) and, for weekly digests, ISO weekday (1 = Monday)
+    hour INTEGER NOT NULL,
+    weekday INTEGER,
+    -- IANA timezone name, e.g. 'America/Sao_Paulo'
+    timezone VARCHAR(64) NOT NULL,
+    max_articles INTEGER NOT NULL DEFAULT 10,
+    min_rate INTEGER,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Create sent_digests table (one row per user and period, so a digest is never sent twice)
+CREATE TABLE sent_digests (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    period_start TIMESTAMPTZ NOT NULL,
+    period_end TIMESTAMPTZ NOT NULL,
+    -- 0 when the period had no articles and no email was sent
+    article_count INTEGER NOT NULL,
+    sent_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, period_end)
+);
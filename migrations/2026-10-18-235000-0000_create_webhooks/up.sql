@@ -0,0 +1,53 @@
+-- Create article_events table (outbox written in the same transaction as the article change)
+CREATE TABLE article_events (
+    id UUID PRIMARY KEY,
+    -- 'article.created', 'article.updated', 'article.enriched' or 'article.deleted'
+    event_type VARCHAR(40) NOT NULL,
+    -- No foreign key: deleted articles keep their events
+    article_id UUID NOT NULL,
+    -- JSON snapshot of the article at the time of the event
+    payload TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- Set once the event has been fanned out to the subscriptions
+    processed_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_article_events_pending ON article_events(created_at) WHERE processed_at IS NULL;
+
+-- Create webhook_subscriptions table
+CREATE TABLE webhook_subscriptions (
+    id UUID PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    url VARCHAR(500) NOT NULL,
+    -- Shared secret used to sign the payloads (HMAC-SHA256)
+    secret VARCHAR(100) NOT NULL,
+    -- Subscribed event types; empty receives every event
+    events TEXT[] NOT NULL DEFAULT '{}',
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Create webhook_deliveries table (delivery log and retry queue)
+CREATE TABLE webhook_deliveries (
+    id UUID PRIMARY KEY,
+    subscription_id UUID NOT NULL REFERENCES webhook_subscriptions(id) ON DELETE CASCADE,
+    -- NULL for test deliveries
+    event_id UUID,
+    event_type VARCHAR(40) NOT NULL,
+    -- Exact JSON body sent (and signed) on every attempt
+    payload TEXT NOT NULL,
+    -- 'pending', 'succeeded' or 'failed'
+    status VARCHAR(20) NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ,
+    last_status_code INTEGER,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    delivered_at TIMESTAMPTZ,
+    UNIQUE (subscription_id, event_id)
+);
+
+CREATE INDEX idx_webhook_deliveries_due ON webhook_deliveries(next_attempt_at) WHERE status = 'pending';
+CREATE INDEX idx_webhook_deliveries_subscription ON webhook_deliveries(subscription_id, created_at DESC);
@@ -0,0 +1,5 @@
+-- Drop the article stream trigger and sequence
+DROP TRIGGER IF EXISTS article_events_notify ON article_events;
+DROP FUNCTION IF EXISTS notify_article_event();
+DROP INDEX IF EXISTS idx_article_events_seq;
+ALTER TABLE article_events DROP COLUMN IF EXISTS seq;
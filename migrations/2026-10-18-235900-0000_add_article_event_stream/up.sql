@@ -0,0 +1,16 @@
+-- Global, monotonic position of each outbox event; used as the SSE event id
+ALTER TABLE article_events ADD COLUMN seq BIGSERIAL NOT NULL;
+
+CREATE UNIQUE INDEX idx_article_events_seq ON article_events(seq);
+
+-- Wake up every server instance listening on 'article_events' once the writing transaction commits
+CREATE FUNCTION notify_article_event() RETURNS trigger AS $$
+BEGIN
+    PERFORM pg_notify('article_events', NEW.seq::text);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER article_events_notify
+    AFTER INSERT ON article_events
+    FOR EACH ROW EXECUTE FUNCTION notify_article_event();
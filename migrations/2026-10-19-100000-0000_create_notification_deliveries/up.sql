@@ -0,0 +1,18 @@
+-- Create notification_deliveries table (retry queue for the email and webhook alert channels,
+-- written in the same transaction as the notification and sent by a background worker)
+CREATE TABLE notification_deliveries (
+    id UUID PRIMARY KEY,
+    notification_id UUID NOT NULL REFERENCES notifications(id) ON DELETE CASCADE,
+    -- 'email' or 'webhook'
+    channel VARCHAR(20) NOT NULL,
+    -- 'pending', 'succeeded' or 'failed'
+    status VARCHAR(20) NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    delivered_at TIMESTAMPTZ,
+    UNIQUE (notification_id, channel)
+);
+
+CREATE INDEX idx_notification_deliveries_due ON notification_deliveries(next_attempt_at) WHERE status = 'pending';
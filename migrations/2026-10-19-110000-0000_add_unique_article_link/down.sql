@@ -0,0 +1,3 @@
+-- Restore the plain index on link
+DROP INDEX IF EXISTS idx_articles_link;
+CREATE INDEX idx_articles_link ON articles(link);
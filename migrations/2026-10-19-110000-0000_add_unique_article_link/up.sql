@@ -0,0 +1,108 @@
+-- Pair each duplicate with the oldest article of its link, which is the one kept
+CREATE TEMPORARY TABLE article_link_duplicates ON COMMIT DROP AS
+SELECT id AS duplicate_id, original_id
+FROM (
+    SELECT id, FIRST_VALUE(id) OVER (PARTITION BY link ORDER BY created_at, id) AS original_id
+    FROM articles
+) ranked
+WHERE id <> original_id;
+
+-- Move the user data of the duplicates to the original before removing them. Rows the original
+-- already has (or that another duplicate moved first) are left behind and go away with the duplicate
+
+-- Reads, tags and emoji reactions: any copy is as good as the other
+INSERT INTO article_reads (user_id, article_id, read_at)
+SELECT r.user_id, d.original_id, r.read_at
+FROM article_reads r
+JOIN article_link_duplicates d ON d.duplicate_id = r.article_id
+ORDER BY r.read_at
+ON CONFLICT (user_id, article_id) DO NOTHING;
+
+INSERT INTO article_tags (tag_id, article_id, created_at)
+SELECT t.tag_id, d.original_id, t.created_at
+FROM article_tags t
+JOIN article_link_duplicates d ON d.duplicate_id = t.article_id
+ORDER BY t.created_at
+ON CONFLICT (tag_id, article_id) DO NOTHING;
+
+INSERT INTO article_reactions (user_id, article_id, reaction, created_at)
+SELECT r.user_id, d.original_id, r.reaction, r.created_at
+FROM article_reactions r
+JOIN article_link_duplicates d ON d.duplicate_id = r.article_id
+WHERE r.reaction NOT IN ('like', 'dislike')
+ORDER BY r.created_at
+ON CONFLICT (user_id, article_id, reaction) DO NOTHING;
+
+-- `like` and `dislike` exclude each other: the user's latest one moves, unless the original already has a vote
+INSERT INTO article_reactions (user_id, article_id, reaction, created_at)
+SELECT DISTINCT ON (r.user_id, d.original_id) r.user_id, d.original_id, r.reaction, r.created_at
+FROM article_reactions r
+JOIN article_link_duplicates d ON d.duplicate_id = r.article_id
+WHERE r.reaction IN ('like', 'dislike')
+  AND NOT EXISTS (
+      SELECT 1 FROM article_reactions o
+      WHERE o.user_id = r.user_id AND o.article_id = d.original_id AND o.reaction IN ('like', 'dislike')
+  )
+ORDER BY r.user_id, d.original_id, r.created_at DESC;
+
+-- Ratings: the most recently updated one wins
+INSERT INTO article_ratings (user_id, article_id, rating, created_at, updated_at)
+SELECT DISTINCT ON (r.user_id, d.original_id) r.user_id, d.original_id, r.rating, r.created_at, r.updated_at
+FROM article_ratings r
+JOIN article_link_duplicates d ON d.duplicate_id = r.article_id
+ORDER BY r.user_id, d.original_id, r.updated_at DESC
+ON CONFLICT (user_id, article_id) DO UPDATE
+SET rating = EXCLUDED.rating, updated_at = EXCLUDED.updated_at
+WHERE article_ratings.updated_at < EXCLUDED.updated_at;
+
+-- Annotations have no uniqueness: all of them move. Highlights keep their quote and context,
+-- which locate them again if the original's content differs
+UPDATE annotations a
+SET article_id = d.original_id
+FROM article_link_duplicates d
+WHERE a.article_id = d.duplicate_id;
+
+-- Saved articles and notifications keep their own ids; the oldest row per user (or rule) moves,
+-- unless the original is already saved (or notified). Saved rows left behind keep their copied
+-- fields with article_id NULL, as when any article is removed
+UPDATE saved_articles s
+SET article_id = d.original_id
+FROM article_link_duplicates d
+WHERE s.article_id = d.duplicate_id
+  AND s.id = (
+      SELECT earliest.id
+      FROM saved_articles earliest
+      JOIN article_link_duplicates fd ON fd.duplicate_id = earliest.article_id
+      WHERE earliest.user_id = s.user_id AND fd.original_id = d.original_id
+      ORDER BY earliest.created_at, earliest.id
+      LIMIT 1
+  )
+  AND NOT EXISTS (
+      SELECT 1 FROM saved_articles o WHERE o.user_id = s.user_id AND o.article_id = d.original_id
+  );
+
+UPDATE notifications n
+SET article_id = d.original_id
+FROM article_link_duplicates d
+WHERE n.article_id = d.duplicate_id
+  AND n.id = (
+      SELECT earliest.id
+      FROM notifications earliest
+      JOIN article_link_duplicates fd ON fd.duplicate_id = earliest.article_id
+      WHERE earliest.rule_id = n.rule_id AND fd.original_id = d.original_id
+      ORDER BY earliest.created_at, earliest.id
+      LIMIT 1
+  )
+  AND NOT EXISTS (
+      SELECT 1 FROM notifications o WHERE o.rule_id = n.rule_id AND o.article_id = d.original_id
+  );
+
+-- The outbox (article_events) has no foreign key and keeps each article as it was written,
+-- so the events of the duplicates stay as history
+
+DELETE FROM articles
+WHERE id IN (SELECT duplicate_id FROM article_link_duplicates);
+
+-- One article per link: batch imports and concurrent ingestion rely on it to skip duplicates
+DROP INDEX IF EXISTS idx_articles_link;
+CREATE UNIQUE INDEX idx_articles_link ON articles(link);
@@ -0,0 +1,213 @@
+use crate::domain::repositories::article_repository::ArticleRepositoryError;
+use crate::domain::repositories::webhook_repository::WebhookRepositoryError;
+use serde::Serialize;
+use std::fmt;
+use validator::{ValidationErrors, ValidationErrorsKind};
+
+/// Categoria de um erro da API; o adaptador HTTP a traduz para o status da resposta
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    BadRequest,
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    MethodNotAllowed,
+    NotAcceptable,
+    Conflict,
+    PayloadTooLarge,
+    UnsupportedMediaType,
+    TooManyRequests,
+    Internal,
+    BadGateway,
+    ServiceUnavailable,
+}
+
+impl ErrorKind {
+    /// Código genérico, usado quando o erro não tem um código próprio
+    pub fn code(&self) -> &'static str {
+        match self {
+            ErrorKind::BadRequest => "bad_request",
+            ErrorKind::Unauthorized => "unauthorized",
+            ErrorKind::Forbidden => "forbidden",
+            ErrorKind::NotFound => "not_found",
+            ErrorKind::MethodNotAllowed => "method_not_allowed",
+            ErrorKind::NotAcceptable => "not_acceptable",
+            ErrorKind::Conflict => "conflict",
+            ErrorKind::PayloadTooLarge => "payload_too_large",
+            ErrorKind::UnsupportedMediaType => "unsupported_media_type",
+            ErrorKind::TooManyRequests => "too_many_requests",
+            ErrorKind::Internal => "internal_error",
+            ErrorKind::BadGateway => "bad_gateway",
+            ErrorKind::ServiceUnavailable => "service_unavailable",
+        }
+    }
+}
+
+/// Erro de validação de um campo; `field` usa pontos e índices para campos aninhados (`items[0].name`)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    /// Regra que falhou (`length`, `range`, `email`...)
+    pub code: String,
+    pub message: Option<String>,
+}
+
+/// Falha de validação de uma entrada: a mensagem e, quando vem do `validator`, os erros de cada campo
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationFailure {
+    pub message: String,
+    pub fields: Vec<FieldError>,
+}
+
+impl ValidationFailure {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            fields: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl From<ValidationErrors> for ValidationFailure {
+    fn from(errors: ValidationErrors) -> Self {
+        let mut fields = Vec::new();
+        collect_field_errors(&errors, "", &mut fields);
+        fields.sort_by(|a, b| a.field.cmp(&b.field));
+
+        Self {
+            message: errors.to_string(),
+            fields,
+        }
+    }
+}
+
+impl From<String> for ValidationFailure {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<&str> for ValidationFailure {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+fn collect_field_errors(errors: &ValidationErrors, prefix: &str, fields: &mut Vec<FieldError>) {
+    for (name, kind) in errors.errors() {
+        let path = if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+
+        match kind {
+            ValidationErrorsKind::Field(errors) => fields.extend(errors.iter().map(|error| FieldError {
+                field: path.clone(),
+                code: error.code.to_string(),
+                message: error.message.as_ref().map(|message| message.to_string()),
+            })),
+            ValidationErrorsKind::Struct(errors) => collect_field_errors(errors, &path, fields),
+            ValidationErrorsKind::List(items) => {
+                for (index, errors) in items {
+                    collect_field_errors(errors, &format!("{}[{}]", path, index), fields);
+                }
+            }
+        }
+    }
+}
+
+/// Erro retornado pelas rotas da API, com um código estável para os clientes tratarem o erro
+/// sem depender da mensagem. Cada use case converte o próprio erro no seu módulo
+/// (`impl From<...Error> for ApplicationError`), junto da definição do erro
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApplicationError {
+    pub kind: ErrorKind,
+    pub code: &'static str,
+    pub detail: String,
+    pub fields: Vec<FieldError>,
+    /// Segundos até uma nova tentativa ser aceita
+    pub retry_after: Option<i64>,
+    /// Causa interna, registrada no log e nunca enviada ao cliente
+    pub cause: Option<String>,
+}
+
+impl ApplicationError {
+    pub fn new(kind: ErrorKind, code: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            kind,
+            code,
+            detail: detail.into(),
+            fields: Vec::new(),
+            retry_after: None,
+            cause: None,
+        }
+    }
+
+    /// Erro com o código genérico da categoria
+    pub fn from_kind(kind: ErrorKind, detail: impl Into<String>) -> Self {
+        Self::new(kind, kind.code(), detail)
+    }
+
+    /// Erro interno: o cliente recebe uma mensagem genérica e a causa fica no log
+    pub fn internal(cause: impl Into<String>) -> Self {
+        Self {
+            cause: Some(cause.into()),
+            ..Self::from_kind(ErrorKind::Internal, "Internal server error")
+        }
+    }
+
+    pub fn with_retry_after(mut self, seconds: i64) -> Self {
+        self.retry_after = Some(seconds);
+        self
+    }
+
+    /// Anexa a causa ao erro sem expô-la no `detail`
+    pub fn with_cause(mut self, cause: impl Into<String>) -> Self {
+        self.cause = Some(cause.into());
+        self
+    }
+}
+
+impl From<ValidationFailure> for ApplicationError {
+    fn from(failure: ValidationFailure) -> Self {
+        Self {
+            fields: failure.fields,
+            ..Self::new(ErrorKind::BadRequest, "validation_failed", failure.message)
+        }
+    }
+}
+
+impl From<ValidationErrors> for ApplicationError {
+    fn from(errors: ValidationErrors) -> Self {
+        ValidationFailure::from(errors).into()
+    }
+}
+
+impl From<ArticleRepositoryError> for ApplicationError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        match err {
+            ArticleRepositoryError::NotFound => Self::new(ErrorKind::NotFound, "article_not_found", "Article not found"),
+            ArticleRepositoryError::LinkAlreadyExists => {
+                Self::new(ErrorKind::Conflict, "article_link_taken", "An article with this link already exists")
+            }
+            ArticleRepositoryError::InvalidPagination => {
+                Self::new(ErrorKind::BadRequest, "invalid_pagination", "Invalid pagination parameters")
+            }
+            ArticleRepositoryError::DatabaseError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+impl From<WebhookRepositoryError> for ApplicationError {
+    fn from(err: WebhookRepositoryError) -> Self {
+        let WebhookRepositoryError::DatabaseError(msg) = err;
+        Self::internal(msg)
+    }
+}
@@ -1,2 +1,3 @@
 pub mod services;
-pub mod use_cases;
\ No newline at end of file
+pub mod use_cases;
+pub mod errors;
\ No newline at end of file
@@ -0,0 +1,134 @@
+use crate::application::services::notification_channel::DynNotificationChannel;
+use crate::domain::entities::alert::NotificationDelivery;
+use crate::domain::repositories::alert_rule_repository::{AlertRuleRepositoryError, DynAlertRuleRepository};
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use crate::domain::repositories::notification_repository::{DynNotificationRepository, NotificationRepositoryError};
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Entregas processadas por rodada
+const BATCH_SIZE: i64 = 100;
+/// Por quanto tempo uma entrega reservada fica fora do alcance das outras instâncias
+const LEASE_SECONDS: i64 = 60;
+
+#[derive(Debug, Error)]
+pub enum AlertDispatcherError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<NotificationRepositoryError> for AlertDispatcherError {
+    fn from(err: NotificationRepositoryError) -> Self {
+        AlertDispatcherError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AlertRuleRepositoryError> for AlertDispatcherError {
+    fn from(err: AlertRuleRepositoryError) -> Self {
+        AlertDispatcherError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ArticleRepositoryError> for AlertDispatcherError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        AlertDispatcherError::RepositoryError(err.to_string())
+    }
+}
+
+/// Envia as entregas pendentes dos alertas (email, webhook) fora da requisição que os
+/// disparou, com novas tentativas em backoff exponencial
+pub struct AlertDispatcher {
+    rule_repository: DynAlertRuleRepository,
+    notification_repository: DynNotificationRepository,
+    article_repository: DynArticleRepository,
+    channels: Vec<DynNotificationChannel>,
+}
+
+impl AlertDispatcher {
+    pub fn new(
+        rule_repository: DynAlertRuleRepository,
+        notification_repository: DynNotificationRepository,
+        article_repository: DynArticleRepository,
+        channels: Vec<DynNotificationChannel>,
+    ) -> Self {
+        Self {
+            rule_repository,
+            notification_repository,
+            article_repository,
+            channels,
+        }
+    }
+
+    /// Processa as entregas periodicamente; roda até o fim do processo
+    pub async fn run(self: Arc<Self>, every: std::time::Duration) {
+        let mut interval = tokio::time::interval(every);
+        loop {
+            interval.tick().await;
+            if let Err(e) = self.dispatch(Utc::now()).await {
+                log::error!("Failed to dispatch alerts: {:?}", e);
+            }
+        }
+    }
+
+    /// Uma rodada: tenta as entregas vencidas em `now`. Retorna quantas foram tentadas
+    pub async fn dispatch(&self, now: DateTime<Utc>) -> Result<usize, AlertDispatcherError> {
+        let deliveries = self
+            .notification_repository
+            .claim_due_deliveries(now, now + Duration::seconds(LEASE_SECONDS), BATCH_SIZE)
+            .await?;
+
+        let mut attempted = 0;
+        for mut delivery in deliveries {
+            if self.attempt(&mut delivery, now).await? {
+                attempted += 1;
+            }
+            self.notification_repository.update_delivery(delivery).await?;
+        }
+
+        Ok(attempted)
+    }
+
+    /// Uma tentativa de envio; retorna `false` quando a entrega foi abandonada sem tentar
+    async fn attempt(&self, delivery: &mut NotificationDelivery, now: DateTime<Utc>) -> Result<bool, AlertDispatcherError> {
+        // Regra, artigo e notificação removidos levam as entregas junto
+        let Some(notification) = self.notification_repository.find_by_id(delivery.notification_id).await? else {
+            delivery.abandon("Notification removed".to_string(), now);
+            return Ok(false);
+        };
+        let rule = self
+            .rule_repository
+            .find_by_id(notification.user_id, notification.rule_id)
+            .await?;
+        let article = self.article_repository.find_by_id(notification.article_id).await?;
+        let (Some(rule), Some(article)) = (rule, article) else {
+            delivery.abandon("Rule or article removed".to_string(), now);
+            return Ok(false);
+        };
+
+        // Canal retirado da regra depois do disparo: a entrega não é mais tentada
+        let channel = self
+            .channels
+            .iter()
+            .find(|channel| channel.channel() == delivery.channel && rule.channels.contains(&delivery.channel));
+        let Some(channel) = channel else {
+            delivery.abandon(format!("Channel {} unavailable", delivery.channel.as_str()), now);
+            return Ok(false);
+        };
+
+        match channel.deliver(&rule, &notification, &article).await {
+            Ok(()) => delivery.record_success(now),
+            Err(e) => {
+                log::warn!(
+                    "Failed to deliver alert {} via {}: {}",
+                    rule.id,
+                    delivery.channel.as_str(),
+                    e
+                );
+                delivery.record_failure(e.to_string(), now);
+            }
+        }
+
+        Ok(true)
+    }
+}
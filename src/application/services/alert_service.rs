@@ -0,0 +1,69 @@
+use crate::domain::entities::alert::{Notification, NotificationDelivery};
+use crate::domain::entities::article::Article;
+use crate::domain::repositories::alert_rule_repository::{AlertRuleRepositoryError, DynAlertRuleRepository};
+use crate::domain::repositories::notification_repository::{DynNotificationRepository, NotificationRepositoryError};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AlertServiceError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<AlertRuleRepositoryError> for AlertServiceError {
+    fn from(err: AlertRuleRepositoryError) -> Self {
+        AlertServiceError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<NotificationRepositoryError> for AlertServiceError {
+    fn from(err: NotificationRepositoryError) -> Self {
+        AlertServiceError::RepositoryError(err.to_string())
+    }
+}
+
+/// Avalia as regras de alerta dos usuários quando um artigo é ingerido ou enriquecido
+/// (nova nota da IA, nova categoria). Os canais externos de cada regra ficam na fila de
+/// entregas, enviada pelo `AlertDispatcher` fora da requisição
+pub struct AlertService {
+    rule_repository: DynAlertRuleRepository,
+    notification_repository: DynNotificationRepository,
+}
+
+impl AlertService {
+    pub fn new(rule_repository: DynAlertRuleRepository, notification_repository: DynNotificationRepository) -> Self {
+        Self {
+            rule_repository,
+            notification_repository,
+        }
+    }
+
+    /// Cria uma notificação para cada regra atendida pelo artigo, com uma entrega pendente
+    /// por canal da regra, e retorna as novas. Uma regra notifica cada artigo uma única vez,
+    /// mesmo que ele volte a atendê-la após outra atualização
+    pub async fn evaluate(&self, article: &Article) -> Result<Vec<Notification>, AlertServiceError> {
+        let candidates = self
+            .rule_repository
+            .find_candidates(article.feed_id, article.category_id)
+            .await?;
+
+        let mut created = Vec::new();
+        for rule in candidates.into_iter().filter(|rule| rule.matches(article)) {
+            let notification = Notification::new(&rule, article);
+            let deliveries = rule
+                .channels
+                .iter()
+                .map(|channel| NotificationDelivery::new(&notification, *channel))
+                .collect();
+            if self
+                .notification_repository
+                .create_if_absent(notification.clone(), deliveries)
+                .await?
+            {
+                created.push(notification);
+            }
+        }
+
+        Ok(created)
+    }
+}
@@ -0,0 +1,79 @@
+use crate::application::services::auth_service::AuthService;
+use crate::domain::entities::api_key::ApiKey;
+use crate::domain::repositories::api_key_repository::{ApiKeyRepositoryError, DynApiKeyRepository};
+use chrono::{Duration, Utc};
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+/// Prefixo fixo que identifica chaves da Nexight (facilita a detecção de vazamentos)
+pub const API_KEY_PREFIX: &str = "nxk";
+
+/// Intervalo mínimo entre atualizações de `last_used_at`, evitando uma escrita por requisição
+const USAGE_UPDATE_INTERVAL_SECONDS: i64 = 60;
+
+/// Chave recém-gerada: o prefixo público, o segredo e a chave completa entregue ao usuário
+pub struct GeneratedApiKey {
+    pub prefix: String,
+    pub secret_hash: String,
+    pub key: String,
+}
+
+/// Geração e validação de chaves de API no formato `nxk_<prefixo>_<segredo>`
+pub struct ApiKeyService {
+    repository: DynApiKeyRepository,
+}
+
+impl ApiKeyService {
+    pub fn new(repository: DynApiKeyRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Gera uma nova chave; somente o hash do segredo deve ser persistido
+    pub fn generate() -> GeneratedApiKey {
+        let mut prefix_bytes = [0u8; 6];
+        OsRng.fill_bytes(&mut prefix_bytes);
+        let prefix = hex::encode(prefix_bytes);
+        let secret = AuthService::generate_opaque_token();
+
+        GeneratedApiKey {
+            key: format!("{}_{}_{}", API_KEY_PREFIX, prefix, secret),
+            secret_hash: AuthService::hash_token(&secret),
+            prefix,
+        }
+    }
+
+    /// Separa a chave completa em prefixo e segredo
+    pub fn parse(raw_key: &str) -> Option<(&str, &str)> {
+        let rest = raw_key.strip_prefix(API_KEY_PREFIX)?.strip_prefix('_')?;
+        let (prefix, secret) = rest.split_once('_')?;
+
+        if prefix.is_empty() || secret.is_empty() {
+            return None;
+        }
+
+        Some((prefix, secret))
+    }
+
+    /// Valida a chave e registra o uso; retorna `None` para chaves inválidas ou expiradas
+    pub async fn authenticate(&self, raw_key: &str) -> Result<Option<ApiKey>, ApiKeyRepositoryError> {
+        let Some((prefix, secret)) = Self::parse(raw_key.trim()) else {
+            return Ok(None);
+        };
+
+        let Some(mut api_key) = self.repository.find_by_prefix(prefix).await? else {
+            return Ok(None);
+        };
+
+        let now = Utc::now();
+        if api_key.secret_hash != AuthService::hash_token(secret) || api_key.is_expired(now) {
+            return Ok(None);
+        }
+
+        if api_key.needs_usage_update(now, Duration::seconds(USAGE_UPDATE_INTERVAL_SECONDS)) {
+            self.repository.touch(api_key.id, now).await?;
+            api_key.last_used_at = Some(now);
+        }
+
+        Ok(Some(api_key))
+    }
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Porta de entrada para os avisos de novos eventos no outbox de artigos,
+/// vindos de qualquer instância do servidor
+#[async_trait]
+pub trait ArticleEventNotifier: Send + Sync {
+    /// Retorna ao receber um aviso ou ao fim de `timeout`, o que vier primeiro
+    async fn wait(&self, timeout: Duration);
+}
+
+pub type DynArticleEventNotifier = Arc<dyn ArticleEventNotifier>;
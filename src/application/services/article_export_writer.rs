@@ -0,0 +1,36 @@
+use crate::application::services::csv_encoder::{csv_record, csv_text};
+use crate::domain::entities::article::Article;
+use crate::domain::entities::article_export::{ExportColumn, ExportFormat};
+use serde_json::{Map, Value};
+
+/// Serializa os artigos exportados linha a linha, para que a resposta seja enviada em partes
+pub struct ArticleExportWriter {
+    format: ExportFormat,
+    columns: Vec<ExportColumn>,
+}
+
+impl ArticleExportWriter {
+    pub fn new(format: ExportFormat, columns: Vec<ExportColumn>) -> Self {
+        Self { format, columns }
+    }
+
+    /// Cabeçalho do arquivo: a linha com os nomes das colunas no CSV, nada no NDJSON
+    pub fn header(&self) -> String {
+        match self.format {
+            ExportFormat::Csv => csv_record(self.columns.iter().map(|column| column.as_str())),
+            ExportFormat::Ndjson => String::new(),
+        }
+    }
+
+    pub fn row(&self, article: &Article, category_name: Option<&str>) -> String {
+        let values = self.columns.iter().map(|column| (column, column.value(article, category_name)));
+
+        match self.format {
+            ExportFormat::Csv => csv_record(values.map(|(_, value)| csv_text(&value))),
+            ExportFormat::Ndjson => {
+                let object: Map<String, Value> = values.map(|(column, value)| (column.as_str().to_string(), value)).collect();
+                format!("{}\n", Value::Object(object))
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::application::services::article_event_notifier::DynArticleEventNotifier;
+use crate::domain::entities::article_stream::{ArticleStreamEvent, ArticleStreamFilter};
+use crate::domain::repositories::article_event_repository::{ArticleEventRepositoryError, DynArticleEventRepository};
+use std::collections::{BTreeSet, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+use thiserror::Error;
+use tokio::sync::broadcast;
+
+/// Eventos lidos do outbox por consulta
+const BATCH_SIZE: i64 = 500;
+/// Quantos `seq` para trás cada leitura revisita: uma transação pode reservar um `seq`
+/// menor e fazer commit depois de outra, e o evento dela não pode ser pulado
+const LOOKBACK: i64 = 100;
+/// Capacidade do canal de cada conexão; quem ficar para trás recebe um `resync`
+const CHANNEL_CAPACITY: usize = 256;
+
+#[derive(Debug, Error)]
+pub enum ArticleStreamError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleEventRepositoryError> for ArticleStreamError {
+    fn from(err: ArticleEventRepositoryError) -> Self {
+        ArticleStreamError::RepositoryError(err.to_string())
+    }
+}
+
+/// Uma conexão recém-aberta do stream
+pub struct ArticleSubscription {
+    /// Eventos do buffer posteriores ao `Last-Event-ID`, já filtrados
+    pub replay: Vec<ArticleStreamEvent>,
+    /// O `Last-Event-ID` é mais antigo que o buffer: o cliente perdeu eventos e deve recarregar a lista
+    pub resync: bool,
+    /// Eventos novos, sem filtro; quem consome aplica o `ArticleStreamFilter`
+    pub receiver: broadcast::Receiver<ArticleStreamEvent>,
+}
+
+#[derive(Default)]
+struct StreamState {
+    /// Maior `seq` já lido do outbox; `None` até a primeira leitura
+    cursor: Option<i64>,
+    /// Fim do outbox na primeira leitura; eventos até ele são anteriores à subida e não são publicados
+    start: i64,
+    /// `seq` já lidos na janela do `LOOKBACK`
+    seen: BTreeSet<i64>,
+    /// Últimos eventos publicados, na ordem de publicação
+    buffer: VecDeque<ArticleStreamEvent>,
+    /// Nada anterior a este `seq` está no buffer
+    floor: i64,
+}
+
+/// Publica os eventos do outbox de artigos para as conexões SSE desta instância.
+/// Cada instância lê o outbox ao receber um aviso do `ArticleEventNotifier` (LISTEN/NOTIFY
+/// no Postgres), então um artigo gravado em qualquer uma chega aos clientes de todas
+pub struct ArticleStreamService {
+    article_event_repository: DynArticleEventRepository,
+    notifier: DynArticleEventNotifier,
+    sender: broadcast::Sender<ArticleStreamEvent>,
+    replay_size: usize,
+    state: Mutex<StreamState>,
+}
+
+impl ArticleStreamService {
+    pub fn new(
+        article_event_repository: DynArticleEventRepository,
+        notifier: DynArticleEventNotifier,
+        replay_size: usize,
+    ) -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self {
+            article_event_repository,
+            notifier,
+            sender,
+            replay_size,
+            state: Mutex::new(StreamState::default()),
+        }
+    }
+
+    /// Lê o outbox a cada aviso, ou a cada `fallback` se nenhum chegar; roda até o fim do processo
+    pub async fn run(self: Arc<Self>, fallback: std::time::Duration) {
+        loop {
+            if let Err(e) = self.poll().await {
+                log::error!("Failed to read article events for the stream: {:?}", e);
+            }
+            self.notifier.wait(fallback).await;
+        }
+    }
+
+    /// Publica os eventos gravados desde a última leitura e retorna quantos foram publicados.
+    /// A primeira chamada só posiciona o cursor no fim do outbox, sem publicar o histórico
+    pub async fn poll(&self) -> Result<usize, ArticleStreamError> {
+        let Some(mut cursor) = self.lock().cursor else {
+            let latest = self.article_event_repository.latest_seq().await?;
+            let mut state = self.lock();
+            state.cursor = Some(latest);
+            state.start = latest;
+            state.floor = latest;
+            return Ok(0);
+        };
+
+        let mut published = 0;
+        loop {
+            let events = self
+                .article_event_repository
+                .list_after((cursor - LOOKBACK).max(0), BATCH_SIZE)
+                .await?;
+            let exhausted = (events.len() as i64) < BATCH_SIZE;
+
+            let mut state = self.lock();
+            let mut advanced = false;
+            for event in events {
+                if event.seq <= state.start || !state.seen.insert(event.seq) {
+                    continue;
+                }
+                if event.seq > cursor {
+                    cursor = event.seq;
+                    advanced = true;
+                }
+                if let Some(stream_event) = ArticleStreamEvent::from_event(&event) {
+                    self.publish(&mut state, stream_event);
+                    published += 1;
+                }
+            }
+            state.cursor = Some(cursor);
+            state.seen = state.seen.split_off(&(cursor - LOOKBACK));
+
+            if exhausted || !advanced {
+                return Ok(published);
+            }
+        }
+    }
+
+    /// Abre uma conexão; com `last_event_id`, devolve os eventos do buffer que vieram depois dele
+    pub fn subscribe(&self, filter: &ArticleStreamFilter, last_event_id: Option<i64>) -> ArticleSubscription {
+        // O lock garante que nenhum evento fique entre o replay e o canal
+        let state = self.lock();
+        let receiver = self.sender.subscribe();
+
+        let Some(last_event_id) = last_event_id else {
+            return ArticleSubscription {
+                replay: Vec::new(),
+                resync: false,
+                receiver,
+            };
+        };
+
+        // O buffer segue a ordem de publicação, que pode divergir da ordem dos `seq`
+        let missed: Vec<&ArticleStreamEvent> = match state.buffer.iter().position(|event| event.seq == last_event_id) {
+            Some(position) => state.buffer.iter().skip(position + 1).collect(),
+            None => state.buffer.iter().filter(|event| event.seq > last_event_id).collect(),
+        };
+
+        ArticleSubscription {
+            replay: missed.into_iter().filter(|event| filter.matches(event)).cloned().collect(),
+            resync: last_event_id < state.floor,
+            receiver,
+        }
+    }
+
+    fn publish(&self, state: &mut StreamState, event: ArticleStreamEvent) {
+        if self.replay_size > 0 {
+            if state.buffer.len() == self.replay_size {
+                if let Some(evicted) = state.buffer.pop_front() {
+                    state.floor = state.floor.max(evicted.seq);
+                }
+            }
+            state.buffer.push_back(event.clone());
+        } else {
+            state.floor = state.floor.max(event.seq);
+        }
+        // Sem conexões abertas o envio falha, e tudo bem
+        let _ = self.sender.send(event);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, StreamState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
@@ -3,8 +3,11 @@ use argon2::{
     Argon2,
 };
 use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use crate::application::services::jwt_keys::{JwkSet, JwtKeySet};
 use chrono::{Duration, Utc};
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use jsonwebtoken::{decode, decode_header, encode, Header, Validation};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
@@ -21,35 +24,59 @@ pub enum AuthError {
     TokenGenerationError,
 
     #[error("Invalid token")]
-    #[allow(dead_code)]
     InvalidToken,
 
     #[error("Token expired")]
-    #[allow(dead_code)]
     TokenExpired,
 }
 
+/// Purpose of a JWT; only access tokens authenticate API requests
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenType {
+    #[default]
+    Access,
+    /// Issued after the password step when the user has 2FA enabled
+    MfaPending,
+    /// Issued after the password step to editors and admins who still have to enable 2FA;
+    /// it only unlocks the TOTP enrollment routes
+    MfaEnrollment,
+    /// Short-lived ticket that only opens the WebSocket (`/ws?ticket=`), so the access token stays out of URLs
+    StreamTicket,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String, // Subject (user id)
     pub email: String,
     pub exp: i64, // Expiration time
     pub iat: i64, // Issued at
+    #[serde(default)]
+    pub token_type: TokenType,
+    /// Session the access token belongs to; revoking the session invalidates the token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
 }
 
 pub struct AuthService {
-    jwt_secret: String,
+    keys: JwtKeySet,
     token_expiration_hours: i64,
 }
 
 impl AuthService {
-    pub fn new(jwt_secret: String, token_expiration_hours: i64) -> Self {
+    /// Signs with the active key of the set and verifies with any key in it
+    pub fn with_keys(keys: JwtKeySet, token_expiration_hours: i64) -> Self {
         Self {
-            jwt_secret,
+            keys,
             token_expiration_hours,
         }
     }
 
+    /// Public keys for `/.well-known/jwks.json` (empty when signing with HMAC)
+    pub fn jwks(&self) -> JwkSet {
+        self.keys.jwks()
+    }
+
     /// Hash a password using Argon2
     pub fn hash_password(&self, password: &str) -> Result<String, AuthError> {
         let salt = SaltString::generate(&mut OsRng);
@@ -72,37 +99,102 @@ impl AuthService {
             .is_ok())
     }
 
-    /// Generate a JWT token
-    pub fn generate_token(&self, user_id: Uuid, email: &str) -> Result<String, AuthError> {
+    /// Lifetime of access tokens (and of the sessions backing them)
+    pub fn token_ttl(&self) -> Duration {
+        Duration::hours(self.token_expiration_hours)
+    }
+
+    /// Generate an access token bound to a login session (`sid` claim)
+    pub fn generate_session_token(&self, user_id: Uuid, email: &str, session_id: Uuid) -> Result<String, AuthError> {
+        self.encode_token(
+            user_id,
+            email,
+            self.token_ttl(),
+            TokenType::Access,
+            Some(session_id),
+        )
+    }
+
+    /// Generate a short-lived token proving the password step of a 2FA login
+    pub fn generate_mfa_token(&self, user_id: Uuid, email: &str, ttl: Duration) -> Result<String, AuthError> {
+        self.encode_token(user_id, email, ttl, TokenType::MfaPending, None)
+    }
+
+    /// Generate a short-lived token that only allows enrolling in 2FA
+    pub fn generate_mfa_enrollment_token(&self, user_id: Uuid, email: &str, ttl: Duration) -> Result<String, AuthError> {
+        self.encode_token(user_id, email, ttl, TokenType::MfaEnrollment, None)
+    }
+
+    /// Generate a stream ticket bound to the same session as the access token that requested it
+    pub fn generate_stream_ticket(
+        &self,
+        user_id: Uuid,
+        email: &str,
+        session_id: Option<Uuid>,
+        ttl: Duration,
+    ) -> Result<String, AuthError> {
+        self.encode_token(user_id, email, ttl, TokenType::StreamTicket, session_id)
+    }
+
+    fn encode_token(
+        &self,
+        user_id: Uuid,
+        email: &str,
+        ttl: Duration,
+        token_type: TokenType,
+        session_id: Option<Uuid>,
+    ) -> Result<String, AuthError> {
         let now = Utc::now();
-        let expiration = now + Duration::hours(self.token_expiration_hours);
+        let expiration = now + ttl;
 
         let claims = Claims {
             sub: user_id.to_string(),
             email: email.to_string(),
             exp: expiration.timestamp(),
             iat: now.timestamp(),
+            token_type,
+            sid: session_id.map(|id| id.to_string()),
         };
 
-        encode(
-            &Header::default(),
-            &claims,
-            &EncodingKey::from_secret(self.jwt_secret.as_bytes()),
-        )
-        .map_err(|_| AuthError::TokenGenerationError)
+        let signing_key = self.keys.signing_key();
+        let mut header = Header::new(signing_key.algorithm());
+        header.kid = signing_key.kid().map(|kid| kid.to_string());
+
+        encode(&header, &claims, signing_key.encoding_key())
+            .map_err(|_| AuthError::TokenGenerationError)
     }
 
-    /// Verify and decode a JWT token
-    #[allow(dead_code)]
+    /// Verify and decode an access token
     pub fn verify_token(&self, token: &str) -> Result<Claims, AuthError> {
-        let validation = Validation::default();
+        self.decode_token(token, TokenType::Access)
+    }
 
-        decode::<Claims>(
-            token,
-            &DecodingKey::from_secret(self.jwt_secret.as_bytes()),
-            &validation,
-        )
-        .map(|data| data.claims)
+    /// Verify and decode an "mfa pending" token
+    pub fn verify_mfa_token(&self, token: &str) -> Result<Claims, AuthError> {
+        self.decode_token(token, TokenType::MfaPending)
+    }
+
+    /// Verify and decode a 2FA enrollment token
+    pub fn verify_mfa_enrollment_token(&self, token: &str) -> Result<Claims, AuthError> {
+        self.decode_token(token, TokenType::MfaEnrollment)
+    }
+
+    /// Verify and decode a stream ticket
+    pub fn verify_stream_ticket(&self, token: &str) -> Result<Claims, AuthError> {
+        self.decode_token(token, TokenType::StreamTicket)
+    }
+
+    fn decode_token(&self, token: &str, expected: TokenType) -> Result<Claims, AuthError> {
+        let header = decode_header(token).map_err(|_| AuthError::InvalidToken)?;
+
+        // The key is chosen by `kid` and pins the algorithm, so a token cannot pick its own
+        let key = self
+            .keys
+            .verification_key(header.kid.as_deref())
+            .ok_or(AuthError::InvalidToken)?;
+        let validation = Validation::new(key.algorithm());
+
+        decode::<Claims>(token, key.decoding_key(), &validation)
         .map_err(|err| {
             if err.to_string().contains("expired") {
                 AuthError::TokenExpired
@@ -110,5 +202,24 @@ impl AuthService {
                 AuthError::InvalidToken
             }
         })
+        .and_then(|data| {
+            if data.claims.token_type == expected {
+                Ok(data.claims)
+            } else {
+                Err(AuthError::InvalidToken)
+            }
+        })
+    }
+
+    /// Generate a random opaque token (hex encoded, 256 bits)
+    pub fn generate_opaque_token() -> String {
+        let mut bytes = [0u8; 32];
+        OsRng.fill_bytes(&mut bytes);
+        hex::encode(bytes)
+    }
+
+    /// Hash an opaque token with SHA-256 so only the digest is stored
+    pub fn hash_token(token: &str) -> String {
+        hex::encode(Sha256::digest(token.as_bytes()))
     }
 }
@@ -0,0 +1,30 @@
+use serde_json::Value;
+
+/// Registro CSV (RFC 4180) terminado em CRLF; cada campo vai entre aspas quando tem separador,
+/// aspas ou quebra de linha
+pub fn csv_record<I, S>(fields: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let fields: Vec<String> = fields.into_iter().map(|field| csv_field(field.as_ref())).collect();
+    format!("{}\r\n", fields.join(","))
+}
+
+/// Texto de um valor JSON no CSV: `null` vira campo vazio, textos aparecem sem as aspas do JSON
+/// e valores aninhados são escritos como JSON
+pub fn csv_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
@@ -0,0 +1,132 @@
+use crate::domain::entities::article::ArticleResponse;
+use crate::domain::entities::digest::{DigestFrequency, DigestPeriod, DigestPreview};
+use chrono::Duration;
+use chrono_tz::Tz;
+
+/// Tamanho máximo do resumo de cada artigo quando não há `ai_summary` e a descrição é usada
+const MAX_DESCRIPTION_CHARS: usize = 300;
+
+/// Monta o assunto e os corpos em texto e HTML do resumo por email
+pub struct DigestRenderer;
+
+impl DigestRenderer {
+    pub fn render(
+        user_name: &str,
+        frequency: DigestFrequency,
+        tz: Tz,
+        period: DigestPeriod,
+        articles: Vec<ArticleResponse>,
+    ) -> DigestPreview {
+        let subject = Self::subject(frequency, tz, period);
+        let text_body = Self::text(user_name, frequency, &articles);
+        let html_body = Self::html(user_name, frequency, &subject, &articles);
+
+        DigestPreview {
+            period,
+            articles,
+            subject,
+            text_body,
+            html_body,
+        }
+    }
+
+    /// Datas no fuso do usuário; o período semanal vai do primeiro ao último dia coberto
+    fn subject(frequency: DigestFrequency, tz: Tz, period: DigestPeriod) -> String {
+        let last_day = (period.end - Duration::seconds(1)).with_timezone(&tz).format("%d/%m/%Y");
+        match frequency {
+            DigestFrequency::Daily => format!("Seu resumo diário do Nexight - {}", last_day),
+            DigestFrequency::Weekly => format!(
+                "Seu resumo semanal do Nexight - {} a {}",
+                period.start.with_timezone(&tz).format("%d/%m/%Y"),
+                last_day
+            ),
+        }
+    }
+
+    fn intro(frequency: DigestFrequency, count: usize) -> String {
+        let period = match frequency {
+            DigestFrequency::Daily => "do dia",
+            DigestFrequency::Weekly => "da semana",
+        };
+        format!("Os {} destaques {} nos feeds que você assina:", count, period)
+    }
+
+    fn text(user_name: &str, frequency: DigestFrequency, articles: &[ArticleResponse]) -> String {
+        let mut body = format!("Olá {},\n\n{}\n", user_name, Self::intro(frequency, articles.len()));
+
+        for (position, article) in articles.iter().enumerate() {
+            body.push_str(&format!("\n{}. {}", position + 1, article.title));
+            if let Some(rate) = article.rate {
+                body.push_str(&format!(" (nota {}/10)", rate));
+            }
+            body.push('\n');
+            for line in summary(article).lines() {
+                body.push_str(&format!("   {}\n", line));
+            }
+            body.push_str(&format!("   {}\n", article.link));
+        }
+
+        body.push_str("\nPara alterar a frequência ou cancelar o resumo, ajuste suas preferências no Nexight.\n");
+        body
+    }
+
+    fn html(user_name: &str, frequency: DigestFrequency, subject: &str, articles: &[ArticleResponse]) -> String {
+        let mut items = String::new();
+        for article in articles {
+            let rate = article
+                .rate
+                .map(|rate| format!(" <span style=\"color:#666\">(nota {}/10)</span>", rate))
+                .unwrap_or_default();
+            items.push_str(&format!(
+                "<li style=\"margin-bottom:16px\"><a href=\"{}\" style=\"font-weight:bold\">{}</a>{}<p style=\"margin:4px 0\">{}</p></li>\n",
+                escape_html(&article.link),
+                escape_html(&article.title),
+                rate,
+                escape_html(&summary(article)).replace('\n', "<br>")
+            ));
+        }
+
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"pt-BR\">\n<head><meta charset=\"utf-8\"><title>{}</title></head>\n\
+             <body style=\"font-family:sans-serif;max-width:640px;margin:0 auto\">\n\
+             <p>Olá {},</p>\n<p>{}</p>\n<ol>\n{}</ol>\n\
+             <p style=\"color:#666;font-size:12px\">Para alterar a frequência ou cancelar o resumo, ajuste suas preferências no Nexight.</p>\n\
+             </body>\n</html>\n",
+            escape_html(subject),
+            escape_html(user_name),
+            Self::intro(frequency, articles.len()),
+            items
+        )
+    }
+}
+
+/// Resumo da IA ou, na falta dele, a descrição do artigo encurtada
+fn summary(article: &ArticleResponse) -> String {
+    match article.ai_summary.as_deref().map(str::trim).filter(|summary| !summary.is_empty()) {
+        Some(summary) => summary.to_string(),
+        None => {
+            let description = article.description.trim();
+            if description.chars().count() <= MAX_DESCRIPTION_CHARS {
+                description.to_string()
+            } else {
+                let truncated: String = description.chars().take(MAX_DESCRIPTION_CHARS).collect();
+                format!("{}...", truncated.trim_end())
+            }
+        }
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,215 @@
+use crate::application::services::digest_renderer::DigestRenderer;
+use crate::application::services::mailer::{DynMailer, EmailMessage};
+use crate::domain::entities::article::{ArticleListFilter, ArticleResponse, ArticleSort};
+use crate::domain::entities::digest::{DigestPeriod, DigestPreview, DigestSettings, SentDigest};
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use crate::domain::repositories::digest_repository::{DigestRepositoryError, DynDigestRepository};
+use crate::domain::repositories::subscription_repository::{DynSubscriptionRepository, SubscriptionRepositoryError};
+use crate::domain::repositories::user_repository::{DynUserRepository, UserRepositoryError};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Quantos candidatos por vaga são buscados antes de aplicar o peso das assinaturas
+const CANDIDATES_PER_SLOT: i64 = 3;
+const MAX_CANDIDATES: i64 = 100;
+
+#[derive(Debug, Error)]
+pub enum DigestServiceError {
+    #[error("Invalid timezone: {0}")]
+    InvalidTimezone(String),
+
+    #[error("User not found")]
+    UserNotFound,
+
+    #[error("Mailer error: {0}")]
+    MailerError(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<DigestRepositoryError> for DigestServiceError {
+    fn from(err: DigestRepositoryError) -> Self {
+        DigestServiceError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SubscriptionRepositoryError> for DigestServiceError {
+    fn from(err: SubscriptionRepositoryError) -> Self {
+        DigestServiceError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ArticleRepositoryError> for DigestServiceError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        DigestServiceError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UserRepositoryError> for DigestServiceError {
+    fn from(err: UserRepositoryError) -> Self {
+        DigestServiceError::RepositoryError(err.to_string())
+    }
+}
+
+/// Monta e envia os resumos por email conforme a agenda de cada usuário.
+/// Cada período é registrado antes do envio, então um resumo nunca sai duas vezes,
+/// nem com várias instâncias da API rodando o agendador
+pub struct DigestService {
+    digest_repository: DynDigestRepository,
+    subscription_repository: DynSubscriptionRepository,
+    article_repository: DynArticleRepository,
+    user_repository: DynUserRepository,
+    mailer: DynMailer,
+}
+
+impl DigestService {
+    pub fn new(
+        digest_repository: DynDigestRepository,
+        subscription_repository: DynSubscriptionRepository,
+        article_repository: DynArticleRepository,
+        user_repository: DynUserRepository,
+        mailer: DynMailer,
+    ) -> Self {
+        Self {
+            digest_repository,
+            subscription_repository,
+            article_repository,
+            user_repository,
+            mailer,
+        }
+    }
+
+    /// Verifica as agendas periodicamente e envia os resumos devidos; roda até o fim do processo
+    pub async fn run_scheduler(self: Arc<Self>, every: std::time::Duration) {
+        let mut interval = tokio::time::interval(every);
+        loop {
+            interval.tick().await;
+            match self.send_due(Utc::now()).await {
+                Ok(0) => {}
+                Ok(sent) => log::info!("Sent {} email digests", sent),
+                Err(e) => log::error!("Failed to check email digests: {:?}", e),
+            }
+        }
+    }
+
+    /// Envia o resumo de cada agenda ativa cujo período mais recente ainda não foi registrado.
+    /// Falhas de um usuário são registradas no log e não impedem os demais
+    pub async fn send_due(&self, now: DateTime<Utc>) -> Result<usize, DigestServiceError> {
+        let mut sent = 0;
+        for settings in self.digest_repository.list_enabled_settings().await? {
+            match self.send_for(&settings, now).await {
+                Ok(true) => sent += 1,
+                Ok(false) => {}
+                Err(e) => log::error!("Failed to send email digest to user {}: {:?}", settings.user_id, e),
+            }
+        }
+        Ok(sent)
+    }
+
+    /// Resumo do período mais recente, como seria enviado, sem registrá-lo
+    pub async fn preview(&self, settings: &DigestSettings, now: DateTime<Utc>) -> Result<DigestPreview, DigestServiceError> {
+        let period = settings
+            .latest_period(now)
+            .ok_or_else(|| DigestServiceError::InvalidTimezone(settings.timezone.clone()))?;
+        let user = self
+            .user_repository
+            .find_by_id(settings.user_id)
+            .await?
+            .ok_or(DigestServiceError::UserNotFound)?;
+
+        self.build(settings, &user.name, period).await
+    }
+
+    /// Retorna `true` se um email foi enviado. Períodos sem artigos são registrados sem envio,
+    /// e períodos encerrados antes da criação da agenda são ignorados
+    async fn send_for(&self, settings: &DigestSettings, now: DateTime<Utc>) -> Result<bool, DigestServiceError> {
+        let period = settings
+            .latest_period(now)
+            .ok_or_else(|| DigestServiceError::InvalidTimezone(settings.timezone.clone()))?;
+        if period.end <= settings.created_at {
+            return Ok(false);
+        }
+
+        let latest = self.digest_repository.list_sent(settings.user_id, 1).await?;
+        if latest.first().is_some_and(|sent| sent.period_end >= period.end) {
+            return Ok(false);
+        }
+
+        let user = match self.user_repository.find_by_id(settings.user_id).await? {
+            Some(user) if user.is_active => user,
+            _ => return Ok(false),
+        };
+
+        let digest = self.build(settings, &user.name, period).await?;
+        let record = SentDigest::new(settings.user_id, period, digest.articles.len() as i32);
+        if !self.digest_repository.record_sent(record.clone()).await? {
+            return Ok(false);
+        }
+        if digest.articles.is_empty() {
+            return Ok(false);
+        }
+
+        let message = EmailMessage {
+            to: user.email,
+            subject: digest.subject,
+            text_body: digest.text_body,
+            html_body: Some(digest.html_body),
+        };
+        if let Err(e) = self.mailer.send(message).await {
+            // Libera o período para a próxima rodada do agendador
+            self.digest_repository.remove_sent(record.id).await?;
+            return Err(DigestServiceError::MailerError(e.to_string()));
+        }
+
+        Ok(true)
+    }
+
+    async fn build(&self, settings: &DigestSettings, user_name: &str, period: DigestPeriod) -> Result<DigestPreview, DigestServiceError> {
+        let tz = settings
+            .tz()
+            .ok_or_else(|| DigestServiceError::InvalidTimezone(settings.timezone.clone()))?;
+        let articles = self.select_articles(settings, period).await?;
+
+        Ok(DigestRenderer::render(user_name, settings.frequency, tz, period, articles))
+    }
+
+    /// Artigos dos feeds assinados publicados no período, pela nota da IA multiplicada
+    /// pelo peso da assinatura; feeds com peso 0 ficam de fora
+    async fn select_articles(&self, settings: &DigestSettings, period: DigestPeriod) -> Result<Vec<ArticleResponse>, DigestServiceError> {
+        let weights: HashMap<_, _> = self
+            .subscription_repository
+            .list_by_user(settings.user_id)
+            .await?
+            .into_iter()
+            .filter(|subscription| subscription.weight > 0.0)
+            .map(|subscription| (subscription.feed_id, subscription.weight))
+            .collect();
+        if weights.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let filter = ArticleListFilter {
+            feed_ids: Some(weights.keys().copied().collect()),
+            published_after: Some(period.start),
+            // O fim do período pertence ao próximo resumo
+            published_before: Some(period.end - Duration::microseconds(1)),
+            min_rate: settings.min_rate,
+            sort: ArticleSort::Rate,
+            ..Default::default()
+        };
+        let per_page = (settings.max_articles as i64 * CANDIDATES_PER_SLOT).min(MAX_CANDIDATES);
+        let mut articles = self.article_repository.list_filtered(&filter, 1, per_page).await?.articles;
+
+        let score = |article: &ArticleResponse| {
+            article.rate.unwrap_or(0) as f64 * weights.get(&article.feed_id).copied().unwrap_or(0.0)
+        };
+        // Ordenação estável: no empate, vale a ordem por nota e data da listagem
+        articles.sort_by(|a, b| score(b).total_cmp(&score(a)));
+        articles.truncate(settings.max_articles.max(0) as usize);
+
+        Ok(articles)
+    }
+}
@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum IdentityProviderError {
+    /// O provedor não respondeu ou respondeu algo inesperado (discovery, JWKS, token endpoint)
+    #[error("Identity provider unavailable: {0}")]
+    Unavailable(String),
+
+    /// O provedor recusou o código ou o ID token não passou na validação
+    #[error("Identity provider rejected the login: {0}")]
+    Rejected(String),
+}
+
+/// Identidade confirmada pelo provedor após a troca do código
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalIdentity {
+    /// Identificador estável do usuário no provedor (claim `sub`)
+    pub subject: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub name: Option<String>,
+}
+
+/// Porta de saída para provedores de identidade (authorization code + PKCE)
+#[async_trait]
+pub trait IdentityProvider: Send + Sync {
+    /// URL para onde o navegador deve ser redirecionado
+    async fn authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> Result<String, IdentityProviderError>;
+
+    /// Troca o código de autorização pela identidade, validando o ID token e o `nonce`
+    async fn exchange_code(
+        &self,
+        code: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> Result<ExternalIdentity, IdentityProviderError>;
+}
+
+pub type DynIdentityProvider = Arc<dyn IdentityProvider>;
+
+/// Provedores configurados, indexados pelo nome usado nas rotas (`/auth/oidc/{provider}`)
+#[derive(Clone, Default)]
+pub struct IdentityProviders {
+    providers: HashMap<String, DynIdentityProvider>,
+}
+
+impl IdentityProviders {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(mut self, name: impl Into<String>, provider: DynIdentityProvider) -> Self {
+        self.providers.insert(name.into(), provider);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&DynIdentityProvider> {
+        self.providers.get(name)
+    }
+
+    /// Nomes dos provedores em ordem alfabética
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.providers.keys().cloned().collect();
+        names.sort();
+        names
+    }
+}
+
+/// `code_challenge` do método S256 (RFC 7636): BASE64URL(SHA256(verifier))
+pub fn pkce_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
@@ -0,0 +1,199 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+use ring::signature::{Ed25519KeyPair, KeyPair, RsaKeyPair};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum JwtKeyError {
+    #[error("Invalid key '{kid}': {reason}")]
+    InvalidKey { kid: String, reason: String },
+
+    #[error("Active key '{0}' not found")]
+    UnknownActiveKey(String),
+
+    #[error("Duplicate key id '{0}'")]
+    DuplicateKeyId(String),
+}
+
+/// Chave pública no formato JWK (RFC 7517)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Jwk {
+    pub kty: String,
+    pub kid: String,
+    pub alg: String,
+    #[serde(rename = "use")]
+    pub use_: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crv: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+}
+
+/// Documento publicado em `/.well-known/jwks.json`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JwkSet {
+    pub keys: Vec<Jwk>,
+}
+
+/// Chave de assinatura/verificação de JWT
+pub struct JwtKey {
+    kid: Option<String>,
+    algorithm: Algorithm,
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    /// Representação pública; `None` para segredos HMAC, que nunca são publicados
+    jwk: Option<Jwk>,
+}
+
+impl JwtKey {
+    /// Segredo compartilhado HS256 (tokens sem `kid`)
+    pub fn hmac(secret: &str) -> Self {
+        Self {
+            kid: None,
+            algorithm: Algorithm::HS256,
+            encoding_key: EncodingKey::from_secret(secret.as_bytes()),
+            decoding_key: DecodingKey::from_secret(secret.as_bytes()),
+            jwk: None,
+        }
+    }
+
+    /// Chave privada PEM: RSA (PKCS#1 ou PKCS#8) assina com RS256, Ed25519 (PKCS#8) com EdDSA
+    pub fn from_pem(kid: &str, pem_data: &[u8]) -> Result<Self, JwtKeyError> {
+        let invalid = |reason: String| JwtKeyError::InvalidKey {
+            kid: kid.to_string(),
+            reason,
+        };
+
+        let parsed = pem::parse(pem_data).map_err(|e| invalid(e.to_string()))?;
+        let der = parsed.contents();
+
+        let rsa = match parsed.tag() {
+            "RSA PRIVATE KEY" => Some(RsaKeyPair::from_der(der).map_err(|e| invalid(e.to_string()))?),
+            "PRIVATE KEY" => RsaKeyPair::from_pkcs8(der).ok(),
+            tag => return Err(invalid(format!("unsupported PEM block '{}'", tag))),
+        };
+
+        if let Some(key_pair) = rsa {
+            let components = ring::rsa::PublicKeyComponents::<Vec<u8>>::from(key_pair.public());
+            let n = URL_SAFE_NO_PAD.encode(&components.n);
+            let e = URL_SAFE_NO_PAD.encode(&components.e);
+
+            return Ok(Self {
+                kid: Some(kid.to_string()),
+                algorithm: Algorithm::RS256,
+                encoding_key: EncodingKey::from_rsa_pem(pem_data).map_err(|e| invalid(e.to_string()))?,
+                decoding_key: DecodingKey::from_rsa_components(&n, &e)
+                    .map_err(|e| invalid(e.to_string()))?,
+                jwk: Some(Jwk {
+                    kty: "RSA".to_string(),
+                    kid: kid.to_string(),
+                    alg: "RS256".to_string(),
+                    use_: "sig".to_string(),
+                    n: Some(n),
+                    e: Some(e),
+                    crv: None,
+                    x: None,
+                }),
+            });
+        }
+
+        let key_pair = Ed25519KeyPair::from_pkcs8_maybe_unchecked(der)
+            .map_err(|_| invalid("expected an RSA or Ed25519 private key".to_string()))?;
+        let x = URL_SAFE_NO_PAD.encode(key_pair.public_key().as_ref());
+
+        Ok(Self {
+            kid: Some(kid.to_string()),
+            algorithm: Algorithm::EdDSA,
+            encoding_key: EncodingKey::from_ed_pem(pem_data).map_err(|e| invalid(e.to_string()))?,
+            decoding_key: DecodingKey::from_ed_components(&x).map_err(|e| invalid(e.to_string()))?,
+            jwk: Some(Jwk {
+                kty: "OKP".to_string(),
+                kid: kid.to_string(),
+                alg: "EdDSA".to_string(),
+                use_: "sig".to_string(),
+                n: None,
+                e: None,
+                crv: Some("Ed25519".to_string()),
+                x: Some(x),
+            }),
+        })
+    }
+
+    pub fn kid(&self) -> Option<&str> {
+        self.kid.as_deref()
+    }
+
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    pub fn encoding_key(&self) -> &EncodingKey {
+        &self.encoding_key
+    }
+
+    pub fn decoding_key(&self) -> &DecodingKey {
+        &self.decoding_key
+    }
+}
+
+/// Conjunto de chaves: uma chave ativa assina novos tokens e todas as demais continuam
+/// verificando tokens já emitidos, permitindo rotação sem derrubar sessões em andamento.
+pub struct JwtKeySet {
+    active: usize,
+    keys: Vec<JwtKey>,
+}
+
+impl JwtKeySet {
+    /// Modo legado: um único segredo HMAC
+    pub fn hmac(secret: &str) -> Self {
+        Self {
+            active: 0,
+            keys: vec![JwtKey::hmac(secret)],
+        }
+    }
+
+    /// Chaves identificadas por `kid`, assinando com `active_kid`
+    pub fn new(keys: Vec<JwtKey>, active_kid: &str) -> Result<Self, JwtKeyError> {
+        for (index, key) in keys.iter().enumerate() {
+            if keys[..index].iter().any(|other| other.kid == key.kid) {
+                return Err(JwtKeyError::DuplicateKeyId(key.kid.clone().unwrap_or_default()));
+            }
+        }
+
+        let active = keys
+            .iter()
+            .position(|key| key.kid() == Some(active_kid))
+            .ok_or_else(|| JwtKeyError::UnknownActiveKey(active_kid.to_string()))?;
+
+        Ok(Self { active, keys })
+    }
+
+    /// Mantém um segredo HMAC apenas para verificar tokens antigos (sem `kid`)
+    /// durante a migração para chaves assimétricas
+    pub fn with_legacy_hmac(mut self, secret: &str) -> Self {
+        self.keys.push(JwtKey::hmac(secret));
+        self
+    }
+
+    pub fn signing_key(&self) -> &JwtKey {
+        &self.keys[self.active]
+    }
+
+    /// Chave que deve verificar um token com o `kid` informado no header
+    pub fn verification_key(&self, kid: Option<&str>) -> Option<&JwtKey> {
+        self.keys.iter().find(|key| key.kid() == kid)
+    }
+
+    /// Chaves públicas publicadas para outros serviços verificarem nossos tokens
+    pub fn jwks(&self) -> JwkSet {
+        JwkSet {
+            keys: self.keys.iter().filter_map(|key| key.jwk.clone()).collect(),
+        }
+    }
+}
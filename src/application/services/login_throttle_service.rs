@@ -0,0 +1,60 @@
+use crate::domain::entities::login_throttle::{LockoutPolicy, ThrottleScope};
+use crate::domain::repositories::login_throttle_repository::{
+    DynLoginThrottleRepository, LoginThrottleRepositoryError,
+};
+use chrono::{DateTime, Utc};
+
+/// Controla tentativas de login com falha e bloqueios temporários
+pub struct LoginThrottleService {
+    repository: DynLoginThrottleRepository,
+    policy: LockoutPolicy,
+}
+
+impl LoginThrottleService {
+    pub fn new(repository: DynLoginThrottleRepository, policy: LockoutPolicy) -> Self {
+        Self { repository, policy }
+    }
+
+    /// Retorna o fim do bloqueio ativo, se houver
+    pub async fn locked_until(
+        &self,
+        scope: ThrottleScope,
+        subject: &str,
+    ) -> Result<Option<DateTime<Utc>>, LoginThrottleRepositoryError> {
+        let now = Utc::now();
+        let throttle = self.repository.find(scope, subject).await?;
+
+        Ok(throttle
+            .filter(|t| t.is_locked(now))
+            .and_then(|t| t.locked_until))
+    }
+
+    /// Registra uma falha e retorna o fim do bloqueio caso ele tenha sido acionado
+    pub async fn register_failure(
+        &self,
+        scope: ThrottleScope,
+        subject: &str,
+    ) -> Result<Option<DateTime<Utc>>, LoginThrottleRepositoryError> {
+        let now = Utc::now();
+        let throttle = self
+            .repository
+            .record_failure(scope, subject, &self.policy, now)
+            .await?;
+
+        let Some(locked_until) = throttle.lockout(&self.policy, now) else {
+            return Ok(None);
+        };
+
+        let threshold = self.policy.threshold(scope);
+        match self.repository.lock(scope, subject, threshold, locked_until, now).await? {
+            Some(throttle) => Ok(throttle.locked_until),
+            // Outra falha simultânea aplicou o bloqueio antes
+            None => self.locked_until(scope, subject).await,
+        }
+    }
+
+    /// Zera o contador e remove qualquer bloqueio
+    pub async fn reset(&self, scope: ThrottleScope, subject: &str) -> Result<(), LoginThrottleRepositoryError> {
+        self.repository.delete(scope, subject).await
+    }
+}
@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum MailerError {
+    #[error("Failed to send email: {0}")]
+    #[allow(dead_code)]
+    SendError(String),
+}
+
+/// Mensagem de email a ser enviada
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: Option<String>,
+}
+
+/// Porta de saída para envio de emails
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, message: EmailMessage) -> Result<(), MailerError>;
+}
+
+pub type DynMailer = Arc<dyn Mailer>;
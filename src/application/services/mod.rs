@@ -1,2 +1,25 @@
 // Módulo de serviços de aplicação
-pub mod auth_service;
\ No newline at end of file
+pub mod auth_service;
+pub mod login_throttle_service;
+pub mod mailer;
+pub mod totp_service;
+pub mod api_key_service;
+pub mod jwt_keys;
+pub mod session_service;
+pub mod identity_provider;
+pub mod ranking_service;
+pub mod notification_channel;
+pub mod alert_dispatcher;
+pub mod alert_service;
+pub mod digest_renderer;
+pub mod digest_service;
+pub mod webhook_sender;
+pub mod webhook_dispatcher;
+pub mod article_event_notifier;
+pub mod article_stream_service;
+pub mod read_state_bus;
+pub mod read_state_sync_service;
+pub mod syndication_renderer;
+pub mod article_export_writer;
+pub mod csv_encoder;
+pub mod webhook_target_policy;
\ No newline at end of file
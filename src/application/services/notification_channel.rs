@@ -0,0 +1,23 @@
+use crate::domain::entities::alert::{AlertChannel, AlertRule, Notification};
+use crate::domain::entities::article::Article;
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum NotificationChannelError {
+    #[error("Notification delivery failed: {0}")]
+    DeliveryFailed(String),
+}
+
+/// Porta de saída para entregar alertas fora do app (email, webhook)
+#[async_trait]
+pub trait NotificationChannel: Send + Sync {
+    /// Canal atendido por esta implementação; as regras escolhem os canais pelo nome
+    fn channel(&self) -> AlertChannel;
+
+    /// Entrega uma notificação recém-criada pela regra
+    async fn deliver(&self, rule: &AlertRule, notification: &Notification, article: &Article) -> Result<(), NotificationChannelError>;
+}
+
+pub type DynNotificationChannel = Arc<dyn NotificationChannel>;
@@ -0,0 +1,135 @@
+use crate::domain::entities::article::ArticleResponse;
+use crate::domain::entities::ranking::{CategoryEngagement, RankingWeights, ScoreBreakdown};
+use crate::domain::entities::subscription::Subscription;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Quanto cada interação pesa na afinidade com uma categoria
+const READ_SIGNAL: f64 = 1.0;
+const SAVE_SIGNAL: f64 = 3.0;
+/// Por avaliação, multiplicado pela distância da nota ao meio da escala (-1 a 1)
+const RATING_SIGNAL: f64 = 2.0;
+
+/// Nota da IA assumida enquanto o artigo não foi avaliado
+const NEUTRAL_QUALITY: f64 = 0.5;
+
+/// O que o ranking sabe do usuário: afinidade por categoria (-1 a 1) e peso de cada feed assinado
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RankingProfile {
+    category_affinity: HashMap<Uuid, f64>,
+    feed_weights: HashMap<Uuid, f64>,
+}
+
+impl RankingProfile {
+    /// Aprende a afinidade com as leituras, os salvos e as notas do usuário: cada categoria soma
+    /// seus sinais e o resultado é dividido pelo maior sinal, então a categoria favorita vale 1
+    pub fn new(engagement: &[CategoryEngagement], saved_categories: &[Uuid], subscriptions: &[Subscription]) -> Self {
+        let mut signals: HashMap<Uuid, f64> = HashMap::new();
+
+        for item in engagement {
+            let rating = item
+                .rating_avg
+                .map(|avg| item.rating_count as f64 * RATING_SIGNAL * (avg - 5.0) / 5.0)
+                .unwrap_or(0.0);
+            *signals.entry(item.category_id).or_default() += item.reads as f64 * READ_SIGNAL + rating;
+        }
+        for category_id in saved_categories {
+            *signals.entry(*category_id).or_default() += SAVE_SIGNAL;
+        }
+
+        let strongest = signals.values().fold(0.0_f64, |max, signal| max.max(signal.abs()));
+        let category_affinity = if strongest > 0.0 {
+            signals
+                .into_iter()
+                .map(|(category_id, signal)| (category_id, signal / strongest))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        Self {
+            category_affinity,
+            feed_weights: subscriptions
+                .iter()
+                .map(|subscription| (subscription.feed_id, subscription.weight))
+                .collect(),
+        }
+    }
+
+    /// Afinidade com a categoria; 0 para categorias sem interação e artigos sem categoria
+    pub fn category_affinity(&self, category_id: Option<Uuid>) -> f64 {
+        category_id
+            .and_then(|category_id| self.category_affinity.get(&category_id).copied())
+            .unwrap_or(0.0)
+    }
+
+    /// Peso do feed na assinatura; neutro para feeds desconhecidos
+    pub fn feed_weight(&self, feed_id: Uuid) -> f64 {
+        self.feed_weights
+            .get(&feed_id)
+            .copied()
+            .unwrap_or(Subscription::DEFAULT_WEIGHT)
+    }
+}
+
+/// Pontuação do ranking "Para você". Fica na camada de aplicação para que os pesos possam
+/// mudar sem tocar nos repositórios, e devolve a composição de cada pontuação para depuração
+pub struct RankingService {
+    weights: RankingWeights,
+}
+
+impl RankingService {
+    pub fn new(weights: RankingWeights) -> Self {
+        Self { weights }
+    }
+
+    /// `(w_quality * rate/10 + w_recency * 0.5^(idade/meia-vida) + w_affinity * afinidade) * peso do feed`
+    pub fn score(&self, article: &ArticleResponse, profile: &RankingProfile, now: DateTime<Utc>) -> ScoreBreakdown {
+        let quality = article
+            .rate
+            .map(|rate| (rate as f64 / 10.0).clamp(0.0, 1.0))
+            .unwrap_or(NEUTRAL_QUALITY);
+
+        // Artigos com data no futuro contam como recém-publicados
+        let age_hours = ((now - article.pub_date).num_seconds().max(0) as f64) / 3600.0;
+        let recency = 0.5_f64.powf(age_hours / self.weights.half_life_hours.max(f64::EPSILON));
+
+        let breakdown = ScoreBreakdown {
+            quality: self.weights.quality * quality,
+            recency: self.weights.recency * recency,
+            affinity: self.weights.affinity * profile.category_affinity(article.category_id),
+            feed_weight: profile.feed_weight(article.feed_id),
+            score: 0.0,
+        };
+
+        ScoreBreakdown {
+            score: (breakdown.quality + breakdown.recency + breakdown.affinity) * breakdown.feed_weight,
+            ..breakdown
+        }
+    }
+
+    /// Ordena da maior para a menor pontuação; no empate, o mais recente primeiro
+    pub fn rank(
+        &self,
+        articles: Vec<ArticleResponse>,
+        profile: &RankingProfile,
+        now: DateTime<Utc>,
+    ) -> Vec<(ArticleResponse, ScoreBreakdown)> {
+        let mut ranked: Vec<(ArticleResponse, ScoreBreakdown)> = articles
+            .into_iter()
+            .map(|article| {
+                let breakdown = self.score(&article, profile, now);
+                (article, breakdown)
+            })
+            .collect();
+
+        ranked.sort_by(|(a, a_score), (b, b_score)| {
+            b_score
+                .score
+                .total_cmp(&a_score.score)
+                .then(b.pub_date.cmp(&a.pub_date))
+        });
+        ranked
+    }
+}
@@ -0,0 +1,23 @@
+use crate::domain::entities::read_state::ReadStateEvent;
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ReadStateBusError {
+    #[error("Publish failed: {0}")]
+    PublishFailed(String),
+}
+
+/// Porta para repassar as mudanças de estado de leitura entre as instâncias do servidor
+#[async_trait]
+pub trait ReadStateBus: Send + Sync {
+    /// Envia a mudança a todas as instâncias, inclusive esta
+    async fn publish(&self, event: &ReadStateEvent) -> Result<(), ReadStateBusError>;
+
+    /// Espera por até `timeout` e retorna as mudanças publicadas por qualquer instância
+    async fn receive(&self, timeout: Duration) -> Vec<ReadStateEvent>;
+}
+
+pub type DynReadStateBus = Arc<dyn ReadStateBus>;
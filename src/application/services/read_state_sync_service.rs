@@ -0,0 +1,66 @@
+use crate::application::services::read_state_bus::DynReadStateBus;
+use crate::domain::entities::read_state::ReadStateEvent;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+/// Capacidade do canal de cada conexão
+const CHANNEL_CAPACITY: usize = 256;
+
+/// Sincroniza o estado de leitura entre os dispositivos de um usuário: as mudanças vão para
+/// o `ReadStateBus` e voltam, em todas as instâncias, para as conexões WebSocket abertas.
+/// Sem bus, as mudanças só chegam às conexões desta instância
+pub struct ReadStateSyncService {
+    sender: broadcast::Sender<ReadStateEvent>,
+    bus: Option<DynReadStateBus>,
+}
+
+impl ReadStateSyncService {
+    pub fn new(bus: DynReadStateBus) -> Self {
+        Self::build(Some(bus))
+    }
+
+    /// Sem repasse entre instâncias; para testes e para rodar com uma instância só
+    #[allow(dead_code)]
+    pub fn local() -> Self {
+        Self::build(None)
+    }
+
+    fn build(bus: Option<DynReadStateBus>) -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { sender, bus }
+    }
+
+    /// Recebe as mudanças do bus e as entrega às conexões locais; roda até o fim do processo
+    pub async fn run(self: Arc<Self>, timeout: std::time::Duration) {
+        let Some(bus) = self.bus.clone() else {
+            return;
+        };
+        loop {
+            for event in bus.receive(timeout).await {
+                self.deliver(event);
+            }
+        }
+    }
+
+    /// Publica uma mudança; se o bus falhar, ao menos as conexões desta instância a recebem
+    pub async fn publish(&self, event: ReadStateEvent) {
+        match &self.bus {
+            Some(bus) => {
+                if let Err(e) = bus.publish(&event).await {
+                    log::error!("Failed to publish read state change: {:?}", e);
+                    self.deliver(event);
+                }
+            }
+            None => self.deliver(event),
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<ReadStateEvent> {
+        self.sender.subscribe()
+    }
+
+    fn deliver(&self, event: ReadStateEvent) {
+        // Sem conexões abertas o envio falha, e tudo bem
+        let _ = self.sender.send(event);
+    }
+}
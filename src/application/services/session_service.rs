@@ -0,0 +1,82 @@
+use crate::application::services::auth_service::{AuthError, AuthService};
+use crate::domain::entities::session::{Session, SessionClient};
+use crate::domain::repositories::session_repository::{DynSessionRepository, SessionRepositoryError};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Intervalo mínimo entre atualizações de `last_seen_at`, evitando uma escrita por requisição
+const LAST_SEEN_UPDATE_INTERVAL_SECONDS: i64 = 60;
+
+#[derive(Debug, Error)]
+pub enum SessionError {
+    #[error("Failed to generate token: {0}")]
+    TokenError(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<AuthError> for SessionError {
+    fn from(err: AuthError) -> Self {
+        SessionError::TokenError(err.to_string())
+    }
+}
+
+impl From<SessionRepositoryError> for SessionError {
+    fn from(err: SessionRepositoryError) -> Self {
+        SessionError::RepositoryError(err.to_string())
+    }
+}
+
+/// Abre sessões de login e valida o `sid` dos tokens de acesso
+pub struct SessionService {
+    repository: DynSessionRepository,
+    auth_service: Arc<AuthService>,
+}
+
+impl SessionService {
+    pub fn new(repository: DynSessionRepository, auth_service: Arc<AuthService>) -> Self {
+        Self {
+            repository,
+            auth_service,
+        }
+    }
+
+    /// Registra a sessão do dispositivo e emite o token de acesso vinculado a ela
+    pub async fn start(&self, user_id: Uuid, email: &str, client: SessionClient) -> Result<String, SessionError> {
+        let now = Utc::now();
+
+        // Aproveita o login para descartar sessões expiradas do usuário
+        self.repository.delete_expired(user_id, now).await?;
+
+        let session = Session::new(user_id, client, now + self.auth_service.token_ttl());
+        let session = self.repository.create(session).await?;
+
+        Ok(self.auth_service.generate_session_token(user_id, email, session.id)?)
+    }
+
+    /// Revoga as sessões do usuário, mantendo `except` (a sessão da requisição atual, por exemplo)
+    pub async fn revoke_all(&self, user_id: Uuid, except: Option<Uuid>) -> Result<usize, SessionRepositoryError> {
+        self.repository.revoke_all(user_id, except, Utc::now()).await
+    }
+
+    /// Verifica se a sessão pertence ao usuário e segue ativa, registrando o acesso
+    pub async fn validate(&self, user_id: Uuid, session_id: Uuid) -> Result<bool, SessionRepositoryError> {
+        let Some(session) = self.repository.find_by_id(session_id).await? else {
+            return Ok(false);
+        };
+
+        let now = Utc::now();
+        if session.user_id != user_id || !session.is_active(now) {
+            return Ok(false);
+        }
+
+        if session.needs_last_seen_update(now, Duration::seconds(LAST_SEEN_UPDATE_INTERVAL_SECONDS)) {
+            self.repository.touch(session.id, now).await?;
+        }
+
+        Ok(true)
+    }
+}
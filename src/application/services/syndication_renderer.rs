@@ -0,0 +1,183 @@
+use crate::domain::entities::syndication::{FeedFormat, SyndicationFeed, SyndicationItem};
+
+/// Serializa um `SyndicationFeed` em RSS 2.0, Atom 1.0 ou JSON Feed 1.1
+pub struct SyndicationRenderer;
+
+impl SyndicationRenderer {
+    /// `feed_url` é o endereço do próprio feed (no formato pedido) e `home_page_url` a página de origem
+    pub fn render(feed: &SyndicationFeed, format: FeedFormat, feed_url: &str, home_page_url: &str) -> String {
+        match format {
+            FeedFormat::Rss => Self::rss(feed, feed_url, home_page_url),
+            FeedFormat::Atom => Self::atom(feed, feed_url, home_page_url),
+            FeedFormat::Json => Self::json(feed, feed_url, home_page_url),
+        }
+    }
+
+    fn rss(feed: &SyndicationFeed, feed_url: &str, home_page_url: &str) -> String {
+        let mut xml = String::from(concat!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            "\n",
+            r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom" "#,
+            r#"xmlns:content="http://purl.org/rss/1.0/modules/content/" xmlns:dc="http://purl.org/dc/elements/1.1/">"#,
+            "\n<channel>\n"
+        ));
+        xml.push_str(&format!("<title>{}</title>\n", escape_xml(&feed.title)));
+        xml.push_str(&format!("<link>{}</link>\n", escape_xml(home_page_url)));
+        xml.push_str(&format!("<description>{}</description>\n", escape_xml(&feed.description)));
+        xml.push_str(&format!(
+            "<atom:link href=\"{}\" rel=\"self\" type=\"application/rss+xml\"/>\n",
+            escape_xml(feed_url)
+        ));
+        xml.push_str(&format!("<lastBuildDate>{}</lastBuildDate>\n", feed.updated.to_rfc2822()));
+        xml.push_str("<generator>Nexight</generator>\n");
+
+        for item in &feed.items {
+            xml.push_str("<item>\n");
+            xml.push_str(&format!("<title>{}</title>\n", escape_xml(&item.title)));
+            xml.push_str(&format!("<link>{}</link>\n", escape_xml(&item.link)));
+            xml.push_str(&format!("<guid isPermaLink=\"false\">urn:uuid:{}</guid>\n", item.id));
+            xml.push_str(&format!("<pubDate>{}</pubDate>\n", item.published.to_rfc2822()));
+            xml.push_str(&format!("<dc:creator>{}</dc:creator>\n", escape_xml(&item.author)));
+            xml.push_str(&format!("<description>{}</description>\n", escape_xml(&item.summary)));
+            if !item.content.is_empty() {
+                xml.push_str(&format!("<content:encoded>{}</content:encoded>\n", escape_xml(&item.content)));
+            }
+            for tag in &item.tags {
+                xml.push_str(&format!("<category>{}</category>\n", escape_xml(tag)));
+            }
+            if let Some(image) = &item.image {
+                xml.push_str(&format!(
+                    "<enclosure url=\"{}\" type=\"{}\" length=\"0\"/>\n",
+                    escape_xml(image),
+                    image_type(image)
+                ));
+            }
+            xml.push_str("</item>\n");
+        }
+
+        xml.push_str("</channel>\n</rss>\n");
+        xml
+    }
+
+    fn atom(feed: &SyndicationFeed, feed_url: &str, home_page_url: &str) -> String {
+        let mut xml = String::from(concat!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            "\n",
+            r#"<feed xmlns="http://www.w3.org/2005/Atom">"#,
+            "\n"
+        ));
+        xml.push_str(&format!("<id>{}</id>\n", escape_xml(&feed.id)));
+        xml.push_str(&format!("<title>{}</title>\n", escape_xml(&feed.title)));
+        xml.push_str(&format!("<subtitle>{}</subtitle>\n", escape_xml(&feed.description)));
+        xml.push_str(&format!("<updated>{}</updated>\n", feed.updated.to_rfc3339()));
+        xml.push_str(&format!("<link rel=\"self\" type=\"application/atom+xml\" href=\"{}\"/>\n", escape_xml(feed_url)));
+        xml.push_str(&format!("<link rel=\"alternate\" href=\"{}\"/>\n", escape_xml(home_page_url)));
+        xml.push_str("<generator>Nexight</generator>\n");
+
+        for item in &feed.items {
+            xml.push_str("<entry>\n");
+            xml.push_str(&format!("<id>urn:uuid:{}</id>\n", item.id));
+            xml.push_str(&format!("<title>{}</title>\n", escape_xml(&item.title)));
+            xml.push_str(&format!("<link rel=\"alternate\" href=\"{}\"/>\n", escape_xml(&item.link)));
+            xml.push_str(&format!("<published>{}</published>\n", item.published.to_rfc3339()));
+            xml.push_str(&format!("<updated>{}</updated>\n", item.updated.to_rfc3339()));
+            xml.push_str(&format!("<author><name>{}</name></author>\n", escape_xml(author_name(item))));
+            xml.push_str(&format!("<summary>{}</summary>\n", escape_xml(&item.summary)));
+            if !item.content.is_empty() {
+                xml.push_str(&format!("<content type=\"html\">{}</content>\n", escape_xml(&item.content)));
+            }
+            for tag in &item.tags {
+                xml.push_str(&format!("<category term=\"{}\"/>\n", escape_xml(tag)));
+            }
+            if let Some(image) = &item.image {
+                xml.push_str(&format!(
+                    "<link rel=\"enclosure\" type=\"{}\" href=\"{}\"/>\n",
+                    image_type(image),
+                    escape_xml(image)
+                ));
+            }
+            xml.push_str("</entry>\n");
+        }
+
+        xml.push_str("</feed>\n");
+        xml
+    }
+
+    fn json(feed: &SyndicationFeed, feed_url: &str, home_page_url: &str) -> String {
+        let items: Vec<serde_json::Value> = feed
+            .items
+            .iter()
+            .map(|item| {
+                let mut value = serde_json::json!({
+                    "id": format!("urn:uuid:{}", item.id),
+                    "url": item.link,
+                    "title": item.title,
+                    "summary": item.summary,
+                    "date_published": item.published.to_rfc3339(),
+                    "date_modified": item.updated.to_rfc3339(),
+                    "authors": [{ "name": author_name(item) }],
+                    "tags": item.tags,
+                });
+                // O JSON Feed exige `content_html` ou `content_text` em cada item
+                if item.content.is_empty() {
+                    value["content_text"] = serde_json::Value::String(item.summary.clone());
+                } else {
+                    value["content_html"] = serde_json::Value::String(item.content.clone());
+                }
+                if let Some(image) = &item.image {
+                    value["image"] = serde_json::Value::String(image.clone());
+                }
+                value
+            })
+            .collect();
+
+        serde_json::json!({
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": feed.title,
+            "description": feed.description,
+            "home_page_url": home_page_url,
+            "feed_url": feed_url,
+            "items": items,
+        })
+        .to_string()
+    }
+}
+
+/// O Atom exige um nome de autor não vazio
+fn author_name(item: &SyndicationItem) -> &str {
+    if item.author.trim().is_empty() {
+        "Nexight"
+    } else {
+        &item.author
+    }
+}
+
+fn image_type(url: &str) -> &'static str {
+    let path = url.split(['?', '#']).next().unwrap_or_default().to_ascii_lowercase();
+    if path.ends_with(".png") {
+        "image/png"
+    } else if path.ends_with(".gif") {
+        "image/gif"
+    } else if path.ends_with(".webp") {
+        "image/webp"
+    } else {
+        "image/jpeg"
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Caracteres de controle não são permitidos em XML 1.0
+            c if (c as u32) < 0x20 && !matches!(c, '\t' | '\n' | '\r') => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
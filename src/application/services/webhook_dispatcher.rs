@@ -0,0 +1,196 @@
+use crate::application::services::webhook_sender::{DynWebhookSender, WebhookRequest};
+use crate::domain::entities::webhook::{webhook_signature, WebhookDelivery, WebhookSubscription};
+use crate::domain::repositories::article_event_repository::{ArticleEventRepositoryError, DynArticleEventRepository};
+use crate::domain::repositories::webhook_repository::{DynWebhookRepository, WebhookRepositoryError};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Eventos do outbox e entregas processados por rodada
+const BATCH_SIZE: i64 = 100;
+/// Por quanto tempo uma entrega reservada fica fora do alcance das outras instâncias
+const LEASE_SECONDS: i64 = 60;
+/// Por quanto tempo eventos distribuídos e entregas concluídas são mantidos, por padrão
+pub const DEFAULT_RETENTION_DAYS: i64 = 30;
+/// Intervalo entre as limpezas do outbox e do histórico de entregas
+const PRUNE_INTERVAL_SECONDS: i64 = 3600;
+
+pub const SIGNATURE_HEADER: &str = "X-Nexight-Signature";
+pub const EVENT_HEADER: &str = "X-Nexight-Event";
+pub const DELIVERY_HEADER: &str = "X-Nexight-Delivery";
+
+#[derive(Debug, Error)]
+pub enum WebhookDispatcherError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleEventRepositoryError> for WebhookDispatcherError {
+    fn from(err: ArticleEventRepositoryError) -> Self {
+        WebhookDispatcherError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<WebhookRepositoryError> for WebhookDispatcherError {
+    fn from(err: WebhookRepositoryError) -> Self {
+        WebhookDispatcherError::RepositoryError(err.to_string())
+    }
+}
+
+/// Distribui os eventos do outbox às assinaturas e envia as entregas pendentes,
+/// com novas tentativas em backoff exponencial. Cada evento gera no máximo uma entrega
+/// por assinatura, então rodadas repetidas (ou várias instâncias) não duplicam envios
+pub struct WebhookDispatcher {
+    article_event_repository: DynArticleEventRepository,
+    webhook_repository: DynWebhookRepository,
+    sender: DynWebhookSender,
+    retention: Duration,
+}
+
+impl WebhookDispatcher {
+    pub fn new(
+        article_event_repository: DynArticleEventRepository,
+        webhook_repository: DynWebhookRepository,
+        sender: DynWebhookSender,
+    ) -> Self {
+        Self {
+            article_event_repository,
+            webhook_repository,
+            sender,
+            retention: Duration::days(DEFAULT_RETENTION_DAYS),
+        }
+    }
+
+    /// Dias que eventos distribuídos e entregas concluídas ficam guardados antes da limpeza
+    pub fn with_retention(mut self, days: i64) -> Self {
+        self.retention = Duration::days(days);
+        self
+    }
+
+    /// Processa o outbox e as entregas periodicamente; roda até o fim do processo
+    pub async fn run(self: Arc<Self>, every: std::time::Duration) {
+        let mut interval = tokio::time::interval(every);
+        let mut last_pruned: Option<DateTime<Utc>> = None;
+        loop {
+            interval.tick().await;
+            let now = Utc::now();
+            if let Err(e) = self.dispatch(now).await {
+                log::error!("Failed to dispatch webhooks: {:?}", e);
+            }
+
+            if last_pruned.is_none_or(|at| now - at >= Duration::seconds(PRUNE_INTERVAL_SECONDS)) {
+                last_pruned = Some(now);
+                if let Err(e) = self.prune(now).await {
+                    log::error!("Failed to prune webhook history: {:?}", e);
+                }
+            }
+        }
+    }
+
+    /// Remove os eventos distribuídos e as entregas concluídas mais antigos que a retenção.
+    /// Eventos pendentes e entregas ainda em tentativa nunca são removidos. Retorna quantas
+    /// linhas foram removidas
+    pub async fn prune(&self, now: DateTime<Utc>) -> Result<usize, WebhookDispatcherError> {
+        let cutoff = now - self.retention;
+        let events = self.article_event_repository.delete_processed_before(cutoff).await?;
+        let deliveries = self.webhook_repository.delete_finished_deliveries_before(cutoff).await?;
+
+        Ok(events + deliveries)
+    }
+
+    /// Uma rodada: distribui os eventos pendentes e tenta as entregas vencidas em `now`.
+    /// Retorna quantas entregas foram tentadas
+    pub async fn dispatch(&self, now: DateTime<Utc>) -> Result<usize, WebhookDispatcherError> {
+        self.fan_out(now).await?;
+        self.deliver_due(now).await
+    }
+
+    /// Envia um evento `webhook.test` na hora, sem novas tentativas; a entrega fica no histórico
+    pub async fn test_fire(&self, subscription: &WebhookSubscription) -> Result<WebhookDelivery, WebhookDispatcherError> {
+        let mut delivery = WebhookDelivery::test(subscription.id);
+        self.webhook_repository.create_delivery(delivery.clone()).await?;
+        self.attempt(subscription, &mut delivery, Utc::now()).await;
+
+        Ok(self.webhook_repository.update_delivery(delivery).await?)
+    }
+
+    /// Cria as entregas de cada evento pendente e o marca como distribuído. Se o processo
+    /// cair no meio, o evento é distribuído de novo e as entregas já criadas são ignoradas
+    async fn fan_out(&self, now: DateTime<Utc>) -> Result<(), WebhookDispatcherError> {
+        let events = self.article_event_repository.list_pending(BATCH_SIZE).await?;
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let subscriptions = self.webhook_repository.list_enabled().await?;
+        for event in events {
+            for subscription in subscriptions.iter().filter(|s| s.wants(event.event_type)) {
+                self.webhook_repository
+                    .create_delivery(WebhookDelivery::for_event(subscription.id, &event))
+                    .await?;
+            }
+            self.article_event_repository.mark_processed(event.id, now).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn deliver_due(&self, now: DateTime<Utc>) -> Result<usize, WebhookDispatcherError> {
+        let deliveries = self
+            .webhook_repository
+            .claim_due_deliveries(now, now + Duration::seconds(LEASE_SECONDS), BATCH_SIZE)
+            .await?;
+        if deliveries.is_empty() {
+            return Ok(0);
+        }
+
+        let subscriptions: HashMap<_, _> = self
+            .webhook_repository
+            .list()
+            .await?
+            .into_iter()
+            .map(|subscription| (subscription.id, subscription))
+            .collect();
+
+        let mut attempted = 0;
+        for mut delivery in deliveries {
+            match subscriptions.get(&delivery.subscription_id) {
+                Some(subscription) if subscription.enabled => {
+                    self.attempt(subscription, &mut delivery, now).await;
+                    attempted += 1;
+                }
+                // Assinatura desativada depois da distribuição: a entrega não é mais tentada
+                Some(_) => {
+                    delivery.attempts = WebhookDelivery::MAX_ATTEMPTS - 1;
+                    delivery.record_failure(None, "Subscription disabled".to_string(), now);
+                }
+                // Removida: as entregas vão junto com ela
+                None => continue,
+            }
+            self.webhook_repository.update_delivery(delivery).await?;
+        }
+
+        Ok(attempted)
+    }
+
+    /// Uma tentativa de envio; qualquer resposta fora de 2xx conta como falha
+    async fn attempt(&self, subscription: &WebhookSubscription, delivery: &mut WebhookDelivery, now: DateTime<Utc>) {
+        let body = delivery.payload.to_string();
+        let request = WebhookRequest {
+            url: subscription.url.clone(),
+            headers: vec![
+                (SIGNATURE_HEADER.to_string(), webhook_signature(&subscription.secret, Utc::now().timestamp(), &body)),
+                (EVENT_HEADER.to_string(), delivery.event_type.as_str().to_string()),
+                (DELIVERY_HEADER.to_string(), delivery.id.to_string()),
+            ],
+            body,
+        };
+
+        match self.sender.send(&request).await {
+            Ok(status) if (200..300).contains(&status) => delivery.record_success(status as i32, now),
+            Ok(status) => delivery.record_failure(Some(status as i32), format!("HTTP {}", status), now),
+            Err(e) => delivery.record_failure(None, e.to_string(), now),
+        }
+    }
+}
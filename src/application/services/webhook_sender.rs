@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum WebhookSenderError {
+    #[error("Request failed: {0}")]
+    RequestFailed(String),
+}
+
+/// Requisição já assinada, pronta para o POST
+#[derive(Debug, Clone)]
+pub struct WebhookRequest {
+    pub url: String,
+    pub body: String,
+    pub headers: Vec<(String, String)>,
+}
+
+/// Porta de saída para os webhooks de eventos de artigos
+#[async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// Envia a requisição e retorna o status HTTP recebido; erros de rede e timeouts viram `Err`
+    async fn send(&self, request: &WebhookRequest) -> Result<u16, WebhookSenderError>;
+}
+
+pub type DynWebhookSender = Arc<dyn WebhookSender>;
@@ -0,0 +1,91 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use url::{Host, Url};
+
+/// Destinos aceitos para os webhooks dos alertas, que qualquer usuário pode cadastrar.
+/// Por padrão só endereços públicos: loopback, redes privadas, link-local (onde ficam os
+/// metadados das nuvens, como `169.254.169.254`) e demais faixas reservadas são recusados
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WebhookTargetPolicy {
+    allow_private_networks: bool,
+}
+
+impl WebhookTargetPolicy {
+    pub fn public_only() -> Self {
+        Self::default()
+    }
+
+    /// Aceita qualquer destino; para desenvolvimento local e testes
+    pub fn allow_private_networks() -> Self {
+        Self {
+            allow_private_networks: true,
+        }
+    }
+
+    /// Valida a URL cadastrada: http(s), com host e, se o host for um IP, um endereço permitido.
+    /// Nomes de domínio só podem ser verificados na entrega, quando são resolvidos
+    pub fn check_url(&self, value: &str) -> Result<Url, String> {
+        let url = Url::parse(value).map_err(|_| "Webhook URL is invalid".to_string())?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err("Webhook URL must use http or https".to_string());
+        }
+
+        let allowed = match url.host() {
+            None => return Err("Webhook URL must have a host".to_string()),
+            Some(Host::Ipv4(ip)) => self.allows(IpAddr::V4(ip)),
+            Some(Host::Ipv6(ip)) => self.allows(IpAddr::V6(ip)),
+            Some(Host::Domain(domain)) => {
+                let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+                self.allow_private_networks || !(domain == "localhost" || domain.ends_with(".localhost"))
+            }
+        };
+
+        if allowed {
+            Ok(url)
+        } else {
+            Err("Webhook URL must point to a public address".to_string())
+        }
+    }
+
+    pub fn allows(&self, ip: IpAddr) -> bool {
+        self.allow_private_networks || is_public(ip)
+    }
+}
+
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_public_v4(v4),
+        IpAddr::V6(v6) => match v6.to_ipv4_mapped() {
+            Some(v4) => is_public_v4(v4),
+            None => is_public_v6(v6),
+        },
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // 0.0.0.0/8, 100.64.0.0/10 (CGNAT), 198.18.0.0/15 (benchmark) e 240.0.0.0/4 (reservado)
+        || a == 0
+        || (a == 100 && (b & 0xc0) == 64)
+        || (a == 198 && (b & 0xfe) == 18)
+        || a >= 240)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // fc00::/7 (rede local única), fe80::/10 (link-local) e 2001:db8::/32 (documentação)
+        || (first & 0xfe00) == 0xfc00
+        || (first & 0xffc0) == 0xfe80
+        || (first == 0x2001 && ip.segments()[1] == 0x0db8))
+}
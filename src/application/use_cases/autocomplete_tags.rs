@@ -0,0 +1,46 @@
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::tag::{Tag, TagSummary};
+use crate::domain::repositories::tag_repository::{DynTagRepository, TagRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum AutocompleteTagsError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<TagRepositoryError> for AutocompleteTagsError {
+    fn from(err: TagRepositoryError) -> Self {
+        AutocompleteTagsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AutocompleteTagsError> for ApplicationError {
+    fn from(err: AutocompleteTagsError) -> Self {
+        let AutocompleteTagsError::RepositoryError(msg) = err;
+        Self::internal(msg)
+    }
+}
+
+/// Sugere tags do usuário que começam com o texto digitado, das mais usadas para as menos usadas
+pub struct AutocompleteTagsUseCase {
+    tag_repository: DynTagRepository,
+}
+
+impl AutocompleteTagsUseCase {
+    pub const DEFAULT_LIMIT: i64 = 10;
+    const MAX_LIMIT: i64 = 50;
+
+    pub fn new(tag_repository: DynTagRepository) -> Self {
+        Self { tag_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, query: &str, limit: i64) -> Result<Vec<TagSummary>, AutocompleteTagsError> {
+        let limit = limit.clamp(1, Self::MAX_LIMIT);
+        Ok(self
+            .tag_repository
+            .search(user_id, &Tag::normalize_name(query), limit)
+            .await?)
+    }
+}
@@ -0,0 +1,117 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::application::services::auth_service::{AuthError, AuthService};
+use crate::application::services::session_service::SessionService;
+use crate::domain::entities::user::ChangePasswordDto;
+use crate::domain::repositories::session_repository::SessionRepositoryError;
+use crate::domain::repositories::user_repository::{DynUserRepository, UserRepositoryError};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum ChangePasswordError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Current password is incorrect")]
+    InvalidCurrentPassword,
+
+    #[error("User not found")]
+    UserNotFound,
+
+    #[error("Failed to hash password: {0}")]
+    PasswordHashError(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<UserRepositoryError> for ChangePasswordError {
+    fn from(err: UserRepositoryError) -> Self {
+        match err {
+            UserRepositoryError::NotFound => ChangePasswordError::UserNotFound,
+            _ => ChangePasswordError::RepositoryError(err.to_string()),
+        }
+    }
+}
+
+impl From<SessionRepositoryError> for ChangePasswordError {
+    fn from(err: SessionRepositoryError) -> Self {
+        ChangePasswordError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuthError> for ChangePasswordError {
+    fn from(err: AuthError) -> Self {
+        match err {
+            AuthError::InvalidPassword => ChangePasswordError::InvalidCurrentPassword,
+            _ => ChangePasswordError::PasswordHashError(err.to_string()),
+        }
+    }
+}
+
+impl From<ChangePasswordError> for ApplicationError {
+    fn from(err: ChangePasswordError) -> Self {
+        match err {
+            ChangePasswordError::ValidationError(failure) => failure.into(),
+            ChangePasswordError::InvalidCurrentPassword => {
+                Self::new(ErrorKind::Forbidden, "invalid_current_password", "Current password is incorrect")
+            }
+            ChangePasswordError::UserNotFound => Self::new(ErrorKind::NotFound, "user_not_found", "User not found"),
+            ChangePasswordError::PasswordHashError(msg) | ChangePasswordError::RepositoryError(msg) => {
+                Self::internal(msg)
+            }
+        }
+    }
+}
+
+pub struct ChangePasswordUseCase {
+    user_repository: DynUserRepository,
+    auth_service: Arc<AuthService>,
+    session_service: Arc<SessionService>,
+}
+
+impl ChangePasswordUseCase {
+    pub fn new(
+        user_repository: DynUserRepository,
+        auth_service: Arc<AuthService>,
+        session_service: Arc<SessionService>,
+    ) -> Self {
+        Self {
+            user_repository,
+            auth_service,
+            session_service,
+        }
+    }
+
+    /// Troca a senha e revoga as demais sessões do usuário, mantendo `current_session`
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        current_session: Option<Uuid>,
+        dto: ChangePasswordDto,
+    ) -> Result<(), ChangePasswordError> {
+        dto.validate()
+            .map_err(|e| ChangePasswordError::ValidationError(e.into()))?;
+
+        let mut user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(ChangePasswordError::UserNotFound)?;
+
+        if !self
+            .auth_service
+            .verify_password(&dto.current_password, &user.password_hash)?
+        {
+            return Err(ChangePasswordError::InvalidCurrentPassword);
+        }
+
+        user.password_hash = self.auth_service.hash_password(&dto.new_password)?;
+        self.user_repository.update(user).await?;
+        self.session_service.revoke_all(user_id, current_session).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,265 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::application::services::auth_service::{AuthError, AuthService};
+use crate::application::services::identity_provider::{ExternalIdentity, IdentityProviderError, IdentityProviders};
+use crate::application::services::session_service::{SessionError, SessionService};
+use crate::application::use_cases::login_user::{LoginContext, LoginOutcome};
+use crate::domain::entities::identity::{OidcCallbackDto, UserIdentity};
+use crate::domain::entities::user::{AuthResponse, User};
+use crate::domain::repositories::identity_repository::{DynIdentityRepository, IdentityRepositoryError};
+use crate::domain::repositories::mfa_repository::{DynMfaRepository, MfaRepositoryError};
+use crate::domain::repositories::user_repository::{DynUserRepository, UserRepositoryError};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum CompleteOidcLoginError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Unknown identity provider")]
+    UnknownProvider,
+
+    #[error("Invalid or expired state")]
+    InvalidState,
+
+    #[error("Identity provider unavailable: {0}")]
+    ProviderUnavailable(String),
+
+    #[error("Identity provider rejected the login: {0}")]
+    ProviderRejected(String),
+
+    #[error("The identity provider did not confirm the email address")]
+    EmailNotVerified,
+
+    #[error("Account is inactive")]
+    AccountInactive,
+
+    #[error("Failed to generate token: {0}")]
+    TokenError(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<IdentityProviderError> for CompleteOidcLoginError {
+    fn from(err: IdentityProviderError) -> Self {
+        match err {
+            IdentityProviderError::Unavailable(msg) => CompleteOidcLoginError::ProviderUnavailable(msg),
+            IdentityProviderError::Rejected(msg) => CompleteOidcLoginError::ProviderRejected(msg),
+        }
+    }
+}
+
+impl From<IdentityRepositoryError> for CompleteOidcLoginError {
+    fn from(err: IdentityRepositoryError) -> Self {
+        CompleteOidcLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UserRepositoryError> for CompleteOidcLoginError {
+    fn from(err: UserRepositoryError) -> Self {
+        CompleteOidcLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<MfaRepositoryError> for CompleteOidcLoginError {
+    fn from(err: MfaRepositoryError) -> Self {
+        CompleteOidcLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuthError> for CompleteOidcLoginError {
+    fn from(err: AuthError) -> Self {
+        CompleteOidcLoginError::TokenError(err.to_string())
+    }
+}
+
+impl From<SessionError> for CompleteOidcLoginError {
+    fn from(err: SessionError) -> Self {
+        match err {
+            SessionError::TokenError(msg) => CompleteOidcLoginError::TokenError(msg),
+            SessionError::RepositoryError(msg) => CompleteOidcLoginError::RepositoryError(msg),
+        }
+    }
+}
+
+impl From<CompleteOidcLoginError> for ApplicationError {
+    fn from(err: CompleteOidcLoginError) -> Self {
+        match err {
+            CompleteOidcLoginError::ValidationError(failure) => failure.into(),
+            CompleteOidcLoginError::UnknownProvider => {
+                Self::new(ErrorKind::NotFound, "unknown_identity_provider", "Unknown identity provider")
+            }
+            CompleteOidcLoginError::InvalidState => {
+                Self::new(ErrorKind::BadRequest, "invalid_oidc_state", "Invalid or expired state")
+            }
+            CompleteOidcLoginError::ProviderUnavailable(msg) => {
+                Self::new(ErrorKind::BadGateway, "identity_provider_unavailable", "Identity provider unavailable")
+                    .with_cause(msg)
+            }
+            CompleteOidcLoginError::ProviderRejected(msg) => Self::new(
+                ErrorKind::Unauthorized,
+                "identity_provider_rejected",
+                "Identity provider login rejected",
+            )
+            .with_cause(msg),
+            CompleteOidcLoginError::EmailNotVerified => Self::new(
+                ErrorKind::Forbidden,
+                "email_not_verified",
+                "The identity provider did not confirm the email address",
+            ),
+            CompleteOidcLoginError::AccountInactive => {
+                Self::new(ErrorKind::Forbidden, "account_inactive", "Account is inactive")
+            }
+            CompleteOidcLoginError::TokenError(msg) | CompleteOidcLoginError::RepositoryError(msg) => {
+                Self::internal(msg)
+            }
+        }
+    }
+}
+
+/// Second step of an OIDC login: validates the state, exchanges the code with the
+/// provider and signs in the linked user, linking or creating one by verified email
+pub struct CompleteOidcLoginUseCase {
+    providers: Arc<IdentityProviders>,
+    identity_repository: DynIdentityRepository,
+    user_repository: DynUserRepository,
+    auth_service: Arc<AuthService>,
+    mfa_repository: DynMfaRepository,
+    mfa_token_ttl: Duration,
+    session_service: Arc<SessionService>,
+}
+
+impl CompleteOidcLoginUseCase {
+    pub fn new(
+        providers: Arc<IdentityProviders>,
+        identity_repository: DynIdentityRepository,
+        user_repository: DynUserRepository,
+        auth_service: Arc<AuthService>,
+        mfa_repository: DynMfaRepository,
+        mfa_token_ttl: Duration,
+        session_service: Arc<SessionService>,
+    ) -> Self {
+        Self {
+            providers,
+            identity_repository,
+            user_repository,
+            auth_service,
+            mfa_repository,
+            mfa_token_ttl,
+            session_service,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        provider_name: &str,
+        dto: OidcCallbackDto,
+        context: LoginContext,
+    ) -> Result<LoginOutcome, CompleteOidcLoginError> {
+        dto.validate()
+            .map_err(|e| CompleteOidcLoginError::ValidationError(e.into()))?;
+
+        let provider = self
+            .providers
+            .get(provider_name)
+            .ok_or(CompleteOidcLoginError::UnknownProvider)?;
+
+        // The state is single use: it is removed even when the rest of the login fails
+        let request = self
+            .identity_repository
+            .take_auth_request(&AuthService::hash_token(&dto.state))
+            .await?
+            .filter(|request| request.provider == provider_name && !request.is_expired(Utc::now()))
+            .ok_or(CompleteOidcLoginError::InvalidState)?;
+
+        let external = provider
+            .exchange_code(&dto.code, &request.code_verifier, &request.nonce)
+            .await?;
+
+        let user = match self
+            .identity_repository
+            .find_identity(provider_name, &external.subject)
+            .await?
+        {
+            Some(identity) => {
+                self.identity_repository
+                    .touch_identity(identity.id, Utc::now())
+                    .await?;
+                self.user_repository
+                    .find_by_id(identity.user_id)
+                    .await?
+                    .ok_or_else(|| CompleteOidcLoginError::RepositoryError("linked user not found".to_string()))?
+            }
+            None => self.link_identity(provider_name, external).await?,
+        };
+
+        if !user.is_active {
+            return Err(CompleteOidcLoginError::AccountInactive);
+        }
+
+        // The provider replaces the password, not the second factor
+        let mfa_enabled = self
+            .mfa_repository
+            .find_by_user(user.id)
+            .await?
+            .is_some_and(|mfa| mfa.enabled);
+
+        if let Some(outcome) =
+            LoginOutcome::second_factor(&self.auth_service, &user, mfa_enabled, self.mfa_token_ttl)?
+        {
+            return Ok(outcome);
+        }
+
+        let token = self
+            .session_service
+            .start(user.id, &user.email, context.session_client())
+            .await?;
+
+        Ok(LoginOutcome::Authenticated(AuthResponse {
+            token,
+            user: user.into(),
+        }))
+    }
+
+    /// Links the external account to the user with the same email, creating the user if needed.
+    /// Only emails verified by the provider are trusted, otherwise anyone could claim an account.
+    async fn link_identity(&self, provider_name: &str, external: ExternalIdentity) -> Result<User, CompleteOidcLoginError> {
+        let email = match external.email {
+            Some(email) if external.email_verified => email,
+            _ => return Err(CompleteOidcLoginError::EmailNotVerified),
+        };
+
+        let user = match self.user_repository.find_by_email(&email).await? {
+            Some(user) => user,
+            None => {
+                // Nobody knows this password: the account signs in through the provider
+                // until the user sets a password of their own
+                let password_hash = self
+                    .auth_service
+                    .hash_password(&AuthService::generate_opaque_token())?;
+                let name = external
+                    .name
+                    .filter(|name| !name.trim().is_empty())
+                    .unwrap_or_else(|| email.split('@').next().unwrap_or(&email).to_string());
+
+                self.user_repository
+                    .create(User::new(email.clone(), password_hash, name))
+                    .await?
+            }
+        };
+
+        self.identity_repository
+            .create_identity(UserIdentity::new(
+                user.id,
+                provider_name.to_string(),
+                external.subject,
+                Some(email),
+            ))
+            .await?;
+
+        Ok(user)
+    }
+}
@@ -0,0 +1,120 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::application::services::auth_service::AuthService;
+use crate::domain::entities::user::{ConfirmEmailChangeDto, UserResponse};
+use crate::domain::repositories::email_change_request_repository::{
+    DynEmailChangeRequestRepository, EmailChangeRequestRepositoryError,
+};
+use crate::domain::repositories::user_repository::{DynUserRepository, UserRepositoryError};
+use chrono::Utc;
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum ConfirmEmailChangeError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Invalid verification token")]
+    InvalidToken,
+
+    #[error("Verification token expired")]
+    TokenExpired,
+
+    #[error("Email already in use")]
+    EmailAlreadyExists,
+
+    #[error("User not found")]
+    UserNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<UserRepositoryError> for ConfirmEmailChangeError {
+    fn from(err: UserRepositoryError) -> Self {
+        match err {
+            UserRepositoryError::EmailAlreadyExists => ConfirmEmailChangeError::EmailAlreadyExists,
+            UserRepositoryError::NotFound => ConfirmEmailChangeError::UserNotFound,
+            _ => ConfirmEmailChangeError::RepositoryError(err.to_string()),
+        }
+    }
+}
+
+impl From<EmailChangeRequestRepositoryError> for ConfirmEmailChangeError {
+    fn from(err: EmailChangeRequestRepositoryError) -> Self {
+        ConfirmEmailChangeError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ConfirmEmailChangeError> for ApplicationError {
+    fn from(err: ConfirmEmailChangeError) -> Self {
+        match err {
+            ConfirmEmailChangeError::ValidationError(failure) => failure.into(),
+            ConfirmEmailChangeError::InvalidToken => {
+                Self::new(ErrorKind::BadRequest, "invalid_verification_token", "Invalid verification token")
+            }
+            ConfirmEmailChangeError::TokenExpired => {
+                Self::new(ErrorKind::BadRequest, "verification_token_expired", "Verification token expired")
+            }
+            ConfirmEmailChangeError::EmailAlreadyExists => {
+                Self::new(ErrorKind::Conflict, "email_taken", "Email already in use")
+            }
+            ConfirmEmailChangeError::UserNotFound => {
+                Self::new(ErrorKind::NotFound, "user_not_found", "User not found")
+            }
+            ConfirmEmailChangeError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Aplica uma troca de email pendente após a confirmação do token
+pub struct ConfirmEmailChangeUseCase {
+    user_repository: DynUserRepository,
+    email_change_repository: DynEmailChangeRequestRepository,
+}
+
+impl ConfirmEmailChangeUseCase {
+    pub fn new(
+        user_repository: DynUserRepository,
+        email_change_repository: DynEmailChangeRequestRepository,
+    ) -> Self {
+        Self {
+            user_repository,
+            email_change_repository,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, dto: ConfirmEmailChangeDto) -> Result<UserResponse, ConfirmEmailChangeError> {
+        dto.validate()
+            .map_err(|e| ConfirmEmailChangeError::ValidationError(e.into()))?;
+
+        let request = self
+            .email_change_repository
+            .find_by_user(user_id)
+            .await?
+            .ok_or(ConfirmEmailChangeError::InvalidToken)?;
+
+        if request.token_hash != AuthService::hash_token(&dto.token) {
+            return Err(ConfirmEmailChangeError::InvalidToken);
+        }
+
+        if request.is_expired(Utc::now()) {
+            self.email_change_repository.delete_by_user(user_id).await?;
+            return Err(ConfirmEmailChangeError::TokenExpired);
+        }
+
+        let mut user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(ConfirmEmailChangeError::UserNotFound)?;
+
+        user.email = request.new_email;
+        let updated = self.user_repository.update(user).await?;
+
+        self.email_change_repository.delete_by_user(user_id).await?;
+
+        Ok(updated.into())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::application::services::auth_service::AuthService;
+use crate::application::services::totp_service::TotpService;
+use crate::domain::entities::mfa::{ConfirmTotpDto, RecoveryCodesResponse};
+use crate::domain::repositories::mfa_repository::{DynMfaRepository, MfaRepositoryError};
+use chrono::Utc;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Quantidade de códigos de recuperação gerados na ativação
+pub const RECOVERY_CODE_COUNT: usize = 10;
+
+#[derive(Debug, Error)]
+pub enum ConfirmTotpError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Two-factor enrollment not started")]
+    NotEnrolled,
+
+    #[error("Two-factor authentication is already enabled")]
+    AlreadyEnabled,
+
+    #[error("Invalid verification code")]
+    InvalidCode,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<MfaRepositoryError> for ConfirmTotpError {
+    fn from(err: MfaRepositoryError) -> Self {
+        ConfirmTotpError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ConfirmTotpError> for ApplicationError {
+    fn from(err: ConfirmTotpError) -> Self {
+        match err {
+            ConfirmTotpError::ValidationError(failure) => failure.into(),
+            ConfirmTotpError::NotEnrolled => {
+                Self::new(ErrorKind::NotFound, "mfa_not_enrolled", "Two-factor enrollment not started")
+            }
+            ConfirmTotpError::AlreadyEnabled => Self::new(
+                ErrorKind::Conflict,
+                "mfa_already_enabled",
+                "Two-factor authentication is already enabled",
+            ),
+            ConfirmTotpError::InvalidCode => {
+                Self::new(ErrorKind::BadRequest, "invalid_mfa_code", "Invalid verification code")
+            }
+            ConfirmTotpError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Ativa o 2FA após o primeiro código válido e emite os códigos de recuperação
+pub struct ConfirmTotpUseCase {
+    mfa_repository: DynMfaRepository,
+    totp_service: Arc<TotpService>,
+}
+
+impl ConfirmTotpUseCase {
+    pub fn new(mfa_repository: DynMfaRepository, totp_service: Arc<TotpService>) -> Self {
+        Self {
+            mfa_repository,
+            totp_service,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, dto: ConfirmTotpDto) -> Result<RecoveryCodesResponse, ConfirmTotpError> {
+        dto.validate()
+            .map_err(|e| ConfirmTotpError::ValidationError(e.into()))?;
+
+        let mut mfa = self
+            .mfa_repository
+            .find_by_user(user_id)
+            .await?
+            .ok_or(ConfirmTotpError::NotEnrolled)?;
+
+        if mfa.enabled {
+            return Err(ConfirmTotpError::AlreadyEnabled);
+        }
+
+        let step = self
+            .totp_service
+            .verify(&mfa.secret, &dto.code, Utc::now().timestamp(), None)
+            .ok_or(ConfirmTotpError::InvalidCode)?;
+
+        let recovery_codes = self.totp_service.generate_recovery_codes(RECOVERY_CODE_COUNT);
+        let hashes = recovery_codes
+            .iter()
+            .map(|code| AuthService::hash_token(&TotpService::normalize_recovery_code(code)))
+            .collect();
+
+        self.mfa_repository.replace_recovery_codes(user_id, hashes).await?;
+
+        mfa.confirm(step);
+        self.mfa_repository.save(mfa).await?;
+
+        Ok(RecoveryCodesResponse { recovery_codes })
+    }
+}
@@ -0,0 +1,115 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::application::services::webhook_target_policy::WebhookTargetPolicy;
+use crate::domain::entities::alert::{AlertChannel, AlertRule, AlertRuleDto, AlertRuleResponse};
+use crate::domain::repositories::alert_rule_repository::{AlertRuleRepositoryError, DynAlertRuleRepository};
+use crate::domain::repositories::article_category_repository::{
+    ArticleCategoryRepositoryError, DynArticleCategoryRepository,
+};
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum CreateAlertRuleError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Category not found")]
+    CategoryNotFound,
+
+    #[error("Feed not found")]
+    FeedNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<AlertRuleRepositoryError> for CreateAlertRuleError {
+    fn from(err: AlertRuleRepositoryError) -> Self {
+        match err {
+            AlertRuleRepositoryError::FeedNotFound => CreateAlertRuleError::FeedNotFound,
+            AlertRuleRepositoryError::DatabaseError(msg) => CreateAlertRuleError::RepositoryError(msg),
+        }
+    }
+}
+
+impl From<ArticleCategoryRepositoryError> for CreateAlertRuleError {
+    fn from(err: ArticleCategoryRepositoryError) -> Self {
+        CreateAlertRuleError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<CreateAlertRuleError> for ApplicationError {
+    fn from(err: CreateAlertRuleError) -> Self {
+        match err {
+            CreateAlertRuleError::ValidationError(failure) => failure.into(),
+            CreateAlertRuleError::CategoryNotFound => {
+                Self::new(ErrorKind::NotFound, "category_not_found", "Category not found")
+            }
+            CreateAlertRuleError::FeedNotFound => Self::new(ErrorKind::NotFound, "feed_not_found", "Feed not found"),
+            CreateAlertRuleError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Verifica o que o validator não cobre: ao menos um critério e a URL exigida pelo canal `webhook`,
+/// que precisa apontar para um destino aceito pela política
+pub(crate) fn check_rule(rule: &AlertRule, webhook_targets: WebhookTargetPolicy) -> Result<(), String> {
+    if rule.name.is_empty() {
+        return Err("Name is required".to_string());
+    }
+    if !rule.has_criteria() {
+        return Err("At least one of query, category_id, feed_id or min_rate is required".to_string());
+    }
+    if rule.query.as_deref().is_some_and(|query| !query.chars().any(char::is_alphanumeric)) {
+        return Err("Query must contain at least one word".to_string());
+    }
+
+    match rule.webhook_url.as_deref() {
+        Some(url) => webhook_targets.check_url(url).map(|_| ()),
+        None if rule.channels.contains(&AlertChannel::Webhook) => {
+            Err("Webhook URL is required for the webhook channel".to_string())
+        }
+        None => Ok(()),
+    }
+}
+
+/// Cria uma regra de alerta; as notificações sempre chegam à caixa de entrada e,
+/// conforme `channels`, também por email ou webhook
+pub struct CreateAlertRuleUseCase {
+    alert_rule_repository: DynAlertRuleRepository,
+    category_repository: DynArticleCategoryRepository,
+    webhook_targets: WebhookTargetPolicy,
+}
+
+impl CreateAlertRuleUseCase {
+    pub fn new(
+        alert_rule_repository: DynAlertRuleRepository,
+        category_repository: DynArticleCategoryRepository,
+        webhook_targets: WebhookTargetPolicy,
+    ) -> Self {
+        Self {
+            alert_rule_repository,
+            category_repository,
+            webhook_targets,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, dto: AlertRuleDto) -> Result<AlertRuleResponse, CreateAlertRuleError> {
+        dto.validate()
+            .map_err(|e| CreateAlertRuleError::ValidationError(e.into()))?;
+
+        let rule = AlertRule::new(user_id, dto);
+        check_rule(&rule, self.webhook_targets).map_err(|e| CreateAlertRuleError::ValidationError(e.into()))?;
+
+        if let Some(category_id) = rule.category_id {
+            self.category_repository
+                .find_by_id(category_id)
+                .await?
+                .ok_or(CreateAlertRuleError::CategoryNotFound)?;
+        }
+
+        let rule = self.alert_rule_repository.create(rule).await?;
+        Ok(rule.into())
+    }
+}
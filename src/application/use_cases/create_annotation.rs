@@ -0,0 +1,95 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::domain::entities::annotation::{Annotation, AnnotationResponse, CreateAnnotationDto};
+use crate::domain::repositories::annotation_repository::{AnnotationRepositoryError, DynAnnotationRepository};
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum CreateAnnotationError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Article not found")]
+    ArticleNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for CreateAnnotationError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        CreateAnnotationError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AnnotationRepositoryError> for CreateAnnotationError {
+    fn from(err: AnnotationRepositoryError) -> Self {
+        match err {
+            AnnotationRepositoryError::ArticleNotFound => CreateAnnotationError::ArticleNotFound,
+            AnnotationRepositoryError::DatabaseError(msg) => CreateAnnotationError::RepositoryError(msg),
+        }
+    }
+}
+
+impl From<CreateAnnotationError> for ApplicationError {
+    fn from(err: CreateAnnotationError) -> Self {
+        match err {
+            CreateAnnotationError::ValidationError(failure) => failure.into(),
+            CreateAnnotationError::ArticleNotFound => {
+                Self::new(ErrorKind::NotFound, "article_not_found", "Article not found")
+            }
+            CreateAnnotationError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Cria um destaque e/ou uma nota em um artigo.
+/// O trecho precisa existir no conteúdo; a posição é corrigida e o contexto completado pelo servidor
+pub struct CreateAnnotationUseCase {
+    article_repository: DynArticleRepository,
+    annotation_repository: DynAnnotationRepository,
+}
+
+impl CreateAnnotationUseCase {
+    pub fn new(article_repository: DynArticleRepository, annotation_repository: DynAnnotationRepository) -> Self {
+        Self {
+            article_repository,
+            annotation_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        article_id: Uuid,
+        dto: CreateAnnotationDto,
+    ) -> Result<AnnotationResponse, CreateAnnotationError> {
+        dto.validate()
+            .map_err(|e| CreateAnnotationError::ValidationError(e.into()))?;
+
+        let article = self
+            .article_repository
+            .find_by_id(article_id)
+            .await?
+            .ok_or(CreateAnnotationError::ArticleNotFound)?;
+
+        let anchor = match dto.anchor {
+            Some(anchor) => Some(anchor.anchored_in(&article.content).ok_or_else(|| {
+                CreateAnnotationError::ValidationError("Quote not found in article content".into())
+            })?),
+            None => None,
+        };
+
+        let annotation = Annotation::new(user_id, article_id, anchor, dto.note);
+        if annotation.is_empty() {
+            return Err(CreateAnnotationError::ValidationError(
+                "An annotation needs a highlighted quote or a note".into(),
+            ));
+        }
+
+        let created = self.annotation_repository.create(annotation).await?;
+        Ok(AnnotationResponse::resolve(created, &article.content))
+    }
+}
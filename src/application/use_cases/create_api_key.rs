@@ -0,0 +1,71 @@
+use crate::application::errors::{ApplicationError, ValidationFailure};
+use crate::application::services::api_key_service::ApiKeyService;
+use crate::domain::entities::api_key::{ApiKey, CreateApiKeyDto, CreatedApiKeyResponse};
+use crate::domain::repositories::api_key_repository::{ApiKeyRepositoryError, DynApiKeyRepository};
+use chrono::{Duration, Utc};
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum CreateApiKeyError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ApiKeyRepositoryError> for CreateApiKeyError {
+    fn from(err: ApiKeyRepositoryError) -> Self {
+        CreateApiKeyError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<CreateApiKeyError> for ApplicationError {
+    fn from(err: CreateApiKeyError) -> Self {
+        match err {
+            CreateApiKeyError::ValidationError(failure) => failure.into(),
+            CreateApiKeyError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Cria uma chave de API pessoal; a chave completa só é retornada aqui
+pub struct CreateApiKeyUseCase {
+    api_key_repository: DynApiKeyRepository,
+}
+
+impl CreateApiKeyUseCase {
+    pub fn new(api_key_repository: DynApiKeyRepository) -> Self {
+        Self { api_key_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, dto: CreateApiKeyDto) -> Result<CreatedApiKeyResponse, CreateApiKeyError> {
+        dto.validate()
+            .map_err(|e| CreateApiKeyError::ValidationError(e.into()))?;
+
+        let mut scopes = dto.scopes;
+        scopes.sort_by_key(|scope| scope.as_str());
+        scopes.dedup();
+
+        let generated = ApiKeyService::generate();
+        let expires_at = dto.expires_in_days.map(|days| Utc::now() + Duration::days(days));
+
+        let api_key = ApiKey::new(
+            user_id,
+            dto.name.trim().to_string(),
+            generated.prefix,
+            generated.secret_hash,
+            scopes,
+            expires_at,
+        );
+
+        let api_key = self.api_key_repository.create(api_key).await?;
+
+        Ok(CreatedApiKeyResponse {
+            api_key: api_key.into(),
+            key: generated.key,
+        })
+    }
+}
@@ -1,19 +1,31 @@
+use crate::application::services::alert_service::AlertService;
 use crate::domain::entities::article::{Article, ArticleResponse, CreateArticleDto};
 use crate::domain::repositories::article_repository::{ArticleRepository, ArticleRepositoryError};
 use std::sync::Arc;
 
 pub struct CreateArticleUseCase {
     article_repository: Arc<dyn ArticleRepository>,
+    alert_service: Arc<AlertService>,
 }
 
 impl CreateArticleUseCase {
-    pub fn new(article_repository: Arc<dyn ArticleRepository>) -> Self {
-        Self { article_repository }
+    pub fn new(article_repository: Arc<dyn ArticleRepository>, alert_service: Arc<AlertService>) -> Self {
+        Self {
+            article_repository,
+            alert_service,
+        }
     }
 
     pub async fn execute(&self, dto: CreateArticleDto) -> Result<ArticleResponse, ArticleRepositoryError> {
         let article = Article::new(dto);
-        let created = self.article_repository.create(article).await?;
+        // O evento `article.created` vai para o outbox na mesma transação
+        let created = self.article_repository.create_with_event(article).await?;
+
+        // O artigo já foi gravado: uma falha nos alertas não deve desfazer a ingestão
+        if let Err(e) = self.alert_service.evaluate(&created).await {
+            log::error!("Failed to evaluate alerts for article {}: {:?}", created.id, e);
+        }
+
         Ok(ArticleResponse::from(created))
     }
 }
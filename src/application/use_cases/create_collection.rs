@@ -0,0 +1,66 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::domain::entities::collection::{Collection, CollectionResponse, CreateCollectionDto};
+use crate::domain::repositories::collection_repository::{CollectionRepositoryError, DynCollectionRepository};
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum CreateCollectionError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("A collection with this name already exists")]
+    NameTaken,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<CollectionRepositoryError> for CreateCollectionError {
+    fn from(err: CollectionRepositoryError) -> Self {
+        match err {
+            CollectionRepositoryError::NameTaken => CreateCollectionError::NameTaken,
+            CollectionRepositoryError::DatabaseError(msg) => CreateCollectionError::RepositoryError(msg),
+        }
+    }
+}
+
+impl From<CreateCollectionError> for ApplicationError {
+    fn from(err: CreateCollectionError) -> Self {
+        match err {
+            CreateCollectionError::ValidationError(failure) => failure.into(),
+            e @ CreateCollectionError::NameTaken => {
+                Self::new(ErrorKind::Conflict, "collection_name_taken", e.to_string())
+            }
+            CreateCollectionError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Cria uma coleção privada de artigos salvos
+pub struct CreateCollectionUseCase {
+    collection_repository: DynCollectionRepository,
+}
+
+impl CreateCollectionUseCase {
+    pub fn new(collection_repository: DynCollectionRepository) -> Self {
+        Self { collection_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, dto: CreateCollectionDto) -> Result<CollectionResponse, CreateCollectionError> {
+        dto.validate()
+            .map_err(|e| CreateCollectionError::ValidationError(e.into()))?;
+
+        if dto.name.trim().is_empty() {
+            return Err(CreateCollectionError::ValidationError("Name is required".into()));
+        }
+
+        let collection = self
+            .collection_repository
+            .create(Collection::new(user_id, &dto.name))
+            .await?;
+
+        Ok(collection.into())
+    }
+}
@@ -0,0 +1,121 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::domain::entities::smart_folder::{CreateSmartFolderDto, SmartFolder, SmartFolderCriteria, SmartFolderResponse};
+use crate::domain::repositories::article_category_repository::{
+    ArticleCategoryRepositoryError, DynArticleCategoryRepository,
+};
+use crate::domain::repositories::smart_folder_repository::{DynSmartFolderRepository, SmartFolderRepositoryError};
+use crate::domain::repositories::tag_repository::{DynTagRepository, TagRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum CreateSmartFolderError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("A smart folder with this name already exists")]
+    NameTaken,
+
+    #[error("Tag not found")]
+    TagNotFound,
+
+    #[error("Category not found")]
+    CategoryNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<SmartFolderRepositoryError> for CreateSmartFolderError {
+    fn from(err: SmartFolderRepositoryError) -> Self {
+        match err {
+            SmartFolderRepositoryError::NameTaken => CreateSmartFolderError::NameTaken,
+            SmartFolderRepositoryError::DatabaseError(msg) => CreateSmartFolderError::RepositoryError(msg),
+        }
+    }
+}
+
+impl From<TagRepositoryError> for CreateSmartFolderError {
+    fn from(err: TagRepositoryError) -> Self {
+        CreateSmartFolderError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ArticleCategoryRepositoryError> for CreateSmartFolderError {
+    fn from(err: ArticleCategoryRepositoryError) -> Self {
+        CreateSmartFolderError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<CreateSmartFolderError> for ApplicationError {
+    fn from(err: CreateSmartFolderError) -> Self {
+        match err {
+            CreateSmartFolderError::ValidationError(failure) => failure.into(),
+            e @ CreateSmartFolderError::NameTaken => {
+                Self::new(ErrorKind::Conflict, "smart_folder_name_taken", e.to_string())
+            }
+            CreateSmartFolderError::TagNotFound => Self::new(ErrorKind::NotFound, "tag_not_found", "Tag not found"),
+            CreateSmartFolderError::CategoryNotFound => {
+                Self::new(ErrorKind::NotFound, "category_not_found", "Category not found")
+            }
+            CreateSmartFolderError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Salva uma busca com nome como pasta inteligente
+pub struct CreateSmartFolderUseCase {
+    smart_folder_repository: DynSmartFolderRepository,
+    tag_repository: DynTagRepository,
+    category_repository: DynArticleCategoryRepository,
+}
+
+impl CreateSmartFolderUseCase {
+    pub fn new(
+        smart_folder_repository: DynSmartFolderRepository,
+        tag_repository: DynTagRepository,
+        category_repository: DynArticleCategoryRepository,
+    ) -> Self {
+        Self {
+            smart_folder_repository,
+            tag_repository,
+            category_repository,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, dto: CreateSmartFolderDto) -> Result<SmartFolderResponse, CreateSmartFolderError> {
+        dto.validate()
+            .map_err(|e| CreateSmartFolderError::ValidationError(e.into()))?;
+
+        if dto.name.trim().is_empty() {
+            return Err(CreateSmartFolderError::ValidationError("Name is required".into()));
+        }
+
+        self.check_references(user_id, &dto.criteria).await?;
+
+        let folder = self
+            .smart_folder_repository
+            .create(SmartFolder::new(user_id, &dto.name, dto.criteria))
+            .await?;
+
+        Ok(folder.into())
+    }
+
+    /// A tag precisa ser do usuário e a categoria precisa existir
+    async fn check_references(&self, user_id: Uuid, criteria: &SmartFolderCriteria) -> Result<(), CreateSmartFolderError> {
+        if let Some(tag_id) = criteria.tag_id {
+            self.tag_repository
+                .find_by_id(user_id, tag_id)
+                .await?
+                .ok_or(CreateSmartFolderError::TagNotFound)?;
+        }
+        if let Some(category_id) = criteria.category_id {
+            self.category_repository
+                .find_by_id(category_id)
+                .await?
+                .ok_or(CreateSmartFolderError::CategoryNotFound)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::domain::entities::tag::{CreateTagDto, Tag, TagResponse};
+use crate::domain::repositories::tag_repository::{DynTagRepository, TagRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum CreateTagError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("A tag with this name already exists")]
+    NameTaken,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<TagRepositoryError> for CreateTagError {
+    fn from(err: TagRepositoryError) -> Self {
+        match err {
+            TagRepositoryError::NameTaken => CreateTagError::NameTaken,
+            _ => CreateTagError::RepositoryError(err.to_string()),
+        }
+    }
+}
+
+impl From<CreateTagError> for ApplicationError {
+    fn from(err: CreateTagError) -> Self {
+        match err {
+            CreateTagError::ValidationError(failure) => failure.into(),
+            e @ CreateTagError::NameTaken => Self::new(ErrorKind::Conflict, "tag_name_taken", e.to_string()),
+            CreateTagError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Cria uma tag do usuário
+pub struct CreateTagUseCase {
+    tag_repository: DynTagRepository,
+}
+
+impl CreateTagUseCase {
+    pub fn new(tag_repository: DynTagRepository) -> Self {
+        Self { tag_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, dto: CreateTagDto) -> Result<TagResponse, CreateTagError> {
+        dto.validate()
+            .map_err(|e| CreateTagError::ValidationError(e.into()))?;
+
+        if Tag::normalize_name(&dto.name).is_empty() {
+            return Err(CreateTagError::ValidationError("Name is required".into()));
+        }
+
+        let tag = self.tag_repository.create(Tag::new(user_id, &dto.name)).await?;
+        Ok(tag.into())
+    }
+}
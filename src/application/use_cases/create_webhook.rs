@@ -0,0 +1,76 @@
+use crate::application::errors::{ApplicationError, ValidationFailure};
+use crate::application::services::webhook_target_policy::WebhookTargetPolicy;
+use crate::domain::entities::webhook::{
+    WebhookEventType, WebhookSubscription, WebhookSubscriptionDto, WebhookSubscriptionResponse,
+};
+use crate::domain::repositories::webhook_repository::{DynWebhookRepository, WebhookRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum CreateWebhookError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<WebhookRepositoryError> for CreateWebhookError {
+    fn from(err: WebhookRepositoryError) -> Self {
+        CreateWebhookError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<CreateWebhookError> for ApplicationError {
+    fn from(err: CreateWebhookError) -> Self {
+        match err {
+            CreateWebhookError::ValidationError(failure) => failure.into(),
+            CreateWebhookError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Verifica o que o validator não cobre: nome sem espaços apenas, eventos assináveis e uma URL
+/// http(s) que aponte para um destino aceito pela política
+pub(crate) fn check_subscription(subscription: &WebhookSubscription, webhook_targets: WebhookTargetPolicy) -> Result<(), String> {
+    if subscription.name.is_empty() {
+        return Err("Name is required".to_string());
+    }
+    if subscription.events.contains(&WebhookEventType::Test) {
+        return Err("Event webhook.test cannot be subscribed".to_string());
+    }
+    webhook_targets.check_url(&subscription.url).map(|_| ())
+}
+
+/// Cadastra um webhook; o segredo da assinatura HMAC é gerado aqui e só aparece nesta resposta
+pub struct CreateWebhookUseCase {
+    webhook_repository: DynWebhookRepository,
+    webhook_targets: WebhookTargetPolicy,
+}
+
+impl CreateWebhookUseCase {
+    pub fn new(webhook_repository: DynWebhookRepository, webhook_targets: WebhookTargetPolicy) -> Self {
+        Self {
+            webhook_repository,
+            webhook_targets,
+        }
+    }
+
+    pub async fn execute(&self, admin_id: Uuid, dto: WebhookSubscriptionDto) -> Result<WebhookSubscriptionResponse, CreateWebhookError> {
+        dto.validate()
+            .map_err(|e| CreateWebhookError::ValidationError(e.into()))?;
+
+        let subscription = WebhookSubscription::new(admin_id, dto);
+        check_subscription(&subscription, self.webhook_targets).map_err(|e| CreateWebhookError::ValidationError(e.into()))?;
+
+        let created = self.webhook_repository.create(subscription).await?;
+        let secret = created.secret.clone();
+
+        Ok(WebhookSubscriptionResponse {
+            secret: Some(secret),
+            ..WebhookSubscriptionResponse::from(created)
+        })
+    }
+}
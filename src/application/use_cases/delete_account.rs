@@ -0,0 +1,100 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::application::services::auth_service::{AuthError, AuthService};
+use crate::application::services::session_service::SessionService;
+use crate::domain::entities::user::DeleteAccountDto;
+use crate::domain::repositories::session_repository::SessionRepositoryError;
+use crate::domain::repositories::user_repository::{DynUserRepository, UserRepositoryError};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum DeleteAccountError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Password is incorrect")]
+    InvalidPassword,
+
+    #[error("User not found")]
+    UserNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<UserRepositoryError> for DeleteAccountError {
+    fn from(err: UserRepositoryError) -> Self {
+        match err {
+            UserRepositoryError::NotFound => DeleteAccountError::UserNotFound,
+            _ => DeleteAccountError::RepositoryError(err.to_string()),
+        }
+    }
+}
+
+impl From<SessionRepositoryError> for DeleteAccountError {
+    fn from(err: SessionRepositoryError) -> Self {
+        DeleteAccountError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuthError> for DeleteAccountError {
+    fn from(_: AuthError) -> Self {
+        DeleteAccountError::InvalidPassword
+    }
+}
+
+impl From<DeleteAccountError> for ApplicationError {
+    fn from(err: DeleteAccountError) -> Self {
+        match err {
+            DeleteAccountError::ValidationError(failure) => failure.into(),
+            DeleteAccountError::InvalidPassword => {
+                Self::new(ErrorKind::Forbidden, "invalid_password", "Password is incorrect")
+            }
+            DeleteAccountError::UserNotFound => Self::new(ErrorKind::NotFound, "user_not_found", "User not found"),
+            DeleteAccountError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Remove a conta do usuário após confirmar a senha, revogando todas as sessões
+pub struct DeleteAccountUseCase {
+    user_repository: DynUserRepository,
+    auth_service: Arc<AuthService>,
+    session_service: Arc<SessionService>,
+}
+
+impl DeleteAccountUseCase {
+    pub fn new(
+        user_repository: DynUserRepository,
+        auth_service: Arc<AuthService>,
+        session_service: Arc<SessionService>,
+    ) -> Self {
+        Self {
+            user_repository,
+            auth_service,
+            session_service,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, dto: DeleteAccountDto) -> Result<(), DeleteAccountError> {
+        dto.validate()
+            .map_err(|e| DeleteAccountError::ValidationError(e.into()))?;
+
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(DeleteAccountError::UserNotFound)?;
+
+        if !self.auth_service.verify_password(&dto.password, &user.password_hash)? {
+            return Err(DeleteAccountError::InvalidPassword);
+        }
+
+        self.session_service.revoke_all(user.id, None).await?;
+        self.user_repository.delete(user.id).await?;
+
+        Ok(())
+    }
+}
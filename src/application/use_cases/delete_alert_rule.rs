@@ -0,0 +1,48 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::repositories::alert_rule_repository::{AlertRuleRepositoryError, DynAlertRuleRepository};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum DeleteAlertRuleError {
+    #[error("Alert rule not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<AlertRuleRepositoryError> for DeleteAlertRuleError {
+    fn from(err: AlertRuleRepositoryError) -> Self {
+        DeleteAlertRuleError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<DeleteAlertRuleError> for ApplicationError {
+    fn from(err: DeleteAlertRuleError) -> Self {
+        match err {
+            DeleteAlertRuleError::NotFound => {
+                Self::new(ErrorKind::NotFound, "alert_rule_not_found", "Alert rule not found")
+            }
+            DeleteAlertRuleError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Remove uma regra de alerta junto com as notificações que ela gerou
+pub struct DeleteAlertRuleUseCase {
+    alert_rule_repository: DynAlertRuleRepository,
+}
+
+impl DeleteAlertRuleUseCase {
+    pub fn new(alert_rule_repository: DynAlertRuleRepository) -> Self {
+        Self { alert_rule_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, rule_id: Uuid) -> Result<(), DeleteAlertRuleError> {
+        if !self.alert_rule_repository.delete(user_id, rule_id).await? {
+            return Err(DeleteAlertRuleError::NotFound);
+        }
+        Ok(())
+    }
+}
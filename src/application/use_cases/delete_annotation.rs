@@ -0,0 +1,56 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::repositories::annotation_repository::{AnnotationRepositoryError, DynAnnotationRepository};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum DeleteAnnotationError {
+    #[error("Annotation not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<AnnotationRepositoryError> for DeleteAnnotationError {
+    fn from(err: AnnotationRepositoryError) -> Self {
+        DeleteAnnotationError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<DeleteAnnotationError> for ApplicationError {
+    fn from(err: DeleteAnnotationError) -> Self {
+        match err {
+            DeleteAnnotationError::NotFound => {
+                Self::new(ErrorKind::NotFound, "annotation_not_found", "Annotation not found")
+            }
+            DeleteAnnotationError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Remove uma anotação do usuário
+pub struct DeleteAnnotationUseCase {
+    annotation_repository: DynAnnotationRepository,
+}
+
+impl DeleteAnnotationUseCase {
+    pub fn new(annotation_repository: DynAnnotationRepository) -> Self {
+        Self { annotation_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, article_id: Uuid, annotation_id: Uuid) -> Result<(), DeleteAnnotationError> {
+        // A anotação precisa pertencer ao artigo da URL
+        let belongs_to_article = self
+            .annotation_repository
+            .find_by_id(user_id, annotation_id)
+            .await?
+            .is_some_and(|annotation| annotation.article_id == article_id);
+
+        if !belongs_to_article || !self.annotation_repository.delete(user_id, annotation_id).await? {
+            return Err(DeleteAnnotationError::NotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -12,6 +12,7 @@ impl DeleteArticleUseCase {
     }
 
     pub async fn execute(&self, id: Uuid) -> Result<(), ArticleRepositoryError> {
-        self.article_repository.delete(id).await
+        // O evento `article.deleted` leva o artigo como estava antes da remoção
+        self.article_repository.delete_with_event(id).await
     }
 }
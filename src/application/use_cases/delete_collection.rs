@@ -0,0 +1,64 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::repositories::collection_repository::{CollectionRepositoryError, DynCollectionRepository};
+use crate::domain::repositories::saved_article_repository::{DynSavedArticleRepository, SavedArticleRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum DeleteCollectionError {
+    #[error("Collection not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<CollectionRepositoryError> for DeleteCollectionError {
+    fn from(err: CollectionRepositoryError) -> Self {
+        DeleteCollectionError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SavedArticleRepositoryError> for DeleteCollectionError {
+    fn from(err: SavedArticleRepositoryError) -> Self {
+        DeleteCollectionError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<DeleteCollectionError> for ApplicationError {
+    fn from(err: DeleteCollectionError) -> Self {
+        match err {
+            DeleteCollectionError::NotFound => {
+                Self::new(ErrorKind::NotFound, "collection_not_found", "Collection not found")
+            }
+            DeleteCollectionError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Remove uma coleção; seus itens continuam salvos, fora de qualquer coleção
+pub struct DeleteCollectionUseCase {
+    collection_repository: DynCollectionRepository,
+    saved_article_repository: DynSavedArticleRepository,
+}
+
+impl DeleteCollectionUseCase {
+    pub fn new(collection_repository: DynCollectionRepository, saved_article_repository: DynSavedArticleRepository) -> Self {
+        Self {
+            collection_repository,
+            saved_article_repository,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, collection_id: Uuid) -> Result<(), DeleteCollectionError> {
+        self.collection_repository
+            .find_by_id(user_id, collection_id)
+            .await?
+            .ok_or(DeleteCollectionError::NotFound)?;
+
+        self.saved_article_repository.detach_collection(collection_id).await?;
+        self.collection_repository.delete(user_id, collection_id).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::repositories::digest_repository::{DigestRepositoryError, DynDigestRepository};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum DeleteDigestSettingsError {
+    #[error("Digest not configured")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<DigestRepositoryError> for DeleteDigestSettingsError {
+    fn from(err: DigestRepositoryError) -> Self {
+        DeleteDigestSettingsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<DeleteDigestSettingsError> for ApplicationError {
+    fn from(err: DeleteDigestSettingsError) -> Self {
+        match err {
+            DeleteDigestSettingsError::NotFound => {
+                Self::new(ErrorKind::NotFound, "digest_not_configured", "Digest not configured")
+            }
+            DeleteDigestSettingsError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Cancela o resumo por email; o histórico de envios é mantido
+pub struct DeleteDigestSettingsUseCase {
+    digest_repository: DynDigestRepository,
+}
+
+impl DeleteDigestSettingsUseCase {
+    pub fn new(digest_repository: DynDigestRepository) -> Self {
+        Self { digest_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<(), DeleteDigestSettingsError> {
+        if !self.digest_repository.delete_settings(user_id).await? {
+            return Err(DeleteDigestSettingsError::NotFound);
+        }
+        Ok(())
+    }
+}
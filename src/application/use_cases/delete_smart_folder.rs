@@ -0,0 +1,48 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::repositories::smart_folder_repository::{DynSmartFolderRepository, SmartFolderRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum DeleteSmartFolderError {
+    #[error("Smart folder not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<SmartFolderRepositoryError> for DeleteSmartFolderError {
+    fn from(err: SmartFolderRepositoryError) -> Self {
+        DeleteSmartFolderError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<DeleteSmartFolderError> for ApplicationError {
+    fn from(err: DeleteSmartFolderError) -> Self {
+        match err {
+            DeleteSmartFolderError::NotFound => {
+                Self::new(ErrorKind::NotFound, "smart_folder_not_found", "Smart folder not found")
+            }
+            DeleteSmartFolderError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Remove uma pasta inteligente; os artigos não são afetados
+pub struct DeleteSmartFolderUseCase {
+    smart_folder_repository: DynSmartFolderRepository,
+}
+
+impl DeleteSmartFolderUseCase {
+    pub fn new(smart_folder_repository: DynSmartFolderRepository) -> Self {
+        Self { smart_folder_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, folder_id: Uuid) -> Result<(), DeleteSmartFolderError> {
+        if !self.smart_folder_repository.delete(user_id, folder_id).await? {
+            return Err(DeleteSmartFolderError::NotFound);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::repositories::tag_repository::{DynTagRepository, TagRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum DeleteTagError {
+    #[error("Tag not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<TagRepositoryError> for DeleteTagError {
+    fn from(err: TagRepositoryError) -> Self {
+        DeleteTagError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<DeleteTagError> for ApplicationError {
+    fn from(err: DeleteTagError) -> Self {
+        match err {
+            DeleteTagError::NotFound => Self::new(ErrorKind::NotFound, "tag_not_found", "Tag not found"),
+            DeleteTagError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Remove uma tag do usuário; os artigos continuam, apenas sem a marcação
+pub struct DeleteTagUseCase {
+    tag_repository: DynTagRepository,
+}
+
+impl DeleteTagUseCase {
+    pub fn new(tag_repository: DynTagRepository) -> Self {
+        Self { tag_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, tag_id: Uuid) -> Result<(), DeleteTagError> {
+        if !self.tag_repository.delete(user_id, tag_id).await? {
+            return Err(DeleteTagError::NotFound);
+        }
+
+        Ok(())
+    }
+}
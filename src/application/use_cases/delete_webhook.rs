@@ -0,0 +1,46 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::repositories::webhook_repository::{DynWebhookRepository, WebhookRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum DeleteWebhookError {
+    #[error("Webhook not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<WebhookRepositoryError> for DeleteWebhookError {
+    fn from(err: WebhookRepositoryError) -> Self {
+        DeleteWebhookError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<DeleteWebhookError> for ApplicationError {
+    fn from(err: DeleteWebhookError) -> Self {
+        match err {
+            DeleteWebhookError::NotFound => Self::new(ErrorKind::NotFound, "webhook_not_found", "Webhook not found"),
+            DeleteWebhookError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Remove um webhook junto com o histórico e as entregas pendentes
+pub struct DeleteWebhookUseCase {
+    webhook_repository: DynWebhookRepository,
+}
+
+impl DeleteWebhookUseCase {
+    pub fn new(webhook_repository: DynWebhookRepository) -> Self {
+        Self { webhook_repository }
+    }
+
+    pub async fn execute(&self, id: Uuid) -> Result<(), DeleteWebhookError> {
+        if !self.webhook_repository.delete(id).await? {
+            return Err(DeleteWebhookError::NotFound);
+        }
+        Ok(())
+    }
+}
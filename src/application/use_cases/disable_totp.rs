@@ -0,0 +1,106 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::application::services::auth_service::{AuthError, AuthService};
+use crate::domain::entities::mfa::DisableTotpDto;
+use crate::domain::repositories::mfa_repository::{DynMfaRepository, MfaRepositoryError};
+use crate::domain::repositories::user_repository::{DynUserRepository, UserRepositoryError};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum DisableTotpError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Password is incorrect")]
+    InvalidPassword,
+
+    #[error("Two-factor authentication is not enabled")]
+    NotEnabled,
+
+    #[error("User not found")]
+    UserNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<UserRepositoryError> for DisableTotpError {
+    fn from(err: UserRepositoryError) -> Self {
+        DisableTotpError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<MfaRepositoryError> for DisableTotpError {
+    fn from(err: MfaRepositoryError) -> Self {
+        DisableTotpError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuthError> for DisableTotpError {
+    fn from(_: AuthError) -> Self {
+        DisableTotpError::InvalidPassword
+    }
+}
+
+impl From<DisableTotpError> for ApplicationError {
+    fn from(err: DisableTotpError) -> Self {
+        match err {
+            DisableTotpError::ValidationError(failure) => failure.into(),
+            DisableTotpError::InvalidPassword => {
+                Self::new(ErrorKind::Forbidden, "invalid_password", "Password is incorrect")
+            }
+            DisableTotpError::NotEnabled => {
+                Self::new(ErrorKind::NotFound, "mfa_not_enabled", "Two-factor authentication is not enabled")
+            }
+            DisableTotpError::UserNotFound => Self::new(ErrorKind::NotFound, "user_not_found", "User not found"),
+            DisableTotpError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Desativa o 2FA, exigindo a senha atual
+pub struct DisableTotpUseCase {
+    user_repository: DynUserRepository,
+    mfa_repository: DynMfaRepository,
+    auth_service: Arc<AuthService>,
+}
+
+impl DisableTotpUseCase {
+    pub fn new(
+        user_repository: DynUserRepository,
+        mfa_repository: DynMfaRepository,
+        auth_service: Arc<AuthService>,
+    ) -> Self {
+        Self {
+            user_repository,
+            mfa_repository,
+            auth_service,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, dto: DisableTotpDto) -> Result<(), DisableTotpError> {
+        dto.validate()
+            .map_err(|e| DisableTotpError::ValidationError(e.into()))?;
+
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(DisableTotpError::UserNotFound)?;
+
+        if !self.auth_service.verify_password(&dto.password, &user.password_hash)? {
+            return Err(DisableTotpError::InvalidPassword);
+        }
+
+        // A pending (unconfirmed) enrollment can also be discarded through this endpoint
+        if self.mfa_repository.find_by_user(user_id).await?.is_none() {
+            return Err(DisableTotpError::NotEnabled);
+        }
+
+        self.mfa_repository.delete(user_id).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::application::services::totp_service::TotpService;
+use crate::domain::entities::mfa::{TotpEnrollmentResponse, UserMfa};
+use crate::domain::repositories::mfa_repository::{DynMfaRepository, MfaRepositoryError};
+use crate::domain::repositories::user_repository::{DynUserRepository, UserRepositoryError};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum EnrollTotpError {
+    #[error("Two-factor authentication is already enabled")]
+    AlreadyEnabled,
+
+    #[error("User not found")]
+    UserNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<UserRepositoryError> for EnrollTotpError {
+    fn from(err: UserRepositoryError) -> Self {
+        EnrollTotpError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<MfaRepositoryError> for EnrollTotpError {
+    fn from(err: MfaRepositoryError) -> Self {
+        EnrollTotpError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<EnrollTotpError> for ApplicationError {
+    fn from(err: EnrollTotpError) -> Self {
+        match err {
+            EnrollTotpError::AlreadyEnabled => Self::new(
+                ErrorKind::Conflict,
+                "mfa_already_enabled",
+                "Two-factor authentication is already enabled",
+            ),
+            EnrollTotpError::UserNotFound => Self::new(ErrorKind::NotFound, "user_not_found", "User not found"),
+            EnrollTotpError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Gera um novo segredo TOTP pendente de confirmação
+pub struct EnrollTotpUseCase {
+    user_repository: DynUserRepository,
+    mfa_repository: DynMfaRepository,
+    totp_service: Arc<TotpService>,
+}
+
+impl EnrollTotpUseCase {
+    pub fn new(
+        user_repository: DynUserRepository,
+        mfa_repository: DynMfaRepository,
+        totp_service: Arc<TotpService>,
+    ) -> Self {
+        Self {
+            user_repository,
+            mfa_repository,
+            totp_service,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<TotpEnrollmentResponse, EnrollTotpError> {
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(EnrollTotpError::UserNotFound)?;
+
+        // Re-enrolling must go through disable first so an active factor is never silently replaced
+        if let Some(existing) = self.mfa_repository.find_by_user(user_id).await? {
+            if existing.enabled {
+                return Err(EnrollTotpError::AlreadyEnabled);
+            }
+        }
+
+        let secret = self.totp_service.generate_secret();
+        let provisioning_uri = self.totp_service.provisioning_uri(&secret, &user.email);
+
+        self.mfa_repository
+            .save(UserMfa::new(user_id, secret.clone()))
+            .await?;
+
+        Ok(TotpEnrollmentResponse {
+            secret,
+            provisioning_uri,
+        })
+    }
+}
@@ -0,0 +1,78 @@
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::annotation::{Annotation, AnnotationResponse, ArticleAnnotations};
+use crate::domain::repositories::annotation_repository::{AnnotationRepositoryError, DynAnnotationRepository};
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ExportAnnotationsError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for ExportAnnotationsError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        ExportAnnotationsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AnnotationRepositoryError> for ExportAnnotationsError {
+    fn from(err: AnnotationRepositoryError) -> Self {
+        ExportAnnotationsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ExportAnnotationsError> for ApplicationError {
+    fn from(err: ExportAnnotationsError) -> Self {
+        let ExportAnnotationsError::RepositoryError(msg) = err;
+        Self::internal(msg)
+    }
+}
+
+/// Reúne todas as anotações do usuário agrupadas por artigo, na ordem em que os artigos
+/// foram anotados pela primeira vez; dentro de cada artigo, na ordem do texto
+pub struct ExportAnnotationsUseCase {
+    article_repository: DynArticleRepository,
+    annotation_repository: DynAnnotationRepository,
+}
+
+impl ExportAnnotationsUseCase {
+    pub fn new(article_repository: DynArticleRepository, annotation_repository: DynAnnotationRepository) -> Self {
+        Self {
+            article_repository,
+            annotation_repository,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<ArticleAnnotations>, ExportAnnotationsError> {
+        let mut groups: Vec<(Uuid, Vec<Annotation>)> = Vec::new();
+        for annotation in self.annotation_repository.list_by_user(user_id).await? {
+            match groups.iter_mut().find(|(article_id, _)| *article_id == annotation.article_id) {
+                Some((_, annotations)) => annotations.push(annotation),
+                None => groups.push((annotation.article_id, vec![annotation])),
+            }
+        }
+
+        let mut exports = Vec::with_capacity(groups.len());
+        for (article_id, mut annotations) in groups {
+            // As anotações são removidas junto com o artigo, mas o artigo pode sumir durante a exportação
+            let Some(article) = self.article_repository.find_by_id(article_id).await? else {
+                continue;
+            };
+
+            annotations.sort_by_key(|annotation| (annotation.anchor.as_ref().map(|anchor| anchor.start), annotation.created_at));
+            exports.push(ArticleAnnotations {
+                article_id,
+                title: article.title,
+                link: article.link,
+                annotations: annotations
+                    .into_iter()
+                    .map(|annotation| AnnotationResponse::resolve(annotation, &article.content))
+                    .collect(),
+            });
+        }
+
+        Ok(exports)
+    }
+}
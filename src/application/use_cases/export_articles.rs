@@ -0,0 +1,129 @@
+use crate::application::errors::ApplicationError;
+use crate::application::services::article_export_writer::ArticleExportWriter;
+use crate::domain::entities::article::ArticleListFilter;
+use crate::domain::entities::article_export::{ArticleExportCursor, ExportColumn, ExportFormat, EXPORT_CHUNK_SIZE};
+use crate::domain::repositories::article_category_repository::{ArticleCategoryRepositoryError, DynArticleCategoryRepository};
+use crate::domain::repositories::article_repository::{ArticleRepository, ArticleRepositoryError};
+use std::collections::HashMap;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ExportArticlesError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for ExportArticlesError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        ExportArticlesError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ArticleCategoryRepositoryError> for ExportArticlesError {
+    fn from(err: ArticleCategoryRepositoryError) -> Self {
+        ExportArticlesError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ExportArticlesError> for ApplicationError {
+    fn from(err: ExportArticlesError) -> Self {
+        let ExportArticlesError::RepositoryError(msg) = err;
+        Self::internal(msg)
+    }
+}
+
+/// Exporta os artigos de qualquer filtro da listagem em CSV ou NDJSON, lendo o banco em lotes
+pub struct ExportArticlesUseCase {
+    article_repository: Arc<dyn ArticleRepository>,
+    category_repository: DynArticleCategoryRepository,
+}
+
+impl ExportArticlesUseCase {
+    pub fn new(article_repository: Arc<dyn ArticleRepository>, category_repository: DynArticleCategoryRepository) -> Self {
+        Self {
+            article_repository,
+            category_repository,
+        }
+    }
+
+    /// Prepara a exportação; os artigos só são lidos conforme `ArticleExport::next_chunk` é chamado
+    pub async fn execute(
+        &self,
+        filter: ArticleListFilter,
+        format: ExportFormat,
+        columns: Vec<ExportColumn>,
+    ) -> Result<ArticleExport, ExportArticlesError> {
+        let category_names = if columns.contains(&ExportColumn::CategoryName) {
+            self.category_repository
+                .find_all()
+                .await?
+                .into_iter()
+                .map(|category| (category.id, category.name))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        Ok(ArticleExport {
+            article_repository: self.article_repository.clone(),
+            filter,
+            writer: ArticleExportWriter::new(format, columns),
+            category_names,
+            cursor: None,
+            header_written: false,
+            finished: false,
+        })
+    }
+}
+
+/// Exportação em andamento: cada chamada lê um lote do banco e devolve o trecho serializado
+pub struct ArticleExport {
+    article_repository: Arc<dyn ArticleRepository>,
+    filter: ArticleListFilter,
+    writer: ArticleExportWriter,
+    category_names: HashMap<Uuid, String>,
+    cursor: Option<ArticleExportCursor>,
+    header_written: bool,
+    finished: bool,
+}
+
+impl ArticleExport {
+    /// Próximo trecho do arquivo (o primeiro traz o cabeçalho); `None` quando não há mais artigos
+    pub async fn next_chunk(&mut self) -> Result<Option<String>, ExportArticlesError> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let articles = self
+            .article_repository
+            .export_chunk(&self.filter, self.cursor, EXPORT_CHUNK_SIZE)
+            .await
+            .inspect_err(|_| self.finished = true)?;
+
+        if (articles.len() as i64) < EXPORT_CHUNK_SIZE {
+            self.finished = true;
+        }
+        self.cursor = articles.last().map(ArticleExportCursor::after);
+
+        let mut chunk = if self.header_written {
+            String::new()
+        } else {
+            self.header_written = true;
+            self.writer.header()
+        };
+        for article in &articles {
+            let category_name = article
+                .category_id
+                .and_then(|id| self.category_names.get(&id))
+                .map(String::as_str);
+            chunk.push_str(&self.writer.row(article, category_name));
+        }
+
+        if chunk.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(chunk))
+    }
+}
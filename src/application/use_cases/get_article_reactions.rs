@@ -0,0 +1,75 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::reaction::ArticleReactionsResponse;
+use crate::domain::repositories::article_reaction_repository::{ArticleReactionRepositoryError, DynArticleReactionRepository};
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum GetArticleReactionsError {
+    #[error("Article not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for GetArticleReactionsError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        GetArticleReactionsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ArticleReactionRepositoryError> for GetArticleReactionsError {
+    fn from(err: ArticleReactionRepositoryError) -> Self {
+        GetArticleReactionsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<GetArticleReactionsError> for ApplicationError {
+    fn from(err: GetArticleReactionsError) -> Self {
+        match err {
+            GetArticleReactionsError::NotFound => {
+                Self::new(ErrorKind::NotFound, "article_not_found", "Article not found")
+            }
+            GetArticleReactionsError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Totais de reações de um artigo e, se houver um usuário identificado, as reações dele
+pub struct GetArticleReactionsUseCase {
+    article_repository: DynArticleRepository,
+    reaction_repository: DynArticleReactionRepository,
+}
+
+impl GetArticleReactionsUseCase {
+    pub fn new(article_repository: DynArticleRepository, reaction_repository: DynArticleReactionRepository) -> Self {
+        Self {
+            article_repository,
+            reaction_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        article_id: Uuid,
+        viewer_id: Option<Uuid>,
+    ) -> Result<ArticleReactionsResponse, GetArticleReactionsError> {
+        if self.article_repository.find_by_id(article_id).await?.is_none() {
+            return Err(GetArticleReactionsError::NotFound);
+        }
+
+        let counts = self.reaction_repository.counts(article_id).await?;
+        let mine = match viewer_id {
+            Some(user_id) => self.reaction_repository.list_by_user(user_id, article_id).await?,
+            None => Vec::new(),
+        };
+
+        Ok(ArticleReactionsResponse {
+            article_id,
+            counts,
+            mine,
+        })
+    }
+}
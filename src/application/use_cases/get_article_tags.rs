@@ -0,0 +1,62 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::tag::TagResponse;
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use crate::domain::repositories::tag_repository::{DynTagRepository, TagRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum GetArticleTagsError {
+    #[error("Article not found")]
+    ArticleNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for GetArticleTagsError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        GetArticleTagsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<TagRepositoryError> for GetArticleTagsError {
+    fn from(err: TagRepositoryError) -> Self {
+        GetArticleTagsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<GetArticleTagsError> for ApplicationError {
+    fn from(err: GetArticleTagsError) -> Self {
+        match err {
+            GetArticleTagsError::ArticleNotFound => {
+                Self::new(ErrorKind::NotFound, "article_not_found", "Article not found")
+            }
+            GetArticleTagsError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Tags que o usuário colocou em um artigo, em ordem alfabética
+pub struct GetArticleTagsUseCase {
+    article_repository: DynArticleRepository,
+    tag_repository: DynTagRepository,
+}
+
+impl GetArticleTagsUseCase {
+    pub fn new(article_repository: DynArticleRepository, tag_repository: DynTagRepository) -> Self {
+        Self {
+            article_repository,
+            tag_repository,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, article_id: Uuid) -> Result<Vec<TagResponse>, GetArticleTagsError> {
+        if self.article_repository.find_by_id(article_id).await?.is_none() {
+            return Err(GetArticleTagsError::ArticleNotFound);
+        }
+
+        let tags = self.tag_repository.list_by_article(user_id, article_id).await?;
+        Ok(tags.into_iter().map(TagResponse::from).collect())
+    }
+}
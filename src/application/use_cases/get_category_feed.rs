@@ -0,0 +1,83 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::article::ArticleListFilter;
+use crate::domain::entities::syndication::{SyndicationFeed, SyndicationItem, SYNDICATION_ITEM_LIMIT};
+use crate::domain::repositories::article_category_repository::{ArticleCategoryRepositoryError, DynArticleCategoryRepository};
+use crate::domain::repositories::article_repository::{ArticleRepository, ArticleRepositoryError};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum GetCategoryFeedError {
+    #[error("Category not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleCategoryRepositoryError> for GetCategoryFeedError {
+    fn from(err: ArticleCategoryRepositoryError) -> Self {
+        GetCategoryFeedError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ArticleRepositoryError> for GetCategoryFeedError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        GetCategoryFeedError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<GetCategoryFeedError> for ApplicationError {
+    fn from(err: GetCategoryFeedError) -> Self {
+        match err {
+            GetCategoryFeedError::NotFound => {
+                Self::new(ErrorKind::NotFound, "category_not_found", "Category not found")
+            }
+            GetCategoryFeedError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Monta o feed de saída de uma categoria com os artigos mais recentes
+pub struct GetCategoryFeedUseCase {
+    category_repository: DynArticleCategoryRepository,
+    article_repository: Arc<dyn ArticleRepository>,
+}
+
+impl GetCategoryFeedUseCase {
+    pub fn new(category_repository: DynArticleCategoryRepository, article_repository: Arc<dyn ArticleRepository>) -> Self {
+        Self {
+            category_repository,
+            article_repository,
+        }
+    }
+
+    pub async fn execute(&self, category_id: Uuid) -> Result<SyndicationFeed, GetCategoryFeedError> {
+        let category = self
+            .category_repository
+            .find_by_id(category_id)
+            .await?
+            .ok_or(GetCategoryFeedError::NotFound)?;
+
+        let filter = ArticleListFilter {
+            category_id: Some(category.id),
+            ..Default::default()
+        };
+        let page = self.article_repository.list_filtered(&filter, 1, SYNDICATION_ITEM_LIMIT).await?;
+
+        let items = page
+            .articles
+            .iter()
+            .map(|article| SyndicationItem::from_article(article, Some(&category.name)))
+            .collect();
+
+        Ok(SyndicationFeed::new(
+            category.id,
+            format!("Nexight: {}", category.name),
+            format!("Artigos mais recentes da categoria {}", category.name),
+            category.updated_at,
+            items,
+        ))
+    }
+}
@@ -0,0 +1,119 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::article::ArticleListFilter;
+use crate::domain::entities::syndication::{SyndicationFeed, SyndicationItem, SYNDICATION_ITEM_LIMIT};
+use crate::domain::repositories::article_repository::{ArticleRepository, ArticleRepositoryError};
+use crate::domain::repositories::collection_repository::{CollectionRepositoryError, DynCollectionRepository};
+use crate::domain::repositories::saved_article_repository::{DynSavedArticleRepository, SavedArticleRepositoryError};
+use std::collections::HashMap;
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum GetCollectionFeedError {
+    #[error("Collection not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<CollectionRepositoryError> for GetCollectionFeedError {
+    fn from(err: CollectionRepositoryError) -> Self {
+        GetCollectionFeedError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SavedArticleRepositoryError> for GetCollectionFeedError {
+    fn from(err: SavedArticleRepositoryError) -> Self {
+        GetCollectionFeedError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ArticleRepositoryError> for GetCollectionFeedError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        GetCollectionFeedError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<GetCollectionFeedError> for ApplicationError {
+    fn from(err: GetCollectionFeedError) -> Self {
+        match err {
+            GetCollectionFeedError::NotFound => {
+                Self::new(ErrorKind::NotFound, "collection_not_found", "Collection not found")
+            }
+            GetCollectionFeedError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Monta o feed de saída de uma coleção compartilhada, pelo token do link e sem autenticação
+pub struct GetCollectionFeedUseCase {
+    collection_repository: DynCollectionRepository,
+    saved_article_repository: DynSavedArticleRepository,
+    article_repository: Arc<dyn ArticleRepository>,
+}
+
+impl GetCollectionFeedUseCase {
+    pub fn new(
+        collection_repository: DynCollectionRepository,
+        saved_article_repository: DynSavedArticleRepository,
+        article_repository: Arc<dyn ArticleRepository>,
+    ) -> Self {
+        Self {
+            collection_repository,
+            saved_article_repository,
+            article_repository,
+        }
+    }
+
+    pub async fn execute(&self, token: &str) -> Result<SyndicationFeed, GetCollectionFeedError> {
+        let collection = self
+            .collection_repository
+            .find_by_share_token(token)
+            .await?
+            .ok_or(GetCollectionFeedError::NotFound)?;
+
+        // Os itens salvos por último entram primeiro no feed
+        let mut saved = self.saved_article_repository.list_by_collection(collection.id).await?;
+        saved.sort_by_key(|item| std::cmp::Reverse(item.created_at));
+        saved.truncate(SYNDICATION_ITEM_LIMIT as usize);
+
+        // Os artigos que ainda existem trazem o resumo da IA e o conteúdo completo
+        let article_ids: Vec<_> = saved.iter().filter_map(|item| item.article_id).collect();
+        let articles = if article_ids.is_empty() {
+            HashMap::new()
+        } else {
+            let filter = ArticleListFilter {
+                article_ids: Some(article_ids),
+                ..Default::default()
+            };
+            self.article_repository
+                .list_filtered(&filter, 1, SYNDICATION_ITEM_LIMIT)
+                .await?
+                .articles
+                .into_iter()
+                .map(|article| (article.id, article))
+                .collect()
+        };
+
+        let items = saved
+            .iter()
+            .map(|item| match item.article_id.and_then(|id| articles.get(&id)) {
+                Some(article) => {
+                    let mut syndicated = SyndicationItem::from_article(article, None);
+                    syndicated.updated = syndicated.updated.max(item.created_at);
+                    syndicated
+                }
+                None => SyndicationItem::from_saved(item),
+            })
+            .collect();
+
+        Ok(SyndicationFeed::new(
+            collection.id,
+            collection.name.clone(),
+            format!("Coleção compartilhada {}", collection.name),
+            collection.updated_at,
+            items,
+        ))
+    }
+}
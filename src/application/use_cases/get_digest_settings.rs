@@ -0,0 +1,53 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::digest::DigestSettingsResponse;
+use crate::domain::repositories::digest_repository::{DigestRepositoryError, DynDigestRepository};
+use chrono::Utc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum GetDigestSettingsError {
+    #[error("Digest not configured")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<DigestRepositoryError> for GetDigestSettingsError {
+    fn from(err: DigestRepositoryError) -> Self {
+        GetDigestSettingsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<GetDigestSettingsError> for ApplicationError {
+    fn from(err: GetDigestSettingsError) -> Self {
+        match err {
+            GetDigestSettingsError::NotFound => {
+                Self::new(ErrorKind::NotFound, "digest_not_configured", "Digest not configured")
+            }
+            GetDigestSettingsError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Agenda do resumo por email do usuário, com o horário do próximo envio
+pub struct GetDigestSettingsUseCase {
+    digest_repository: DynDigestRepository,
+}
+
+impl GetDigestSettingsUseCase {
+    pub fn new(digest_repository: DynDigestRepository) -> Self {
+        Self { digest_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<DigestSettingsResponse, GetDigestSettingsError> {
+        let settings = self
+            .digest_repository
+            .find_settings(user_id)
+            .await?
+            .ok_or(GetDigestSettingsError::NotFound)?;
+
+        Ok(DigestSettingsResponse::new(settings, Utc::now()))
+    }
+}
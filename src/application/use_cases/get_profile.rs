@@ -0,0 +1,76 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::user::ProfileResponse;
+use crate::domain::repositories::email_change_request_repository::{
+    DynEmailChangeRequestRepository, EmailChangeRequestRepositoryError,
+};
+use crate::domain::repositories::user_repository::{DynUserRepository, UserRepositoryError};
+use chrono::Utc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum GetProfileError {
+    #[error("User not found")]
+    UserNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<UserRepositoryError> for GetProfileError {
+    fn from(err: UserRepositoryError) -> Self {
+        GetProfileError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<EmailChangeRequestRepositoryError> for GetProfileError {
+    fn from(err: EmailChangeRequestRepositoryError) -> Self {
+        GetProfileError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<GetProfileError> for ApplicationError {
+    fn from(err: GetProfileError) -> Self {
+        match err {
+            GetProfileError::UserNotFound => Self::new(ErrorKind::NotFound, "user_not_found", "User not found"),
+            GetProfileError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+pub struct GetProfileUseCase {
+    user_repository: DynUserRepository,
+    email_change_repository: DynEmailChangeRequestRepository,
+}
+
+impl GetProfileUseCase {
+    pub fn new(
+        user_repository: DynUserRepository,
+        email_change_repository: DynEmailChangeRequestRepository,
+    ) -> Self {
+        Self {
+            user_repository,
+            email_change_repository,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<ProfileResponse, GetProfileError> {
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(GetProfileError::UserNotFound)?;
+
+        let pending_email = self
+            .email_change_repository
+            .find_by_user(user_id)
+            .await?
+            .filter(|request| !request.is_expired(Utc::now()))
+            .map(|request| request.new_email);
+
+        Ok(ProfileResponse {
+            user: user.into(),
+            pending_email,
+        })
+    }
+}
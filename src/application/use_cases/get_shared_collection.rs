@@ -0,0 +1,68 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::collection::SharedCollectionResponse;
+use crate::domain::entities::saved_article::SavedArticleResponse;
+use crate::domain::repositories::collection_repository::{CollectionRepositoryError, DynCollectionRepository};
+use crate::domain::repositories::saved_article_repository::{DynSavedArticleRepository, SavedArticleRepositoryError};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum GetSharedCollectionError {
+    #[error("Collection not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<CollectionRepositoryError> for GetSharedCollectionError {
+    fn from(err: CollectionRepositoryError) -> Self {
+        GetSharedCollectionError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SavedArticleRepositoryError> for GetSharedCollectionError {
+    fn from(err: SavedArticleRepositoryError) -> Self {
+        GetSharedCollectionError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<GetSharedCollectionError> for ApplicationError {
+    fn from(err: GetSharedCollectionError) -> Self {
+        match err {
+            GetSharedCollectionError::NotFound => {
+                Self::new(ErrorKind::NotFound, "collection_not_found", "Collection not found")
+            }
+            GetSharedCollectionError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Abre uma coleção compartilhada pelo token do link, sem autenticação
+pub struct GetSharedCollectionUseCase {
+    collection_repository: DynCollectionRepository,
+    saved_article_repository: DynSavedArticleRepository,
+}
+
+impl GetSharedCollectionUseCase {
+    pub fn new(collection_repository: DynCollectionRepository, saved_article_repository: DynSavedArticleRepository) -> Self {
+        Self {
+            collection_repository,
+            saved_article_repository,
+        }
+    }
+
+    pub async fn execute(&self, token: &str) -> Result<SharedCollectionResponse, GetSharedCollectionError> {
+        let collection = self
+            .collection_repository
+            .find_by_share_token(token)
+            .await?
+            .ok_or(GetSharedCollectionError::NotFound)?;
+
+        let items = self.saved_article_repository.list_by_collection(collection.id).await?;
+
+        Ok(SharedCollectionResponse {
+            name: collection.name,
+            items: items.into_iter().map(SavedArticleResponse::from).collect(),
+        })
+    }
+}
@@ -0,0 +1,56 @@
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::article::UnreadCounts;
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use crate::domain::repositories::subscription_repository::{DynSubscriptionRepository, SubscriptionRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum GetUnreadCountsError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for GetUnreadCountsError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        GetUnreadCountsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SubscriptionRepositoryError> for GetUnreadCountsError {
+    fn from(err: SubscriptionRepositoryError) -> Self {
+        GetUnreadCountsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<GetUnreadCountsError> for ApplicationError {
+    fn from(err: GetUnreadCountsError) -> Self {
+        let GetUnreadCountsError::RepositoryError(msg) = err;
+        Self::internal(msg)
+    }
+}
+
+/// Contagem de artigos não lidos nos feeds assinados, por feed e por categoria
+pub struct GetUnreadCountsUseCase {
+    article_repository: DynArticleRepository,
+    subscription_repository: DynSubscriptionRepository,
+}
+
+impl GetUnreadCountsUseCase {
+    pub fn new(article_repository: DynArticleRepository, subscription_repository: DynSubscriptionRepository) -> Self {
+        Self {
+            article_repository,
+            subscription_repository,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<UnreadCounts, GetUnreadCountsError> {
+        let feed_ids = self.subscription_repository.feed_ids_by_user(user_id).await?;
+
+        if feed_ids.is_empty() {
+            return Ok(UnreadCounts::default());
+        }
+
+        Ok(self.article_repository.unread_counts(user_id, &feed_ids).await?)
+    }
+}
@@ -0,0 +1,215 @@
+use crate::domain::entities::article_import::{ImportArticleRecord, ImportReport, IMPORT_BATCH_SIZE};
+use crate::domain::repositories::article_category_repository::DynArticleCategoryRepository;
+use crate::domain::repositories::article_repository::ArticleRepository;
+use crate::domain::repositories::feed_repository::DynFeedRepository;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Feeds e categorias já resolvidos durante a importação, para não repetir a consulta a cada linha
+#[derive(Default)]
+struct ResolvedReferences {
+    feeds_by_url: HashMap<String, Option<Uuid>>,
+    feeds_by_id: HashMap<Uuid, bool>,
+    categories_by_name: HashMap<String, Option<Uuid>>,
+    categories_by_id: HashMap<Uuid, bool>,
+}
+
+/// Importa artigos de um arquivo NDJSON (ou de um array JSON) em lotes, cada lote em uma transação.
+/// Linhas com link já cadastrado (ou repetido no arquivo) são ignoradas; as inválidas entram no relatório com o motivo.
+/// A importação é silenciosa de propósito: carga de histórico ou cópia entre ambientes não grava eventos no outbox
+/// (webhooks e stream) nem avalia os alertas, que notificariam cada artigo antigo de uma vez
+pub struct ImportArticlesUseCase {
+    article_repository: Arc<dyn ArticleRepository>,
+    category_repository: DynArticleCategoryRepository,
+    feed_repository: DynFeedRepository,
+}
+
+impl ImportArticlesUseCase {
+    pub fn new(
+        article_repository: Arc<dyn ArticleRepository>,
+        category_repository: DynArticleCategoryRepository,
+        feed_repository: DynFeedRepository,
+    ) -> Self {
+        Self {
+            article_repository,
+            category_repository,
+            feed_repository,
+        }
+    }
+
+    /// Separa o arquivo em registros numerados: um array JSON vira um registro por elemento,
+    /// qualquer outro conteúdo é lido como NDJSON (uma linha por registro)
+    pub fn records(body: &str) -> Result<Vec<(usize, String)>, String> {
+        if body.trim_start().starts_with('[') {
+            let values: Vec<serde_json::Value> =
+                serde_json::from_str(body).map_err(|e| format!("Invalid JSON array: {}", e))?;
+            return Ok(values
+                .into_iter()
+                .enumerate()
+                .map(|(index, value)| (index + 1, value.to_string()))
+                .collect());
+        }
+
+        Ok(body
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.to_string()))
+            .collect())
+    }
+
+    pub async fn execute(&self, records: Vec<(usize, String)>) -> ImportReport {
+        let mut report = ImportReport::default();
+        let mut references = ResolvedReferences::default();
+        let mut seen_links = HashSet::new();
+
+        for chunk in records.chunks(IMPORT_BATCH_SIZE) {
+            let mut batch = Vec::new();
+
+            for (line, text) in chunk {
+                if text.trim().is_empty() {
+                    continue;
+                }
+
+                let record: ImportArticleRecord = match serde_json::from_str(text) {
+                    Ok(record) => record,
+                    Err(e) => {
+                        report.failed(*line, format!("Invalid record: {}", e));
+                        continue;
+                    }
+                };
+                if let Err(errors) = record.validate() {
+                    report.failed(*line, format!("Validation failed: {}", errors));
+                    continue;
+                }
+
+                let feed_id = match self.resolve_feed(&record, &mut references).await {
+                    Ok(feed_id) => feed_id,
+                    Err(reason) => {
+                        report.failed(*line, reason);
+                        continue;
+                    }
+                };
+                let category_id = match self.resolve_category(&record, &mut references).await {
+                    Ok(category_id) => category_id,
+                    Err(reason) => {
+                        report.failed(*line, reason);
+                        continue;
+                    }
+                };
+
+                if !seen_links.insert(record.link.clone()) {
+                    report.skipped(*line, "Duplicate link in import");
+                    continue;
+                }
+                batch.push((*line, record.into_article(feed_id, category_id)));
+            }
+
+            if batch.is_empty() {
+                continue;
+            }
+
+            let articles = batch.iter().map(|(_, article)| article.clone()).collect();
+            match self.article_repository.create_batch(articles).await {
+                Ok(inserted) => {
+                    let inserted: HashSet<Uuid> = inserted.into_iter().map(|article| article.id).collect();
+                    for (line, article) in batch {
+                        if inserted.contains(&article.id) {
+                            report.inserted(line, article.id);
+                        } else {
+                            report.skipped(line, "Article with this link already exists");
+                        }
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to import article batch: {:?}", e);
+                    for (line, _) in batch {
+                        report.failed(line, format!("Batch rolled back: {}", e));
+                    }
+                }
+            }
+        }
+
+        report.lines.sort_by_key(|result| result.line);
+        report
+    }
+
+    async fn resolve_feed(&self, record: &ImportArticleRecord, references: &mut ResolvedReferences) -> Result<Uuid, String> {
+        if let Some(feed_id) = record.feed_id {
+            let exists = match references.feeds_by_id.get(&feed_id) {
+                Some(exists) => *exists,
+                None => {
+                    let exists = self
+                        .feed_repository
+                        .exists(feed_id)
+                        .await
+                        .map_err(|e| format!("Failed to resolve feed: {}", e))?;
+                    references.feeds_by_id.insert(feed_id, exists);
+                    exists
+                }
+            };
+            return if exists { Ok(feed_id) } else { Err(format!("Feed not found: {}", feed_id)) };
+        }
+
+        let url = match record.feed_url.as_deref().map(str::trim).filter(|url| !url.is_empty()) {
+            Some(url) => url,
+            None => return Err("feed_id or feed_url is required".to_string()),
+        };
+        let feed_id = match references.feeds_by_url.get(url) {
+            Some(feed_id) => *feed_id,
+            None => {
+                let feed_id = self
+                    .feed_repository
+                    .find_id_by_url(url)
+                    .await
+                    .map_err(|e| format!("Failed to resolve feed: {}", e))?;
+                references.feeds_by_url.insert(url.to_string(), feed_id);
+                feed_id
+            }
+        };
+        feed_id.ok_or_else(|| format!("Feed not found: {}", url))
+    }
+
+    async fn resolve_category(
+        &self,
+        record: &ImportArticleRecord,
+        references: &mut ResolvedReferences,
+    ) -> Result<Option<Uuid>, String> {
+        if let Some(category_id) = record.category_id {
+            let exists = match references.categories_by_id.get(&category_id) {
+                Some(exists) => *exists,
+                None => {
+                    let exists = self
+                        .category_repository
+                        .find_by_id(category_id)
+                        .await
+                        .map_err(|e| format!("Failed to resolve category: {}", e))?
+                        .is_some();
+                    references.categories_by_id.insert(category_id, exists);
+                    exists
+                }
+            };
+            return if exists { Ok(Some(category_id)) } else { Err(format!("Category not found: {}", category_id)) };
+        }
+
+        let name = match record.category_name.as_deref().map(str::trim).filter(|name| !name.is_empty()) {
+            Some(name) => name,
+            None => return Ok(None),
+        };
+        let category_id = match references.categories_by_name.get(name) {
+            Some(category_id) => *category_id,
+            None => {
+                let category_id = self
+                    .category_repository
+                    .find_by_name(name)
+                    .await
+                    .map_err(|e| format!("Failed to resolve category: {}", e))?
+                    .map(|category| category.id);
+                references.categories_by_name.insert(name.to_string(), category_id);
+                category_id
+            }
+        };
+        category_id.map(Some).ok_or_else(|| format!("Category not found: {}", name))
+    }
+}
@@ -0,0 +1,46 @@
+use crate::application::errors::ApplicationError;
+use crate::application::services::auth_service::AuthService;
+use crate::domain::entities::live_session::{StreamTicketResponse, STREAM_TICKET_TTL_SECONDS};
+use crate::domain::entities::user::User;
+use chrono::Duration;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum IssueStreamTicketError {
+    #[error("Token error: {0}")]
+    TokenError(String),
+}
+
+impl From<IssueStreamTicketError> for ApplicationError {
+    fn from(err: IssueStreamTicketError) -> Self {
+        let IssueStreamTicketError::TokenError(msg) = err;
+        Self::internal(msg)
+    }
+}
+
+/// Emite o ticket curto que abre o WebSocket em `/ws?ticket=`, para que o navegador não precise
+/// colocar o access token na URL (onde acabaria em logs de proxies e no histórico)
+pub struct IssueStreamTicketUseCase {
+    auth_service: Arc<AuthService>,
+}
+
+impl IssueStreamTicketUseCase {
+    pub fn new(auth_service: Arc<AuthService>) -> Self {
+        Self { auth_service }
+    }
+
+    /// O ticket herda a sessão do token que o pediu: revogar a sessão também fecha o WebSocket
+    pub fn execute(&self, user: &User, session_id: Option<Uuid>) -> Result<StreamTicketResponse, IssueStreamTicketError> {
+        let ticket = self
+            .auth_service
+            .generate_stream_ticket(user.id, &user.email, session_id, Duration::seconds(STREAM_TICKET_TTL_SECONDS))
+            .map_err(|e| IssueStreamTicketError::TokenError(e.to_string()))?;
+
+        Ok(StreamTicketResponse {
+            ticket,
+            expires_in: STREAM_TICKET_TTL_SECONDS,
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::alert::AlertRuleResponse;
+use crate::domain::repositories::alert_rule_repository::{AlertRuleRepositoryError, DynAlertRuleRepository};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ListAlertRulesError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<AlertRuleRepositoryError> for ListAlertRulesError {
+    fn from(err: AlertRuleRepositoryError) -> Self {
+        ListAlertRulesError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ListAlertRulesError> for ApplicationError {
+    fn from(err: ListAlertRulesError) -> Self {
+        let ListAlertRulesError::RepositoryError(msg) = err;
+        Self::internal(msg)
+    }
+}
+
+/// Lista as regras de alerta do usuário
+pub struct ListAlertRulesUseCase {
+    alert_rule_repository: DynAlertRuleRepository,
+}
+
+impl ListAlertRulesUseCase {
+    pub fn new(alert_rule_repository: DynAlertRuleRepository) -> Self {
+        Self { alert_rule_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<AlertRuleResponse>, ListAlertRulesError> {
+        let rules = self.alert_rule_repository.list_by_user(user_id).await?;
+        Ok(rules.into_iter().map(AlertRuleResponse::from).collect())
+    }
+}
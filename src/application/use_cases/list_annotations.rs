@@ -0,0 +1,68 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::annotation::AnnotationResponse;
+use crate::domain::repositories::annotation_repository::{AnnotationRepositoryError, DynAnnotationRepository};
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ListAnnotationsError {
+    #[error("Article not found")]
+    ArticleNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for ListAnnotationsError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        ListAnnotationsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AnnotationRepositoryError> for ListAnnotationsError {
+    fn from(err: AnnotationRepositoryError) -> Self {
+        ListAnnotationsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ListAnnotationsError> for ApplicationError {
+    fn from(err: ListAnnotationsError) -> Self {
+        match err {
+            ListAnnotationsError::ArticleNotFound => {
+                Self::new(ErrorKind::NotFound, "article_not_found", "Article not found")
+            }
+            ListAnnotationsError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Lista as anotações do usuário em um artigo, com as âncoras reposicionadas no conteúdo atual
+pub struct ListAnnotationsUseCase {
+    article_repository: DynArticleRepository,
+    annotation_repository: DynAnnotationRepository,
+}
+
+impl ListAnnotationsUseCase {
+    pub fn new(article_repository: DynArticleRepository, annotation_repository: DynAnnotationRepository) -> Self {
+        Self {
+            article_repository,
+            annotation_repository,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, article_id: Uuid) -> Result<Vec<AnnotationResponse>, ListAnnotationsError> {
+        let article = self
+            .article_repository
+            .find_by_id(article_id)
+            .await?
+            .ok_or(ListAnnotationsError::ArticleNotFound)?;
+
+        let annotations = self.annotation_repository.list_by_article(user_id, article_id).await?;
+
+        Ok(annotations
+            .into_iter()
+            .map(|annotation| AnnotationResponse::resolve(annotation, &article.content))
+            .collect())
+    }
+}
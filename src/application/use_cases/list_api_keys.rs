@@ -0,0 +1,40 @@
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::api_key::ApiKeyResponse;
+use crate::domain::repositories::api_key_repository::{ApiKeyRepositoryError, DynApiKeyRepository};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ListApiKeysError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ApiKeyRepositoryError> for ListApiKeysError {
+    fn from(err: ApiKeyRepositoryError) -> Self {
+        ListApiKeysError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ListApiKeysError> for ApplicationError {
+    fn from(err: ListApiKeysError) -> Self {
+        let ListApiKeysError::RepositoryError(msg) = err;
+        Self::internal(msg)
+    }
+}
+
+/// Lista as chaves de API do usuário (sem os segredos)
+pub struct ListApiKeysUseCase {
+    api_key_repository: DynApiKeyRepository,
+}
+
+impl ListApiKeysUseCase {
+    pub fn new(api_key_repository: DynApiKeyRepository) -> Self {
+        Self { api_key_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<ApiKeyResponse>, ListApiKeysError> {
+        let api_keys = self.api_key_repository.list_by_user(user_id).await?;
+        Ok(api_keys.into_iter().map(ApiKeyResponse::from).collect())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::application::errors::ApplicationError;
 use crate::domain::entities::article_category::ArticleCategoryResponse;
 use crate::domain::repositories::article_category_repository::{DynArticleCategoryRepository, ArticleCategoryRepositoryError};
 use thiserror::Error;
@@ -14,6 +15,13 @@ impl From<ArticleCategoryRepositoryError> for ListArticleCategoriesError {
     }
 }
 
+impl From<ListArticleCategoriesError> for ApplicationError {
+    fn from(err: ListArticleCategoriesError) -> Self {
+        let ListArticleCategoriesError::RepositoryError(msg) = err;
+        Self::internal(msg)
+    }
+}
+
 #[allow(dead_code)]
 pub struct ListArticleCategoriesUseCase {
     category_repository: DynArticleCategoryRepository,
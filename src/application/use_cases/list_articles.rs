@@ -1,4 +1,4 @@
-use crate::domain::entities::article::PaginatedArticles;
+use crate::domain::entities::article::{ArticleListFilter, PaginatedArticles};
 use crate::domain::repositories::article_repository::{ArticleRepository, ArticleRepositoryError};
 use std::sync::Arc;
 
@@ -11,7 +11,18 @@ impl ListArticlesUseCase {
         Self { article_repository }
     }
 
+    #[allow(dead_code)]
     pub async fn execute(&self, page: i64, per_page: i64) -> Result<PaginatedArticles, ArticleRepositoryError> {
         self.article_repository.list(page, per_page).await
     }
+
+    /// Listagem com filtros (ex.: estado de leitura do usuário autenticado)
+    pub async fn execute_filtered(
+        &self,
+        filter: &ArticleListFilter,
+        page: i64,
+        per_page: i64,
+    ) -> Result<PaginatedArticles, ArticleRepositoryError> {
+        self.article_repository.list_filtered(filter, page, per_page).await
+    }
 }
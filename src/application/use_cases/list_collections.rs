@@ -0,0 +1,40 @@
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::collection::CollectionResponse;
+use crate::domain::repositories::collection_repository::{CollectionRepositoryError, DynCollectionRepository};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ListCollectionsError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<CollectionRepositoryError> for ListCollectionsError {
+    fn from(err: CollectionRepositoryError) -> Self {
+        ListCollectionsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ListCollectionsError> for ApplicationError {
+    fn from(err: ListCollectionsError) -> Self {
+        let ListCollectionsError::RepositoryError(msg) = err;
+        Self::internal(msg)
+    }
+}
+
+/// Lista as coleções do usuário
+pub struct ListCollectionsUseCase {
+    collection_repository: DynCollectionRepository,
+}
+
+impl ListCollectionsUseCase {
+    pub fn new(collection_repository: DynCollectionRepository) -> Self {
+        Self { collection_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<CollectionResponse>, ListCollectionsError> {
+        let collections = self.collection_repository.list_by_user(user_id).await?;
+        Ok(collections.into_iter().map(CollectionResponse::from).collect())
+    }
+}
@@ -0,0 +1,168 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::application::services::ranking_service::{RankingProfile, RankingService};
+use crate::domain::entities::article::ArticleListFilter;
+use crate::domain::entities::ranking::{PaginatedRankedArticles, RankedArticle};
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use crate::domain::repositories::saved_article_repository::{DynSavedArticleRepository, SavedArticleRepositoryError};
+use crate::domain::repositories::subscription_repository::{DynSubscriptionRepository, SubscriptionRepositoryError};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ListForYouError {
+    #[error("Invalid pagination parameters")]
+    InvalidPagination,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for ListForYouError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        ListForYouError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SubscriptionRepositoryError> for ListForYouError {
+    fn from(err: SubscriptionRepositoryError) -> Self {
+        ListForYouError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SavedArticleRepositoryError> for ListForYouError {
+    fn from(err: SavedArticleRepositoryError) -> Self {
+        ListForYouError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ListForYouError> for ApplicationError {
+    fn from(err: ListForYouError) -> Self {
+        match err {
+            ListForYouError::InvalidPagination => {
+                Self::new(ErrorKind::BadRequest, "invalid_pagination", "Invalid pagination parameters")
+            }
+            ListForYouError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Listagem "Para você": os artigos não lidos e recentes dos feeds assinados, ordenados pela
+/// pontuação do `RankingService` em vez da data
+pub struct ListForYouUseCase {
+    article_repository: DynArticleRepository,
+    subscription_repository: DynSubscriptionRepository,
+    saved_article_repository: DynSavedArticleRepository,
+    ranking_service: Arc<RankingService>,
+}
+
+impl ListForYouUseCase {
+    /// Só os artigos desta janela concorrem; os mais antigos já perderam quase toda a recência
+    const CANDIDATE_WINDOW_DAYS: i64 = 14;
+    /// Limite de candidatos pontuados por requisição (os mais recentes da janela)
+    const MAX_CANDIDATES: i64 = 500;
+    const MAX_PER_PAGE: i64 = 100;
+
+    pub fn new(
+        article_repository: DynArticleRepository,
+        subscription_repository: DynSubscriptionRepository,
+        saved_article_repository: DynSavedArticleRepository,
+        ranking_service: Arc<RankingService>,
+    ) -> Self {
+        Self {
+            article_repository,
+            subscription_repository,
+            saved_article_repository,
+            ranking_service,
+        }
+    }
+
+    /// Com `explain`, cada artigo traz a composição da sua pontuação
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        explain: bool,
+        page: i64,
+        per_page: i64,
+    ) -> Result<PaginatedRankedArticles, ListForYouError> {
+        if page < 1 || !(1..=Self::MAX_PER_PAGE).contains(&per_page) {
+            return Err(ListForYouError::InvalidPagination);
+        }
+
+        let now = Utc::now();
+        let subscriptions = self.subscription_repository.list_by_user(user_id).await?;
+        // Peso 0 tira o feed do ranking
+        let feed_ids: Vec<Uuid> = subscriptions
+            .iter()
+            .filter(|subscription| subscription.weight > 0.0)
+            .map(|subscription| subscription.feed_id)
+            .collect();
+
+        let candidates = if feed_ids.is_empty() {
+            Vec::new()
+        } else {
+            let filter = ArticleListFilter {
+                feed_ids: Some(feed_ids),
+                reader_id: Some(user_id),
+                unread_only: true,
+                published_after: Some(now - Duration::days(Self::CANDIDATE_WINDOW_DAYS)),
+                ..Default::default()
+            };
+            self.article_repository
+                .list_filtered(&filter, 1, Self::MAX_CANDIDATES)
+                .await?
+                .articles
+        };
+
+        let engagement = self.article_repository.category_engagement(user_id).await?;
+        let saved_categories = self.saved_categories(user_id).await?;
+        let profile = RankingProfile::new(&engagement, &saved_categories, &subscriptions);
+
+        let ranked = self.ranking_service.rank(candidates, &profile, now);
+        let total = ranked.len() as i64;
+
+        let articles = ranked
+            .into_iter()
+            .skip(((page - 1) * per_page) as usize)
+            .take(per_page as usize)
+            .map(|(article, breakdown)| RankedArticle {
+                article,
+                score: breakdown.score,
+                score_breakdown: explain.then_some(breakdown),
+            })
+            .collect();
+
+        Ok(PaginatedRankedArticles {
+            articles,
+            total,
+            page,
+            per_page,
+            total_pages: (total as f64 / per_page as f64).ceil() as i64,
+        })
+    }
+
+    /// Categoria de cada artigo salvo que ainda existe
+    async fn saved_categories(&self, user_id: Uuid) -> Result<Vec<Uuid>, ListForYouError> {
+        let article_ids: Vec<Uuid> = self
+            .saved_article_repository
+            .list_by_user(user_id)
+            .await?
+            .into_iter()
+            .filter_map(|saved| saved.article_id)
+            .collect();
+
+        if article_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let per_page = article_ids.len() as i64;
+        let filter = ArticleListFilter {
+            article_ids: Some(article_ids),
+            ..Default::default()
+        };
+        let saved = self.article_repository.list_filtered(&filter, 1, per_page).await?;
+
+        Ok(saved.articles.into_iter().filter_map(|article| article.category_id).collect())
+    }
+}
@@ -0,0 +1,72 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::alert::{NotificationResponse, PaginatedNotifications};
+use crate::domain::repositories::notification_repository::{DynNotificationRepository, NotificationRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Limite de itens por página da caixa de entrada
+pub const MAX_PER_PAGE: i64 = 100;
+
+#[derive(Debug, Error)]
+pub enum ListNotificationsError {
+    #[error("Invalid pagination parameters")]
+    InvalidPagination,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<NotificationRepositoryError> for ListNotificationsError {
+    fn from(err: NotificationRepositoryError) -> Self {
+        ListNotificationsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ListNotificationsError> for ApplicationError {
+    fn from(err: ListNotificationsError) -> Self {
+        match err {
+            ListNotificationsError::InvalidPagination => {
+                Self::new(ErrorKind::BadRequest, "invalid_pagination", "Invalid pagination parameters")
+            }
+            ListNotificationsError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Caixa de entrada de notificações do usuário, das mais recentes para as mais antigas
+pub struct ListNotificationsUseCase {
+    notification_repository: DynNotificationRepository,
+}
+
+impl ListNotificationsUseCase {
+    pub fn new(notification_repository: DynNotificationRepository) -> Self {
+        Self { notification_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        unread_only: bool,
+        page: i64,
+        per_page: i64,
+    ) -> Result<PaginatedNotifications, ListNotificationsError> {
+        if page < 1 || !(1..=MAX_PER_PAGE).contains(&per_page) {
+            return Err(ListNotificationsError::InvalidPagination);
+        }
+
+        let (notifications, total) = self
+            .notification_repository
+            .list_by_user(user_id, unread_only, page, per_page)
+            .await?;
+        let unread = self.notification_repository.unread_count(user_id).await?;
+
+        Ok(PaginatedNotifications {
+            notifications: notifications.into_iter().map(NotificationResponse::from).collect(),
+            total,
+            unread,
+            page,
+            per_page,
+            total_pages: (total + per_page - 1) / per_page,
+        })
+    }
+}
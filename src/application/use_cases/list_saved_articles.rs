@@ -0,0 +1,83 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::saved_article::SavedArticleResponse;
+use crate::domain::repositories::saved_article_repository::{DynSavedArticleRepository, SavedArticleRepositoryError};
+use crate::domain::repositories::tag_repository::{DynTagRepository, TagRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ListSavedArticlesError {
+    #[error("Tag not found")]
+    TagNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<SavedArticleRepositoryError> for ListSavedArticlesError {
+    fn from(err: SavedArticleRepositoryError) -> Self {
+        ListSavedArticlesError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<TagRepositoryError> for ListSavedArticlesError {
+    fn from(err: TagRepositoryError) -> Self {
+        ListSavedArticlesError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ListSavedArticlesError> for ApplicationError {
+    fn from(err: ListSavedArticlesError) -> Self {
+        match err {
+            ListSavedArticlesError::TagNotFound => Self::new(ErrorKind::NotFound, "tag_not_found", "Tag not found"),
+            ListSavedArticlesError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Lista os artigos salvos do usuário, todos ou apenas os de uma coleção e/ou marcados com uma tag
+pub struct ListSavedArticlesUseCase {
+    saved_article_repository: DynSavedArticleRepository,
+    tag_repository: DynTagRepository,
+}
+
+impl ListSavedArticlesUseCase {
+    pub fn new(saved_article_repository: DynSavedArticleRepository, tag_repository: DynTagRepository) -> Self {
+        Self {
+            saved_article_repository,
+            tag_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        collection_id: Option<Uuid>,
+        tag_id: Option<Uuid>,
+    ) -> Result<Vec<SavedArticleResponse>, ListSavedArticlesError> {
+        let tagged = match tag_id {
+            Some(tag_id) => {
+                self.tag_repository
+                    .find_by_id(user_id, tag_id)
+                    .await?
+                    .ok_or(ListSavedArticlesError::TagNotFound)?;
+                Some(self.tag_repository.article_ids(tag_id).await?)
+            }
+            None => None,
+        };
+
+        let saved = self.saved_article_repository.list_by_user(user_id).await?;
+
+        Ok(saved
+            .into_iter()
+            .filter(|item| collection_id.is_none() || item.collection_id == collection_id)
+            // Itens cujo artigo foi removido não têm mais tags
+            .filter(|item| {
+                tagged
+                    .as_ref()
+                    .is_none_or(|ids| item.article_id.is_some_and(|article_id| ids.contains(&article_id)))
+            })
+            .map(SavedArticleResponse::from)
+            .collect())
+    }
+}
@@ -0,0 +1,43 @@
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::digest::SentDigestResponse;
+use crate::domain::repositories::digest_repository::{DigestRepositoryError, DynDigestRepository};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Quantidade de resumos retornada no histórico
+pub const HISTORY_LIMIT: i64 = 50;
+
+#[derive(Debug, Error)]
+pub enum ListSentDigestsError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<DigestRepositoryError> for ListSentDigestsError {
+    fn from(err: DigestRepositoryError) -> Self {
+        ListSentDigestsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ListSentDigestsError> for ApplicationError {
+    fn from(err: ListSentDigestsError) -> Self {
+        let ListSentDigestsError::RepositoryError(msg) = err;
+        Self::internal(msg)
+    }
+}
+
+/// Histórico dos resumos por email do usuário, dos mais recentes para os mais antigos
+pub struct ListSentDigestsUseCase {
+    digest_repository: DynDigestRepository,
+}
+
+impl ListSentDigestsUseCase {
+    pub fn new(digest_repository: DynDigestRepository) -> Self {
+        Self { digest_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<SentDigestResponse>, ListSentDigestsError> {
+        let sent = self.digest_repository.list_sent(user_id, HISTORY_LIMIT).await?;
+        Ok(sent.into_iter().map(SentDigestResponse::from).collect())
+    }
+}
@@ -0,0 +1,52 @@
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::session::SessionResponse;
+use crate::domain::repositories::session_repository::{DynSessionRepository, SessionRepositoryError};
+use chrono::Utc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ListSessionsError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<SessionRepositoryError> for ListSessionsError {
+    fn from(err: SessionRepositoryError) -> Self {
+        ListSessionsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ListSessionsError> for ApplicationError {
+    fn from(err: ListSessionsError) -> Self {
+        let ListSessionsError::RepositoryError(msg) = err;
+        Self::internal(msg)
+    }
+}
+
+/// Lista as sessões ativas do usuário, marcando a da requisição atual
+pub struct ListSessionsUseCase {
+    session_repository: DynSessionRepository,
+}
+
+impl ListSessionsUseCase {
+    pub fn new(session_repository: DynSessionRepository) -> Self {
+        Self { session_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        current_session_id: Option<Uuid>,
+    ) -> Result<Vec<SessionResponse>, ListSessionsError> {
+        let sessions = self
+            .session_repository
+            .list_active_by_user(user_id, Utc::now())
+            .await?;
+
+        Ok(sessions
+            .into_iter()
+            .map(|session| SessionResponse::from_session(session, current_session_id))
+            .collect())
+    }
+}
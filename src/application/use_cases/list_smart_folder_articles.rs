@@ -0,0 +1,82 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::application::use_cases::list_timeline::{ListTimelineError, ListTimelineUseCase};
+use crate::domain::entities::article::PaginatedArticles;
+use crate::domain::repositories::smart_folder_repository::{DynSmartFolderRepository, SmartFolderRepositoryError};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ListSmartFolderArticlesError {
+    #[error("Smart folder not found")]
+    NotFound,
+
+    #[error("Invalid pagination parameters")]
+    InvalidPagination,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<SmartFolderRepositoryError> for ListSmartFolderArticlesError {
+    fn from(err: SmartFolderRepositoryError) -> Self {
+        ListSmartFolderArticlesError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ListTimelineError> for ListSmartFolderArticlesError {
+    fn from(err: ListTimelineError) -> Self {
+        match err {
+            ListTimelineError::InvalidPagination => ListSmartFolderArticlesError::InvalidPagination,
+            _ => ListSmartFolderArticlesError::RepositoryError(err.to_string()),
+        }
+    }
+}
+
+impl From<ListSmartFolderArticlesError> for ApplicationError {
+    fn from(err: ListSmartFolderArticlesError) -> Self {
+        match err {
+            ListSmartFolderArticlesError::NotFound => {
+                Self::new(ErrorKind::NotFound, "smart_folder_not_found", "Smart folder not found")
+            }
+            ListSmartFolderArticlesError::InvalidPagination => {
+                Self::new(ErrorKind::BadRequest, "invalid_pagination", "Invalid pagination parameters")
+            }
+            ListSmartFolderArticlesError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Avalia uma pasta inteligente: a timeline do usuário com os critérios salvos
+pub struct ListSmartFolderArticlesUseCase {
+    smart_folder_repository: DynSmartFolderRepository,
+    list_timeline_use_case: Arc<ListTimelineUseCase>,
+}
+
+impl ListSmartFolderArticlesUseCase {
+    pub fn new(smart_folder_repository: DynSmartFolderRepository, list_timeline_use_case: Arc<ListTimelineUseCase>) -> Self {
+        Self {
+            smart_folder_repository,
+            list_timeline_use_case,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        folder_id: Uuid,
+        page: i64,
+        per_page: i64,
+    ) -> Result<PaginatedArticles, ListSmartFolderArticlesError> {
+        let folder = self
+            .smart_folder_repository
+            .find_by_id(user_id, folder_id)
+            .await?
+            .ok_or(ListSmartFolderArticlesError::NotFound)?;
+
+        Ok(self
+            .list_timeline_use_case
+            .execute_filtered(user_id, folder.criteria.filter(), folder.criteria.tag_id, page, per_page)
+            .await?)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::application::errors::ApplicationError;
+use crate::application::use_cases::list_timeline::{ListTimelineError, ListTimelineUseCase};
+use crate::domain::entities::article::ArticleListFilter;
+use crate::domain::entities::smart_folder::SmartFolderResponse;
+use crate::domain::repositories::smart_folder_repository::{DynSmartFolderRepository, SmartFolderRepositoryError};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ListSmartFoldersError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<SmartFolderRepositoryError> for ListSmartFoldersError {
+    fn from(err: SmartFolderRepositoryError) -> Self {
+        ListSmartFoldersError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ListTimelineError> for ListSmartFoldersError {
+    fn from(err: ListTimelineError) -> Self {
+        ListSmartFoldersError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ListSmartFoldersError> for ApplicationError {
+    fn from(err: ListSmartFoldersError) -> Self {
+        let ListSmartFoldersError::RepositoryError(msg) = err;
+        Self::internal(msg)
+    }
+}
+
+/// Lista as pastas inteligentes do usuário com a quantidade de não lidos de cada uma
+pub struct ListSmartFoldersUseCase {
+    smart_folder_repository: DynSmartFolderRepository,
+    list_timeline_use_case: Arc<ListTimelineUseCase>,
+}
+
+impl ListSmartFoldersUseCase {
+    pub fn new(smart_folder_repository: DynSmartFolderRepository, list_timeline_use_case: Arc<ListTimelineUseCase>) -> Self {
+        Self {
+            smart_folder_repository,
+            list_timeline_use_case,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<SmartFolderResponse>, ListSmartFoldersError> {
+        let folders = self.smart_folder_repository.list_by_user(user_id).await?;
+
+        let mut responses = Vec::with_capacity(folders.len());
+        for folder in folders {
+            // Conta só os não lidos, mesmo que a pasta também mostre os já lidos
+            let filter = ArticleListFilter {
+                unread_only: true,
+                ..folder.criteria.filter()
+            };
+            let unread = self
+                .list_timeline_use_case
+                .execute_filtered(user_id, filter, folder.criteria.tag_id, 1, 1)
+                .await?
+                .total;
+
+            responses.push(SmartFolderResponse {
+                unread: Some(unread),
+                ..folder.into()
+            });
+        }
+
+        Ok(responses)
+    }
+}
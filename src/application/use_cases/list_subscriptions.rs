@@ -0,0 +1,40 @@
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::subscription::SubscriptionResponse;
+use crate::domain::repositories::subscription_repository::{DynSubscriptionRepository, SubscriptionRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ListSubscriptionsError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<SubscriptionRepositoryError> for ListSubscriptionsError {
+    fn from(err: SubscriptionRepositoryError) -> Self {
+        ListSubscriptionsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ListSubscriptionsError> for ApplicationError {
+    fn from(err: ListSubscriptionsError) -> Self {
+        let ListSubscriptionsError::RepositoryError(msg) = err;
+        Self::internal(msg)
+    }
+}
+
+/// Lista os feeds assinados pelo usuário, agrupáveis pela pasta
+pub struct ListSubscriptionsUseCase {
+    subscription_repository: DynSubscriptionRepository,
+}
+
+impl ListSubscriptionsUseCase {
+    pub fn new(subscription_repository: DynSubscriptionRepository) -> Self {
+        Self { subscription_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<SubscriptionResponse>, ListSubscriptionsError> {
+        let subscriptions = self.subscription_repository.list_by_user(user_id).await?;
+        Ok(subscriptions.into_iter().map(SubscriptionResponse::from).collect())
+    }
+}
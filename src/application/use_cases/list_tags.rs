@@ -0,0 +1,39 @@
+use crate::application::errors::ApplicationError;
+use crate::domain::entities::tag::TagSummary;
+use crate::domain::repositories::tag_repository::{DynTagRepository, TagRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ListTagsError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<TagRepositoryError> for ListTagsError {
+    fn from(err: TagRepositoryError) -> Self {
+        ListTagsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ListTagsError> for ApplicationError {
+    fn from(err: ListTagsError) -> Self {
+        let ListTagsError::RepositoryError(msg) = err;
+        Self::internal(msg)
+    }
+}
+
+/// Lista as tags do usuário em ordem alfabética, com a quantidade de artigos de cada uma
+pub struct ListTagsUseCase {
+    tag_repository: DynTagRepository,
+}
+
+impl ListTagsUseCase {
+    pub fn new(tag_repository: DynTagRepository) -> Self {
+        Self { tag_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<TagSummary>, ListTagsError> {
+        Ok(self.tag_repository.list_by_user(user_id).await?)
+    }
+}
@@ -0,0 +1,122 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::article::{ArticleListFilter, PaginatedArticles};
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use crate::domain::repositories::subscription_repository::{DynSubscriptionRepository, SubscriptionRepositoryError};
+use crate::domain::repositories::tag_repository::{DynTagRepository, TagRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ListTimelineError {
+    #[error("Invalid pagination parameters")]
+    InvalidPagination,
+
+    #[error("Tag not found")]
+    TagNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for ListTimelineError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        match err {
+            ArticleRepositoryError::InvalidPagination => ListTimelineError::InvalidPagination,
+            _ => ListTimelineError::RepositoryError(err.to_string()),
+        }
+    }
+}
+
+impl From<SubscriptionRepositoryError> for ListTimelineError {
+    fn from(err: SubscriptionRepositoryError) -> Self {
+        ListTimelineError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<TagRepositoryError> for ListTimelineError {
+    fn from(err: TagRepositoryError) -> Self {
+        ListTimelineError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ListTimelineError> for ApplicationError {
+    fn from(err: ListTimelineError) -> Self {
+        match err {
+            ListTimelineError::InvalidPagination => {
+                Self::new(ErrorKind::BadRequest, "invalid_pagination", "Invalid pagination parameters")
+            }
+            ListTimelineError::TagNotFound => Self::new(ErrorKind::NotFound, "tag_not_found", "Tag not found"),
+            ListTimelineError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Timeline pessoal: a listagem de artigos restrita aos feeds assinados pelo usuário
+pub struct ListTimelineUseCase {
+    article_repository: DynArticleRepository,
+    subscription_repository: DynSubscriptionRepository,
+    tag_repository: DynTagRepository,
+}
+
+impl ListTimelineUseCase {
+    pub fn new(
+        article_repository: DynArticleRepository,
+        subscription_repository: DynSubscriptionRepository,
+        tag_repository: DynTagRepository,
+    ) -> Self {
+        Self {
+            article_repository,
+            subscription_repository,
+            tag_repository,
+        }
+    }
+
+    /// Cada artigo traz o estado de leitura do usuário; `unread_only` omite os já lidos
+    #[allow(dead_code)]
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        unread_only: bool,
+        page: i64,
+        per_page: i64,
+    ) -> Result<PaginatedArticles, ListTimelineError> {
+        let filter = ArticleListFilter {
+            unread_only,
+            ..Default::default()
+        };
+
+        self.execute_filtered(user_id, filter, None, page, per_page).await
+    }
+
+    /// Aplica os filtros e a ordenação recebidos, restritos aos feeds assinados pelo usuário
+    /// e, com `tag_id`, aos artigos marcados com essa tag
+    pub async fn execute_filtered(
+        &self,
+        user_id: Uuid,
+        filter: ArticleListFilter,
+        tag_id: Option<Uuid>,
+        page: i64,
+        per_page: i64,
+    ) -> Result<PaginatedArticles, ListTimelineError> {
+        let article_ids = match tag_id {
+            Some(tag_id) => {
+                self.tag_repository
+                    .find_by_id(user_id, tag_id)
+                    .await?
+                    .ok_or(ListTimelineError::TagNotFound)?;
+                Some(self.tag_repository.article_ids(tag_id).await?)
+            }
+            None => None,
+        };
+
+        // Sem assinaturas a lista de feeds fica vazia e a timeline também
+        let filter = ArticleListFilter {
+            feed_ids: Some(self.subscription_repository.feed_ids_by_user(user_id).await?),
+            reader_id: Some(user_id),
+            article_ids,
+            ..filter
+        };
+
+        Ok(self.article_repository.list_filtered(&filter, page, per_page).await?)
+    }
+}
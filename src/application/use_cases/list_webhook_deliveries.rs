@@ -0,0 +1,70 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::webhook::{PaginatedWebhookDeliveries, WebhookDeliveryResponse};
+use crate::domain::repositories::webhook_repository::{DynWebhookRepository, WebhookRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Limite de itens por página do histórico de entregas
+pub const MAX_PER_PAGE: i64 = 100;
+
+#[derive(Debug, Error)]
+pub enum ListWebhookDeliveriesError {
+    #[error("Webhook not found")]
+    NotFound,
+
+    #[error("Invalid pagination parameters")]
+    InvalidPagination,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<WebhookRepositoryError> for ListWebhookDeliveriesError {
+    fn from(err: WebhookRepositoryError) -> Self {
+        ListWebhookDeliveriesError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ListWebhookDeliveriesError> for ApplicationError {
+    fn from(err: ListWebhookDeliveriesError) -> Self {
+        match err {
+            ListWebhookDeliveriesError::NotFound => {
+                Self::new(ErrorKind::NotFound, "webhook_not_found", "Webhook not found")
+            }
+            ListWebhookDeliveriesError::InvalidPagination => {
+                Self::new(ErrorKind::BadRequest, "invalid_pagination", "Invalid pagination parameters")
+            }
+            ListWebhookDeliveriesError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Histórico de entregas de um webhook, das mais recentes para as mais antigas
+pub struct ListWebhookDeliveriesUseCase {
+    webhook_repository: DynWebhookRepository,
+}
+
+impl ListWebhookDeliveriesUseCase {
+    pub fn new(webhook_repository: DynWebhookRepository) -> Self {
+        Self { webhook_repository }
+    }
+
+    pub async fn execute(&self, id: Uuid, page: i64, per_page: i64) -> Result<PaginatedWebhookDeliveries, ListWebhookDeliveriesError> {
+        if page < 1 || !(1..=MAX_PER_PAGE).contains(&per_page) {
+            return Err(ListWebhookDeliveriesError::InvalidPagination);
+        }
+        if self.webhook_repository.find_by_id(id).await?.is_none() {
+            return Err(ListWebhookDeliveriesError::NotFound);
+        }
+
+        let (deliveries, total) = self.webhook_repository.list_deliveries(id, page, per_page).await?;
+
+        Ok(PaginatedWebhookDeliveries {
+            deliveries: deliveries.into_iter().map(WebhookDeliveryResponse::from).collect(),
+            total,
+            page,
+            per_page,
+            total_pages: (total + per_page - 1) / per_page,
+        })
+    }
+}
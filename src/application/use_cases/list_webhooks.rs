@@ -0,0 +1,18 @@
+use crate::domain::entities::webhook::WebhookSubscriptionResponse;
+use crate::domain::repositories::webhook_repository::{DynWebhookRepository, WebhookRepositoryError};
+
+/// Lista os webhooks cadastrados, sem os segredos
+pub struct ListWebhooksUseCase {
+    webhook_repository: DynWebhookRepository,
+}
+
+impl ListWebhooksUseCase {
+    pub fn new(webhook_repository: DynWebhookRepository) -> Self {
+        Self { webhook_repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<WebhookSubscriptionResponse>, WebhookRepositoryError> {
+        let subscriptions = self.webhook_repository.list().await?;
+        Ok(subscriptions.into_iter().map(WebhookSubscriptionResponse::from).collect())
+    }
+}
@@ -1,6 +1,16 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
 use crate::application::services::auth_service::{AuthError, AuthService};
-use crate::domain::entities::user::{AuthResponse, LoginDto};
+use crate::application::services::login_throttle_service::LoginThrottleService;
+use crate::application::services::session_service::{SessionError, SessionService};
+use crate::domain::entities::login_throttle::ThrottleScope;
+use crate::domain::entities::mfa::{MfaChallengeResponse, MfaEnrollmentRequiredResponse};
+use crate::domain::entities::session::SessionClient;
+use crate::domain::entities::user::{AuthResponse, LoginDto, User};
+use crate::domain::repositories::login_throttle_repository::LoginThrottleRepositoryError;
+use crate::domain::repositories::mfa_repository::{DynMfaRepository, MfaRepositoryError};
 use crate::domain::repositories::user_repository::{DynUserRepository, UserRepositoryError};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
 use std::sync::Arc;
 use thiserror::Error;
 use validator::Validate;
@@ -8,11 +18,17 @@ use validator::Validate;
 #[derive(Debug, Error)]
 pub enum LoginUserError {
     #[error("Validation error: {0}")]
-    ValidationError(String),
+    ValidationError(ValidationFailure),
 
     #[error("Invalid credentials")]
     InvalidCredentials,
 
+    #[error("Account is inactive")]
+    AccountInactive,
+
+    #[error("Too many failed login attempts, retry in {retry_after_seconds} seconds")]
+    AccountLocked { retry_after_seconds: i64 },
+
     #[error("Failed to generate token: {0}")]
     TokenError(String),
 
@@ -26,6 +42,18 @@ impl From<UserRepositoryError> for LoginUserError {
     }
 }
 
+impl From<LoginThrottleRepositoryError> for LoginUserError {
+    fn from(err: LoginThrottleRepositoryError) -> Self {
+        LoginUserError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<MfaRepositoryError> for LoginUserError {
+    fn from(err: MfaRepositoryError) -> Self {
+        LoginUserError::RepositoryError(err.to_string())
+    }
+}
+
 impl From<AuthError> for LoginUserError {
     fn from(err: AuthError) -> Self {
         match err {
@@ -36,30 +64,166 @@ impl From<AuthError> for LoginUserError {
     }
 }
 
+impl From<SessionError> for LoginUserError {
+    fn from(err: SessionError) -> Self {
+        match err {
+            SessionError::TokenError(msg) => LoginUserError::TokenError(msg),
+            SessionError::RepositoryError(msg) => LoginUserError::RepositoryError(msg),
+        }
+    }
+}
+
+impl From<LoginUserError> for ApplicationError {
+    fn from(err: LoginUserError) -> Self {
+        match err {
+            LoginUserError::ValidationError(failure) => failure.into(),
+            LoginUserError::InvalidCredentials => {
+                Self::new(ErrorKind::Unauthorized, "invalid_credentials", "Invalid email or password")
+            }
+            LoginUserError::AccountInactive => Self::new(ErrorKind::Forbidden, "account_inactive", "Account is inactive"),
+            LoginUserError::AccountLocked { retry_after_seconds } => {
+                Self::new(ErrorKind::TooManyRequests, "account_locked", "Too many failed login attempts")
+                    .with_retry_after(retry_after_seconds)
+            }
+            LoginUserError::TokenError(msg) | LoginUserError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+impl LoginUserError {
+    fn locked_until(until: DateTime<Utc>) -> Self {
+        let retry_after_seconds = (until - Utc::now()).num_seconds().max(1);
+        LoginUserError::AccountLocked { retry_after_seconds }
+    }
+}
+
+/// Result of the password step: a full session, a pending 2FA challenge or,
+/// for roles that require 2FA, a token that only allows enrolling
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum LoginOutcome {
+    Authenticated(AuthResponse),
+    MfaRequired(MfaChallengeResponse),
+    MfaEnrollmentRequired(MfaEnrollmentRequiredResponse),
+}
+
+impl LoginOutcome {
+    /// Second step required after the first factor (password or OIDC provider), if any.
+    /// With 2FA enabled the first factor only earns a short-lived challenge token; editors
+    /// and admins without 2FA get a token that only allows enrolling, never a session.
+    pub(crate) fn second_factor(
+        auth_service: &AuthService,
+        user: &User,
+        mfa_enabled: bool,
+        ttl: Duration,
+    ) -> Result<Option<Self>, AuthError> {
+        if mfa_enabled {
+            let mfa_token = auth_service.generate_mfa_token(user.id, &user.email, ttl)?;
+
+            return Ok(Some(LoginOutcome::MfaRequired(MfaChallengeResponse {
+                mfa_required: true,
+                mfa_token,
+                expires_in: ttl.num_seconds(),
+            })));
+        }
+
+        if user.role.requires_mfa() {
+            let enrollment_token = auth_service.generate_mfa_enrollment_token(user.id, &user.email, ttl)?;
+
+            return Ok(Some(LoginOutcome::MfaEnrollmentRequired(MfaEnrollmentRequiredResponse {
+                mfa_enrollment_required: true,
+                enrollment_token,
+                expires_in: ttl.num_seconds(),
+            })));
+        }
+
+        Ok(None)
+    }
+}
+
+/// Dados da requisição de login que não fazem parte das credenciais
+#[derive(Debug, Clone, Default)]
+pub struct LoginContext {
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    /// Nome do dispositivo informado pelo cliente (header `X-Device-Name`)
+    pub device_label: Option<String>,
+}
+
+impl LoginContext {
+    /// Dados do dispositivo registrados na sessão aberta pelo login
+    pub fn session_client(&self) -> SessionClient {
+        SessionClient {
+            device_label: self.device_label.clone(),
+            user_agent: self.user_agent.clone(),
+            ip_address: self.ip_address.clone(),
+        }
+    }
+}
+
 pub struct LoginUserUseCase {
     user_repository: DynUserRepository,
     auth_service: Arc<AuthService>,
+    throttle_service: Arc<LoginThrottleService>,
+    mfa_repository: DynMfaRepository,
+    mfa_token_ttl: Duration,
+    session_service: Arc<SessionService>,
 }
 
 impl LoginUserUseCase {
-    pub fn new(user_repository: DynUserRepository, auth_service: Arc<AuthService>) -> Self {
+    pub fn new(
+        user_repository: DynUserRepository,
+        auth_service: Arc<AuthService>,
+        throttle_service: Arc<LoginThrottleService>,
+        mfa_repository: DynMfaRepository,
+        mfa_token_ttl: Duration,
+        session_service: Arc<SessionService>,
+    ) -> Self {
         Self {
             user_repository,
             auth_service,
+            throttle_service,
+            mfa_repository,
+            mfa_token_ttl,
+            session_service,
         }
     }
 
-    pub async fn execute(&self, dto: LoginDto) -> Result<AuthResponse, LoginUserError> {
+    pub async fn execute(
+        &self,
+        dto: LoginDto,
+        context: LoginContext,
+    ) -> Result<LoginOutcome, LoginUserError> {
         // Validate input
         dto.validate()
-            .map_err(|e| LoginUserError::ValidationError(e.to_string()))?;
+            .map_err(|e| LoginUserError::ValidationError(e.into()))?;
+
+        // Reject requests from a locked IP before touching the account
+        if let Some(ip) = &context.ip_address {
+            if let Some(until) = self.throttle_service.locked_until(ThrottleScope::Ip, ip).await? {
+                return Err(LoginUserError::locked_until(until));
+            }
+        }
 
         // Find user by email
-        let user = self
-            .user_repository
-            .find_by_email(&dto.email)
+        let user = match self.user_repository.find_by_email(&dto.email).await? {
+            Some(user) => user,
+            None => {
+                if let Some(until) = self.register_failure(None, &context).await? {
+                    return Err(LoginUserError::locked_until(until));
+                }
+                return Err(LoginUserError::InvalidCredentials);
+            }
+        };
+
+        let account = user.id.to_string();
+        if let Some(until) = self
+            .throttle_service
+            .locked_until(ThrottleScope::Account, &account)
             .await?
-            .ok_or(LoginUserError::InvalidCredentials)?;
+        {
+            return Err(LoginUserError::locked_until(until));
+        }
 
         // Verify password
         let is_valid = self
@@ -67,17 +231,69 @@ impl LoginUserUseCase {
             .verify_password(&dto.password, &user.password_hash)?;
 
         if !is_valid {
+            if let Some(until) = self.register_failure(Some(&account), &context).await? {
+                return Err(LoginUserError::locked_until(until));
+            }
             return Err(LoginUserError::InvalidCredentials);
         }
 
-        // Generate token
+        // Only disclose the account status to callers holding the right password
+        if !user.is_active {
+            return Err(LoginUserError::AccountInactive);
+        }
+
+        // The account throttle is kept until a session is issued
+        let mfa_enabled = self
+            .mfa_repository
+            .find_by_user(user.id)
+            .await?
+            .is_some_and(|mfa| mfa.enabled);
+
+        if let Some(outcome) =
+            LoginOutcome::second_factor(&self.auth_service, &user, mfa_enabled, self.mfa_token_ttl)?
+        {
+            return Ok(outcome);
+        }
+
+        self.throttle_service
+            .reset(ThrottleScope::Account, &account)
+            .await?;
+
+        // Open a session for this device and issue a token bound to it
         let token = self
-            .auth_service
-            .generate_token(user.id, &user.email)?;
+            .session_service
+            .start(user.id, &user.email, context.session_client())
+            .await?;
 
-        Ok(AuthResponse {
+        Ok(LoginOutcome::Authenticated(AuthResponse {
             token,
             user: user.into(),
-        })
+        }))
+    }
+
+    /// Contabiliza a falha na conta (se conhecida) e no IP, retornando o bloqueio acionado
+    async fn register_failure(
+        &self,
+        account: Option<&str>,
+        context: &LoginContext,
+    ) -> Result<Option<DateTime<Utc>>, LoginUserError> {
+        let mut locked_until = None;
+
+        if let Some(account) = account {
+            locked_until = self
+                .throttle_service
+                .register_failure(ThrottleScope::Account, account)
+                .await?;
+        }
+
+        if let Some(ip) = &context.ip_address {
+            let ip_locked_until = self
+                .throttle_service
+                .register_failure(ThrottleScope::Ip, ip)
+                .await?;
+            locked_until = locked_until.max(ip_locked_until);
+        }
+
+        Ok(locked_until)
     }
 }
@@ -0,0 +1,91 @@
+use crate::application::errors::ApplicationError;
+use crate::application::services::read_state_sync_service::ReadStateSyncService;
+use crate::domain::entities::article::{ArticleListFilter, MarkAllReadDto, MarkAllReadResponse};
+use crate::domain::entities::read_state::{ReadStateChange, ReadStateEvent};
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use crate::domain::repositories::subscription_repository::{DynSubscriptionRepository, SubscriptionRepositoryError};
+use chrono::Utc;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum MarkAllReadError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for MarkAllReadError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        MarkAllReadError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SubscriptionRepositoryError> for MarkAllReadError {
+    fn from(err: SubscriptionRepositoryError) -> Self {
+        MarkAllReadError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<MarkAllReadError> for ApplicationError {
+    fn from(err: MarkAllReadError) -> Self {
+        let MarkAllReadError::RepositoryError(msg) = err;
+        Self::internal(msg)
+    }
+}
+
+/// Marca como lidos, de uma vez, os artigos de um feed, de uma categoria ou de toda a timeline.
+/// A mudança é repassada aos outros dispositivos do usuário
+pub struct MarkAllReadUseCase {
+    article_repository: DynArticleRepository,
+    subscription_repository: DynSubscriptionRepository,
+    read_state_sync: Arc<ReadStateSyncService>,
+}
+
+impl MarkAllReadUseCase {
+    pub fn new(
+        article_repository: DynArticleRepository,
+        subscription_repository: DynSubscriptionRepository,
+        read_state_sync: Arc<ReadStateSyncService>,
+    ) -> Self {
+        Self {
+            article_repository,
+            subscription_repository,
+            read_state_sync,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, dto: MarkAllReadDto) -> Result<MarkAllReadResponse, MarkAllReadError> {
+        // Um feed explícito pode não estar assinado; sem ele, vale a timeline do usuário
+        let feed_ids = match dto.feed_id {
+            Some(feed_id) => vec![feed_id],
+            None => self.subscription_repository.feed_ids_by_user(user_id).await?,
+        };
+
+        let filter = ArticleListFilter {
+            feed_ids: Some(feed_ids),
+            category_id: dto.category_id,
+            published_before: dto.before,
+            ..Default::default()
+        };
+
+        let marked = self
+            .article_repository
+            .mark_read_matching(user_id, &filter, Utc::now())
+            .await?;
+
+        self.read_state_sync
+            .publish(ReadStateEvent::new(
+                user_id,
+                ReadStateChange::AllRead {
+                    feed_id: dto.feed_id,
+                    category_id: dto.category_id,
+                    before: dto.before,
+                    marked,
+                },
+            ))
+            .await;
+
+        Ok(MarkAllReadResponse { marked })
+    }
+}
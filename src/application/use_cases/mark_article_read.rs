@@ -0,0 +1,59 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::application::services::read_state_sync_service::ReadStateSyncService;
+use crate::domain::entities::read_state::{ReadStateChange, ReadStateEvent};
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use chrono::Utc;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum MarkArticleReadError {
+    #[error("Article not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for MarkArticleReadError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        match err {
+            ArticleRepositoryError::NotFound => MarkArticleReadError::NotFound,
+            _ => MarkArticleReadError::RepositoryError(err.to_string()),
+        }
+    }
+}
+
+impl From<MarkArticleReadError> for ApplicationError {
+    fn from(err: MarkArticleReadError) -> Self {
+        match err {
+            MarkArticleReadError::NotFound => Self::new(ErrorKind::NotFound, "article_not_found", "Article not found"),
+            MarkArticleReadError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Marca um artigo como lido pelo usuário; marcar de novo preserva o horário da primeira leitura.
+/// A mudança é repassada aos outros dispositivos do usuário
+pub struct MarkArticleReadUseCase {
+    article_repository: DynArticleRepository,
+    read_state_sync: Arc<ReadStateSyncService>,
+}
+
+impl MarkArticleReadUseCase {
+    pub fn new(article_repository: DynArticleRepository, read_state_sync: Arc<ReadStateSyncService>) -> Self {
+        Self {
+            article_repository,
+            read_state_sync,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, article_id: Uuid) -> Result<(), MarkArticleReadError> {
+        self.article_repository.mark_read(user_id, article_id, Utc::now()).await?;
+        self.read_state_sync
+            .publish(ReadStateEvent::new(user_id, ReadStateChange::Read { article_id }))
+            .await;
+        Ok(())
+    }
+}
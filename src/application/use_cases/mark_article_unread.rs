@@ -0,0 +1,60 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::application::services::read_state_sync_service::ReadStateSyncService;
+use crate::domain::entities::read_state::{ReadStateChange, ReadStateEvent};
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum MarkArticleUnreadError {
+    #[error("Article not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for MarkArticleUnreadError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        match err {
+            ArticleRepositoryError::NotFound => MarkArticleUnreadError::NotFound,
+            _ => MarkArticleUnreadError::RepositoryError(err.to_string()),
+        }
+    }
+}
+
+impl From<MarkArticleUnreadError> for ApplicationError {
+    fn from(err: MarkArticleUnreadError) -> Self {
+        match err {
+            MarkArticleUnreadError::NotFound => {
+                Self::new(ErrorKind::NotFound, "article_not_found", "Article not found")
+            }
+            MarkArticleUnreadError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Volta um artigo para não lido; é idempotente para artigos que o usuário ainda não leu.
+/// A mudança é repassada aos outros dispositivos do usuário
+pub struct MarkArticleUnreadUseCase {
+    article_repository: DynArticleRepository,
+    read_state_sync: Arc<ReadStateSyncService>,
+}
+
+impl MarkArticleUnreadUseCase {
+    pub fn new(article_repository: DynArticleRepository, read_state_sync: Arc<ReadStateSyncService>) -> Self {
+        Self {
+            article_repository,
+            read_state_sync,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, article_id: Uuid) -> Result<(), MarkArticleUnreadError> {
+        self.article_repository.mark_unread(user_id, article_id).await?;
+        self.read_state_sync
+            .publish(ReadStateEvent::new(user_id, ReadStateChange::Unread { article_id }))
+            .await;
+        Ok(())
+    }
+}
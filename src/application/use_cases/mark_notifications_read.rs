@@ -0,0 +1,60 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::alert::MarkNotificationsReadResponse;
+use crate::domain::repositories::notification_repository::{DynNotificationRepository, NotificationRepositoryError};
+use chrono::Utc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum MarkNotificationsReadError {
+    #[error("Notification not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<NotificationRepositoryError> for MarkNotificationsReadError {
+    fn from(err: NotificationRepositoryError) -> Self {
+        MarkNotificationsReadError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<MarkNotificationsReadError> for ApplicationError {
+    fn from(err: MarkNotificationsReadError) -> Self {
+        match err {
+            MarkNotificationsReadError::NotFound => {
+                Self::new(ErrorKind::NotFound, "notification_not_found", "Notification not found")
+            }
+            MarkNotificationsReadError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Marca notificações da caixa de entrada como lidas, uma a uma ou todas de uma vez
+pub struct MarkNotificationsReadUseCase {
+    notification_repository: DynNotificationRepository,
+}
+
+impl MarkNotificationsReadUseCase {
+    pub fn new(notification_repository: DynNotificationRepository) -> Self {
+        Self { notification_repository }
+    }
+
+    /// Marcar de novo mantém o `read_at` original
+    pub async fn execute(&self, user_id: Uuid, notification_id: Uuid) -> Result<(), MarkNotificationsReadError> {
+        if !self
+            .notification_repository
+            .mark_read(user_id, notification_id, Utc::now())
+            .await?
+        {
+            return Err(MarkNotificationsReadError::NotFound);
+        }
+        Ok(())
+    }
+
+    pub async fn execute_all(&self, user_id: Uuid) -> Result<MarkNotificationsReadResponse, MarkNotificationsReadError> {
+        let marked = self.notification_repository.mark_all_read(user_id, Utc::now()).await?;
+        Ok(MarkNotificationsReadResponse { marked })
+    }
+}
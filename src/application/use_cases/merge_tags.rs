@@ -0,0 +1,76 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::tag::{MergeTagDto, TagResponse};
+use crate::domain::repositories::smart_folder_repository::{DynSmartFolderRepository, SmartFolderRepositoryError};
+use crate::domain::repositories::tag_repository::{DynTagRepository, TagRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum MergeTagsError {
+    #[error("Tag not found")]
+    NotFound,
+
+    #[error("Cannot merge a tag into itself")]
+    SameTag,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<TagRepositoryError> for MergeTagsError {
+    fn from(err: TagRepositoryError) -> Self {
+        MergeTagsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SmartFolderRepositoryError> for MergeTagsError {
+    fn from(err: SmartFolderRepositoryError) -> Self {
+        MergeTagsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<MergeTagsError> for ApplicationError {
+    fn from(err: MergeTagsError) -> Self {
+        match err {
+            MergeTagsError::NotFound => Self::new(ErrorKind::NotFound, "tag_not_found", "Tag not found"),
+            e @ MergeTagsError::SameTag => Self::new(ErrorKind::BadRequest, "same_tag", e.to_string()),
+            MergeTagsError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Junta duas tags do usuário: os artigos da tag de origem passam para a de destino
+/// e a tag de origem deixa de existir. Pastas inteligentes filtradas pela origem passam a usar o destino
+pub struct MergeTagsUseCase {
+    tag_repository: DynTagRepository,
+    smart_folder_repository: DynSmartFolderRepository,
+}
+
+impl MergeTagsUseCase {
+    pub fn new(tag_repository: DynTagRepository, smart_folder_repository: DynSmartFolderRepository) -> Self {
+        Self {
+            tag_repository,
+            smart_folder_repository,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, source_id: Uuid, dto: MergeTagDto) -> Result<TagResponse, MergeTagsError> {
+        if source_id == dto.into {
+            return Err(MergeTagsError::SameTag);
+        }
+
+        self.tag_repository
+            .find_by_id(user_id, source_id)
+            .await?
+            .ok_or(MergeTagsError::NotFound)?;
+        let target = self
+            .tag_repository
+            .find_by_id(user_id, dto.into)
+            .await?
+            .ok_or(MergeTagsError::NotFound)?;
+
+        self.smart_folder_repository.replace_tag(user_id, source_id, target.id).await?;
+        self.tag_repository.merge(user_id, source_id, target.id).await?;
+        Ok(target.into())
+    }
+}
@@ -6,4 +6,86 @@ pub mod create_article;
 pub mod update_article;
 pub mod delete_article;
 pub mod get_article;
-pub mod list_articles;
\ No newline at end of file
+pub mod list_articles;
+pub mod unlock_user;
+pub mod get_profile;
+pub mod update_profile;
+pub mod confirm_email_change;
+pub mod change_password;
+pub mod delete_account;
+pub mod enroll_totp;
+pub mod confirm_totp;
+pub mod disable_totp;
+pub mod verify_mfa_login;
+pub mod create_api_key;
+pub mod list_api_keys;
+pub mod revoke_api_key;
+pub mod list_sessions;
+pub mod revoke_session;
+pub mod start_oidc_login;
+pub mod complete_oidc_login;
+pub mod subscribe_feed;
+pub mod list_subscriptions;
+pub mod update_subscription;
+pub mod unsubscribe_feed;
+pub mod list_timeline;
+pub mod mark_article_read;
+pub mod mark_article_unread;
+pub mod mark_all_read;
+pub mod get_unread_counts;
+pub mod save_article;
+pub mod list_saved_articles;
+pub mod move_saved_article;
+pub mod unsave_article;
+pub mod reorder_saved_articles;
+pub mod create_collection;
+pub mod list_collections;
+pub mod update_collection;
+pub mod delete_collection;
+pub mod get_shared_collection;
+pub mod rate_article;
+pub mod remove_article_rating;
+pub mod react_to_article;
+pub mod remove_article_reaction;
+pub mod get_article_reactions;
+pub mod create_annotation;
+pub mod list_annotations;
+pub mod update_annotation;
+pub mod delete_annotation;
+pub mod export_annotations;
+pub mod create_tag;
+pub mod list_tags;
+pub mod autocomplete_tags;
+pub mod rename_tag;
+pub mod merge_tags;
+pub mod delete_tag;
+pub mod get_article_tags;
+pub mod set_article_tags;
+pub mod list_for_you;
+pub mod create_smart_folder;
+pub mod list_smart_folders;
+pub mod update_smart_folder;
+pub mod delete_smart_folder;
+pub mod list_smart_folder_articles;
+pub mod create_alert_rule;
+pub mod list_alert_rules;
+pub mod update_alert_rule;
+pub mod delete_alert_rule;
+pub mod list_notifications;
+pub mod mark_notifications_read;
+pub mod get_digest_settings;
+pub mod save_digest_settings;
+pub mod delete_digest_settings;
+pub mod list_sent_digests;
+pub mod preview_digest;
+pub mod create_webhook;
+pub mod list_webhooks;
+pub mod update_webhook;
+pub mod delete_webhook;
+pub mod list_webhook_deliveries;
+pub mod test_webhook;
+pub mod get_category_feed;
+pub mod get_collection_feed;
+pub mod export_articles;
+pub mod import_articles;
+pub mod issue_stream_ticket;
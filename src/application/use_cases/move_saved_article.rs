@@ -0,0 +1,84 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::saved_article::{MoveSavedArticleDto, SavedArticleResponse};
+use crate::domain::repositories::collection_repository::{CollectionRepositoryError, DynCollectionRepository};
+use crate::domain::repositories::saved_article_repository::{DynSavedArticleRepository, SavedArticleRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum MoveSavedArticleError {
+    #[error("Saved article not found")]
+    NotFound,
+
+    #[error("Collection not found")]
+    CollectionNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<CollectionRepositoryError> for MoveSavedArticleError {
+    fn from(err: CollectionRepositoryError) -> Self {
+        MoveSavedArticleError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SavedArticleRepositoryError> for MoveSavedArticleError {
+    fn from(err: SavedArticleRepositoryError) -> Self {
+        MoveSavedArticleError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<MoveSavedArticleError> for ApplicationError {
+    fn from(err: MoveSavedArticleError) -> Self {
+        match err {
+            MoveSavedArticleError::NotFound => {
+                Self::new(ErrorKind::NotFound, "saved_article_not_found", "Saved article not found")
+            }
+            MoveSavedArticleError::CollectionNotFound => {
+                Self::new(ErrorKind::NotFound, "collection_not_found", "Collection not found")
+            }
+            MoveSavedArticleError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Move um artigo salvo para outra coleção ou o retira da coleção atual
+pub struct MoveSavedArticleUseCase {
+    saved_article_repository: DynSavedArticleRepository,
+    collection_repository: DynCollectionRepository,
+}
+
+impl MoveSavedArticleUseCase {
+    pub fn new(saved_article_repository: DynSavedArticleRepository, collection_repository: DynCollectionRepository) -> Self {
+        Self {
+            saved_article_repository,
+            collection_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        saved_id: Uuid,
+        dto: MoveSavedArticleDto,
+    ) -> Result<SavedArticleResponse, MoveSavedArticleError> {
+        let mut saved = self
+            .saved_article_repository
+            .find_by_id(user_id, saved_id)
+            .await?
+            .ok_or(MoveSavedArticleError::NotFound)?;
+
+        if let Some(collection_id) = dto.collection_id {
+            self.collection_repository
+                .find_by_id(user_id, collection_id)
+                .await?
+                .ok_or(MoveSavedArticleError::CollectionNotFound)?;
+        }
+
+        saved.collection_id = dto.collection_id;
+
+        let saved = self.saved_article_repository.update(saved).await?;
+        Ok(saved.into())
+    }
+}
@@ -0,0 +1,68 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::application::services::digest_service::{DigestService, DigestServiceError};
+use crate::domain::entities::digest::DigestPreview;
+use crate::domain::repositories::digest_repository::{DigestRepositoryError, DynDigestRepository};
+use chrono::Utc;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum PreviewDigestError {
+    #[error("Digest not configured")]
+    NotFound,
+
+    #[error("Digest error: {0}")]
+    DigestError(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<DigestRepositoryError> for PreviewDigestError {
+    fn from(err: DigestRepositoryError) -> Self {
+        PreviewDigestError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<DigestServiceError> for PreviewDigestError {
+    fn from(err: DigestServiceError) -> Self {
+        PreviewDigestError::DigestError(err.to_string())
+    }
+}
+
+impl From<PreviewDigestError> for ApplicationError {
+    fn from(err: PreviewDigestError) -> Self {
+        match err {
+            PreviewDigestError::NotFound => {
+                Self::new(ErrorKind::NotFound, "digest_not_configured", "Digest not configured")
+            }
+            PreviewDigestError::DigestError(msg) | PreviewDigestError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Mostra o resumo do período mais recente como ele seria enviado, sem enviá-lo
+pub struct PreviewDigestUseCase {
+    digest_repository: DynDigestRepository,
+    digest_service: Arc<DigestService>,
+}
+
+impl PreviewDigestUseCase {
+    pub fn new(digest_repository: DynDigestRepository, digest_service: Arc<DigestService>) -> Self {
+        Self {
+            digest_repository,
+            digest_service,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<DigestPreview, PreviewDigestError> {
+        let settings = self
+            .digest_repository
+            .find_settings(user_id)
+            .await?
+            .ok_or(PreviewDigestError::NotFound)?;
+
+        Ok(self.digest_service.preview(&settings, Utc::now()).await?)
+    }
+}
@@ -0,0 +1,71 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::domain::entities::article::{ArticleRatingResponse, RateArticleDto};
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use chrono::Utc;
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum RateArticleError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Article not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for RateArticleError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        match err {
+            ArticleRepositoryError::NotFound => RateArticleError::NotFound,
+            _ => RateArticleError::RepositoryError(err.to_string()),
+        }
+    }
+}
+
+impl From<RateArticleError> for ApplicationError {
+    fn from(err: RateArticleError) -> Self {
+        match err {
+            RateArticleError::ValidationError(failure) => failure.into(),
+            RateArticleError::NotFound => Self::new(ErrorKind::NotFound, "article_not_found", "Article not found"),
+            RateArticleError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Grava a nota do usuário para um artigo; avaliar de novo substitui a nota anterior
+pub struct RateArticleUseCase {
+    article_repository: DynArticleRepository,
+}
+
+impl RateArticleUseCase {
+    pub fn new(article_repository: DynArticleRepository) -> Self {
+        Self { article_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        article_id: Uuid,
+        dto: RateArticleDto,
+    ) -> Result<ArticleRatingResponse, RateArticleError> {
+        dto.validate()
+            .map_err(|e| RateArticleError::ValidationError(e.into()))?;
+
+        let summary = self
+            .article_repository
+            .rate(user_id, article_id, dto.rating, Utc::now())
+            .await?;
+
+        Ok(ArticleRatingResponse {
+            article_id,
+            rating: Some(dto.rating),
+            average: summary.average,
+            count: summary.count,
+        })
+    }
+}
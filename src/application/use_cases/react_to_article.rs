@@ -0,0 +1,81 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::reaction::Reaction;
+use crate::domain::repositories::article_reaction_repository::{ArticleReactionRepositoryError, DynArticleReactionRepository};
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ReactToArticleError {
+    #[error("Article not found")]
+    NotFound,
+
+    #[error("Invalid reaction: use like, dislike or a single emoji")]
+    InvalidReaction,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for ReactToArticleError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        ReactToArticleError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ArticleReactionRepositoryError> for ReactToArticleError {
+    fn from(err: ArticleReactionRepositoryError) -> Self {
+        match err {
+            ArticleReactionRepositoryError::ArticleNotFound => ReactToArticleError::NotFound,
+            ArticleReactionRepositoryError::DatabaseError(msg) => ReactToArticleError::RepositoryError(msg),
+        }
+    }
+}
+
+impl From<ReactToArticleError> for ApplicationError {
+    fn from(err: ReactToArticleError) -> Self {
+        match err {
+            ReactToArticleError::NotFound => Self::new(ErrorKind::NotFound, "article_not_found", "Article not found"),
+            e @ ReactToArticleError::InvalidReaction => {
+                Self::new(ErrorKind::BadRequest, "invalid_reaction", e.to_string())
+            }
+            ReactToArticleError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Adiciona uma reação do usuário a um artigo.
+/// `like` substitui um `dislike` anterior (e vice-versa); emojis se acumulam
+pub struct ReactToArticleUseCase {
+    article_repository: DynArticleRepository,
+    reaction_repository: DynArticleReactionRepository,
+}
+
+impl ReactToArticleUseCase {
+    pub fn new(article_repository: DynArticleRepository, reaction_repository: DynArticleReactionRepository) -> Self {
+        Self {
+            article_repository,
+            reaction_repository,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, article_id: Uuid, value: &str) -> Result<(), ReactToArticleError> {
+        if self.article_repository.find_by_id(article_id).await?.is_none() {
+            return Err(ReactToArticleError::NotFound);
+        }
+
+        let reaction = Reaction::parse(value).ok_or(ReactToArticleError::InvalidReaction)?;
+
+        if let Some(opposite) = reaction.opposite() {
+            self.reaction_repository
+                .remove(user_id, article_id, opposite.as_str())
+                .await?;
+        }
+
+        self.reaction_repository
+            .add(user_id, article_id, reaction.as_str())
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
 use crate::application::services::auth_service::{AuthError, AuthService};
+use crate::application::services::session_service::{SessionError, SessionService};
+use crate::domain::entities::session::SessionClient;
 use crate::domain::entities::user::{AuthResponse, CreateUserDto, User};
 use crate::domain::repositories::user_repository::{DynUserRepository, UserRepositoryError};
 use std::sync::Arc;
@@ -8,7 +11,7 @@ use validator::Validate;
 #[derive(Debug, Error)]
 pub enum RegisterUserError {
     #[error("Validation error: {0}")]
-    ValidationError(String),
+    ValidationError(ValidationFailure),
 
     #[error("Email already in use")]
     EmailAlreadyExists,
@@ -42,26 +45,60 @@ impl From<AuthError> for RegisterUserError {
     }
 }
 
+impl From<SessionError> for RegisterUserError {
+    fn from(err: SessionError) -> Self {
+        match err {
+            SessionError::TokenError(msg) => RegisterUserError::TokenError(msg),
+            SessionError::RepositoryError(msg) => RegisterUserError::RepositoryError(msg),
+        }
+    }
+}
+
+impl From<RegisterUserError> for ApplicationError {
+    fn from(err: RegisterUserError) -> Self {
+        match err {
+            RegisterUserError::ValidationError(failure) => failure.into(),
+            RegisterUserError::EmailAlreadyExists => {
+                Self::new(ErrorKind::Conflict, "email_taken", "Email already in use")
+            }
+            RegisterUserError::PasswordHashError(msg)
+            | RegisterUserError::RepositoryError(msg)
+            | RegisterUserError::TokenError(msg) => Self::internal(msg),
+        }
+    }
+}
+
 pub struct RegisterUserUseCase {
     user_repository: DynUserRepository,
     auth_service: Arc<AuthService>,
+    session_service: Arc<SessionService>,
 }
 
 impl RegisterUserUseCase {
-    pub fn new(user_repository: DynUserRepository, auth_service: Arc<AuthService>) -> Self {
+    pub fn new(
+        user_repository: DynUserRepository,
+        auth_service: Arc<AuthService>,
+        session_service: Arc<SessionService>,
+    ) -> Self {
         Self {
             user_repository,
             auth_service,
+            session_service,
         }
     }
 
-    pub async fn execute(&self, dto: CreateUserDto) -> Result<AuthResponse, RegisterUserError> {
+    /// Registers the user and opens a session for the device that signed up
+    pub async fn execute(
+        &self,
+        dto: CreateUserDto,
+        client: SessionClient,
+    ) -> Result<AuthResponse, RegisterUserError> {
         // Validate input
         dto.validate()
-            .map_err(|e| RegisterUserError::ValidationError(e.to_string()))?;
+            .map_err(|e| RegisterUserError::ValidationError(e.into()))?;
 
         // Check if email already exists
-        if let Some(_) = self.user_repository.find_by_email(&dto.email).await? {
+        if self.user_repository.find_by_email(&dto.email).await?.is_some() {
             return Err(RegisterUserError::EmailAlreadyExists);
         }
 
@@ -74,10 +111,11 @@ impl RegisterUserUseCase {
         // Save user
         let created_user = self.user_repository.create(user).await?;
 
-        // Generate token
+        // Open the first session and issue a token bound to it
         let token = self
-            .auth_service
-            .generate_token(created_user.id, &created_user.email)?;
+            .session_service
+            .start(created_user.id, &created_user.email, client)
+            .await?;
 
         Ok(AuthResponse {
             token,
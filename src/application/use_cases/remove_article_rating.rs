@@ -0,0 +1,56 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::article::ArticleRatingResponse;
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum RemoveArticleRatingError {
+    #[error("Article not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for RemoveArticleRatingError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        match err {
+            ArticleRepositoryError::NotFound => RemoveArticleRatingError::NotFound,
+            _ => RemoveArticleRatingError::RepositoryError(err.to_string()),
+        }
+    }
+}
+
+impl From<RemoveArticleRatingError> for ApplicationError {
+    fn from(err: RemoveArticleRatingError) -> Self {
+        match err {
+            RemoveArticleRatingError::NotFound => {
+                Self::new(ErrorKind::NotFound, "article_not_found", "Article not found")
+            }
+            RemoveArticleRatingError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Remove a nota do usuário; sem nota anterior, apenas devolve o agregado atual
+pub struct RemoveArticleRatingUseCase {
+    article_repository: DynArticleRepository,
+}
+
+impl RemoveArticleRatingUseCase {
+    pub fn new(article_repository: DynArticleRepository) -> Self {
+        Self { article_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, article_id: Uuid) -> Result<ArticleRatingResponse, RemoveArticleRatingError> {
+        let summary = self.article_repository.remove_rating(user_id, article_id).await?;
+
+        Ok(ArticleRatingResponse {
+            article_id,
+            rating: None,
+            average: summary.average,
+            count: summary.count,
+        })
+    }
+}
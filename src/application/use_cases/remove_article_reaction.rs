@@ -0,0 +1,58 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::reaction::Reaction;
+use crate::domain::repositories::article_reaction_repository::{ArticleReactionRepositoryError, DynArticleReactionRepository};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum RemoveArticleReactionError {
+    #[error("Reaction not found")]
+    NotFound,
+
+    #[error("Invalid reaction: use like, dislike or a single emoji")]
+    InvalidReaction,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleReactionRepositoryError> for RemoveArticleReactionError {
+    fn from(err: ArticleReactionRepositoryError) -> Self {
+        RemoveArticleReactionError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<RemoveArticleReactionError> for ApplicationError {
+    fn from(err: RemoveArticleReactionError) -> Self {
+        match err {
+            RemoveArticleReactionError::NotFound => {
+                Self::new(ErrorKind::NotFound, "reaction_not_found", "Reaction not found")
+            }
+            e @ RemoveArticleReactionError::InvalidReaction => {
+                Self::new(ErrorKind::BadRequest, "invalid_reaction", e.to_string())
+            }
+            RemoveArticleReactionError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Remove uma reação do usuário a um artigo
+pub struct RemoveArticleReactionUseCase {
+    reaction_repository: DynArticleReactionRepository,
+}
+
+impl RemoveArticleReactionUseCase {
+    pub fn new(reaction_repository: DynArticleReactionRepository) -> Self {
+        Self { reaction_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, article_id: Uuid, value: &str) -> Result<(), RemoveArticleReactionError> {
+        let reaction = Reaction::parse(value).ok_or(RemoveArticleReactionError::InvalidReaction)?;
+
+        if !self.reaction_repository.remove(user_id, article_id, reaction.as_str()).await? {
+            return Err(RemoveArticleReactionError::NotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::domain::entities::tag::{RenameTagDto, Tag, TagResponse};
+use crate::domain::repositories::tag_repository::{DynTagRepository, TagRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum RenameTagError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Tag not found")]
+    NotFound,
+
+    #[error("A tag with this name already exists; merge the tags instead")]
+    NameTaken,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<TagRepositoryError> for RenameTagError {
+    fn from(err: TagRepositoryError) -> Self {
+        match err {
+            TagRepositoryError::NameTaken => RenameTagError::NameTaken,
+            _ => RenameTagError::RepositoryError(err.to_string()),
+        }
+    }
+}
+
+impl From<RenameTagError> for ApplicationError {
+    fn from(err: RenameTagError) -> Self {
+        match err {
+            RenameTagError::ValidationError(failure) => failure.into(),
+            RenameTagError::NotFound => Self::new(ErrorKind::NotFound, "tag_not_found", "Tag not found"),
+            e @ RenameTagError::NameTaken => Self::new(ErrorKind::Conflict, "tag_name_taken", e.to_string()),
+            RenameTagError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Renomeia uma tag do usuário; mudar apenas maiúsculas e minúsculas é permitido
+pub struct RenameTagUseCase {
+    tag_repository: DynTagRepository,
+}
+
+impl RenameTagUseCase {
+    pub fn new(tag_repository: DynTagRepository) -> Self {
+        Self { tag_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, tag_id: Uuid, dto: RenameTagDto) -> Result<TagResponse, RenameTagError> {
+        dto.validate()
+            .map_err(|e| RenameTagError::ValidationError(e.into()))?;
+
+        if Tag::normalize_name(&dto.name).is_empty() {
+            return Err(RenameTagError::ValidationError("Name is required".into()));
+        }
+
+        let mut tag = self
+            .tag_repository
+            .find_by_id(user_id, tag_id)
+            .await?
+            .ok_or(RenameTagError::NotFound)?;
+
+        tag.rename(&dto.name);
+        let tag = self.tag_repository.update(tag).await?;
+        Ok(tag.into())
+    }
+}
@@ -0,0 +1,88 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::saved_article::{ReorderSavedArticlesDto, SavedArticleResponse};
+use crate::domain::repositories::saved_article_repository::{DynSavedArticleRepository, SavedArticleRepositoryError};
+use std::collections::HashSet;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ReorderSavedArticlesError {
+    #[error("The new order must list each item of the collection exactly once")]
+    InvalidOrder,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<SavedArticleRepositoryError> for ReorderSavedArticlesError {
+    fn from(err: SavedArticleRepositoryError) -> Self {
+        ReorderSavedArticlesError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ReorderSavedArticlesError> for ApplicationError {
+    fn from(err: ReorderSavedArticlesError) -> Self {
+        match err {
+            e @ ReorderSavedArticlesError::InvalidOrder => {
+                Self::new(ErrorKind::BadRequest, "invalid_order", e.to_string())
+            }
+            ReorderSavedArticlesError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Reordena os itens de uma coleção (ou os que estão fora de coleções).
+/// As posições já ocupadas pelo grupo são redistribuídas na nova ordem,
+/// de modo que a ordem relativa aos itens de outros grupos não muda
+pub struct ReorderSavedArticlesUseCase {
+    saved_article_repository: DynSavedArticleRepository,
+}
+
+impl ReorderSavedArticlesUseCase {
+    pub fn new(saved_article_repository: DynSavedArticleRepository) -> Self {
+        Self { saved_article_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        dto: ReorderSavedArticlesDto,
+    ) -> Result<Vec<SavedArticleResponse>, ReorderSavedArticlesError> {
+        let group: Vec<_> = self
+            .saved_article_repository
+            .list_by_user(user_id)
+            .await?
+            .into_iter()
+            .filter(|item| item.collection_id == dto.collection_id)
+            .collect();
+
+        let group_ids: HashSet<Uuid> = group.iter().map(|item| item.id).collect();
+        let requested: HashSet<Uuid> = dto.ids.iter().copied().collect();
+        if requested.len() != dto.ids.len() || requested != group_ids {
+            return Err(ReorderSavedArticlesError::InvalidOrder);
+        }
+
+        // A lista já vem ordenada por posição
+        let positions: Vec<(Uuid, i32)> = dto
+            .ids
+            .iter()
+            .zip(group.iter().map(|item| item.position))
+            .map(|(id, position)| (*id, position))
+            .collect();
+
+        self.saved_article_repository.update_positions(user_id, &positions).await?;
+
+        let mut reordered: Vec<SavedArticleResponse> = group
+            .into_iter()
+            .map(|mut item| {
+                if let Some((_, position)) = positions.iter().find(|(id, _)| *id == item.id) {
+                    item.position = *position;
+                }
+                SavedArticleResponse::from(item)
+            })
+            .collect();
+        reordered.sort_by_key(|item| item.position);
+
+        Ok(reordered)
+    }
+}
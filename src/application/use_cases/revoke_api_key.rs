@@ -0,0 +1,47 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::repositories::api_key_repository::{ApiKeyRepositoryError, DynApiKeyRepository};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum RevokeApiKeyError {
+    #[error("API key not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ApiKeyRepositoryError> for RevokeApiKeyError {
+    fn from(err: ApiKeyRepositoryError) -> Self {
+        RevokeApiKeyError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<RevokeApiKeyError> for ApplicationError {
+    fn from(err: RevokeApiKeyError) -> Self {
+        match err {
+            RevokeApiKeyError::NotFound => Self::new(ErrorKind::NotFound, "api_key_not_found", "API key not found"),
+            RevokeApiKeyError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Revoga (remove) uma chave de API do usuário
+pub struct RevokeApiKeyUseCase {
+    api_key_repository: DynApiKeyRepository,
+}
+
+impl RevokeApiKeyUseCase {
+    pub fn new(api_key_repository: DynApiKeyRepository) -> Self {
+        Self { api_key_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, api_key_id: Uuid) -> Result<(), RevokeApiKeyError> {
+        if !self.api_key_repository.delete(user_id, api_key_id).await? {
+            return Err(RevokeApiKeyError::NotFound);
+        }
+
+        Ok(())
+    }
+}
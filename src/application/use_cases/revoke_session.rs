@@ -0,0 +1,52 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::repositories::session_repository::{DynSessionRepository, SessionRepositoryError};
+use chrono::Utc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum RevokeSessionError {
+    #[error("Session not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<SessionRepositoryError> for RevokeSessionError {
+    fn from(err: SessionRepositoryError) -> Self {
+        RevokeSessionError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<RevokeSessionError> for ApplicationError {
+    fn from(err: RevokeSessionError) -> Self {
+        match err {
+            RevokeSessionError::NotFound => Self::new(ErrorKind::NotFound, "session_not_found", "Session not found"),
+            RevokeSessionError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Revoga uma sessão do usuário; os tokens dela deixam de ser aceitos imediatamente
+pub struct RevokeSessionUseCase {
+    session_repository: DynSessionRepository,
+}
+
+impl RevokeSessionUseCase {
+    pub fn new(session_repository: DynSessionRepository) -> Self {
+        Self { session_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, session_id: Uuid) -> Result<(), RevokeSessionError> {
+        if !self
+            .session_repository
+            .revoke(user_id, session_id, Utc::now())
+            .await?
+        {
+            return Err(RevokeSessionError::NotFound);
+        }
+
+        Ok(())
+    }
+}
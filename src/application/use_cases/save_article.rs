@@ -0,0 +1,110 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::entities::saved_article::{SaveArticleDto, SavedArticle, SavedArticleResponse};
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use crate::domain::repositories::collection_repository::{CollectionRepositoryError, DynCollectionRepository};
+use crate::domain::repositories::saved_article_repository::{DynSavedArticleRepository, SavedArticleRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum SaveArticleError {
+    #[error("Article not found")]
+    ArticleNotFound,
+
+    #[error("Collection not found")]
+    CollectionNotFound,
+
+    #[error("Article already saved")]
+    AlreadySaved,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for SaveArticleError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        SaveArticleError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<CollectionRepositoryError> for SaveArticleError {
+    fn from(err: CollectionRepositoryError) -> Self {
+        SaveArticleError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SavedArticleRepositoryError> for SaveArticleError {
+    fn from(err: SavedArticleRepositoryError) -> Self {
+        match err {
+            SavedArticleRepositoryError::AlreadySaved => SaveArticleError::AlreadySaved,
+            SavedArticleRepositoryError::DatabaseError(msg) => SaveArticleError::RepositoryError(msg),
+        }
+    }
+}
+
+impl From<SaveArticleError> for ApplicationError {
+    fn from(err: SaveArticleError) -> Self {
+        match err {
+            SaveArticleError::ArticleNotFound => {
+                Self::new(ErrorKind::NotFound, "article_not_found", "Article not found")
+            }
+            SaveArticleError::CollectionNotFound => {
+                Self::new(ErrorKind::NotFound, "collection_not_found", "Collection not found")
+            }
+            SaveArticleError::AlreadySaved => {
+                Self::new(ErrorKind::Conflict, "article_already_saved", "Article already saved")
+            }
+            SaveArticleError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Salva um artigo para ler depois, opcionalmente já em uma coleção.
+/// Guarda uma cópia dos campos principais e coloca o item no fim da lista
+pub struct SaveArticleUseCase {
+    article_repository: DynArticleRepository,
+    saved_article_repository: DynSavedArticleRepository,
+    collection_repository: DynCollectionRepository,
+}
+
+impl SaveArticleUseCase {
+    pub fn new(
+        article_repository: DynArticleRepository,
+        saved_article_repository: DynSavedArticleRepository,
+        collection_repository: DynCollectionRepository,
+    ) -> Self {
+        Self {
+            article_repository,
+            saved_article_repository,
+            collection_repository,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, dto: SaveArticleDto) -> Result<SavedArticleResponse, SaveArticleError> {
+        let article = self
+            .article_repository
+            .find_by_id(dto.article_id)
+            .await?
+            .ok_or(SaveArticleError::ArticleNotFound)?;
+
+        if let Some(collection_id) = dto.collection_id {
+            self.collection_repository
+                .find_by_id(user_id, collection_id)
+                .await?
+                .ok_or(SaveArticleError::CollectionNotFound)?;
+        }
+
+        let position = self
+            .saved_article_repository
+            .max_position(user_id)
+            .await?
+            .map_or(0, |max| max + 1);
+
+        let saved = self
+            .saved_article_repository
+            .create(SavedArticle::from_article(user_id, &article, dto.collection_id, position))
+            .await?;
+
+        Ok(saved.into())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::application::errors::{ApplicationError, ValidationFailure};
+use crate::domain::entities::digest::{DigestSettings, DigestSettingsDto, DigestSettingsResponse};
+use crate::domain::repositories::digest_repository::{DigestRepositoryError, DynDigestRepository};
+use chrono::Utc;
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum SaveDigestSettingsError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<DigestRepositoryError> for SaveDigestSettingsError {
+    fn from(err: DigestRepositoryError) -> Self {
+        SaveDigestSettingsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SaveDigestSettingsError> for ApplicationError {
+    fn from(err: SaveDigestSettingsError) -> Self {
+        match err {
+            SaveDigestSettingsError::ValidationError(failure) => failure.into(),
+            SaveDigestSettingsError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Cria ou substitui a agenda do resumo por email do usuário
+pub struct SaveDigestSettingsUseCase {
+    digest_repository: DynDigestRepository,
+}
+
+impl SaveDigestSettingsUseCase {
+    pub fn new(digest_repository: DynDigestRepository) -> Self {
+        Self { digest_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, dto: DigestSettingsDto) -> Result<DigestSettingsResponse, SaveDigestSettingsError> {
+        dto.validate()
+            .map_err(|e| SaveDigestSettingsError::ValidationError(e.into()))?;
+
+        let settings = match self.digest_repository.find_settings(user_id).await? {
+            Some(mut settings) => {
+                settings.replace(dto);
+                settings
+            }
+            None => DigestSettings::new(user_id, dto),
+        };
+
+        let settings = self.digest_repository.save_settings(settings).await?;
+        Ok(DigestSettingsResponse::new(settings, Utc::now()))
+    }
+}
@@ -0,0 +1,113 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::domain::entities::tag::{SetArticleTagsDto, Tag, TagResponse};
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use crate::domain::repositories::tag_repository::{DynTagRepository, TagRepositoryError};
+use std::collections::HashSet;
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum SetArticleTagsError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Article not found")]
+    ArticleNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for SetArticleTagsError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        SetArticleTagsError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<TagRepositoryError> for SetArticleTagsError {
+    fn from(err: TagRepositoryError) -> Self {
+        match err {
+            TagRepositoryError::ArticleNotFound => SetArticleTagsError::ArticleNotFound,
+            _ => SetArticleTagsError::RepositoryError(err.to_string()),
+        }
+    }
+}
+
+impl From<SetArticleTagsError> for ApplicationError {
+    fn from(err: SetArticleTagsError) -> Self {
+        match err {
+            SetArticleTagsError::ValidationError(failure) => failure.into(),
+            SetArticleTagsError::ArticleNotFound => {
+                Self::new(ErrorKind::NotFound, "article_not_found", "Article not found")
+            }
+            SetArticleTagsError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Define as tags do usuário em um artigo pelos nomes, criando as que ainda não existem.
+/// Nomes repetidos (sem diferenciar maiúsculas) contam uma vez só; lista vazia remove todas
+pub struct SetArticleTagsUseCase {
+    article_repository: DynArticleRepository,
+    tag_repository: DynTagRepository,
+}
+
+impl SetArticleTagsUseCase {
+    const MAX_NAME_CHARS: usize = 50;
+
+    pub fn new(article_repository: DynArticleRepository, tag_repository: DynTagRepository) -> Self {
+        Self {
+            article_repository,
+            tag_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        article_id: Uuid,
+        dto: SetArticleTagsDto,
+    ) -> Result<Vec<TagResponse>, SetArticleTagsError> {
+        dto.validate()
+            .map_err(|e| SetArticleTagsError::ValidationError(e.into()))?;
+
+        let mut seen = HashSet::new();
+        let mut names = Vec::new();
+        for name in dto.names.iter().map(|name| Tag::normalize_name(name)) {
+            if name.is_empty() || name.chars().count() > Self::MAX_NAME_CHARS {
+                return Err(SetArticleTagsError::ValidationError(
+                    "Tag names must have between 1 and 50 characters".into(),
+                ));
+            }
+            if seen.insert(Tag::name_key(&name)) {
+                names.push(name);
+            }
+        }
+
+        if self.article_repository.find_by_id(article_id).await?.is_none() {
+            return Err(SetArticleTagsError::ArticleNotFound);
+        }
+
+        let mut tags = self.tag_repository.find_by_names(user_id, &names).await?;
+        for name in &names {
+            if tags.iter().any(|tag| Tag::name_key(&tag.name) == Tag::name_key(name)) {
+                continue;
+            }
+            match self.tag_repository.create(Tag::new(user_id, name)).await {
+                Ok(tag) => tags.push(tag),
+                // Criada por outra requisição ao mesmo tempo
+                Err(TagRepositoryError::NameTaken) => {
+                    tags.extend(self.tag_repository.find_by_names(user_id, std::slice::from_ref(name)).await?);
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        let tag_ids: Vec<Uuid> = tags.iter().map(|tag| tag.id).collect();
+        self.tag_repository.set_article_tags(user_id, article_id, &tag_ids).await?;
+
+        tags.sort_by_key(|tag| tag.name.to_lowercase());
+        Ok(tags.into_iter().map(TagResponse::from).collect())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::application::services::auth_service::AuthService;
+use crate::application::services::identity_provider::{pkce_challenge, IdentityProviderError, IdentityProviders};
+use crate::domain::entities::identity::{OidcAuthRequest, OidcAuthorizationResponse};
+use crate::domain::repositories::identity_repository::{DynIdentityRepository, IdentityRepositoryError};
+use chrono::Duration;
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum StartOidcLoginError {
+    #[error("Unknown identity provider")]
+    UnknownProvider,
+
+    #[error("Identity provider error: {0}")]
+    ProviderError(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<IdentityProviderError> for StartOidcLoginError {
+    fn from(err: IdentityProviderError) -> Self {
+        StartOidcLoginError::ProviderError(err.to_string())
+    }
+}
+
+impl From<IdentityRepositoryError> for StartOidcLoginError {
+    fn from(err: IdentityRepositoryError) -> Self {
+        StartOidcLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<StartOidcLoginError> for ApplicationError {
+    fn from(err: StartOidcLoginError) -> Self {
+        match err {
+            StartOidcLoginError::UnknownProvider => {
+                Self::new(ErrorKind::NotFound, "unknown_identity_provider", "Unknown identity provider")
+            }
+            StartOidcLoginError::ProviderError(msg) => {
+                Self::new(ErrorKind::BadGateway, "identity_provider_unavailable", "Identity provider unavailable")
+                    .with_cause(msg)
+            }
+            StartOidcLoginError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// First step of an OIDC login: creates the state, nonce and PKCE verifier
+/// and returns the provider URL the browser must be sent to
+pub struct StartOidcLoginUseCase {
+    providers: Arc<IdentityProviders>,
+    identity_repository: DynIdentityRepository,
+    request_ttl: Duration,
+}
+
+impl StartOidcLoginUseCase {
+    pub fn new(
+        providers: Arc<IdentityProviders>,
+        identity_repository: DynIdentityRepository,
+        request_ttl: Duration,
+    ) -> Self {
+        Self {
+            providers,
+            identity_repository,
+            request_ttl,
+        }
+    }
+
+    /// Names of the configured providers
+    pub fn available_providers(&self) -> Vec<String> {
+        self.providers.names()
+    }
+
+    pub async fn execute(&self, provider_name: &str) -> Result<OidcAuthorizationResponse, StartOidcLoginError> {
+        let provider = self
+            .providers
+            .get(provider_name)
+            .ok_or(StartOidcLoginError::UnknownProvider)?;
+
+        let state = AuthService::generate_opaque_token();
+        let nonce = AuthService::generate_opaque_token();
+        let code_verifier = AuthService::generate_opaque_token();
+
+        let authorization_url = provider
+            .authorization_url(&state, &nonce, &pkce_challenge(&code_verifier))
+            .await?;
+
+        // Only the state hash is stored; the verifier never leaves the server
+        self.identity_repository
+            .save_auth_request(OidcAuthRequest::new(
+                AuthService::hash_token(&state),
+                provider_name.to_string(),
+                code_verifier,
+                nonce,
+                self.request_ttl,
+            ))
+            .await?;
+
+        Ok(OidcAuthorizationResponse {
+            authorization_url,
+            state,
+        })
+    }
+}
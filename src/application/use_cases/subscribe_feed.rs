@@ -0,0 +1,67 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::domain::entities::subscription::{CreateSubscriptionDto, Subscription, SubscriptionResponse};
+use crate::domain::repositories::subscription_repository::{DynSubscriptionRepository, SubscriptionRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum SubscribeFeedError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Already subscribed to this feed")]
+    AlreadySubscribed,
+
+    #[error("Feed not found")]
+    FeedNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<SubscriptionRepositoryError> for SubscribeFeedError {
+    fn from(err: SubscriptionRepositoryError) -> Self {
+        match err {
+            SubscriptionRepositoryError::AlreadySubscribed => SubscribeFeedError::AlreadySubscribed,
+            SubscriptionRepositoryError::FeedNotFound => SubscribeFeedError::FeedNotFound,
+            SubscriptionRepositoryError::DatabaseError(msg) => SubscribeFeedError::RepositoryError(msg),
+        }
+    }
+}
+
+impl From<SubscribeFeedError> for ApplicationError {
+    fn from(err: SubscribeFeedError) -> Self {
+        match err {
+            SubscribeFeedError::ValidationError(failure) => failure.into(),
+            SubscribeFeedError::AlreadySubscribed => {
+                Self::new(ErrorKind::Conflict, "already_subscribed", "Already subscribed to this feed")
+            }
+            SubscribeFeedError::FeedNotFound => Self::new(ErrorKind::NotFound, "feed_not_found", "Feed not found"),
+            SubscribeFeedError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Assina um feed, com título e pasta opcionais
+pub struct SubscribeFeedUseCase {
+    subscription_repository: DynSubscriptionRepository,
+}
+
+impl SubscribeFeedUseCase {
+    pub fn new(subscription_repository: DynSubscriptionRepository) -> Self {
+        Self { subscription_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, dto: CreateSubscriptionDto) -> Result<SubscriptionResponse, SubscribeFeedError> {
+        dto.validate()
+            .map_err(|e| SubscribeFeedError::ValidationError(e.into()))?;
+
+        let subscription = self
+            .subscription_repository
+            .create(Subscription::new(user_id, dto))
+            .await?;
+
+        Ok(subscription.into())
+    }
+}
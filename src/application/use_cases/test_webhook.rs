@@ -0,0 +1,64 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::application::services::webhook_dispatcher::{WebhookDispatcher, WebhookDispatcherError};
+use crate::domain::entities::webhook::WebhookDeliveryResponse;
+use crate::domain::repositories::webhook_repository::{DynWebhookRepository, WebhookRepositoryError};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum TestWebhookError {
+    #[error("Webhook not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<WebhookRepositoryError> for TestWebhookError {
+    fn from(err: WebhookRepositoryError) -> Self {
+        TestWebhookError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<WebhookDispatcherError> for TestWebhookError {
+    fn from(err: WebhookDispatcherError) -> Self {
+        TestWebhookError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<TestWebhookError> for ApplicationError {
+    fn from(err: TestWebhookError) -> Self {
+        match err {
+            TestWebhookError::NotFound => Self::new(ErrorKind::NotFound, "webhook_not_found", "Webhook not found"),
+            TestWebhookError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Envia um evento `webhook.test` assinado na hora, mesmo com o webhook desativado,
+/// e retorna o resultado da tentativa
+pub struct TestWebhookUseCase {
+    webhook_repository: DynWebhookRepository,
+    webhook_dispatcher: Arc<WebhookDispatcher>,
+}
+
+impl TestWebhookUseCase {
+    pub fn new(webhook_repository: DynWebhookRepository, webhook_dispatcher: Arc<WebhookDispatcher>) -> Self {
+        Self {
+            webhook_repository,
+            webhook_dispatcher,
+        }
+    }
+
+    pub async fn execute(&self, id: Uuid) -> Result<WebhookDeliveryResponse, TestWebhookError> {
+        let subscription = self
+            .webhook_repository
+            .find_by_id(id)
+            .await?
+            .ok_or(TestWebhookError::NotFound)?;
+
+        let delivery = self.webhook_dispatcher.test_fire(&subscription).await?;
+        Ok(WebhookDeliveryResponse::from(delivery))
+    }
+}
@@ -0,0 +1,66 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::application::services::login_throttle_service::LoginThrottleService;
+use crate::domain::entities::login_throttle::ThrottleScope;
+use crate::domain::repositories::login_throttle_repository::LoginThrottleRepositoryError;
+use crate::domain::repositories::user_repository::{DynUserRepository, UserRepositoryError};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum UnlockUserError {
+    #[error("User not found")]
+    UserNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<UserRepositoryError> for UnlockUserError {
+    fn from(err: UserRepositoryError) -> Self {
+        UnlockUserError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<LoginThrottleRepositoryError> for UnlockUserError {
+    fn from(err: LoginThrottleRepositoryError) -> Self {
+        UnlockUserError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UnlockUserError> for ApplicationError {
+    fn from(err: UnlockUserError) -> Self {
+        match err {
+            UnlockUserError::UserNotFound => Self::new(ErrorKind::NotFound, "user_not_found", "User not found"),
+            UnlockUserError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Remove o bloqueio de login de uma conta (uso administrativo)
+pub struct UnlockUserUseCase {
+    user_repository: DynUserRepository,
+    throttle_service: Arc<LoginThrottleService>,
+}
+
+impl UnlockUserUseCase {
+    pub fn new(user_repository: DynUserRepository, throttle_service: Arc<LoginThrottleService>) -> Self {
+        Self {
+            user_repository,
+            throttle_service,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<(), UnlockUserError> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(UnlockUserError::UserNotFound)?;
+
+        self.throttle_service
+            .reset(ThrottleScope::Account, &user_id.to_string())
+            .await?;
+
+        Ok(())
+    }
+}
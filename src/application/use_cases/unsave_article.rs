@@ -0,0 +1,49 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::repositories::saved_article_repository::{DynSavedArticleRepository, SavedArticleRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum UnsaveArticleError {
+    #[error("Saved article not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<SavedArticleRepositoryError> for UnsaveArticleError {
+    fn from(err: SavedArticleRepositoryError) -> Self {
+        UnsaveArticleError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UnsaveArticleError> for ApplicationError {
+    fn from(err: UnsaveArticleError) -> Self {
+        match err {
+            UnsaveArticleError::NotFound => {
+                Self::new(ErrorKind::NotFound, "saved_article_not_found", "Saved article not found")
+            }
+            UnsaveArticleError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Remove um artigo da lista de salvos (e da coleção em que estiver)
+pub struct UnsaveArticleUseCase {
+    saved_article_repository: DynSavedArticleRepository,
+}
+
+impl UnsaveArticleUseCase {
+    pub fn new(saved_article_repository: DynSavedArticleRepository) -> Self {
+        Self { saved_article_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, saved_id: Uuid) -> Result<(), UnsaveArticleError> {
+        if !self.saved_article_repository.delete(user_id, saved_id).await? {
+            return Err(UnsaveArticleError::NotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::domain::repositories::subscription_repository::{DynSubscriptionRepository, SubscriptionRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum UnsubscribeFeedError {
+    #[error("Subscription not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<SubscriptionRepositoryError> for UnsubscribeFeedError {
+    fn from(err: SubscriptionRepositoryError) -> Self {
+        UnsubscribeFeedError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UnsubscribeFeedError> for ApplicationError {
+    fn from(err: UnsubscribeFeedError) -> Self {
+        match err {
+            UnsubscribeFeedError::NotFound => {
+                Self::new(ErrorKind::NotFound, "subscription_not_found", "Subscription not found")
+            }
+            UnsubscribeFeedError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Cancela uma assinatura; os artigos do feed deixam de aparecer na timeline
+pub struct UnsubscribeFeedUseCase {
+    subscription_repository: DynSubscriptionRepository,
+}
+
+impl UnsubscribeFeedUseCase {
+    pub fn new(subscription_repository: DynSubscriptionRepository) -> Self {
+        Self { subscription_repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, subscription_id: Uuid) -> Result<(), UnsubscribeFeedError> {
+        if !self.subscription_repository.delete(user_id, subscription_id).await? {
+            return Err(UnsubscribeFeedError::NotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::application::services::webhook_target_policy::WebhookTargetPolicy;
+use crate::application::use_cases::create_alert_rule::check_rule;
+use crate::domain::entities::alert::{AlertRuleDto, AlertRuleResponse};
+use crate::domain::repositories::alert_rule_repository::{AlertRuleRepositoryError, DynAlertRuleRepository};
+use crate::domain::repositories::article_category_repository::{
+    ArticleCategoryRepositoryError, DynArticleCategoryRepository,
+};
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum UpdateAlertRuleError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Alert rule not found")]
+    NotFound,
+
+    #[error("Category not found")]
+    CategoryNotFound,
+
+    #[error("Feed not found")]
+    FeedNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<AlertRuleRepositoryError> for UpdateAlertRuleError {
+    fn from(err: AlertRuleRepositoryError) -> Self {
+        match err {
+            AlertRuleRepositoryError::FeedNotFound => UpdateAlertRuleError::FeedNotFound,
+            AlertRuleRepositoryError::DatabaseError(msg) => UpdateAlertRuleError::RepositoryError(msg),
+        }
+    }
+}
+
+impl From<ArticleCategoryRepositoryError> for UpdateAlertRuleError {
+    fn from(err: ArticleCategoryRepositoryError) -> Self {
+        UpdateAlertRuleError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UpdateAlertRuleError> for ApplicationError {
+    fn from(err: UpdateAlertRuleError) -> Self {
+        match err {
+            UpdateAlertRuleError::ValidationError(failure) => failure.into(),
+            UpdateAlertRuleError::NotFound => {
+                Self::new(ErrorKind::NotFound, "alert_rule_not_found", "Alert rule not found")
+            }
+            UpdateAlertRuleError::CategoryNotFound => {
+                Self::new(ErrorKind::NotFound, "category_not_found", "Category not found")
+            }
+            UpdateAlertRuleError::FeedNotFound => Self::new(ErrorKind::NotFound, "feed_not_found", "Feed not found"),
+            UpdateAlertRuleError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Substitui uma regra de alerta; as notificações já criadas são mantidas
+pub struct UpdateAlertRuleUseCase {
+    alert_rule_repository: DynAlertRuleRepository,
+    category_repository: DynArticleCategoryRepository,
+    webhook_targets: WebhookTargetPolicy,
+}
+
+impl UpdateAlertRuleUseCase {
+    pub fn new(
+        alert_rule_repository: DynAlertRuleRepository,
+        category_repository: DynArticleCategoryRepository,
+        webhook_targets: WebhookTargetPolicy,
+    ) -> Self {
+        Self {
+            alert_rule_repository,
+            category_repository,
+            webhook_targets,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, rule_id: Uuid, dto: AlertRuleDto) -> Result<AlertRuleResponse, UpdateAlertRuleError> {
+        dto.validate()
+            .map_err(|e| UpdateAlertRuleError::ValidationError(e.into()))?;
+
+        let mut rule = self
+            .alert_rule_repository
+            .find_by_id(user_id, rule_id)
+            .await?
+            .ok_or(UpdateAlertRuleError::NotFound)?;
+
+        rule.replace(dto);
+        check_rule(&rule, self.webhook_targets).map_err(|e| UpdateAlertRuleError::ValidationError(e.into()))?;
+
+        if let Some(category_id) = rule.category_id {
+            self.category_repository
+                .find_by_id(category_id)
+                .await?
+                .ok_or(UpdateAlertRuleError::CategoryNotFound)?;
+        }
+
+        let rule = self.alert_rule_repository.update(rule).await?;
+        Ok(rule.into())
+    }
+}
@@ -0,0 +1,99 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::domain::entities::annotation::{AnnotationResponse, UpdateAnnotationDto};
+use crate::domain::repositories::annotation_repository::{AnnotationRepositoryError, DynAnnotationRepository};
+use crate::domain::repositories::article_repository::{ArticleRepositoryError, DynArticleRepository};
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum UpdateAnnotationError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Annotation not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ArticleRepositoryError> for UpdateAnnotationError {
+    fn from(err: ArticleRepositoryError) -> Self {
+        UpdateAnnotationError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AnnotationRepositoryError> for UpdateAnnotationError {
+    fn from(err: AnnotationRepositoryError) -> Self {
+        UpdateAnnotationError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UpdateAnnotationError> for ApplicationError {
+    fn from(err: UpdateAnnotationError) -> Self {
+        match err {
+            UpdateAnnotationError::ValidationError(failure) => failure.into(),
+            UpdateAnnotationError::NotFound => {
+                Self::new(ErrorKind::NotFound, "annotation_not_found", "Annotation not found")
+            }
+            UpdateAnnotationError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Edita a nota e/ou move o destaque de uma anotação do usuário
+pub struct UpdateAnnotationUseCase {
+    article_repository: DynArticleRepository,
+    annotation_repository: DynAnnotationRepository,
+}
+
+impl UpdateAnnotationUseCase {
+    pub fn new(article_repository: DynArticleRepository, annotation_repository: DynAnnotationRepository) -> Self {
+        Self {
+            article_repository,
+            annotation_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        article_id: Uuid,
+        annotation_id: Uuid,
+        dto: UpdateAnnotationDto,
+    ) -> Result<AnnotationResponse, UpdateAnnotationError> {
+        dto.validate()
+            .map_err(|e| UpdateAnnotationError::ValidationError(e.into()))?;
+
+        let mut annotation = self
+            .annotation_repository
+            .find_by_id(user_id, annotation_id)
+            .await?
+            .filter(|annotation| annotation.article_id == article_id)
+            .ok_or(UpdateAnnotationError::NotFound)?;
+
+        let article = self
+            .article_repository
+            .find_by_id(article_id)
+            .await?
+            .ok_or(UpdateAnnotationError::NotFound)?;
+
+        let anchor = match dto.anchor {
+            Some(anchor) => Some(anchor.anchored_in(&article.content).ok_or_else(|| {
+                UpdateAnnotationError::ValidationError("Quote not found in article content".into())
+            })?),
+            None => None,
+        };
+
+        annotation.update(anchor, dto.note);
+        if annotation.is_empty() {
+            return Err(UpdateAnnotationError::ValidationError(
+                "An annotation needs a highlighted quote or a note".into(),
+            ));
+        }
+
+        let updated = self.annotation_repository.update(annotation).await?;
+        Ok(AnnotationResponse::resolve(updated, &article.content))
+    }
+}
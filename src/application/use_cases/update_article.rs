@@ -1,15 +1,21 @@
+use crate::application::services::alert_service::AlertService;
 use crate::domain::entities::article::{ArticleResponse, UpdateArticleDto};
+use crate::domain::entities::webhook::WebhookEventType;
 use crate::domain::repositories::article_repository::{ArticleRepository, ArticleRepositoryError};
 use std::sync::Arc;
 use uuid::Uuid;
 
 pub struct UpdateArticleUseCase {
     article_repository: Arc<dyn ArticleRepository>,
+    alert_service: Arc<AlertService>,
 }
 
 impl UpdateArticleUseCase {
-    pub fn new(article_repository: Arc<dyn ArticleRepository>) -> Self {
-        Self { article_repository }
+    pub fn new(article_repository: Arc<dyn ArticleRepository>, alert_service: Arc<AlertService>) -> Self {
+        Self {
+            article_repository,
+            alert_service,
+        }
     }
 
     pub async fn execute(&self, id: Uuid, dto: UpdateArticleDto) -> Result<ArticleResponse, ArticleRepositoryError> {
@@ -19,11 +25,23 @@ impl UpdateArticleUseCase {
             .await?
             .ok_or(ArticleRepositoryError::NotFound)?;
         
+        let event_type = if dto.is_enrichment() {
+            WebhookEventType::ArticleEnriched
+        } else {
+            WebhookEventType::ArticleUpdated
+        };
+
         // Atualiza os campos
         article.update(dto);
         
-        // Salva no banco
-        let updated = self.article_repository.update(article).await?;
+        // Salva no banco, com o evento no outbox na mesma transação
+        let updated = self.article_repository.update_with_event(article, event_type).await?;
+
+        // O enriquecimento (nota, categoria) pode fazer o artigo passar a atender a uma regra
+        if let Err(e) = self.alert_service.evaluate(&updated).await {
+            log::error!("Failed to evaluate alerts for article {}: {:?}", updated.id, e);
+        }
+
         Ok(ArticleResponse::from(updated))
     }
 }
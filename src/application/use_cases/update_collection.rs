@@ -0,0 +1,89 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::application::services::auth_service::AuthService;
+use crate::domain::entities::collection::{CollectionResponse, UpdateCollectionDto};
+use crate::domain::repositories::collection_repository::{CollectionRepositoryError, DynCollectionRepository};
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum UpdateCollectionError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Collection not found")]
+    NotFound,
+
+    #[error("A collection with this name already exists")]
+    NameTaken,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<CollectionRepositoryError> for UpdateCollectionError {
+    fn from(err: CollectionRepositoryError) -> Self {
+        match err {
+            CollectionRepositoryError::NameTaken => UpdateCollectionError::NameTaken,
+            CollectionRepositoryError::DatabaseError(msg) => UpdateCollectionError::RepositoryError(msg),
+        }
+    }
+}
+
+impl From<UpdateCollectionError> for ApplicationError {
+    fn from(err: UpdateCollectionError) -> Self {
+        match err {
+            UpdateCollectionError::ValidationError(failure) => failure.into(),
+            UpdateCollectionError::NotFound => {
+                Self::new(ErrorKind::NotFound, "collection_not_found", "Collection not found")
+            }
+            e @ UpdateCollectionError::NameTaken => {
+                Self::new(ErrorKind::Conflict, "collection_name_taken", e.to_string())
+            }
+            UpdateCollectionError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Renomeia uma coleção e/ou liga e desliga o link de compartilhamento
+pub struct UpdateCollectionUseCase {
+    collection_repository: DynCollectionRepository,
+}
+
+impl UpdateCollectionUseCase {
+    pub fn new(collection_repository: DynCollectionRepository) -> Self {
+        Self { collection_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        collection_id: Uuid,
+        dto: UpdateCollectionDto,
+    ) -> Result<CollectionResponse, UpdateCollectionError> {
+        dto.validate()
+            .map_err(|e| UpdateCollectionError::ValidationError(e.into()))?;
+
+        let mut collection = self
+            .collection_repository
+            .find_by_id(user_id, collection_id)
+            .await?
+            .ok_or(UpdateCollectionError::NotFound)?;
+
+        if let Some(name) = dto.name {
+            if name.trim().is_empty() {
+                return Err(UpdateCollectionError::ValidationError("Name is required".into()));
+            }
+            collection.rename(&name);
+        }
+
+        match dto.shared {
+            Some(true) => collection.share(AuthService::generate_opaque_token()),
+            Some(false) => collection.unshare(),
+            None => {}
+        }
+
+        let collection = self.collection_repository.update(collection).await?;
+        Ok(collection.into())
+    }
+}
@@ -0,0 +1,151 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::application::services::auth_service::AuthService;
+use crate::application::services::mailer::{DynMailer, EmailMessage, MailerError};
+use crate::domain::entities::email_change_request::EmailChangeRequest;
+use crate::domain::entities::user::{ProfileResponse, UpdateProfileDto};
+use crate::domain::repositories::email_change_request_repository::{
+    DynEmailChangeRequestRepository, EmailChangeRequestRepositoryError,
+};
+use crate::domain::repositories::user_repository::{DynUserRepository, UserRepositoryError};
+use chrono::Duration;
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Validade do token de confirmação de troca de email
+const EMAIL_CHANGE_TTL_HOURS: i64 = 24;
+
+#[derive(Debug, Error)]
+pub enum UpdateProfileError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Email already in use")]
+    EmailAlreadyExists,
+
+    #[error("User not found")]
+    UserNotFound,
+
+    #[error("Failed to send verification email: {0}")]
+    MailerError(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<UserRepositoryError> for UpdateProfileError {
+    fn from(err: UserRepositoryError) -> Self {
+        match err {
+            UserRepositoryError::EmailAlreadyExists => UpdateProfileError::EmailAlreadyExists,
+            UserRepositoryError::NotFound => UpdateProfileError::UserNotFound,
+            _ => UpdateProfileError::RepositoryError(err.to_string()),
+        }
+    }
+}
+
+impl From<EmailChangeRequestRepositoryError> for UpdateProfileError {
+    fn from(err: EmailChangeRequestRepositoryError) -> Self {
+        UpdateProfileError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<MailerError> for UpdateProfileError {
+    fn from(err: MailerError) -> Self {
+        UpdateProfileError::MailerError(err.to_string())
+    }
+}
+
+impl From<UpdateProfileError> for ApplicationError {
+    fn from(err: UpdateProfileError) -> Self {
+        match err {
+            UpdateProfileError::ValidationError(failure) => failure.into(),
+            UpdateProfileError::EmailAlreadyExists => {
+                Self::new(ErrorKind::Conflict, "email_taken", "Email already in use")
+            }
+            UpdateProfileError::UserNotFound => Self::new(ErrorKind::NotFound, "user_not_found", "User not found"),
+            UpdateProfileError::MailerError(msg) | UpdateProfileError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Atualiza nome e/ou email do usuário.
+///
+/// O nome é alterado imediatamente; a troca de email só é aplicada depois que o
+/// novo endereço confirmar o token enviado por email.
+pub struct UpdateProfileUseCase {
+    user_repository: DynUserRepository,
+    email_change_repository: DynEmailChangeRequestRepository,
+    mailer: DynMailer,
+}
+
+impl UpdateProfileUseCase {
+    pub fn new(
+        user_repository: DynUserRepository,
+        email_change_repository: DynEmailChangeRequestRepository,
+        mailer: DynMailer,
+    ) -> Self {
+        Self {
+            user_repository,
+            email_change_repository,
+            mailer,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, dto: UpdateProfileDto) -> Result<ProfileResponse, UpdateProfileError> {
+        dto.validate()
+            .map_err(|e| UpdateProfileError::ValidationError(e.into()))?;
+
+        let mut user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(UpdateProfileError::UserNotFound)?;
+
+        if let Some(name) = dto.name {
+            user.name = name;
+            user = self.user_repository.update(user).await?;
+        }
+
+        let mut pending_email = None;
+
+        if let Some(new_email) = dto.email {
+            if new_email == user.email {
+                // Voltar ao email atual cancela uma troca pendente
+                self.email_change_repository.delete_by_user(user_id).await?;
+            } else {
+                if self.user_repository.find_by_email(&new_email).await?.is_some() {
+                    return Err(UpdateProfileError::EmailAlreadyExists);
+                }
+
+                let token = AuthService::generate_opaque_token();
+                let request = EmailChangeRequest::new(
+                    user_id,
+                    new_email.clone(),
+                    AuthService::hash_token(&token),
+                    Duration::hours(EMAIL_CHANGE_TTL_HOURS),
+                );
+                self.email_change_repository.replace(request).await?;
+
+                self.mailer
+                    .send(EmailMessage {
+                        to: new_email.clone(),
+                        subject: "Confirme seu novo email no Nexight".to_string(),
+                        text_body: format!(
+                            "Olá {},\n\nUse o código abaixo para confirmar a troca do seu email \
+                             (válido por {} horas):\n\n{}\n\nSe você não solicitou esta troca, ignore esta mensagem.",
+                            user.name, EMAIL_CHANGE_TTL_HOURS, token
+                        ),
+                        html_body: None,
+                    })
+                    .await?;
+
+                pending_email = Some(new_email);
+            }
+        }
+
+        Ok(ProfileResponse {
+            user: user.into(),
+            pending_email,
+        })
+    }
+}
@@ -0,0 +1,140 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::domain::entities::smart_folder::{SmartFolderCriteria, SmartFolderResponse, UpdateSmartFolderDto};
+use crate::domain::repositories::article_category_repository::{
+    ArticleCategoryRepositoryError, DynArticleCategoryRepository,
+};
+use crate::domain::repositories::smart_folder_repository::{DynSmartFolderRepository, SmartFolderRepositoryError};
+use crate::domain::repositories::tag_repository::{DynTagRepository, TagRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum UpdateSmartFolderError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Smart folder not found")]
+    NotFound,
+
+    #[error("A smart folder with this name already exists")]
+    NameTaken,
+
+    #[error("Tag not found")]
+    TagNotFound,
+
+    #[error("Category not found")]
+    CategoryNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<SmartFolderRepositoryError> for UpdateSmartFolderError {
+    fn from(err: SmartFolderRepositoryError) -> Self {
+        match err {
+            SmartFolderRepositoryError::NameTaken => UpdateSmartFolderError::NameTaken,
+            SmartFolderRepositoryError::DatabaseError(msg) => UpdateSmartFolderError::RepositoryError(msg),
+        }
+    }
+}
+
+impl From<TagRepositoryError> for UpdateSmartFolderError {
+    fn from(err: TagRepositoryError) -> Self {
+        UpdateSmartFolderError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ArticleCategoryRepositoryError> for UpdateSmartFolderError {
+    fn from(err: ArticleCategoryRepositoryError) -> Self {
+        UpdateSmartFolderError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UpdateSmartFolderError> for ApplicationError {
+    fn from(err: UpdateSmartFolderError) -> Self {
+        match err {
+            UpdateSmartFolderError::ValidationError(failure) => failure.into(),
+            UpdateSmartFolderError::NotFound => {
+                Self::new(ErrorKind::NotFound, "smart_folder_not_found", "Smart folder not found")
+            }
+            e @ UpdateSmartFolderError::NameTaken => {
+                Self::new(ErrorKind::Conflict, "smart_folder_name_taken", e.to_string())
+            }
+            UpdateSmartFolderError::TagNotFound => Self::new(ErrorKind::NotFound, "tag_not_found", "Tag not found"),
+            UpdateSmartFolderError::CategoryNotFound => {
+                Self::new(ErrorKind::NotFound, "category_not_found", "Category not found")
+            }
+            UpdateSmartFolderError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Renomeia uma pasta inteligente e/ou troca seus critérios
+pub struct UpdateSmartFolderUseCase {
+    smart_folder_repository: DynSmartFolderRepository,
+    tag_repository: DynTagRepository,
+    category_repository: DynArticleCategoryRepository,
+}
+
+impl UpdateSmartFolderUseCase {
+    pub fn new(
+        smart_folder_repository: DynSmartFolderRepository,
+        tag_repository: DynTagRepository,
+        category_repository: DynArticleCategoryRepository,
+    ) -> Self {
+        Self {
+            smart_folder_repository,
+            tag_repository,
+            category_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        folder_id: Uuid,
+        dto: UpdateSmartFolderDto,
+    ) -> Result<SmartFolderResponse, UpdateSmartFolderError> {
+        dto.validate()
+            .map_err(|e| UpdateSmartFolderError::ValidationError(e.into()))?;
+
+        let mut folder = self
+            .smart_folder_repository
+            .find_by_id(user_id, folder_id)
+            .await?
+            .ok_or(UpdateSmartFolderError::NotFound)?;
+
+        if let Some(name) = dto.name {
+            if name.trim().is_empty() {
+                return Err(UpdateSmartFolderError::ValidationError("Name is required".into()));
+            }
+            folder.rename(&name);
+        }
+
+        if let Some(criteria) = dto.criteria {
+            self.check_references(user_id, &criteria).await?;
+            folder.set_criteria(criteria);
+        }
+
+        let folder = self.smart_folder_repository.update(folder).await?;
+        Ok(folder.into())
+    }
+
+    /// A tag precisa ser do usuário e a categoria precisa existir
+    async fn check_references(&self, user_id: Uuid, criteria: &SmartFolderCriteria) -> Result<(), UpdateSmartFolderError> {
+        if let Some(tag_id) = criteria.tag_id {
+            self.tag_repository
+                .find_by_id(user_id, tag_id)
+                .await?
+                .ok_or(UpdateSmartFolderError::TagNotFound)?;
+        }
+        if let Some(category_id) = criteria.category_id {
+            self.category_repository
+                .find_by_id(category_id)
+                .await?
+                .ok_or(UpdateSmartFolderError::CategoryNotFound)?;
+        }
+        Ok(())
+    }
+}
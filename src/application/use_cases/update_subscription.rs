@@ -0,0 +1,68 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::domain::entities::subscription::{SubscriptionResponse, UpdateSubscriptionDto};
+use crate::domain::repositories::subscription_repository::{DynSubscriptionRepository, SubscriptionRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum UpdateSubscriptionError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Subscription not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<SubscriptionRepositoryError> for UpdateSubscriptionError {
+    fn from(err: SubscriptionRepositoryError) -> Self {
+        UpdateSubscriptionError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UpdateSubscriptionError> for ApplicationError {
+    fn from(err: UpdateSubscriptionError) -> Self {
+        match err {
+            UpdateSubscriptionError::ValidationError(failure) => failure.into(),
+            UpdateSubscriptionError::NotFound => {
+                Self::new(ErrorKind::NotFound, "subscription_not_found", "Subscription not found")
+            }
+            UpdateSubscriptionError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Renomeia uma assinatura ou a move de pasta
+pub struct UpdateSubscriptionUseCase {
+    subscription_repository: DynSubscriptionRepository,
+}
+
+impl UpdateSubscriptionUseCase {
+    pub fn new(subscription_repository: DynSubscriptionRepository) -> Self {
+        Self { subscription_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        subscription_id: Uuid,
+        dto: UpdateSubscriptionDto,
+    ) -> Result<SubscriptionResponse, UpdateSubscriptionError> {
+        dto.validate()
+            .map_err(|e| UpdateSubscriptionError::ValidationError(e.into()))?;
+
+        let mut subscription = self
+            .subscription_repository
+            .find_by_id(user_id, subscription_id)
+            .await?
+            .ok_or(UpdateSubscriptionError::NotFound)?;
+
+        subscription.apply(dto);
+
+        let subscription = self.subscription_repository.update(subscription).await?;
+        Ok(subscription.into())
+    }
+}
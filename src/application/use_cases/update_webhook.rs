@@ -0,0 +1,67 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::application::services::webhook_target_policy::WebhookTargetPolicy;
+use crate::application::use_cases::create_webhook::check_subscription;
+use crate::domain::entities::webhook::{WebhookSubscriptionDto, WebhookSubscriptionResponse};
+use crate::domain::repositories::webhook_repository::{DynWebhookRepository, WebhookRepositoryError};
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum UpdateWebhookError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Webhook not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<WebhookRepositoryError> for UpdateWebhookError {
+    fn from(err: WebhookRepositoryError) -> Self {
+        UpdateWebhookError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UpdateWebhookError> for ApplicationError {
+    fn from(err: UpdateWebhookError) -> Self {
+        match err {
+            UpdateWebhookError::ValidationError(failure) => failure.into(),
+            UpdateWebhookError::NotFound => Self::new(ErrorKind::NotFound, "webhook_not_found", "Webhook not found"),
+            UpdateWebhookError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+/// Substitui nome, URL, eventos e estado de um webhook; o segredo não muda
+pub struct UpdateWebhookUseCase {
+    webhook_repository: DynWebhookRepository,
+    webhook_targets: WebhookTargetPolicy,
+}
+
+impl UpdateWebhookUseCase {
+    pub fn new(webhook_repository: DynWebhookRepository, webhook_targets: WebhookTargetPolicy) -> Self {
+        Self {
+            webhook_repository,
+            webhook_targets,
+        }
+    }
+
+    pub async fn execute(&self, id: Uuid, dto: WebhookSubscriptionDto) -> Result<WebhookSubscriptionResponse, UpdateWebhookError> {
+        dto.validate()
+            .map_err(|e| UpdateWebhookError::ValidationError(e.into()))?;
+
+        let mut subscription = self
+            .webhook_repository
+            .find_by_id(id)
+            .await?
+            .ok_or(UpdateWebhookError::NotFound)?;
+        subscription.replace(dto);
+        check_subscription(&subscription, self.webhook_targets).map_err(|e| UpdateWebhookError::ValidationError(e.into()))?;
+
+        let updated = self.webhook_repository.update(subscription).await?;
+        Ok(WebhookSubscriptionResponse::from(updated))
+    }
+}
@@ -0,0 +1,216 @@
+use crate::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use crate::application::services::auth_service::{AuthError, AuthService};
+use crate::application::services::login_throttle_service::LoginThrottleService;
+use crate::application::services::session_service::{SessionError, SessionService};
+use crate::application::services::totp_service::TotpService;
+use crate::application::use_cases::login_user::LoginContext;
+use crate::domain::entities::login_throttle::ThrottleScope;
+use crate::domain::entities::mfa::VerifyMfaDto;
+use crate::domain::entities::user::AuthResponse;
+use crate::domain::repositories::login_throttle_repository::LoginThrottleRepositoryError;
+use crate::domain::repositories::mfa_repository::{DynMfaRepository, MfaRepositoryError};
+use crate::domain::repositories::user_repository::{DynUserRepository, UserRepositoryError};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Error)]
+pub enum VerifyMfaLoginError {
+    #[error("Validation error: {0}")]
+    ValidationError(ValidationFailure),
+
+    #[error("Invalid or expired MFA token")]
+    InvalidMfaToken,
+
+    #[error("Invalid verification code")]
+    InvalidCode,
+
+    #[error("Account is inactive")]
+    AccountInactive,
+
+    #[error("Too many failed attempts, retry in {retry_after_seconds} seconds")]
+    AccountLocked { retry_after_seconds: i64 },
+
+    #[error("Failed to generate token: {0}")]
+    TokenError(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<UserRepositoryError> for VerifyMfaLoginError {
+    fn from(err: UserRepositoryError) -> Self {
+        VerifyMfaLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<MfaRepositoryError> for VerifyMfaLoginError {
+    fn from(err: MfaRepositoryError) -> Self {
+        VerifyMfaLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<LoginThrottleRepositoryError> for VerifyMfaLoginError {
+    fn from(err: LoginThrottleRepositoryError) -> Self {
+        VerifyMfaLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuthError> for VerifyMfaLoginError {
+    fn from(err: AuthError) -> Self {
+        VerifyMfaLoginError::TokenError(err.to_string())
+    }
+}
+
+impl From<SessionError> for VerifyMfaLoginError {
+    fn from(err: SessionError) -> Self {
+        match err {
+            SessionError::TokenError(msg) => VerifyMfaLoginError::TokenError(msg),
+            SessionError::RepositoryError(msg) => VerifyMfaLoginError::RepositoryError(msg),
+        }
+    }
+}
+
+impl From<VerifyMfaLoginError> for ApplicationError {
+    fn from(err: VerifyMfaLoginError) -> Self {
+        match err {
+            VerifyMfaLoginError::ValidationError(failure) => failure.into(),
+            VerifyMfaLoginError::InvalidMfaToken => {
+                Self::new(ErrorKind::Unauthorized, "invalid_mfa_token", "Invalid or expired MFA token")
+            }
+            VerifyMfaLoginError::InvalidCode => {
+                Self::new(ErrorKind::Unauthorized, "invalid_mfa_code", "Invalid verification code")
+            }
+            VerifyMfaLoginError::AccountInactive => {
+                Self::new(ErrorKind::Forbidden, "account_inactive", "Account is inactive")
+            }
+            VerifyMfaLoginError::AccountLocked { retry_after_seconds } => {
+                Self::new(ErrorKind::TooManyRequests, "account_locked", "Too many failed attempts")
+                    .with_retry_after(retry_after_seconds)
+            }
+            VerifyMfaLoginError::TokenError(msg) | VerifyMfaLoginError::RepositoryError(msg) => Self::internal(msg),
+        }
+    }
+}
+
+impl VerifyMfaLoginError {
+    fn locked_until(until: DateTime<Utc>) -> Self {
+        let retry_after_seconds = (until - Utc::now()).num_seconds().max(1);
+        VerifyMfaLoginError::AccountLocked { retry_after_seconds }
+    }
+}
+
+/// Segunda etapa do login: troca o token "mfa pending" e um código válido por um token de acesso
+pub struct VerifyMfaLoginUseCase {
+    user_repository: DynUserRepository,
+    mfa_repository: DynMfaRepository,
+    auth_service: Arc<AuthService>,
+    totp_service: Arc<TotpService>,
+    throttle_service: Arc<LoginThrottleService>,
+    session_service: Arc<SessionService>,
+}
+
+impl VerifyMfaLoginUseCase {
+    pub fn new(
+        user_repository: DynUserRepository,
+        mfa_repository: DynMfaRepository,
+        auth_service: Arc<AuthService>,
+        totp_service: Arc<TotpService>,
+        throttle_service: Arc<LoginThrottleService>,
+        session_service: Arc<SessionService>,
+    ) -> Self {
+        Self {
+            user_repository,
+            mfa_repository,
+            auth_service,
+            totp_service,
+            throttle_service,
+            session_service,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        dto: VerifyMfaDto,
+        context: LoginContext,
+    ) -> Result<AuthResponse, VerifyMfaLoginError> {
+        dto.validate()
+            .map_err(|e| VerifyMfaLoginError::ValidationError(e.into()))?;
+
+        let claims = self
+            .auth_service
+            .verify_mfa_token(&dto.mfa_token)
+            .map_err(|_| VerifyMfaLoginError::InvalidMfaToken)?;
+        let user_id = Uuid::parse_str(&claims.sub).map_err(|_| VerifyMfaLoginError::InvalidMfaToken)?;
+
+        // Failed codes count against the same account throttle as failed passwords
+        let account = user_id.to_string();
+        if let Some(until) = self
+            .throttle_service
+            .locked_until(ThrottleScope::Account, &account)
+            .await?
+        {
+            return Err(VerifyMfaLoginError::locked_until(until));
+        }
+
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(VerifyMfaLoginError::InvalidMfaToken)?;
+
+        if !user.is_active {
+            return Err(VerifyMfaLoginError::AccountInactive);
+        }
+
+        let mut mfa = match self.mfa_repository.find_by_user(user_id).await? {
+            Some(mfa) if mfa.enabled => mfa,
+            _ => return Err(VerifyMfaLoginError::InvalidMfaToken),
+        };
+
+        let now = Utc::now().timestamp();
+        let accepted = match self
+            .totp_service
+            .verify(&mfa.secret, &dto.code, now, mfa.last_used_step)
+        {
+            Some(step) => {
+                mfa.mark_step_used(step);
+                self.mfa_repository.save(mfa).await?;
+                true
+            }
+            None => {
+                let code_hash = AuthService::hash_token(&TotpService::normalize_recovery_code(&dto.code));
+                self.mfa_repository
+                    .consume_recovery_code(user_id, &code_hash)
+                    .await?
+            }
+        };
+
+        if !accepted {
+            if let Some(until) = self
+                .throttle_service
+                .register_failure(ThrottleScope::Account, &account)
+                .await?
+            {
+                return Err(VerifyMfaLoginError::locked_until(until));
+            }
+            return Err(VerifyMfaLoginError::InvalidCode);
+        }
+
+        self.throttle_service
+            .reset(ThrottleScope::Account, &account)
+            .await?;
+
+        let token = self
+            .session_service
+            .start(user.id, &user.email, context.session_client())
+            .await?;
+
+        Ok(AuthResponse {
+            token,
+            user: user.into(),
+        })
+    }
+}
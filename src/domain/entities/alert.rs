@@ -0,0 +1,361 @@
+use crate::domain::entities::article::Article;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Canal de entrega de um alerta, além da caixa de notificações do app
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertChannel {
+    Email,
+    Webhook,
+}
+
+impl AlertChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertChannel::Email => "email",
+            AlertChannel::Webhook => "webhook",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "email" => Some(AlertChannel::Email),
+            "webhook" => Some(AlertChannel::Webhook),
+            _ => None,
+        }
+    }
+}
+
+/// Regra de alerta do usuário, avaliada quando um artigo é ingerido ou enriquecido.
+/// Todos os critérios informados precisam ser atendidos
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AlertRule {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    /// Palavra-chave ou frase procurada, como palavras inteiras, no título, na descrição e no conteúdo
+    pub query: Option<String>,
+    pub category_id: Option<Uuid>,
+    pub feed_id: Option<Uuid>,
+    /// Nota mínima da IA; enquanto o artigo não tem nota, a regra não dispara
+    pub min_rate: Option<i32>,
+    pub channels: Vec<AlertChannel>,
+    pub webhook_url: Option<String>,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl AlertRule {
+    pub fn new(user_id: Uuid, dto: AlertRuleDto) -> Self {
+        let now = Utc::now();
+        let mut rule = Self {
+            id: Uuid::new_v4(),
+            user_id,
+            name: String::new(),
+            query: None,
+            category_id: None,
+            feed_id: None,
+            min_rate: None,
+            channels: Vec::new(),
+            webhook_url: None,
+            enabled: true,
+            created_at: now,
+            updated_at: now,
+        };
+        rule.replace(dto);
+        rule
+    }
+
+    /// Substitui nome, critérios e canais pelos informados
+    pub fn replace(&mut self, dto: AlertRuleDto) {
+        let mut channels = Vec::new();
+        for channel in dto.channels {
+            if !channels.contains(&channel) {
+                channels.push(channel);
+            }
+        }
+
+        self.name = dto.name.trim().to_string();
+        self.query = dto
+            .query
+            .map(|query| query.trim().to_string())
+            .filter(|query| !query.is_empty());
+        self.category_id = dto.category_id;
+        self.feed_id = dto.feed_id;
+        self.min_rate = dto.min_rate;
+        self.channels = channels;
+        self.webhook_url = dto
+            .webhook_url
+            .map(|url| url.trim().to_string())
+            .filter(|url| !url.is_empty());
+        self.enabled = dto.enabled;
+        self.updated_at = Utc::now();
+    }
+
+    /// Uma regra sem critérios dispararia para todos os artigos
+    pub fn has_criteria(&self) -> bool {
+        self.query.is_some() || self.category_id.is_some() || self.feed_id.is_some() || self.min_rate.is_some()
+    }
+
+    pub fn matches(&self, article: &Article) -> bool {
+        self.enabled
+            && self.has_criteria()
+            && self.feed_id.is_none_or(|feed_id| article.feed_id == feed_id)
+            && self
+                .category_id
+                .is_none_or(|category_id| article.category_id == Some(category_id))
+            && self
+                .min_rate
+                .is_none_or(|min| article.rate.is_some_and(|rate| rate >= min))
+            && self.query.as_ref().is_none_or(|query| {
+                let phrase = words(query);
+                !phrase.is_empty()
+                    && [&article.title, &article.description, &article.content]
+                        .iter()
+                        .any(|text| words(text).windows(phrase.len()).any(|window| window == phrase.as_slice()))
+            })
+    }
+}
+
+/// Palavras do texto em minúsculas, separadas por qualquer caractere que não seja letra ou número
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Regra completa, usada tanto na criação quanto na substituição
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct AlertRuleDto {
+    #[validate(length(min = 1, max = 100, message = "Name must have between 1 and 100 characters"))]
+    pub name: String,
+
+    #[validate(length(max = 200, message = "Query must have at most 200 characters"))]
+    pub query: Option<String>,
+
+    pub category_id: Option<Uuid>,
+
+    pub feed_id: Option<Uuid>,
+
+    #[validate(range(min = 0, max = 10, message = "Minimum rate must be between 0 and 10"))]
+    pub min_rate: Option<i32>,
+
+    #[serde(default)]
+    pub channels: Vec<AlertChannel>,
+
+    /// Obrigatória com o canal `webhook`
+    #[validate(url(message = "Webhook URL must be a valid URL"))]
+    #[validate(length(max = 500, message = "Webhook URL must have at most 500 characters"))]
+    pub webhook_url: Option<String>,
+
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertRuleResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub query: Option<String>,
+    pub category_id: Option<Uuid>,
+    pub feed_id: Option<Uuid>,
+    pub min_rate: Option<i32>,
+    pub channels: Vec<AlertChannel>,
+    pub webhook_url: Option<String>,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<AlertRule> for AlertRuleResponse {
+    fn from(rule: AlertRule) -> Self {
+        AlertRuleResponse {
+            id: rule.id,
+            name: rule.name,
+            query: rule.query,
+            category_id: rule.category_id,
+            feed_id: rule.feed_id,
+            min_rate: rule.min_rate,
+            channels: rule.channels,
+            webhook_url: rule.webhook_url,
+            enabled: rule.enabled,
+            created_at: rule.created_at,
+            updated_at: rule.updated_at,
+        }
+    }
+}
+
+/// Notificação da caixa de entrada: um artigo que atendeu a uma regra.
+/// Título e link são copiados do artigo no momento do disparo
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Notification {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub rule_id: Uuid,
+    pub article_id: Uuid,
+    pub title: String,
+    pub link: String,
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Notification {
+    pub fn new(rule: &AlertRule, article: &Article) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id: rule.user_id,
+            rule_id: rule.id,
+            article_id: article.id,
+            title: article.title.clone(),
+            link: article.link.clone(),
+            read_at: None,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationDeliveryStatus {
+    /// Aguardando a primeira tentativa ou uma nova tentativa
+    Pending,
+    Succeeded,
+    /// Tentativas esgotadas
+    Failed,
+}
+
+impl NotificationDeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationDeliveryStatus::Pending => "pending",
+            NotificationDeliveryStatus::Succeeded => "succeeded",
+            NotificationDeliveryStatus::Failed => "failed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(NotificationDeliveryStatus::Pending),
+            "succeeded" => Some(NotificationDeliveryStatus::Succeeded),
+            "failed" => Some(NotificationDeliveryStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// Entrega de uma notificação por um canal externo (email, webhook), feita fora da
+/// requisição que disparou o alerta e repetida em caso de falha
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NotificationDelivery {
+    pub id: Uuid,
+    pub notification_id: Uuid,
+    pub channel: AlertChannel,
+    pub status: NotificationDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl NotificationDelivery {
+    /// Tentativas antes de desistir; com o backoff, a última acontece cerca de 15 minutos após o alerta
+    pub const MAX_ATTEMPTS: i32 = 6;
+    const BASE_DELAY_SECONDS: i64 = 30;
+
+    pub fn new(notification: &Notification, channel: AlertChannel) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            notification_id: notification.id,
+            channel,
+            status: NotificationDeliveryStatus::Pending,
+            attempts: 0,
+            // Devida desde a notificação, para sair na próxima rodada
+            next_attempt_at: Some(notification.created_at),
+            last_error: None,
+            created_at: notification.created_at,
+            delivered_at: None,
+        }
+    }
+
+    pub fn record_success(&mut self, at: DateTime<Utc>) {
+        self.attempts += 1;
+        self.status = NotificationDeliveryStatus::Succeeded;
+        self.last_error = None;
+        self.next_attempt_at = None;
+        self.delivered_at = Some(at);
+    }
+
+    /// Agenda a próxima tentativa com backoff exponencial (30s, 1min, 2min, ...) ou desiste
+    /// após `MAX_ATTEMPTS`
+    pub fn record_failure(&mut self, error: String, at: DateTime<Utc>) {
+        self.attempts += 1;
+        self.last_error = Some(error);
+
+        if self.attempts >= Self::MAX_ATTEMPTS {
+            self.status = NotificationDeliveryStatus::Failed;
+            self.next_attempt_at = None;
+        } else {
+            let delay = Self::BASE_DELAY_SECONDS << (self.attempts - 1);
+            self.next_attempt_at = Some(at + Duration::seconds(delay));
+        }
+    }
+
+    /// Desiste da entrega sem novas tentativas
+    pub fn abandon(&mut self, error: String, at: DateTime<Utc>) {
+        self.attempts = self.attempts.max(Self::MAX_ATTEMPTS - 1);
+        self.record_failure(error, at);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationResponse {
+    pub id: Uuid,
+    pub rule_id: Uuid,
+    pub article_id: Uuid,
+    pub title: String,
+    pub link: String,
+    pub read: bool,
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Notification> for NotificationResponse {
+    fn from(notification: Notification) -> Self {
+        NotificationResponse {
+            id: notification.id,
+            rule_id: notification.rule_id,
+            article_id: notification.article_id,
+            title: notification.title,
+            link: notification.link,
+            read: notification.read_at.is_some(),
+            read_at: notification.read_at,
+            created_at: notification.created_at,
+        }
+    }
+}
+
+/// Página da caixa de entrada, com o total de não lidas independente do filtro
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaginatedNotifications {
+    pub notifications: Vec<NotificationResponse>,
+    pub total: i64,
+    pub unread: i64,
+    pub page: i64,
+    pub per_page: i64,
+    pub total_pages: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkNotificationsReadResponse {
+    pub marked: u64,
+}
@@ -0,0 +1,323 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Âncora de um destaque no `content` do artigo: o trecho citado, o contexto antes e depois
+/// e a posição (em caracteres, `end` exclusivo). O contexto permite reencontrar o trecho
+/// quando o conteúdo muda numa nova ingestão do feed
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, PartialEq)]
+pub struct TextQuoteAnchor {
+    #[validate(length(min = 1, max = 2000, message = "Quote must have between 1 and 2000 characters"))]
+    pub quote: String,
+
+    #[serde(default)]
+    #[validate(length(max = 200, message = "Prefix must have at most 200 characters"))]
+    pub prefix: String,
+
+    #[serde(default)]
+    #[validate(length(max = 200, message = "Suffix must have at most 200 characters"))]
+    pub suffix: String,
+
+    #[validate(range(min = 0, message = "Start must not be negative"))]
+    pub start: i32,
+
+    #[validate(range(min = 0, message = "End must not be negative"))]
+    pub end: i32,
+}
+
+/// Situação da âncora frente ao conteúdo atual do artigo
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AnchorStatus {
+    /// O trecho continua na posição gravada
+    Anchored,
+    /// O trecho foi reencontrado em outra posição
+    Moved,
+    /// O trecho não existe mais no conteúdo
+    Orphaned,
+}
+
+impl TextQuoteAnchor {
+    /// Tamanho do contexto completado pelo servidor quando o cliente não envia `prefix`/`suffix`
+    const CONTEXT_CHARS: usize = 32;
+
+    /// Localiza o trecho no conteúdo: primeiro na posição gravada, depois em qualquer ocorrência
+    /// exata e, por fim, ignorando diferenças de espaços. Entre várias ocorrências, vence a que
+    /// tem mais contexto em comum e, no empate, a mais próxima da posição original
+    pub fn locate(&self, content: &str) -> Option<(usize, usize)> {
+        let text: Vec<char> = content.chars().collect();
+        let quote: Vec<char> = self.quote.chars().collect();
+        if quote.is_empty() {
+            return None;
+        }
+
+        let (start, end) = (self.start.max(0) as usize, self.end.max(0) as usize);
+        if end <= text.len() && start < end && text[start..end] == quote[..] {
+            return Some((start, end));
+        }
+
+        let prefix: Vec<char> = self.prefix.chars().collect();
+        let suffix: Vec<char> = self.suffix.chars().collect();
+        if let Some(found) = best_match(&text, &quote, &prefix, &suffix, start) {
+            return Some((found, found + quote.len()));
+        }
+
+        // Novo conteúdo com quebras de linha ou espaços diferentes
+        let (normalized, positions) = collapse_whitespace(&text);
+        let quote = collapse_whitespace(&quote).0;
+        let prefix = collapse_whitespace(&prefix).0;
+        let suffix = collapse_whitespace(&suffix).0;
+        let hint = positions.partition_point(|&position| position < start);
+
+        best_match(&normalized, &quote, &prefix, &suffix, hint)
+            .map(|found| (positions[found], positions[found + quote.len() - 1] + 1))
+    }
+
+    /// Situação da âncora e a posição atual do trecho, se ainda existir
+    pub fn resolve(&self, content: &str) -> (AnchorStatus, Option<(usize, usize)>) {
+        match self.locate(content) {
+            Some((start, end)) if start == self.start as usize && end == self.end as usize => {
+                (AnchorStatus::Anchored, Some((start, end)))
+            }
+            Some(position) => (AnchorStatus::Moved, Some(position)),
+            None => (AnchorStatus::Orphaned, None),
+        }
+    }
+
+    /// Âncora ajustada ao conteúdo: posição corrigida e contexto completado quando ausente.
+    /// `None` se o trecho não for encontrado
+    pub fn anchored_in(&self, content: &str) -> Option<TextQuoteAnchor> {
+        let (start, end) = self.locate(content)?;
+        let text: Vec<char> = content.chars().collect();
+
+        let prefix = if self.prefix.is_empty() {
+            text[start.saturating_sub(Self::CONTEXT_CHARS)..start].iter().collect()
+        } else {
+            self.prefix.clone()
+        };
+        let suffix = if self.suffix.is_empty() {
+            text[end..(end + Self::CONTEXT_CHARS).min(text.len())].iter().collect()
+        } else {
+            self.suffix.clone()
+        };
+
+        Some(TextQuoteAnchor {
+            quote: text[start..end].iter().collect(),
+            prefix,
+            suffix,
+            start: start as i32,
+            end: end as i32,
+        })
+    }
+}
+
+/// Ocorrência de `quote` em `text` com mais contexto em comum, desempatando pela distância a `hint`
+fn best_match(text: &[char], quote: &[char], prefix: &[char], suffix: &[char], hint: usize) -> Option<usize> {
+    if quote.is_empty() || quote.len() > text.len() {
+        return None;
+    }
+
+    (0..=text.len() - quote.len())
+        .filter(|&i| text[i..i + quote.len()] == *quote)
+        .max_by_key(|&i| {
+            let before = text[..i]
+                .iter()
+                .rev()
+                .zip(prefix.iter().rev())
+                .take_while(|(a, b)| a == b)
+                .count();
+            let after = text[i + quote.len()..]
+                .iter()
+                .zip(suffix.iter())
+                .take_while(|(a, b)| a == b)
+                .count();
+            (before + after, std::cmp::Reverse(i.abs_diff(hint)))
+        })
+}
+
+/// Reduz cada sequência de espaços a um único espaço, guardando a posição original de cada caractere
+fn collapse_whitespace(text: &[char]) -> (Vec<char>, Vec<usize>) {
+    let mut collapsed = Vec::with_capacity(text.len());
+    let mut positions = Vec::with_capacity(text.len());
+
+    for (position, &c) in text.iter().enumerate() {
+        if c.is_whitespace() {
+            if collapsed.last() != Some(&' ') {
+                collapsed.push(' ');
+                positions.push(position);
+            }
+        } else {
+            collapsed.push(c);
+            positions.push(position);
+        }
+    }
+
+    (collapsed, positions)
+}
+
+/// Destaque e/ou nota de um usuário em um artigo. Sem âncora, a nota vale para o artigo inteiro
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Annotation {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub article_id: Uuid,
+    pub anchor: Option<TextQuoteAnchor>,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Annotation {
+    pub fn new(user_id: Uuid, article_id: Uuid, anchor: Option<TextQuoteAnchor>, note: Option<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            article_id,
+            anchor,
+            note: note.and_then(|note| clean_note(&note)),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Substitui a âncora e/ou a nota; uma nota vazia é removida
+    pub fn update(&mut self, anchor: Option<TextQuoteAnchor>, note: Option<String>) {
+        if anchor.is_some() {
+            self.anchor = anchor;
+        }
+        if let Some(note) = note {
+            self.note = clean_note(&note);
+        }
+        self.updated_at = Utc::now();
+    }
+
+    /// Uma anotação precisa de um trecho destacado ou de uma nota
+    pub fn is_empty(&self) -> bool {
+        self.anchor.is_none() && self.note.is_none()
+    }
+}
+
+fn clean_note(note: &str) -> Option<String> {
+    let note = note.trim();
+    (!note.is_empty()).then(|| note.to_string())
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct CreateAnnotationDto {
+    #[validate(nested)]
+    pub anchor: Option<TextQuoteAnchor>,
+
+    #[validate(length(max = 10000, message = "Note must have at most 10000 characters"))]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct UpdateAnnotationDto {
+    /// Nova âncora, ajustada ao conteúdo atual como na criação
+    #[validate(nested)]
+    pub anchor: Option<TextQuoteAnchor>,
+
+    /// Texto vazio remove a nota
+    #[validate(length(max = 10000, message = "Note must have at most 10000 characters"))]
+    pub note: Option<String>,
+}
+
+/// Âncora como vista no conteúdo atual: `start`/`end` já acompanham o texto e ficam
+/// ausentes quando o trecho não é mais encontrado
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AnchorResponse {
+    pub quote: String,
+    pub prefix: String,
+    pub suffix: String,
+    pub start: Option<i32>,
+    pub end: Option<i32>,
+    pub status: AnchorStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnotationResponse {
+    pub id: Uuid,
+    pub article_id: Uuid,
+    pub anchor: Option<AnchorResponse>,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl AnnotationResponse {
+    /// Monta a resposta reposicionando a âncora no conteúdo atual do artigo
+    pub fn resolve(annotation: Annotation, content: &str) -> Self {
+        let anchor = annotation.anchor.map(|anchor| {
+            let (status, position) = anchor.resolve(content);
+            AnchorResponse {
+                start: position.map(|(start, _)| start as i32),
+                end: position.map(|(_, end)| end as i32),
+                status,
+                quote: anchor.quote,
+                prefix: anchor.prefix,
+                suffix: anchor.suffix,
+            }
+        });
+
+        AnnotationResponse {
+            id: annotation.id,
+            article_id: annotation.article_id,
+            anchor,
+            note: annotation.note,
+            created_at: annotation.created_at,
+            updated_at: annotation.updated_at,
+        }
+    }
+}
+
+/// Formato da exportação das anotações
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnotationExportFormat {
+    #[default]
+    Json,
+    Markdown,
+}
+
+/// Anotações de um usuário agrupadas por artigo, para exportação
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArticleAnnotations {
+    pub article_id: Uuid,
+    pub title: String,
+    pub link: String,
+    pub annotations: Vec<AnnotationResponse>,
+}
+
+impl ArticleAnnotations {
+    /// Documento Markdown com um título por artigo, os trechos como citações e as notas logo abaixo
+    pub fn to_markdown(exports: &[ArticleAnnotations]) -> String {
+        let mut markdown = String::from("# Annotations\n");
+
+        for article in exports {
+            markdown.push_str(&format!("\n## [{}]({})\n", article.title, article.link));
+
+            for annotation in &article.annotations {
+                markdown.push('\n');
+                if let Some(anchor) = &annotation.anchor {
+                    for line in anchor.quote.lines() {
+                        markdown.push_str(&format!("> {}\n", line));
+                    }
+                    if anchor.status == AnchorStatus::Orphaned {
+                        markdown.push_str(">\n> _(no longer found in the article)_\n");
+                    }
+                }
+                if let Some(note) = &annotation.note {
+                    if annotation.anchor.is_some() {
+                        markdown.push('\n');
+                    }
+                    markdown.push_str(note);
+                    markdown.push('\n');
+                }
+            }
+        }
+
+        markdown
+    }
+}
@@ -0,0 +1,139 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Permissões que podem ser concedidas a uma chave de API
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ApiKeyScope {
+    #[serde(rename = "articles:read")]
+    ArticlesRead,
+    #[serde(rename = "articles:write")]
+    ArticlesWrite,
+    #[serde(rename = "feeds:read")]
+    FeedsRead,
+    #[serde(rename = "feeds:write")]
+    FeedsWrite,
+    /// Alterações na biblioteca pessoal (estado de leitura, salvos, tags, anotações...)
+    #[serde(rename = "library:write")]
+    LibraryWrite,
+}
+
+impl ApiKeyScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiKeyScope::ArticlesRead => "articles:read",
+            ApiKeyScope::ArticlesWrite => "articles:write",
+            ApiKeyScope::FeedsRead => "feeds:read",
+            ApiKeyScope::FeedsWrite => "feeds:write",
+            ApiKeyScope::LibraryWrite => "library:write",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "articles:read" => Some(ApiKeyScope::ArticlesRead),
+            "articles:write" => Some(ApiKeyScope::ArticlesWrite),
+            "feeds:read" => Some(ApiKeyScope::FeedsRead),
+            "feeds:write" => Some(ApiKeyScope::FeedsWrite),
+            "library:write" => Some(ApiKeyScope::LibraryWrite),
+            _ => None,
+        }
+    }
+}
+
+/// Chave de API pessoal para clientes automatizados (scripts, integrações).
+/// Apenas o prefixo é armazenado em claro; o segredo é guardado como hash.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ApiKey {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub prefix: String,
+    pub secret_hash: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ApiKey {
+    pub fn new(
+        user_id: Uuid,
+        name: String,
+        prefix: String,
+        secret_hash: String,
+        scopes: Vec<ApiKeyScope>,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            name,
+            prefix,
+            secret_hash,
+            scopes,
+            expires_at,
+            last_used_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+
+    pub fn has_scope(&self, scope: ApiKeyScope) -> bool {
+        self.scopes.contains(&scope)
+    }
+
+    /// Indica se `last_used_at` está desatualizado o bastante para justificar uma escrita
+    pub fn needs_usage_update(&self, now: DateTime<Utc>, interval: Duration) -> bool {
+        self.last_used_at.is_none_or(|last| now - last >= interval)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CreateApiKeyDto {
+    #[validate(length(min = 1, max = 100, message = "Name must be between 1 and 100 characters"))]
+    pub name: String,
+
+    #[validate(length(min = 1, message = "At least one scope is required"))]
+    pub scopes: Vec<ApiKeyScope>,
+
+    #[validate(range(min = 1, max = 365, message = "Expiration must be between 1 and 365 days"))]
+    pub expires_in_days: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKeyResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub prefix: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Resposta da criação; a chave completa só é exibida neste momento
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedApiKeyResponse {
+    #[serde(flatten)]
+    pub api_key: ApiKeyResponse,
+    pub key: String,
+}
+
+impl From<ApiKey> for ApiKeyResponse {
+    fn from(api_key: ApiKey) -> Self {
+        ApiKeyResponse {
+            id: api_key.id,
+            name: api_key.name,
+            prefix: api_key.prefix,
+            scopes: api_key.scopes,
+            expires_at: api_key.expires_at,
+            last_used_at: api_key.last_used_at,
+            created_at: api_key.created_at,
+        }
+    }
+}
@@ -26,6 +26,9 @@ pub struct Article {
     pub processing_columnist: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Média das notas dos usuários (0–10), mantida pelo repositório a cada avaliação
+    pub user_rating_avg: Option<f64>,
+    pub user_rating_count: i32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
@@ -67,6 +70,18 @@ pub struct UpdateArticleDto {
     pub ai_columnist: Option<String>,
 }
 
+impl UpdateArticleDto {
+    /// Se a atualização traz resultados do pipeline de IA (resumo, nota, palavras-chave,
+    /// categoria ou colunista), que viram o evento `article.enriched` em vez de `article.updated`
+    pub fn is_enrichment(&self) -> bool {
+        self.ai_summary.is_some()
+            || self.rate.is_some()
+            || self.keywords.is_some()
+            || self.category_id.is_some()
+            || self.ai_columnist.is_some()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArticleResponse {
     pub id: Uuid,
@@ -84,6 +99,28 @@ pub struct ArticleResponse {
     pub category_id: Option<Uuid>,
     pub ai_columnist: Option<String>,
     pub created_at: DateTime<Utc>,
+    /// Média das notas dos usuários, ao lado da nota da IA (`rate`)
+    pub user_rating: Option<f64>,
+    pub user_rating_count: i32,
+    /// Estado do artigo para o usuário autenticado; ausente em requisições anônimas
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_state: Option<ArticleUserState>,
+}
+
+/// Estado de leitura de um artigo para um usuário
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ArticleUserState {
+    pub read: bool,
+    pub read_at: Option<DateTime<Utc>>,
+}
+
+impl ArticleUserState {
+    pub fn from_read_at(read_at: Option<DateTime<Utc>>) -> Self {
+        Self {
+            read: read_at.is_some(),
+            read_at,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,6 +132,132 @@ pub struct PaginatedArticles {
     pub total_pages: i64,
 }
 
+/// Filtros e ordenação da listagem de artigos; um filtro ausente não restringe o resultado
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ArticleListFilter {
+    /// Restringe aos feeds informados (ex.: os assinados pelo usuário)
+    pub feed_ids: Option<Vec<Uuid>>,
+    /// Restringe aos artigos informados (ex.: os marcados com uma tag do usuário)
+    pub article_ids: Option<Vec<Uuid>>,
+    pub category_id: Option<Uuid>,
+    /// Texto procurado no título, na descrição e no conteúdo, sem diferenciar maiúsculas
+    pub search: Option<String>,
+    /// Apenas artigos publicados até este instante
+    pub published_before: Option<DateTime<Utc>>,
+    /// Apenas artigos publicados a partir deste instante
+    pub published_after: Option<DateTime<Utc>>,
+    /// Usuário cujo estado de leitura acompanha cada artigo da resposta
+    pub reader_id: Option<Uuid>,
+    /// Com `reader_id`, retorna apenas os artigos que ele ainda não leu
+    pub unread_only: bool,
+    /// Nota mínima da IA
+    pub min_rate: Option<i32>,
+    /// Média mínima das notas dos usuários
+    pub min_user_rating: Option<f64>,
+    pub sort: ArticleSort,
+}
+
+/// Ordenação das listagens; as notas ausentes ficam por último e o empate é resolvido pela data
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArticleSort {
+    /// Mais recentes primeiro
+    #[default]
+    Recent,
+    /// Maior nota da IA primeiro
+    Rate,
+    /// Maior média dos usuários primeiro
+    UserRating,
+}
+
+impl ArticleSort {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "recent" => Some(ArticleSort::Recent),
+            "rate" => Some(ArticleSort::Rate),
+            "user_rating" => Some(ArticleSort::UserRating),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ArticleSort::Recent => "recent",
+            ArticleSort::Rate => "rate",
+            ArticleSort::UserRating => "user_rating",
+        }
+    }
+}
+
+impl ArticleListFilter {
+    /// Verifica os filtros que dependem apenas do artigo (o estado de leitura fica com o repositório)
+    pub fn matches(&self, article: &Article) -> bool {
+        self.feed_ids
+            .as_ref()
+            .is_none_or(|feed_ids| feed_ids.contains(&article.feed_id))
+            && self
+                .article_ids
+                .as_ref()
+                .is_none_or(|article_ids| article_ids.contains(&article.id))
+            && self
+                .category_id
+                .is_none_or(|category_id| article.category_id == Some(category_id))
+            && self.search.as_ref().is_none_or(|search| {
+                let search = search.to_lowercase();
+                [&article.title, &article.description, &article.content]
+                    .iter()
+                    .any(|text| text.to_lowercase().contains(&search))
+            })
+            && self
+                .published_before
+                .is_none_or(|before| article.pub_date <= before)
+            && self
+                .published_after
+                .is_none_or(|after| article.pub_date >= after)
+            && self
+                .min_rate
+                .is_none_or(|min| article.rate.is_some_and(|rate| rate >= min))
+            && self
+                .min_user_rating
+                .is_none_or(|min| article.user_rating_avg.is_some_and(|avg| avg >= min))
+    }
+}
+
+/// Marcação em lote como lido; sem `feed_id`, vale para todos os feeds assinados
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MarkAllReadDto {
+    pub feed_id: Option<Uuid>,
+    pub category_id: Option<Uuid>,
+    /// Apenas artigos publicados até este instante, para não marcar o que chegou depois da última atualização
+    pub before: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkAllReadResponse {
+    pub marked: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FeedUnreadCount {
+    pub feed_id: Uuid,
+    pub unread: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CategoryUnreadCount {
+    /// `None` agrupa os artigos ainda sem categoria
+    pub category_id: Option<Uuid>,
+    pub unread: i64,
+}
+
+/// Contagem de não lidos nos feeds assinados, por feed e por categoria
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct UnreadCounts {
+    pub total: i64,
+    pub feeds: Vec<FeedUnreadCount>,
+    pub categories: Vec<CategoryUnreadCount>,
+}
+
 impl From<Article> for ArticleResponse {
     fn from(article: Article) -> Self {
         ArticleResponse {
@@ -113,6 +276,9 @@ impl From<Article> for ArticleResponse {
             category_id: article.category_id,
             ai_columnist: article.ai_columnist,
             created_at: article.created_at,
+            user_rating: article.user_rating_avg,
+            user_rating_count: article.user_rating_count,
+            user_state: None,
         }
     }
 }
@@ -143,6 +309,8 @@ impl Article {
             processing_columnist: false,
             created_at: now,
             updated_at: now,
+            user_rating_avg: None,
+            user_rating_count: 0,
         }
     }
 
@@ -187,3 +355,39 @@ impl Article {
         self.updated_at = Utc::now();
     }
 }
+
+/// Avaliação de um usuário (0 a 10, mesma escala da nota da IA)
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct RateArticleDto {
+    #[validate(range(min = 0, max = 10, message = "Rating must be between 0 and 10"))]
+    pub rating: i32,
+}
+
+/// Agregado das notas dos usuários para um artigo
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ArticleRatingSummary {
+    pub average: Option<f64>,
+    pub count: i32,
+}
+
+impl ArticleRatingSummary {
+    pub fn from_ratings(ratings: &[i32]) -> Self {
+        if ratings.is_empty() {
+            return Self::default();
+        }
+
+        Self {
+            average: Some(ratings.iter().map(|&r| r as f64).sum::<f64>() / ratings.len() as f64),
+            count: ratings.len() as i32,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArticleRatingResponse {
+    pub article_id: Uuid,
+    /// Nota do usuário autenticado; `None` após remover a avaliação
+    pub rating: Option<i32>,
+    pub average: Option<f64>,
+    pub count: i32,
+}
@@ -12,6 +12,7 @@ pub struct ArticleCategory {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[allow(dead_code)]
 pub struct CreateArticleCategoryDto {
     #[validate(length(min = 2, message = "Name must be at least 2 characters"))]
     pub name: String,
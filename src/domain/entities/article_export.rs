@@ -0,0 +1,180 @@
+use crate::domain::entities::article::Article;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Artigos lidos do banco por consulta durante uma exportação
+pub const EXPORT_CHUNK_SIZE: i64 = 500;
+
+/// Formato do arquivo exportado
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    /// Um objeto JSON por linha, com os tipos preservados (números, datas RFC 3339 e `null`)
+    Ndjson,
+}
+
+impl ExportFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "csv" => Some(ExportFormat::Csv),
+            "ndjson" => Some(ExportFormat::Ndjson),
+            _ => None,
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Ndjson => "application/x-ndjson",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Ndjson => "ndjson",
+        }
+    }
+}
+
+/// Coluna da exportação; o nome é o mesmo do campo em `ArticleResponse`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportColumn {
+    Id,
+    Title,
+    Description,
+    Link,
+    PubDate,
+    Media,
+    Content,
+    Creator,
+    FeedId,
+    AiSummary,
+    Rate,
+    Keywords,
+    CategoryId,
+    /// Nome da categoria, resolvido a partir de `category_id`
+    CategoryName,
+    AiColumnist,
+    UserRating,
+    UserRatingCount,
+    CreatedAt,
+}
+
+impl ExportColumn {
+    /// Todas as colunas, na ordem usada quando `columns` não é informado
+    pub const ALL: [ExportColumn; 18] = [
+        ExportColumn::Id,
+        ExportColumn::Title,
+        ExportColumn::Description,
+        ExportColumn::Link,
+        ExportColumn::PubDate,
+        ExportColumn::Media,
+        ExportColumn::Content,
+        ExportColumn::Creator,
+        ExportColumn::FeedId,
+        ExportColumn::AiSummary,
+        ExportColumn::Rate,
+        ExportColumn::Keywords,
+        ExportColumn::CategoryId,
+        ExportColumn::CategoryName,
+        ExportColumn::AiColumnist,
+        ExportColumn::UserRating,
+        ExportColumn::UserRatingCount,
+        ExportColumn::CreatedAt,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportColumn::Id => "id",
+            ExportColumn::Title => "title",
+            ExportColumn::Description => "description",
+            ExportColumn::Link => "link",
+            ExportColumn::PubDate => "pub_date",
+            ExportColumn::Media => "media",
+            ExportColumn::Content => "content",
+            ExportColumn::Creator => "creator",
+            ExportColumn::FeedId => "feed_id",
+            ExportColumn::AiSummary => "ai_summary",
+            ExportColumn::Rate => "rate",
+            ExportColumn::Keywords => "keywords",
+            ExportColumn::CategoryId => "category_id",
+            ExportColumn::CategoryName => "category_name",
+            ExportColumn::AiColumnist => "ai_columnist",
+            ExportColumn::UserRating => "user_rating",
+            ExportColumn::UserRatingCount => "user_rating_count",
+            ExportColumn::CreatedAt => "created_at",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|column| column.as_str() == value)
+    }
+
+    /// Lê a lista separada por vírgulas do parâmetro `columns`, na ordem pedida e sem repetições.
+    /// Retorna o nome desconhecido como erro
+    pub fn parse_list(value: &str) -> Result<Vec<Self>, String> {
+        let mut columns = Vec::new();
+        for name in value.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            let column = Self::parse(name).ok_or_else(|| name.to_string())?;
+            if !columns.contains(&column) {
+                columns.push(column);
+            }
+        }
+
+        if columns.is_empty() {
+            return Ok(Self::ALL.to_vec());
+        }
+        Ok(columns)
+    }
+
+    /// Valor da coluna para o artigo, com o tipo que ela tem no JSON
+    pub fn value(&self, article: &Article, category_name: Option<&str>) -> Value {
+        let text = |value: &str| Value::String(value.to_string());
+        let optional = |value: &Option<String>| value.as_deref().map_or(Value::Null, text);
+        let date = |value: &DateTime<Utc>| Value::String(value.to_rfc3339());
+
+        match self {
+            ExportColumn::Id => Value::String(article.id.to_string()),
+            ExportColumn::Title => text(&article.title),
+            ExportColumn::Description => text(&article.description),
+            ExportColumn::Link => text(&article.link),
+            ExportColumn::PubDate => date(&article.pub_date),
+            ExportColumn::Media => optional(&article.media),
+            ExportColumn::Content => text(&article.content),
+            ExportColumn::Creator => text(&article.creator),
+            ExportColumn::FeedId => Value::String(article.feed_id.to_string()),
+            ExportColumn::AiSummary => optional(&article.ai_summary),
+            ExportColumn::Rate => article.rate.map_or(Value::Null, Value::from),
+            ExportColumn::Keywords => optional(&article.keywords),
+            ExportColumn::CategoryId => article.category_id.map_or(Value::Null, |id| Value::String(id.to_string())),
+            ExportColumn::CategoryName => category_name.map_or(Value::Null, text),
+            ExportColumn::AiColumnist => optional(&article.ai_columnist),
+            ExportColumn::UserRating => article.user_rating_avg.map_or(Value::Null, Value::from),
+            ExportColumn::UserRatingCount => Value::from(article.user_rating_count),
+            ExportColumn::CreatedAt => date(&article.created_at),
+        }
+    }
+}
+
+/// Posição da exportação: o próximo lote começa depois deste artigo, na ordem (`pub_date`, `id`) decrescente
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArticleExportCursor {
+    pub pub_date: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl ArticleExportCursor {
+    pub fn after(article: &Article) -> Self {
+        Self {
+            pub_date: article.pub_date,
+            id: article.id,
+        }
+    }
+
+    /// Verifica se o artigo vem depois do cursor na ordem da exportação
+    pub fn precedes(&self, article: &Article) -> bool {
+        (article.pub_date, article.id) < (self.pub_date, self.id)
+    }
+}
@@ -0,0 +1,127 @@
+use crate::domain::entities::article::{Article, CreateArticleDto};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Linhas gravadas por transação durante uma importação
+pub const IMPORT_BATCH_SIZE: usize = 500;
+
+/// Registro de uma linha importada: os campos de `CreateArticleDto`, com o feed e a categoria
+/// identificados pelo ID ou pela URL/nome, mais os campos de enriquecimento opcionais.
+/// Campos desconhecidos são ignorados, então a saída de `GET /articles/export?format=ndjson` pode ser importada
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct ImportArticleRecord {
+    #[validate(length(min = 1, max = 255, message = "Title must have between 1 and 255 characters"))]
+    pub title: String,
+
+    #[validate(length(min = 1, message = "Description is required"))]
+    pub description: String,
+
+    #[validate(length(min = 1, max = 255, message = "Link must have between 1 and 255 characters"))]
+    pub link: String,
+
+    pub pub_date: DateTime<Utc>,
+
+    #[validate(length(max = 255, message = "Media must have at most 255 characters"))]
+    pub media: Option<String>,
+
+    #[validate(length(min = 1, message = "Content is required"))]
+    pub content: String,
+
+    #[validate(length(min = 1, max = 255, message = "Creator must have between 1 and 255 characters"))]
+    pub creator: String,
+
+    pub feed_id: Option<Uuid>,
+    /// Endereço do feed (`feed_url` ou o `link` do site), usado quando `feed_id` não é informado
+    pub feed_url: Option<String>,
+
+    pub category_id: Option<Uuid>,
+    /// Nome da categoria, usado quando `category_id` não é informado
+    #[serde(alias = "category")]
+    pub category_name: Option<String>,
+
+    pub ai_summary: Option<String>,
+    #[validate(range(min = 0, max = 10, message = "Rate must be between 0 and 10"))]
+    pub rate: Option<i32>,
+    #[validate(length(max = 255, message = "Keywords must have at most 255 characters"))]
+    pub keywords: Option<String>,
+    pub ai_columnist: Option<String>,
+}
+
+impl ImportArticleRecord {
+    /// Artigo novo com o feed e a categoria já resolvidos
+    pub fn into_article(self, feed_id: Uuid, category_id: Option<Uuid>) -> Article {
+        let mut article = Article::new(CreateArticleDto {
+            title: self.title,
+            description: self.description,
+            link: self.link,
+            pub_date: self.pub_date,
+            media: self.media,
+            content: self.content,
+            creator: self.creator,
+            feed_id,
+        });
+        article.category_id = category_id;
+        article.ai_summary = self.ai_summary;
+        article.rate = self.rate;
+        article.keywords = self.keywords;
+        article.ai_columnist = self.ai_columnist;
+        article
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportLineStatus {
+    Inserted,
+    /// Link já cadastrado ou repetido no próprio arquivo
+    Skipped,
+    Failed,
+}
+
+/// Resultado de uma linha (ou de um elemento, quando o arquivo é um array JSON), numerada a partir de 1
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ImportLineResult {
+    pub line: usize,
+    pub status: ImportLineStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub article_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Relatório da importação; as linhas vêm na ordem do arquivo e as linhas em branco ficam de fora
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ImportReport {
+    pub inserted: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    pub lines: Vec<ImportLineResult>,
+}
+
+impl ImportReport {
+    pub fn inserted(&mut self, line: usize, article_id: Uuid) {
+        self.inserted += 1;
+        self.push(line, ImportLineStatus::Inserted, Some(article_id), None);
+    }
+
+    pub fn skipped(&mut self, line: usize, reason: impl Into<String>) {
+        self.skipped += 1;
+        self.push(line, ImportLineStatus::Skipped, None, Some(reason.into()));
+    }
+
+    pub fn failed(&mut self, line: usize, reason: impl Into<String>) {
+        self.failed += 1;
+        self.push(line, ImportLineStatus::Failed, None, Some(reason.into()));
+    }
+
+    fn push(&mut self, line: usize, status: ImportLineStatus, article_id: Option<Uuid>, reason: Option<String>) {
+        self.lines.push(ImportLineResult {
+            line,
+            status,
+            article_id,
+            reason,
+        });
+    }
+}
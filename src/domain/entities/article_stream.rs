@@ -0,0 +1,53 @@
+use crate::domain::entities::webhook::{ArticleEvent, WebhookEventType};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Evento enviado aos clientes do stream SSE de artigos
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ArticleStreamEvent {
+    /// `seq` do evento no outbox; vira o `id:` do SSE e o `Last-Event-ID` na reconexão
+    pub seq: i64,
+    pub event_type: WebhookEventType,
+    pub article_id: Uuid,
+    pub feed_id: Option<Uuid>,
+    pub category_id: Option<Uuid>,
+    /// O artigo no momento do evento (`ArticleResponse`)
+    pub data: serde_json::Value,
+}
+
+impl ArticleStreamEvent {
+    /// Só criações, atualizações e enriquecimentos vão para o stream
+    pub fn from_event(event: &ArticleEvent) -> Option<Self> {
+        match event.event_type {
+            WebhookEventType::ArticleCreated
+            | WebhookEventType::ArticleUpdated
+            | WebhookEventType::ArticleEnriched => Some(Self {
+                seq: event.seq,
+                event_type: event.event_type,
+                article_id: event.article_id,
+                feed_id: payload_uuid(&event.payload, "feed_id"),
+                category_id: payload_uuid(&event.payload, "category_id"),
+                data: event.payload.clone(),
+            }),
+            WebhookEventType::ArticleDeleted | WebhookEventType::Test => None,
+        }
+    }
+}
+
+fn payload_uuid(payload: &serde_json::Value, field: &str) -> Option<Uuid> {
+    payload.get(field)?.as_str()?.parse().ok()
+}
+
+/// Filtros de uma conexão do stream; campos vazios não filtram
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ArticleStreamFilter {
+    pub feed_id: Option<Uuid>,
+    pub category_id: Option<Uuid>,
+}
+
+impl ArticleStreamFilter {
+    pub fn matches(&self, event: &ArticleStreamEvent) -> bool {
+        self.feed_id.is_none_or(|feed_id| event.feed_id == Some(feed_id))
+            && self.category_id.is_none_or(|category_id| event.category_id == Some(category_id))
+    }
+}
@@ -0,0 +1,95 @@
+use crate::domain::entities::saved_article::SavedArticleResponse;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Coleção nomeada de artigos salvos; com `share_token`, qualquer pessoa com o link pode vê-la
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Collection {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub share_token: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Collection {
+    pub fn new(user_id: Uuid, name: &str) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            name: name.trim().to_string(),
+            share_token: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn rename(&mut self, name: &str) {
+        self.name = name.trim().to_string();
+        self.updated_at = Utc::now();
+    }
+
+    /// Publica a coleção com um novo token; um token já existente é mantido
+    pub fn share(&mut self, token: String) {
+        if self.share_token.is_none() {
+            self.share_token = Some(token);
+            self.updated_at = Utc::now();
+        }
+    }
+
+    /// Torna a coleção privada; o link anterior deixa de funcionar
+    pub fn unshare(&mut self) {
+        if self.share_token.take().is_some() {
+            self.updated_at = Utc::now();
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CreateCollectionDto {
+    #[validate(length(min = 1, max = 100, message = "Name must have between 1 and 100 characters"))]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct UpdateCollectionDto {
+    #[validate(length(min = 1, max = 100, message = "Name must have between 1 and 100 characters"))]
+    pub name: Option<String>,
+
+    /// `true` gera o link público, `false` o revoga
+    pub shared: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectionResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub shared: bool,
+    pub share_token: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Collection> for CollectionResponse {
+    fn from(collection: Collection) -> Self {
+        CollectionResponse {
+            id: collection.id,
+            name: collection.name,
+            shared: collection.share_token.is_some(),
+            share_token: collection.share_token,
+            created_at: collection.created_at,
+            updated_at: collection.updated_at,
+        }
+    }
+}
+
+/// Visão pública de uma coleção compartilhada, sem dados do dono
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedCollectionResponse {
+    pub name: String,
+    pub items: Vec<SavedArticleResponse>,
+}
@@ -0,0 +1,275 @@
+use crate::domain::entities::article::ArticleResponse;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+/// Frequência de envio do resumo por email
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DigestFrequency {
+    Daily,
+    Weekly,
+}
+
+impl DigestFrequency {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DigestFrequency::Daily => "daily",
+            DigestFrequency::Weekly => "weekly",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "daily" => Some(DigestFrequency::Daily),
+            "weekly" => Some(DigestFrequency::Weekly),
+            _ => None,
+        }
+    }
+}
+
+/// Período coberto por um resumo, do envio anterior (inclusivo) ao envio atual (exclusivo)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DigestPeriod {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+/// Agenda do resumo por email de um usuário. O horário é local ao fuso `timezone`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DigestSettings {
+    pub user_id: Uuid,
+    pub frequency: DigestFrequency,
+    /// Hora local do envio, de 0 a 23
+    pub hour: i32,
+    /// Dia do envio semanal, de 1 (segunda) a 7 (domingo)
+    pub weekday: Option<i32>,
+    /// Fuso horário IANA, ex.: `America/Sao_Paulo`
+    pub timezone: String,
+    pub max_articles: i32,
+    /// Nota mínima da IA para um artigo entrar no resumo
+    pub min_rate: Option<i32>,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl DigestSettings {
+    pub const DEFAULT_MAX_ARTICLES: i32 = 10;
+    /// Dia do envio semanal quando não informado (segunda-feira)
+    pub const DEFAULT_WEEKDAY: i32 = 1;
+
+    pub fn new(user_id: Uuid, dto: DigestSettingsDto) -> Self {
+        let now = Utc::now();
+        let mut settings = Self {
+            user_id,
+            frequency: dto.frequency,
+            hour: 0,
+            weekday: None,
+            timezone: String::new(),
+            max_articles: Self::DEFAULT_MAX_ARTICLES,
+            min_rate: None,
+            enabled: true,
+            created_at: now,
+            updated_at: now,
+        };
+        settings.replace(dto);
+        settings
+    }
+
+    /// Substitui a agenda pela informada, mantendo a data de criação
+    pub fn replace(&mut self, dto: DigestSettingsDto) {
+        self.frequency = dto.frequency;
+        self.hour = dto.hour;
+        self.weekday = match dto.frequency {
+            DigestFrequency::Daily => None,
+            DigestFrequency::Weekly => Some(dto.weekday.unwrap_or(Self::DEFAULT_WEEKDAY)),
+        };
+        self.timezone = dto.timezone.trim().to_string();
+        self.max_articles = dto.max_articles.unwrap_or(Self::DEFAULT_MAX_ARTICLES);
+        self.min_rate = dto.min_rate;
+        self.enabled = dto.enabled;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn tz(&self) -> Option<Tz> {
+        self.timezone.parse().ok()
+    }
+
+    /// Período mais recente já encerrado em `now`: termina no último horário de envio
+    /// e começa no horário de envio anterior, ambos calculados no fuso do usuário
+    pub fn latest_period(&self, now: DateTime<Utc>) -> Option<DigestPeriod> {
+        let tz = self.tz()?;
+        let local = now.with_timezone(&tz);
+        let step = match self.frequency {
+            DigestFrequency::Daily => 1,
+            DigestFrequency::Weekly => 7,
+        };
+
+        let mut date = local.date_naive();
+        if let Some(weekday) = self.weekday.filter(|_| self.frequency == DigestFrequency::Weekly) {
+            let days_since = (date.weekday().number_from_monday() as i64 - weekday as i64).rem_euclid(7);
+            date -= Duration::days(days_since);
+        }
+
+        let mut end = self.send_time(tz, date)?;
+        if end > now {
+            date -= Duration::days(step);
+            end = self.send_time(tz, date)?;
+        }
+        let start = self.send_time(tz, date - Duration::days(step))?;
+
+        Some(DigestPeriod { start, end })
+    }
+
+    /// Horário de envio na data local; num salto de horário de verão, a primeira hora válida seguinte
+    fn send_time(&self, tz: Tz, date: NaiveDate) -> Option<DateTime<Utc>> {
+        let naive = date.and_hms_opt(self.hour as u32, 0, 0)?;
+        tz.from_local_datetime(&naive)
+            .earliest()
+            .or_else(|| tz.from_local_datetime(&(naive + Duration::hours(1))).earliest())
+            .map(|time| time.with_timezone(&Utc))
+    }
+}
+
+fn validate_timezone(timezone: &str) -> Result<(), ValidationError> {
+    timezone
+        .trim()
+        .parse::<Tz>()
+        .map(|_| ())
+        .map_err(|_| ValidationError::new("timezone").with_message("Timezone must be a valid IANA timezone".into()))
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct DigestSettingsDto {
+    pub frequency: DigestFrequency,
+
+    #[validate(range(min = 0, max = 23, message = "Hour must be between 0 and 23"))]
+    pub hour: i32,
+
+    /// Apenas no envio semanal; padrão segunda-feira
+    #[validate(range(min = 1, max = 7, message = "Weekday must be between 1 (Monday) and 7 (Sunday)"))]
+    pub weekday: Option<i32>,
+
+    #[validate(custom(function = "validate_timezone"))]
+    pub timezone: String,
+
+    #[validate(range(min = 1, max = 50, message = "Max articles must be between 1 and 50"))]
+    pub max_articles: Option<i32>,
+
+    #[validate(range(min = 0, max = 10, message = "Minimum rate must be between 0 and 10"))]
+    pub min_rate: Option<i32>,
+
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DigestSettingsResponse {
+    pub frequency: DigestFrequency,
+    pub hour: i32,
+    pub weekday: Option<i32>,
+    pub timezone: String,
+    pub max_articles: i32,
+    pub min_rate: Option<i32>,
+    pub enabled: bool,
+    /// Fim do próximo período, isto é, o próximo envio
+    pub next_send_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl DigestSettingsResponse {
+    pub fn new(settings: DigestSettings, now: DateTime<Utc>) -> Self {
+        let next_send_at = settings
+            .enabled
+            .then(|| settings.latest_period(now))
+            .flatten()
+            .and_then(|period| {
+                let step = match settings.frequency {
+                    DigestFrequency::Daily => 1,
+                    DigestFrequency::Weekly => 7,
+                };
+                // Um dia a mais cobre as mudanças de horário de verão; o período seguinte é recalculado
+                settings
+                    .latest_period(period.end + Duration::days(step) + Duration::hours(1))
+                    .map(|next| next.end)
+                    .filter(|end| *end > now)
+            });
+
+        DigestSettingsResponse {
+            frequency: settings.frequency,
+            hour: settings.hour,
+            weekday: settings.weekday,
+            timezone: settings.timezone,
+            max_articles: settings.max_articles,
+            min_rate: settings.min_rate,
+            enabled: settings.enabled,
+            next_send_at,
+            created_at: settings.created_at,
+            updated_at: settings.updated_at,
+        }
+    }
+}
+
+/// Registro de um resumo enviado; um por usuário e período, para não repetir envios
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SentDigest {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    /// Artigos incluídos; 0 quando o período não teve artigos e nenhum email foi enviado
+    pub article_count: i32,
+    pub sent_at: DateTime<Utc>,
+}
+
+impl SentDigest {
+    pub fn new(user_id: Uuid, period: DigestPeriod, article_count: i32) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            period_start: period.start,
+            period_end: period.end,
+            article_count,
+            sent_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SentDigestResponse {
+    pub id: Uuid,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub article_count: i32,
+    pub sent_at: DateTime<Utc>,
+}
+
+impl From<SentDigest> for SentDigestResponse {
+    fn from(sent: SentDigest) -> Self {
+        SentDigestResponse {
+            id: sent.id,
+            period_start: sent.period_start,
+            period_end: sent.period_end,
+            article_count: sent.article_count,
+            sent_at: sent.sent_at,
+        }
+    }
+}
+
+/// Resumo montado para um período: os artigos escolhidos e o email renderizado
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DigestPreview {
+    pub period: DigestPeriod,
+    pub articles: Vec<ArticleResponse>,
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: String,
+}
@@ -0,0 +1,77 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Vínculo entre um usuário e uma conta em um provedor de identidade externo (OIDC)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UserIdentity {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub provider: String,
+    /// Claim `sub` do provedor
+    pub subject: String,
+    pub email: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_login_at: DateTime<Utc>,
+}
+
+impl UserIdentity {
+    pub fn new(user_id: Uuid, provider: String, subject: String, email: Option<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            provider,
+            subject,
+            email,
+            created_at: now,
+            last_login_at: now,
+        }
+    }
+}
+
+/// Login OIDC iniciado e aguardando o retorno do provedor.
+/// Guarda o `code_verifier` do PKCE e o `nonce` no servidor; o `state` é armazenado como hash.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OidcAuthRequest {
+    pub state_hash: String,
+    pub provider: String,
+    pub code_verifier: String,
+    pub nonce: String,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OidcAuthRequest {
+    pub fn new(state_hash: String, provider: String, code_verifier: String, nonce: String, ttl: Duration) -> Self {
+        let now = Utc::now();
+        Self {
+            state_hash,
+            provider,
+            code_verifier,
+            nonce,
+            expires_at: now + ttl,
+            created_at: now,
+        }
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct OidcCallbackDto {
+    #[validate(length(min = 1, message = "Authorization code is required"))]
+    pub code: String,
+
+    #[validate(length(min = 1, message = "State is required"))]
+    pub state: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OidcAuthorizationResponse {
+    pub authorization_url: String,
+    pub state: String,
+}
@@ -0,0 +1,146 @@
+use crate::domain::entities::article_stream::ArticleStreamEvent;
+use crate::domain::entities::read_state::ReadStateEvent;
+use crate::domain::entities::webhook::WebhookEventType;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+use uuid::Uuid;
+
+/// Tópicos que uma conexão WebSocket pode assinar ao mesmo tempo
+pub const MAX_LIVE_TOPICS: usize = 100;
+
+/// Validade de um ticket de `POST /live/ticket`: só precisa durar até o handshake do WebSocket
+pub const STREAM_TICKET_TTL_SECONDS: i64 = 60;
+
+/// Resposta de `POST /live/ticket`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamTicketResponse {
+    pub ticket: String,
+    pub expires_in: i64,
+}
+
+/// Tópico de uma conexão WebSocket: `feed:<id>`, `category:<id>` ou `article:<id>`
+/// (o progresso do enriquecimento de um artigo pela IA)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LiveTopic {
+    Feed(Uuid),
+    Category(Uuid),
+    Article(Uuid),
+}
+
+impl LiveTopic {
+    pub fn parse(value: &str) -> Option<Self> {
+        let (kind, id) = value.split_once(':')?;
+        let id = id.parse().ok()?;
+        match kind {
+            "feed" => Some(LiveTopic::Feed(id)),
+            "category" => Some(LiveTopic::Category(id)),
+            "article" => Some(LiveTopic::Article(id)),
+            _ => None,
+        }
+    }
+
+    pub fn matches(&self, event: &ArticleStreamEvent) -> bool {
+        match self {
+            LiveTopic::Feed(id) => event.feed_id == Some(*id),
+            LiveTopic::Category(id) => event.category_id == Some(*id),
+            LiveTopic::Article(id) => event.article_id == *id,
+        }
+    }
+}
+
+impl fmt::Display for LiveTopic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LiveTopic::Feed(id) => write!(f, "feed:{}", id),
+            LiveTopic::Category(id) => write!(f, "category:{}", id),
+            LiveTopic::Article(id) => write!(f, "article:{}", id),
+        }
+    }
+}
+
+/// Tópicos assinados por uma conexão
+#[derive(Debug, Clone, Default)]
+pub struct LiveSubscriptions {
+    topics: BTreeSet<LiveTopic>,
+}
+
+impl LiveSubscriptions {
+    /// Retorna `false` se a conexão já estiver no limite de tópicos; assinar de novo não conta
+    pub fn subscribe(&mut self, topic: LiveTopic) -> bool {
+        if self.topics.len() >= MAX_LIVE_TOPICS && !self.topics.contains(&topic) {
+            return false;
+        }
+        self.topics.insert(topic);
+        true
+    }
+
+    pub fn unsubscribe(&mut self, topic: &LiveTopic) -> bool {
+        self.topics.remove(topic)
+    }
+
+    /// Tópicos assinados que o evento atinge, para enviá-lo uma vez só
+    pub fn matching(&self, event: &ArticleStreamEvent) -> Vec<LiveTopic> {
+        self.topics.iter().filter(|topic| topic.matches(event)).copied().collect()
+    }
+}
+
+/// Mensagem de texto (JSON) enviada pelo cliente
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LiveClientMessage {
+    Subscribe { topic: String },
+    Unsubscribe { topic: String },
+    MarkRead { article_id: Uuid },
+    MarkUnread { article_id: Uuid },
+    /// Heartbeat da aplicação, para clientes que não enxergam os pings do protocolo
+    Ping,
+}
+
+/// Mensagem de texto (JSON) enviada pelo servidor
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LiveServerMessage {
+    Welcome {
+        user_id: Uuid,
+        heartbeat_seconds: u64,
+    },
+    Subscribed {
+        topic: String,
+    },
+    Unsubscribed {
+        topic: String,
+    },
+    /// Artigo criado, atualizado ou enriquecido em algum tópico assinado
+    Article {
+        seq: i64,
+        event: WebhookEventType,
+        topics: Vec<String>,
+        article: serde_json::Value,
+    },
+    /// Mudança no estado de leitura feita em qualquer dispositivo do usuário
+    ReadState(ReadStateEvent),
+    /// A conexão perdeu eventos; o cliente deve recarregar a lista
+    Resync,
+    Pong,
+    Error {
+        message: String,
+    },
+}
+
+impl LiveServerMessage {
+    pub fn article(event: &ArticleStreamEvent, topics: &[LiveTopic]) -> Self {
+        LiveServerMessage::Article {
+            seq: event.seq,
+            event: event.event_type,
+            topics: topics.iter().map(LiveTopic::to_string).collect(),
+            article: event.data.clone(),
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        LiveServerMessage::Error {
+            message: message.into(),
+        }
+    }
+}
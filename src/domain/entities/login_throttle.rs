@@ -0,0 +1,137 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Escopo em que as tentativas de login com falha são contabilizadas
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum ThrottleScope {
+    Account,
+    Ip,
+}
+
+impl ThrottleScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThrottleScope::Account => "account",
+            ThrottleScope::Ip => "ip",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "account" => Some(ThrottleScope::Account),
+            "ip" => Some(ThrottleScope::Ip),
+            _ => None,
+        }
+    }
+}
+
+/// Política de bloqueio após tentativas de login com falha
+#[derive(Debug, Clone, PartialEq)]
+pub struct LockoutPolicy {
+    pub max_failed_attempts_per_account: i32,
+    pub max_failed_attempts_per_ip: i32,
+    /// Falhas mais antigas que esta janela deixam de contar
+    pub attempt_window: Duration,
+    /// Duração do primeiro bloqueio; dobra a cada bloqueio consecutivo
+    pub base_lockout: Duration,
+    /// Limite superior da duração de um bloqueio
+    pub max_lockout: Duration,
+}
+
+impl Default for LockoutPolicy {
+    fn default() -> Self {
+        Self {
+            max_failed_attempts_per_account: 5,
+            max_failed_attempts_per_ip: 20,
+            attempt_window: Duration::minutes(15),
+            base_lockout: Duration::minutes(1),
+            max_lockout: Duration::hours(24),
+        }
+    }
+}
+
+impl LockoutPolicy {
+    pub fn threshold(&self, scope: ThrottleScope) -> i32 {
+        match scope {
+            ThrottleScope::Account => self.max_failed_attempts_per_account,
+            ThrottleScope::Ip => self.max_failed_attempts_per_ip,
+        }
+    }
+
+    /// Duração do bloqueio dado o número de bloqueios anteriores
+    pub fn lockout_duration(&self, previous_lockouts: i32) -> Duration {
+        let exponent = previous_lockouts.clamp(0, 30) as u32;
+        let seconds = self
+            .base_lockout
+            .num_seconds()
+            .saturating_mul(2i64.saturating_pow(exponent));
+
+        Duration::seconds(seconds.min(self.max_lockout.num_seconds()))
+    }
+}
+
+/// Contador de tentativas de login com falha para uma conta ou IP
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LoginThrottle {
+    pub scope: ThrottleScope,
+    pub subject: String,
+    pub failed_attempts: i32,
+    pub lockout_count: i32,
+    pub locked_until: Option<DateTime<Utc>>,
+    pub last_failed_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl LoginThrottle {
+    pub fn new(scope: ThrottleScope, subject: impl Into<String>) -> Self {
+        Self {
+            scope,
+            subject: subject.into(),
+            failed_attempts: 0,
+            lockout_count: 0,
+            locked_until: None,
+            last_failed_at: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn is_locked(&self, now: DateTime<Utc>) -> bool {
+        self.locked_until.is_some_and(|until| until > now)
+    }
+
+    /// Conta uma falha, descartando as que saíram da janela e a escalada de bloqueios antiga
+    pub fn record_failure(&mut self, policy: &LockoutPolicy, now: DateTime<Utc>) {
+        if let Some(last_failed_at) = self.last_failed_at {
+            if now - last_failed_at > policy.attempt_window {
+                self.failed_attempts = 0;
+            }
+
+            // Um longo período sem falhas após o último bloqueio zera a escalada
+            let quiet_since = self
+                .locked_until
+                .map_or(last_failed_at, |until| until.max(last_failed_at));
+            if now - quiet_since > policy.max_lockout {
+                self.lockout_count = 0;
+            }
+        }
+
+        self.failed_attempts += 1;
+        self.last_failed_at = Some(now);
+        self.updated_at = now;
+    }
+
+    /// Fim do bloqueio que as falhas contadas acionam, se o limite foi atingido
+    pub fn lockout(&self, policy: &LockoutPolicy, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        (self.failed_attempts >= policy.threshold(self.scope))
+            .then(|| now + policy.lockout_duration(self.lockout_count))
+    }
+
+    /// Bloqueia até `locked_until` e recomeça a contagem de falhas
+    pub fn apply_lockout(&mut self, locked_until: DateTime<Utc>, now: DateTime<Utc>) {
+        self.locked_until = Some(locked_until);
+        self.lockout_count += 1;
+        self.failed_attempts = 0;
+        self.updated_at = now;
+    }
+}
@@ -0,0 +1,101 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Configuração de autenticação em dois fatores (TOTP) de um usuário
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UserMfa {
+    pub user_id: Uuid,
+    /// Segredo TOTP em Base32; precisa ser recuperável para calcular os códigos
+    pub secret: String,
+    pub enabled: bool,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    /// Última janela TOTP aceita, usada para impedir o reuso de um código
+    pub last_used_step: Option<i64>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl UserMfa {
+    /// Inicia um cadastro pendente de confirmação
+    pub fn new(user_id: Uuid, secret: String) -> Self {
+        let now = Utc::now();
+        Self {
+            user_id,
+            secret,
+            enabled: false,
+            confirmed_at: None,
+            last_used_step: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Ativa o segundo fator após o primeiro código válido
+    pub fn confirm(&mut self, step: i64) {
+        let now = Utc::now();
+        self.enabled = true;
+        self.confirmed_at = Some(now);
+        self.last_used_step = Some(step);
+        self.updated_at = now;
+    }
+
+    /// Registra o uso de um código para que ele não seja aceito novamente
+    pub fn mark_step_used(&mut self, step: i64) {
+        self.last_used_step = Some(step);
+        self.updated_at = Utc::now();
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ConfirmTotpDto {
+    #[validate(length(min = 1, message = "Code is required"))]
+    pub code: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct DisableTotpDto {
+    #[validate(length(min = 1, message = "Password is required"))]
+    pub password: String,
+}
+
+/// Segunda etapa do login: troca o token "mfa pending" por um token de acesso.
+/// `code` aceita tanto o código TOTP quanto um código de recuperação.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct VerifyMfaDto {
+    #[validate(length(min = 1, message = "MFA token is required"))]
+    pub mfa_token: String,
+
+    #[validate(length(min = 1, message = "Code is required"))]
+    pub code: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TotpEnrollmentResponse {
+    pub secret: String,
+    pub provisioning_uri: String,
+}
+
+/// Códigos de recuperação em texto puro; exibidos uma única vez
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecoveryCodesResponse {
+    pub recovery_codes: Vec<String>,
+}
+
+/// Resposta do login quando o usuário possui 2FA ativo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MfaChallengeResponse {
+    pub mfa_required: bool,
+    pub mfa_token: String,
+    pub expires_in: i64,
+}
+
+/// Resposta do login quando o papel do usuário exige 2FA e ele ainda não foi ativado;
+/// o token só vale para cadastrar e confirmar o TOTP
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MfaEnrollmentRequiredResponse {
+    pub mfa_enrollment_required: bool,
+    pub enrollment_token: String,
+    pub expires_in: i64,
+}
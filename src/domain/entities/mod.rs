@@ -1,4 +1,27 @@
 // Módulo de entidades do domínio
 pub mod user;
 pub mod article_category;
-pub mod article;
\ No newline at end of file
+pub mod article;
+pub mod login_throttle;
+pub mod email_change_request;
+pub mod mfa;
+pub mod api_key;
+pub mod session;
+pub mod identity;
+pub mod subscription;
+pub mod collection;
+pub mod saved_article;
+pub mod reaction;
+pub mod annotation;
+pub mod tag;
+pub mod ranking;
+pub mod smart_folder;
+pub mod alert;
+pub mod digest;
+pub mod webhook;
+pub mod article_stream;
+pub mod read_state;
+pub mod live_session;
+pub mod syndication;
+pub mod article_export;
+pub mod article_import;
\ No newline at end of file
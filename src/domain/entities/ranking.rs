@@ -0,0 +1,80 @@
+use crate::domain::entities::article::ArticleResponse;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Pesos do ranking "Para você": cada componente vai de 0 a 1 (a afinidade, de -1 a 1)
+/// antes de ser multiplicado pelo seu peso
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankingWeights {
+    /// Peso da nota da IA (`rate`)
+    pub quality: f64,
+    /// Peso da idade do artigo
+    pub recency: f64,
+    /// Peso da afinidade do usuário com a categoria do artigo
+    pub affinity: f64,
+    /// Horas para o componente de recência cair pela metade
+    pub half_life_hours: f64,
+}
+
+impl Default for RankingWeights {
+    fn default() -> Self {
+        Self {
+            quality: 0.4,
+            recency: 0.35,
+            affinity: 0.25,
+            half_life_hours: 24.0,
+        }
+    }
+}
+
+/// Leituras e notas de um usuário nos artigos de uma categoria
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryEngagement {
+    pub category_id: Uuid,
+    pub reads: i64,
+    pub rating_count: i64,
+    /// Média das notas que o usuário deu aos artigos da categoria
+    pub rating_avg: Option<f64>,
+}
+
+impl CategoryEngagement {
+    pub fn new(category_id: Uuid) -> Self {
+        Self {
+            category_id,
+            reads: 0,
+            rating_count: 0,
+            rating_avg: None,
+        }
+    }
+}
+
+/// Composição da pontuação de um artigo: as contribuições já ponderadas e o peso do feed
+/// que multiplica a soma delas
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct ScoreBreakdown {
+    pub quality: f64,
+    pub recency: f64,
+    /// Negativa para categorias que o usuário costuma avaliar mal
+    pub affinity: f64,
+    pub feed_weight: f64,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RankedArticle {
+    #[serde(flatten)]
+    pub article: ArticleResponse,
+    pub score: f64,
+    /// Presente apenas com `explain=true`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score_breakdown: Option<ScoreBreakdown>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaginatedRankedArticles {
+    pub articles: Vec<RankedArticle>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+    pub total_pages: i64,
+}
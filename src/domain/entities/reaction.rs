@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Reação de um usuário a um artigo: `like`, `dislike` ou um emoji.
+/// `like` e `dislike` são mutuamente exclusivos; emojis podem ser combinados livremente
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Reaction {
+    Like,
+    Dislike,
+    Emoji(String),
+}
+
+impl Reaction {
+    /// Limite de caracteres de um emoji, suficiente para sequências com modificadores (ex.: 👍🏽, 🧑‍💻)
+    const MAX_EMOJI_CHARS: usize = 8;
+
+    /// Interpreta o valor recebido na URL; texto comum (letras, dígitos, espaços) não é aceito como emoji
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "like" => Some(Reaction::Like),
+            "dislike" => Some(Reaction::Dislike),
+            emoji
+                if !emoji.is_empty()
+                    && emoji.chars().count() <= Self::MAX_EMOJI_CHARS
+                    && emoji.chars().all(|c| !c.is_alphanumeric() && !c.is_whitespace() && !c.is_ascii()) =>
+            {
+                Some(Reaction::Emoji(emoji.to_string()))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Reaction::Like => "like",
+            Reaction::Dislike => "dislike",
+            Reaction::Emoji(emoji) => emoji,
+        }
+    }
+
+    /// A reação que deixa de valer quando esta é adicionada
+    pub fn opposite(&self) -> Option<Reaction> {
+        match self {
+            Reaction::Like => Some(Reaction::Dislike),
+            Reaction::Dislike => Some(Reaction::Like),
+            Reaction::Emoji(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReactionCount {
+    pub reaction: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArticleReactionsResponse {
+    pub article_id: Uuid,
+    /// Total por reação, da mais usada para a menos usada
+    pub counts: Vec<ReactionCount>,
+    /// Reações do usuário autenticado (vazio em requisições anônimas)
+    pub mine: Vec<String>,
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Mudança no estado de leitura de um usuário, repassada aos outros dispositivos dele
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReadStateEvent {
+    pub user_id: Uuid,
+    #[serde(flatten)]
+    pub change: ReadStateChange,
+    pub at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum ReadStateChange {
+    Read { article_id: Uuid },
+    Unread { article_id: Uuid },
+    /// "Marcar todos como lidos"; os clientes recarregam a lista e os contadores
+    AllRead {
+        feed_id: Option<Uuid>,
+        category_id: Option<Uuid>,
+        before: Option<DateTime<Utc>>,
+        marked: u64,
+    },
+}
+
+impl ReadStateEvent {
+    pub fn new(user_id: Uuid, change: ReadStateChange) -> Self {
+        Self {
+            user_id,
+            change,
+            at: Utc::now(),
+        }
+    }
+}
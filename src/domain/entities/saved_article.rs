@@ -0,0 +1,100 @@
+use crate::domain::entities::article::Article;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Artigo salvo por um usuário. Os campos principais são copiados no momento em que ele é salvo,
+/// para que o item continue legível se o artigo (ou o feed) for removido depois
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SavedArticle {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    /// `None` quando o artigo original foi removido
+    pub article_id: Option<Uuid>,
+    pub collection_id: Option<Uuid>,
+    /// Ordem entre os itens salvos do usuário (menor primeiro)
+    pub position: i32,
+    pub title: String,
+    pub description: String,
+    pub link: String,
+    pub media: Option<String>,
+    pub creator: String,
+    pub feed_id: Uuid,
+    pub pub_date: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl SavedArticle {
+    pub fn from_article(user_id: Uuid, article: &Article, collection_id: Option<Uuid>, position: i32) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            article_id: Some(article.id),
+            collection_id,
+            position,
+            title: article.title.clone(),
+            description: article.description.clone(),
+            link: article.link.clone(),
+            media: article.media.clone(),
+            creator: article.creator.clone(),
+            feed_id: article.feed_id,
+            pub_date: article.pub_date,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveArticleDto {
+    pub article_id: Uuid,
+    pub collection_id: Option<Uuid>,
+}
+
+/// Move um item salvo para outra coleção; `null` o deixa fora de qualquer coleção
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveSavedArticleDto {
+    pub collection_id: Option<Uuid>,
+}
+
+/// Nova ordem dos itens de uma coleção (ou dos itens sem coleção, se `collection_id` for `null`).
+/// `ids` deve conter exatamente os itens desse grupo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReorderSavedArticlesDto {
+    pub collection_id: Option<Uuid>,
+    pub ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedArticleResponse {
+    pub id: Uuid,
+    pub article_id: Option<Uuid>,
+    pub collection_id: Option<Uuid>,
+    pub position: i32,
+    pub title: String,
+    pub description: String,
+    pub link: String,
+    pub media: Option<String>,
+    pub creator: String,
+    pub feed_id: Uuid,
+    pub pub_date: DateTime<Utc>,
+    pub saved_at: DateTime<Utc>,
+}
+
+impl From<SavedArticle> for SavedArticleResponse {
+    fn from(saved: SavedArticle) -> Self {
+        SavedArticleResponse {
+            id: saved.id,
+            article_id: saved.article_id,
+            collection_id: saved.collection_id,
+            position: saved.position,
+            title: saved.title,
+            description: saved.description,
+            link: saved.link,
+            media: saved.media,
+            creator: saved.creator,
+            feed_id: saved.feed_id,
+            pub_date: saved.pub_date,
+            saved_at: saved.created_at,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Tamanho máximo do rótulo do dispositivo (coluna `device_label`)
+const DEVICE_LABEL_MAX_LENGTH: usize = 100;
+
+/// Dispositivo/cliente que abriu a sessão
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionClient {
+    pub device_label: Option<String>,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+/// Sessão de login: cada token de acesso carrega o id da sessão (`sid`),
+/// permitindo listar os dispositivos conectados e revogar um deles.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Session {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub device_label: Option<String>,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl Session {
+    pub fn new(user_id: Uuid, client: SessionClient, expires_at: DateTime<Utc>) -> Self {
+        let now = Utc::now();
+        let device_label = client
+            .device_label
+            .map(|label| label.trim().chars().take(DEVICE_LABEL_MAX_LENGTH).collect::<String>())
+            .filter(|label| !label.is_empty())
+            .or_else(|| client.user_agent.as_deref().and_then(Self::label_from_user_agent));
+
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            device_label,
+            user_agent: client.user_agent,
+            ip_address: client.ip_address,
+            created_at: now,
+            last_seen_at: now,
+            expires_at,
+            revoked_at: None,
+        }
+    }
+
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+
+    /// Indica se `last_seen_at` está desatualizado o bastante para justificar uma escrita
+    pub fn needs_last_seen_update(&self, now: DateTime<Utc>, interval: Duration) -> bool {
+        now - self.last_seen_at >= interval
+    }
+
+    /// Rótulo legível ("Firefox on Linux") quando o cliente não informa um nome
+    pub fn label_from_user_agent(user_agent: &str) -> Option<String> {
+        // A ordem importa: Edge e Opera também se anunciam como Chrome, e Chrome como Safari
+        const BROWSERS: &[(&str, &str)] = &[
+            ("Edg/", "Edge"),
+            ("OPR/", "Opera"),
+            ("Firefox/", "Firefox"),
+            ("Chrome/", "Chrome"),
+            ("Safari/", "Safari"),
+            ("curl/", "curl"),
+        ];
+        const SYSTEMS: &[(&str, &str)] = &[
+            ("Android", "Android"),
+            ("iPhone", "iOS"),
+            ("iPad", "iPadOS"),
+            ("Windows", "Windows"),
+            ("Mac OS X", "macOS"),
+            ("Linux", "Linux"),
+        ];
+
+        let browser = BROWSERS
+            .iter()
+            .find(|(token, _)| user_agent.contains(token))
+            .map(|(_, name)| *name);
+        let system = SYSTEMS
+            .iter()
+            .find(|(token, _)| user_agent.contains(token))
+            .map(|(_, name)| *name);
+
+        match (browser, system) {
+            (Some(browser), Some(system)) => Some(format!("{} on {}", browser, system)),
+            (Some(name), None) | (None, Some(name)) => Some(name.to_string()),
+            (None, None) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionResponse {
+    pub id: Uuid,
+    pub device_label: Option<String>,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    /// Sessão do token usado nesta requisição
+    pub current: bool,
+}
+
+impl SessionResponse {
+    pub fn from_session(session: Session, current_session_id: Option<Uuid>) -> Self {
+        SessionResponse {
+            current: current_session_id == Some(session.id),
+            id: session.id,
+            device_label: session.device_label,
+            user_agent: session.user_agent,
+            ip_address: session.ip_address,
+            created_at: session.created_at,
+            last_seen_at: session.last_seen_at,
+            expires_at: session.expires_at,
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::domain::entities::article::{ArticleListFilter, ArticleSort};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Critérios de uma pasta inteligente: os mesmos filtros e ordenação de `GET /me/articles`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, PartialEq)]
+pub struct SmartFolderCriteria {
+    /// Texto procurado no título, na descrição e no conteúdo
+    #[validate(length(max = 200, message = "Search must have at most 200 characters"))]
+    pub search: Option<String>,
+
+    pub category_id: Option<Uuid>,
+
+    /// Tag do próprio usuário
+    pub tag_id: Option<Uuid>,
+
+    /// Apenas artigos ainda não lidos
+    #[serde(default)]
+    pub unread: bool,
+
+    /// Notas na mesma escala da IA e dos usuários, de 0 a 10
+    #[validate(range(min = 0, max = 10, message = "Minimum rate must be between 0 and 10"))]
+    pub min_rate: Option<i32>,
+
+    #[validate(range(min = 0.0, max = 10.0, message = "Minimum user rating must be between 0 and 10"))]
+    pub min_user_rating: Option<f64>,
+
+    #[serde(default)]
+    pub sort: ArticleSort,
+}
+
+impl SmartFolderCriteria {
+    /// Remove os espaços da busca; uma busca vazia deixa de filtrar
+    pub fn normalized(mut self) -> Self {
+        self.search = self
+            .search
+            .as_deref()
+            .map(str::trim)
+            .filter(|search| !search.is_empty())
+            .map(str::to_string);
+        self
+    }
+
+    /// Filtro da listagem de artigos; a tag é resolvida pela timeline
+    pub fn filter(&self) -> ArticleListFilter {
+        ArticleListFilter {
+            category_id: self.category_id,
+            search: self.search.clone(),
+            unread_only: self.unread,
+            min_rate: self.min_rate,
+            min_user_rating: self.min_user_rating,
+            sort: self.sort,
+            ..Default::default()
+        }
+    }
+}
+
+/// Busca salva com nome, avaliada no servidor sobre os feeds assinados pelo usuário
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SmartFolder {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub criteria: SmartFolderCriteria,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SmartFolder {
+    pub fn new(user_id: Uuid, name: &str, criteria: SmartFolderCriteria) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            name: name.trim().to_string(),
+            criteria: criteria.normalized(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn rename(&mut self, name: &str) {
+        self.name = name.trim().to_string();
+        self.updated_at = Utc::now();
+    }
+
+    /// Substitui todos os critérios de uma vez
+    pub fn set_criteria(&mut self, criteria: SmartFolderCriteria) {
+        self.criteria = criteria.normalized();
+        self.updated_at = Utc::now();
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CreateSmartFolderDto {
+    #[validate(length(min = 1, max = 100, message = "Name must have between 1 and 100 characters"))]
+    pub name: String,
+
+    #[serde(default)]
+    #[validate(nested)]
+    pub criteria: SmartFolderCriteria,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct UpdateSmartFolderDto {
+    #[validate(length(min = 1, max = 100, message = "Name must have between 1 and 100 characters"))]
+    pub name: Option<String>,
+
+    /// Novos critérios, substituindo os anteriores por completo
+    #[validate(nested)]
+    pub criteria: Option<SmartFolderCriteria>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmartFolderResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub criteria: SmartFolderCriteria,
+    /// Artigos não lidos que atendem aos critérios, presente apenas na listagem
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread: Option<i64>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<SmartFolder> for SmartFolderResponse {
+    fn from(folder: SmartFolder) -> Self {
+        SmartFolderResponse {
+            id: folder.id,
+            name: folder.name,
+            criteria: folder.criteria,
+            unread: None,
+            created_at: folder.created_at,
+            updated_at: folder.updated_at,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Assinatura de um feed por um usuário; define quais artigos aparecem na timeline pessoal
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Subscription {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub feed_id: Uuid,
+    /// Título escolhido pelo usuário; sem ele, os clientes exibem o nome do feed
+    pub title: Option<String>,
+    pub folder: Option<String>,
+    /// Peso do feed no ranking "Para você" (0 tira o feed do ranking, 1 é neutro)
+    pub weight: f64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Subscription {
+    pub const DEFAULT_WEIGHT: f64 = 1.0;
+
+    pub fn new(user_id: Uuid, dto: CreateSubscriptionDto) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            feed_id: dto.feed_id,
+            title: dto.title.and_then(Self::normalize),
+            folder: dto.folder.and_then(Self::normalize),
+            weight: dto.weight.unwrap_or(Self::DEFAULT_WEIGHT),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Aplica uma alteração parcial; um valor vazio remove o título ou a pasta
+    pub fn apply(&mut self, dto: UpdateSubscriptionDto) {
+        if let Some(title) = dto.title {
+            self.title = Self::normalize(title);
+        }
+        if let Some(folder) = dto.folder {
+            self.folder = Self::normalize(folder);
+        }
+        if let Some(weight) = dto.weight {
+            self.weight = weight;
+        }
+        self.updated_at = Utc::now();
+    }
+
+    fn normalize(value: String) -> Option<String> {
+        let value = value.trim();
+        (!value.is_empty()).then(|| value.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CreateSubscriptionDto {
+    pub feed_id: Uuid,
+
+    #[validate(length(max = 255, message = "Title must be at most 255 characters"))]
+    pub title: Option<String>,
+
+    #[validate(length(max = 100, message = "Folder must be at most 100 characters"))]
+    pub folder: Option<String>,
+
+    #[validate(range(min = 0.0, max = 5.0, message = "Weight must be between 0 and 5"))]
+    pub weight: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct UpdateSubscriptionDto {
+    #[validate(length(max = 255, message = "Title must be at most 255 characters"))]
+    pub title: Option<String>,
+
+    #[validate(length(max = 100, message = "Folder must be at most 100 characters"))]
+    pub folder: Option<String>,
+
+    #[validate(range(min = 0.0, max = 5.0, message = "Weight must be between 0 and 5"))]
+    pub weight: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscriptionResponse {
+    pub id: Uuid,
+    pub feed_id: Uuid,
+    pub title: Option<String>,
+    pub folder: Option<String>,
+    pub weight: f64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Subscription> for SubscriptionResponse {
+    fn from(subscription: Subscription) -> Self {
+        SubscriptionResponse {
+            id: subscription.id,
+            feed_id: subscription.feed_id,
+            title: subscription.title,
+            folder: subscription.folder,
+            weight: subscription.weight,
+            created_at: subscription.created_at,
+            updated_at: subscription.updated_at,
+        }
+    }
+}
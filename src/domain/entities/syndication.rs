@@ -0,0 +1,120 @@
+use crate::domain::entities::article::ArticleResponse;
+use crate::domain::entities::saved_article::SavedArticle;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Itens publicados em cada feed de saída (os mais recentes)
+pub const SYNDICATION_ITEM_LIMIT: i64 = 50;
+
+/// Formato de um feed de saída
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+    /// RSS 2.0 (`feed.xml`)
+    Rss,
+    /// Atom 1.0 (`feed.atom`)
+    Atom,
+    /// JSON Feed 1.1 (`feed.json`)
+    Json,
+}
+
+impl FeedFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+            FeedFormat::Json => "application/feed+json; charset=utf-8",
+        }
+    }
+}
+
+/// Feed de saída com o conteúdo curado (uma categoria ou uma coleção compartilhada),
+/// independente do formato em que vai ser publicado
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyndicationFeed {
+    /// Identificador estável do feed (`urn:uuid:...`)
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    /// Data do item mais recente, ou da origem do feed se ele estiver vazio
+    pub updated: DateTime<Utc>,
+    pub items: Vec<SyndicationItem>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyndicationItem {
+    pub id: Uuid,
+    pub title: String,
+    pub link: String,
+    /// O resumo da IA quando existe; senão, a descrição original
+    pub summary: String,
+    /// Conteúdo completo em HTML (vazio nos itens sem o artigo original)
+    pub content: String,
+    pub author: String,
+    pub image: Option<String>,
+    /// Nome da categoria seguido das palavras-chave
+    pub tags: Vec<String>,
+    pub published: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+}
+
+impl SyndicationFeed {
+    pub fn new(id: Uuid, title: String, description: String, fallback_updated: DateTime<Utc>, items: Vec<SyndicationItem>) -> Self {
+        let updated = items.iter().map(|item| item.updated).max().unwrap_or(fallback_updated);
+        Self {
+            id: format!("urn:uuid:{}", id),
+            title,
+            description,
+            updated,
+            items,
+        }
+    }
+}
+
+impl SyndicationItem {
+    pub fn from_article(article: &ArticleResponse, category_name: Option<&str>) -> Self {
+        let summary = article
+            .ai_summary
+            .as_deref()
+            .map(str::trim)
+            .filter(|summary| !summary.is_empty())
+            .unwrap_or(&article.description)
+            .to_string();
+
+        let mut tags: Vec<String> = category_name.map(str::to_string).into_iter().collect();
+        for keyword in article.keywords.as_deref().unwrap_or_default().split(',') {
+            let keyword = keyword.trim();
+            if !keyword.is_empty() && !tags.iter().any(|tag| tag.eq_ignore_ascii_case(keyword)) {
+                tags.push(keyword.to_string());
+            }
+        }
+
+        Self {
+            id: article.id,
+            title: article.title.clone(),
+            link: article.link.clone(),
+            summary,
+            content: article.content.clone(),
+            author: article.creator.clone(),
+            image: article.media.clone(),
+            tags,
+            published: article.pub_date,
+            updated: article.created_at.max(article.pub_date),
+        }
+    }
+
+    /// Item de uma coleção cujo artigo original foi removido: usa a cópia salva
+    pub fn from_saved(saved: &SavedArticle) -> Self {
+        Self {
+            id: saved.article_id.unwrap_or(saved.id),
+            title: saved.title.clone(),
+            link: saved.link.clone(),
+            summary: saved.description.clone(),
+            content: String::new(),
+            author: saved.creator.clone(),
+            image: saved.media.clone(),
+            tags: Vec::new(),
+            published: saved.pub_date,
+            updated: saved.created_at.max(saved.pub_date),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Tag criada pelo usuário para organizar artigos, independente das `keywords` geradas pela IA.
+/// O nome é único por usuário sem diferenciar maiúsculas de minúsculas
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Tag {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Tag {
+    pub fn new(user_id: Uuid, name: &str) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            name: Self::normalize_name(name),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn rename(&mut self, name: &str) {
+        self.name = Self::normalize_name(name);
+        self.updated_at = Utc::now();
+    }
+
+    /// Remove espaços nas pontas e reduz espaços internos a um só
+    pub fn normalize_name(name: &str) -> String {
+        name.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    /// Chave usada para comparar nomes de tags
+    pub fn name_key(name: &str) -> String {
+        Self::normalize_name(name).to_lowercase()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CreateTagDto {
+    #[validate(length(min = 1, max = 50, message = "Name must have between 1 and 50 characters"))]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct RenameTagDto {
+    #[validate(length(min = 1, max = 50, message = "Name must have between 1 and 50 characters"))]
+    pub name: String,
+}
+
+/// Junta a tag da URL à tag `into`: os artigos passam para `into` e a tag de origem é removida
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeTagDto {
+    pub into: Uuid,
+}
+
+/// Substitui as tags do usuário em um artigo; nomes ainda inexistentes viram tags novas
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct SetArticleTagsDto {
+    #[validate(length(max = 20, message = "An article can have at most 20 tags"))]
+    pub names: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TagResponse {
+    pub id: Uuid,
+    pub name: String,
+}
+
+impl From<Tag> for TagResponse {
+    fn from(tag: Tag) -> Self {
+        TagResponse {
+            id: tag.id,
+            name: tag.name,
+        }
+    }
+}
+
+/// Tag com a quantidade de artigos marcados, para listagem e autocompletar
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TagSummary {
+    pub id: Uuid,
+    pub name: String,
+    pub article_count: i64,
+}
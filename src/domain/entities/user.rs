@@ -3,12 +3,47 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 
+/// Papel do usuário, usado para autorização de rotas administrativas
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum UserRole {
+    #[default]
+    Reader,
+    Editor,
+    Admin,
+}
+
+impl UserRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserRole::Reader => "reader",
+            UserRole::Editor => "editor",
+            UserRole::Admin => "admin",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "reader" => Some(UserRole::Reader),
+            "editor" => Some(UserRole::Editor),
+            "admin" => Some(UserRole::Admin),
+            _ => None,
+        }
+    }
+
+    /// Editores e administradores só recebem sessão depois de ativar o 2FA
+    pub fn requires_mfa(&self) -> bool {
+        matches!(self, UserRole::Editor | UserRole::Admin)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct User {
     pub id: Uuid,
     pub email: String,
     pub password_hash: String,
     pub name: String,
+    pub role: UserRole,
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -35,6 +70,36 @@ pub struct LoginDto {
     pub password: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct UpdateProfileDto {
+    #[validate(length(min = 2, message = "Name must be at least 2 characters"))]
+    pub name: Option<String>,
+
+    #[validate(email(message = "Invalid email format"))]
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ChangePasswordDto {
+    #[validate(length(min = 1, message = "Current password is required"))]
+    pub current_password: String,
+
+    #[validate(length(min = 8, message = "Password must be at least 8 characters"))]
+    pub new_password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct DeleteAccountDto {
+    #[validate(length(min = 1, message = "Password is required"))]
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ConfirmEmailChangeDto {
+    #[validate(length(min = 1, message = "Token is required"))]
+    pub token: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthResponse {
     pub token: String,
@@ -46,15 +111,25 @@ pub struct UserResponse {
     pub id: Uuid,
     pub email: String,
     pub name: String,
+    pub role: UserRole,
     pub created_at: DateTime<Utc>,
 }
 
+/// Perfil do usuário autenticado, incluindo uma troca de email pendente
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileResponse {
+    #[serde(flatten)]
+    pub user: UserResponse,
+    pub pending_email: Option<String>,
+}
+
 impl From<User> for UserResponse {
     fn from(user: User) -> Self {
         UserResponse {
             id: user.id,
             email: user.email,
             name: user.name,
+            role: user.role,
             created_at: user.created_at,
         }
     }
@@ -68,9 +143,14 @@ impl User {
             email,
             password_hash,
             name,
+            role: UserRole::default(),
             is_active: true,
             created_at: now,
             updated_at: now,
         }
     }
+
+    pub fn is_admin(&self) -> bool {
+        self.role == UserRole::Admin
+    }
 }
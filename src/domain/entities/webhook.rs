@@ -0,0 +1,366 @@
+use crate::domain::entities::article::{Article, ArticleResponse};
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Tipo de evento entregue aos webhooks
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum WebhookEventType {
+    #[serde(rename = "article.created")]
+    ArticleCreated,
+    #[serde(rename = "article.updated")]
+    ArticleUpdated,
+    /// Atualização vinda do pipeline de IA (resumo, nota, palavras-chave, categoria ou colunista)
+    #[serde(rename = "article.enriched")]
+    ArticleEnriched,
+    #[serde(rename = "article.deleted")]
+    ArticleDeleted,
+    /// Disparo manual pelo endpoint de teste; não pode ser assinado
+    #[serde(rename = "webhook.test")]
+    Test,
+}
+
+impl WebhookEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEventType::ArticleCreated => "article.created",
+            WebhookEventType::ArticleUpdated => "article.updated",
+            WebhookEventType::ArticleEnriched => "article.enriched",
+            WebhookEventType::ArticleDeleted => "article.deleted",
+            WebhookEventType::Test => "webhook.test",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "article.created" => Some(WebhookEventType::ArticleCreated),
+            "article.updated" => Some(WebhookEventType::ArticleUpdated),
+            "article.enriched" => Some(WebhookEventType::ArticleEnriched),
+            "article.deleted" => Some(WebhookEventType::ArticleDeleted),
+            "webhook.test" => Some(WebhookEventType::Test),
+            _ => None,
+        }
+    }
+}
+
+/// Evento do ciclo de vida de um artigo, gravado no outbox na mesma transação da escrita
+/// do artigo e distribuído às assinaturas depois, para não se perder se o processo cair
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ArticleEvent {
+    pub id: Uuid,
+    /// Posição global no outbox, atribuída pelo banco na gravação (0 antes disso);
+    /// é o `id` dos eventos do stream SSE
+    pub seq: i64,
+    pub event_type: WebhookEventType,
+    pub article_id: Uuid,
+    /// O artigo no momento do evento (no `article.deleted`, como estava antes da remoção)
+    pub payload: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+    /// Quando o evento foi distribuído às assinaturas
+    pub processed_at: Option<DateTime<Utc>>,
+}
+
+impl ArticleEvent {
+    pub fn new(event_type: WebhookEventType, article: &Article) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            seq: 0,
+            event_type,
+            article_id: article.id,
+            payload: serde_json::to_value(ArticleResponse::from(article.clone())).unwrap_or_default(),
+            created_at: Utc::now(),
+            processed_at: None,
+        }
+    }
+}
+
+/// Destino externo que recebe eventos assinados com HMAC-SHA256
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WebhookSubscription {
+    pub id: Uuid,
+    pub name: String,
+    pub url: String,
+    /// Segredo compartilhado usado na assinatura dos payloads
+    pub secret: String,
+    /// Eventos assinados; vazio recebe todos
+    pub events: Vec<WebhookEventType>,
+    pub enabled: bool,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WebhookSubscription {
+    pub const SECRET_PREFIX: &'static str = "whsec_";
+
+    pub fn new(created_by: Uuid, dto: WebhookSubscriptionDto) -> Self {
+        let now = Utc::now();
+        let mut subscription = Self {
+            id: Uuid::new_v4(),
+            name: String::new(),
+            url: String::new(),
+            secret: Self::generate_secret(),
+            events: Vec::new(),
+            enabled: true,
+            created_by: Some(created_by),
+            created_at: now,
+            updated_at: now,
+        };
+        subscription.replace(dto);
+        subscription
+    }
+
+    /// Substitui nome, URL, eventos e estado; o segredo é mantido
+    pub fn replace(&mut self, dto: WebhookSubscriptionDto) {
+        let mut events = Vec::new();
+        for event in dto.events {
+            if !events.contains(&event) {
+                events.push(event);
+            }
+        }
+
+        self.name = dto.name.trim().to_string();
+        self.url = dto.url.trim().to_string();
+        self.events = events;
+        self.enabled = dto.enabled;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn wants(&self, event_type: WebhookEventType) -> bool {
+        self.enabled && (self.events.is_empty() || self.events.contains(&event_type))
+    }
+
+    fn generate_secret() -> String {
+        let mut bytes = [0u8; 24];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        format!("{}{}", Self::SECRET_PREFIX, hex::encode(bytes))
+    }
+}
+
+/// Assinatura enviada no header `X-Nexight-Signature`: `t=<unix>,v1=<hex>`, onde `v1` é o
+/// HMAC-SHA256 de `"<t>.<corpo>"` com o segredo da assinatura
+pub fn webhook_signature(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    format!("t={},v1={}", timestamp, hex::encode(mac.finalize().into_bytes()))
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct WebhookSubscriptionDto {
+    #[validate(length(min = 1, max = 100, message = "Name must have between 1 and 100 characters"))]
+    pub name: String,
+
+    #[validate(url(message = "URL must be a valid URL"))]
+    #[validate(length(max = 500, message = "URL must have at most 500 characters"))]
+    pub url: String,
+
+    /// Vazio assina todos os eventos
+    #[serde(default)]
+    pub events: Vec<WebhookEventType>,
+
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookSubscriptionResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub url: String,
+    /// Presente apenas na criação
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    pub events: Vec<WebhookEventType>,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<WebhookSubscription> for WebhookSubscriptionResponse {
+    fn from(subscription: WebhookSubscription) -> Self {
+        WebhookSubscriptionResponse {
+            id: subscription.id,
+            name: subscription.name,
+            url: subscription.url,
+            secret: None,
+            events: subscription.events,
+            enabled: subscription.enabled,
+            created_at: subscription.created_at,
+            updated_at: subscription.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// Aguardando a primeira tentativa ou uma nova tentativa
+    Pending,
+    Succeeded,
+    /// Tentativas esgotadas
+    Failed,
+}
+
+impl WebhookDeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookDeliveryStatus::Pending => "pending",
+            WebhookDeliveryStatus::Succeeded => "succeeded",
+            WebhookDeliveryStatus::Failed => "failed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(WebhookDeliveryStatus::Pending),
+            "succeeded" => Some(WebhookDeliveryStatus::Succeeded),
+            "failed" => Some(WebhookDeliveryStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// Entrega de um evento a uma assinatura, com o histórico da última tentativa
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    /// Evento do outbox; ausente nos disparos de teste
+    pub event_id: Option<Uuid>,
+    pub event_type: WebhookEventType,
+    /// Corpo exato enviado (e assinado) em cada tentativa
+    pub payload: serde_json::Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl WebhookDelivery {
+    /// Tentativas antes de desistir; com o backoff, a última acontece cerca de uma hora após o evento
+    pub const MAX_ATTEMPTS: i32 = 8;
+    const BASE_DELAY_SECONDS: i64 = 30;
+
+    pub fn for_event(subscription_id: Uuid, event: &ArticleEvent) -> Self {
+        let payload = serde_json::json!({
+            "id": event.id,
+            "type": event.event_type,
+            "created_at": event.created_at,
+            "data": event.payload,
+        });
+        let mut delivery = Self::new(subscription_id, Some(event.id), event.event_type, payload);
+        // Devida desde o evento, para sair já na rodada que a criou
+        delivery.next_attempt_at = Some(event.created_at);
+        delivery
+    }
+
+    /// Disparo de teste: uma única tentativa, sem novas tentativas em caso de falha
+    pub fn test(subscription_id: Uuid) -> Self {
+        let id = Uuid::new_v4();
+        let payload = serde_json::json!({
+            "id": id,
+            "type": WebhookEventType::Test,
+            "created_at": Utc::now(),
+            "data": { "message": "Webhook test from Nexight" },
+        });
+        let mut delivery = Self::new(subscription_id, None, WebhookEventType::Test, payload);
+        delivery.id = id;
+        delivery
+    }
+
+    fn new(subscription_id: Uuid, event_id: Option<Uuid>, event_type: WebhookEventType, payload: serde_json::Value) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            subscription_id,
+            event_id,
+            event_type,
+            payload,
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: Some(now),
+            last_status_code: None,
+            last_error: None,
+            created_at: now,
+            delivered_at: None,
+        }
+    }
+
+    pub fn record_success(&mut self, status_code: i32, at: DateTime<Utc>) {
+        self.attempts += 1;
+        self.status = WebhookDeliveryStatus::Succeeded;
+        self.last_status_code = Some(status_code);
+        self.last_error = None;
+        self.next_attempt_at = None;
+        self.delivered_at = Some(at);
+    }
+
+    /// Agenda a próxima tentativa com backoff exponencial (30s, 1min, 2min, ...) ou desiste
+    /// após `MAX_ATTEMPTS`; disparos de teste não são repetidos
+    pub fn record_failure(&mut self, status_code: Option<i32>, error: String, at: DateTime<Utc>) {
+        self.attempts += 1;
+        self.last_status_code = status_code;
+        self.last_error = Some(error);
+
+        if self.event_type == WebhookEventType::Test || self.attempts >= Self::MAX_ATTEMPTS {
+            self.status = WebhookDeliveryStatus::Failed;
+            self.next_attempt_at = None;
+        } else {
+            let delay = Self::BASE_DELAY_SECONDS << (self.attempts - 1);
+            self.next_attempt_at = Some(at + Duration::seconds(delay));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDeliveryResponse {
+    pub id: Uuid,
+    pub event_id: Option<Uuid>,
+    pub event_type: WebhookEventType,
+    pub payload: serde_json::Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryResponse {
+    fn from(delivery: WebhookDelivery) -> Self {
+        WebhookDeliveryResponse {
+            id: delivery.id,
+            event_id: delivery.event_id,
+            event_type: delivery.event_type,
+            payload: delivery.payload,
+            status: delivery.status,
+            attempts: delivery.attempts,
+            next_attempt_at: delivery.next_attempt_at,
+            last_status_code: delivery.last_status_code,
+            last_error: delivery.last_error,
+            created_at: delivery.created_at,
+            delivered_at: delivery.delivered_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaginatedWebhookDeliveries {
+    pub deliveries: Vec<WebhookDeliveryResponse>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+    pub total_pages: i64,
+}
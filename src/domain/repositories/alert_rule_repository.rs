@@ -0,0 +1,38 @@
+use crate::domain::entities::alert::AlertRule;
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum AlertRuleRepositoryError {
+    #[error("Feed not found")]
+    FeedNotFound,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait AlertRuleRepository: Send + Sync {
+    /// Cria uma regra de alerta
+    async fn create(&self, rule: AlertRule) -> Result<AlertRule, AlertRuleRepositoryError>;
+
+    /// Busca uma regra do usuário pelo ID
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<AlertRule>, AlertRuleRepositoryError>;
+
+    /// Lista as regras do usuário em ordem alfabética
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<AlertRule>, AlertRuleRepositoryError>;
+
+    /// Regras ativas que podem disparar para um artigo do feed e da categoria informados;
+    /// os demais critérios são verificados por `AlertRule::matches`
+    async fn find_candidates(&self, feed_id: Uuid, category_id: Option<Uuid>) -> Result<Vec<AlertRule>, AlertRuleRepositoryError>;
+
+    /// Substitui os dados da regra
+    async fn update(&self, rule: AlertRule) -> Result<AlertRule, AlertRuleRepositoryError>;
+
+    /// Remove uma regra do usuário (e suas notificações); retorna `false` se ela não existir
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, AlertRuleRepositoryError>;
+}
+
+pub type DynAlertRuleRepository = Arc<dyn AlertRuleRepository>;
@@ -0,0 +1,37 @@
+use crate::domain::entities::annotation::Annotation;
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum AnnotationRepositoryError {
+    #[error("Article not found")]
+    ArticleNotFound,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait AnnotationRepository: Send + Sync {
+    /// Cria uma anotação
+    async fn create(&self, annotation: Annotation) -> Result<Annotation, AnnotationRepositoryError>;
+
+    /// Busca uma anotação do usuário pelo ID
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Annotation>, AnnotationRepositoryError>;
+
+    /// Lista as anotações do usuário em um artigo: notas gerais primeiro, depois os destaques na ordem do texto
+    async fn list_by_article(&self, user_id: Uuid, article_id: Uuid) -> Result<Vec<Annotation>, AnnotationRepositoryError>;
+
+    /// Lista todas as anotações do usuário, das mais antigas para as mais recentes
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<Annotation>, AnnotationRepositoryError>;
+
+    /// Atualiza âncora e nota
+    async fn update(&self, annotation: Annotation) -> Result<Annotation, AnnotationRepositoryError>;
+
+    /// Remove uma anotação do usuário; retorna `false` se ela não existir
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, AnnotationRepositoryError>;
+}
+
+pub type DynAnnotationRepository = Arc<dyn AnnotationRepository>;
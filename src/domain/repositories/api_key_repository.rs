@@ -0,0 +1,35 @@
+use crate::domain::entities::api_key::ApiKey;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ApiKeyRepositoryError {
+    #[error("API key prefix already exists")]
+    PrefixAlreadyExists,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    /// Cria uma nova chave
+    async fn create(&self, api_key: ApiKey) -> Result<ApiKey, ApiKeyRepositoryError>;
+
+    /// Busca uma chave pelo prefixo público
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>, ApiKeyRepositoryError>;
+
+    /// Lista as chaves de um usuário, das mais recentes para as mais antigas
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<ApiKey>, ApiKeyRepositoryError>;
+
+    /// Remove uma chave do usuário; retorna `false` se ela não existir
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiKeyRepositoryError>;
+
+    /// Atualiza o horário do último uso
+    async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<(), ApiKeyRepositoryError>;
+}
+
+pub type DynApiKeyRepository = Arc<dyn ApiKeyRepository>;
@@ -0,0 +1,33 @@
+use crate::domain::entities::webhook::ArticleEvent;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ArticleEventRepositoryError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Outbox dos eventos de artigos. Os eventos são gravados pelo `ArticleRepository`,
+/// na mesma transação da escrita do artigo; aqui eles são lidos e marcados como distribuídos
+#[async_trait]
+pub trait ArticleEventRepository: Send + Sync {
+    /// Eventos ainda não distribuídos, dos mais antigos para os mais recentes
+    async fn list_pending(&self, limit: i64) -> Result<Vec<ArticleEvent>, ArticleEventRepositoryError>;
+
+    async fn mark_processed(&self, id: Uuid, processed_at: DateTime<Utc>) -> Result<(), ArticleEventRepositoryError>;
+
+    /// Maior `seq` já gravado (0 se o outbox estiver vazio)
+    async fn latest_seq(&self) -> Result<i64, ArticleEventRepositoryError>;
+
+    /// Eventos com `seq` maior que `after`, em ordem crescente de `seq`
+    async fn list_after(&self, after: i64, limit: i64) -> Result<Vec<ArticleEvent>, ArticleEventRepositoryError>;
+
+    /// Remove os eventos já distribuídos criados antes de `before`; retorna quantos foram removidos
+    async fn delete_processed_before(&self, before: DateTime<Utc>) -> Result<usize, ArticleEventRepositoryError>;
+}
+
+pub type DynArticleEventRepository = Arc<dyn ArticleEventRepository>;
@@ -0,0 +1,31 @@
+use crate::domain::entities::reaction::ReactionCount;
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ArticleReactionRepositoryError {
+    #[error("Article not found")]
+    ArticleNotFound,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait ArticleReactionRepository: Send + Sync {
+    /// Registra a reação; repetir a mesma reação não tem efeito
+    async fn add(&self, user_id: Uuid, article_id: Uuid, reaction: &str) -> Result<(), ArticleReactionRepositoryError>;
+
+    /// Remove a reação; retorna `false` se o usuário não a tinha
+    async fn remove(&self, user_id: Uuid, article_id: Uuid, reaction: &str) -> Result<bool, ArticleReactionRepositoryError>;
+
+    /// Total por reação em um artigo, da mais usada para a menos usada
+    async fn counts(&self, article_id: Uuid) -> Result<Vec<ReactionCount>, ArticleReactionRepositoryError>;
+
+    /// Reações do usuário em um artigo
+    async fn list_by_user(&self, user_id: Uuid, article_id: Uuid) -> Result<Vec<String>, ArticleReactionRepositoryError>;
+}
+
+pub type DynArticleReactionRepository = Arc<dyn ArticleReactionRepository>;
@@ -1,5 +1,9 @@
-use crate::domain::entities::article::{Article, PaginatedArticles};
+use crate::domain::entities::article::{Article, ArticleListFilter, ArticleRatingSummary, PaginatedArticles, UnreadCounts};
+use crate::domain::entities::article_export::ArticleExportCursor;
+use crate::domain::entities::ranking::CategoryEngagement;
+use crate::domain::entities::webhook::WebhookEventType;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use std::sync::Arc;
 use thiserror::Error;
 use uuid::Uuid;
@@ -8,6 +12,9 @@ use uuid::Uuid;
 pub enum ArticleRepositoryError {
     #[error("Article not found")]
     NotFound,
+
+    #[error("An article with this link already exists")]
+    LinkAlreadyExists,
     
     #[allow(dead_code)]
     #[error("Database error: {0}")]
@@ -23,6 +30,11 @@ pub trait ArticleRepository: Send + Sync {
     #[allow(dead_code)]
     async fn create(&self, article: Article) -> Result<Article, ArticleRepositoryError>;
     
+    /// Insere os artigos numa única transação, ignorando os que têm um link já cadastrado; retorna os inseridos.
+    /// Não grava eventos no outbox: é usado pela importação, que é silenciosa
+    #[allow(dead_code)]
+    async fn create_batch(&self, articles: Vec<Article>) -> Result<Vec<Article>, ArticleRepositoryError>;
+
     /// Busca um artigo por ID
     #[allow(dead_code)]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Article>, ArticleRepositoryError>;
@@ -30,7 +42,26 @@ pub trait ArticleRepository: Send + Sync {
     /// Lista artigos com paginação
     #[allow(dead_code)]
     async fn list(&self, page: i64, per_page: i64) -> Result<PaginatedArticles, ArticleRepositoryError>;
+
+    /// Lista artigos que atendem ao filtro, com paginação
+    #[allow(dead_code)]
+    async fn list_filtered(
+        &self,
+        filter: &ArticleListFilter,
+        page: i64,
+        per_page: i64,
+    ) -> Result<PaginatedArticles, ArticleRepositoryError>;
     
+    /// Lote de artigos que atendem ao filtro para exportação, em ordem (`pub_date`, `id`) decrescente
+    /// a partir do cursor; ignora a ordenação do filtro para que o lote seguinte continue de onde este parou
+    #[allow(dead_code)]
+    async fn export_chunk(
+        &self,
+        filter: &ArticleListFilter,
+        after: Option<ArticleExportCursor>,
+        limit: i64,
+    ) -> Result<Vec<Article>, ArticleRepositoryError>;
+
     /// Atualiza um artigo
     #[allow(dead_code)]
     async fn update(&self, article: Article) -> Result<Article, ArticleRepositoryError>;
@@ -39,6 +70,57 @@ pub trait ArticleRepository: Send + Sync {
     #[allow(dead_code)]
     async fn delete(&self, id: Uuid) -> Result<(), ArticleRepositoryError>;
     
+    /// Cria o artigo e grava o evento `article.created` no outbox na mesma transação
+    #[allow(dead_code)]
+    async fn create_with_event(&self, article: Article) -> Result<Article, ArticleRepositoryError>;
+
+    /// Atualiza o artigo e grava o evento informado no outbox na mesma transação
+    #[allow(dead_code)]
+    async fn update_with_event(&self, article: Article, event_type: WebhookEventType) -> Result<Article, ArticleRepositoryError>;
+
+    /// Remove o artigo e grava o evento `article.deleted`, com o artigo como estava, na mesma transação
+    #[allow(dead_code)]
+    async fn delete_with_event(&self, id: Uuid) -> Result<(), ArticleRepositoryError>;
+
+    /// Marca o artigo como lido pelo usuário; mantém o `read_at` original se já estava lido
+    #[allow(dead_code)]
+    async fn mark_read(&self, user_id: Uuid, article_id: Uuid, read_at: DateTime<Utc>) -> Result<(), ArticleRepositoryError>;
+
+    /// Volta o artigo para não lido
+    #[allow(dead_code)]
+    async fn mark_unread(&self, user_id: Uuid, article_id: Uuid) -> Result<(), ArticleRepositoryError>;
+
+    /// Marca como lidos todos os artigos que atendem ao filtro; retorna quantos passaram a ser lidos
+    #[allow(dead_code)]
+    async fn mark_read_matching(
+        &self,
+        user_id: Uuid,
+        filter: &ArticleListFilter,
+        read_at: DateTime<Utc>,
+    ) -> Result<u64, ArticleRepositoryError>;
+
+    /// Conta os artigos não lidos pelo usuário nos feeds informados
+    #[allow(dead_code)]
+    async fn unread_counts(&self, user_id: Uuid, feed_ids: &[Uuid]) -> Result<UnreadCounts, ArticleRepositoryError>;
+
+    /// Grava (ou substitui) a nota do usuário e recalcula a média do artigo
+    #[allow(dead_code)]
+    async fn rate(
+        &self,
+        user_id: Uuid,
+        article_id: Uuid,
+        rating: i32,
+        rated_at: DateTime<Utc>,
+    ) -> Result<ArticleRatingSummary, ArticleRepositoryError>;
+
+    /// Remove a nota do usuário e recalcula a média do artigo
+    #[allow(dead_code)]
+    async fn remove_rating(&self, user_id: Uuid, article_id: Uuid) -> Result<ArticleRatingSummary, ArticleRepositoryError>;
+
+    /// Leituras e notas do usuário agrupadas pela categoria dos artigos (artigos sem categoria ficam de fora)
+    #[allow(dead_code)]
+    async fn category_engagement(&self, user_id: Uuid) -> Result<Vec<CategoryEngagement>, ArticleRepositoryError>;
+
     /// Conta o total de artigos
     #[allow(dead_code)]
     async fn count(&self) -> Result<i64, ArticleRepositoryError>;
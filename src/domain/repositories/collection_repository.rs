@@ -0,0 +1,37 @@
+use crate::domain::entities::collection::Collection;
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum CollectionRepositoryError {
+    #[error("A collection with this name already exists")]
+    NameTaken,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait CollectionRepository: Send + Sync {
+    /// Cria uma coleção; o nome é único por usuário
+    async fn create(&self, collection: Collection) -> Result<Collection, CollectionRepositoryError>;
+
+    /// Busca uma coleção do usuário pelo ID
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Collection>, CollectionRepositoryError>;
+
+    /// Busca uma coleção compartilhada pelo token do link
+    async fn find_by_share_token(&self, token: &str) -> Result<Option<Collection>, CollectionRepositoryError>;
+
+    /// Lista as coleções do usuário em ordem alfabética
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<Collection>, CollectionRepositoryError>;
+
+    /// Atualiza nome e token de compartilhamento
+    async fn update(&self, collection: Collection) -> Result<Collection, CollectionRepositoryError>;
+
+    /// Remove uma coleção do usuário; retorna `false` se ela não existir
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, CollectionRepositoryError>;
+}
+
+pub type DynCollectionRepository = Arc<dyn CollectionRepository>;
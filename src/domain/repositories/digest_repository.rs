@@ -0,0 +1,36 @@
+use crate::domain::entities::digest::{DigestSettings, SentDigest};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum DigestRepositoryError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait DigestRepository: Send + Sync {
+    async fn find_settings(&self, user_id: Uuid) -> Result<Option<DigestSettings>, DigestRepositoryError>;
+
+    /// Cria ou substitui a agenda do usuário
+    async fn save_settings(&self, settings: DigestSettings) -> Result<DigestSettings, DigestRepositoryError>;
+
+    /// Remove a agenda; retorna `false` se o usuário não tinha uma
+    async fn delete_settings(&self, user_id: Uuid) -> Result<bool, DigestRepositoryError>;
+
+    /// Agendas ativas, avaliadas a cada rodada do agendador
+    async fn list_enabled_settings(&self) -> Result<Vec<DigestSettings>, DigestRepositoryError>;
+
+    /// Registra o resumo do período; retorna `false` se o período já foi registrado
+    async fn record_sent(&self, sent: SentDigest) -> Result<bool, DigestRepositoryError>;
+
+    /// Desfaz um registro, liberando o período para uma nova tentativa
+    async fn remove_sent(&self, id: Uuid) -> Result<(), DigestRepositoryError>;
+
+    /// Resumos registrados do usuário, dos mais recentes para os mais antigos
+    async fn list_sent(&self, user_id: Uuid, limit: i64) -> Result<Vec<SentDigest>, DigestRepositoryError>;
+}
+
+pub type DynDigestRepository = Arc<dyn DigestRepository>;
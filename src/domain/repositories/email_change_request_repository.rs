@@ -0,0 +1,25 @@
+use crate::domain::entities::email_change_request::EmailChangeRequest;
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum EmailChangeRequestRepositoryError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait EmailChangeRequestRepository: Send + Sync {
+    /// Salva a solicitação, substituindo qualquer solicitação anterior do usuário
+    async fn replace(&self, request: EmailChangeRequest) -> Result<EmailChangeRequest, EmailChangeRequestRepositoryError>;
+
+    /// Busca a solicitação pendente de um usuário
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Option<EmailChangeRequest>, EmailChangeRequestRepositoryError>;
+
+    /// Remove a solicitação pendente de um usuário
+    async fn delete_by_user(&self, user_id: Uuid) -> Result<(), EmailChangeRequestRepositoryError>;
+}
+
+pub type DynEmailChangeRequestRepository = Arc<dyn EmailChangeRequestRepository>;
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum FeedRepositoryError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Consulta aos feeds cadastrados pelo coletor (a API não cria nem altera feeds)
+#[async_trait]
+pub trait FeedRepository: Send + Sync {
+    /// ID do feed pelo endereço do feed (`feed_url`) ou do site (`link`)
+    async fn find_id_by_url(&self, url: &str) -> Result<Option<Uuid>, FeedRepositoryError>;
+
+    /// Verifica se o feed existe
+    async fn exists(&self, id: Uuid) -> Result<bool, FeedRepositoryError>;
+}
+
+pub type DynFeedRepository = Arc<dyn FeedRepository>;
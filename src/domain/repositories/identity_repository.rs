@@ -0,0 +1,35 @@
+use crate::domain::entities::identity::{OidcAuthRequest, UserIdentity};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum IdentityRepositoryError {
+    #[error("Identity already linked")]
+    IdentityAlreadyLinked,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait IdentityRepository: Send + Sync {
+    /// Busca o vínculo de uma conta externa
+    async fn find_identity(&self, provider: &str, subject: &str) -> Result<Option<UserIdentity>, IdentityRepositoryError>;
+
+    /// Vincula uma conta externa a um usuário
+    async fn create_identity(&self, identity: UserIdentity) -> Result<UserIdentity, IdentityRepositoryError>;
+
+    /// Atualiza o horário do último login pela conta externa
+    async fn touch_identity(&self, id: Uuid, at: DateTime<Utc>) -> Result<(), IdentityRepositoryError>;
+
+    /// Registra um login OIDC iniciado
+    async fn save_auth_request(&self, request: OidcAuthRequest) -> Result<(), IdentityRepositoryError>;
+
+    /// Remove e retorna o login iniciado com o `state` informado (uso único)
+    async fn take_auth_request(&self, state_hash: &str) -> Result<Option<OidcAuthRequest>, IdentityRepositoryError>;
+}
+
+pub type DynIdentityRepository = Arc<dyn IdentityRepository>;
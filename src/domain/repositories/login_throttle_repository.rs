@@ -0,0 +1,47 @@
+use crate::domain::entities::login_throttle::{LockoutPolicy, LoginThrottle, ThrottleScope};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum LoginThrottleRepositoryError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait LoginThrottleRepository: Send + Sync {
+    /// Busca o contador de um escopo/sujeito
+    async fn find(
+        &self,
+        scope: ThrottleScope,
+        subject: &str,
+    ) -> Result<Option<LoginThrottle>, LoginThrottleRepositoryError>;
+
+    /// Conta uma falha de forma atômica (criando o contador se preciso) e retorna o contador
+    /// atualizado; falhas concorrentes nunca se perdem
+    async fn record_failure(
+        &self,
+        scope: ThrottleScope,
+        subject: &str,
+        policy: &LockoutPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<LoginThrottle, LoginThrottleRepositoryError>;
+
+    /// Aplica o bloqueio se o contador ainda estiver no limite; retorna `None` quando uma
+    /// falha concorrente já o aplicou
+    async fn lock(
+        &self,
+        scope: ThrottleScope,
+        subject: &str,
+        threshold: i32,
+        locked_until: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<Option<LoginThrottle>, LoginThrottleRepositoryError>;
+
+    /// Remove o contador (desbloqueio)
+    async fn delete(&self, scope: ThrottleScope, subject: &str) -> Result<(), LoginThrottleRepositoryError>;
+}
+
+pub type DynLoginThrottleRepository = Arc<dyn LoginThrottleRepository>;
@@ -0,0 +1,31 @@
+use crate::domain::entities::mfa::UserMfa;
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum MfaRepositoryError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait MfaRepository: Send + Sync {
+    /// Busca a configuração de 2FA de um usuário
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Option<UserMfa>, MfaRepositoryError>;
+
+    /// Cria ou atualiza a configuração de 2FA do usuário
+    async fn save(&self, mfa: UserMfa) -> Result<UserMfa, MfaRepositoryError>;
+
+    /// Remove a configuração de 2FA e todos os códigos de recuperação do usuário
+    async fn delete(&self, user_id: Uuid) -> Result<(), MfaRepositoryError>;
+
+    /// Substitui os códigos de recuperação do usuário (apenas os hashes são armazenados)
+    async fn replace_recovery_codes(&self, user_id: Uuid, code_hashes: Vec<String>) -> Result<(), MfaRepositoryError>;
+
+    /// Consome um código de recuperação; retorna `false` se não existir ou já tiver sido usado
+    async fn consume_recovery_code(&self, user_id: Uuid, code_hash: &str) -> Result<bool, MfaRepositoryError>;
+}
+
+pub type DynMfaRepository = Arc<dyn MfaRepository>;
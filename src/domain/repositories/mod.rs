@@ -1,4 +1,23 @@
 // Módulo de interfaces de repositórios
 pub mod user_repository;
 pub mod article_category_repository;
-pub mod article_repository;
\ No newline at end of file
+pub mod article_repository;
+pub mod login_throttle_repository;
+pub mod email_change_request_repository;
+pub mod mfa_repository;
+pub mod api_key_repository;
+pub mod session_repository;
+pub mod identity_repository;
+pub mod subscription_repository;
+pub mod collection_repository;
+pub mod saved_article_repository;
+pub mod article_reaction_repository;
+pub mod annotation_repository;
+pub mod tag_repository;
+pub mod smart_folder_repository;
+pub mod alert_rule_repository;
+pub mod notification_repository;
+pub mod digest_repository;
+pub mod article_event_repository;
+pub mod webhook_repository;
+pub mod feed_repository;
\ No newline at end of file
@@ -0,0 +1,57 @@
+use crate::domain::entities::alert::{Notification, NotificationDelivery};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum NotificationRepositoryError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait NotificationRepository: Send + Sync {
+    /// Grava a notificação e suas entregas na mesma transação se a regra ainda não notificou
+    /// este artigo; retorna `false` (sem gravar as entregas) se já existia
+    async fn create_if_absent(
+        &self,
+        notification: Notification,
+        deliveries: Vec<NotificationDelivery>,
+    ) -> Result<bool, NotificationRepositoryError>;
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Notification>, NotificationRepositoryError>;
+
+    /// Notificações do usuário, das mais recentes para as mais antigas, e o total que atende ao filtro
+    async fn list_by_user(
+        &self,
+        user_id: Uuid,
+        unread_only: bool,
+        page: i64,
+        per_page: i64,
+    ) -> Result<(Vec<Notification>, i64), NotificationRepositoryError>;
+
+    /// Quantidade de notificações não lidas do usuário
+    async fn unread_count(&self, user_id: Uuid) -> Result<i64, NotificationRepositoryError>;
+
+    /// Marca uma notificação do usuário como lida; retorna `false` se ela não existir
+    async fn mark_read(&self, user_id: Uuid, id: Uuid, read_at: DateTime<Utc>) -> Result<bool, NotificationRepositoryError>;
+
+    /// Marca todas as notificações não lidas do usuário; retorna quantas foram marcadas
+    async fn mark_all_read(&self, user_id: Uuid, read_at: DateTime<Utc>) -> Result<u64, NotificationRepositoryError>;
+
+    /// Reserva até `limit` entregas pendentes com tentativa vencida em `now`, adiando a próxima
+    /// tentativa para `lease_until`; assim duas instâncias não enviam a mesma entrega ao mesmo tempo
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<NotificationDelivery>, NotificationRepositoryError>;
+
+    /// Grava o resultado de uma tentativa
+    async fn update_delivery(&self, delivery: NotificationDelivery) -> Result<NotificationDelivery, NotificationRepositoryError>;
+}
+
+pub type DynNotificationRepository = Arc<dyn NotificationRepository>;
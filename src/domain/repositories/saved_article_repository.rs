@@ -0,0 +1,46 @@
+use crate::domain::entities::saved_article::SavedArticle;
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum SavedArticleRepositoryError {
+    #[error("Article already saved")]
+    AlreadySaved,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait SavedArticleRepository: Send + Sync {
+    /// Salva um artigo; cada artigo pode ser salvo uma única vez por usuário
+    async fn create(&self, saved: SavedArticle) -> Result<SavedArticle, SavedArticleRepositoryError>;
+
+    /// Busca um item salvo do usuário pelo ID
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<SavedArticle>, SavedArticleRepositoryError>;
+
+    /// Lista os itens salvos do usuário pela posição
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<SavedArticle>, SavedArticleRepositoryError>;
+
+    /// Lista os itens de uma coleção pela posição
+    async fn list_by_collection(&self, collection_id: Uuid) -> Result<Vec<SavedArticle>, SavedArticleRepositoryError>;
+
+    /// Maior posição entre os itens do usuário (`None` se ele não salvou nada)
+    async fn max_position(&self, user_id: Uuid) -> Result<Option<i32>, SavedArticleRepositoryError>;
+
+    /// Atualiza a coleção de um item
+    async fn update(&self, saved: SavedArticle) -> Result<SavedArticle, SavedArticleRepositoryError>;
+
+    /// Grava novas posições para itens do usuário, atomicamente
+    async fn update_positions(&self, user_id: Uuid, positions: &[(Uuid, i32)]) -> Result<(), SavedArticleRepositoryError>;
+
+    /// Tira da coleção todos os seus itens, que continuam salvos
+    async fn detach_collection(&self, collection_id: Uuid) -> Result<(), SavedArticleRepositoryError>;
+
+    /// Remove um item salvo do usuário; retorna `false` se ele não existir
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, SavedArticleRepositoryError>;
+}
+
+pub type DynSavedArticleRepository = Arc<dyn SavedArticleRepository>;
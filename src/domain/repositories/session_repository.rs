@@ -0,0 +1,43 @@
+use crate::domain::entities::session::Session;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum SessionRepositoryError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait SessionRepository: Send + Sync {
+    /// Registra uma nova sessão
+    async fn create(&self, session: Session) -> Result<Session, SessionRepositoryError>;
+
+    /// Busca uma sessão pelo ID
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Session>, SessionRepositoryError>;
+
+    /// Lista as sessões não revogadas e não expiradas, das mais recentes para as mais antigas
+    async fn list_active_by_user(&self, user_id: Uuid, now: DateTime<Utc>) -> Result<Vec<Session>, SessionRepositoryError>;
+
+    /// Revoga uma sessão ativa do usuário; retorna `false` se ela não existir ou já estiver revogada
+    async fn revoke(&self, user_id: Uuid, id: Uuid, revoked_at: DateTime<Utc>) -> Result<bool, SessionRepositoryError>;
+
+    /// Revoga as sessões ativas do usuário, exceto `except`; retorna quantas foram revogadas
+    async fn revoke_all(
+        &self,
+        user_id: Uuid,
+        except: Option<Uuid>,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<usize, SessionRepositoryError>;
+
+    /// Atualiza o horário do último acesso
+    async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>) -> Result<(), SessionRepositoryError>;
+
+    /// Remove as sessões do usuário expiradas antes de `before`
+    async fn delete_expired(&self, user_id: Uuid, before: DateTime<Utc>) -> Result<(), SessionRepositoryError>;
+}
+
+pub type DynSessionRepository = Arc<dyn SessionRepository>;
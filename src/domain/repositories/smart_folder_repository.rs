@@ -0,0 +1,37 @@
+use crate::domain::entities::smart_folder::SmartFolder;
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum SmartFolderRepositoryError {
+    #[error("A smart folder with this name already exists")]
+    NameTaken,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait SmartFolderRepository: Send + Sync {
+    /// Cria uma pasta inteligente; o nome é único por usuário
+    async fn create(&self, folder: SmartFolder) -> Result<SmartFolder, SmartFolderRepositoryError>;
+
+    /// Busca uma pasta do usuário pelo ID
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<SmartFolder>, SmartFolderRepositoryError>;
+
+    /// Lista as pastas do usuário em ordem alfabética
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<SmartFolder>, SmartFolderRepositoryError>;
+
+    /// Atualiza nome e critérios
+    async fn update(&self, folder: SmartFolder) -> Result<SmartFolder, SmartFolderRepositoryError>;
+
+    /// Remove uma pasta do usuário; retorna `false` se ela não existir
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, SmartFolderRepositoryError>;
+
+    /// Troca a tag dos critérios das pastas do usuário (usado ao juntar tags)
+    async fn replace_tag(&self, user_id: Uuid, source_id: Uuid, target_id: Uuid) -> Result<(), SmartFolderRepositoryError>;
+}
+
+pub type DynSmartFolderRepository = Arc<dyn SmartFolderRepository>;
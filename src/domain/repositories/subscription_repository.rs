@@ -0,0 +1,40 @@
+use crate::domain::entities::subscription::Subscription;
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum SubscriptionRepositoryError {
+    #[error("Already subscribed to this feed")]
+    AlreadySubscribed,
+
+    #[error("Feed not found")]
+    FeedNotFound,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait SubscriptionRepository: Send + Sync {
+    /// Registra uma nova assinatura
+    async fn create(&self, subscription: Subscription) -> Result<Subscription, SubscriptionRepositoryError>;
+
+    /// Busca uma assinatura do usuário pelo ID
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Subscription>, SubscriptionRepositoryError>;
+
+    /// Lista as assinaturas do usuário, ordenadas por pasta e data de criação
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<Subscription>, SubscriptionRepositoryError>;
+
+    /// IDs dos feeds assinados pelo usuário
+    async fn feed_ids_by_user(&self, user_id: Uuid) -> Result<Vec<Uuid>, SubscriptionRepositoryError>;
+
+    /// Atualiza título e pasta
+    async fn update(&self, subscription: Subscription) -> Result<Subscription, SubscriptionRepositoryError>;
+
+    /// Remove uma assinatura do usuário; retorna `false` se ela não existir
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, SubscriptionRepositoryError>;
+}
+
+pub type DynSubscriptionRepository = Arc<dyn SubscriptionRepository>;
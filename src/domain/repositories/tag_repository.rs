@@ -0,0 +1,55 @@
+use crate::domain::entities::tag::{Tag, TagSummary};
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum TagRepositoryError {
+    #[error("A tag with this name already exists")]
+    NameTaken,
+
+    #[error("Article not found")]
+    ArticleNotFound,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait TagRepository: Send + Sync {
+    /// Cria uma tag; o nome é único por usuário, sem diferenciar maiúsculas de minúsculas
+    async fn create(&self, tag: Tag) -> Result<Tag, TagRepositoryError>;
+
+    /// Busca uma tag do usuário pelo ID
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Tag>, TagRepositoryError>;
+
+    /// Busca as tags do usuário com os nomes informados, sem diferenciar maiúsculas de minúsculas
+    async fn find_by_names(&self, user_id: Uuid, names: &[String]) -> Result<Vec<Tag>, TagRepositoryError>;
+
+    /// Lista as tags do usuário em ordem alfabética, com a contagem de artigos
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<TagSummary>, TagRepositoryError>;
+
+    /// Tags cujo nome começa com `prefix`, das mais usadas para as menos usadas
+    async fn search(&self, user_id: Uuid, prefix: &str, limit: i64) -> Result<Vec<TagSummary>, TagRepositoryError>;
+
+    /// Atualiza o nome
+    async fn update(&self, tag: Tag) -> Result<Tag, TagRepositoryError>;
+
+    /// Remove uma tag do usuário (e suas marcações); retorna `false` se ela não existir
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, TagRepositoryError>;
+
+    /// Move as marcações de `source_id` para `target_id` e remove `source_id`
+    async fn merge(&self, user_id: Uuid, source_id: Uuid, target_id: Uuid) -> Result<(), TagRepositoryError>;
+
+    /// Tags do usuário em um artigo, em ordem alfabética
+    async fn list_by_article(&self, user_id: Uuid, article_id: Uuid) -> Result<Vec<Tag>, TagRepositoryError>;
+
+    /// Substitui as tags do usuário em um artigo pelas informadas
+    async fn set_article_tags(&self, user_id: Uuid, article_id: Uuid, tag_ids: &[Uuid]) -> Result<(), TagRepositoryError>;
+
+    /// IDs dos artigos marcados com a tag
+    async fn article_ids(&self, tag_id: Uuid) -> Result<Vec<Uuid>, TagRepositoryError>;
+}
+
+pub type DynTagRepository = Arc<dyn TagRepository>;
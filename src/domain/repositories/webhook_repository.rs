@@ -0,0 +1,59 @@
+use crate::domain::entities::webhook::{WebhookDelivery, WebhookSubscription};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum WebhookRepositoryError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn create(&self, subscription: WebhookSubscription) -> Result<WebhookSubscription, WebhookRepositoryError>;
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WebhookSubscription>, WebhookRepositoryError>;
+
+    /// Todas as assinaturas, das mais recentes para as mais antigas
+    async fn list(&self) -> Result<Vec<WebhookSubscription>, WebhookRepositoryError>;
+
+    /// Assinaturas ativas, usadas na distribuição dos eventos
+    async fn list_enabled(&self) -> Result<Vec<WebhookSubscription>, WebhookRepositoryError>;
+
+    async fn update(&self, subscription: WebhookSubscription) -> Result<WebhookSubscription, WebhookRepositoryError>;
+
+    /// Remove a assinatura e suas entregas; retorna `false` se ela não existia
+    async fn delete(&self, id: Uuid) -> Result<bool, WebhookRepositoryError>;
+
+    /// Registra a entrega; retorna `false` se o evento já tinha uma entrega para a assinatura
+    async fn create_delivery(&self, delivery: WebhookDelivery) -> Result<bool, WebhookRepositoryError>;
+
+    /// Reserva até `limit` entregas pendentes com tentativa vencida em `now`, adiando a próxima
+    /// tentativa para `lease_until`; assim duas instâncias não enviam a mesma entrega ao mesmo tempo
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, WebhookRepositoryError>;
+
+    /// Grava o resultado de uma tentativa
+    async fn update_delivery(&self, delivery: WebhookDelivery) -> Result<WebhookDelivery, WebhookRepositoryError>;
+
+    /// Entregas da assinatura, das mais recentes para as mais antigas, com o total
+    async fn list_deliveries(
+        &self,
+        subscription_id: Uuid,
+        page: i64,
+        per_page: i64,
+    ) -> Result<(Vec<WebhookDelivery>, i64), WebhookRepositoryError>;
+
+    /// Remove as entregas concluídas (com sucesso ou esgotadas) criadas antes de `before`;
+    /// retorna quantas foram removidas
+    async fn delete_finished_deliveries_before(&self, before: DateTime<Utc>) -> Result<usize, WebhookRepositoryError>;
+}
+
+pub type DynWebhookRepository = Arc<dyn WebhookRepository>;
@@ -1,9 +1,13 @@
 // Módulo de configurações de banco de dados
 pub mod schema;
 pub mod models;
+pub mod pg_listener;
+pub mod pg_article_event_notifier;
+pub mod pg_read_state_bus;
 
 use diesel::prelude::*;
 use diesel::r2d2::{ConnectionManager, Pool};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations};
 use std::env;
 
 pub type DbPool = Pool<ConnectionManager<PgConnection>>;
@@ -20,12 +24,46 @@ pub fn establish_connection_pool() -> DbPool {
         .expect("Failed to create pool")
 }
 
+/// Migrações renomeadas (versão antiga, versão nova); bancos que já aplicaram a versão antiga
+/// passam a registrar a nova, em vez de aplicá-la de novo
+const RENAMED_MIGRATIONS: &[(&str, &str)] = &[
+    // As versões de 2026-10-18 abaixo tinham horas inválidas
+    ("202610182400000000", "202610182330000000"),
+    ("202610182500000000", "202610182340000000"),
+    ("202610182600000000", "202610182350000000"),
+    ("202610182700000000", "202610182359000000"),
+];
+
+fn rename_applied_migrations(conn: &mut PgConnection) -> QueryResult<()> {
+    use diesel::sql_types::{Bool, Text};
+
+    let tracked = diesel::select(diesel::dsl::sql::<Bool>(
+        "to_regclass('__diesel_schema_migrations') IS NOT NULL",
+    ))
+    .get_result::<bool>(conn)?;
+    if !tracked {
+        return Ok(());
+    }
+
+    for (old_version, new_version) in RENAMED_MIGRATIONS {
+        diesel::sql_query("UPDATE __diesel_schema_migrations SET version = $1 WHERE version = $2")
+            .bind::<Text, _>(*new_version)
+            .bind::<Text, _>(*old_version)
+            .execute(conn)?;
+    }
+
+    Ok(())
+}
+
+/// Migrações embutidas no binário; públicas para os testes aplicarem uma a uma
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+
 pub fn run_migrations(pool: &DbPool) {
-    use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
-    
-    pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+    use diesel_migrations::MigrationHarness;
     
     let mut conn = pool.get().expect("Failed to get connection from pool");
+    rename_applied_migrations(&mut conn)
+        .expect("Failed to rename applied migrations");
     conn.run_pending_migrations(MIGRATIONS)
         .expect("Failed to run migrations");
     
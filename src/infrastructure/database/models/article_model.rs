@@ -30,6 +30,8 @@ pub struct ArticleModel {
     pub processing_columnist: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub user_rating_avg: Option<f64>,
+    pub user_rating_count: i32,
 }
 
 /// Modelo Diesel para inserção de novos artigos
@@ -0,0 +1,28 @@
+use crate::application::services::article_event_notifier::ArticleEventNotifier;
+use crate::infrastructure::database::pg_listener::PgListener;
+use async_trait::async_trait;
+use std::time::Duration;
+
+/// Canal do `pg_notify` disparado pelo trigger de `article_events`
+pub const ARTICLE_EVENTS_CHANNEL: &str = "article_events";
+
+/// Recebe os avisos do trigger de `article_events` com LISTEN
+pub struct PgArticleEventNotifier {
+    listener: PgListener,
+}
+
+impl PgArticleEventNotifier {
+    pub fn new(database_url: String) -> Self {
+        Self {
+            listener: PgListener::new(database_url, ARTICLE_EVENTS_CHANNEL),
+        }
+    }
+}
+
+#[async_trait]
+impl ArticleEventNotifier for PgArticleEventNotifier {
+    async fn wait(&self, timeout: Duration) {
+        // O payload é só o `seq`; o serviço do stream lê os eventos no outbox
+        self.listener.wait(timeout).await;
+    }
+}
@@ -0,0 +1,86 @@
+use diesel::prelude::*;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Intervalo entre as leituras do socket enquanto espera um aviso
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Escuta um canal com LISTEN numa conexão própria, fora do pool (o LISTEN vale para a sessão).
+/// Se a conexão cair, é refeita na próxima espera
+pub struct PgListener {
+    database_url: String,
+    channel: &'static str,
+    connection: Arc<Mutex<Option<PgConnection>>>,
+}
+
+impl PgListener {
+    pub fn new(database_url: String, channel: &'static str) -> Self {
+        Self {
+            database_url,
+            channel,
+            connection: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Espera avisos no canal por até `timeout` e retorna os payloads recebidos
+    /// (vazio se o tempo acabou ou a conexão falhou)
+    pub async fn wait(&self, timeout: Duration) -> Vec<String> {
+        let database_url = self.database_url.clone();
+        let channel = self.channel;
+        let connection = self.connection.clone();
+
+        let result = tokio::task::spawn_blocking(move || {
+            let mut connection = connection.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            let outcome = listen(&database_url, channel, &mut connection, timeout);
+            if outcome.is_err() {
+                *connection = None;
+            }
+            outcome
+        })
+        .await;
+
+        match result {
+            Ok(Ok(payloads)) => payloads,
+            Ok(Err(e)) => {
+                log::warn!("Listener on '{}' failed, retrying: {}", self.channel, e);
+                tokio::time::sleep(timeout).await;
+                Vec::new()
+            }
+            Err(e) => {
+                log::error!("Listener task on '{}' failed: {:?}", self.channel, e);
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// Bloqueia até chegar um aviso no canal ou acabar o `timeout`
+fn listen(
+    database_url: &str,
+    channel: &str,
+    connection: &mut Option<PgConnection>,
+    timeout: Duration,
+) -> Result<Vec<String>, String> {
+    let conn = match connection {
+        Some(conn) => conn,
+        None => {
+            let mut conn = PgConnection::establish(database_url).map_err(|e| e.to_string())?;
+            diesel::sql_query(format!("LISTEN {}", channel))
+                .execute(&mut conn)
+                .map_err(|e| e.to_string())?;
+            connection.insert(conn)
+        }
+    };
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        let mut payloads = Vec::new();
+        for notification in conn.notifications_iter() {
+            payloads.push(notification.map_err(|e| e.to_string())?.payload);
+        }
+        if !payloads.is_empty() || Instant::now() >= deadline {
+            return Ok(payloads);
+        }
+        std::thread::sleep(POLL_INTERVAL.min(deadline.saturating_duration_since(Instant::now())));
+    }
+}
@@ -0,0 +1,65 @@
+use crate::application::services::read_state_bus::{ReadStateBus, ReadStateBusError};
+use crate::domain::entities::read_state::ReadStateEvent;
+use crate::infrastructure::database::pg_listener::PgListener;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::sql_types::Text;
+use std::time::Duration;
+
+/// Canal do `pg_notify` das mudanças de estado de leitura
+pub const READ_STATE_CHANNEL: &str = "read_state";
+
+/// Repassa as mudanças de estado de leitura entre as instâncias com NOTIFY/LISTEN.
+/// O payload é o `ReadStateEvent` em JSON, bem abaixo do limite de 8000 bytes do NOTIFY
+pub struct PgReadStateBus {
+    pool: Pool<ConnectionManager<PgConnection>>,
+    listener: PgListener,
+}
+
+impl PgReadStateBus {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>, database_url: String) -> Self {
+        Self {
+            pool,
+            listener: PgListener::new(database_url, READ_STATE_CHANNEL),
+        }
+    }
+}
+
+#[async_trait]
+impl ReadStateBus for PgReadStateBus {
+    async fn publish(&self, event: &ReadStateEvent) -> Result<(), ReadStateBusError> {
+        let payload = serde_json::to_string(event)
+            .map_err(|e| ReadStateBusError::PublishFailed(e.to_string()))?;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| ReadStateBusError::PublishFailed(e.to_string()))?;
+
+        tokio::task::spawn_blocking(move || {
+            diesel::sql_query("SELECT pg_notify($1, $2)")
+                .bind::<Text, _>(READ_STATE_CHANNEL)
+                .bind::<Text, _>(payload)
+                .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| ReadStateBusError::PublishFailed(e.to_string()))?
+        .map_err(|e| ReadStateBusError::PublishFailed(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn receive(&self, timeout: Duration) -> Vec<ReadStateEvent> {
+        self.listener
+            .wait(timeout)
+            .await
+            .into_iter()
+            .filter_map(|payload| match serde_json::from_str(&payload) {
+                Ok(event) => Some(event),
+                Err(e) => {
+                    log::warn!("Ignoring invalid read state notification: {}", e);
+                    None
+                }
+            })
+            .collect()
+    }
+}
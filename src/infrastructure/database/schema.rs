@@ -1,5 +1,58 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    alert_rules (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 200]
+        query -> Nullable<Varchar>,
+        category_id -> Nullable<Uuid>,
+        feed_id -> Nullable<Uuid>,
+        min_rate -> Nullable<Int4>,
+        channels -> Array<Text>,
+        #[max_length = 500]
+        webhook_url -> Nullable<Varchar>,
+        enabled -> Bool,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    annotations (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        article_id -> Uuid,
+        quote -> Nullable<Text>,
+        prefix -> Nullable<Text>,
+        suffix -> Nullable<Text>,
+        start_offset -> Nullable<Int4>,
+        end_offset -> Nullable<Int4>,
+        note -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    api_keys (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 16]
+        prefix -> Varchar,
+        #[max_length = 64]
+        secret_hash -> Varchar,
+        scopes -> Array<Text>,
+        expires_at -> Nullable<Timestamptz>,
+        last_used_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     article_categories (id) {
         id -> Uuid,
@@ -10,6 +63,55 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    article_events (id) {
+        id -> Uuid,
+        #[max_length = 40]
+        event_type -> Varchar,
+        article_id -> Uuid,
+        payload -> Text,
+        created_at -> Timestamptz,
+        processed_at -> Nullable<Timestamptz>,
+        seq -> Int8,
+    }
+}
+
+diesel::table! {
+    article_ratings (user_id, article_id) {
+        user_id -> Uuid,
+        article_id -> Uuid,
+        rating -> Int4,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    article_reactions (user_id, article_id, reaction) {
+        user_id -> Uuid,
+        article_id -> Uuid,
+        #[max_length = 32]
+        reaction -> Varchar,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    article_reads (user_id, article_id) {
+        user_id -> Uuid,
+        article_id -> Uuid,
+        read_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    article_tags (tag_id, article_id) {
+        tag_id -> Uuid,
+        article_id -> Uuid,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     articles (id) {
         id -> Uuid,
@@ -38,6 +140,51 @@ diesel::table! {
         processing_columnist -> Bool,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        user_rating_avg -> Nullable<Float8>,
+        user_rating_count -> Int4,
+    }
+}
+
+diesel::table! {
+    collections (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 64]
+        share_token -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    digest_settings (user_id) {
+        user_id -> Uuid,
+        #[max_length = 10]
+        frequency -> Varchar,
+        hour -> Int4,
+        weekday -> Nullable<Int4>,
+        #[max_length = 64]
+        timezone -> Varchar,
+        max_articles -> Int4,
+        min_rate -> Nullable<Int4>,
+        enabled -> Bool,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    email_change_requests (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 255]
+        new_email -> Varchar,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        expires_at -> Timestamptz,
+        created_at -> Timestamptz,
     }
 }
 
@@ -62,6 +209,199 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    login_throttles (scope, subject) {
+        #[max_length = 20]
+        scope -> Varchar,
+        #[max_length = 255]
+        subject -> Varchar,
+        failed_attempts -> Int4,
+        lockout_count -> Int4,
+        locked_until -> Nullable<Timestamptz>,
+        last_failed_at -> Nullable<Timestamptz>,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    mfa_recovery_codes (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 64]
+        code_hash -> Varchar,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    notification_deliveries (id) {
+        id -> Uuid,
+        notification_id -> Uuid,
+        #[max_length = 20]
+        channel -> Varchar,
+        #[max_length = 20]
+        status -> Varchar,
+        attempts -> Int4,
+        next_attempt_at -> Nullable<Timestamptz>,
+        last_error -> Nullable<Text>,
+        created_at -> Timestamptz,
+        delivered_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    notifications (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        rule_id -> Uuid,
+        article_id -> Uuid,
+        #[max_length = 255]
+        title -> Varchar,
+        #[max_length = 255]
+        link -> Varchar,
+        read_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    oidc_auth_requests (state_hash) {
+        #[max_length = 64]
+        state_hash -> Varchar,
+        #[max_length = 50]
+        provider -> Varchar,
+        #[max_length = 128]
+        code_verifier -> Varchar,
+        #[max_length = 64]
+        nonce -> Varchar,
+        expires_at -> Timestamptz,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    saved_articles (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        article_id -> Nullable<Uuid>,
+        collection_id -> Nullable<Uuid>,
+        position -> Int4,
+        #[max_length = 255]
+        title -> Varchar,
+        description -> Text,
+        #[max_length = 255]
+        link -> Varchar,
+        #[max_length = 255]
+        media -> Nullable<Varchar>,
+        #[max_length = 255]
+        creator -> Varchar,
+        feed_id -> Uuid,
+        pub_date -> Timestamptz,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    sent_digests (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        period_start -> Timestamptz,
+        period_end -> Timestamptz,
+        article_count -> Int4,
+        sent_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    sessions (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 100]
+        device_label -> Nullable<Varchar>,
+        user_agent -> Nullable<Text>,
+        #[max_length = 45]
+        ip_address -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+        last_seen_at -> Timestamptz,
+        expires_at -> Timestamptz,
+        revoked_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    smart_folders (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 200]
+        search -> Nullable<Varchar>,
+        category_id -> Nullable<Uuid>,
+        tag_id -> Nullable<Uuid>,
+        unread_only -> Bool,
+        min_rate -> Nullable<Int4>,
+        min_user_rating -> Nullable<Float8>,
+        #[max_length = 20]
+        sort -> Varchar,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    subscriptions (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        feed_id -> Uuid,
+        #[max_length = 255]
+        title -> Nullable<Varchar>,
+        #[max_length = 100]
+        folder -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        weight -> Float8,
+    }
+}
+
+diesel::table! {
+    tags (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 50]
+        name -> Varchar,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    user_identities (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 50]
+        provider -> Varchar,
+        #[max_length = 255]
+        subject -> Varchar,
+        #[max_length = 255]
+        email -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+        last_login_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    user_mfa (user_id) {
+        user_id -> Uuid,
+        #[max_length = 64]
+        secret -> Varchar,
+        enabled -> Bool,
+        confirmed_at -> Nullable<Timestamptz>,
+        last_used_step -> Nullable<Int8>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     users (id) {
         id -> Uuid,
@@ -74,16 +414,117 @@ diesel::table! {
         is_active -> Bool,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        #[max_length = 20]
+        role -> Varchar,
+    }
+}
+
+diesel::table! {
+    webhook_deliveries (id) {
+        id -> Uuid,
+        subscription_id -> Uuid,
+        event_id -> Nullable<Uuid>,
+        #[max_length = 40]
+        event_type -> Varchar,
+        payload -> Text,
+        #[max_length = 20]
+        status -> Varchar,
+        attempts -> Int4,
+        next_attempt_at -> Nullable<Timestamptz>,
+        last_status_code -> Nullable<Int4>,
+        last_error -> Nullable<Text>,
+        created_at -> Timestamptz,
+        delivered_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    webhook_subscriptions (id) {
+        id -> Uuid,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 500]
+        url -> Varchar,
+        #[max_length = 100]
+        secret -> Varchar,
+        events -> Array<Text>,
+        enabled -> Bool,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
     }
 }
 
+diesel::joinable!(alert_rules -> article_categories (category_id));
+diesel::joinable!(alert_rules -> feeds (feed_id));
+diesel::joinable!(alert_rules -> users (user_id));
+diesel::joinable!(annotations -> articles (article_id));
+diesel::joinable!(annotations -> users (user_id));
+diesel::joinable!(api_keys -> users (user_id));
+diesel::joinable!(article_ratings -> articles (article_id));
+diesel::joinable!(article_ratings -> users (user_id));
+diesel::joinable!(article_reactions -> articles (article_id));
+diesel::joinable!(article_reactions -> users (user_id));
+diesel::joinable!(article_reads -> articles (article_id));
+diesel::joinable!(article_reads -> users (user_id));
+diesel::joinable!(article_tags -> articles (article_id));
+diesel::joinable!(article_tags -> tags (tag_id));
 diesel::joinable!(articles -> article_categories (category_id));
 diesel::joinable!(articles -> feeds (feed_id));
+diesel::joinable!(collections -> users (user_id));
+diesel::joinable!(digest_settings -> users (user_id));
+diesel::joinable!(email_change_requests -> users (user_id));
+diesel::joinable!(mfa_recovery_codes -> users (user_id));
+diesel::joinable!(notification_deliveries -> notifications (notification_id));
+diesel::joinable!(notifications -> alert_rules (rule_id));
+diesel::joinable!(notifications -> articles (article_id));
+diesel::joinable!(notifications -> users (user_id));
+diesel::joinable!(saved_articles -> articles (article_id));
+diesel::joinable!(saved_articles -> collections (collection_id));
+diesel::joinable!(saved_articles -> users (user_id));
+diesel::joinable!(sent_digests -> users (user_id));
+diesel::joinable!(sessions -> users (user_id));
+diesel::joinable!(smart_folders -> article_categories (category_id));
+diesel::joinable!(smart_folders -> tags (tag_id));
+diesel::joinable!(smart_folders -> users (user_id));
+diesel::joinable!(subscriptions -> feeds (feed_id));
+diesel::joinable!(subscriptions -> users (user_id));
+diesel::joinable!(tags -> users (user_id));
+diesel::joinable!(user_identities -> users (user_id));
+diesel::joinable!(user_mfa -> users (user_id));
+diesel::joinable!(webhook_deliveries -> webhook_subscriptions (subscription_id));
+diesel::joinable!(webhook_subscriptions -> users (created_by));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    alert_rules,
+    annotations,
+    api_keys,
     article_categories,
+    article_events,
+    article_ratings,
+    article_reactions,
+    article_reads,
+    article_tags,
     articles,
+    collections,
+    digest_settings,
+    email_change_requests,
     feeds,
+    login_throttles,
+    mfa_recovery_codes,
+    notification_deliveries,
+    notifications,
+    oidc_auth_requests,
+    saved_articles,
+    sent_digests,
+    sessions,
+    smart_folders,
+    subscriptions,
+    tags,
+    user_identities,
+    user_mfa,
     users,
+    webhook_deliveries,
+    webhook_subscriptions,
 );
 
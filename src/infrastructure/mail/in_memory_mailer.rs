@@ -0,0 +1,31 @@
+use crate::application::services::mailer::{EmailMessage, Mailer, MailerError};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Mailer em memória que guarda as mensagens enviadas, para testes
+#[derive(Debug, Clone, Default)]
+#[allow(dead_code)]
+pub struct InMemoryMailer {
+    sent: Arc<RwLock<Vec<EmailMessage>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryMailer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retorna as mensagens enviadas até o momento
+    pub async fn sent_messages(&self) -> Vec<EmailMessage> {
+        self.sent.read().await.clone()
+    }
+}
+
+#[async_trait]
+impl Mailer for InMemoryMailer {
+    async fn send(&self, message: EmailMessage) -> Result<(), MailerError> {
+        self.sent.write().await.push(message);
+        Ok(())
+    }
+}
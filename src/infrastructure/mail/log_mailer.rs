@@ -0,0 +1,30 @@
+use crate::application::services::mailer::{EmailMessage, Mailer, MailerError};
+use async_trait::async_trait;
+
+/// Mailer que apenas registra as mensagens no log (desenvolvimento)
+pub struct LogMailer;
+
+impl LogMailer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for LogMailer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, message: EmailMessage) -> Result<(), MailerError> {
+        log::info!(
+            "📧 Email para {} | {}\n{}",
+            message.to,
+            message.subject,
+            message.text_body
+        );
+        Ok(())
+    }
+}
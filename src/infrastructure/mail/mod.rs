@@ -0,0 +1,3 @@
+// Módulo de implementações de envio de email
+pub mod log_mailer;
+pub mod in_memory_mailer;
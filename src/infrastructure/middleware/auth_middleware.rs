@@ -1,14 +1,34 @@
-use crate::application::services::auth_service::AuthService;
+use crate::application::errors::{ApplicationError, ErrorKind};
+use crate::application::services::api_key_service::ApiKeyService;
+use crate::application::services::auth_service::{AuthService, Claims};
+use crate::application::services::session_service::SessionService;
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::domain::entities::user::User;
+use crate::domain::repositories::user_repository::DynUserRepository;
+use crate::infrastructure::web::ActixWebServer;
 use actix_web::{HttpRequest, HttpResponse};
 use std::sync::Arc;
 
 pub struct AuthMiddleware {
     auth_service: Arc<AuthService>,
+    user_repository: DynUserRepository,
+    api_key_service: Arc<ApiKeyService>,
+    session_service: Arc<SessionService>,
 }
 
 impl AuthMiddleware {
-    pub fn new(auth_service: Arc<AuthService>) -> Self {
-        Self { auth_service }
+    pub fn new(
+        auth_service: Arc<AuthService>,
+        user_repository: DynUserRepository,
+        api_key_service: Arc<ApiKeyService>,
+        session_service: Arc<SessionService>,
+    ) -> Self {
+        Self {
+            auth_service,
+            user_repository,
+            api_key_service,
+            session_service,
+        }
     }
 
     /// Extrai o token do header Authorization
@@ -21,42 +41,269 @@ impl AuthMiddleware {
             .map(|s| s.to_string())
     }
 
-    /// Verifica se o token JWT é válido e extrai o user_id
-    pub fn verify_token(&self, token: &str) -> Result<uuid::Uuid, AuthError> {
+    /// Extrai a chave de API do header X-API-Key ou de `Authorization: ApiKey ...`
+    pub fn extract_api_key(req: &HttpRequest) -> Option<String> {
+        if let Some(value) = req.headers().get("X-API-Key") {
+            return value.to_str().ok().map(|s| s.trim().to_string());
+        }
+
+        req.headers()
+            .get("Authorization")?
+            .to_str()
+            .ok()?
+            .strip_prefix("ApiKey ")
+            .map(|s| s.trim().to_string())
+    }
+
+    /// Verifica se o token JWT é válido e extrai o user_id e a sessão (`sid`)
+    pub fn verify_token(&self, token: &str) -> Result<(uuid::Uuid, Option<uuid::Uuid>), AuthError> {
         let claims = self.auth_service
             .verify_token(token)
             .map_err(|_| AuthError::InvalidToken)?;
-        
-        // Extrai o user_id do subject (sub) do claims
-        uuid::Uuid::parse_str(&claims.sub)
-            .map_err(|_| AuthError::InvalidToken)
+
+        Self::subject(claims)
+    }
+
+    /// Extrai o user_id do subject (sub) e a sessão (`sid`) dos claims
+    fn subject(claims: Claims) -> Result<(uuid::Uuid, Option<uuid::Uuid>), AuthError> {
+        let user_id = uuid::Uuid::parse_str(&claims.sub)
+            .map_err(|_| AuthError::InvalidToken)?;
+
+        let session_id = claims
+            .sid
+            .map(|sid| uuid::Uuid::parse_str(&sid).map_err(|_| AuthError::InvalidToken))
+            .transpose()?;
+
+        Ok((user_id, session_id))
+    }
+
+    /// Valida o token e carrega o usuário, rejeitando contas inativas ou removidas
+    pub async fn authenticate(&self, req: &HttpRequest) -> Result<User, AuthError> {
+        self.authenticate_session(req).await.map(|(user, _)| user)
+    }
+
+    /// Como `authenticate`, retornando também a sessão do token.
+    /// Tokens com `sid` só são aceitos enquanto a sessão estiver ativa;
+    /// tokens emitidos antes das sessões (sem `sid`) valem até expirar.
+    pub async fn authenticate_session(&self, req: &HttpRequest) -> Result<(User, Option<uuid::Uuid>), AuthError> {
+        let token = Self::extract_token(req).ok_or(AuthError::MissingToken)?;
+        self.authenticate_token(&token).await
+    }
+
+    /// Valida um access token já extraído da requisição e carrega o dono
+    pub async fn authenticate_token(&self, token: &str) -> Result<(User, Option<uuid::Uuid>), AuthError> {
+        let (user_id, session_id) = self.verify_token(token)?;
+        let user = self.reauthorize(user_id, session_id).await?;
+
+        Ok((user, session_id))
+    }
+
+    /// Valida um ticket de `POST /live/ticket`; a sessão de origem precisa continuar ativa
+    pub async fn authenticate_stream_ticket(&self, ticket: &str) -> Result<(User, Option<uuid::Uuid>), AuthError> {
+        let claims = self.auth_service
+            .verify_stream_ticket(ticket)
+            .map_err(|_| AuthError::InvalidToken)?;
+        let (user_id, session_id) = Self::subject(claims)?;
+        let user = self.reauthorize(user_id, session_id).await?;
+
+        Ok((user, session_id))
+    }
+
+    /// Confere de novo uma credencial já aceita: a sessão (quando houver) segue ativa e a conta, habilitada.
+    /// Usado por conexões longas, que não reenviam o token
+    pub async fn reauthorize(&self, user_id: uuid::Uuid, session_id: Option<uuid::Uuid>) -> Result<User, AuthError> {
+        if let Some(session_id) = session_id {
+            let active = self
+                .session_service
+                .validate(user_id, session_id)
+                .await
+                .map_err(|e| AuthError::Internal(e.to_string()))?;
+
+            if !active {
+                return Err(AuthError::SessionRevoked);
+            }
+        }
+
+        self.load_active_user(user_id).await
+    }
+
+    /// Carrega o dono do token, rejeitando contas inativas ou removidas
+    async fn load_active_user(&self, user_id: uuid::Uuid) -> Result<User, AuthError> {
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?
+            .ok_or(AuthError::InvalidToken)?;
+
+        if !user.is_active {
+            return Err(AuthError::InactiveAccount);
+        }
+
+        Ok(user)
+    }
+
+    /// Valida a chave de API, exige o escopo e carrega o dono da chave
+    async fn authenticate_api_key(&self, raw_key: &str, scope: ApiKeyScope) -> Result<User, AuthError> {
+        let api_key = self
+            .api_key_service
+            .authenticate(raw_key)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?
+            .ok_or(AuthError::InvalidApiKey)?;
+
+        if !api_key.has_scope(scope) {
+            return Err(AuthError::InsufficientScope);
+        }
+
+        let user = self
+            .user_repository
+            .find_by_id(api_key.user_id)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?
+            .ok_or(AuthError::InvalidApiKey)?;
+
+        if !user.is_active {
+            return Err(AuthError::InactiveAccount);
+        }
+
+        Ok(user)
     }
 
     /// Middleware que exige autenticação e retorna o user_id ou erro HTTP
-    pub fn require_auth(&self, req: &HttpRequest) -> Result<uuid::Uuid, HttpResponse> {
-        // Extrai o token do header
-        let token = Self::extract_token(req)
-            .ok_or_else(|| {
-                HttpResponse::Unauthorized()
-                    .json(serde_json::json!({
-                        "error": "Missing or invalid Authorization header"
-                    }))
-            })?;
-
-        // Verifica o token e retorna o user_id
-        self.verify_token(&token)
-            .map_err(|err| {
-                HttpResponse::Unauthorized()
-                    .json(serde_json::json!({
-                        "error": match err {
-                            AuthError::InvalidToken => "Invalid or expired token",
-                        }
-                    }))
-            })
+    pub async fn require_auth(&self, req: &HttpRequest) -> Result<uuid::Uuid, HttpResponse> {
+        self.require_user(req).await.map(|user| user.id)
+    }
+
+    /// Middleware que exige autenticação e retorna o usuário ou erro HTTP
+    pub async fn require_user(&self, req: &HttpRequest) -> Result<User, HttpResponse> {
+        self.authenticate(req).await.map_err(Self::error_response)
+    }
+
+    /// Como `require_auth`, aceitando também o token de cadastro do 2FA que o login emite para
+    /// editores e administradores ainda sem 2FA; usado só pelas rotas de cadastro do TOTP
+    pub async fn require_mfa_enrollment(&self, req: &HttpRequest) -> Result<uuid::Uuid, HttpResponse> {
+        let claims = Self::extract_token(req)
+            .and_then(|token| self.auth_service.verify_mfa_enrollment_token(&token).ok());
+
+        let Some(claims) = claims else {
+            return self.require_auth(req).await;
+        };
+
+        let user_id = uuid::Uuid::parse_str(&claims.sub)
+            .map_err(|_| Self::error_response(AuthError::InvalidToken))?;
+
+        self.load_active_user(user_id)
+            .await
+            .map(|user| user.id)
+            .map_err(Self::error_response)
+    }
+
+    /// Middleware que exige autenticação e retorna o user_id e a sessão atual
+    pub async fn require_session(&self, req: &HttpRequest) -> Result<(uuid::Uuid, Option<uuid::Uuid>), HttpResponse> {
+        self.authenticate_session(req)
+            .await
+            .map(|(user, session_id)| (user.id, session_id))
+            .map_err(Self::error_response)
+    }
+
+    /// Como `require_session`, retornando o usuário carregado
+    pub async fn require_user_session(&self, req: &HttpRequest) -> Result<(User, Option<uuid::Uuid>), HttpResponse> {
+        self.authenticate_session(req).await.map_err(Self::error_response)
+    }
+
+    /// Exige um ticket de stream válido (o WebSocket do navegador não envia headers próprios)
+    pub async fn require_stream_ticket(&self, ticket: &str) -> Result<(User, Option<uuid::Uuid>), HttpResponse> {
+        self.authenticate_stream_ticket(ticket).await.map_err(Self::error_response)
+    }
+
+    /// Middleware para rotas acessíveis a clientes automatizados: aceita um JWT
+    /// ou uma chave de API que possua o escopo exigido.
+    /// As demais rotas (`require_auth`/`require_user`) aceitam apenas JWT.
+    pub async fn require_scope(&self, req: &HttpRequest, scope: ApiKeyScope) -> Result<User, HttpResponse> {
+        match Self::extract_api_key(req) {
+            Some(raw_key) => self
+                .authenticate_api_key(&raw_key, scope)
+                .await
+                .map_err(Self::error_response),
+            None => self.require_user(req).await,
+        }
+    }
+
+    /// Para rotas públicas: valida a credencial (chave de API ou JWT) apenas quando ela é enviada,
+    /// para que credenciais inválidas ou sem o escopo sejam rejeitadas em vez de ignoradas.
+    /// Retorna o usuário identificado, usado para personalizar a resposta
+    pub async fn optional_scope(&self, req: &HttpRequest, scope: ApiKeyScope) -> Result<Option<User>, HttpResponse> {
+        match Self::extract_api_key(req) {
+            Some(raw_key) => self
+                .authenticate_api_key(&raw_key, scope)
+                .await
+                .map(Some)
+                .map_err(Self::error_response),
+            None if Self::extract_token(req).is_some() => self.require_user(req).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Middleware que exige um usuário com papel de administrador
+    pub async fn require_admin(&self, req: &HttpRequest) -> Result<User, HttpResponse> {
+        let user = self.require_user(req).await?;
+
+        if !user.is_admin() {
+            return Err(Self::error_response(AuthError::InsufficientRole));
+        }
+
+        Ok(user)
+    }
+
+    fn error_response(err: AuthError) -> HttpResponse {
+        ActixWebServer::problem(err.into())
+    }
+}
+
+impl From<AuthError> for ApplicationError {
+    fn from(err: AuthError) -> Self {
+        match err {
+            AuthError::MissingToken => ApplicationError::new(
+                ErrorKind::Unauthorized,
+                "missing_credentials",
+                "Missing or invalid Authorization header",
+            ),
+            AuthError::InvalidToken => {
+                ApplicationError::new(ErrorKind::Unauthorized, "invalid_token", "Invalid or expired token")
+            }
+            AuthError::SessionRevoked => ApplicationError::new(
+                ErrorKind::Unauthorized,
+                "session_revoked",
+                "Session has been revoked or has expired",
+            ),
+            AuthError::InvalidApiKey => {
+                ApplicationError::new(ErrorKind::Unauthorized, "invalid_api_key", "Invalid or expired API key")
+            }
+            AuthError::InsufficientScope => ApplicationError::new(
+                ErrorKind::Forbidden,
+                "insufficient_scope",
+                "API key does not have the required scope",
+            ),
+            AuthError::InactiveAccount => {
+                ApplicationError::new(ErrorKind::Forbidden, "account_inactive", "Account is inactive")
+            }
+            AuthError::InsufficientRole => {
+                ApplicationError::new(ErrorKind::Forbidden, "insufficient_role", "Insufficient permissions")
+            }
+            AuthError::Internal(msg) => ApplicationError::internal(format!("Failed to authenticate request: {}", msg)),
+        }
     }
 }
 
 #[derive(Debug)]
 pub enum AuthError {
+    MissingToken,
     InvalidToken,
+    SessionRevoked,
+    InvalidApiKey,
+    InactiveAccount,
+    InsufficientRole,
+    InsufficientScope,
+    Internal(String),
 }
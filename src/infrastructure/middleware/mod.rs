@@ -1 +1,3 @@
 pub mod auth_middleware;
+
+pub mod request_id;
\ No newline at end of file
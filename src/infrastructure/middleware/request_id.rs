@@ -0,0 +1,118 @@
+use crate::application::errors::ApplicationError;
+use crate::infrastructure::web::problem::{self, ErrorCause, PROBLEM_CONTENT_TYPE};
+use crate::infrastructure::web::Response;
+use actix_web::body::{self, BoxBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::{HeaderName, HeaderValue, CONTENT_TYPE};
+use actix_web::middleware::Next;
+use actix_web::HttpMessage;
+use uuid::Uuid;
+
+/// Header com o identificador da requisição, aceito do cliente (ou do proxy) e devolvido em toda resposta
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Tamanho máximo aceito para um request id enviado pelo cliente
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+/// Identificador da requisição, guardado nas extensions para correlacionar logs e respostas de erro
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+/// Atribui um request id a cada requisição e padroniza as respostas de erro: todo erro sai como
+/// `application/problem+json` com `instance` e `request_id`, e os erros 5xx são registrados no log com a causa
+pub async fn request_id(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, actix_web::Error> {
+    let id = req
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| is_valid(value))
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    req.extensions_mut().insert(RequestId(id.clone()));
+
+    let method = req.method().to_string();
+    let path = req.path().to_string();
+    let response = next.call(req).await?;
+    let status = response.status();
+
+    let mut response = if status.is_client_error() || status.is_server_error() {
+        if status.is_server_error() {
+            let cause = response.response().extensions().get::<ErrorCause>().map(|cause| cause.0.clone());
+            log::error!(
+                "Request {} {} {} failed with {}: {}",
+                id,
+                method,
+                path,
+                status.as_u16(),
+                cause.as_deref().unwrap_or("no cause recorded")
+            );
+        }
+        stamp_problem(response, &id, &path).await?
+    } else {
+        response.map_into_boxed_body()
+    };
+
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+    }
+    Ok(response)
+}
+
+/// Apenas caracteres visíveis, para o id poder ser repassado em headers e logs sem escape
+fn is_valid(id: &str) -> bool {
+    !id.is_empty() && id.len() <= MAX_REQUEST_ID_LENGTH && id.bytes().all(|byte| byte.is_ascii_graphic())
+}
+
+/// Completa o problema com `instance` e `request_id`; respostas de erro em outro formato (como as geradas
+/// pelo próprio Actix para rotas inexistentes ou payloads grandes demais) são convertidas em problemas
+async fn stamp_problem(
+    response: ServiceResponse<impl MessageBody + 'static>,
+    id: &str,
+    path: &str,
+) -> Result<ServiceResponse<BoxBody>, actix_web::Error> {
+    let (req, response) = response.into_parts();
+    let status = response.status().as_u16();
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let (mut head, body) = response.into_parts();
+    let bytes = body::to_bytes(body)
+        .await
+        .map_err(|_| actix_web::error::ErrorInternalServerError("Failed to read error response"))?;
+
+    let problem = if content_type.starts_with(PROBLEM_CONTENT_TYPE) {
+        serde_json::from_slice::<serde_json::Value>(&bytes).ok()
+    } else {
+        None
+    };
+
+    let mut problem = match problem {
+        Some(problem) => problem,
+        None => {
+            let detail = if content_type.starts_with("text/plain") && !bytes.is_empty() {
+                String::from_utf8_lossy(&bytes).into_owned()
+            } else {
+                problem::title_for(status).to_string()
+            };
+            let error = ApplicationError::from_kind(problem::kind_for(status), detail);
+            let body = Response::new(status).problem(&error).body.unwrap_or_default();
+            head.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_static(PROBLEM_CONTENT_TYPE));
+            serde_json::from_slice(&body).unwrap_or_default()
+        }
+    };
+
+    if let Some(object) = problem.as_object_mut() {
+        object.entry("instance").or_insert_with(|| path.into());
+        object.insert("request_id".to_string(), id.into());
+    }
+
+    let body = serde_json::to_vec(&problem).unwrap_or_default();
+    Ok(ServiceResponse::new(req, head.set_body(body).map_into_boxed_body()))
+}
\ No newline at end of file
@@ -1,4 +1,8 @@
 pub mod database;
 pub mod repositories;
 pub mod web;
-pub mod middleware;
\ No newline at end of file
+pub mod middleware;
+pub mod mail;
+pub mod security;
+pub mod oidc;
+pub mod notifications;
\ No newline at end of file
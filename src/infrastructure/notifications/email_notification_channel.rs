@@ -0,0 +1,47 @@
+use crate::application::services::mailer::{DynMailer, EmailMessage};
+use crate::application::services::notification_channel::{NotificationChannel, NotificationChannelError};
+use crate::domain::entities::alert::{AlertChannel, AlertRule, Notification};
+use crate::domain::entities::article::Article;
+use crate::domain::repositories::user_repository::DynUserRepository;
+use async_trait::async_trait;
+
+/// Entrega alertas por email, para o endereço atual do dono da regra
+pub struct EmailNotificationChannel {
+    mailer: DynMailer,
+    user_repository: DynUserRepository,
+}
+
+impl EmailNotificationChannel {
+    pub fn new(mailer: DynMailer, user_repository: DynUserRepository) -> Self {
+        Self { mailer, user_repository }
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for EmailNotificationChannel {
+    fn channel(&self) -> AlertChannel {
+        AlertChannel::Email
+    }
+
+    async fn deliver(&self, rule: &AlertRule, notification: &Notification, article: &Article) -> Result<(), NotificationChannelError> {
+        let user = self
+            .user_repository
+            .find_by_id(rule.user_id)
+            .await
+            .map_err(|e| NotificationChannelError::DeliveryFailed(e.to_string()))?
+            .ok_or_else(|| NotificationChannelError::DeliveryFailed("user not found".to_string()))?;
+
+        self.mailer
+            .send(EmailMessage {
+                to: user.email,
+                subject: format!("Alerta \"{}\": {}", rule.name, notification.title),
+                text_body: format!(
+                    "Olá {},\n\nUm novo artigo atende ao seu alerta \"{}\":\n\n{}\n{}\n\n{}",
+                    user.name, rule.name, notification.title, notification.link, article.description
+                ),
+                html_body: None,
+            })
+            .await
+            .map_err(|e| NotificationChannelError::DeliveryFailed(e.to_string()))
+    }
+}
@@ -0,0 +1,34 @@
+use crate::application::services::webhook_target_policy::WebhookTargetPolicy;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use std::net::SocketAddr;
+
+/// Resolve os hosts dos webhooks descartando os endereços recusados pela política, para que
+/// um domínio público não leve a requisição à rede interna
+pub(crate) struct GuardedResolver {
+    policy: WebhookTargetPolicy,
+}
+
+impl GuardedResolver {
+    pub(crate) fn new(policy: WebhookTargetPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+impl Resolve for GuardedResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let policy = self.policy;
+        let host = name.as_str().to_string();
+
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0))
+                .await?
+                .filter(|addr| policy.allows(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(format!("{} does not resolve to a public address", host).into());
+            }
+
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
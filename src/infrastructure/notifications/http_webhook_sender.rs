@@ -0,0 +1,60 @@
+use crate::application::services::webhook_sender::{WebhookRequest, WebhookSender, WebhookSenderError};
+use crate::application::services::webhook_target_policy::WebhookTargetPolicy;
+use crate::infrastructure::notifications::guarded_resolver::GuardedResolver;
+use async_trait::async_trait;
+use reqwest::redirect;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Tempo máximo de cada tentativa; o que passar disso é tratado como falha e tentado de novo
+const HTTP_TIMEOUT_SECONDS: u64 = 10;
+
+/// Envia os webhooks de eventos com um POST JSON. Como nos alertas, redirecionamentos não são
+/// seguidos e só destinos aceitos pela política são contatados
+pub struct HttpWebhookSender {
+    http: reqwest::Client,
+    policy: WebhookTargetPolicy,
+}
+
+impl HttpWebhookSender {
+    pub fn new(policy: WebhookTargetPolicy) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(HTTP_TIMEOUT_SECONDS))
+            .redirect(redirect::Policy::none())
+            .dns_resolver(Arc::new(GuardedResolver::new(policy)))
+            .build()
+            .unwrap_or_default();
+
+        Self { http, policy }
+    }
+}
+
+impl Default for HttpWebhookSender {
+    fn default() -> Self {
+        Self::new(WebhookTargetPolicy::public_only())
+    }
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn send(&self, request: &WebhookRequest) -> Result<u16, WebhookSenderError> {
+        // Webhooks antigos podem ter URLs que a política atual recusa
+        let url = self.policy.check_url(&request.url).map_err(WebhookSenderError::RequestFailed)?;
+
+        let mut builder = self
+            .http
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(request.body.clone());
+        for (name, value) in &request.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| WebhookSenderError::RequestFailed(e.to_string()))?;
+
+        Ok(response.status().as_u16())
+    }
+}
@@ -0,0 +1,5 @@
+// Módulo de canais de entrega de alertas
+pub mod email_notification_channel;
+pub mod webhook_notification_channel;
+pub mod http_webhook_sender;
+mod guarded_resolver;
\ No newline at end of file
@@ -0,0 +1,78 @@
+use crate::application::services::notification_channel::{NotificationChannel, NotificationChannelError};
+use crate::application::services::webhook_target_policy::WebhookTargetPolicy;
+use crate::domain::entities::alert::{AlertChannel, AlertRule, Notification, NotificationResponse};
+use crate::domain::entities::article::{Article, ArticleResponse};
+use crate::infrastructure::notifications::guarded_resolver::GuardedResolver;
+use async_trait::async_trait;
+use reqwest::redirect;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Tempo máximo de cada entrega, para não segurar a ingestão do artigo
+const HTTP_TIMEOUT_SECONDS: u64 = 5;
+
+/// Entrega alertas com um POST JSON para a `webhook_url` da regra. Redirecionamentos não são
+/// seguidos e só destinos aceitos pela política são contatados
+pub struct WebhookNotificationChannel {
+    http: reqwest::Client,
+    policy: WebhookTargetPolicy,
+}
+
+impl WebhookNotificationChannel {
+    pub fn new(policy: WebhookTargetPolicy) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(HTTP_TIMEOUT_SECONDS))
+            .redirect(redirect::Policy::none())
+            .dns_resolver(Arc::new(GuardedResolver::new(policy)))
+            .build()
+            .unwrap_or_default();
+
+        Self { http, policy }
+    }
+}
+
+impl Default for WebhookNotificationChannel {
+    fn default() -> Self {
+        Self::new(WebhookTargetPolicy::public_only())
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for WebhookNotificationChannel {
+    fn channel(&self) -> AlertChannel {
+        AlertChannel::Webhook
+    }
+
+    async fn deliver(&self, rule: &AlertRule, notification: &Notification, article: &Article) -> Result<(), NotificationChannelError> {
+        let url = rule
+            .webhook_url
+            .as_deref()
+            .ok_or_else(|| NotificationChannelError::DeliveryFailed("rule has no webhook URL".to_string()))?;
+        // Regras antigas podem ter URLs que a política atual recusa
+        let url = self.policy.check_url(url).map_err(NotificationChannelError::DeliveryFailed)?;
+
+        let payload = serde_json::json!({
+            "event": "alert.triggered",
+            "rule": { "id": rule.id, "name": rule.name },
+            "notification": NotificationResponse::from(notification.clone()),
+            "article": ArticleResponse::from(article.clone()),
+        });
+
+        let response = self
+            .http
+            .post(url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| NotificationChannelError::DeliveryFailed(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(NotificationChannelError::DeliveryFailed(format!(
+                "webhook responded with {}",
+                response.status()
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+// Módulo de integração com provedores OpenID Connect
+pub mod oidc_config;
+pub mod oidc_identity_provider;
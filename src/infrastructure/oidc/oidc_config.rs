@@ -0,0 +1,71 @@
+use thiserror::Error;
+
+/// Escopos pedidos quando `OIDC_<NOME>_SCOPES` não é informado
+const DEFAULT_SCOPES: &str = "openid email profile";
+
+#[derive(Debug, Error)]
+pub enum OidcConfigError {
+    #[error("Missing environment variable {0}")]
+    MissingVariable(String),
+
+    #[error("Invalid OIDC provider name '{0}' (use letters, digits, '-' or '_')")]
+    InvalidName(String),
+}
+
+/// Configuração de um provedor OIDC genérico (Google, Keycloak, Auth0, ...)
+#[derive(Debug, Clone, PartialEq)]
+pub struct OidcProviderConfig {
+    /// Nome usado nas rotas (`/auth/oidc/{name}`) e gravado nos vínculos
+    pub name: String,
+    pub issuer: String,
+    pub client_id: String,
+    /// Ausente para clientes públicos, que dependem apenas do PKCE
+    pub client_secret: Option<String>,
+    pub scopes: Vec<String>,
+    /// URL do frontend que recebe o `code` e o `state` e chama o callback da API
+    pub redirect_uri: String,
+}
+
+impl OidcProviderConfig {
+    /// Lê `OIDC_PROVIDERS` (nomes separados por vírgula) e, para cada nome,
+    /// `OIDC_<NOME>_ISSUER`, `_CLIENT_ID`, `_CLIENT_SECRET`, `_SCOPES` e `_REDIRECT_URI`
+    pub fn from_env() -> Result<Vec<Self>, OidcConfigError> {
+        let names = std::env::var("OIDC_PROVIDERS").unwrap_or_default();
+
+        names
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(Self::provider_from_env)
+            .collect()
+    }
+
+    fn provider_from_env(name: &str) -> Result<Self, OidcConfigError> {
+        if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(OidcConfigError::InvalidName(name.to_string()));
+        }
+
+        let prefix = format!("OIDC_{}_", name.to_ascii_uppercase().replace('-', "_"));
+        let optional = |key: &str| {
+            std::env::var(format!("{}{}", prefix, key))
+                .ok()
+                .filter(|value| !value.trim().is_empty())
+        };
+        let required = |key: &str| {
+            optional(key).ok_or_else(|| OidcConfigError::MissingVariable(format!("{}{}", prefix, key)))
+        };
+
+        Ok(Self {
+            name: name.to_ascii_lowercase(),
+            issuer: required("ISSUER")?,
+            client_id: required("CLIENT_ID")?,
+            client_secret: optional("CLIENT_SECRET"),
+            scopes: optional("SCOPES")
+                .unwrap_or_else(|| DEFAULT_SCOPES.to_string())
+                .split_whitespace()
+                .map(str::to_string)
+                .collect(),
+            redirect_uri: required("REDIRECT_URI")?,
+        })
+    }
+}
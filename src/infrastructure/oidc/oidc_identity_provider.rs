@@ -0,0 +1,240 @@
+use crate::application::services::identity_provider::{ExternalIdentity, IdentityProvider, IdentityProviderError};
+use crate::infrastructure::oidc::oidc_config::OidcProviderConfig;
+use async_trait::async_trait;
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use url::Url;
+
+/// Tempo máximo de cada chamada ao provedor
+const HTTP_TIMEOUT_SECONDS: u64 = 10;
+
+/// Campos usados do documento `/.well-known/openid-configuration`
+#[derive(Debug, Clone, Deserialize)]
+struct ProviderMetadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    id_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IdTokenClaims {
+    sub: String,
+    email: Option<String>,
+    /// Alguns provedores enviam `"true"` como string
+    email_verified: Option<serde_json::Value>,
+    name: Option<String>,
+    nonce: Option<String>,
+}
+
+/// Provedor OIDC genérico: discovery, authorization code com PKCE (S256)
+/// e validação do ID token pelas chaves publicadas no JWKS do provedor.
+pub struct OidcIdentityProvider {
+    config: OidcProviderConfig,
+    http: reqwest::Client,
+    metadata: RwLock<Option<ProviderMetadata>>,
+    jwks: RwLock<Option<JwkSet>>,
+}
+
+impl OidcIdentityProvider {
+    pub fn new(config: OidcProviderConfig) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(HTTP_TIMEOUT_SECONDS))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            config,
+            http,
+            metadata: RwLock::new(None),
+            jwks: RwLock::new(None),
+        }
+    }
+
+    /// Documento de discovery, buscado uma vez e mantido em memória
+    async fn metadata(&self) -> Result<ProviderMetadata, IdentityProviderError> {
+        if let Some(metadata) = self.metadata.read().await.as_ref() {
+            return Ok(metadata.clone());
+        }
+
+        let issuer = self.config.issuer.trim_end_matches('/');
+        let url = format!("{}/.well-known/openid-configuration", issuer);
+        let metadata: ProviderMetadata = self.get_json(&url).await?;
+
+        // O issuer anunciado precisa ser o configurado (OpenID Connect Discovery, seção 4.3)
+        if metadata.issuer.trim_end_matches('/') != issuer {
+            return Err(IdentityProviderError::Unavailable(format!(
+                "discovery issuer '{}' does not match configured issuer",
+                metadata.issuer
+            )));
+        }
+
+        *self.metadata.write().await = Some(metadata.clone());
+        Ok(metadata)
+    }
+
+    /// Chave do JWKS para o `kid` do token; recarrega o JWKS uma vez se o provedor rotacionou as chaves
+    async fn signing_key(&self, jwks_uri: &str, kid: Option<&str>) -> Result<Jwk, IdentityProviderError> {
+        let find = |jwks: &JwkSet| match kid {
+            Some(kid) => jwks.find(kid).cloned(),
+            None if jwks.keys.len() == 1 => jwks.keys.first().cloned(),
+            None => None,
+        };
+
+        if let Some(key) = self.jwks.read().await.as_ref().and_then(find) {
+            return Ok(key);
+        }
+
+        let jwks: JwkSet = self.get_json(jwks_uri).await?;
+        let key = find(&jwks);
+        *self.jwks.write().await = Some(jwks);
+
+        key.ok_or_else(|| IdentityProviderError::Rejected("ID token signed with an unknown key".to_string()))
+    }
+
+    async fn get_json<T: for<'de> Deserialize<'de>>(&self, url: &str) -> Result<T, IdentityProviderError> {
+        let response = self
+            .http
+            .get(url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| IdentityProviderError::Unavailable(e.to_string()))?;
+
+        response
+            .json::<T>()
+            .await
+            .map_err(|e| IdentityProviderError::Unavailable(e.to_string()))
+    }
+
+    async fn validate_id_token(
+        &self,
+        metadata: &ProviderMetadata,
+        id_token: &str,
+        nonce: &str,
+    ) -> Result<IdTokenClaims, IdentityProviderError> {
+        let rejected = |reason: String| IdentityProviderError::Rejected(reason);
+
+        let header = decode_header(id_token).map_err(|e| rejected(e.to_string()))?;
+        if matches!(header.alg, Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512) {
+            return Err(rejected("symmetric ID token signatures are not accepted".to_string()));
+        }
+
+        let jwk = self.signing_key(&metadata.jwks_uri, header.kid.as_deref()).await?;
+        let key = DecodingKey::from_jwk(&jwk).map_err(|e| rejected(e.to_string()))?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[metadata.issuer.as_str()]);
+        validation.set_audience(&[self.config.client_id.as_str()]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+        let claims = decode::<IdTokenClaims>(id_token, &key, &validation)
+            .map_err(|e| rejected(e.to_string()))?
+            .claims;
+
+        if claims.nonce.as_deref() != Some(nonce) {
+            return Err(rejected("nonce mismatch".to_string()));
+        }
+
+        Ok(claims)
+    }
+}
+
+#[async_trait]
+impl IdentityProvider for OidcIdentityProvider {
+    async fn authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> Result<String, IdentityProviderError> {
+        let metadata = self.metadata().await?;
+        let mut url = Url::parse(&metadata.authorization_endpoint)
+            .map_err(|e| IdentityProviderError::Unavailable(e.to_string()))?;
+
+        let mut scopes = self.config.scopes.clone();
+        if !scopes.iter().any(|scope| scope == "openid") {
+            scopes.insert(0, "openid".to_string());
+        }
+
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &self.config.client_id)
+            .append_pair("redirect_uri", &self.config.redirect_uri)
+            .append_pair("scope", &scopes.join(" "))
+            .append_pair("state", state)
+            .append_pair("nonce", nonce)
+            .append_pair("code_challenge", code_challenge)
+            .append_pair("code_challenge_method", "S256");
+
+        Ok(url.into())
+    }
+
+    async fn exchange_code(
+        &self,
+        code: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> Result<ExternalIdentity, IdentityProviderError> {
+        let metadata = self.metadata().await?;
+
+        let form = [
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", self.config.redirect_uri.as_str()),
+            ("code_verifier", code_verifier),
+            ("client_id", self.config.client_id.as_str()),
+        ];
+
+        let mut request = self.http.post(&metadata.token_endpoint).form(&form);
+        if let Some(secret) = &self.config.client_secret {
+            request = request.basic_auth(&self.config.client_id, Some(secret));
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| IdentityProviderError::Unavailable(e.to_string()))?;
+
+        // 4xx significa código inválido/expirado ou verifier incorreto (`invalid_grant`)
+        let status = response.status();
+        if status.is_client_error() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(IdentityProviderError::Rejected(format!("token endpoint returned {}: {}", status, body)));
+        }
+        if !status.is_success() {
+            return Err(IdentityProviderError::Unavailable(format!("token endpoint returned {}", status)));
+        }
+
+        let tokens: TokenResponse = response
+            .json()
+            .await
+            .map_err(|e| IdentityProviderError::Unavailable(e.to_string()))?;
+        let id_token = tokens
+            .id_token
+            .ok_or_else(|| IdentityProviderError::Rejected("token response has no id_token".to_string()))?;
+
+        let claims = self.validate_id_token(&metadata, &id_token, nonce).await?;
+
+        let email_verified = match &claims.email_verified {
+            Some(serde_json::Value::Bool(verified)) => *verified,
+            Some(serde_json::Value::String(verified)) => verified.eq_ignore_ascii_case("true"),
+            _ => false,
+        };
+
+        Ok(ExternalIdentity {
+            subject: claims.sub,
+            email: claims.email,
+            email_verified,
+            name: claims.name,
+        })
+    }
+}
@@ -0,0 +1,222 @@
+use crate::domain::entities::alert::{AlertChannel, AlertRule};
+use crate::domain::repositories::alert_rule_repository::{AlertRuleRepository, AlertRuleRepositoryError};
+use crate::infrastructure::database::schema::alert_rules;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use uuid::Uuid;
+
+// Modelo Diesel para leitura e escrita
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = alert_rules)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct AlertRuleModel {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    query: Option<String>,
+    category_id: Option<Uuid>,
+    feed_id: Option<Uuid>,
+    min_rate: Option<i32>,
+    channels: Vec<String>,
+    webhook_url: Option<String>,
+    enabled: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<AlertRuleModel> for AlertRule {
+    fn from(model: AlertRuleModel) -> Self {
+        AlertRule {
+            id: model.id,
+            user_id: model.user_id,
+            name: model.name,
+            query: model.query,
+            category_id: model.category_id,
+            feed_id: model.feed_id,
+            min_rate: model.min_rate,
+            channels: model.channels.iter().filter_map(|c| AlertChannel::parse(c)).collect(),
+            webhook_url: model.webhook_url,
+            enabled: model.enabled,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl From<AlertRule> for AlertRuleModel {
+    fn from(rule: AlertRule) -> Self {
+        AlertRuleModel {
+            id: rule.id,
+            user_id: rule.user_id,
+            name: rule.name,
+            query: rule.query,
+            category_id: rule.category_id,
+            feed_id: rule.feed_id,
+            min_rate: rule.min_rate,
+            channels: rule.channels.iter().map(|c| c.as_str().to_string()).collect(),
+            webhook_url: rule.webhook_url,
+            enabled: rule.enabled,
+            created_at: rule.created_at,
+            updated_at: rule.updated_at,
+        }
+    }
+}
+
+fn map_write_error(e: DieselError) -> AlertRuleRepositoryError {
+    match e {
+        DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _) => AlertRuleRepositoryError::FeedNotFound,
+        _ => AlertRuleRepositoryError::DatabaseError(e.to_string()),
+    }
+}
+
+pub struct DieselAlertRuleRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselAlertRuleRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AlertRuleRepository for DieselAlertRuleRepository {
+    async fn create(&self, rule: AlertRule) -> Result<AlertRule, AlertRuleRepositoryError> {
+        let mut conn = self.pool.get()
+            .map_err(|e| AlertRuleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = AlertRuleModel::from(rule);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::insert_into(alert_rules::table)
+                .values(&model)
+                .returning(AlertRuleModel::as_returning())
+                .get_result::<AlertRuleModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| AlertRuleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(map_write_error)?;
+
+        Ok(result.into())
+    }
+
+    async fn find_by_id(&self, owner_id: Uuid, rule_id: Uuid) -> Result<Option<AlertRule>, AlertRuleRepositoryError> {
+        use crate::infrastructure::database::schema::alert_rules::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| AlertRuleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            alert_rules
+                .filter(id.eq(rule_id))
+                .filter(user_id.eq(owner_id))
+                .select(AlertRuleModel::as_select())
+                .first::<AlertRuleModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| AlertRuleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| AlertRuleRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn list_by_user(&self, owner_id: Uuid) -> Result<Vec<AlertRule>, AlertRuleRepositoryError> {
+        use crate::infrastructure::database::schema::alert_rules::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| AlertRuleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            alert_rules
+                .filter(user_id.eq(owner_id))
+                .order((name.asc(), created_at.asc()))
+                .select(AlertRuleModel::as_select())
+                .load::<AlertRuleModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| AlertRuleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| AlertRuleRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn find_candidates(&self, article_feed_id: Uuid, article_category_id: Option<Uuid>) -> Result<Vec<AlertRule>, AlertRuleRepositoryError> {
+        use crate::infrastructure::database::schema::alert_rules::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| AlertRuleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            let candidates = alert_rules
+                .filter(enabled.eq(true))
+                .filter(feed_id.is_null().or(feed_id.eq(article_feed_id)))
+                .into_boxed();
+            let candidates = match article_category_id {
+                Some(article_category_id) => {
+                    candidates.filter(category_id.is_null().or(category_id.eq(article_category_id)))
+                }
+                None => candidates.filter(category_id.is_null()),
+            };
+
+            candidates
+                .select(AlertRuleModel::as_select())
+                .load::<AlertRuleModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| AlertRuleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| AlertRuleRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn update(&self, rule: AlertRule) -> Result<AlertRule, AlertRuleRepositoryError> {
+        use crate::infrastructure::database::schema::alert_rules::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| AlertRuleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = AlertRuleModel::from(rule);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::update(alert_rules.filter(id.eq(model.id)))
+                .set((
+                    name.eq(model.name),
+                    query.eq(model.query),
+                    category_id.eq(model.category_id),
+                    feed_id.eq(model.feed_id),
+                    min_rate.eq(model.min_rate),
+                    channels.eq(model.channels),
+                    webhook_url.eq(model.webhook_url),
+                    enabled.eq(model.enabled),
+                    updated_at.eq(model.updated_at),
+                ))
+                .returning(AlertRuleModel::as_returning())
+                .get_result::<AlertRuleModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| AlertRuleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(map_write_error)?;
+
+        Ok(result.into())
+    }
+
+    async fn delete(&self, owner_id: Uuid, rule_id: Uuid) -> Result<bool, AlertRuleRepositoryError> {
+        use crate::infrastructure::database::schema::alert_rules::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| AlertRuleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let deleted = tokio::task::spawn_blocking(move || {
+            diesel::delete(alert_rules.filter(id.eq(rule_id)).filter(user_id.eq(owner_id))).execute(&mut conn)
+        })
+        .await
+        .map_err(|e| AlertRuleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| AlertRuleRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deleted > 0)
+    }
+}
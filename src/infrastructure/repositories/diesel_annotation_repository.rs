@@ -0,0 +1,220 @@
+use crate::domain::entities::annotation::{Annotation, TextQuoteAnchor};
+use crate::domain::repositories::annotation_repository::{AnnotationRepository, AnnotationRepositoryError};
+use crate::infrastructure::database::schema::annotations;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use uuid::Uuid;
+
+// Modelo Diesel para leitura e escrita; a âncora fica em colunas próprias, todas nulas para notas gerais
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = annotations)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct AnnotationModel {
+    id: Uuid,
+    user_id: Uuid,
+    article_id: Uuid,
+    quote: Option<String>,
+    prefix: Option<String>,
+    suffix: Option<String>,
+    start_offset: Option<i32>,
+    end_offset: Option<i32>,
+    note: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<AnnotationModel> for Annotation {
+    fn from(model: AnnotationModel) -> Self {
+        let anchor = match (model.quote, model.start_offset, model.end_offset) {
+            (Some(quote), Some(start), Some(end)) => Some(TextQuoteAnchor {
+                quote,
+                prefix: model.prefix.unwrap_or_default(),
+                suffix: model.suffix.unwrap_or_default(),
+                start,
+                end,
+            }),
+            _ => None,
+        };
+
+        Annotation {
+            id: model.id,
+            user_id: model.user_id,
+            article_id: model.article_id,
+            anchor,
+            note: model.note,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl From<Annotation> for AnnotationModel {
+    fn from(annotation: Annotation) -> Self {
+        let anchor = annotation.anchor;
+        AnnotationModel {
+            id: annotation.id,
+            user_id: annotation.user_id,
+            article_id: annotation.article_id,
+            start_offset: anchor.as_ref().map(|anchor| anchor.start),
+            end_offset: anchor.as_ref().map(|anchor| anchor.end),
+            prefix: anchor.as_ref().map(|anchor| anchor.prefix.clone()),
+            suffix: anchor.as_ref().map(|anchor| anchor.suffix.clone()),
+            quote: anchor.map(|anchor| anchor.quote),
+            note: annotation.note,
+            created_at: annotation.created_at,
+            updated_at: annotation.updated_at,
+        }
+    }
+}
+
+pub struct DieselAnnotationRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselAnnotationRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AnnotationRepository for DieselAnnotationRepository {
+    async fn create(&self, annotation: Annotation) -> Result<Annotation, AnnotationRepositoryError> {
+        let mut conn = self.pool.get()
+            .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = AnnotationModel::from(annotation);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::insert_into(annotations::table)
+                .values(&model)
+                .returning(AnnotationModel::as_returning())
+                .get_result::<AnnotationModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| match e {
+            DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _) => {
+                AnnotationRepositoryError::ArticleNotFound
+            }
+            _ => AnnotationRepositoryError::DatabaseError(e.to_string()),
+        })?;
+
+        Ok(result.into())
+    }
+
+    async fn find_by_id(&self, owner_id: Uuid, annotation_id: Uuid) -> Result<Option<Annotation>, AnnotationRepositoryError> {
+        use crate::infrastructure::database::schema::annotations::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            annotations
+                .filter(id.eq(annotation_id))
+                .filter(user_id.eq(owner_id))
+                .select(AnnotationModel::as_select())
+                .first::<AnnotationModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn list_by_article(&self, owner_id: Uuid, target_article_id: Uuid) -> Result<Vec<Annotation>, AnnotationRepositoryError> {
+        use crate::infrastructure::database::schema::annotations::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            annotations
+                .filter(user_id.eq(owner_id))
+                .filter(article_id.eq(target_article_id))
+                .order((start_offset.asc().nulls_first(), created_at.asc()))
+                .select(AnnotationModel::as_select())
+                .load::<AnnotationModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn list_by_user(&self, owner_id: Uuid) -> Result<Vec<Annotation>, AnnotationRepositoryError> {
+        use crate::infrastructure::database::schema::annotations::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            annotations
+                .filter(user_id.eq(owner_id))
+                .order(created_at.asc())
+                .select(AnnotationModel::as_select())
+                .load::<AnnotationModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn update(&self, annotation: Annotation) -> Result<Annotation, AnnotationRepositoryError> {
+        use crate::infrastructure::database::schema::annotations::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = AnnotationModel::from(annotation);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::update(annotations.filter(id.eq(model.id)))
+                .set((
+                    quote.eq(model.quote),
+                    prefix.eq(model.prefix),
+                    suffix.eq(model.suffix),
+                    start_offset.eq(model.start_offset),
+                    end_offset.eq(model.end_offset),
+                    note.eq(model.note),
+                    updated_at.eq(model.updated_at),
+                ))
+                .returning(AnnotationModel::as_returning())
+                .get_result::<AnnotationModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.into())
+    }
+
+    async fn delete(&self, owner_id: Uuid, annotation_id: Uuid) -> Result<bool, AnnotationRepositoryError> {
+        use crate::infrastructure::database::schema::annotations::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?;
+
+        let deleted = tokio::task::spawn_blocking(move || {
+            diesel::delete(
+                annotations
+                    .filter(id.eq(annotation_id))
+                    .filter(user_id.eq(owner_id)),
+            )
+            .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| AnnotationRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deleted > 0)
+    }
+}
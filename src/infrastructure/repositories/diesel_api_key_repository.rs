@@ -0,0 +1,172 @@
+use crate::domain::entities::api_key::{ApiKey, ApiKeyScope};
+use crate::domain::repositories::api_key_repository::{ApiKeyRepository, ApiKeyRepositoryError};
+use crate::infrastructure::database::schema::api_keys;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use uuid::Uuid;
+
+// Modelo Diesel para leitura e escrita
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = api_keys)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct ApiKeyModel {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    prefix: String,
+    secret_hash: String,
+    scopes: Vec<String>,
+    expires_at: Option<DateTime<Utc>>,
+    last_used_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<ApiKeyModel> for ApiKey {
+    fn from(model: ApiKeyModel) -> Self {
+        ApiKey {
+            id: model.id,
+            user_id: model.user_id,
+            name: model.name,
+            prefix: model.prefix,
+            secret_hash: model.secret_hash,
+            // Escopos desconhecidos (ex.: removidos do código) são ignorados
+            scopes: model.scopes.iter().filter_map(|s| ApiKeyScope::parse(s)).collect(),
+            expires_at: model.expires_at,
+            last_used_at: model.last_used_at,
+            created_at: model.created_at,
+        }
+    }
+}
+
+impl From<ApiKey> for ApiKeyModel {
+    fn from(api_key: ApiKey) -> Self {
+        ApiKeyModel {
+            id: api_key.id,
+            user_id: api_key.user_id,
+            name: api_key.name,
+            prefix: api_key.prefix,
+            secret_hash: api_key.secret_hash,
+            scopes: api_key.scopes.iter().map(|s| s.as_str().to_string()).collect(),
+            expires_at: api_key.expires_at,
+            last_used_at: api_key.last_used_at,
+            created_at: api_key.created_at,
+        }
+    }
+}
+
+pub struct DieselApiKeyRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselApiKeyRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for DieselApiKeyRepository {
+    async fn create(&self, api_key: ApiKey) -> Result<ApiKey, ApiKeyRepositoryError> {
+        let mut conn = self.pool.get()
+            .map_err(|e| ApiKeyRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = ApiKeyModel::from(api_key);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::insert_into(api_keys::table)
+                .values(&model)
+                .returning(ApiKeyModel::as_returning())
+                .get_result::<ApiKeyModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| ApiKeyRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e: diesel::result::Error| match e {
+            diesel::result::Error::DatabaseError(
+                diesel::result::DatabaseErrorKind::UniqueViolation,
+                _,
+            ) => ApiKeyRepositoryError::PrefixAlreadyExists,
+            _ => ApiKeyRepositoryError::DatabaseError(e.to_string()),
+        })?;
+
+        Ok(result.into())
+    }
+
+    async fn find_by_prefix(&self, key_prefix: &str) -> Result<Option<ApiKey>, ApiKeyRepositoryError> {
+        use crate::infrastructure::database::schema::api_keys::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| ApiKeyRepositoryError::DatabaseError(e.to_string()))?;
+
+        let key_prefix = key_prefix.to_string();
+
+        let result = tokio::task::spawn_blocking(move || {
+            api_keys
+                .filter(prefix.eq(key_prefix))
+                .select(ApiKeyModel::as_select())
+                .first::<ApiKeyModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| ApiKeyRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| ApiKeyRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn list_by_user(&self, owner_id: Uuid) -> Result<Vec<ApiKey>, ApiKeyRepositoryError> {
+        use crate::infrastructure::database::schema::api_keys::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| ApiKeyRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            api_keys
+                .filter(user_id.eq(owner_id))
+                .order(created_at.desc())
+                .select(ApiKeyModel::as_select())
+                .load::<ApiKeyModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| ApiKeyRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| ApiKeyRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn delete(&self, owner_id: Uuid, key_id: Uuid) -> Result<bool, ApiKeyRepositoryError> {
+        use crate::infrastructure::database::schema::api_keys::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| ApiKeyRepositoryError::DatabaseError(e.to_string()))?;
+
+        let deleted = tokio::task::spawn_blocking(move || {
+            diesel::delete(api_keys.filter(id.eq(key_id)).filter(user_id.eq(owner_id)))
+                .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| ApiKeyRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| ApiKeyRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deleted > 0)
+    }
+
+    async fn touch(&self, key_id: Uuid, used_at: DateTime<Utc>) -> Result<(), ApiKeyRepositoryError> {
+        use crate::infrastructure::database::schema::api_keys::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| ApiKeyRepositoryError::DatabaseError(e.to_string()))?;
+
+        tokio::task::spawn_blocking(move || {
+            diesel::update(api_keys.filter(id.eq(key_id)))
+                .set(last_used_at.eq(Some(used_at)))
+                .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| ApiKeyRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| ApiKeyRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+use crate::domain::entities::webhook::{ArticleEvent, WebhookEventType};
+use crate::domain::repositories::article_event_repository::{ArticleEventRepository, ArticleEventRepositoryError};
+use crate::infrastructure::database::schema::article_events;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use uuid::Uuid;
+
+// Modelo Diesel para leitura dos eventos do outbox
+#[derive(Queryable, Selectable, Debug, Clone)]
+#[diesel(table_name = article_events)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct ArticleEventModel {
+    id: Uuid,
+    event_type: String,
+    article_id: Uuid,
+    payload: String,
+    created_at: DateTime<Utc>,
+    processed_at: Option<DateTime<Utc>>,
+    seq: i64,
+}
+
+// Modelo Diesel para gravação; o `seq` vem do BIGSERIAL
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = article_events)]
+struct NewArticleEventModel {
+    id: Uuid,
+    event_type: String,
+    article_id: Uuid,
+    payload: String,
+    created_at: DateTime<Utc>,
+    processed_at: Option<DateTime<Utc>>,
+}
+
+impl From<ArticleEventModel> for ArticleEvent {
+    fn from(model: ArticleEventModel) -> Self {
+        ArticleEvent {
+            id: model.id,
+            seq: model.seq,
+            event_type: WebhookEventType::parse(&model.event_type).unwrap_or(WebhookEventType::ArticleUpdated),
+            article_id: model.article_id,
+            payload: serde_json::from_str(&model.payload).unwrap_or_default(),
+            created_at: model.created_at,
+            processed_at: model.processed_at,
+        }
+    }
+}
+
+impl From<&ArticleEvent> for NewArticleEventModel {
+    fn from(event: &ArticleEvent) -> Self {
+        NewArticleEventModel {
+            id: event.id,
+            event_type: event.event_type.as_str().to_string(),
+            article_id: event.article_id,
+            payload: event.payload.to_string(),
+            created_at: event.created_at,
+            processed_at: event.processed_at,
+        }
+    }
+}
+
+/// Grava o evento no outbox; chamado pelo repositório de artigos dentro da transação da escrita
+pub(crate) fn insert_event(conn: &mut PgConnection, event: &ArticleEvent) -> QueryResult<()> {
+    diesel::insert_into(article_events::table)
+        .values(NewArticleEventModel::from(event))
+        .execute(conn)
+        .map(|_| ())
+}
+
+pub struct DieselArticleEventRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselArticleEventRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ArticleEventRepository for DieselArticleEventRepository {
+    async fn list_pending(&self, limit: i64) -> Result<Vec<ArticleEvent>, ArticleEventRepositoryError> {
+        use crate::infrastructure::database::schema::article_events::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| ArticleEventRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            article_events
+                .filter(processed_at.is_null())
+                .order(created_at.asc())
+                .limit(limit)
+                .select(ArticleEventModel::as_select())
+                .load::<ArticleEventModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| ArticleEventRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| ArticleEventRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn mark_processed(&self, event_id: Uuid, at: DateTime<Utc>) -> Result<(), ArticleEventRepositoryError> {
+        use crate::infrastructure::database::schema::article_events::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| ArticleEventRepositoryError::DatabaseError(e.to_string()))?;
+
+        tokio::task::spawn_blocking(move || {
+            diesel::update(article_events.filter(id.eq(event_id)))
+                .set(processed_at.eq(Some(at)))
+                .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| ArticleEventRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| ArticleEventRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn latest_seq(&self) -> Result<i64, ArticleEventRepositoryError> {
+        use crate::infrastructure::database::schema::article_events::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| ArticleEventRepositoryError::DatabaseError(e.to_string()))?;
+
+        let latest = tokio::task::spawn_blocking(move || {
+            article_events
+                .select(diesel::dsl::max(seq))
+                .first::<Option<i64>>(&mut conn)
+        })
+        .await
+        .map_err(|e| ArticleEventRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| ArticleEventRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(latest.unwrap_or(0))
+    }
+
+    async fn list_after(&self, after: i64, limit: i64) -> Result<Vec<ArticleEvent>, ArticleEventRepositoryError> {
+        use crate::infrastructure::database::schema::article_events::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| ArticleEventRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            article_events
+                .filter(seq.gt(after))
+                .order(seq.asc())
+                .limit(limit)
+                .select(ArticleEventModel::as_select())
+                .load::<ArticleEventModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| ArticleEventRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| ArticleEventRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn delete_processed_before(&self, before: DateTime<Utc>) -> Result<usize, ArticleEventRepositoryError> {
+        use crate::infrastructure::database::schema::article_events::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| ArticleEventRepositoryError::DatabaseError(e.to_string()))?;
+
+        let deleted = tokio::task::spawn_blocking(move || {
+            diesel::delete(
+                article_events
+                    .filter(processed_at.is_not_null())
+                    .filter(created_at.lt(before)),
+            )
+            .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| ArticleEventRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| ArticleEventRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deleted)
+    }
+}
@@ -0,0 +1,121 @@
+use crate::domain::entities::reaction::ReactionCount;
+use crate::domain::repositories::article_reaction_repository::{ArticleReactionRepository, ArticleReactionRepositoryError};
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use uuid::Uuid;
+
+pub struct DieselArticleReactionRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselArticleReactionRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ArticleReactionRepository for DieselArticleReactionRepository {
+    async fn add(&self, reactor_id: Uuid, target_article_id: Uuid, value: &str) -> Result<(), ArticleReactionRepositoryError> {
+        use crate::infrastructure::database::schema::article_reactions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| ArticleReactionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let value = value.to_string();
+
+        tokio::task::spawn_blocking(move || {
+            diesel::insert_into(article_reactions)
+                .values((
+                    user_id.eq(reactor_id),
+                    article_id.eq(target_article_id),
+                    reaction.eq(value),
+                    created_at.eq(chrono::Utc::now()),
+                ))
+                .on_conflict_do_nothing()
+                .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| ArticleReactionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| match e {
+            DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _) => {
+                ArticleReactionRepositoryError::ArticleNotFound
+            }
+            _ => ArticleReactionRepositoryError::DatabaseError(e.to_string()),
+        })?;
+
+        Ok(())
+    }
+
+    async fn remove(&self, reactor_id: Uuid, target_article_id: Uuid, value: &str) -> Result<bool, ArticleReactionRepositoryError> {
+        use crate::infrastructure::database::schema::article_reactions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| ArticleReactionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let value = value.to_string();
+
+        let deleted = tokio::task::spawn_blocking(move || {
+            diesel::delete(
+                article_reactions
+                    .filter(user_id.eq(reactor_id))
+                    .filter(article_id.eq(target_article_id))
+                    .filter(reaction.eq(value)),
+            )
+            .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| ArticleReactionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| ArticleReactionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deleted > 0)
+    }
+
+    async fn counts(&self, target_article_id: Uuid) -> Result<Vec<ReactionCount>, ArticleReactionRepositoryError> {
+        use crate::infrastructure::database::schema::article_reactions::dsl::*;
+        use diesel::dsl::count_star;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| ArticleReactionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            article_reactions
+                .filter(article_id.eq(target_article_id))
+                .group_by(reaction)
+                .select((reaction, count_star()))
+                .order((count_star().desc(), reaction.asc()))
+                .load::<(String, i64)>(&mut conn)
+        })
+        .await
+        .map_err(|e| ArticleReactionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| ArticleReactionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results
+            .into_iter()
+            .map(|(value, count)| ReactionCount { reaction: value, count })
+            .collect())
+    }
+
+    async fn list_by_user(&self, reactor_id: Uuid, target_article_id: Uuid) -> Result<Vec<String>, ArticleReactionRepositoryError> {
+        use crate::infrastructure::database::schema::article_reactions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| ArticleReactionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            article_reactions
+                .filter(user_id.eq(reactor_id))
+                .filter(article_id.eq(target_article_id))
+                .order(reaction.asc())
+                .select(reaction)
+                .load::<String>(&mut conn)
+        })
+        .await
+        .map_err(|e| ArticleReactionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| ArticleReactionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results)
+    }
+}
@@ -1,20 +1,141 @@
-use crate::domain::entities::article::{Article, ArticleResponse, PaginatedArticles};
+use crate::domain::entities::article::{
+    Article, ArticleListFilter, ArticleRatingSummary, ArticleResponse, ArticleSort, ArticleUserState,
+    CategoryUnreadCount, FeedUnreadCount, PaginatedArticles, UnreadCounts,
+};
+use crate::domain::entities::article_export::ArticleExportCursor;
+use crate::domain::entities::ranking::CategoryEngagement;
+use crate::domain::entities::webhook::{ArticleEvent, WebhookEventType};
 use crate::domain::repositories::article_repository::{ArticleRepository, ArticleRepositoryError};
 use crate::infrastructure::database::models::article_model::{ArticleModel, NewArticle, UpdateArticleModel};
+use crate::infrastructure::database::schema::{article_ratings, article_reads, articles};
 use crate::infrastructure::database::DbPool;
+use crate::infrastructure::repositories::diesel_article_event_repository::insert_event;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::pg::Pg;
 use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use std::collections::HashMap;
 use uuid::Uuid;
 
+/// Padrão ILIKE que encontra o texto em qualquer posição, com os curingas escapados
+fn like_contains(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{}%", escaped)
+}
+
 pub struct DieselArticleRepository {
     pool: DbPool,
 }
 
+/// Erro de escrita de um artigo; o índice único em `link` vira `LinkAlreadyExists`
+fn map_write_error(action: &str, e: DieselError) -> ArticleRepositoryError {
+    match e {
+        DieselError::NotFound => ArticleRepositoryError::NotFound,
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => ArticleRepositoryError::LinkAlreadyExists,
+        _ => ArticleRepositoryError::DatabaseError(format!("Failed to {} article: {}", action, e)),
+    }
+}
+
 impl DieselArticleRepository {
     pub fn new(pool: DbPool) -> Self {
         Self { pool }
     }
 
+    /// Consulta de artigos com os filtros aplicados, usada tanto na contagem quanto na página
+    fn filtered_query(filter: &ArticleListFilter) -> articles::BoxedQuery<'static, Pg> {
+        let mut query = articles::table.into_boxed();
+
+        if let Some(feed_ids) = &filter.feed_ids {
+            query = query.filter(articles::feed_id.eq_any(feed_ids.clone()));
+        }
+        if let Some(article_ids) = &filter.article_ids {
+            query = query.filter(articles::id.eq_any(article_ids.clone()));
+        }
+        if let Some(category_id) = filter.category_id {
+            query = query.filter(articles::category_id.eq(category_id));
+        }
+        if let Some(search) = &filter.search {
+            let pattern = like_contains(search);
+            query = query.filter(
+                articles::title
+                    .ilike(pattern.clone())
+                    .escape('\\')
+                    .or(articles::description.ilike(pattern.clone()).escape('\\'))
+                    .or(articles::content.ilike(pattern).escape('\\')),
+            );
+        }
+        if let Some(before) = filter.published_before {
+            query = query.filter(articles::pub_date.le(before));
+        }
+        if let Some(after) = filter.published_after {
+            query = query.filter(articles::pub_date.ge(after));
+        }
+        if let Some(min_rate) = filter.min_rate {
+            query = query.filter(articles::rate.ge(min_rate));
+        }
+        if let Some(min_user_rating) = filter.min_user_rating {
+            query = query.filter(articles::user_rating_avg.ge(min_user_rating));
+        }
+        if let (Some(reader_id), true) = (filter.reader_id, filter.unread_only) {
+            query = query.filter(articles::id.ne_all(
+                article_reads::table
+                    .filter(article_reads::user_id.eq(reader_id))
+                    .select(article_reads::article_id),
+            ));
+        }
+
+        query
+    }
+
+    /// Recalcula a média e a contagem das notas e grava no artigo (chamado dentro da transação)
+    fn refresh_rating_summary(conn: &mut PgConnection, article_id: Uuid) -> Result<ArticleRatingSummary, DieselError> {
+        let ratings = article_ratings::table
+            .filter(article_ratings::article_id.eq(article_id))
+            .select(article_ratings::rating)
+            .load::<i32>(conn)?;
+        let summary = ArticleRatingSummary::from_ratings(&ratings);
+
+        diesel::update(articles::table.filter(articles::id.eq(article_id)))
+            .set((
+                articles::user_rating_avg.eq(summary.average),
+                articles::user_rating_count.eq(summary.count),
+            ))
+            .execute(conn)?;
+
+        Ok(summary)
+    }
+
+    /// Trava a linha do artigo para que avaliações simultâneas não gravem agregados desatualizados
+    fn lock_article(conn: &mut PgConnection, article_id: Uuid) -> Result<Uuid, DieselError> {
+        articles::table
+            .filter(articles::id.eq(article_id))
+            .select(articles::id)
+            .for_update()
+            .first::<Uuid>(conn)
+    }
+
+    /// `read_at` do leitor para cada artigo da página (ausente = não lido)
+    fn read_states(
+        conn: &mut PgConnection,
+        reader_id: Uuid,
+        article_ids: Vec<Uuid>,
+    ) -> Result<HashMap<Uuid, DateTime<Utc>>, ArticleRepositoryError> {
+        let rows = article_reads::table
+            .filter(article_reads::user_id.eq(reader_id))
+            .filter(article_reads::article_id.eq_any(article_ids))
+            .select((article_reads::article_id, article_reads::read_at))
+            .load::<(Uuid, DateTime<Utc>)>(conn)
+            .map_err(|e| {
+                ArticleRepositoryError::DatabaseError(format!("Failed to load read states: {}", e))
+            })?;
+
+        Ok(rows.into_iter().collect())
+    }
+
     fn model_to_entity(model: ArticleModel) -> Article {
         Article {
             id: model.id,
@@ -38,6 +159,31 @@ impl DieselArticleRepository {
             processing_columnist: model.processing_columnist,
             created_at: model.created_at,
             updated_at: model.updated_at,
+            user_rating_avg: model.user_rating_avg,
+            user_rating_count: model.user_rating_count,
+        }
+    }
+
+    fn entity_to_update_model(article: &Article) -> UpdateArticleModel {
+        UpdateArticleModel {
+            title: Some(article.title.clone()),
+            description: Some(article.description.clone()),
+            link: Some(article.link.clone()),
+            pub_date: Some(article.pub_date),
+            media: Some(article.media.clone()),
+            content: Some(article.content.clone()),
+            creator: Some(article.creator.clone()),
+            ai_summary: Some(article.ai_summary.clone()),
+            rate: Some(article.rate),
+            keywords: Some(article.keywords.clone()),
+            processing_ai_summary: Some(article.processing_ai_summary),
+            processing_rating: Some(article.processing_rating),
+            processing_keywords: Some(article.processing_keywords),
+            category_id: Some(article.category_id),
+            processing_categorizing: Some(article.processing_categorizing),
+            ai_columnist: Some(article.ai_columnist.clone()),
+            processing_columnist: Some(article.processing_columnist),
+            updated_at: chrono::Utc::now(),
         }
     }
 
@@ -81,11 +227,34 @@ impl ArticleRepository for DieselArticleRepository {
         let result = diesel::insert_into(articles)
             .values(&new_article)
             .get_result::<ArticleModel>(&mut conn)
+            .map_err(|e| map_write_error("create", e))?;
+
+        Ok(Self::model_to_entity(result))
+    }
+
+    async fn create_batch(&self, batch: Vec<Article>) -> Result<Vec<Article>, ArticleRepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| {
+            ArticleRepositoryError::DatabaseError(format!("Failed to get connection: {}", e))
+        })?;
+
+        // O índice único em `link` descarta tanto os links já cadastrados quanto os repetidos
+        // no próprio lote, inclusive com importações simultâneas
+        let new_articles: Vec<NewArticle> = batch.iter().map(Self::entity_to_new_model).collect();
+        if new_articles.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let inserted = diesel::insert_into(articles::table)
+            .values(&new_articles)
+            .on_conflict(articles::link)
+            .do_nothing()
+            .returning(ArticleModel::as_returning())
+            .get_results::<ArticleModel>(&mut conn)
             .map_err(|e| {
-                ArticleRepositoryError::DatabaseError(format!("Failed to create article: {}", e))
+                ArticleRepositoryError::DatabaseError(format!("Failed to create articles: {}", e))
             })?;
 
-        Ok(Self::model_to_entity(result))
+        Ok(inserted.into_iter().map(Self::model_to_entity).collect())
     }
 
     async fn find_by_id(&self, article_id: Uuid) -> Result<Option<Article>, ArticleRepositoryError> {
@@ -107,8 +276,15 @@ impl ArticleRepository for DieselArticleRepository {
     }
 
     async fn list(&self, page: i64, per_page: i64) -> Result<PaginatedArticles, ArticleRepositoryError> {
-        use crate::infrastructure::database::schema::articles::dsl::*;
+        self.list_filtered(&ArticleListFilter::default(), page, per_page).await
+    }
 
+    async fn list_filtered(
+        &self,
+        filter: &ArticleListFilter,
+        page: i64,
+        per_page: i64,
+    ) -> Result<PaginatedArticles, ArticleRepositoryError> {
         if page < 1 || per_page < 1 {
             return Err(ArticleRepositoryError::InvalidPagination);
         }
@@ -118,7 +294,7 @@ impl ArticleRepository for DieselArticleRepository {
         })?;
 
         // Conta total de artigos
-        let total = articles
+        let total = Self::filtered_query(filter)
             .count()
             .get_result::<i64>(&mut conn)
             .map_err(|e| {
@@ -129,9 +305,16 @@ impl ArticleRepository for DieselArticleRepository {
         let total_pages = (total as f64 / per_page as f64).ceil() as i64;
         let offset = (page - 1) * per_page;
 
-        // Busca artigos paginados, ordenados por pub_date descendente
-        let results = articles
-            .order(pub_date.desc())
+        // Busca artigos paginados; notas ausentes ficam por último e o empate é pela data
+        let ordered = match filter.sort {
+            ArticleSort::Recent => Self::filtered_query(filter).order(articles::pub_date.desc()),
+            ArticleSort::Rate => Self::filtered_query(filter)
+                .order((articles::rate.desc().nulls_last(), articles::pub_date.desc())),
+            ArticleSort::UserRating => Self::filtered_query(filter)
+                .order((articles::user_rating_avg.desc().nulls_last(), articles::pub_date.desc())),
+        };
+
+        let results = ordered
             .limit(per_page)
             .offset(offset)
             .load::<ArticleModel>(&mut conn)
@@ -139,9 +322,26 @@ impl ArticleRepository for DieselArticleRepository {
                 ArticleRepositoryError::DatabaseError(format!("Failed to list articles: {}", e))
             })?;
 
+        let read_states = match filter.reader_id {
+            Some(reader_id) => Some(Self::read_states(
+                &mut conn,
+                reader_id,
+                results.iter().map(|model| model.id).collect(),
+            )?),
+            None => None,
+        };
+
         let article_responses: Vec<ArticleResponse> = results
             .into_iter()
-            .map(|model| ArticleResponse::from(Self::model_to_entity(model)))
+            .map(|model| {
+                let user_state = read_states
+                    .as_ref()
+                    .map(|states| ArticleUserState::from_read_at(states.get(&model.id).copied()));
+                ArticleResponse {
+                    user_state,
+                    ..ArticleResponse::from(Self::model_to_entity(model))
+                }
+            })
             .collect();
 
         Ok(PaginatedArticles {
@@ -153,6 +353,37 @@ impl ArticleRepository for DieselArticleRepository {
         })
     }
 
+    async fn export_chunk(
+        &self,
+        filter: &ArticleListFilter,
+        after: Option<ArticleExportCursor>,
+        limit: i64,
+    ) -> Result<Vec<Article>, ArticleRepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| {
+            ArticleRepositoryError::DatabaseError(format!("Failed to get connection: {}", e))
+        })?;
+
+        // Paginação por chave: cada lote usa o índice de `pub_date` em vez de um OFFSET crescente
+        let mut query = Self::filtered_query(filter);
+        if let Some(cursor) = after {
+            query = query.filter(
+                articles::pub_date
+                    .lt(cursor.pub_date)
+                    .or(articles::pub_date.eq(cursor.pub_date).and(articles::id.lt(cursor.id))),
+            );
+        }
+
+        let results = query
+            .order((articles::pub_date.desc(), articles::id.desc()))
+            .limit(limit)
+            .load::<ArticleModel>(&mut conn)
+            .map_err(|e| {
+                ArticleRepositoryError::DatabaseError(format!("Failed to export articles: {}", e))
+            })?;
+
+        Ok(results.into_iter().map(Self::model_to_entity).collect())
+    }
+
     async fn update(&self, article: Article) -> Result<Article, ArticleRepositoryError> {
         use crate::infrastructure::database::schema::articles::dsl::*;
 
@@ -160,34 +391,12 @@ impl ArticleRepository for DieselArticleRepository {
             ArticleRepositoryError::DatabaseError(format!("Failed to get connection: {}", e))
         })?;
 
-        let update_model = UpdateArticleModel {
-            title: Some(article.title.clone()),
-            description: Some(article.description.clone()),
-            link: Some(article.link.clone()),
-            pub_date: Some(article.pub_date),
-            media: Some(article.media.clone()),
-            content: Some(article.content.clone()),
-            creator: Some(article.creator.clone()),
-            ai_summary: Some(article.ai_summary.clone()),
-            rate: Some(article.rate),
-            keywords: Some(article.keywords.clone()),
-            processing_ai_summary: Some(article.processing_ai_summary),
-            processing_rating: Some(article.processing_rating),
-            processing_keywords: Some(article.processing_keywords),
-            category_id: Some(article.category_id),
-            processing_categorizing: Some(article.processing_categorizing),
-            ai_columnist: Some(article.ai_columnist.clone()),
-            processing_columnist: Some(article.processing_columnist),
-            updated_at: chrono::Utc::now(),
-        };
+        let update_model = Self::entity_to_update_model(&article);
 
         let result = diesel::update(articles.filter(id.eq(article.id)))
             .set(&update_model)
             .get_result::<ArticleModel>(&mut conn)
-            .map_err(|e| match e {
-                diesel::result::Error::NotFound => ArticleRepositoryError::NotFound,
-                _ => ArticleRepositoryError::DatabaseError(format!("Failed to update article: {}", e)),
-            })?;
+            .map_err(|e| map_write_error("update", e))?;
 
         Ok(Self::model_to_entity(result))
     }
@@ -212,6 +421,306 @@ impl ArticleRepository for DieselArticleRepository {
         Ok(())
     }
 
+    async fn create_with_event(&self, article: Article) -> Result<Article, ArticleRepositoryError> {
+        let new_article = Self::entity_to_new_model(&article);
+        let mut conn = self.pool.get().map_err(|e| {
+            ArticleRepositoryError::DatabaseError(format!("Failed to get connection: {}", e))
+        })?;
+
+        conn.transaction::<_, DieselError, _>(|conn| {
+            let created = Self::model_to_entity(
+                diesel::insert_into(articles::table)
+                    .values(&new_article)
+                    .get_result::<ArticleModel>(conn)?,
+            );
+            insert_event(conn, &ArticleEvent::new(WebhookEventType::ArticleCreated, &created))?;
+            Ok(created)
+        })
+        .map_err(|e| map_write_error("create", e))
+    }
+
+    async fn update_with_event(&self, article: Article, event_type: WebhookEventType) -> Result<Article, ArticleRepositoryError> {
+        let update_model = Self::entity_to_update_model(&article);
+        let mut conn = self.pool.get().map_err(|e| {
+            ArticleRepositoryError::DatabaseError(format!("Failed to get connection: {}", e))
+        })?;
+
+        conn.transaction::<_, DieselError, _>(|conn| {
+            let updated = Self::model_to_entity(
+                diesel::update(articles::table.filter(articles::id.eq(article.id)))
+                    .set(&update_model)
+                    .get_result::<ArticleModel>(conn)?,
+            );
+            insert_event(conn, &ArticleEvent::new(event_type, &updated))?;
+            Ok(updated)
+        })
+        .map_err(|e| map_write_error("update", e))
+    }
+
+    async fn delete_with_event(&self, article_id: Uuid) -> Result<(), ArticleRepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| {
+            ArticleRepositoryError::DatabaseError(format!("Failed to get connection: {}", e))
+        })?;
+
+        conn.transaction::<_, DieselError, _>(|conn| {
+            let deleted = Self::model_to_entity(
+                diesel::delete(articles::table.filter(articles::id.eq(article_id)))
+                    .get_result::<ArticleModel>(conn)?,
+            );
+            insert_event(conn, &ArticleEvent::new(WebhookEventType::ArticleDeleted, &deleted))
+        })
+        .map_err(|e| match e {
+            DieselError::NotFound => ArticleRepositoryError::NotFound,
+            _ => ArticleRepositoryError::DatabaseError(format!("Failed to delete article: {}", e)),
+        })
+    }
+
+    async fn mark_read(&self, user_id: Uuid, article_id: Uuid, read_at: DateTime<Utc>) -> Result<(), ArticleRepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| {
+            ArticleRepositoryError::DatabaseError(format!("Failed to get connection: {}", e))
+        })?;
+
+        diesel::insert_into(article_reads::table)
+            .values((
+                article_reads::user_id.eq(user_id),
+                article_reads::article_id.eq(article_id),
+                article_reads::read_at.eq(read_at),
+            ))
+            .on_conflict_do_nothing()
+            .execute(&mut conn)
+            .map_err(|e| match e {
+                DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _) => {
+                    ArticleRepositoryError::NotFound
+                }
+                _ => ArticleRepositoryError::DatabaseError(format!("Failed to mark article as read: {}", e)),
+            })?;
+
+        Ok(())
+    }
+
+    async fn mark_unread(&self, user_id: Uuid, article_id: Uuid) -> Result<(), ArticleRepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| {
+            ArticleRepositoryError::DatabaseError(format!("Failed to get connection: {}", e))
+        })?;
+
+        let exists = diesel::select(diesel::dsl::exists(articles::table.filter(articles::id.eq(article_id))))
+            .get_result::<bool>(&mut conn)
+            .map_err(|e| {
+                ArticleRepositoryError::DatabaseError(format!("Failed to find article: {}", e))
+            })?;
+
+        if !exists {
+            return Err(ArticleRepositoryError::NotFound);
+        }
+
+        diesel::delete(
+            article_reads::table
+                .filter(article_reads::user_id.eq(user_id))
+                .filter(article_reads::article_id.eq(article_id)),
+        )
+        .execute(&mut conn)
+        .map_err(|e| {
+            ArticleRepositoryError::DatabaseError(format!("Failed to mark article as unread: {}", e))
+        })?;
+
+        Ok(())
+    }
+
+    async fn mark_read_matching(
+        &self,
+        user_id: Uuid,
+        filter: &ArticleListFilter,
+        read_at: DateTime<Utc>,
+    ) -> Result<u64, ArticleRepositoryError> {
+        use diesel::sql_types::{Timestamptz, Uuid as SqlUuid};
+
+        let mut conn = self.pool.get().map_err(|e| {
+            ArticleRepositoryError::DatabaseError(format!("Failed to get connection: {}", e))
+        })?;
+
+        // INSERT ... SELECT: os artigos não passam pela aplicação, mesmo em feeds grandes
+        let matching = Self::filtered_query(filter).select((
+            user_id.into_sql::<SqlUuid>(),
+            articles::id,
+            read_at.into_sql::<Timestamptz>(),
+        ));
+
+        let marked = diesel::insert_into(article_reads::table)
+            .values(matching)
+            .into_columns((article_reads::user_id, article_reads::article_id, article_reads::read_at))
+            .on_conflict_do_nothing()
+            .execute(&mut conn)
+            .map_err(|e| {
+                ArticleRepositoryError::DatabaseError(format!("Failed to mark articles as read: {}", e))
+            })?;
+
+        Ok(marked as u64)
+    }
+
+    async fn unread_counts(&self, user_id: Uuid, feed_ids: &[Uuid]) -> Result<UnreadCounts, ArticleRepositoryError> {
+        use diesel::dsl::count_star;
+
+        let mut conn = self.pool.get().map_err(|e| {
+            ArticleRepositoryError::DatabaseError(format!("Failed to get connection: {}", e))
+        })?;
+
+        let read_by_user = || {
+            article_reads::table
+                .filter(article_reads::user_id.eq(user_id))
+                .select(article_reads::article_id)
+        };
+
+        let feeds = articles::table
+            .filter(articles::feed_id.eq_any(feed_ids))
+            .filter(articles::id.ne_all(read_by_user()))
+            .group_by(articles::feed_id)
+            .select((articles::feed_id, count_star()))
+            .order(articles::feed_id)
+            .load::<(Uuid, i64)>(&mut conn)
+            .map_err(|e| {
+                ArticleRepositoryError::DatabaseError(format!("Failed to count unread articles: {}", e))
+            })?;
+
+        let categories = articles::table
+            .filter(articles::feed_id.eq_any(feed_ids))
+            .filter(articles::id.ne_all(read_by_user()))
+            .group_by(articles::category_id)
+            .select((articles::category_id, count_star()))
+            .order(articles::category_id)
+            .load::<(Option<Uuid>, i64)>(&mut conn)
+            .map_err(|e| {
+                ArticleRepositoryError::DatabaseError(format!("Failed to count unread articles: {}", e))
+            })?;
+
+        Ok(UnreadCounts {
+            total: feeds.iter().map(|(_, unread)| unread).sum(),
+            feeds: feeds
+                .into_iter()
+                .map(|(feed_id, unread)| FeedUnreadCount { feed_id, unread })
+                .collect(),
+            categories: categories
+                .into_iter()
+                .map(|(category_id, unread)| CategoryUnreadCount { category_id, unread })
+                .collect(),
+        })
+    }
+
+    async fn rate(
+        &self,
+        user_id: Uuid,
+        article_id: Uuid,
+        rating: i32,
+        rated_at: DateTime<Utc>,
+    ) -> Result<ArticleRatingSummary, ArticleRepositoryError> {
+        use diesel::upsert::excluded;
+
+        let mut conn = self.pool.get().map_err(|e| {
+            ArticleRepositoryError::DatabaseError(format!("Failed to get connection: {}", e))
+        })?;
+
+        conn.transaction::<_, DieselError, _>(|conn| {
+            Self::lock_article(conn, article_id)?;
+
+            diesel::insert_into(article_ratings::table)
+                .values((
+                    article_ratings::user_id.eq(user_id),
+                    article_ratings::article_id.eq(article_id),
+                    article_ratings::rating.eq(rating),
+                    article_ratings::created_at.eq(rated_at),
+                    article_ratings::updated_at.eq(rated_at),
+                ))
+                .on_conflict((article_ratings::user_id, article_ratings::article_id))
+                .do_update()
+                .set((
+                    article_ratings::rating.eq(excluded(article_ratings::rating)),
+                    article_ratings::updated_at.eq(excluded(article_ratings::updated_at)),
+                ))
+                .execute(conn)?;
+
+            Self::refresh_rating_summary(conn, article_id)
+        })
+        .map_err(|e| match e {
+            DieselError::NotFound => ArticleRepositoryError::NotFound,
+            _ => ArticleRepositoryError::DatabaseError(format!("Failed to rate article: {}", e)),
+        })
+    }
+
+    async fn remove_rating(&self, user_id: Uuid, article_id: Uuid) -> Result<ArticleRatingSummary, ArticleRepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| {
+            ArticleRepositoryError::DatabaseError(format!("Failed to get connection: {}", e))
+        })?;
+
+        conn.transaction::<_, DieselError, _>(|conn| {
+            Self::lock_article(conn, article_id)?;
+
+            diesel::delete(
+                article_ratings::table
+                    .filter(article_ratings::user_id.eq(user_id))
+                    .filter(article_ratings::article_id.eq(article_id)),
+            )
+            .execute(conn)?;
+
+            Self::refresh_rating_summary(conn, article_id)
+        })
+        .map_err(|e| match e {
+            DieselError::NotFound => ArticleRepositoryError::NotFound,
+            _ => ArticleRepositoryError::DatabaseError(format!("Failed to remove rating: {}", e)),
+        })
+    }
+
+    async fn category_engagement(&self, user_id: Uuid) -> Result<Vec<CategoryEngagement>, ArticleRepositoryError> {
+        use diesel::dsl::count_star;
+
+        let mut conn = self.pool.get().map_err(|e| {
+            ArticleRepositoryError::DatabaseError(format!("Failed to get connection: {}", e))
+        })?;
+
+        let reads = article_reads::table
+            .inner_join(articles::table)
+            .filter(article_reads::user_id.eq(user_id))
+            .filter(articles::category_id.is_not_null())
+            .group_by(articles::category_id)
+            .select((articles::category_id, count_star()))
+            .load::<(Option<Uuid>, i64)>(&mut conn)
+            .map_err(|e| {
+                ArticleRepositoryError::DatabaseError(format!("Failed to load category engagement: {}", e))
+            })?;
+
+        let ratings = article_ratings::table
+            .inner_join(articles::table)
+            .filter(article_ratings::user_id.eq(user_id))
+            .filter(articles::category_id.is_not_null())
+            .group_by(articles::category_id)
+            .select((articles::category_id, count_star(), diesel::dsl::sum(article_ratings::rating)))
+            .load::<(Option<Uuid>, i64, Option<i64>)>(&mut conn)
+            .map_err(|e| {
+                ArticleRepositoryError::DatabaseError(format!("Failed to load category engagement: {}", e))
+            })?;
+
+        let mut by_category: HashMap<Uuid, CategoryEngagement> = HashMap::new();
+        for (category_id, count) in reads {
+            if let Some(category_id) = category_id {
+                by_category
+                    .entry(category_id)
+                    .or_insert_with(|| CategoryEngagement::new(category_id))
+                    .reads = count;
+            }
+        }
+        for (category_id, count, total) in ratings {
+            if let Some(category_id) = category_id {
+                let engagement = by_category
+                    .entry(category_id)
+                    .or_insert_with(|| CategoryEngagement::new(category_id));
+                engagement.rating_count = count;
+                engagement.rating_avg = total.map(|total| total as f64 / count as f64);
+            }
+        }
+
+        let mut engagement: Vec<CategoryEngagement> = by_category.into_values().collect();
+        engagement.sort_by_key(|item| item.category_id);
+        Ok(engagement)
+    }
+
     async fn count(&self) -> Result<i64, ArticleRepositoryError> {
         use crate::infrastructure::database::schema::articles::dsl::*;
 
@@ -228,4 +737,4 @@ impl ArticleRepository for DieselArticleRepository {
 
         Ok(total)
     }
-}
+}
\ No newline at end of file
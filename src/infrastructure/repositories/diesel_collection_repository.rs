@@ -0,0 +1,194 @@
+use crate::domain::entities::collection::Collection;
+use crate::domain::repositories::collection_repository::{CollectionRepository, CollectionRepositoryError};
+use crate::infrastructure::database::schema::collections;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use uuid::Uuid;
+
+// Modelo Diesel para leitura e escrita
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = collections)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct CollectionModel {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    share_token: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<CollectionModel> for Collection {
+    fn from(model: CollectionModel) -> Self {
+        Collection {
+            id: model.id,
+            user_id: model.user_id,
+            name: model.name,
+            share_token: model.share_token,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl From<Collection> for CollectionModel {
+    fn from(collection: Collection) -> Self {
+        CollectionModel {
+            id: collection.id,
+            user_id: collection.user_id,
+            name: collection.name,
+            share_token: collection.share_token,
+            created_at: collection.created_at,
+            updated_at: collection.updated_at,
+        }
+    }
+}
+
+fn map_write_error(e: DieselError) -> CollectionRepositoryError {
+    match e {
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => CollectionRepositoryError::NameTaken,
+        _ => CollectionRepositoryError::DatabaseError(e.to_string()),
+    }
+}
+
+pub struct DieselCollectionRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselCollectionRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CollectionRepository for DieselCollectionRepository {
+    async fn create(&self, collection: Collection) -> Result<Collection, CollectionRepositoryError> {
+        let mut conn = self.pool.get()
+            .map_err(|e| CollectionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = CollectionModel::from(collection);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::insert_into(collections::table)
+                .values(&model)
+                .returning(CollectionModel::as_returning())
+                .get_result::<CollectionModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| CollectionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(map_write_error)?;
+
+        Ok(result.into())
+    }
+
+    async fn find_by_id(&self, owner_id: Uuid, collection_id: Uuid) -> Result<Option<Collection>, CollectionRepositoryError> {
+        use crate::infrastructure::database::schema::collections::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| CollectionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            collections
+                .filter(id.eq(collection_id))
+                .filter(user_id.eq(owner_id))
+                .select(CollectionModel::as_select())
+                .first::<CollectionModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| CollectionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| CollectionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn find_by_share_token(&self, token: &str) -> Result<Option<Collection>, CollectionRepositoryError> {
+        use crate::infrastructure::database::schema::collections::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| CollectionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let token = token.to_string();
+
+        let result = tokio::task::spawn_blocking(move || {
+            collections
+                .filter(share_token.eq(token))
+                .select(CollectionModel::as_select())
+                .first::<CollectionModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| CollectionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| CollectionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn list_by_user(&self, owner_id: Uuid) -> Result<Vec<Collection>, CollectionRepositoryError> {
+        use crate::infrastructure::database::schema::collections::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| CollectionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            collections
+                .filter(user_id.eq(owner_id))
+                .order(name.asc())
+                .select(CollectionModel::as_select())
+                .load::<CollectionModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| CollectionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| CollectionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn update(&self, collection: Collection) -> Result<Collection, CollectionRepositoryError> {
+        use crate::infrastructure::database::schema::collections::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| CollectionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::update(collections.filter(id.eq(collection.id)))
+                .set((
+                    name.eq(collection.name),
+                    share_token.eq(collection.share_token),
+                    updated_at.eq(collection.updated_at),
+                ))
+                .returning(CollectionModel::as_returning())
+                .get_result::<CollectionModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| CollectionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(map_write_error)?;
+
+        Ok(result.into())
+    }
+
+    async fn delete(&self, owner_id: Uuid, collection_id: Uuid) -> Result<bool, CollectionRepositoryError> {
+        use crate::infrastructure::database::schema::collections::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| CollectionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let deleted = tokio::task::spawn_blocking(move || {
+            diesel::delete(
+                collections
+                    .filter(id.eq(collection_id))
+                    .filter(user_id.eq(owner_id)),
+            )
+            .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| CollectionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| CollectionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deleted > 0)
+    }
+}
@@ -0,0 +1,251 @@
+use crate::domain::entities::digest::{DigestFrequency, DigestSettings, SentDigest};
+use crate::domain::repositories::digest_repository::{DigestRepository, DigestRepositoryError};
+use crate::infrastructure::database::schema::{digest_settings, sent_digests};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use uuid::Uuid;
+
+// Modelo Diesel para leitura e escrita da agenda
+#[derive(Queryable, Selectable, Insertable, AsChangeset, Debug, Clone)]
+#[diesel(table_name = digest_settings)]
+#[diesel(primary_key(user_id))]
+#[diesel(treat_none_as_null = true)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct DigestSettingsModel {
+    user_id: Uuid,
+    frequency: String,
+    hour: i32,
+    weekday: Option<i32>,
+    timezone: String,
+    max_articles: i32,
+    min_rate: Option<i32>,
+    enabled: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+// Modelo Diesel para leitura e escrita dos resumos enviados
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = sent_digests)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct SentDigestModel {
+    id: Uuid,
+    user_id: Uuid,
+    period_start: DateTime<Utc>,
+    period_end: DateTime<Utc>,
+    article_count: i32,
+    sent_at: DateTime<Utc>,
+}
+
+impl From<DigestSettingsModel> for DigestSettings {
+    fn from(model: DigestSettingsModel) -> Self {
+        DigestSettings {
+            user_id: model.user_id,
+            frequency: DigestFrequency::parse(&model.frequency).unwrap_or(DigestFrequency::Daily),
+            hour: model.hour,
+            weekday: model.weekday,
+            timezone: model.timezone,
+            max_articles: model.max_articles,
+            min_rate: model.min_rate,
+            enabled: model.enabled,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl From<DigestSettings> for DigestSettingsModel {
+    fn from(settings: DigestSettings) -> Self {
+        DigestSettingsModel {
+            user_id: settings.user_id,
+            frequency: settings.frequency.as_str().to_string(),
+            hour: settings.hour,
+            weekday: settings.weekday,
+            timezone: settings.timezone,
+            max_articles: settings.max_articles,
+            min_rate: settings.min_rate,
+            enabled: settings.enabled,
+            created_at: settings.created_at,
+            updated_at: settings.updated_at,
+        }
+    }
+}
+
+impl From<SentDigestModel> for SentDigest {
+    fn from(model: SentDigestModel) -> Self {
+        SentDigest {
+            id: model.id,
+            user_id: model.user_id,
+            period_start: model.period_start,
+            period_end: model.period_end,
+            article_count: model.article_count,
+            sent_at: model.sent_at,
+        }
+    }
+}
+
+impl From<SentDigest> for SentDigestModel {
+    fn from(sent: SentDigest) -> Self {
+        SentDigestModel {
+            id: sent.id,
+            user_id: sent.user_id,
+            period_start: sent.period_start,
+            period_end: sent.period_end,
+            article_count: sent.article_count,
+            sent_at: sent.sent_at,
+        }
+    }
+}
+
+pub struct DieselDigestRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselDigestRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DigestRepository for DieselDigestRepository {
+    async fn find_settings(&self, owner_id: Uuid) -> Result<Option<DigestSettings>, DigestRepositoryError> {
+        use crate::infrastructure::database::schema::digest_settings::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            digest_settings
+                .filter(user_id.eq(owner_id))
+                .select(DigestSettingsModel::as_select())
+                .first::<DigestSettingsModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn save_settings(&self, settings: DigestSettings) -> Result<DigestSettings, DigestRepositoryError> {
+        use crate::infrastructure::database::schema::digest_settings::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = DigestSettingsModel::from(settings);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::insert_into(digest_settings)
+                .values(&model)
+                .on_conflict(user_id)
+                .do_update()
+                .set(&model)
+                .returning(DigestSettingsModel::as_returning())
+                .get_result::<DigestSettingsModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.into())
+    }
+
+    async fn delete_settings(&self, owner_id: Uuid) -> Result<bool, DigestRepositoryError> {
+        use crate::infrastructure::database::schema::digest_settings::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?;
+
+        let deleted = tokio::task::spawn_blocking(move || {
+            diesel::delete(digest_settings.filter(user_id.eq(owner_id))).execute(&mut conn)
+        })
+        .await
+        .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deleted > 0)
+    }
+
+    async fn list_enabled_settings(&self) -> Result<Vec<DigestSettings>, DigestRepositoryError> {
+        use crate::infrastructure::database::schema::digest_settings::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            digest_settings
+                .filter(enabled.eq(true))
+                .select(DigestSettingsModel::as_select())
+                .load::<DigestSettingsModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn record_sent(&self, sent: SentDigest) -> Result<bool, DigestRepositoryError> {
+        use crate::infrastructure::database::schema::sent_digests::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = SentDigestModel::from(sent);
+
+        let inserted = tokio::task::spawn_blocking(move || {
+            diesel::insert_into(sent_digests)
+                .values(&model)
+                .on_conflict((user_id, period_end))
+                .do_nothing()
+                .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(inserted > 0)
+    }
+
+    async fn remove_sent(&self, sent_id: Uuid) -> Result<(), DigestRepositoryError> {
+        use crate::infrastructure::database::schema::sent_digests::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?;
+
+        tokio::task::spawn_blocking(move || {
+            diesel::delete(sent_digests.filter(id.eq(sent_id))).execute(&mut conn)
+        })
+        .await
+        .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn list_sent(&self, owner_id: Uuid, limit: i64) -> Result<Vec<SentDigest>, DigestRepositoryError> {
+        use crate::infrastructure::database::schema::sent_digests::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            sent_digests
+                .filter(user_id.eq(owner_id))
+                .order(period_end.desc())
+                .limit(limit)
+                .select(SentDigestModel::as_select())
+                .load::<SentDigestModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| DigestRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+}
@@ -0,0 +1,123 @@
+use crate::domain::entities::email_change_request::EmailChangeRequest;
+use crate::domain::repositories::email_change_request_repository::{
+    EmailChangeRequestRepository, EmailChangeRequestRepositoryError,
+};
+use crate::infrastructure::database::schema::email_change_requests;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use uuid::Uuid;
+
+// Modelo Diesel para leitura e escrita
+#[derive(Queryable, Selectable, Insertable, AsChangeset, Debug, Clone)]
+#[diesel(table_name = email_change_requests)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct EmailChangeRequestModel {
+    id: Uuid,
+    user_id: Uuid,
+    new_email: String,
+    token_hash: String,
+    expires_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<EmailChangeRequestModel> for EmailChangeRequest {
+    fn from(model: EmailChangeRequestModel) -> Self {
+        EmailChangeRequest {
+            id: model.id,
+            user_id: model.user_id,
+            new_email: model.new_email,
+            token_hash: model.token_hash,
+            expires_at: model.expires_at,
+            created_at: model.created_at,
+        }
+    }
+}
+
+impl From<EmailChangeRequest> for EmailChangeRequestModel {
+    fn from(request: EmailChangeRequest) -> Self {
+        EmailChangeRequestModel {
+            id: request.id,
+            user_id: request.user_id,
+            new_email: request.new_email,
+            token_hash: request.token_hash,
+            expires_at: request.expires_at,
+            created_at: request.created_at,
+        }
+    }
+}
+
+pub struct DieselEmailChangeRequestRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselEmailChangeRequestRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EmailChangeRequestRepository for DieselEmailChangeRequestRepository {
+    async fn replace(&self, request: EmailChangeRequest) -> Result<EmailChangeRequest, EmailChangeRequestRepositoryError> {
+        use crate::infrastructure::database::schema::email_change_requests::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| EmailChangeRequestRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = EmailChangeRequestModel::from(request);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::insert_into(email_change_requests)
+                .values(&model)
+                .on_conflict(user_id)
+                .do_update()
+                .set(&model)
+                .returning(EmailChangeRequestModel::as_returning())
+                .get_result::<EmailChangeRequestModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| EmailChangeRequestRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| EmailChangeRequestRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.into())
+    }
+
+    async fn find_by_user(&self, owner_id: Uuid) -> Result<Option<EmailChangeRequest>, EmailChangeRequestRepositoryError> {
+        use crate::infrastructure::database::schema::email_change_requests::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| EmailChangeRequestRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            email_change_requests
+                .filter(user_id.eq(owner_id))
+                .select(EmailChangeRequestModel::as_select())
+                .first::<EmailChangeRequestModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| EmailChangeRequestRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| EmailChangeRequestRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn delete_by_user(&self, owner_id: Uuid) -> Result<(), EmailChangeRequestRepositoryError> {
+        use crate::infrastructure::database::schema::email_change_requests::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| EmailChangeRequestRepositoryError::DatabaseError(e.to_string()))?;
+
+        tokio::task::spawn_blocking(move || {
+            diesel::delete(email_change_requests.filter(user_id.eq(owner_id)))
+                .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| EmailChangeRequestRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| EmailChangeRequestRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use crate::domain::repositories::feed_repository::{FeedRepository, FeedRepositoryError};
+use crate::infrastructure::database::schema::feeds;
+use crate::infrastructure::database::DbPool;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+pub struct DieselFeedRepository {
+    pool: DbPool,
+}
+
+impl DieselFeedRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl FeedRepository for DieselFeedRepository {
+    async fn find_id_by_url(&self, url: &str) -> Result<Option<Uuid>, FeedRepositoryError> {
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| FeedRepositoryError::DatabaseError(e.to_string()))?;
+        let url = url.to_string();
+
+        tokio::task::spawn_blocking(move || {
+            // O endereço do feed é único; o do site só é usado se nenhum feed tiver esse `feed_url`
+            feeds::table
+                .filter(feeds::feed_url.eq(&url).or(feeds::link.eq(&url)))
+                .order((feeds::feed_url.eq(&url).desc(), feeds::created_at.asc()))
+                .select(feeds::id)
+                .first::<Uuid>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| FeedRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| FeedRepositoryError::DatabaseError(e.to_string()))
+    }
+
+    async fn exists(&self, id: Uuid) -> Result<bool, FeedRepositoryError> {
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| FeedRepositoryError::DatabaseError(e.to_string()))?;
+
+        tokio::task::spawn_blocking(move || {
+            diesel::select(diesel::dsl::exists(feeds::table.filter(feeds::id.eq(id)))).get_result::<bool>(&mut conn)
+        })
+        .await
+        .map_err(|e| FeedRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| FeedRepositoryError::DatabaseError(e.to_string()))
+    }
+}
@@ -0,0 +1,217 @@
+use crate::domain::entities::identity::{OidcAuthRequest, UserIdentity};
+use crate::domain::repositories::identity_repository::{IdentityRepository, IdentityRepositoryError};
+use crate::infrastructure::database::schema::{oidc_auth_requests, user_identities};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use uuid::Uuid;
+
+// Modelos Diesel para leitura e escrita
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = user_identities)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct UserIdentityModel {
+    id: Uuid,
+    user_id: Uuid,
+    provider: String,
+    subject: String,
+    email: Option<String>,
+    created_at: DateTime<Utc>,
+    last_login_at: DateTime<Utc>,
+}
+
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = oidc_auth_requests)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct OidcAuthRequestModel {
+    state_hash: String,
+    provider: String,
+    code_verifier: String,
+    nonce: String,
+    expires_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<UserIdentityModel> for UserIdentity {
+    fn from(model: UserIdentityModel) -> Self {
+        UserIdentity {
+            id: model.id,
+            user_id: model.user_id,
+            provider: model.provider,
+            subject: model.subject,
+            email: model.email,
+            created_at: model.created_at,
+            last_login_at: model.last_login_at,
+        }
+    }
+}
+
+impl From<UserIdentity> for UserIdentityModel {
+    fn from(identity: UserIdentity) -> Self {
+        UserIdentityModel {
+            id: identity.id,
+            user_id: identity.user_id,
+            provider: identity.provider,
+            subject: identity.subject,
+            email: identity.email,
+            created_at: identity.created_at,
+            last_login_at: identity.last_login_at,
+        }
+    }
+}
+
+impl From<OidcAuthRequestModel> for OidcAuthRequest {
+    fn from(model: OidcAuthRequestModel) -> Self {
+        OidcAuthRequest {
+            state_hash: model.state_hash,
+            provider: model.provider,
+            code_verifier: model.code_verifier,
+            nonce: model.nonce,
+            expires_at: model.expires_at,
+            created_at: model.created_at,
+        }
+    }
+}
+
+impl From<OidcAuthRequest> for OidcAuthRequestModel {
+    fn from(request: OidcAuthRequest) -> Self {
+        OidcAuthRequestModel {
+            state_hash: request.state_hash,
+            provider: request.provider,
+            code_verifier: request.code_verifier,
+            nonce: request.nonce,
+            expires_at: request.expires_at,
+            created_at: request.created_at,
+        }
+    }
+}
+
+pub struct DieselIdentityRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselIdentityRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IdentityRepository for DieselIdentityRepository {
+    async fn find_identity(&self, identity_provider: &str, identity_subject: &str) -> Result<Option<UserIdentity>, IdentityRepositoryError> {
+        use crate::infrastructure::database::schema::user_identities::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| IdentityRepositoryError::DatabaseError(e.to_string()))?;
+
+        let identity_provider = identity_provider.to_string();
+        let identity_subject = identity_subject.to_string();
+
+        let result = tokio::task::spawn_blocking(move || {
+            user_identities
+                .filter(provider.eq(identity_provider))
+                .filter(subject.eq(identity_subject))
+                .select(UserIdentityModel::as_select())
+                .first::<UserIdentityModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| IdentityRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| IdentityRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn create_identity(&self, identity: UserIdentity) -> Result<UserIdentity, IdentityRepositoryError> {
+        let mut conn = self.pool.get()
+            .map_err(|e| IdentityRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = UserIdentityModel::from(identity);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::insert_into(user_identities::table)
+                .values(&model)
+                .returning(UserIdentityModel::as_returning())
+                .get_result::<UserIdentityModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| IdentityRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e: diesel::result::Error| match e {
+            diesel::result::Error::DatabaseError(
+                diesel::result::DatabaseErrorKind::UniqueViolation,
+                _,
+            ) => IdentityRepositoryError::IdentityAlreadyLinked,
+            _ => IdentityRepositoryError::DatabaseError(e.to_string()),
+        })?;
+
+        Ok(result.into())
+    }
+
+    async fn touch_identity(&self, identity_id: Uuid, at: DateTime<Utc>) -> Result<(), IdentityRepositoryError> {
+        use crate::infrastructure::database::schema::user_identities::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| IdentityRepositoryError::DatabaseError(e.to_string()))?;
+
+        tokio::task::spawn_blocking(move || {
+            diesel::update(user_identities.filter(id.eq(identity_id)))
+                .set(last_login_at.eq(at))
+                .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| IdentityRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| IdentityRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn save_auth_request(&self, request: OidcAuthRequest) -> Result<(), IdentityRepositoryError> {
+        use crate::infrastructure::database::schema::oidc_auth_requests::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| IdentityRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = OidcAuthRequestModel::from(request);
+
+        tokio::task::spawn_blocking(move || {
+            conn.transaction::<_, diesel::result::Error, _>(|conn| {
+                // Descarta logins abandonados antes de registrar o novo
+                diesel::delete(oidc_auth_requests.filter(expires_at.le(Utc::now())))
+                    .execute(conn)?;
+
+                diesel::insert_into(oidc_auth_requests)
+                    .values(&model)
+                    .execute(conn)?;
+
+                Ok(())
+            })
+        })
+        .await
+        .map_err(|e| IdentityRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| IdentityRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn take_auth_request(&self, request_state_hash: &str) -> Result<Option<OidcAuthRequest>, IdentityRepositoryError> {
+        use crate::infrastructure::database::schema::oidc_auth_requests::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| IdentityRepositoryError::DatabaseError(e.to_string()))?;
+
+        let request_state_hash = request_state_hash.to_string();
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::delete(oidc_auth_requests.filter(state_hash.eq(request_state_hash)))
+                .returning(OidcAuthRequestModel::as_returning())
+                .get_result::<OidcAuthRequestModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| IdentityRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| IdentityRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+}
@@ -0,0 +1,189 @@
+use crate::domain::entities::login_throttle::{LockoutPolicy, LoginThrottle, ThrottleScope};
+use crate::domain::repositories::login_throttle_repository::{
+    LoginThrottleRepository, LoginThrottleRepositoryError,
+};
+use crate::infrastructure::database::schema::login_throttles;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::sql_types::{Text, Timestamptz};
+
+// Modelo Diesel para leitura e escrita
+#[derive(Queryable, QueryableByName, Selectable, Debug, Clone)]
+#[diesel(table_name = login_throttles)]
+#[diesel(primary_key(scope, subject))]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct LoginThrottleModel {
+    scope: String,
+    subject: String,
+    failed_attempts: i32,
+    lockout_count: i32,
+    locked_until: Option<DateTime<Utc>>,
+    last_failed_at: Option<DateTime<Utc>>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<LoginThrottleModel> for LoginThrottle {
+    fn from(model: LoginThrottleModel) -> Self {
+        LoginThrottle {
+            scope: ThrottleScope::parse(&model.scope).unwrap_or(ThrottleScope::Account),
+            subject: model.subject,
+            failed_attempts: model.failed_attempts,
+            lockout_count: model.lockout_count,
+            locked_until: model.locked_until,
+            last_failed_at: model.last_failed_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+pub struct DieselLoginThrottleRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselLoginThrottleRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LoginThrottleRepository for DieselLoginThrottleRepository {
+    async fn find(
+        &self,
+        throttle_scope: ThrottleScope,
+        throttle_subject: &str,
+    ) -> Result<Option<LoginThrottle>, LoginThrottleRepositoryError> {
+        use crate::infrastructure::database::schema::login_throttles::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| LoginThrottleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let throttle_subject = throttle_subject.to_string();
+
+        let result = tokio::task::spawn_blocking(move || {
+            login_throttles
+                .filter(scope.eq(throttle_scope.as_str()))
+                .filter(subject.eq(throttle_subject))
+                .select(LoginThrottleModel::as_select())
+                .first::<LoginThrottleModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| LoginThrottleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| LoginThrottleRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn record_failure(
+        &self,
+        throttle_scope: ThrottleScope,
+        throttle_subject: &str,
+        policy: &LockoutPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<LoginThrottle, LoginThrottleRepositoryError> {
+        let mut conn = self.pool.get()
+            .map_err(|e| LoginThrottleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let throttle_subject = throttle_subject.to_string();
+        let window_start = now - policy.attempt_window;
+        let escalation_start = now - policy.max_lockout;
+
+        // O incremento acontece no próprio upsert, então falhas simultâneas não se sobrescrevem;
+        // as regras da janela e da escalada são as mesmas de `LoginThrottle::record_failure`
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::sql_query(
+                "INSERT INTO login_throttles AS t \
+                     (scope, subject, failed_attempts, lockout_count, last_failed_at, updated_at) \
+                 VALUES ($1, $2, 1, 0, $3, $3) \
+                 ON CONFLICT (scope, subject) DO UPDATE SET \
+                     failed_attempts = CASE WHEN t.last_failed_at < $4 THEN 1 \
+                                            ELSE t.failed_attempts + 1 END, \
+                     lockout_count = CASE WHEN GREATEST(t.last_failed_at, t.locked_until) < $5 THEN 0 \
+                                          ELSE t.lockout_count END, \
+                     last_failed_at = $3, \
+                     updated_at = $3 \
+                 RETURNING t.*",
+            )
+            .bind::<Text, _>(throttle_scope.as_str())
+            .bind::<Text, _>(throttle_subject)
+            .bind::<Timestamptz, _>(now)
+            .bind::<Timestamptz, _>(window_start)
+            .bind::<Timestamptz, _>(escalation_start)
+            .get_result::<LoginThrottleModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| LoginThrottleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| LoginThrottleRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.into())
+    }
+
+    async fn lock(
+        &self,
+        throttle_scope: ThrottleScope,
+        throttle_subject: &str,
+        threshold: i32,
+        until: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<Option<LoginThrottle>, LoginThrottleRepositoryError> {
+        use crate::infrastructure::database::schema::login_throttles::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| LoginThrottleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let throttle_subject = throttle_subject.to_string();
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::update(
+                login_throttles
+                    .filter(scope.eq(throttle_scope.as_str()))
+                    .filter(subject.eq(throttle_subject))
+                    .filter(failed_attempts.ge(threshold)),
+            )
+            .set((
+                locked_until.eq(Some(until)),
+                lockout_count.eq(lockout_count + 1),
+                failed_attempts.eq(0),
+                updated_at.eq(now),
+            ))
+            .returning(LoginThrottleModel::as_returning())
+            .get_result::<LoginThrottleModel>(&mut conn)
+            .optional()
+        })
+        .await
+        .map_err(|e| LoginThrottleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| LoginThrottleRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn delete(
+        &self,
+        throttle_scope: ThrottleScope,
+        throttle_subject: &str,
+    ) -> Result<(), LoginThrottleRepositoryError> {
+        use crate::infrastructure::database::schema::login_throttles::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| LoginThrottleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let throttle_subject = throttle_subject.to_string();
+
+        tokio::task::spawn_blocking(move || {
+            diesel::delete(
+                login_throttles
+                    .filter(scope.eq(throttle_scope.as_str()))
+                    .filter(subject.eq(throttle_subject)),
+            )
+            .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| LoginThrottleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| LoginThrottleRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+use crate::domain::entities::mfa::UserMfa;
+use crate::domain::repositories::mfa_repository::{MfaRepository, MfaRepositoryError};
+use crate::infrastructure::database::schema::{mfa_recovery_codes, user_mfa};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use uuid::Uuid;
+
+// Modelo Diesel para leitura e escrita
+#[derive(Queryable, Selectable, Insertable, AsChangeset, Debug, Clone)]
+#[diesel(table_name = user_mfa)]
+#[diesel(primary_key(user_id))]
+#[diesel(treat_none_as_null = true)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct UserMfaModel {
+    user_id: Uuid,
+    secret: String,
+    enabled: bool,
+    confirmed_at: Option<DateTime<Utc>>,
+    last_used_step: Option<i64>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+// Modelo Diesel para inserção de códigos de recuperação
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = mfa_recovery_codes)]
+struct NewRecoveryCodeModel {
+    id: Uuid,
+    user_id: Uuid,
+    code_hash: String,
+    created_at: DateTime<Utc>,
+}
+
+impl From<UserMfaModel> for UserMfa {
+    fn from(model: UserMfaModel) -> Self {
+        UserMfa {
+            user_id: model.user_id,
+            secret: model.secret,
+            enabled: model.enabled,
+            confirmed_at: model.confirmed_at,
+            last_used_step: model.last_used_step,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl From<UserMfa> for UserMfaModel {
+    fn from(mfa: UserMfa) -> Self {
+        UserMfaModel {
+            user_id: mfa.user_id,
+            secret: mfa.secret,
+            enabled: mfa.enabled,
+            confirmed_at: mfa.confirmed_at,
+            last_used_step: mfa.last_used_step,
+            created_at: mfa.created_at,
+            updated_at: mfa.updated_at,
+        }
+    }
+}
+
+pub struct DieselMfaRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselMfaRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MfaRepository for DieselMfaRepository {
+    async fn find_by_user(&self, owner_id: Uuid) -> Result<Option<UserMfa>, MfaRepositoryError> {
+        use crate::infrastructure::database::schema::user_mfa::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| MfaRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            user_mfa
+                .filter(user_id.eq(owner_id))
+                .select(UserMfaModel::as_select())
+                .first::<UserMfaModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| MfaRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| MfaRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn save(&self, mfa: UserMfa) -> Result<UserMfa, MfaRepositoryError> {
+        use crate::infrastructure::database::schema::user_mfa::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| MfaRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = UserMfaModel::from(mfa);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::insert_into(user_mfa)
+                .values(&model)
+                .on_conflict(user_id)
+                .do_update()
+                .set(&model)
+                .returning(UserMfaModel::as_returning())
+                .get_result::<UserMfaModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| MfaRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| MfaRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.into())
+    }
+
+    async fn delete(&self, owner_id: Uuid) -> Result<(), MfaRepositoryError> {
+        let mut conn = self.pool.get()
+            .map_err(|e| MfaRepositoryError::DatabaseError(e.to_string()))?;
+
+        tokio::task::spawn_blocking(move || {
+            conn.transaction::<_, diesel::result::Error, _>(|conn| {
+                diesel::delete(mfa_recovery_codes::table.filter(mfa_recovery_codes::user_id.eq(owner_id)))
+                    .execute(conn)?;
+                diesel::delete(user_mfa::table.filter(user_mfa::user_id.eq(owner_id)))
+                    .execute(conn)?;
+                Ok(())
+            })
+        })
+        .await
+        .map_err(|e| MfaRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| MfaRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn replace_recovery_codes(&self, owner_id: Uuid, code_hashes: Vec<String>) -> Result<(), MfaRepositoryError> {
+        use crate::infrastructure::database::schema::mfa_recovery_codes::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| MfaRepositoryError::DatabaseError(e.to_string()))?;
+
+        let now = Utc::now();
+        let models: Vec<NewRecoveryCodeModel> = code_hashes
+            .into_iter()
+            .map(|hash| NewRecoveryCodeModel {
+                id: Uuid::new_v4(),
+                user_id: owner_id,
+                code_hash: hash,
+                created_at: now,
+            })
+            .collect();
+
+        tokio::task::spawn_blocking(move || {
+            conn.transaction::<_, diesel::result::Error, _>(|conn| {
+                diesel::delete(mfa_recovery_codes.filter(user_id.eq(owner_id)))
+                    .execute(conn)?;
+                diesel::insert_into(mfa_recovery_codes)
+                    .values(&models)
+                    .execute(conn)?;
+                Ok(())
+            })
+        })
+        .await
+        .map_err(|e| MfaRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| MfaRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn consume_recovery_code(&self, owner_id: Uuid, hash: &str) -> Result<bool, MfaRepositoryError> {
+        use crate::infrastructure::database::schema::mfa_recovery_codes::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| MfaRepositoryError::DatabaseError(e.to_string()))?;
+
+        let hash = hash.to_string();
+
+        // O DELETE é atômico: dois usos concorrentes do mesmo código não podem ambos ter sucesso
+        let deleted = tokio::task::spawn_blocking(move || {
+            diesel::delete(
+                mfa_recovery_codes
+                    .filter(user_id.eq(owner_id))
+                    .filter(code_hash.eq(hash)),
+            )
+            .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| MfaRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| MfaRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deleted > 0)
+    }
+}
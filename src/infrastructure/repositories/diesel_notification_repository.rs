@@ -0,0 +1,332 @@
+use crate::domain::entities::alert::{AlertChannel, Notification, NotificationDelivery, NotificationDeliveryStatus};
+use crate::domain::repositories::notification_repository::{NotificationRepository, NotificationRepositoryError};
+use crate::infrastructure::database::schema::{notification_deliveries, notifications};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use uuid::Uuid;
+
+// Modelo Diesel para leitura e escrita
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = notifications)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct NotificationModel {
+    id: Uuid,
+    user_id: Uuid,
+    rule_id: Uuid,
+    article_id: Uuid,
+    title: String,
+    link: String,
+    read_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<NotificationModel> for Notification {
+    fn from(model: NotificationModel) -> Self {
+        Notification {
+            id: model.id,
+            user_id: model.user_id,
+            rule_id: model.rule_id,
+            article_id: model.article_id,
+            title: model.title,
+            link: model.link,
+            read_at: model.read_at,
+            created_at: model.created_at,
+        }
+    }
+}
+
+impl From<Notification> for NotificationModel {
+    fn from(notification: Notification) -> Self {
+        NotificationModel {
+            id: notification.id,
+            user_id: notification.user_id,
+            rule_id: notification.rule_id,
+            article_id: notification.article_id,
+            title: notification.title,
+            link: notification.link,
+            read_at: notification.read_at,
+            created_at: notification.created_at,
+        }
+    }
+}
+
+#[derive(Queryable, Selectable, Insertable, AsChangeset, Debug, Clone)]
+#[diesel(table_name = notification_deliveries)]
+#[diesel(treat_none_as_null = true)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct NotificationDeliveryModel {
+    id: Uuid,
+    notification_id: Uuid,
+    channel: String,
+    status: String,
+    attempts: i32,
+    next_attempt_at: Option<DateTime<Utc>>,
+    last_error: Option<String>,
+    created_at: DateTime<Utc>,
+    delivered_at: Option<DateTime<Utc>>,
+}
+
+impl From<NotificationDeliveryModel> for NotificationDelivery {
+    fn from(model: NotificationDeliveryModel) -> Self {
+        NotificationDelivery {
+            id: model.id,
+            notification_id: model.notification_id,
+            channel: AlertChannel::parse(&model.channel).unwrap_or(AlertChannel::Email),
+            status: NotificationDeliveryStatus::parse(&model.status).unwrap_or(NotificationDeliveryStatus::Failed),
+            attempts: model.attempts,
+            next_attempt_at: model.next_attempt_at,
+            last_error: model.last_error,
+            created_at: model.created_at,
+            delivered_at: model.delivered_at,
+        }
+    }
+}
+
+impl From<NotificationDelivery> for NotificationDeliveryModel {
+    fn from(delivery: NotificationDelivery) -> Self {
+        NotificationDeliveryModel {
+            id: delivery.id,
+            notification_id: delivery.notification_id,
+            channel: delivery.channel.as_str().to_string(),
+            status: delivery.status.as_str().to_string(),
+            attempts: delivery.attempts,
+            next_attempt_at: delivery.next_attempt_at,
+            last_error: delivery.last_error,
+            created_at: delivery.created_at,
+            delivered_at: delivery.delivered_at,
+        }
+    }
+}
+
+pub struct DieselNotificationRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselNotificationRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationRepository for DieselNotificationRepository {
+    async fn create_if_absent(
+        &self,
+        notification: Notification,
+        deliveries: Vec<NotificationDelivery>,
+    ) -> Result<bool, NotificationRepositoryError> {
+        let mut conn = self.pool.get()
+            .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = NotificationModel::from(notification);
+        let delivery_models: Vec<NotificationDeliveryModel> = deliveries.into_iter().map(NotificationDeliveryModel::from).collect();
+
+        // A restrição única (rule_id, article_id) garante uma notificação por regra e artigo
+        // mesmo com ingestões simultâneas; as entregas só entram junto com a notificação
+        let inserted = tokio::task::spawn_blocking(move || {
+            conn.transaction::<_, diesel::result::Error, _>(|conn| {
+                let inserted = diesel::insert_into(notifications::table)
+                    .values(&model)
+                    .on_conflict((notifications::rule_id, notifications::article_id))
+                    .do_nothing()
+                    .execute(conn)?;
+
+                if inserted > 0 && !delivery_models.is_empty() {
+                    diesel::insert_into(notification_deliveries::table)
+                        .values(&delivery_models)
+                        .execute(conn)?;
+                }
+
+                Ok(inserted > 0)
+            })
+        })
+        .await
+        .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(inserted)
+    }
+
+    async fn find_by_id(&self, notification_id: Uuid) -> Result<Option<Notification>, NotificationRepositoryError> {
+        use crate::infrastructure::database::schema::notifications::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            notifications
+                .filter(id.eq(notification_id))
+                .select(NotificationModel::as_select())
+                .first::<NotificationModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn list_by_user(
+        &self,
+        owner_id: Uuid,
+        unread_only: bool,
+        page: i64,
+        per_page: i64,
+    ) -> Result<(Vec<Notification>, i64), NotificationRepositoryError> {
+        use crate::infrastructure::database::schema::notifications::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            let filtered = || {
+                let mut query = notifications.filter(user_id.eq(owner_id)).into_boxed();
+                if unread_only {
+                    query = query.filter(read_at.is_null());
+                }
+                query
+            };
+
+            let total = filtered().count().get_result::<i64>(&mut conn)?;
+            let items = filtered()
+                .order((created_at.desc(), id.desc()))
+                .limit(per_page)
+                .offset((page - 1) * per_page)
+                .select(NotificationModel::as_select())
+                .load::<NotificationModel>(&mut conn)?;
+
+            Ok::<_, diesel::result::Error>((items, total))
+        })
+        .await
+        .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?;
+
+        let (items, total) = result;
+        Ok((items.into_iter().map(|model| model.into()).collect(), total))
+    }
+
+    async fn unread_count(&self, owner_id: Uuid) -> Result<i64, NotificationRepositoryError> {
+        use crate::infrastructure::database::schema::notifications::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?;
+
+        let count = tokio::task::spawn_blocking(move || {
+            notifications
+                .filter(user_id.eq(owner_id))
+                .filter(read_at.is_null())
+                .count()
+                .get_result::<i64>(&mut conn)
+        })
+        .await
+        .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(count)
+    }
+
+    async fn mark_read(&self, owner_id: Uuid, notification_id: Uuid, at: DateTime<Utc>) -> Result<bool, NotificationRepositoryError> {
+        use crate::infrastructure::database::schema::notifications::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?;
+
+        // Marcar de novo mantém o `read_at` original
+        let found = tokio::task::spawn_blocking(move || {
+            let owned = notifications.filter(id.eq(notification_id)).filter(user_id.eq(owner_id));
+
+            let updated = diesel::update(owned.filter(read_at.is_null()))
+                .set(read_at.eq(Some(at)))
+                .execute(&mut conn)?;
+            if updated > 0 {
+                return Ok(true);
+            }
+
+            diesel::select(diesel::dsl::exists(owned)).get_result::<bool>(&mut conn)
+        })
+        .await
+        .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e: diesel::result::Error| NotificationRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(found)
+    }
+
+    async fn mark_all_read(&self, owner_id: Uuid, at: DateTime<Utc>) -> Result<u64, NotificationRepositoryError> {
+        use crate::infrastructure::database::schema::notifications::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?;
+
+        let marked = tokio::task::spawn_blocking(move || {
+            diesel::update(notifications.filter(user_id.eq(owner_id)).filter(read_at.is_null()))
+                .set(read_at.eq(Some(at)))
+                .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(marked as u64)
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<NotificationDelivery>, NotificationRepositoryError> {
+        use crate::infrastructure::database::schema::notification_deliveries::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            conn.transaction::<_, diesel::result::Error, _>(|conn| {
+                // SKIP LOCKED: entregas reservadas por outra instância ficam de fora
+                let due_ids = notification_deliveries
+                    .filter(status.eq(NotificationDeliveryStatus::Pending.as_str()))
+                    .filter(next_attempt_at.le(now))
+                    .order(next_attempt_at.asc())
+                    .limit(limit)
+                    .select(id)
+                    .for_update()
+                    .skip_locked()
+                    .load::<Uuid>(conn)?;
+
+                diesel::update(notification_deliveries.filter(id.eq_any(due_ids)))
+                    .set(next_attempt_at.eq(Some(lease_until)))
+                    .returning(NotificationDeliveryModel::as_returning())
+                    .get_results::<NotificationDeliveryModel>(conn)
+            })
+        })
+        .await
+        .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn update_delivery(&self, delivery: NotificationDelivery) -> Result<NotificationDelivery, NotificationRepositoryError> {
+        use crate::infrastructure::database::schema::notification_deliveries::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = NotificationDeliveryModel::from(delivery);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::update(notification_deliveries.filter(id.eq(model.id)))
+                .set(&model)
+                .returning(NotificationDeliveryModel::as_returning())
+                .get_result::<NotificationDeliveryModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| NotificationRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,273 @@
+use crate::domain::entities::saved_article::SavedArticle;
+use crate::domain::repositories::saved_article_repository::{SavedArticleRepository, SavedArticleRepositoryError};
+use crate::infrastructure::database::schema::saved_articles;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use uuid::Uuid;
+
+// Modelo Diesel para leitura e escrita
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = saved_articles)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct SavedArticleModel {
+    id: Uuid,
+    user_id: Uuid,
+    article_id: Option<Uuid>,
+    collection_id: Option<Uuid>,
+    position: i32,
+    title: String,
+    description: String,
+    link: String,
+    media: Option<String>,
+    creator: String,
+    feed_id: Uuid,
+    pub_date: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<SavedArticleModel> for SavedArticle {
+    fn from(model: SavedArticleModel) -> Self {
+        SavedArticle {
+            id: model.id,
+            user_id: model.user_id,
+            article_id: model.article_id,
+            collection_id: model.collection_id,
+            position: model.position,
+            title: model.title,
+            description: model.description,
+            link: model.link,
+            media: model.media,
+            creator: model.creator,
+            feed_id: model.feed_id,
+            pub_date: model.pub_date,
+            created_at: model.created_at,
+        }
+    }
+}
+
+impl From<SavedArticle> for SavedArticleModel {
+    fn from(saved: SavedArticle) -> Self {
+        SavedArticleModel {
+            id: saved.id,
+            user_id: saved.user_id,
+            article_id: saved.article_id,
+            collection_id: saved.collection_id,
+            position: saved.position,
+            title: saved.title,
+            description: saved.description,
+            link: saved.link,
+            media: saved.media,
+            creator: saved.creator,
+            feed_id: saved.feed_id,
+            pub_date: saved.pub_date,
+            created_at: saved.created_at,
+        }
+    }
+}
+
+pub struct DieselSavedArticleRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselSavedArticleRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SavedArticleRepository for DieselSavedArticleRepository {
+    async fn create(&self, saved: SavedArticle) -> Result<SavedArticle, SavedArticleRepositoryError> {
+        let mut conn = self.pool.get()
+            .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = SavedArticleModel::from(saved);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::insert_into(saved_articles::table)
+                .values(&model)
+                .returning(SavedArticleModel::as_returning())
+                .get_result::<SavedArticleModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| match e {
+            DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                SavedArticleRepositoryError::AlreadySaved
+            }
+            _ => SavedArticleRepositoryError::DatabaseError(e.to_string()),
+        })?;
+
+        Ok(result.into())
+    }
+
+    async fn find_by_id(&self, owner_id: Uuid, saved_id: Uuid) -> Result<Option<SavedArticle>, SavedArticleRepositoryError> {
+        use crate::infrastructure::database::schema::saved_articles::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            saved_articles
+                .filter(id.eq(saved_id))
+                .filter(user_id.eq(owner_id))
+                .select(SavedArticleModel::as_select())
+                .first::<SavedArticleModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn list_by_user(&self, owner_id: Uuid) -> Result<Vec<SavedArticle>, SavedArticleRepositoryError> {
+        use crate::infrastructure::database::schema::saved_articles::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            saved_articles
+                .filter(user_id.eq(owner_id))
+                .order((position.asc(), created_at.asc()))
+                .select(SavedArticleModel::as_select())
+                .load::<SavedArticleModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn list_by_collection(&self, target_collection_id: Uuid) -> Result<Vec<SavedArticle>, SavedArticleRepositoryError> {
+        use crate::infrastructure::database::schema::saved_articles::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            saved_articles
+                .filter(collection_id.eq(target_collection_id))
+                .order((position.asc(), created_at.asc()))
+                .select(SavedArticleModel::as_select())
+                .load::<SavedArticleModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn max_position(&self, owner_id: Uuid) -> Result<Option<i32>, SavedArticleRepositoryError> {
+        use crate::infrastructure::database::schema::saved_articles::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            saved_articles
+                .filter(user_id.eq(owner_id))
+                .select(diesel::dsl::max(position))
+                .first::<Option<i32>>(&mut conn)
+        })
+        .await
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn update(&self, saved: SavedArticle) -> Result<SavedArticle, SavedArticleRepositoryError> {
+        use crate::infrastructure::database::schema::saved_articles::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::update(saved_articles.filter(id.eq(saved.id)))
+                .set((collection_id.eq(saved.collection_id), position.eq(saved.position)))
+                .returning(SavedArticleModel::as_returning())
+                .get_result::<SavedArticleModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.into())
+    }
+
+    async fn update_positions(&self, owner_id: Uuid, positions: &[(Uuid, i32)]) -> Result<(), SavedArticleRepositoryError> {
+        use crate::infrastructure::database::schema::saved_articles::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let positions = positions.to_vec();
+
+        tokio::task::spawn_blocking(move || {
+            conn.transaction::<_, DieselError, _>(|conn| {
+                for (saved_id, new_position) in positions {
+                    diesel::update(
+                        saved_articles
+                            .filter(id.eq(saved_id))
+                            .filter(user_id.eq(owner_id)),
+                    )
+                    .set(position.eq(new_position))
+                    .execute(conn)?;
+                }
+                Ok(())
+            })
+        })
+        .await
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn detach_collection(&self, target_collection_id: Uuid) -> Result<(), SavedArticleRepositoryError> {
+        use crate::infrastructure::database::schema::saved_articles::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        tokio::task::spawn_blocking(move || {
+            diesel::update(saved_articles.filter(collection_id.eq(target_collection_id)))
+                .set(collection_id.eq(None::<Uuid>))
+                .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, owner_id: Uuid, saved_id: Uuid) -> Result<bool, SavedArticleRepositoryError> {
+        use crate::infrastructure::database::schema::saved_articles::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        let deleted = tokio::task::spawn_blocking(move || {
+            diesel::delete(
+                saved_articles
+                    .filter(id.eq(saved_id))
+                    .filter(user_id.eq(owner_id)),
+            )
+            .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SavedArticleRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deleted > 0)
+    }
+}
@@ -0,0 +1,219 @@
+use crate::domain::entities::session::Session;
+use crate::domain::repositories::session_repository::{SessionRepository, SessionRepositoryError};
+use crate::infrastructure::database::schema::sessions;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use uuid::Uuid;
+
+// Modelo Diesel para leitura e escrita
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = sessions)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct SessionModel {
+    id: Uuid,
+    user_id: Uuid,
+    device_label: Option<String>,
+    user_agent: Option<String>,
+    ip_address: Option<String>,
+    created_at: DateTime<Utc>,
+    last_seen_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl From<SessionModel> for Session {
+    fn from(model: SessionModel) -> Self {
+        Session {
+            id: model.id,
+            user_id: model.user_id,
+            device_label: model.device_label,
+            user_agent: model.user_agent,
+            ip_address: model.ip_address,
+            created_at: model.created_at,
+            last_seen_at: model.last_seen_at,
+            expires_at: model.expires_at,
+            revoked_at: model.revoked_at,
+        }
+    }
+}
+
+impl From<Session> for SessionModel {
+    fn from(session: Session) -> Self {
+        SessionModel {
+            id: session.id,
+            user_id: session.user_id,
+            device_label: session.device_label,
+            user_agent: session.user_agent,
+            ip_address: session.ip_address,
+            created_at: session.created_at,
+            last_seen_at: session.last_seen_at,
+            expires_at: session.expires_at,
+            revoked_at: session.revoked_at,
+        }
+    }
+}
+
+pub struct DieselSessionRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselSessionRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SessionRepository for DieselSessionRepository {
+    async fn create(&self, session: Session) -> Result<Session, SessionRepositoryError> {
+        let mut conn = self.pool.get()
+            .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = SessionModel::from(session);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::insert_into(sessions::table)
+                .values(&model)
+                .returning(SessionModel::as_returning())
+                .get_result::<SessionModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.into())
+    }
+
+    async fn find_by_id(&self, session_id: Uuid) -> Result<Option<Session>, SessionRepositoryError> {
+        use crate::infrastructure::database::schema::sessions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            sessions
+                .filter(id.eq(session_id))
+                .select(SessionModel::as_select())
+                .first::<SessionModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn list_active_by_user(&self, owner_id: Uuid, now: DateTime<Utc>) -> Result<Vec<Session>, SessionRepositoryError> {
+        use crate::infrastructure::database::schema::sessions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            sessions
+                .filter(user_id.eq(owner_id))
+                .filter(revoked_at.is_null())
+                .filter(expires_at.gt(now))
+                .order(created_at.desc())
+                .select(SessionModel::as_select())
+                .load::<SessionModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn revoke(&self, owner_id: Uuid, session_id: Uuid, at: DateTime<Utc>) -> Result<bool, SessionRepositoryError> {
+        use crate::infrastructure::database::schema::sessions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let updated = tokio::task::spawn_blocking(move || {
+            diesel::update(
+                sessions
+                    .filter(id.eq(session_id))
+                    .filter(user_id.eq(owner_id))
+                    .filter(revoked_at.is_null()),
+            )
+            .set(revoked_at.eq(Some(at)))
+            .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(updated > 0)
+    }
+
+    async fn revoke_all(
+        &self,
+        owner_id: Uuid,
+        except: Option<Uuid>,
+        at: DateTime<Utc>,
+    ) -> Result<usize, SessionRepositoryError> {
+        use crate::infrastructure::database::schema::sessions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?;
+
+        // O ID nulo nunca pertence a uma sessão, então sem `except` todas são revogadas
+        let kept = except.unwrap_or(Uuid::nil());
+
+        let updated = tokio::task::spawn_blocking(move || {
+            diesel::update(
+                sessions
+                    .filter(user_id.eq(owner_id))
+                    .filter(revoked_at.is_null())
+                    .filter(id.ne(kept)),
+            )
+            .set(revoked_at.eq(Some(at)))
+            .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(updated)
+    }
+
+    async fn touch(&self, session_id: Uuid, seen_at: DateTime<Utc>) -> Result<(), SessionRepositoryError> {
+        use crate::infrastructure::database::schema::sessions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?;
+
+        tokio::task::spawn_blocking(move || {
+            diesel::update(sessions.filter(id.eq(session_id)))
+                .set(last_seen_at.eq(seen_at))
+                .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete_expired(&self, owner_id: Uuid, before: DateTime<Utc>) -> Result<(), SessionRepositoryError> {
+        use crate::infrastructure::database::schema::sessions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?;
+
+        tokio::task::spawn_blocking(move || {
+            diesel::delete(sessions.filter(user_id.eq(owner_id)).filter(expires_at.le(before)))
+                .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SessionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,223 @@
+use crate::domain::entities::article::ArticleSort;
+use crate::domain::entities::smart_folder::{SmartFolder, SmartFolderCriteria};
+use crate::domain::repositories::smart_folder_repository::{SmartFolderRepository, SmartFolderRepositoryError};
+use crate::infrastructure::database::schema::smart_folders;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use uuid::Uuid;
+
+// Modelo Diesel para leitura e escrita; os critérios ficam em colunas próprias
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = smart_folders)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct SmartFolderModel {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    search: Option<String>,
+    category_id: Option<Uuid>,
+    tag_id: Option<Uuid>,
+    unread_only: bool,
+    min_rate: Option<i32>,
+    min_user_rating: Option<f64>,
+    sort: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<SmartFolderModel> for SmartFolder {
+    fn from(model: SmartFolderModel) -> Self {
+        SmartFolder {
+            id: model.id,
+            user_id: model.user_id,
+            name: model.name,
+            criteria: SmartFolderCriteria {
+                search: model.search,
+                category_id: model.category_id,
+                tag_id: model.tag_id,
+                unread: model.unread_only,
+                min_rate: model.min_rate,
+                min_user_rating: model.min_user_rating,
+                sort: ArticleSort::parse(&model.sort).unwrap_or_default(),
+            },
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl From<SmartFolder> for SmartFolderModel {
+    fn from(folder: SmartFolder) -> Self {
+        SmartFolderModel {
+            id: folder.id,
+            user_id: folder.user_id,
+            name: folder.name,
+            search: folder.criteria.search,
+            category_id: folder.criteria.category_id,
+            tag_id: folder.criteria.tag_id,
+            unread_only: folder.criteria.unread,
+            min_rate: folder.criteria.min_rate,
+            min_user_rating: folder.criteria.min_user_rating,
+            sort: folder.criteria.sort.as_str().to_string(),
+            created_at: folder.created_at,
+            updated_at: folder.updated_at,
+        }
+    }
+}
+
+fn map_write_error(e: DieselError) -> SmartFolderRepositoryError {
+    match e {
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => SmartFolderRepositoryError::NameTaken,
+        _ => SmartFolderRepositoryError::DatabaseError(e.to_string()),
+    }
+}
+
+pub struct DieselSmartFolderRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselSmartFolderRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SmartFolderRepository for DieselSmartFolderRepository {
+    async fn create(&self, folder: SmartFolder) -> Result<SmartFolder, SmartFolderRepositoryError> {
+        let mut conn = self.pool.get()
+            .map_err(|e| SmartFolderRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = SmartFolderModel::from(folder);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::insert_into(smart_folders::table)
+                .values(&model)
+                .returning(SmartFolderModel::as_returning())
+                .get_result::<SmartFolderModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| SmartFolderRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(map_write_error)?;
+
+        Ok(result.into())
+    }
+
+    async fn find_by_id(&self, owner_id: Uuid, folder_id: Uuid) -> Result<Option<SmartFolder>, SmartFolderRepositoryError> {
+        use crate::infrastructure::database::schema::smart_folders::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SmartFolderRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            smart_folders
+                .filter(id.eq(folder_id))
+                .filter(user_id.eq(owner_id))
+                .select(SmartFolderModel::as_select())
+                .first::<SmartFolderModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| SmartFolderRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SmartFolderRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn list_by_user(&self, owner_id: Uuid) -> Result<Vec<SmartFolder>, SmartFolderRepositoryError> {
+        use crate::infrastructure::database::schema::smart_folders::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SmartFolderRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            smart_folders
+                .filter(user_id.eq(owner_id))
+                .order(name.asc())
+                .select(SmartFolderModel::as_select())
+                .load::<SmartFolderModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| SmartFolderRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SmartFolderRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn update(&self, folder: SmartFolder) -> Result<SmartFolder, SmartFolderRepositoryError> {
+        use crate::infrastructure::database::schema::smart_folders::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SmartFolderRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = SmartFolderModel::from(folder);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::update(smart_folders.filter(id.eq(model.id)))
+                .set((
+                    name.eq(model.name),
+                    search.eq(model.search),
+                    category_id.eq(model.category_id),
+                    tag_id.eq(model.tag_id),
+                    unread_only.eq(model.unread_only),
+                    min_rate.eq(model.min_rate),
+                    min_user_rating.eq(model.min_user_rating),
+                    sort.eq(model.sort),
+                    updated_at.eq(model.updated_at),
+                ))
+                .returning(SmartFolderModel::as_returning())
+                .get_result::<SmartFolderModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| SmartFolderRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(map_write_error)?;
+
+        Ok(result.into())
+    }
+
+    async fn delete(&self, owner_id: Uuid, folder_id: Uuid) -> Result<bool, SmartFolderRepositoryError> {
+        use crate::infrastructure::database::schema::smart_folders::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SmartFolderRepositoryError::DatabaseError(e.to_string()))?;
+
+        let deleted = tokio::task::spawn_blocking(move || {
+            diesel::delete(
+                smart_folders
+                    .filter(id.eq(folder_id))
+                    .filter(user_id.eq(owner_id)),
+            )
+            .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| SmartFolderRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SmartFolderRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deleted > 0)
+    }
+
+    async fn replace_tag(&self, owner_id: Uuid, source_id: Uuid, target_id: Uuid) -> Result<(), SmartFolderRepositoryError> {
+        use crate::infrastructure::database::schema::smart_folders::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SmartFolderRepositoryError::DatabaseError(e.to_string()))?;
+
+        tokio::task::spawn_blocking(move || {
+            diesel::update(
+                smart_folders
+                    .filter(user_id.eq(owner_id))
+                    .filter(tag_id.eq(source_id)),
+            )
+            .set((tag_id.eq(target_id), updated_at.eq(Utc::now())))
+            .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| SmartFolderRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SmartFolderRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
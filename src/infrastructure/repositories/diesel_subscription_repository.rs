@@ -0,0 +1,199 @@
+use crate::domain::entities::subscription::Subscription;
+use crate::domain::repositories::subscription_repository::{SubscriptionRepository, SubscriptionRepositoryError};
+use crate::infrastructure::database::schema::subscriptions;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use uuid::Uuid;
+
+// Modelo Diesel para leitura e escrita
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = subscriptions)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct SubscriptionModel {
+    id: Uuid,
+    user_id: Uuid,
+    feed_id: Uuid,
+    title: Option<String>,
+    folder: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    weight: f64,
+}
+
+impl From<SubscriptionModel> for Subscription {
+    fn from(model: SubscriptionModel) -> Self {
+        Subscription {
+            id: model.id,
+            user_id: model.user_id,
+            feed_id: model.feed_id,
+            title: model.title,
+            folder: model.folder,
+            weight: model.weight,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl From<Subscription> for SubscriptionModel {
+    fn from(subscription: Subscription) -> Self {
+        SubscriptionModel {
+            id: subscription.id,
+            user_id: subscription.user_id,
+            feed_id: subscription.feed_id,
+            title: subscription.title,
+            folder: subscription.folder,
+            created_at: subscription.created_at,
+            updated_at: subscription.updated_at,
+            weight: subscription.weight,
+        }
+    }
+}
+
+pub struct DieselSubscriptionRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselSubscriptionRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SubscriptionRepository for DieselSubscriptionRepository {
+    async fn create(&self, subscription: Subscription) -> Result<Subscription, SubscriptionRepositoryError> {
+        let mut conn = self.pool.get()
+            .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = SubscriptionModel::from(subscription);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::insert_into(subscriptions::table)
+                .values(&model)
+                .returning(SubscriptionModel::as_returning())
+                .get_result::<SubscriptionModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| match e {
+            DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                SubscriptionRepositoryError::AlreadySubscribed
+            }
+            DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _) => {
+                SubscriptionRepositoryError::FeedNotFound
+            }
+            _ => SubscriptionRepositoryError::DatabaseError(e.to_string()),
+        })?;
+
+        Ok(result.into())
+    }
+
+    async fn find_by_id(&self, owner_id: Uuid, subscription_id: Uuid) -> Result<Option<Subscription>, SubscriptionRepositoryError> {
+        use crate::infrastructure::database::schema::subscriptions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            subscriptions
+                .filter(id.eq(subscription_id))
+                .filter(user_id.eq(owner_id))
+                .select(SubscriptionModel::as_select())
+                .first::<SubscriptionModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn list_by_user(&self, owner_id: Uuid) -> Result<Vec<Subscription>, SubscriptionRepositoryError> {
+        use crate::infrastructure::database::schema::subscriptions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            subscriptions
+                .filter(user_id.eq(owner_id))
+                .order((folder.asc(), created_at.asc()))
+                .select(SubscriptionModel::as_select())
+                .load::<SubscriptionModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn feed_ids_by_user(&self, owner_id: Uuid) -> Result<Vec<Uuid>, SubscriptionRepositoryError> {
+        use crate::infrastructure::database::schema::subscriptions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            subscriptions
+                .filter(user_id.eq(owner_id))
+                .select(feed_id)
+                .load::<Uuid>(&mut conn)
+        })
+        .await
+        .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results)
+    }
+
+    async fn update(&self, subscription: Subscription) -> Result<Subscription, SubscriptionRepositoryError> {
+        use crate::infrastructure::database::schema::subscriptions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::update(subscriptions.filter(id.eq(subscription.id)))
+                .set((
+                    title.eq(subscription.title),
+                    folder.eq(subscription.folder),
+                    weight.eq(subscription.weight),
+                    updated_at.eq(subscription.updated_at),
+                ))
+                .returning(SubscriptionModel::as_returning())
+                .get_result::<SubscriptionModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.into())
+    }
+
+    async fn delete(&self, owner_id: Uuid, subscription_id: Uuid) -> Result<bool, SubscriptionRepositoryError> {
+        use crate::infrastructure::database::schema::subscriptions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?;
+
+        let deleted = tokio::task::spawn_blocking(move || {
+            diesel::delete(
+                subscriptions
+                    .filter(id.eq(subscription_id))
+                    .filter(user_id.eq(owner_id)),
+            )
+            .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| SubscriptionRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deleted > 0)
+    }
+}
@@ -0,0 +1,347 @@
+use crate::domain::entities::tag::{Tag, TagSummary};
+use crate::domain::repositories::tag_repository::{TagRepository, TagRepositoryError};
+use crate::infrastructure::database::schema::{article_tags, tags};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use uuid::Uuid;
+
+diesel::define_sql_function! {
+    /// LOWER() do Postgres, para comparar nomes de tags sem diferenciar maiúsculas
+    fn lower(x: diesel::sql_types::Text) -> diesel::sql_types::Text;
+}
+
+// Modelo Diesel para leitura e escrita
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = tags)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct TagModel {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<TagModel> for Tag {
+    fn from(model: TagModel) -> Self {
+        Tag {
+            id: model.id,
+            user_id: model.user_id,
+            name: model.name,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl From<Tag> for TagModel {
+    fn from(tag: Tag) -> Self {
+        TagModel {
+            id: tag.id,
+            user_id: tag.user_id,
+            name: tag.name,
+            created_at: tag.created_at,
+            updated_at: tag.updated_at,
+        }
+    }
+}
+
+fn map_write_error(e: DieselError) -> TagRepositoryError {
+    match e {
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => TagRepositoryError::NameTaken,
+        DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _) => TagRepositoryError::ArticleNotFound,
+        _ => TagRepositoryError::DatabaseError(e.to_string()),
+    }
+}
+
+/// Escapa os curingas do LIKE para buscar o prefixo literalmente
+fn like_prefix(prefix: &str) -> String {
+    let escaped = prefix
+        .to_lowercase()
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("{}%", escaped)
+}
+
+pub struct DieselTagRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselTagRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TagRepository for DieselTagRepository {
+    async fn create(&self, tag: Tag) -> Result<Tag, TagRepositoryError> {
+        let mut conn = self.pool.get()
+            .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = TagModel::from(tag);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::insert_into(tags::table)
+                .values(&model)
+                .returning(TagModel::as_returning())
+                .get_result::<TagModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(map_write_error)?;
+
+        Ok(result.into())
+    }
+
+    async fn find_by_id(&self, owner_id: Uuid, tag_id: Uuid) -> Result<Option<Tag>, TagRepositoryError> {
+        use crate::infrastructure::database::schema::tags::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            tags.filter(id.eq(tag_id))
+                .filter(user_id.eq(owner_id))
+                .select(TagModel::as_select())
+                .first::<TagModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn find_by_names(&self, owner_id: Uuid, names: &[String]) -> Result<Vec<Tag>, TagRepositoryError> {
+        use crate::infrastructure::database::schema::tags::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        let keys: Vec<String> = names.iter().map(|tag_name| Tag::name_key(tag_name)).collect();
+
+        let results = tokio::task::spawn_blocking(move || {
+            tags.filter(user_id.eq(owner_id))
+                .filter(lower(name).eq_any(keys))
+                .select(TagModel::as_select())
+                .load::<TagModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn list_by_user(&self, owner_id: Uuid) -> Result<Vec<TagSummary>, TagRepositoryError> {
+        use diesel::dsl::count;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            tags::table
+                .left_join(article_tags::table)
+                .filter(tags::user_id.eq(owner_id))
+                .group_by(tags::id)
+                .select((tags::id, tags::name, count(article_tags::article_id.nullable())))
+                .order(lower(tags::name).asc())
+                .load::<(Uuid, String, i64)>(&mut conn)
+        })
+        .await
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results
+            .into_iter()
+            .map(|(id, name, article_count)| TagSummary { id, name, article_count })
+            .collect())
+    }
+
+    async fn search(&self, owner_id: Uuid, prefix: &str, limit: i64) -> Result<Vec<TagSummary>, TagRepositoryError> {
+        use diesel::dsl::count;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        let pattern = like_prefix(prefix);
+
+        let results = tokio::task::spawn_blocking(move || {
+            tags::table
+                .left_join(article_tags::table)
+                .filter(tags::user_id.eq(owner_id))
+                .filter(lower(tags::name).like(pattern).escape('\\'))
+                .group_by(tags::id)
+                .select((tags::id, tags::name, count(article_tags::article_id.nullable())))
+                .order((count(article_tags::article_id.nullable()).desc(), lower(tags::name).asc()))
+                .limit(limit)
+                .load::<(Uuid, String, i64)>(&mut conn)
+        })
+        .await
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results
+            .into_iter()
+            .map(|(id, name, article_count)| TagSummary { id, name, article_count })
+            .collect())
+    }
+
+    async fn update(&self, tag: Tag) -> Result<Tag, TagRepositoryError> {
+        use crate::infrastructure::database::schema::tags::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::update(tags.filter(id.eq(tag.id)))
+                .set((name.eq(tag.name), updated_at.eq(tag.updated_at)))
+                .returning(TagModel::as_returning())
+                .get_result::<TagModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(map_write_error)?;
+
+        Ok(result.into())
+    }
+
+    async fn delete(&self, owner_id: Uuid, tag_id: Uuid) -> Result<bool, TagRepositoryError> {
+        use crate::infrastructure::database::schema::tags::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        let deleted = tokio::task::spawn_blocking(move || {
+            diesel::delete(tags.filter(id.eq(tag_id)).filter(user_id.eq(owner_id))).execute(&mut conn)
+        })
+        .await
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deleted > 0)
+    }
+
+    async fn merge(&self, owner_id: Uuid, source_id: Uuid, target_id: Uuid) -> Result<(), TagRepositoryError> {
+        use diesel::sql_types::Uuid as SqlUuid;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        tokio::task::spawn_blocking(move || {
+            conn.transaction::<_, DieselError, _>(|conn| {
+                // INSERT ... SELECT: artigos já marcados com as duas tags não se repetem
+                let moved = article_tags::table
+                    .filter(article_tags::tag_id.eq(source_id))
+                    .select((target_id.into_sql::<SqlUuid>(), article_tags::article_id, article_tags::created_at));
+
+                diesel::insert_into(article_tags::table)
+                    .values(moved)
+                    .into_columns((article_tags::tag_id, article_tags::article_id, article_tags::created_at))
+                    .on_conflict_do_nothing()
+                    .execute(conn)?;
+
+                diesel::delete(tags::table.filter(tags::id.eq(source_id)).filter(tags::user_id.eq(owner_id)))
+                    .execute(conn)?;
+                Ok(())
+            })
+        })
+        .await
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn list_by_article(&self, owner_id: Uuid, target_article_id: Uuid) -> Result<Vec<Tag>, TagRepositoryError> {
+        let mut conn = self.pool.get()
+            .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            tags::table
+                .inner_join(article_tags::table)
+                .filter(tags::user_id.eq(owner_id))
+                .filter(article_tags::article_id.eq(target_article_id))
+                .order(lower(tags::name).asc())
+                .select(TagModel::as_select())
+                .load::<TagModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn set_article_tags(&self, owner_id: Uuid, target_article_id: Uuid, tag_ids: &[Uuid]) -> Result<(), TagRepositoryError> {
+        let mut conn = self.pool.get()
+            .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        let tag_ids = tag_ids.to_vec();
+
+        tokio::task::spawn_blocking(move || {
+            conn.transaction::<_, DieselError, _>(|conn| {
+                let owned_tags = tags::table.filter(tags::user_id.eq(owner_id)).select(tags::id);
+
+                diesel::delete(
+                    article_tags::table
+                        .filter(article_tags::article_id.eq(target_article_id))
+                        .filter(article_tags::tag_id.eq_any(owned_tags)),
+                )
+                .execute(conn)?;
+
+                if tag_ids.is_empty() {
+                    return Ok(());
+                }
+
+                let now = Utc::now();
+                let rows: Vec<_> = tag_ids
+                    .iter()
+                    .map(|tag_id| {
+                        (
+                            article_tags::tag_id.eq(*tag_id),
+                            article_tags::article_id.eq(target_article_id),
+                            article_tags::created_at.eq(now),
+                        )
+                    })
+                    .collect();
+
+                diesel::insert_into(article_tags::table)
+                    .values(&rows)
+                    .on_conflict_do_nothing()
+                    .execute(conn)?;
+                Ok(())
+            })
+        })
+        .await
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(map_write_error)?;
+
+        Ok(())
+    }
+
+    async fn article_ids(&self, target_tag_id: Uuid) -> Result<Vec<Uuid>, TagRepositoryError> {
+        use crate::infrastructure::database::schema::article_tags::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            article_tags
+                .filter(tag_id.eq(target_tag_id))
+                .select(article_id)
+                .load::<Uuid>(&mut conn)
+        })
+        .await
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| TagRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::domain::entities::user::User;
+use crate::domain::entities::user::{User, UserRole};
 use crate::domain::repositories::user_repository::{UserRepository, UserRepositoryError};
 use crate::infrastructure::database::schema::users;
 use async_trait::async_trait;
@@ -18,6 +18,7 @@ struct NewUser {
     is_active: bool,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
+    role: String,
 }
 
 // Modelo Diesel para leitura
@@ -32,6 +33,7 @@ struct UserModel {
     is_active: bool,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
+    role: String,
 }
 
 impl From<UserModel> for User {
@@ -41,6 +43,7 @@ impl From<UserModel> for User {
             email: model.email,
             password_hash: model.password_hash,
             name: model.name,
+            role: UserRole::parse(&model.role).unwrap_or_default(),
             is_active: model.is_active,
             created_at: model.created_at,
             updated_at: model.updated_at,
@@ -58,6 +61,7 @@ impl From<User> for NewUser {
             is_active: user.is_active,
             created_at: user.created_at,
             updated_at: user.updated_at,
+            role: user.role.as_str().to_string(),
         }
     }
 }
@@ -153,6 +157,7 @@ impl UserRepository for DieselUserRepository {
         let user_email = user.email.clone();
         let user_password_hash = user.password_hash.clone();
         let user_name = user.name.clone();
+        let user_role = user.role.as_str().to_string();
         let user_is_active = user.is_active;
         let now = Utc::now();
 
@@ -162,6 +167,7 @@ impl UserRepository for DieselUserRepository {
                     email.eq(user_email),
                     password_hash.eq(user_password_hash),
                     name.eq(user_name),
+                    role.eq(user_role),
                     is_active.eq(user_is_active),
                     updated_at.eq(now),
                 ))
@@ -172,6 +178,10 @@ impl UserRepository for DieselUserRepository {
         .map_err(|e| UserRepositoryError::DatabaseError(e.to_string()))?
         .map_err(|e: diesel::result::Error| match e {
             diesel::result::Error::NotFound => UserRepositoryError::NotFound,
+            diesel::result::Error::DatabaseError(
+                diesel::result::DatabaseErrorKind::UniqueViolation,
+                _,
+            ) => UserRepositoryError::EmailAlreadyExists,
             _ => UserRepositoryError::DatabaseError(e.to_string()),
         })?;
 
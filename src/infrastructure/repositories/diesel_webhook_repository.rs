@@ -0,0 +1,377 @@
+use crate::domain::entities::webhook::{WebhookDelivery, WebhookDeliveryStatus, WebhookEventType, WebhookSubscription};
+use crate::domain::repositories::webhook_repository::{WebhookRepository, WebhookRepositoryError};
+use crate::infrastructure::database::schema::{webhook_deliveries, webhook_subscriptions};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use uuid::Uuid;
+
+// Modelo Diesel para leitura e escrita das assinaturas
+#[derive(Queryable, Selectable, Insertable, AsChangeset, Debug, Clone)]
+#[diesel(table_name = webhook_subscriptions)]
+#[diesel(treat_none_as_null = true)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct WebhookSubscriptionModel {
+    id: Uuid,
+    name: String,
+    url: String,
+    secret: String,
+    events: Vec<String>,
+    enabled: bool,
+    created_by: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+// Modelo Diesel para leitura e escrita das entregas
+#[derive(Queryable, Selectable, Insertable, AsChangeset, Debug, Clone)]
+#[diesel(table_name = webhook_deliveries)]
+#[diesel(treat_none_as_null = true)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+struct WebhookDeliveryModel {
+    id: Uuid,
+    subscription_id: Uuid,
+    event_id: Option<Uuid>,
+    event_type: String,
+    payload: String,
+    status: String,
+    attempts: i32,
+    next_attempt_at: Option<DateTime<Utc>>,
+    last_status_code: Option<i32>,
+    last_error: Option<String>,
+    created_at: DateTime<Utc>,
+    delivered_at: Option<DateTime<Utc>>,
+}
+
+impl From<WebhookSubscriptionModel> for WebhookSubscription {
+    fn from(model: WebhookSubscriptionModel) -> Self {
+        WebhookSubscription {
+            id: model.id,
+            name: model.name,
+            url: model.url,
+            secret: model.secret,
+            events: model.events.iter().filter_map(|e| WebhookEventType::parse(e)).collect(),
+            enabled: model.enabled,
+            created_by: model.created_by,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl From<WebhookSubscription> for WebhookSubscriptionModel {
+    fn from(subscription: WebhookSubscription) -> Self {
+        WebhookSubscriptionModel {
+            id: subscription.id,
+            name: subscription.name,
+            url: subscription.url,
+            secret: subscription.secret,
+            events: subscription.events.iter().map(|e| e.as_str().to_string()).collect(),
+            enabled: subscription.enabled,
+            created_by: subscription.created_by,
+            created_at: subscription.created_at,
+            updated_at: subscription.updated_at,
+        }
+    }
+}
+
+impl From<WebhookDeliveryModel> for WebhookDelivery {
+    fn from(model: WebhookDeliveryModel) -> Self {
+        WebhookDelivery {
+            id: model.id,
+            subscription_id: model.subscription_id,
+            event_id: model.event_id,
+            event_type: WebhookEventType::parse(&model.event_type).unwrap_or(WebhookEventType::Test),
+            payload: serde_json::from_str(&model.payload).unwrap_or_default(),
+            status: WebhookDeliveryStatus::parse(&model.status).unwrap_or(WebhookDeliveryStatus::Failed),
+            attempts: model.attempts,
+            next_attempt_at: model.next_attempt_at,
+            last_status_code: model.last_status_code,
+            last_error: model.last_error,
+            created_at: model.created_at,
+            delivered_at: model.delivered_at,
+        }
+    }
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryModel {
+    fn from(delivery: WebhookDelivery) -> Self {
+        WebhookDeliveryModel {
+            id: delivery.id,
+            subscription_id: delivery.subscription_id,
+            event_id: delivery.event_id,
+            event_type: delivery.event_type.as_str().to_string(),
+            payload: delivery.payload.to_string(),
+            status: delivery.status.as_str().to_string(),
+            attempts: delivery.attempts,
+            next_attempt_at: delivery.next_attempt_at,
+            last_status_code: delivery.last_status_code,
+            last_error: delivery.last_error,
+            created_at: delivery.created_at,
+            delivered_at: delivery.delivered_at,
+        }
+    }
+}
+
+pub struct DieselWebhookRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl DieselWebhookRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for DieselWebhookRepository {
+    async fn create(&self, subscription: WebhookSubscription) -> Result<WebhookSubscription, WebhookRepositoryError> {
+        let mut conn = self.pool.get()
+            .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = WebhookSubscriptionModel::from(subscription);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::insert_into(webhook_subscriptions::table)
+                .values(&model)
+                .returning(WebhookSubscriptionModel::as_returning())
+                .get_result::<WebhookSubscriptionModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.into())
+    }
+
+    async fn find_by_id(&self, subscription_id: Uuid) -> Result<Option<WebhookSubscription>, WebhookRepositoryError> {
+        use crate::infrastructure::database::schema::webhook_subscriptions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            webhook_subscriptions
+                .filter(id.eq(subscription_id))
+                .select(WebhookSubscriptionModel::as_select())
+                .first::<WebhookSubscriptionModel>(&mut conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn list(&self) -> Result<Vec<WebhookSubscription>, WebhookRepositoryError> {
+        use crate::infrastructure::database::schema::webhook_subscriptions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            webhook_subscriptions
+                .order(created_at.desc())
+                .select(WebhookSubscriptionModel::as_select())
+                .load::<WebhookSubscriptionModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn list_enabled(&self) -> Result<Vec<WebhookSubscription>, WebhookRepositoryError> {
+        use crate::infrastructure::database::schema::webhook_subscriptions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            webhook_subscriptions
+                .filter(enabled.eq(true))
+                .select(WebhookSubscriptionModel::as_select())
+                .load::<WebhookSubscriptionModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn update(&self, subscription: WebhookSubscription) -> Result<WebhookSubscription, WebhookRepositoryError> {
+        use crate::infrastructure::database::schema::webhook_subscriptions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = WebhookSubscriptionModel::from(subscription);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::update(webhook_subscriptions.filter(id.eq(model.id)))
+                .set(&model)
+                .returning(WebhookSubscriptionModel::as_returning())
+                .get_result::<WebhookSubscriptionModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.into())
+    }
+
+    async fn delete(&self, subscription_id: Uuid) -> Result<bool, WebhookRepositoryError> {
+        use crate::infrastructure::database::schema::webhook_subscriptions::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        // As entregas são removidas pelo ON DELETE CASCADE
+        let deleted = tokio::task::spawn_blocking(move || {
+            diesel::delete(webhook_subscriptions.filter(id.eq(subscription_id))).execute(&mut conn)
+        })
+        .await
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deleted > 0)
+    }
+
+    async fn create_delivery(&self, delivery: WebhookDelivery) -> Result<bool, WebhookRepositoryError> {
+        use crate::infrastructure::database::schema::webhook_deliveries::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = WebhookDeliveryModel::from(delivery);
+
+        let inserted = tokio::task::spawn_blocking(move || {
+            diesel::insert_into(webhook_deliveries)
+                .values(&model)
+                .on_conflict((subscription_id, event_id))
+                .do_nothing()
+                .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(inserted > 0)
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, WebhookRepositoryError> {
+        use crate::infrastructure::database::schema::webhook_deliveries::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        let results = tokio::task::spawn_blocking(move || {
+            conn.transaction::<_, diesel::result::Error, _>(|conn| {
+                // SKIP LOCKED: entregas reservadas por outra instância ficam de fora
+                let due_ids = webhook_deliveries
+                    .filter(status.eq(WebhookDeliveryStatus::Pending.as_str()))
+                    .filter(next_attempt_at.le(now))
+                    .order(next_attempt_at.asc())
+                    .limit(limit)
+                    .select(id)
+                    .for_update()
+                    .skip_locked()
+                    .load::<Uuid>(conn)?;
+
+                diesel::update(webhook_deliveries.filter(id.eq_any(due_ids)))
+                    .set(next_attempt_at.eq(Some(lease_until)))
+                    .returning(WebhookDeliveryModel::as_returning())
+                    .get_results::<WebhookDeliveryModel>(conn)
+            })
+        })
+        .await
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(results.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn update_delivery(&self, delivery: WebhookDelivery) -> Result<WebhookDelivery, WebhookRepositoryError> {
+        use crate::infrastructure::database::schema::webhook_deliveries::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        let model = WebhookDeliveryModel::from(delivery);
+
+        let result = tokio::task::spawn_blocking(move || {
+            diesel::update(webhook_deliveries.filter(id.eq(model.id)))
+                .set(&model)
+                .returning(WebhookDeliveryModel::as_returning())
+                .get_result::<WebhookDeliveryModel>(&mut conn)
+        })
+        .await
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.into())
+    }
+
+    async fn list_deliveries(
+        &self,
+        owner_subscription_id: Uuid,
+        page: i64,
+        per_page: i64,
+    ) -> Result<(Vec<WebhookDelivery>, i64), WebhookRepositoryError> {
+        use crate::infrastructure::database::schema::webhook_deliveries::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        let (items, total) = tokio::task::spawn_blocking(move || {
+            let total = webhook_deliveries
+                .filter(subscription_id.eq(owner_subscription_id))
+                .count()
+                .get_result::<i64>(&mut conn)?;
+            let items = webhook_deliveries
+                .filter(subscription_id.eq(owner_subscription_id))
+                .order((created_at.desc(), id.desc()))
+                .limit(per_page)
+                .offset((page - 1) * per_page)
+                .select(WebhookDeliveryModel::as_select())
+                .load::<WebhookDeliveryModel>(&mut conn)?;
+
+            Ok::<_, diesel::result::Error>((items, total))
+        })
+        .await
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok((items.into_iter().map(|model| model.into()).collect(), total))
+    }
+
+    async fn delete_finished_deliveries_before(&self, before: DateTime<Utc>) -> Result<usize, WebhookRepositoryError> {
+        use crate::infrastructure::database::schema::webhook_deliveries::dsl::*;
+
+        let mut conn = self.pool.get()
+            .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        let deleted = tokio::task::spawn_blocking(move || {
+            diesel::delete(
+                webhook_deliveries
+                    .filter(status.ne(WebhookDeliveryStatus::Pending.as_str()))
+                    .filter(created_at.lt(before)),
+            )
+            .execute(&mut conn)
+        })
+        .await
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?
+        .map_err(|e| WebhookRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(deleted)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::domain::entities::alert::AlertRule;
+use crate::domain::repositories::alert_rule_repository::{AlertRuleRepository, AlertRuleRepositoryError};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do AlertRuleRepository para testes
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemoryAlertRuleRepository {
+    rules: Arc<RwLock<HashMap<Uuid, AlertRule>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryAlertRuleRepository {
+    pub fn new() -> Self {
+        Self {
+            rules: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+impl Default for InMemoryAlertRuleRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl AlertRuleRepository for InMemoryAlertRuleRepository {
+    async fn create(&self, rule: AlertRule) -> Result<AlertRule, AlertRuleRepositoryError> {
+        let mut rules = self.rules.write().await;
+        rules.insert(rule.id, rule.clone());
+        Ok(rule)
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<AlertRule>, AlertRuleRepositoryError> {
+        let rules = self.rules.read().await;
+        Ok(rules.get(&id).filter(|rule| rule.user_id == user_id).cloned())
+    }
+
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<AlertRule>, AlertRuleRepositoryError> {
+        let rules = self.rules.read().await;
+        let mut result: Vec<AlertRule> = rules.values().filter(|rule| rule.user_id == user_id).cloned().collect();
+        result.sort_by(|a, b| a.name.cmp(&b.name).then(a.created_at.cmp(&b.created_at)));
+        Ok(result)
+    }
+
+    async fn find_candidates(&self, feed_id: Uuid, category_id: Option<Uuid>) -> Result<Vec<AlertRule>, AlertRuleRepositoryError> {
+        let rules = self.rules.read().await;
+        Ok(rules
+            .values()
+            .filter(|rule| {
+                rule.enabled
+                    && rule.feed_id.is_none_or(|id| id == feed_id)
+                    && rule.category_id.is_none_or(|id| Some(id) == category_id)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn update(&self, rule: AlertRule) -> Result<AlertRule, AlertRuleRepositoryError> {
+        let mut rules = self.rules.write().await;
+        rules.insert(rule.id, rule.clone());
+        Ok(rule)
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, AlertRuleRepositoryError> {
+        let mut rules = self.rules.write().await;
+
+        match rules.get(&id) {
+            Some(rule) if rule.user_id == user_id => {
+                rules.remove(&id);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+}
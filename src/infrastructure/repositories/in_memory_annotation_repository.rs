@@ -0,0 +1,86 @@
+use crate::domain::entities::annotation::Annotation;
+use crate::domain::repositories::annotation_repository::{AnnotationRepository, AnnotationRepositoryError};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do AnnotationRepository para testes
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemoryAnnotationRepository {
+    annotations: Arc<RwLock<HashMap<Uuid, Annotation>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryAnnotationRepository {
+    pub fn new() -> Self {
+        Self {
+            annotations: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+impl Default for InMemoryAnnotationRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl AnnotationRepository for InMemoryAnnotationRepository {
+    async fn create(&self, annotation: Annotation) -> Result<Annotation, AnnotationRepositoryError> {
+        let mut annotations = self.annotations.write().await;
+        annotations.insert(annotation.id, annotation.clone());
+        Ok(annotation)
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Annotation>, AnnotationRepositoryError> {
+        let annotations = self.annotations.read().await;
+        Ok(annotations
+            .get(&id)
+            .filter(|annotation| annotation.user_id == user_id)
+            .cloned())
+    }
+
+    async fn list_by_article(&self, user_id: Uuid, article_id: Uuid) -> Result<Vec<Annotation>, AnnotationRepositoryError> {
+        let annotations = self.annotations.read().await;
+        let mut result: Vec<Annotation> = annotations
+            .values()
+            .filter(|annotation| annotation.user_id == user_id && annotation.article_id == article_id)
+            .cloned()
+            .collect();
+        // Como o NULLS FIRST do Postgres: notas sem âncora antes dos destaques
+        result.sort_by_key(|annotation| (annotation.anchor.as_ref().map(|anchor| anchor.start), annotation.created_at));
+        Ok(result)
+    }
+
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<Annotation>, AnnotationRepositoryError> {
+        let annotations = self.annotations.read().await;
+        let mut result: Vec<Annotation> = annotations
+            .values()
+            .filter(|annotation| annotation.user_id == user_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|annotation| annotation.created_at);
+        Ok(result)
+    }
+
+    async fn update(&self, annotation: Annotation) -> Result<Annotation, AnnotationRepositoryError> {
+        let mut annotations = self.annotations.write().await;
+        annotations.insert(annotation.id, annotation.clone());
+        Ok(annotation)
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, AnnotationRepositoryError> {
+        let mut annotations = self.annotations.write().await;
+
+        if annotations.get(&id).is_some_and(|annotation| annotation.user_id == user_id) {
+            annotations.remove(&id);
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::domain::entities::api_key::ApiKey;
+use crate::domain::repositories::api_key_repository::{ApiKeyRepository, ApiKeyRepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do ApiKeyRepository para testes
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemoryApiKeyRepository {
+    api_keys: Arc<RwLock<HashMap<Uuid, ApiKey>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryApiKeyRepository {
+    pub fn new() -> Self {
+        Self {
+            api_keys: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+impl Default for InMemoryApiKeyRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for InMemoryApiKeyRepository {
+    async fn create(&self, api_key: ApiKey) -> Result<ApiKey, ApiKeyRepositoryError> {
+        let mut api_keys = self.api_keys.write().await;
+
+        if api_keys.values().any(|existing| existing.prefix == api_key.prefix) {
+            return Err(ApiKeyRepositoryError::PrefixAlreadyExists);
+        }
+
+        api_keys.insert(api_key.id, api_key.clone());
+        Ok(api_key)
+    }
+
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>, ApiKeyRepositoryError> {
+        let api_keys = self.api_keys.read().await;
+        Ok(api_keys.values().find(|api_key| api_key.prefix == prefix).cloned())
+    }
+
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<ApiKey>, ApiKeyRepositoryError> {
+        let api_keys = self.api_keys.read().await;
+        let mut result: Vec<ApiKey> = api_keys
+            .values()
+            .filter(|api_key| api_key.user_id == user_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|api_key| std::cmp::Reverse(api_key.created_at));
+        Ok(result)
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiKeyRepositoryError> {
+        let mut api_keys = self.api_keys.write().await;
+
+        match api_keys.get(&id) {
+            Some(api_key) if api_key.user_id == user_id => {
+                api_keys.remove(&id);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<(), ApiKeyRepositoryError> {
+        let mut api_keys = self.api_keys.write().await;
+        if let Some(api_key) = api_keys.get_mut(&id) {
+            api_key.last_used_at = Some(used_at);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use crate::domain::entities::webhook::ArticleEvent;
+use crate::domain::repositories::article_event_repository::{ArticleEventRepository, ArticleEventRepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do ArticleEventRepository para testes. Para receber os eventos
+/// gravados pelo repositório de artigos, use `InMemoryArticleRepository::events`
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemoryArticleEventRepository {
+    events: Arc<RwLock<Vec<ArticleEvent>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryArticleEventRepository {
+    pub fn new() -> Self {
+        Self::shared(Arc::new(RwLock::new(Vec::new())))
+    }
+
+    pub(crate) fn shared(events: Arc<RwLock<Vec<ArticleEvent>>>) -> Self {
+        Self { events }
+    }
+
+    /// Todos os eventos gravados, distribuídos ou não
+    pub async fn all(&self) -> Vec<ArticleEvent> {
+        self.events.read().await.clone()
+    }
+}
+
+impl Default for InMemoryArticleEventRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ArticleEventRepository for InMemoryArticleEventRepository {
+    async fn list_pending(&self, limit: i64) -> Result<Vec<ArticleEvent>, ArticleEventRepositoryError> {
+        let events = self.events.read().await;
+        Ok(events
+            .iter()
+            .filter(|event| event.processed_at.is_none())
+            .take(limit.max(0) as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn mark_processed(&self, id: Uuid, processed_at: DateTime<Utc>) -> Result<(), ArticleEventRepositoryError> {
+        let mut events = self.events.write().await;
+        if let Some(event) = events.iter_mut().find(|event| event.id == id) {
+            event.processed_at = Some(processed_at);
+        }
+        Ok(())
+    }
+
+    async fn latest_seq(&self) -> Result<i64, ArticleEventRepositoryError> {
+        let events = self.events.read().await;
+        Ok(events.iter().map(|event| event.seq).max().unwrap_or(0))
+    }
+
+    async fn list_after(&self, after: i64, limit: i64) -> Result<Vec<ArticleEvent>, ArticleEventRepositoryError> {
+        let events = self.events.read().await;
+        let mut results: Vec<ArticleEvent> = events.iter().filter(|event| event.seq > after).cloned().collect();
+        results.sort_by_key(|event| event.seq);
+        results.truncate(limit.max(0) as usize);
+        Ok(results)
+    }
+
+    async fn delete_processed_before(&self, before: DateTime<Utc>) -> Result<usize, ArticleEventRepositoryError> {
+        let mut events = self.events.write().await;
+        let count = events.len();
+        events.retain(|event| event.processed_at.is_none() || event.created_at >= before);
+        Ok(count - events.len())
+    }
+}
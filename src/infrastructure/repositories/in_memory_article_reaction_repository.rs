@@ -0,0 +1,82 @@
+use crate::domain::entities::reaction::ReactionCount;
+use crate::domain::repositories::article_reaction_repository::{ArticleReactionRepository, ArticleReactionRepositoryError};
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do ArticleReactionRepository para testes.
+/// Não conhece a tabela de artigos, então qualquer `article_id` é aceito
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemoryArticleReactionRepository {
+    /// (user_id, article_id, reação)
+    reactions: Arc<RwLock<HashSet<(Uuid, Uuid, String)>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryArticleReactionRepository {
+    pub fn new() -> Self {
+        Self {
+            reactions: Arc::new(RwLock::new(HashSet::new())),
+        }
+    }
+}
+
+impl Default for InMemoryArticleReactionRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ArticleReactionRepository for InMemoryArticleReactionRepository {
+    async fn add(&self, user_id: Uuid, article_id: Uuid, reaction: &str) -> Result<(), ArticleReactionRepositoryError> {
+        self.reactions
+            .write()
+            .await
+            .insert((user_id, article_id, reaction.to_string()));
+        Ok(())
+    }
+
+    async fn remove(&self, user_id: Uuid, article_id: Uuid, reaction: &str) -> Result<bool, ArticleReactionRepositoryError> {
+        Ok(self
+            .reactions
+            .write()
+            .await
+            .remove(&(user_id, article_id, reaction.to_string())))
+    }
+
+    async fn counts(&self, article_id: Uuid) -> Result<Vec<ReactionCount>, ArticleReactionRepositoryError> {
+        let reactions = self.reactions.read().await;
+
+        let mut totals: HashMap<&str, i64> = HashMap::new();
+        for (_, reacted_article, reaction) in reactions.iter() {
+            if *reacted_article == article_id {
+                *totals.entry(reaction.as_str()).or_default() += 1;
+            }
+        }
+
+        let mut counts: Vec<ReactionCount> = totals
+            .into_iter()
+            .map(|(reaction, count)| ReactionCount {
+                reaction: reaction.to_string(),
+                count,
+            })
+            .collect();
+        counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.reaction.cmp(&b.reaction)));
+        Ok(counts)
+    }
+
+    async fn list_by_user(&self, user_id: Uuid, article_id: Uuid) -> Result<Vec<String>, ArticleReactionRepositoryError> {
+        let reactions = self.reactions.read().await;
+        let mut mine: Vec<String> = reactions
+            .iter()
+            .filter(|(reactor, reacted_article, _)| *reactor == user_id && *reacted_article == article_id)
+            .map(|(_, _, reaction)| reaction.clone())
+            .collect();
+        mine.sort();
+        Ok(mine)
+    }
+}
@@ -1,21 +1,73 @@
-use crate::domain::entities::article::{Article, ArticleResponse, PaginatedArticles};
+use crate::domain::entities::article::{
+    Article, ArticleListFilter, ArticleRatingSummary, ArticleResponse, ArticleSort, ArticleUserState,
+    CategoryUnreadCount, FeedUnreadCount, PaginatedArticles, UnreadCounts,
+};
+use crate::domain::entities::article_export::ArticleExportCursor;
+use crate::domain::entities::ranking::CategoryEngagement;
+use crate::domain::entities::webhook::{ArticleEvent, WebhookEventType};
 use crate::domain::repositories::article_repository::{ArticleRepository, ArticleRepositoryError};
+use crate::infrastructure::repositories::in_memory_article_event_repository::InMemoryArticleEventRepository;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
+/// Estado de leitura: (user_id, article_id) -> read_at
+type ReadStates = HashMap<(Uuid, Uuid), DateTime<Utc>>;
+
 pub struct InMemoryArticleRepository {
     articles: Arc<RwLock<HashMap<Uuid, Article>>>,
+    reads: Arc<RwLock<ReadStates>>,
+    /// Notas dos usuários: (user_id, article_id) -> nota
+    ratings: Arc<RwLock<HashMap<(Uuid, Uuid), i32>>>,
+    /// Outbox dos eventos de artigos, compartilhado com `events()`
+    events: Arc<RwLock<Vec<ArticleEvent>>>,
 }
 
 impl InMemoryArticleRepository {
     pub fn new() -> Self {
         Self {
             articles: Arc::new(RwLock::new(HashMap::new())),
+            reads: Arc::new(RwLock::new(HashMap::new())),
+            ratings: Arc::new(RwLock::new(HashMap::new())),
+            events: Arc::new(RwLock::new(Vec::new())),
         }
     }
+
+    /// Repositório de eventos que lê o outbox gravado por este repositório
+    #[allow(dead_code)]
+    pub fn events(&self) -> InMemoryArticleEventRepository {
+        InMemoryArticleEventRepository::shared(self.events.clone())
+    }
+
+    /// Grava o evento no outbox atribuindo o próximo `seq`, como o BIGSERIAL do banco
+    async fn record_event(&self, mut event: ArticleEvent) {
+        let mut events = self.events.write().await;
+        event.seq = events.len() as i64 + 1;
+        events.push(event);
+    }
+
+    /// Recalcula o agregado das notas e o grava no artigo, como a transação do repositório Diesel
+    async fn refresh_rating_summary(&self, article_id: Uuid) -> Result<ArticleRatingSummary, ArticleRepositoryError> {
+        let ratings: Vec<i32> = self
+            .ratings
+            .read()
+            .await
+            .iter()
+            .filter(|((_, rated_article), _)| *rated_article == article_id)
+            .map(|(_, rating)| *rating)
+            .collect();
+        let summary = ArticleRatingSummary::from_ratings(&ratings);
+
+        let mut articles = self.articles.write().await;
+        let article = articles.get_mut(&article_id).ok_or(ArticleRepositoryError::NotFound)?;
+        article.user_rating_avg = summary.average;
+        article.user_rating_count = summary.count;
+
+        Ok(summary)
+    }
 }
 
 impl Default for InMemoryArticleRepository {
@@ -28,26 +80,76 @@ impl Default for InMemoryArticleRepository {
 impl ArticleRepository for InMemoryArticleRepository {
     async fn create(&self, article: Article) -> Result<Article, ArticleRepositoryError> {
         let mut articles = self.articles.write().await;
+        // Como o índice único em `link`
+        if articles.values().any(|existing| existing.link == article.link) {
+            return Err(ArticleRepositoryError::LinkAlreadyExists);
+        }
         articles.insert(article.id, article.clone());
         Ok(article)
     }
 
+    async fn create_batch(&self, batch: Vec<Article>) -> Result<Vec<Article>, ArticleRepositoryError> {
+        let mut articles = self.articles.write().await;
+        let mut links: std::collections::HashSet<String> = articles.values().map(|article| article.link.clone()).collect();
+
+        let inserted: Vec<Article> = batch.into_iter().filter(|article| links.insert(article.link.clone())).collect();
+        for article in &inserted {
+            articles.insert(article.id, article.clone());
+        }
+        Ok(inserted)
+    }
+
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Article>, ArticleRepositoryError> {
         let articles = self.articles.read().await;
         Ok(articles.get(&id).cloned())
     }
 
     async fn list(&self, page: i64, per_page: i64) -> Result<PaginatedArticles, ArticleRepositoryError> {
+        self.list_filtered(&ArticleListFilter::default(), page, per_page).await
+    }
+
+    async fn list_filtered(
+        &self,
+        filter: &ArticleListFilter,
+        page: i64,
+        per_page: i64,
+    ) -> Result<PaginatedArticles, ArticleRepositoryError> {
         if page < 1 || per_page < 1 {
             return Err(ArticleRepositoryError::InvalidPagination);
         }
 
         let articles = self.articles.read().await;
-        let total = articles.len() as i64;
+        let reads = self.reads.read().await;
+        let read_at = |article: &Article| {
+            filter
+                .reader_id
+                .and_then(|reader_id| reads.get(&(reader_id, article.id)).copied())
+        };
         
         // Converte para Vec e ordena por data de publicação (mais recente primeiro)
-        let mut articles_vec: Vec<Article> = articles.values().cloned().collect();
-        articles_vec.sort_by(|a, b| b.pub_date.cmp(&a.pub_date));
+        let mut articles_vec: Vec<Article> = articles
+            .values()
+            .filter(|article| filter.matches(article))
+            .filter(|article| !filter.unread_only || filter.reader_id.is_none() || read_at(article).is_none())
+            .cloned()
+            .collect();
+        // Mesma ordem do Postgres: notas ausentes por último e, no empate, os mais recentes primeiro
+        match filter.sort {
+            ArticleSort::Recent => articles_vec.sort_by_key(|a| std::cmp::Reverse(a.pub_date)),
+            ArticleSort::Rate => {
+                articles_vec.sort_by_key(|a| (a.rate.is_none(), std::cmp::Reverse(a.rate), std::cmp::Reverse(a.pub_date)))
+            }
+            ArticleSort::UserRating => articles_vec.sort_by(|a, b| {
+                let by_rating = match (a.user_rating_avg, b.user_rating_avg) {
+                    (Some(x), Some(y)) => y.total_cmp(&x),
+                    (Some(_), None) => std::cmp::Ordering::Less,
+                    (None, Some(_)) => std::cmp::Ordering::Greater,
+                    (None, None) => std::cmp::Ordering::Equal,
+                };
+                by_rating.then(b.pub_date.cmp(&a.pub_date))
+            }),
+        }
+        let total = articles_vec.len() as i64;
         
         // Calcula paginação
         let total_pages = (total as f64 / per_page as f64).ceil() as i64;
@@ -59,7 +161,13 @@ impl ArticleRepository for InMemoryArticleRepository {
             .into_iter()
             .skip(skip)
             .take(take)
-            .map(ArticleResponse::from)
+            .map(|article| {
+                let state = filter.reader_id.map(|_| ArticleUserState::from_read_at(read_at(&article)));
+                ArticleResponse {
+                    user_state: state,
+                    ..ArticleResponse::from(article)
+                }
+            })
             .collect();
         
         Ok(PaginatedArticles {
@@ -71,12 +179,40 @@ impl ArticleRepository for InMemoryArticleRepository {
         })
     }
 
+    async fn export_chunk(
+        &self,
+        filter: &ArticleListFilter,
+        after: Option<ArticleExportCursor>,
+        limit: i64,
+    ) -> Result<Vec<Article>, ArticleRepositoryError> {
+        let articles = self.articles.read().await;
+        let reads = self.reads.read().await;
+        let unread = |article: &Article| match (filter.reader_id, filter.unread_only) {
+            (Some(reader_id), true) => !reads.contains_key(&(reader_id, article.id)),
+            _ => true,
+        };
+
+        let mut chunk: Vec<Article> = articles
+            .values()
+            .filter(|article| filter.matches(article) && unread(article))
+            .filter(|article| after.is_none_or(|cursor| cursor.precedes(article)))
+            .cloned()
+            .collect();
+        chunk.sort_by_key(|article| std::cmp::Reverse((article.pub_date, article.id)));
+        chunk.truncate(limit.max(0) as usize);
+
+        Ok(chunk)
+    }
+
     async fn update(&self, article: Article) -> Result<Article, ArticleRepositoryError> {
         let mut articles = self.articles.write().await;
         
         if !articles.contains_key(&article.id) {
             return Err(ArticleRepositoryError::NotFound);
         }
+        if articles.values().any(|existing| existing.id != article.id && existing.link == article.link) {
+            return Err(ArticleRepositoryError::LinkAlreadyExists);
+        }
         
         articles.insert(article.id, article.clone());
         Ok(article)
@@ -88,10 +224,161 @@ impl ArticleRepository for InMemoryArticleRepository {
         if articles.remove(&id).is_none() {
             return Err(ArticleRepositoryError::NotFound);
         }
+
+        // Como o ON DELETE CASCADE de article_reads e article_ratings
+        self.reads.write().await.retain(|(_, article_id), _| *article_id != id);
+        self.ratings.write().await.retain(|(_, article_id), _| *article_id != id);
         
         Ok(())
     }
 
+    async fn create_with_event(&self, article: Article) -> Result<Article, ArticleRepositoryError> {
+        let article = self.create(article).await?;
+        self.record_event(ArticleEvent::new(WebhookEventType::ArticleCreated, &article)).await;
+        Ok(article)
+    }
+
+    async fn update_with_event(&self, article: Article, event_type: WebhookEventType) -> Result<Article, ArticleRepositoryError> {
+        let article = self.update(article).await?;
+        self.record_event(ArticleEvent::new(event_type, &article)).await;
+        Ok(article)
+    }
+
+    async fn delete_with_event(&self, id: Uuid) -> Result<(), ArticleRepositoryError> {
+        let article = self.find_by_id(id).await?.ok_or(ArticleRepositoryError::NotFound)?;
+        self.delete(id).await?;
+        self.record_event(ArticleEvent::new(WebhookEventType::ArticleDeleted, &article)).await;
+        Ok(())
+    }
+
+    async fn mark_read(&self, user_id: Uuid, article_id: Uuid, read_at: DateTime<Utc>) -> Result<(), ArticleRepositoryError> {
+        if !self.articles.read().await.contains_key(&article_id) {
+            return Err(ArticleRepositoryError::NotFound);
+        }
+
+        let mut reads = self.reads.write().await;
+        reads.entry((user_id, article_id)).or_insert(read_at);
+        Ok(())
+    }
+
+    async fn mark_unread(&self, user_id: Uuid, article_id: Uuid) -> Result<(), ArticleRepositoryError> {
+        if !self.articles.read().await.contains_key(&article_id) {
+            return Err(ArticleRepositoryError::NotFound);
+        }
+
+        let mut reads = self.reads.write().await;
+        reads.remove(&(user_id, article_id));
+        Ok(())
+    }
+
+    async fn mark_read_matching(
+        &self,
+        user_id: Uuid,
+        filter: &ArticleListFilter,
+        read_at: DateTime<Utc>,
+    ) -> Result<u64, ArticleRepositoryError> {
+        let articles = self.articles.read().await;
+        let mut reads = self.reads.write().await;
+        let mut marked = 0;
+
+        for article in articles.values().filter(|article| filter.matches(article)) {
+            if let std::collections::hash_map::Entry::Vacant(entry) = reads.entry((user_id, article.id)) {
+                entry.insert(read_at);
+                marked += 1;
+            }
+        }
+
+        Ok(marked)
+    }
+
+    async fn unread_counts(&self, user_id: Uuid, feed_ids: &[Uuid]) -> Result<UnreadCounts, ArticleRepositoryError> {
+        let articles = self.articles.read().await;
+        let reads = self.reads.read().await;
+
+        let mut by_feed: HashMap<Uuid, i64> = HashMap::new();
+        let mut by_category: HashMap<Option<Uuid>, i64> = HashMap::new();
+
+        for article in articles.values().filter(|article| {
+            feed_ids.contains(&article.feed_id) && !reads.contains_key(&(user_id, article.id))
+        }) {
+            *by_feed.entry(article.feed_id).or_default() += 1;
+            *by_category.entry(article.category_id).or_default() += 1;
+        }
+
+        let mut feeds: Vec<FeedUnreadCount> = by_feed
+            .into_iter()
+            .map(|(feed_id, unread)| FeedUnreadCount { feed_id, unread })
+            .collect();
+        feeds.sort_by_key(|count| count.feed_id);
+        let mut categories: Vec<CategoryUnreadCount> = by_category
+            .into_iter()
+            .map(|(category_id, unread)| CategoryUnreadCount { category_id, unread })
+            .collect();
+        categories.sort_by_key(|count| count.category_id);
+
+        Ok(UnreadCounts {
+            total: feeds.iter().map(|count| count.unread).sum(),
+            feeds,
+            categories,
+        })
+    }
+
+    async fn rate(
+        &self,
+        user_id: Uuid,
+        article_id: Uuid,
+        rating: i32,
+        _rated_at: DateTime<Utc>,
+    ) -> Result<ArticleRatingSummary, ArticleRepositoryError> {
+        if !self.articles.read().await.contains_key(&article_id) {
+            return Err(ArticleRepositoryError::NotFound);
+        }
+
+        self.ratings.write().await.insert((user_id, article_id), rating);
+        self.refresh_rating_summary(article_id).await
+    }
+
+    async fn remove_rating(&self, user_id: Uuid, article_id: Uuid) -> Result<ArticleRatingSummary, ArticleRepositoryError> {
+        if !self.articles.read().await.contains_key(&article_id) {
+            return Err(ArticleRepositoryError::NotFound);
+        }
+
+        self.ratings.write().await.remove(&(user_id, article_id));
+        self.refresh_rating_summary(article_id).await
+    }
+
+    async fn category_engagement(&self, user_id: Uuid) -> Result<Vec<CategoryEngagement>, ArticleRepositoryError> {
+        let articles = self.articles.read().await;
+        let category_of = |article_id: &Uuid| articles.get(article_id).and_then(|article| article.category_id);
+
+        let mut by_category: HashMap<Uuid, (i64, Vec<i32>)> = HashMap::new();
+        for (reader_id, article_id) in self.reads.read().await.keys() {
+            if let Some(category_id) = category_of(article_id).filter(|_| *reader_id == user_id) {
+                by_category.entry(category_id).or_default().0 += 1;
+            }
+        }
+        for ((rater_id, article_id), rating) in self.ratings.read().await.iter() {
+            if let Some(category_id) = category_of(article_id).filter(|_| *rater_id == user_id) {
+                by_category.entry(category_id).or_default().1.push(*rating);
+            }
+        }
+
+        let mut engagement: Vec<CategoryEngagement> = by_category
+            .into_iter()
+            .map(|(category_id, (reads, ratings))| {
+                let summary = ArticleRatingSummary::from_ratings(&ratings);
+                CategoryEngagement {
+                    category_id,
+                    reads,
+                    rating_count: summary.count as i64,
+                    rating_avg: summary.average,
+                }
+            })
+            .collect();
+        engagement.sort_by_key(|item| item.category_id);
+        Ok(engagement)
+    }
+
     async fn count(&self) -> Result<i64, ArticleRepositoryError> {
         let articles = self.articles.read().await;
         Ok(articles.len() as i64)
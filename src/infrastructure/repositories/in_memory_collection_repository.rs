@@ -0,0 +1,99 @@
+use crate::domain::entities::collection::Collection;
+use crate::domain::repositories::collection_repository::{CollectionRepository, CollectionRepositoryError};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do CollectionRepository para testes
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemoryCollectionRepository {
+    collections: Arc<RwLock<HashMap<Uuid, Collection>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryCollectionRepository {
+    pub fn new() -> Self {
+        Self {
+            collections: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    fn name_taken(collections: &HashMap<Uuid, Collection>, collection: &Collection) -> bool {
+        collections.values().any(|existing| {
+            existing.id != collection.id && existing.user_id == collection.user_id && existing.name == collection.name
+        })
+    }
+}
+
+impl Default for InMemoryCollectionRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CollectionRepository for InMemoryCollectionRepository {
+    async fn create(&self, collection: Collection) -> Result<Collection, CollectionRepositoryError> {
+        let mut collections = self.collections.write().await;
+
+        if Self::name_taken(&collections, &collection) {
+            return Err(CollectionRepositoryError::NameTaken);
+        }
+
+        collections.insert(collection.id, collection.clone());
+        Ok(collection)
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Collection>, CollectionRepositoryError> {
+        let collections = self.collections.read().await;
+        Ok(collections
+            .get(&id)
+            .filter(|collection| collection.user_id == user_id)
+            .cloned())
+    }
+
+    async fn find_by_share_token(&self, token: &str) -> Result<Option<Collection>, CollectionRepositoryError> {
+        let collections = self.collections.read().await;
+        Ok(collections
+            .values()
+            .find(|collection| collection.share_token.as_deref() == Some(token))
+            .cloned())
+    }
+
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<Collection>, CollectionRepositoryError> {
+        let collections = self.collections.read().await;
+        let mut result: Vec<Collection> = collections
+            .values()
+            .filter(|collection| collection.user_id == user_id)
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(result)
+    }
+
+    async fn update(&self, collection: Collection) -> Result<Collection, CollectionRepositoryError> {
+        let mut collections = self.collections.write().await;
+
+        if Self::name_taken(&collections, &collection) {
+            return Err(CollectionRepositoryError::NameTaken);
+        }
+
+        collections.insert(collection.id, collection.clone());
+        Ok(collection)
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, CollectionRepositoryError> {
+        let mut collections = self.collections.write().await;
+
+        match collections.get(&id) {
+            Some(collection) if collection.user_id == user_id => {
+                collections.remove(&id);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+}
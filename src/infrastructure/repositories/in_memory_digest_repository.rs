@@ -0,0 +1,85 @@
+use crate::domain::entities::digest::{DigestSettings, SentDigest};
+use crate::domain::repositories::digest_repository::{DigestRepository, DigestRepositoryError};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do DigestRepository para testes
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemoryDigestRepository {
+    settings: Arc<RwLock<HashMap<Uuid, DigestSettings>>>,
+    sent: Arc<RwLock<HashMap<Uuid, SentDigest>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryDigestRepository {
+    pub fn new() -> Self {
+        Self {
+            settings: Arc::new(RwLock::new(HashMap::new())),
+            sent: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+impl Default for InMemoryDigestRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DigestRepository for InMemoryDigestRepository {
+    async fn find_settings(&self, user_id: Uuid) -> Result<Option<DigestSettings>, DigestRepositoryError> {
+        Ok(self.settings.read().await.get(&user_id).cloned())
+    }
+
+    async fn save_settings(&self, settings: DigestSettings) -> Result<DigestSettings, DigestRepositoryError> {
+        self.settings.write().await.insert(settings.user_id, settings.clone());
+        Ok(settings)
+    }
+
+    async fn delete_settings(&self, user_id: Uuid) -> Result<bool, DigestRepositoryError> {
+        Ok(self.settings.write().await.remove(&user_id).is_some())
+    }
+
+    async fn list_enabled_settings(&self) -> Result<Vec<DigestSettings>, DigestRepositoryError> {
+        let settings = self.settings.read().await;
+        Ok(settings.values().filter(|settings| settings.enabled).cloned().collect())
+    }
+
+    async fn record_sent(&self, sent: SentDigest) -> Result<bool, DigestRepositoryError> {
+        let mut records = self.sent.write().await;
+
+        let exists = records
+            .values()
+            .any(|existing| existing.user_id == sent.user_id && existing.period_end == sent.period_end);
+        if exists {
+            return Ok(false);
+        }
+
+        records.insert(sent.id, sent);
+        Ok(true)
+    }
+
+    async fn remove_sent(&self, id: Uuid) -> Result<(), DigestRepositoryError> {
+        self.sent.write().await.remove(&id);
+        Ok(())
+    }
+
+    async fn list_sent(&self, user_id: Uuid, limit: i64) -> Result<Vec<SentDigest>, DigestRepositoryError> {
+        let records = self.sent.read().await;
+
+        let mut result: Vec<SentDigest> = records
+            .values()
+            .filter(|sent| sent.user_id == user_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|sent| std::cmp::Reverse(sent.period_end));
+        result.truncate(limit.max(0) as usize);
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::domain::entities::email_change_request::EmailChangeRequest;
+use crate::domain::repositories::email_change_request_repository::{
+    EmailChangeRequestRepository, EmailChangeRequestRepositoryError,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do EmailChangeRequestRepository para testes
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemoryEmailChangeRequestRepository {
+    requests: Arc<RwLock<HashMap<Uuid, EmailChangeRequest>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryEmailChangeRequestRepository {
+    pub fn new() -> Self {
+        Self {
+            requests: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+impl Default for InMemoryEmailChangeRequestRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl EmailChangeRequestRepository for InMemoryEmailChangeRequestRepository {
+    async fn replace(&self, request: EmailChangeRequest) -> Result<EmailChangeRequest, EmailChangeRequestRepositoryError> {
+        let mut requests = self.requests.write().await;
+        requests.insert(request.user_id, request.clone());
+        Ok(request)
+    }
+
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Option<EmailChangeRequest>, EmailChangeRequestRepositoryError> {
+        let requests = self.requests.read().await;
+        Ok(requests.get(&user_id).cloned())
+    }
+
+    async fn delete_by_user(&self, user_id: Uuid) -> Result<(), EmailChangeRequestRepositoryError> {
+        let mut requests = self.requests.write().await;
+        requests.remove(&user_id);
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::domain::repositories::feed_repository::{FeedRepository, FeedRepositoryError};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do FeedRepository para testes
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemoryFeedRepository {
+    /// URL (do feed ou do site) -> ID do feed
+    urls: Arc<RwLock<HashMap<String, Uuid>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryFeedRepository {
+    pub fn new() -> Self {
+        Self {
+            urls: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Cadastra um feed com os endereços informados, como o coletor faria
+    pub async fn add(&self, feed_url: &str, link: &str) -> Uuid {
+        let id = Uuid::new_v4();
+        let mut urls = self.urls.write().await;
+        urls.insert(feed_url.to_string(), id);
+        urls.insert(link.to_string(), id);
+        id
+    }
+}
+
+impl Default for InMemoryFeedRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl FeedRepository for InMemoryFeedRepository {
+    async fn find_id_by_url(&self, url: &str) -> Result<Option<Uuid>, FeedRepositoryError> {
+        Ok(self.urls.read().await.get(url).copied())
+    }
+
+    async fn exists(&self, id: Uuid) -> Result<bool, FeedRepositoryError> {
+        Ok(self.urls.read().await.values().any(|feed_id| *feed_id == id))
+    }
+}
@@ -0,0 +1,86 @@
+use crate::domain::entities::identity::{OidcAuthRequest, UserIdentity};
+use crate::domain::repositories::identity_repository::{IdentityRepository, IdentityRepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do IdentityRepository para testes
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemoryIdentityRepository {
+    identities: Arc<RwLock<HashMap<Uuid, UserIdentity>>>,
+    auth_requests: Arc<RwLock<HashMap<String, OidcAuthRequest>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryIdentityRepository {
+    pub fn new() -> Self {
+        Self {
+            identities: Arc::new(RwLock::new(HashMap::new())),
+            auth_requests: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Vínculos de um usuário (apenas para testes)
+    pub async fn identities_of(&self, user_id: Uuid) -> Vec<UserIdentity> {
+        let identities = self.identities.read().await;
+        identities
+            .values()
+            .filter(|identity| identity.user_id == user_id)
+            .cloned()
+            .collect()
+    }
+}
+
+impl Default for InMemoryIdentityRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl IdentityRepository for InMemoryIdentityRepository {
+    async fn find_identity(&self, provider: &str, subject: &str) -> Result<Option<UserIdentity>, IdentityRepositoryError> {
+        let identities = self.identities.read().await;
+        Ok(identities
+            .values()
+            .find(|identity| identity.provider == provider && identity.subject == subject)
+            .cloned())
+    }
+
+    async fn create_identity(&self, identity: UserIdentity) -> Result<UserIdentity, IdentityRepositoryError> {
+        let mut identities = self.identities.write().await;
+
+        if identities
+            .values()
+            .any(|existing| existing.provider == identity.provider && existing.subject == identity.subject)
+        {
+            return Err(IdentityRepositoryError::IdentityAlreadyLinked);
+        }
+
+        identities.insert(identity.id, identity.clone());
+        Ok(identity)
+    }
+
+    async fn touch_identity(&self, id: Uuid, at: DateTime<Utc>) -> Result<(), IdentityRepositoryError> {
+        let mut identities = self.identities.write().await;
+        if let Some(identity) = identities.get_mut(&id) {
+            identity.last_login_at = at;
+        }
+        Ok(())
+    }
+
+    async fn save_auth_request(&self, request: OidcAuthRequest) -> Result<(), IdentityRepositoryError> {
+        let mut auth_requests = self.auth_requests.write().await;
+        auth_requests.insert(request.state_hash.clone(), request);
+        Ok(())
+    }
+
+    async fn take_auth_request(&self, state_hash: &str) -> Result<Option<OidcAuthRequest>, IdentityRepositoryError> {
+        let mut auth_requests = self.auth_requests.write().await;
+        Ok(auth_requests.remove(state_hash))
+    }
+}
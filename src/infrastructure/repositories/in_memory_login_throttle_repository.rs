@@ -0,0 +1,82 @@
+use crate::domain::entities::login_throttle::{LockoutPolicy, LoginThrottle, ThrottleScope};
+use crate::domain::repositories::login_throttle_repository::{
+    LoginThrottleRepository, LoginThrottleRepositoryError,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Implementação em memória do LoginThrottleRepository para testes
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemoryLoginThrottleRepository {
+    throttles: Arc<RwLock<HashMap<(ThrottleScope, String), LoginThrottle>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryLoginThrottleRepository {
+    pub fn new() -> Self {
+        Self {
+            throttles: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+impl Default for InMemoryLoginThrottleRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl LoginThrottleRepository for InMemoryLoginThrottleRepository {
+    async fn find(
+        &self,
+        scope: ThrottleScope,
+        subject: &str,
+    ) -> Result<Option<LoginThrottle>, LoginThrottleRepositoryError> {
+        let throttles = self.throttles.read().await;
+        Ok(throttles.get(&(scope, subject.to_string())).cloned())
+    }
+
+    async fn record_failure(
+        &self,
+        scope: ThrottleScope,
+        subject: &str,
+        policy: &LockoutPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<LoginThrottle, LoginThrottleRepositoryError> {
+        let mut throttles = self.throttles.write().await;
+        let throttle = throttles
+            .entry((scope, subject.to_string()))
+            .or_insert_with(|| LoginThrottle::new(scope, subject));
+        throttle.record_failure(policy, now);
+        Ok(throttle.clone())
+    }
+
+    async fn lock(
+        &self,
+        scope: ThrottleScope,
+        subject: &str,
+        threshold: i32,
+        locked_until: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<Option<LoginThrottle>, LoginThrottleRepositoryError> {
+        let mut throttles = self.throttles.write().await;
+        Ok(throttles
+            .get_mut(&(scope, subject.to_string()))
+            .filter(|throttle| throttle.failed_attempts >= threshold)
+            .map(|throttle| {
+                throttle.apply_lockout(locked_until, now);
+                throttle.clone()
+            }))
+    }
+
+    async fn delete(&self, scope: ThrottleScope, subject: &str) -> Result<(), LoginThrottleRepositoryError> {
+        let mut throttles = self.throttles.write().await;
+        throttles.remove(&(scope, subject.to_string()));
+        Ok(())
+    }
+}
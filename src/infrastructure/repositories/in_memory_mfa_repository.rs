@@ -0,0 +1,71 @@
+use crate::domain::entities::mfa::UserMfa;
+use crate::domain::repositories::mfa_repository::{MfaRepository, MfaRepositoryError};
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do MfaRepository para testes
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemoryMfaRepository {
+    settings: Arc<RwLock<HashMap<Uuid, UserMfa>>>,
+    recovery_codes: Arc<RwLock<HashMap<Uuid, HashSet<String>>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryMfaRepository {
+    pub fn new() -> Self {
+        Self {
+            settings: Arc::new(RwLock::new(HashMap::new())),
+            recovery_codes: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Quantidade de códigos de recuperação ainda não usados (para testes)
+    pub async fn remaining_recovery_codes(&self, user_id: Uuid) -> usize {
+        let codes = self.recovery_codes.read().await;
+        codes.get(&user_id).map(|set| set.len()).unwrap_or(0)
+    }
+}
+
+impl Default for InMemoryMfaRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl MfaRepository for InMemoryMfaRepository {
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Option<UserMfa>, MfaRepositoryError> {
+        let settings = self.settings.read().await;
+        Ok(settings.get(&user_id).cloned())
+    }
+
+    async fn save(&self, mfa: UserMfa) -> Result<UserMfa, MfaRepositoryError> {
+        let mut settings = self.settings.write().await;
+        settings.insert(mfa.user_id, mfa.clone());
+        Ok(mfa)
+    }
+
+    async fn delete(&self, user_id: Uuid) -> Result<(), MfaRepositoryError> {
+        self.settings.write().await.remove(&user_id);
+        self.recovery_codes.write().await.remove(&user_id);
+        Ok(())
+    }
+
+    async fn replace_recovery_codes(&self, user_id: Uuid, code_hashes: Vec<String>) -> Result<(), MfaRepositoryError> {
+        let mut codes = self.recovery_codes.write().await;
+        codes.insert(user_id, code_hashes.into_iter().collect());
+        Ok(())
+    }
+
+    async fn consume_recovery_code(&self, user_id: Uuid, code_hash: &str) -> Result<bool, MfaRepositoryError> {
+        let mut codes = self.recovery_codes.write().await;
+        Ok(codes
+            .get_mut(&user_id)
+            .map(|set| set.remove(code_hash))
+            .unwrap_or(false))
+    }
+}
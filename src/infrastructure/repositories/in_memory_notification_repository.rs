@@ -0,0 +1,162 @@
+use crate::domain::entities::alert::{Notification, NotificationDelivery, NotificationDeliveryStatus};
+use crate::domain::repositories::notification_repository::{NotificationRepository, NotificationRepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do NotificationRepository para testes
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemoryNotificationRepository {
+    notifications: Arc<RwLock<HashMap<Uuid, Notification>>>,
+    deliveries: Arc<RwLock<HashMap<Uuid, NotificationDelivery>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryNotificationRepository {
+    pub fn new() -> Self {
+        Self {
+            notifications: Arc::new(RwLock::new(HashMap::new())),
+            deliveries: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Entregas da notificação, para conferência nos testes
+    pub async fn deliveries_of(&self, notification_id: Uuid) -> Vec<NotificationDelivery> {
+        self.deliveries
+            .read()
+            .await
+            .values()
+            .filter(|delivery| delivery.notification_id == notification_id)
+            .cloned()
+            .collect()
+    }
+}
+
+impl Default for InMemoryNotificationRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl NotificationRepository for InMemoryNotificationRepository {
+    async fn create_if_absent(
+        &self,
+        notification: Notification,
+        deliveries: Vec<NotificationDelivery>,
+    ) -> Result<bool, NotificationRepositoryError> {
+        let mut notifications = self.notifications.write().await;
+
+        let exists = notifications.values().any(|existing| {
+            existing.rule_id == notification.rule_id && existing.article_id == notification.article_id
+        });
+        if exists {
+            return Ok(false);
+        }
+
+        notifications.insert(notification.id, notification);
+        let mut stored = self.deliveries.write().await;
+        for delivery in deliveries {
+            stored.insert(delivery.id, delivery);
+        }
+        Ok(true)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Notification>, NotificationRepositoryError> {
+        Ok(self.notifications.read().await.get(&id).cloned())
+    }
+
+    async fn list_by_user(
+        &self,
+        user_id: Uuid,
+        unread_only: bool,
+        page: i64,
+        per_page: i64,
+    ) -> Result<(Vec<Notification>, i64), NotificationRepositoryError> {
+        let notifications = self.notifications.read().await;
+
+        let mut result: Vec<Notification> = notifications
+            .values()
+            .filter(|notification| notification.user_id == user_id)
+            .filter(|notification| !unread_only || notification.read_at.is_none())
+            .cloned()
+            .collect();
+        result.sort_by_key(|notification| std::cmp::Reverse(notification.created_at));
+
+        let total = result.len() as i64;
+        let offset = ((page - 1) * per_page) as usize;
+        let page_items = result.into_iter().skip(offset).take(per_page as usize).collect();
+
+        Ok((page_items, total))
+    }
+
+    async fn unread_count(&self, user_id: Uuid) -> Result<i64, NotificationRepositoryError> {
+        let notifications = self.notifications.read().await;
+        Ok(notifications
+            .values()
+            .filter(|notification| notification.user_id == user_id && notification.read_at.is_none())
+            .count() as i64)
+    }
+
+    async fn mark_read(&self, user_id: Uuid, id: Uuid, read_at: DateTime<Utc>) -> Result<bool, NotificationRepositoryError> {
+        let mut notifications = self.notifications.write().await;
+
+        match notifications.get_mut(&id) {
+            Some(notification) if notification.user_id == user_id => {
+                notification.read_at.get_or_insert(read_at);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    async fn mark_all_read(&self, user_id: Uuid, read_at: DateTime<Utc>) -> Result<u64, NotificationRepositoryError> {
+        let mut notifications = self.notifications.write().await;
+
+        let mut marked = 0;
+        for notification in notifications.values_mut() {
+            if notification.user_id == user_id && notification.read_at.is_none() {
+                notification.read_at = Some(read_at);
+                marked += 1;
+            }
+        }
+
+        Ok(marked)
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<NotificationDelivery>, NotificationRepositoryError> {
+        let mut deliveries = self.deliveries.write().await;
+
+        let mut due: Vec<&mut NotificationDelivery> = deliveries
+            .values_mut()
+            .filter(|delivery| {
+                delivery.status == NotificationDeliveryStatus::Pending
+                    && delivery.next_attempt_at.is_some_and(|at| at <= now)
+            })
+            .collect();
+        due.sort_by_key(|delivery| delivery.next_attempt_at);
+
+        Ok(due
+            .into_iter()
+            .take(limit.max(0) as usize)
+            .map(|delivery| {
+                delivery.next_attempt_at = Some(lease_until);
+                delivery.clone()
+            })
+            .collect())
+    }
+
+    async fn update_delivery(&self, delivery: NotificationDelivery) -> Result<NotificationDelivery, NotificationRepositoryError> {
+        self.deliveries.write().await.insert(delivery.id, delivery.clone());
+        Ok(delivery)
+    }
+}
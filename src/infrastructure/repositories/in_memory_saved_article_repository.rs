@@ -0,0 +1,128 @@
+use crate::domain::entities::saved_article::SavedArticle;
+use crate::domain::repositories::saved_article_repository::{SavedArticleRepository, SavedArticleRepositoryError};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do SavedArticleRepository para testes
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemorySavedArticleRepository {
+    saved: Arc<RwLock<HashMap<Uuid, SavedArticle>>>,
+}
+
+#[allow(dead_code)]
+impl InMemorySavedArticleRepository {
+    pub fn new() -> Self {
+        Self {
+            saved: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Simula o `ON DELETE SET NULL` de `saved_articles.article_id` quando o artigo é removido
+    pub async fn forget_article(&self, article_id: Uuid) {
+        let mut saved = self.saved.write().await;
+        for item in saved.values_mut().filter(|item| item.article_id == Some(article_id)) {
+            item.article_id = None;
+        }
+    }
+
+    fn sorted(mut items: Vec<SavedArticle>) -> Vec<SavedArticle> {
+        items.sort_by_key(|item| (item.position, item.created_at));
+        items
+    }
+}
+
+impl Default for InMemorySavedArticleRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SavedArticleRepository for InMemorySavedArticleRepository {
+    async fn create(&self, item: SavedArticle) -> Result<SavedArticle, SavedArticleRepositoryError> {
+        let mut saved = self.saved.write().await;
+
+        if item.article_id.is_some()
+            && saved
+                .values()
+                .any(|existing| existing.user_id == item.user_id && existing.article_id == item.article_id)
+        {
+            return Err(SavedArticleRepositoryError::AlreadySaved);
+        }
+
+        saved.insert(item.id, item.clone());
+        Ok(item)
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<SavedArticle>, SavedArticleRepositoryError> {
+        let saved = self.saved.read().await;
+        Ok(saved.get(&id).filter(|item| item.user_id == user_id).cloned())
+    }
+
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<SavedArticle>, SavedArticleRepositoryError> {
+        let saved = self.saved.read().await;
+        Ok(Self::sorted(
+            saved.values().filter(|item| item.user_id == user_id).cloned().collect(),
+        ))
+    }
+
+    async fn list_by_collection(&self, collection_id: Uuid) -> Result<Vec<SavedArticle>, SavedArticleRepositoryError> {
+        let saved = self.saved.read().await;
+        Ok(Self::sorted(
+            saved
+                .values()
+                .filter(|item| item.collection_id == Some(collection_id))
+                .cloned()
+                .collect(),
+        ))
+    }
+
+    async fn max_position(&self, user_id: Uuid) -> Result<Option<i32>, SavedArticleRepositoryError> {
+        let saved = self.saved.read().await;
+        Ok(saved
+            .values()
+            .filter(|item| item.user_id == user_id)
+            .map(|item| item.position)
+            .max())
+    }
+
+    async fn update(&self, item: SavedArticle) -> Result<SavedArticle, SavedArticleRepositoryError> {
+        let mut saved = self.saved.write().await;
+        saved.insert(item.id, item.clone());
+        Ok(item)
+    }
+
+    async fn update_positions(&self, user_id: Uuid, positions: &[(Uuid, i32)]) -> Result<(), SavedArticleRepositoryError> {
+        let mut saved = self.saved.write().await;
+        for (id, position) in positions {
+            if let Some(item) = saved.get_mut(id).filter(|item| item.user_id == user_id) {
+                item.position = *position;
+            }
+        }
+        Ok(())
+    }
+
+    async fn detach_collection(&self, collection_id: Uuid) -> Result<(), SavedArticleRepositoryError> {
+        let mut saved = self.saved.write().await;
+        for item in saved.values_mut().filter(|item| item.collection_id == Some(collection_id)) {
+            item.collection_id = None;
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, SavedArticleRepositoryError> {
+        let mut saved = self.saved.write().await;
+
+        match saved.get(&id) {
+            Some(item) if item.user_id == user_id => {
+                saved.remove(&id);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::domain::entities::session::Session;
+use crate::domain::repositories::session_repository::{SessionRepository, SessionRepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do SessionRepository para testes
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemorySessionRepository {
+    sessions: Arc<RwLock<HashMap<Uuid, Session>>>,
+}
+
+#[allow(dead_code)]
+impl InMemorySessionRepository {
+    pub fn new() -> Self {
+        Self {
+            sessions: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+impl Default for InMemorySessionRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SessionRepository for InMemorySessionRepository {
+    async fn create(&self, session: Session) -> Result<Session, SessionRepositoryError> {
+        let mut sessions = self.sessions.write().await;
+        sessions.insert(session.id, session.clone());
+        Ok(session)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Session>, SessionRepositoryError> {
+        let sessions = self.sessions.read().await;
+        Ok(sessions.get(&id).cloned())
+    }
+
+    async fn list_active_by_user(&self, user_id: Uuid, now: DateTime<Utc>) -> Result<Vec<Session>, SessionRepositoryError> {
+        let sessions = self.sessions.read().await;
+        let mut result: Vec<Session> = sessions
+            .values()
+            .filter(|session| session.user_id == user_id && session.is_active(now))
+            .cloned()
+            .collect();
+        result.sort_by_key(|session| std::cmp::Reverse(session.created_at));
+        Ok(result)
+    }
+
+    async fn revoke(&self, user_id: Uuid, id: Uuid, revoked_at: DateTime<Utc>) -> Result<bool, SessionRepositoryError> {
+        let mut sessions = self.sessions.write().await;
+
+        match sessions.get_mut(&id) {
+            Some(session) if session.user_id == user_id && session.revoked_at.is_none() => {
+                session.revoked_at = Some(revoked_at);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    async fn revoke_all(
+        &self,
+        user_id: Uuid,
+        except: Option<Uuid>,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<usize, SessionRepositoryError> {
+        let mut sessions = self.sessions.write().await;
+        let mut revoked = 0;
+
+        for session in sessions.values_mut() {
+            if session.user_id == user_id && session.revoked_at.is_none() && Some(session.id) != except {
+                session.revoked_at = Some(revoked_at);
+                revoked += 1;
+            }
+        }
+
+        Ok(revoked)
+    }
+
+    async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>) -> Result<(), SessionRepositoryError> {
+        let mut sessions = self.sessions.write().await;
+        if let Some(session) = sessions.get_mut(&id) {
+            session.last_seen_at = seen_at;
+        }
+        Ok(())
+    }
+
+    async fn delete_expired(&self, user_id: Uuid, before: DateTime<Utc>) -> Result<(), SessionRepositoryError> {
+        let mut sessions = self.sessions.write().await;
+        sessions.retain(|_, session| session.user_id != user_id || session.expires_at > before);
+        Ok(())
+    }
+}
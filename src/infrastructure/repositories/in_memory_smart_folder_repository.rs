@@ -0,0 +1,102 @@
+use crate::domain::entities::smart_folder::SmartFolder;
+use crate::domain::repositories::smart_folder_repository::{SmartFolderRepository, SmartFolderRepositoryError};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do SmartFolderRepository para testes
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemorySmartFolderRepository {
+    folders: Arc<RwLock<HashMap<Uuid, SmartFolder>>>,
+}
+
+#[allow(dead_code)]
+impl InMemorySmartFolderRepository {
+    pub fn new() -> Self {
+        Self {
+            folders: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    fn name_taken(folders: &HashMap<Uuid, SmartFolder>, folder: &SmartFolder) -> bool {
+        folders
+            .values()
+            .any(|existing| existing.id != folder.id && existing.user_id == folder.user_id && existing.name == folder.name)
+    }
+}
+
+impl Default for InMemorySmartFolderRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SmartFolderRepository for InMemorySmartFolderRepository {
+    async fn create(&self, folder: SmartFolder) -> Result<SmartFolder, SmartFolderRepositoryError> {
+        let mut folders = self.folders.write().await;
+
+        if Self::name_taken(&folders, &folder) {
+            return Err(SmartFolderRepositoryError::NameTaken);
+        }
+
+        folders.insert(folder.id, folder.clone());
+        Ok(folder)
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<SmartFolder>, SmartFolderRepositoryError> {
+        let folders = self.folders.read().await;
+        Ok(folders.get(&id).filter(|folder| folder.user_id == user_id).cloned())
+    }
+
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<SmartFolder>, SmartFolderRepositoryError> {
+        let folders = self.folders.read().await;
+        let mut result: Vec<SmartFolder> = folders
+            .values()
+            .filter(|folder| folder.user_id == user_id)
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(result)
+    }
+
+    async fn update(&self, folder: SmartFolder) -> Result<SmartFolder, SmartFolderRepositoryError> {
+        let mut folders = self.folders.write().await;
+
+        if Self::name_taken(&folders, &folder) {
+            return Err(SmartFolderRepositoryError::NameTaken);
+        }
+
+        folders.insert(folder.id, folder.clone());
+        Ok(folder)
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, SmartFolderRepositoryError> {
+        let mut folders = self.folders.write().await;
+
+        match folders.get(&id) {
+            Some(folder) if folder.user_id == user_id => {
+                folders.remove(&id);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    async fn replace_tag(&self, user_id: Uuid, source_id: Uuid, target_id: Uuid) -> Result<(), SmartFolderRepositoryError> {
+        let mut folders = self.folders.write().await;
+
+        for folder in folders.values_mut() {
+            if folder.user_id == user_id && folder.criteria.tag_id == Some(source_id) {
+                folder.criteria.tag_id = Some(target_id);
+                folder.updated_at = Utc::now();
+            }
+        }
+
+        Ok(())
+    }
+}
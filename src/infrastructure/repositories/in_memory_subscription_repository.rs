@@ -0,0 +1,96 @@
+use crate::domain::entities::subscription::Subscription;
+use crate::domain::repositories::subscription_repository::{SubscriptionRepository, SubscriptionRepositoryError};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do SubscriptionRepository para testes.
+/// Não há tabela de feeds em memória, então qualquer `feed_id` é aceito.
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemorySubscriptionRepository {
+    subscriptions: Arc<RwLock<HashMap<Uuid, Subscription>>>,
+}
+
+#[allow(dead_code)]
+impl InMemorySubscriptionRepository {
+    pub fn new() -> Self {
+        Self {
+            subscriptions: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+impl Default for InMemorySubscriptionRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SubscriptionRepository for InMemorySubscriptionRepository {
+    async fn create(&self, subscription: Subscription) -> Result<Subscription, SubscriptionRepositoryError> {
+        let mut subscriptions = self.subscriptions.write().await;
+
+        if subscriptions
+            .values()
+            .any(|existing| existing.user_id == subscription.user_id && existing.feed_id == subscription.feed_id)
+        {
+            return Err(SubscriptionRepositoryError::AlreadySubscribed);
+        }
+
+        subscriptions.insert(subscription.id, subscription.clone());
+        Ok(subscription)
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Subscription>, SubscriptionRepositoryError> {
+        let subscriptions = self.subscriptions.read().await;
+        Ok(subscriptions
+            .get(&id)
+            .filter(|subscription| subscription.user_id == user_id)
+            .cloned())
+    }
+
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<Subscription>, SubscriptionRepositoryError> {
+        let subscriptions = self.subscriptions.read().await;
+        let mut result: Vec<Subscription> = subscriptions
+            .values()
+            .filter(|subscription| subscription.user_id == user_id)
+            .cloned()
+            .collect();
+        // Mesma ordem do Postgres: pastas em ordem alfabética, sem pasta por último
+        result.sort_by(|a, b| {
+            (a.folder.is_none(), &a.folder, a.created_at).cmp(&(b.folder.is_none(), &b.folder, b.created_at))
+        });
+        Ok(result)
+    }
+
+    async fn feed_ids_by_user(&self, user_id: Uuid) -> Result<Vec<Uuid>, SubscriptionRepositoryError> {
+        let subscriptions = self.subscriptions.read().await;
+        Ok(subscriptions
+            .values()
+            .filter(|subscription| subscription.user_id == user_id)
+            .map(|subscription| subscription.feed_id)
+            .collect())
+    }
+
+    async fn update(&self, subscription: Subscription) -> Result<Subscription, SubscriptionRepositoryError> {
+        let mut subscriptions = self.subscriptions.write().await;
+        subscriptions.insert(subscription.id, subscription.clone());
+        Ok(subscription)
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, SubscriptionRepositoryError> {
+        let mut subscriptions = self.subscriptions.write().await;
+
+        match subscriptions.get(&id) {
+            Some(subscription) if subscription.user_id == user_id => {
+                subscriptions.remove(&id);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+}
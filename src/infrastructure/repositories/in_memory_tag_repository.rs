@@ -0,0 +1,182 @@
+use crate::domain::entities::tag::{Tag, TagSummary};
+use crate::domain::repositories::tag_repository::{TagRepository, TagRepositoryError};
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do TagRepository para testes.
+/// Não conhece a tabela de artigos, então qualquer `article_id` é aceito
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemoryTagRepository {
+    tags: Arc<RwLock<HashMap<Uuid, Tag>>>,
+    /// (tag_id, article_id)
+    links: Arc<RwLock<HashSet<(Uuid, Uuid)>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryTagRepository {
+    pub fn new() -> Self {
+        Self {
+            tags: Arc::new(RwLock::new(HashMap::new())),
+            links: Arc::new(RwLock::new(HashSet::new())),
+        }
+    }
+
+    fn name_taken(tags: &HashMap<Uuid, Tag>, tag: &Tag) -> bool {
+        let key = Tag::name_key(&tag.name);
+        tags.values()
+            .any(|existing| existing.id != tag.id && existing.user_id == tag.user_id && Tag::name_key(&existing.name) == key)
+    }
+
+    async fn summaries(&self, user_id: Uuid) -> Vec<TagSummary> {
+        let tags = self.tags.read().await;
+        let links = self.links.read().await;
+
+        tags.values()
+            .filter(|tag| tag.user_id == user_id)
+            .map(|tag| TagSummary {
+                id: tag.id,
+                name: tag.name.clone(),
+                article_count: links.iter().filter(|(tag_id, _)| *tag_id == tag.id).count() as i64,
+            })
+            .collect()
+    }
+}
+
+impl Default for InMemoryTagRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TagRepository for InMemoryTagRepository {
+    async fn create(&self, tag: Tag) -> Result<Tag, TagRepositoryError> {
+        let mut tags = self.tags.write().await;
+
+        if Self::name_taken(&tags, &tag) {
+            return Err(TagRepositoryError::NameTaken);
+        }
+
+        tags.insert(tag.id, tag.clone());
+        Ok(tag)
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Tag>, TagRepositoryError> {
+        let tags = self.tags.read().await;
+        Ok(tags.get(&id).filter(|tag| tag.user_id == user_id).cloned())
+    }
+
+    async fn find_by_names(&self, user_id: Uuid, names: &[String]) -> Result<Vec<Tag>, TagRepositoryError> {
+        let keys: HashSet<String> = names.iter().map(|name| Tag::name_key(name)).collect();
+        let tags = self.tags.read().await;
+        Ok(tags
+            .values()
+            .filter(|tag| tag.user_id == user_id && keys.contains(&Tag::name_key(&tag.name)))
+            .cloned()
+            .collect())
+    }
+
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<TagSummary>, TagRepositoryError> {
+        let mut summaries = self.summaries(user_id).await;
+        summaries.sort_by_key(|summary| summary.name.to_lowercase());
+        Ok(summaries)
+    }
+
+    async fn search(&self, user_id: Uuid, prefix: &str, limit: i64) -> Result<Vec<TagSummary>, TagRepositoryError> {
+        let prefix = prefix.to_lowercase();
+        let mut summaries: Vec<TagSummary> = self
+            .summaries(user_id)
+            .await
+            .into_iter()
+            .filter(|summary| summary.name.to_lowercase().starts_with(&prefix))
+            .collect();
+        summaries.sort_by(|a, b| {
+            b.article_count
+                .cmp(&a.article_count)
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        });
+        summaries.truncate(limit.max(0) as usize);
+        Ok(summaries)
+    }
+
+    async fn update(&self, tag: Tag) -> Result<Tag, TagRepositoryError> {
+        let mut tags = self.tags.write().await;
+
+        if Self::name_taken(&tags, &tag) {
+            return Err(TagRepositoryError::NameTaken);
+        }
+
+        tags.insert(tag.id, tag.clone());
+        Ok(tag)
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, TagRepositoryError> {
+        let mut tags = self.tags.write().await;
+
+        if tags.get(&id).is_none_or(|tag| tag.user_id != user_id) {
+            return Ok(false);
+        }
+
+        tags.remove(&id);
+        // Como o ON DELETE CASCADE de article_tags
+        self.links.write().await.retain(|(tag_id, _)| *tag_id != id);
+        Ok(true)
+    }
+
+    async fn merge(&self, user_id: Uuid, source_id: Uuid, target_id: Uuid) -> Result<(), TagRepositoryError> {
+        {
+            let mut links = self.links.write().await;
+            let moved: Vec<Uuid> = links
+                .iter()
+                .filter(|(tag_id, _)| *tag_id == source_id)
+                .map(|(_, article_id)| *article_id)
+                .collect();
+            for article_id in moved {
+                links.insert((target_id, article_id));
+            }
+        }
+
+        self.delete(user_id, source_id).await?;
+        Ok(())
+    }
+
+    async fn list_by_article(&self, user_id: Uuid, article_id: Uuid) -> Result<Vec<Tag>, TagRepositoryError> {
+        let tags = self.tags.read().await;
+        let links = self.links.read().await;
+
+        let mut result: Vec<Tag> = tags
+            .values()
+            .filter(|tag| tag.user_id == user_id && links.contains(&(tag.id, article_id)))
+            .cloned()
+            .collect();
+        result.sort_by_key(|tag| tag.name.to_lowercase());
+        Ok(result)
+    }
+
+    async fn set_article_tags(&self, user_id: Uuid, article_id: Uuid, tag_ids: &[Uuid]) -> Result<(), TagRepositoryError> {
+        let tags = self.tags.read().await;
+        let mut links = self.links.write().await;
+
+        links.retain(|(tag_id, linked_article)| {
+            *linked_article != article_id || tags.get(tag_id).is_none_or(|tag| tag.user_id != user_id)
+        });
+        for tag_id in tag_ids {
+            links.insert((*tag_id, article_id));
+        }
+
+        Ok(())
+    }
+
+    async fn article_ids(&self, tag_id: Uuid) -> Result<Vec<Uuid>, TagRepositoryError> {
+        let links = self.links.read().await;
+        Ok(links
+            .iter()
+            .filter(|(linked_tag, _)| *linked_tag == tag_id)
+            .map(|(_, article_id)| *article_id)
+            .collect())
+    }
+}
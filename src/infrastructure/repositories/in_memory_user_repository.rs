@@ -8,11 +8,13 @@ use uuid::Uuid;
 
 /// Implementação em memória do UserRepository para testes
 #[derive(Debug, Clone)]
+#[allow(dead_code)]
 pub struct InMemoryUserRepository {
     users: Arc<RwLock<HashMap<Uuid, User>>>,
     email_index: Arc<RwLock<HashMap<String, Uuid>>>,
 }
 
+#[allow(dead_code)]
 impl InMemoryUserRepository {
     pub fn new() -> Self {
         Self {
@@ -64,10 +66,21 @@ impl UserRepository for InMemoryUserRepository {
     }
 
     async fn update(&self, user: User) -> Result<User, UserRepositoryError> {
+        let mut email_index = self.email_index.write().await;
         let mut users = self.users.write().await;
         
-        if !users.contains_key(&user.id) {
-            return Err(UserRepositoryError::NotFound);
+        let previous_email = match users.get(&user.id) {
+            Some(existing) => existing.email.clone(),
+            None => return Err(UserRepositoryError::NotFound),
+        };
+        
+        // Mantém o índice de emails consistente quando o email muda
+        if previous_email != user.email {
+            if email_index.contains_key(&user.email) {
+                return Err(UserRepositoryError::EmailAlreadyExists);
+            }
+            email_index.remove(&previous_email);
+            email_index.insert(user.email.clone(), user.id);
         }
         
         users.insert(user.id, user.clone());
@@ -197,6 +210,47 @@ mod tests {
         assert_eq!(updated_user.name, "Updated Name");
     }
 
+    #[tokio::test]
+    async fn test_update_user_email_updates_index() {
+        let repo = InMemoryUserRepository::new();
+        let mut user = User::new(
+            "old@example.com".to_string(),
+            "hashed_password".to_string(),
+            "Test User".to_string(),
+        );
+
+        repo.create(user.clone()).await.unwrap();
+        
+        user.email = "new@example.com".to_string();
+        repo.update(user.clone()).await.unwrap();
+        
+        assert!(repo.find_by_email("old@example.com").await.unwrap().is_none());
+        assert_eq!(repo.find_by_email("new@example.com").await.unwrap().unwrap().id, user.id);
+    }
+
+    #[tokio::test]
+    async fn test_update_user_email_to_existing_email() {
+        let repo = InMemoryUserRepository::new();
+        let mut user = User::new(
+            "first@example.com".to_string(),
+            "hashed_password".to_string(),
+            "First User".to_string(),
+        );
+        let other = User::new(
+            "second@example.com".to_string(),
+            "hashed_password".to_string(),
+            "Second User".to_string(),
+        );
+
+        repo.create(user.clone()).await.unwrap();
+        repo.create(other).await.unwrap();
+        
+        user.email = "second@example.com".to_string();
+        let result = repo.update(user).await;
+        
+        assert!(matches!(result.unwrap_err(), UserRepositoryError::EmailAlreadyExists));
+    }
+
     #[tokio::test]
     async fn test_update_non_existing_user() {
         let repo = InMemoryUserRepository::new();
@@ -0,0 +1,147 @@
+use crate::domain::entities::webhook::{WebhookDelivery, WebhookDeliveryStatus, WebhookSubscription};
+use crate::domain::repositories::webhook_repository::{WebhookRepository, WebhookRepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Implementação em memória do WebhookRepository para testes
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct InMemoryWebhookRepository {
+    subscriptions: Arc<RwLock<HashMap<Uuid, WebhookSubscription>>>,
+    deliveries: Arc<RwLock<HashMap<Uuid, WebhookDelivery>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryWebhookRepository {
+    pub fn new() -> Self {
+        Self {
+            subscriptions: Arc::new(RwLock::new(HashMap::new())),
+            deliveries: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+impl Default for InMemoryWebhookRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for InMemoryWebhookRepository {
+    async fn create(&self, subscription: WebhookSubscription) -> Result<WebhookSubscription, WebhookRepositoryError> {
+        self.subscriptions.write().await.insert(subscription.id, subscription.clone());
+        Ok(subscription)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WebhookSubscription>, WebhookRepositoryError> {
+        Ok(self.subscriptions.read().await.get(&id).cloned())
+    }
+
+    async fn list(&self) -> Result<Vec<WebhookSubscription>, WebhookRepositoryError> {
+        let mut result: Vec<WebhookSubscription> = self.subscriptions.read().await.values().cloned().collect();
+        result.sort_by_key(|subscription| std::cmp::Reverse(subscription.created_at));
+        Ok(result)
+    }
+
+    async fn list_enabled(&self) -> Result<Vec<WebhookSubscription>, WebhookRepositoryError> {
+        let subscriptions = self.subscriptions.read().await;
+        Ok(subscriptions.values().filter(|subscription| subscription.enabled).cloned().collect())
+    }
+
+    async fn update(&self, subscription: WebhookSubscription) -> Result<WebhookSubscription, WebhookRepositoryError> {
+        self.subscriptions.write().await.insert(subscription.id, subscription.clone());
+        Ok(subscription)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<bool, WebhookRepositoryError> {
+        let removed = self.subscriptions.write().await.remove(&id).is_some();
+        if removed {
+            self.deliveries.write().await.retain(|_, delivery| delivery.subscription_id != id);
+        }
+        Ok(removed)
+    }
+
+    async fn create_delivery(&self, delivery: WebhookDelivery) -> Result<bool, WebhookRepositoryError> {
+        let mut deliveries = self.deliveries.write().await;
+
+        let exists = delivery.event_id.is_some()
+            && deliveries.values().any(|existing| {
+                existing.subscription_id == delivery.subscription_id && existing.event_id == delivery.event_id
+            });
+        if exists {
+            return Ok(false);
+        }
+
+        deliveries.insert(delivery.id, delivery);
+        Ok(true)
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, WebhookRepositoryError> {
+        let mut deliveries = self.deliveries.write().await;
+
+        let mut due: Vec<&mut WebhookDelivery> = deliveries
+            .values_mut()
+            .filter(|delivery| {
+                delivery.status == WebhookDeliveryStatus::Pending
+                    && delivery.next_attempt_at.is_some_and(|at| at <= now)
+            })
+            .collect();
+        due.sort_by_key(|delivery| delivery.next_attempt_at);
+
+        Ok(due
+            .into_iter()
+            .take(limit.max(0) as usize)
+            .map(|delivery| {
+                delivery.next_attempt_at = Some(lease_until);
+                delivery.clone()
+            })
+            .collect())
+    }
+
+    async fn update_delivery(&self, delivery: WebhookDelivery) -> Result<WebhookDelivery, WebhookRepositoryError> {
+        self.deliveries.write().await.insert(delivery.id, delivery.clone());
+        Ok(delivery)
+    }
+
+    async fn list_deliveries(
+        &self,
+        subscription_id: Uuid,
+        page: i64,
+        per_page: i64,
+    ) -> Result<(Vec<WebhookDelivery>, i64), WebhookRepositoryError> {
+        let deliveries = self.deliveries.read().await;
+
+        let mut result: Vec<WebhookDelivery> = deliveries
+            .values()
+            .filter(|delivery| delivery.subscription_id == subscription_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|delivery| std::cmp::Reverse(delivery.created_at));
+        let total = result.len() as i64;
+
+        let page = result
+            .into_iter()
+            .skip(((page - 1) * per_page).max(0) as usize)
+            .take(per_page.max(0) as usize)
+            .collect();
+
+        Ok((page, total))
+    }
+
+    async fn delete_finished_deliveries_before(&self, before: DateTime<Utc>) -> Result<usize, WebhookRepositoryError> {
+        let mut deliveries = self.deliveries.write().await;
+        let count = deliveries.len();
+        deliveries.retain(|_, delivery| delivery.status == WebhookDeliveryStatus::Pending || delivery.created_at >= before);
+        Ok(count - deliveries.len())
+    }
+}
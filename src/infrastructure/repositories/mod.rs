@@ -4,4 +4,43 @@ pub mod diesel_user_repository;
 pub mod in_memory_article_category_repository;
 pub mod diesel_article_category_repository;
 pub mod in_memory_article_repository;
-pub mod diesel_article_repository;
\ No newline at end of file
+pub mod diesel_article_repository;
+pub mod in_memory_login_throttle_repository;
+pub mod diesel_login_throttle_repository;
+
+pub mod in_memory_email_change_request_repository;
+pub mod diesel_email_change_request_repository;
+pub mod in_memory_mfa_repository;
+pub mod diesel_mfa_repository;
+pub mod in_memory_api_key_repository;
+pub mod diesel_api_key_repository;
+pub mod in_memory_session_repository;
+pub mod diesel_session_repository;
+pub mod in_memory_identity_repository;
+pub mod diesel_identity_repository;
+pub mod in_memory_subscription_repository;
+pub mod diesel_subscription_repository;
+pub mod in_memory_collection_repository;
+pub mod diesel_collection_repository;
+pub mod in_memory_saved_article_repository;
+pub mod diesel_saved_article_repository;
+pub mod in_memory_article_reaction_repository;
+pub mod diesel_article_reaction_repository;
+pub mod in_memory_annotation_repository;
+pub mod diesel_annotation_repository;
+pub mod in_memory_tag_repository;
+pub mod diesel_tag_repository;
+pub mod in_memory_smart_folder_repository;
+pub mod diesel_smart_folder_repository;
+pub mod in_memory_alert_rule_repository;
+pub mod diesel_alert_rule_repository;
+pub mod in_memory_notification_repository;
+pub mod diesel_notification_repository;
+pub mod in_memory_digest_repository;
+pub mod diesel_digest_repository;
+pub mod in_memory_article_event_repository;
+pub mod diesel_article_event_repository;
+pub mod in_memory_webhook_repository;
+pub mod diesel_webhook_repository;
+pub mod in_memory_feed_repository;
+pub mod diesel_feed_repository;
\ No newline at end of file
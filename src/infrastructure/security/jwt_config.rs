@@ -0,0 +1,125 @@
+use crate::application::services::jwt_keys::{JwtKey, JwtKeyError, JwtKeySet};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Segredo usado apenas quando nenhum `JWT_SECRET` é configurado em desenvolvimento
+pub const DEVELOPMENT_SECRET: &str = "your-secret-key-change-in-production";
+
+/// Valores de exemplo (código, `.env.example` e docker-compose) que nunca protegem produção
+const INSECURE_SECRETS: &[&str] = &[
+    DEVELOPMENT_SECRET,
+    "your-super-secret-jwt-key",
+    "your-super-secret-jwt-key-change-this-in-production",
+];
+
+#[derive(Debug, Error)]
+pub enum JwtConfigError {
+    #[error("JWT_SECRET is missing or uses a default value; refusing to start in '{0}' environment")]
+    InsecureSecret(String),
+
+    #[error("Failed to read JWT keys from {path}: {reason}")]
+    KeyDirectory { path: String, reason: String },
+
+    #[error("No .pem keys found in {0}")]
+    NoKeys(String),
+
+    #[error("JWT_ACTIVE_KID is required when more than one key is configured")]
+    MissingActiveKid,
+
+    #[error(transparent)]
+    InvalidKey(#[from] JwtKeyError),
+}
+
+/// Configuração das chaves de JWT lida do ambiente
+#[derive(Debug, Clone, Default)]
+pub struct JwtConfig {
+    pub environment: String,
+    pub secret: Option<String>,
+    pub keys_dir: Option<PathBuf>,
+    pub active_kid: Option<String>,
+}
+
+impl JwtConfig {
+    /// Lê `ENVIRONMENT`, `JWT_SECRET`, `JWT_KEYS_DIR` e `JWT_ACTIVE_KID`.
+    /// Sem `ENVIRONMENT` assume produção, para que um deploy esquecido não suba inseguro.
+    pub fn from_env() -> Self {
+        let non_empty = |key: &str| std::env::var(key).ok().filter(|value| !value.trim().is_empty());
+
+        Self {
+            environment: non_empty("ENVIRONMENT").unwrap_or_else(|| "production".to_string()),
+            secret: non_empty("JWT_SECRET"),
+            keys_dir: non_empty("JWT_KEYS_DIR").map(PathBuf::from),
+            active_kid: non_empty("JWT_ACTIVE_KID"),
+        }
+    }
+
+    pub fn is_development(&self) -> bool {
+        self.environment.eq_ignore_ascii_case("development")
+    }
+
+    /// Monta o conjunto de chaves: PEMs de `JWT_KEYS_DIR` (RS256/EdDSA) ou o segredo HMAC.
+    /// Com chaves assimétricas, um `JWT_SECRET` seguro continua verificando os tokens HS256 antigos.
+    pub fn build_key_set(&self) -> Result<JwtKeySet, JwtConfigError> {
+        let secure_secret = self.secret.as_deref().filter(|secret| !is_insecure_secret(secret));
+
+        if let Some(dir) = &self.keys_dir {
+            let keys = load_keys_from_dir(dir)?;
+
+            let active_kid = match (&self.active_kid, keys.as_slice()) {
+                (Some(kid), _) => kid.clone(),
+                (None, [only]) => only.kid().unwrap_or_default().to_string(),
+                (None, _) => return Err(JwtConfigError::MissingActiveKid),
+            };
+
+            let key_set = JwtKeySet::new(keys, &active_kid)?;
+            return Ok(match secure_secret {
+                Some(secret) => key_set.with_legacy_hmac(secret),
+                None => key_set,
+            });
+        }
+
+        match secure_secret {
+            Some(secret) => Ok(JwtKeySet::hmac(secret)),
+            None if self.is_development() => {
+                log::warn!("⚠️ JWT_SECRET ausente ou padrão; aceitável apenas em desenvolvimento");
+                Ok(JwtKeySet::hmac(self.secret.as_deref().unwrap_or(DEVELOPMENT_SECRET)))
+            }
+            None => Err(JwtConfigError::InsecureSecret(self.environment.clone())),
+        }
+    }
+}
+
+pub fn is_insecure_secret(secret: &str) -> bool {
+    INSECURE_SECRETS.contains(&secret.trim())
+}
+
+/// Carrega todos os arquivos `<kid>.pem` do diretório, em ordem alfabética
+fn load_keys_from_dir(dir: &Path) -> Result<Vec<JwtKey>, JwtConfigError> {
+    let directory_error = |reason: String| JwtConfigError::KeyDirectory {
+        path: dir.display().to_string(),
+        reason,
+    };
+
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map_err(|e| directory_error(e.to_string()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "pem"))
+        .collect();
+    paths.sort();
+
+    if paths.is_empty() {
+        return Err(JwtConfigError::NoKeys(dir.display().to_string()));
+    }
+
+    paths
+        .iter()
+        .map(|path| {
+            let kid = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .ok_or_else(|| directory_error(format!("invalid file name {}", path.display())))?;
+            let pem = std::fs::read(path).map_err(|e| directory_error(e.to_string()))?;
+            Ok(JwtKey::from_pem(kid, &pem)?)
+        })
+        .collect()
+}
@@ -0,0 +1,2 @@
+// Módulo de configuração de segurança (chaves de assinatura de JWT)
+pub mod jwt_config;
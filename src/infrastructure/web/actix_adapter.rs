@@ -1,6 +1,10 @@
 use actix_web::{web, HttpRequest, HttpResponse};
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 
+use super::content::{ContentError, MediaType, DOCUMENT_MEDIA_TYPES, LIST_MEDIA_TYPES};
+use super::problem::{self, ErrorCause};
+use crate::application::errors::{ApplicationError, ErrorKind};
 use super::server::{HttpMethod, Request, Response, Route, WebServer};
 
 /// Adapter para usar Actix Web como implementação do WebServer
@@ -60,8 +64,16 @@ impl ActixWebServer {
         }
     }
 
-    /// Converte nosso Response abstrato para HttpResponse do Actix
+    /// Converte nosso Response abstrato para HttpResponse do Actix.
+    /// Respostas de erro sem corpo recebem o problema genérico do status
     pub fn convert_response(response: Response) -> HttpResponse {
+        let response = if response.status_code >= 400 && response.body.is_none() {
+            let status = response.status_code;
+            response.problem(&ApplicationError::from_kind(problem::kind_for(status), problem::title_for(status)))
+        } else {
+            response
+        };
+
         let mut builder = HttpResponse::build(
             actix_web::http::StatusCode::from_u16(response.status_code)
                 .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR),
@@ -76,6 +88,94 @@ impl ActixWebServer {
             None => builder.finish(),
         }
     }
+
+    /// Responde um erro da aplicação como `application/problem+json`; a causa interna vai para o log
+    pub fn problem(error: ApplicationError) -> HttpResponse {
+        let mut response = Self::convert_response(Response::new(problem::status_for(error.kind)).problem(&error));
+        if let Some(cause) = error.cause {
+            response.extensions_mut().insert(ErrorCause(cause));
+        }
+        response
+    }
+
+    /// Problema com o código genérico do status de `response`, mantendo os headers já definidos nela
+    pub fn error(response: Response, message: &str) -> HttpResponse {
+        let error = ApplicationError::from_kind(problem::kind_for(response.status_code), message);
+        Self::convert_response(response.problem(&error))
+    }
+
+    /// Formato pedido no `Accept` entre os oferecidos; sem nenhum aceitável, o `Err` já é a resposta 406
+    pub fn negotiate(req: &HttpRequest, offered: &[MediaType]) -> Result<MediaType, HttpResponse> {
+        let accept = req.headers().get("Accept").and_then(|value| value.to_str().ok());
+
+        MediaType::negotiate(accept, offered).ok_or_else(|| {
+            let supported: Vec<&str> = offered.iter().map(|media_type| media_type.content_type()).collect();
+            Self::problem(ApplicationError::from_kind(
+                ErrorKind::NotAcceptable,
+                format!("None of the accepted media types is available (supported: {})", supported.join(", ")),
+            ))
+        })
+    }
+
+    /// Responde com `data` em JSON ou MessagePack, conforme o `Accept`
+    pub fn respond<T: Serialize>(req: &HttpRequest, response: Response, data: &T) -> HttpResponse {
+        match Self::negotiate(req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => Self::serialize(response, media_type, data, None::<&[()]>),
+            Err(not_acceptable) => not_acceptable,
+        }
+    }
+
+    /// Responde com `data` no formato já negociado; para rotas que negociam antes de executar o caso de uso
+    pub fn respond_as<T: Serialize>(media_type: MediaType, response: Response, data: &T) -> HttpResponse {
+        Self::serialize(response, media_type, data, None::<&[()]>)
+    }
+
+    /// Responde uma listagem: `data` em JSON ou MessagePack, ou apenas os itens de `rows` em CSV
+    pub fn respond_list<T: Serialize, R: Serialize>(
+        req: &HttpRequest,
+        response: Response,
+        data: &T,
+        rows: &[R],
+    ) -> HttpResponse {
+        match Self::negotiate(req, LIST_MEDIA_TYPES) {
+            Ok(media_type) => Self::serialize(response, media_type, data, Some(rows)),
+            Err(not_acceptable) => not_acceptable,
+        }
+    }
+
+    fn serialize<T: Serialize, R: Serialize>(
+        response: Response,
+        media_type: MediaType,
+        data: &T,
+        rows: Option<&[R]>,
+    ) -> HttpResponse {
+        let response = response.with_header("Vary", "Accept");
+        let result = match (media_type, rows) {
+            (MediaType::Csv, Some(rows)) => response.csv(rows),
+            _ => response.negotiated(media_type, data),
+        };
+
+        match result {
+            Ok(response) => Self::convert_response(response),
+            Err(e) => Self::problem(ApplicationError::internal(format!(
+                "Failed to serialize response as {}: {}",
+                media_type.content_type(),
+                e
+            ))),
+        }
+    }
+
+    /// Deserializa o body conforme o `Content-Type`; o `Err` já é a resposta 415 ou 400
+    pub fn parse_body<T: for<'de> Deserialize<'de>>(req: &HttpRequest, body: web::Bytes) -> Result<T, HttpResponse> {
+        Self::convert_request(req, body).body().map_err(|e| {
+            Self::problem(match e {
+                ContentError::UnsupportedMediaType(_) => {
+                    ApplicationError::from_kind(ErrorKind::UnsupportedMediaType, e.to_string())
+                }
+                _ => ApplicationError::new(ErrorKind::BadRequest, "invalid_request_body", e.to_string()),
+            })
+        })
+    }
 }
 
 impl Default for ActixWebServer {
@@ -0,0 +1,110 @@
+use actix_web::HttpRequest;
+use std::net::IpAddr;
+
+/// Faixa de endereços (`10.0.0.0/8`, `::1/128`) ou um endereço isolado
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct IpRange {
+    network: IpAddr,
+    prefix: u8,
+}
+
+impl IpRange {
+    fn parse(value: &str) -> Option<Self> {
+        let (address, prefix) = match value.split_once('/') {
+            Some((address, prefix)) => (address, Some(prefix.parse::<u8>().ok()?)),
+            None => (value, None),
+        };
+        let network: IpAddr = address.trim().parse().ok()?;
+        let max = if network.is_ipv4() { 32 } else { 128 };
+        let prefix = prefix.unwrap_or(max);
+
+        (prefix <= max).then_some(Self { network, prefix })
+    }
+
+    fn contains(&self, ip: IpAddr) -> bool {
+        match (self.network, normalize(ip)) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix as u32).unwrap_or(0);
+                u32::from(network) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix as u32).unwrap_or(0);
+                u128::from(network) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Endereços IPv4 mapeados em IPv6 (`::ffff:10.0.0.1`) são comparados como IPv4
+fn normalize(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V6(v6) => v6.to_ipv4_mapped().map(IpAddr::V4).unwrap_or(ip),
+        ip => ip,
+    }
+}
+
+/// Proxies reversos confiáveis: só deles os headers `X-Forwarded-For` são aceitos
+/// para descobrir o IP do cliente
+#[derive(Debug, Clone, Default)]
+pub struct TrustedProxies {
+    ranges: Vec<IpRange>,
+}
+
+impl TrustedProxies {
+    /// Lê a lista separada por vírgulas (IPs ou CIDRs); entradas inválidas são ignoradas
+    pub fn parse(value: &str) -> Self {
+        let ranges = value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .filter_map(|entry| {
+                let range = IpRange::parse(entry);
+                if range.is_none() {
+                    log::warn!("Ignoring invalid trusted proxy entry '{}'", entry);
+                }
+                range
+            })
+            .collect();
+
+        Self { ranges }
+    }
+
+    /// Lê `TRUSTED_PROXIES`; sem a variável nenhum proxy é confiável
+    pub fn from_env() -> Self {
+        Self::parse(&std::env::var("TRUSTED_PROXIES").unwrap_or_default())
+    }
+
+    pub fn is_trusted(&self, ip: IpAddr) -> bool {
+        self.ranges.iter().any(|range| range.contains(ip))
+    }
+
+    /// IP do cliente: o endereço da conexão ou, se ela veio de um proxy confiável, o último
+    /// endereço não confiável do `X-Forwarded-For` (cada proxy acrescenta à direita)
+    pub fn client_ip(&self, req: &HttpRequest) -> Option<String> {
+        let peer = normalize(req.peer_addr()?.ip());
+        if !self.is_trusted(peer) {
+            return Some(peer.to_string());
+        }
+
+        let forwarded: Vec<IpAddr> = req
+            .headers()
+            .get_all("X-Forwarded-For")
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(|entry| entry.trim().parse::<IpAddr>().ok().map(normalize))
+            .collect::<Option<_>>()
+            // Um valor que não é IP torna a cadeia inteira suspeita
+            .unwrap_or_default();
+
+        let client = forwarded
+            .iter()
+            .rev()
+            .find(|ip| !self.is_trusted(**ip))
+            .or_else(|| forwarded.first())
+            .copied()
+            .unwrap_or(peer);
+
+        Some(client.to_string())
+    }
+}
@@ -0,0 +1,163 @@
+use crate::application::services::csv_encoder::{csv_record, csv_text};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+/// Formatos de corpo suportados na negociação de conteúdo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaType {
+    Json,
+    MessagePack,
+    /// Apenas em respostas de listagens: uma linha por item
+    Csv,
+}
+
+/// Formatos oferecidos pelas respostas com um único recurso
+pub const DOCUMENT_MEDIA_TYPES: &[MediaType] = &[MediaType::Json, MediaType::MessagePack];
+
+/// Formatos oferecidos pelas listagens
+pub const LIST_MEDIA_TYPES: &[MediaType] = &[MediaType::Json, MediaType::MessagePack, MediaType::Csv];
+
+impl MediaType {
+    /// Valor do `Content-Type` enviado nas respostas
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            MediaType::Json => "application/json",
+            MediaType::MessagePack => "application/msgpack",
+            MediaType::Csv => "text/csv; charset=utf-8",
+        }
+    }
+
+    fn matches(&self, essence: &str) -> bool {
+        match self {
+            MediaType::Json => essence == "application/json",
+            MediaType::MessagePack => matches!(
+                essence,
+                "application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack"
+            ),
+            MediaType::Csv => essence == "text/csv",
+        }
+    }
+
+    fn essence(&self) -> &'static str {
+        self.content_type().split(';').next().unwrap_or_default()
+    }
+
+    /// Formato de um `Content-Type` (parâmetros como `charset` são ignorados)
+    pub fn from_content_type(value: &str) -> Option<Self> {
+        let essence = value.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+        [MediaType::Json, MediaType::MessagePack, MediaType::Csv]
+            .into_iter()
+            .find(|media_type| media_type.matches(&essence))
+    }
+
+    /// Escolhe, entre os formatos oferecidos, o de maior `q` no `Accept`; empates ficam com a ordem
+    /// de `offered`. Sem `Accept`, vale o primeiro oferecido; `None` quando nenhum é aceitável
+    pub fn negotiate(accept: Option<&str>, offered: &[MediaType]) -> Option<MediaType> {
+        let accept = match accept.map(str::trim).filter(|accept| !accept.is_empty()) {
+            Some(accept) => accept,
+            None => return offered.first().copied(),
+        };
+
+        let ranges: Vec<(String, f32)> = accept.split(',').filter_map(parse_media_range).collect();
+
+        let mut best: Option<(MediaType, f32)> = None;
+        for media_type in offered {
+            let quality = media_type.quality(&ranges);
+            if quality > 0.0 && best.is_none_or(|(_, best_quality)| quality > best_quality) {
+                best = Some((*media_type, quality));
+            }
+        }
+
+        best.map(|(media_type, _)| media_type)
+    }
+
+    /// `q` da faixa mais específica do `Accept` que cobre o formato
+    fn quality(&self, ranges: &[(String, f32)]) -> f32 {
+        let essence = self.essence();
+        let main_type = essence.split('/').next().unwrap_or_default();
+
+        let specificity = |range: &str| {
+            if self.matches(range) {
+                Some(2)
+            } else if range.strip_suffix("/*") == Some(main_type) {
+                Some(1)
+            } else if range == "*/*" {
+                Some(0)
+            } else {
+                None
+            }
+        };
+
+        ranges
+            .iter()
+            .filter_map(|(range, quality)| specificity(range).map(|level| (level, *quality)))
+            .max_by_key(|(level, _)| *level)
+            .map(|(_, quality)| quality)
+            .unwrap_or(0.0)
+    }
+}
+
+/// Faixa do `Accept` (`tipo/subtipo;q=0.5`); `q` ausente ou inválido vale 1
+fn parse_media_range(item: &str) -> Option<(String, f32)> {
+    let mut parts = item.split(';');
+    let range = parts.next()?.trim().to_ascii_lowercase();
+    if range.is_empty() {
+        return None;
+    }
+
+    let quality = parts
+        .filter_map(|param| param.trim().strip_prefix("q="))
+        .find_map(|value| value.trim().parse::<f32>().ok())
+        .map(|quality| quality.clamp(0.0, 1.0))
+        .unwrap_or(1.0);
+
+    Some((range, quality))
+}
+
+#[derive(Debug, Error)]
+pub enum ContentError {
+    #[error("No body present")]
+    MissingBody,
+
+    #[error("Unsupported media type: {0}")]
+    UnsupportedMediaType(String),
+
+    #[error("Invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Invalid MessagePack: {0}")]
+    MessagePackDecode(#[from] rmp_serde::decode::Error),
+
+    #[error("MessagePack serialization failed: {0}")]
+    MessagePackEncode(#[from] rmp_serde::encode::Error),
+
+    #[error("CSV is only available for lists of records")]
+    NotTabular,
+}
+
+/// Serializa os itens de uma listagem em CSV (RFC 4180): as colunas são os campos dos itens, na ordem
+/// em que aparecem; `null` vira campo vazio e valores aninhados são escritos como JSON
+pub fn to_csv<T: Serialize>(rows: &[T]) -> Result<Vec<u8>, ContentError> {
+    let rows = rows
+        .iter()
+        .map(|row| match serde_json::to_value(row)? {
+            Value::Object(object) => Ok(object),
+            _ => Err(ContentError::NotTabular),
+        })
+        .collect::<Result<Vec<Map<String, Value>>, ContentError>>()?;
+
+    let mut columns: Vec<&str> = Vec::new();
+    for key in rows.iter().flat_map(|row| row.keys()) {
+        if !columns.contains(&key.as_str()) {
+            columns.push(key);
+        }
+    }
+
+    let mut csv = csv_record(&columns);
+    for row in &rows {
+        csv.push_str(&csv_record(columns.iter().map(|column| csv_text(row.get(*column).unwrap_or(&Value::Null)))));
+    }
+
+    Ok(csv.into_bytes())
+}
@@ -1,4 +1,7 @@
 pub mod server;
+pub mod content;
+pub mod problem;
+pub mod client_ip;
 pub mod actix_adapter;
 
 pub use server::Response;
@@ -0,0 +1,82 @@
+use crate::application::errors::{ErrorKind, FieldError};
+use serde::Serialize;
+
+/// `Content-Type` das respostas de erro (RFC 7807)
+pub const PROBLEM_CONTENT_TYPE: &str = "application/problem+json";
+
+/// Causa interna de um erro, anexada à resposta para o middleware de request id registrá-la no log
+#[derive(Debug, Clone)]
+pub struct ErrorCause(pub String);
+
+/// Corpo `application/problem+json`; `instance` e `request_id` são preenchidos pelo middleware de request id
+#[derive(Debug, Serialize)]
+pub struct ProblemDetails<'a> {
+    #[serde(rename = "type")]
+    pub problem_type: &'static str,
+    pub title: &'static str,
+    pub status: u16,
+    pub detail: &'a str,
+    /// Código estável do erro
+    pub code: &'a str,
+    /// Erros de validação por campo
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub errors: &'a [FieldError],
+}
+
+/// Status HTTP de cada categoria de erro
+pub fn status_for(kind: ErrorKind) -> u16 {
+    match kind {
+        ErrorKind::BadRequest => 400,
+        ErrorKind::Unauthorized => 401,
+        ErrorKind::Forbidden => 403,
+        ErrorKind::NotFound => 404,
+        ErrorKind::MethodNotAllowed => 405,
+        ErrorKind::NotAcceptable => 406,
+        ErrorKind::Conflict => 409,
+        ErrorKind::PayloadTooLarge => 413,
+        ErrorKind::UnsupportedMediaType => 415,
+        ErrorKind::TooManyRequests => 429,
+        ErrorKind::Internal => 500,
+        ErrorKind::BadGateway => 502,
+        ErrorKind::ServiceUnavailable => 503,
+    }
+}
+
+/// Categoria de um status de erro, para as respostas que não partiram de um `ApplicationError`
+pub fn kind_for(status: u16) -> ErrorKind {
+    match status {
+        401 => ErrorKind::Unauthorized,
+        403 => ErrorKind::Forbidden,
+        404 => ErrorKind::NotFound,
+        405 => ErrorKind::MethodNotAllowed,
+        406 => ErrorKind::NotAcceptable,
+        409 => ErrorKind::Conflict,
+        413 => ErrorKind::PayloadTooLarge,
+        415 => ErrorKind::UnsupportedMediaType,
+        429 => ErrorKind::TooManyRequests,
+        502 => ErrorKind::BadGateway,
+        503 => ErrorKind::ServiceUnavailable,
+        500..=599 => ErrorKind::Internal,
+        _ => ErrorKind::BadRequest,
+    }
+}
+
+/// Frase do status, usada como `title` (o `type` é sempre `about:blank`)
+pub fn title_for(status: u16) -> &'static str {
+    match status {
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        406 => "Not Acceptable",
+        409 => "Conflict",
+        413 => "Payload Too Large",
+        415 => "Unsupported Media Type",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        _ => "Error",
+    }
+}
\ No newline at end of file
@@ -1,3 +1,6 @@
+use super::content::{self, ContentError, MediaType};
+use super::problem::{self, ProblemDetails, PROBLEM_CONTENT_TYPE};
+use crate::application::errors::ApplicationError;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -5,7 +8,7 @@ use std::sync::Arc;
 
 /// Métodos HTTP suportados
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-#[allow(dead_code)]
+#[allow(dead_code, clippy::upper_case_acronyms)]
 pub enum HttpMethod {
     GET,
     POST,
@@ -39,6 +42,30 @@ impl Request {
             ))),
         }
     }
+
+    /// Valor de um header, sem diferenciar maiúsculas no nome
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Deserializa o body conforme o `Content-Type` (JSON ou MessagePack); sem o header, o body é lido como JSON
+    pub fn body<T: for<'de> Deserialize<'de>>(&self) -> Result<T, ContentError> {
+        let media_type = match self.header("Content-Type") {
+            Some(value) => MediaType::from_content_type(value)
+                .ok_or_else(|| ContentError::UnsupportedMediaType(value.to_string()))?,
+            None => MediaType::Json,
+        };
+        let bytes = self.body.as_deref().ok_or(ContentError::MissingBody)?;
+
+        match media_type {
+            MediaType::Json => Ok(serde_json::from_slice(bytes)?),
+            MediaType::MessagePack => Ok(rmp_serde::from_slice(bytes)?),
+            MediaType::Csv => Err(ContentError::UnsupportedMediaType(media_type.content_type().to_string())),
+        }
+    }
 }
 
 /// Representa uma resposta HTTP de forma agnóstica ao framework
@@ -74,6 +101,11 @@ impl Response {
         Self::new(204)
     }
 
+    /// Resposta 304 Not Modified
+    pub fn not_modified() -> Self {
+        Self::new(304)
+    }
+
     /// Resposta 400 Bad Request
     pub fn bad_request() -> Self {
         Self::new(400)
@@ -84,6 +116,12 @@ impl Response {
         Self::new(401)
     }
 
+    /// Resposta 403 Forbidden
+    #[allow(dead_code)]
+    pub fn forbidden() -> Self {
+        Self::new(403)
+    }
+
     /// Resposta 404 Not Found
     #[allow(dead_code)]
     pub fn not_found() -> Self {
@@ -91,17 +129,41 @@ impl Response {
     }
 
     /// Resposta 409 Conflict
+    #[allow(dead_code)]
     pub fn conflict() -> Self {
         Self::new(409)
     }
 
+    /// Resposta 406 Not Acceptable
+    #[allow(dead_code)]
+    pub fn not_acceptable() -> Self {
+        Self::new(406)
+    }
+
+    /// Resposta 415 Unsupported Media Type
+    #[allow(dead_code)]
+    pub fn unsupported_media_type() -> Self {
+        Self::new(415)
+    }
+
+    /// Resposta 429 Too Many Requests
+    #[allow(dead_code)]
+    pub fn too_many_requests() -> Self {
+        Self::new(429)
+    }
+
     /// Resposta 500 Internal Server Error
     pub fn internal_error() -> Self {
         Self::new(500)
     }
 
-    /// Define um header
+    /// Resposta 502 Bad Gateway
     #[allow(dead_code)]
+    pub fn bad_gateway() -> Self {
+        Self::new(502)
+    }
+
+    /// Define um header
     pub fn with_header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.headers.insert(key.into(), value.into());
         self
@@ -114,6 +176,48 @@ impl Response {
         Ok(self)
     }
 
+    /// Define o body como MessagePack, com os campos nomeados como no JSON
+    pub fn msgpack<T: Serialize>(mut self, data: &T) -> Result<Self, ContentError> {
+        self.body = Some(rmp_serde::to_vec_named(data)?);
+        self.headers.insert("Content-Type".to_string(), MediaType::MessagePack.content_type().to_string());
+        Ok(self)
+    }
+
+    /// Define o body como CSV, uma linha por item
+    pub fn csv<T: Serialize>(mut self, rows: &[T]) -> Result<Self, ContentError> {
+        self.body = Some(content::to_csv(rows)?);
+        self.headers.insert("Content-Type".to_string(), MediaType::Csv.content_type().to_string());
+        Ok(self)
+    }
+
+    /// Define o body no formato negociado; CSV exige uma lista de itens e não é aceito aqui
+    pub fn negotiated<T: Serialize>(self, media_type: MediaType, data: &T) -> Result<Self, ContentError> {
+        match media_type {
+            MediaType::Json => Ok(self.json(data)?),
+            MediaType::MessagePack => self.msgpack(data),
+            MediaType::Csv => Err(ContentError::NotTabular),
+        }
+    }
+
+    /// Define o body como um problema RFC 7807, com o status já definido na resposta
+    pub fn problem(mut self, error: &ApplicationError) -> Self {
+        let problem = ProblemDetails {
+            problem_type: "about:blank",
+            title: problem::title_for(self.status_code),
+            status: self.status_code,
+            detail: &error.detail,
+            code: error.code,
+            errors: &error.fields,
+        };
+
+        self.body = Some(serde_json::to_vec(&problem).unwrap_or_default());
+        self.headers.insert("Content-Type".to_string(), PROBLEM_CONTENT_TYPE.to_string());
+        if let Some(seconds) = error.retry_after {
+            self.headers.insert("Retry-After".to_string(), seconds.to_string());
+        }
+        self
+    }
+
     /// Define o body como texto
     #[allow(dead_code)]
     pub fn text(mut self, text: impl Into<String>) -> Self {
@@ -0,0 +1,28 @@
+use crate::application::use_cases::import_articles::ImportArticlesUseCase;
+use std::io::Read;
+
+/// `import-articles <arquivo>` - Importa um arquivo NDJSON (ou um array JSON) de artigos; com `-`, lê da entrada padrão.
+/// O relatório vai para a saída padrão e o comando termina com erro se alguma linha falhar
+pub async fn run(use_case: &ImportArticlesUseCase, path: &str) -> std::io::Result<()> {
+    let body = if path == "-" {
+        let mut body = String::new();
+        std::io::stdin().read_to_string(&mut body)?;
+        body
+    } else {
+        std::fs::read_to_string(path)?
+    };
+
+    let records = ImportArticlesUseCase::records(&body).map_err(std::io::Error::other)?;
+    let report = use_case.execute(records).await;
+
+    println!("{}", serde_json::to_string_pretty(&report).map_err(std::io::Error::other)?);
+    eprintln!(
+        "Importação concluída: {} inseridos, {} ignorados, {} com falha",
+        report.inserted, report.skipped, report.failed
+    );
+
+    if report.failed > 0 {
+        return Err(std::io::Error::other(format!("{} linhas não foram importadas", report.failed)));
+    }
+    Ok(())
+}
\ No newline at end of file
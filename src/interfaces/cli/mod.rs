@@ -0,0 +1,2 @@
+// Comandos de linha de comando, executados no lugar do servidor HTTP
+pub mod import_articles;
\ No newline at end of file
@@ -0,0 +1,49 @@
+use crate::application::use_cases::unlock_user::UnlockUserUseCase;
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct AdminController {
+    unlock_user_use_case: Arc<UnlockUserUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl AdminController {
+    pub fn new(
+        unlock_user_use_case: Arc<UnlockUserUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            unlock_user_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// POST /admin/users/:id/unlock - Remove o bloqueio de login de uma conta
+    /// Requer papel de administrador
+    pub async fn unlock_user(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let admin = match self.auth_middleware.require_admin(&req).await {
+            Ok(admin) => admin,
+            Err(response) => return response,
+        };
+
+        let id_str = req.match_info().get("id").unwrap_or("");
+
+        let id = match Uuid::parse_str(id_str) {
+            Ok(id) => id,
+            Err(_) => {
+                return ActixWebServer::error(Response::bad_request(), "Invalid user ID");
+            }
+        };
+
+        match self.unlock_user_use_case.execute(id).await {
+            Ok(_) => {
+                log::info!("User {} unlocked by admin {}", id, admin.id);
+                ActixWebServer::convert_response(Response::no_content())
+            }
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+}
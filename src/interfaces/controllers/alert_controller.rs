@@ -0,0 +1,131 @@
+use crate::application::use_cases::create_alert_rule::CreateAlertRuleUseCase;
+use crate::application::use_cases::delete_alert_rule::DeleteAlertRuleUseCase;
+use crate::application::use_cases::list_alert_rules::ListAlertRulesUseCase;
+use crate::application::use_cases::update_alert_rule::UpdateAlertRuleUseCase;
+use crate::domain::entities::alert::AlertRuleDto;
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Regras de alerta do usuário autenticado.
+/// Aceita JWT ou chave de API com o escopo `articles:read` (listagem) ou `library:write` (alterações)
+pub struct AlertController {
+    create_alert_rule_use_case: Arc<CreateAlertRuleUseCase>,
+    list_alert_rules_use_case: Arc<ListAlertRulesUseCase>,
+    update_alert_rule_use_case: Arc<UpdateAlertRuleUseCase>,
+    delete_alert_rule_use_case: Arc<DeleteAlertRuleUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl AlertController {
+    pub fn new(
+        create_alert_rule_use_case: Arc<CreateAlertRuleUseCase>,
+        list_alert_rules_use_case: Arc<ListAlertRulesUseCase>,
+        update_alert_rule_use_case: Arc<UpdateAlertRuleUseCase>,
+        delete_alert_rule_use_case: Arc<DeleteAlertRuleUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            create_alert_rule_use_case,
+            list_alert_rules_use_case,
+            update_alert_rule_use_case,
+            delete_alert_rule_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// GET /me/alerts - Lista as regras de alerta
+    pub async fn list(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        match self.list_alert_rules_use_case.execute(user.id).await {
+            Ok(rules) => ActixWebServer::respond(&req, Response::ok(), &rules),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /me/alerts - Cria uma regra (`query`, `category_id`, `feed_id`, `min_rate`, `channels`)
+    pub async fn create(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let dto: AlertRuleDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.create_alert_rule_use_case.execute(user.id, dto).await {
+            Ok(rule) => ActixWebServer::respond_as(media_type, Response::created(), &rule),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// PUT /me/alerts/:id - Substitui a regra por completo
+    pub async fn update(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let id = match Self::rule_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        let dto: AlertRuleDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.update_alert_rule_use_case.execute(user.id, id, dto).await {
+            Ok(rule) => ActixWebServer::respond_as(media_type, Response::ok(), &rule),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// DELETE /me/alerts/:id - Remove a regra e as notificações geradas por ela
+    pub async fn delete(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let id = match Self::rule_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        match self.delete_alert_rule_use_case.execute(user.id, id).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    fn rule_id(req: &HttpRequest) -> Result<Uuid, HttpResponse> {
+        Uuid::parse_str(req.match_info().get("id").unwrap_or(""))
+            .map_err(|_| Self::error(Response::bad_request(), "Invalid alert rule ID"))
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
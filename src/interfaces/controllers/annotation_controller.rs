@@ -0,0 +1,190 @@
+use crate::application::use_cases::create_annotation::CreateAnnotationUseCase;
+use crate::application::use_cases::delete_annotation::DeleteAnnotationUseCase;
+use crate::application::use_cases::export_annotations::ExportAnnotationsUseCase;
+use crate::application::use_cases::list_annotations::ListAnnotationsUseCase;
+use crate::application::use_cases::update_annotation::UpdateAnnotationUseCase;
+use crate::domain::entities::annotation::{AnnotationExportFormat, ArticleAnnotations, CreateAnnotationDto, UpdateAnnotationDto};
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+struct ExportQuery {
+    #[serde(default)]
+    format: AnnotationExportFormat,
+}
+
+/// Destaques e notas dos usuários nos artigos. Aceita JWT ou chave de API com o escopo `articles:read`
+/// (consultas) ou `library:write` (alterações)
+pub struct AnnotationController {
+    create_annotation_use_case: Arc<CreateAnnotationUseCase>,
+    list_annotations_use_case: Arc<ListAnnotationsUseCase>,
+    update_annotation_use_case: Arc<UpdateAnnotationUseCase>,
+    delete_annotation_use_case: Arc<DeleteAnnotationUseCase>,
+    export_annotations_use_case: Arc<ExportAnnotationsUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl AnnotationController {
+    pub fn new(
+        create_annotation_use_case: Arc<CreateAnnotationUseCase>,
+        list_annotations_use_case: Arc<ListAnnotationsUseCase>,
+        update_annotation_use_case: Arc<UpdateAnnotationUseCase>,
+        delete_annotation_use_case: Arc<DeleteAnnotationUseCase>,
+        export_annotations_use_case: Arc<ExportAnnotationsUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            create_annotation_use_case,
+            list_annotations_use_case,
+            update_annotation_use_case,
+            delete_annotation_use_case,
+            export_annotations_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// GET /articles/:id/annotations - Anotações do usuário no artigo, com as âncoras no conteúdo atual
+    pub async fn list(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let article_id = match Self::path_id(&req, "id", "Invalid article ID") {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        match self.list_annotations_use_case.execute(user.id, article_id).await {
+            Ok(annotations) => ActixWebServer::respond(&req, Response::ok(), &annotations),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /articles/:id/annotations - Cria um destaque (`anchor`) e/ou uma nota (`note`)
+    pub async fn create(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let article_id = match Self::path_id(&req, "id", "Invalid article ID") {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        let dto: CreateAnnotationDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.create_annotation_use_case.execute(user.id, article_id, dto).await {
+            Ok(annotation) => ActixWebServer::respond_as(media_type, Response::created(), &annotation),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// PATCH /articles/:id/annotations/:annotation_id - Edita a nota (texto vazio a remove) e/ou a âncora
+    pub async fn update(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let (article_id, annotation_id) = match Self::ids(&req) {
+            Ok(ids) => ids,
+            Err(response) => return response,
+        };
+
+        let dto: UpdateAnnotationDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.update_annotation_use_case.execute(user.id, article_id, annotation_id, dto).await {
+            Ok(annotation) => ActixWebServer::respond_as(media_type, Response::ok(), &annotation),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// DELETE /articles/:id/annotations/:annotation_id - Remove uma anotação
+    pub async fn delete(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let (article_id, annotation_id) = match Self::ids(&req) {
+            Ok(ids) => ids,
+            Err(response) => return response,
+        };
+
+        match self.delete_annotation_use_case.execute(user.id, article_id, annotation_id).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// GET /me/annotations/export - Todas as anotações do usuário (`format=json|markdown`)
+    pub async fn export(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let query = match web::Query::<ExportQuery>::from_query(req.query_string()) {
+            Ok(query) => query.into_inner(),
+            Err(_) => return Self::error(Response::bad_request(), "Invalid export format, use json or markdown"),
+        };
+
+        let exports = match self.export_annotations_use_case.execute(user.id).await {
+            Ok(exports) => exports,
+            Err(e) => return ActixWebServer::problem(e.into()),
+        };
+
+        let response = match query.format {
+            AnnotationExportFormat::Json => Response::ok()
+                .json(&exports)
+                .unwrap_or_else(|_| Response::internal_error())
+                .with_header("Content-Disposition", "attachment; filename=\"annotations.json\""),
+            AnnotationExportFormat::Markdown => Response::ok()
+                .text(ArticleAnnotations::to_markdown(&exports))
+                .with_header("Content-Type", "text/markdown; charset=utf-8")
+                .with_header("Content-Disposition", "attachment; filename=\"annotations.md\""),
+        };
+
+        ActixWebServer::convert_response(response)
+    }
+
+    fn ids(req: &HttpRequest) -> Result<(Uuid, Uuid), HttpResponse> {
+        Ok((
+            Self::path_id(req, "id", "Invalid article ID")?,
+            Self::path_id(req, "annotation_id", "Invalid annotation ID")?,
+        ))
+    }
+
+    fn path_id(req: &HttpRequest, name: &str, message: &str) -> Result<Uuid, HttpResponse> {
+        Uuid::parse_str(req.match_info().get(name).unwrap_or(""))
+            .map_err(|_| Self::error(Response::bad_request(), message))
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
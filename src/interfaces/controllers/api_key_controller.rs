@@ -0,0 +1,93 @@
+use crate::application::use_cases::create_api_key::CreateApiKeyUseCase;
+use crate::application::use_cases::list_api_keys::ListApiKeysUseCase;
+use crate::application::use_cases::revoke_api_key::RevokeApiKeyUseCase;
+use crate::domain::entities::api_key::CreateApiKeyDto;
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Gerenciamento das chaves de API do usuário.
+/// Exige JWT: uma chave de API não pode criar ou revogar outras chaves.
+pub struct ApiKeyController {
+    create_api_key_use_case: Arc<CreateApiKeyUseCase>,
+    list_api_keys_use_case: Arc<ListApiKeysUseCase>,
+    revoke_api_key_use_case: Arc<RevokeApiKeyUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl ApiKeyController {
+    pub fn new(
+        create_api_key_use_case: Arc<CreateApiKeyUseCase>,
+        list_api_keys_use_case: Arc<ListApiKeysUseCase>,
+        revoke_api_key_use_case: Arc<RevokeApiKeyUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            create_api_key_use_case,
+            list_api_keys_use_case,
+            revoke_api_key_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// GET /users/me/api-keys - Lista as chaves do usuário (sem os segredos)
+    pub async fn list(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user_id = match self.auth_middleware.require_auth(&req).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response,
+        };
+
+        match self.list_api_keys_use_case.execute(user_id).await {
+            Ok(api_keys) => ActixWebServer::respond(&req, Response::ok(), &api_keys),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /users/me/api-keys - Cria uma chave; a chave completa só aparece nesta resposta
+    pub async fn create(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user_id = match self.auth_middleware.require_auth(&req).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response,
+        };
+
+        let dto: CreateApiKeyDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.create_api_key_use_case.execute(user_id, dto).await {
+            Ok(created) => ActixWebServer::respond_as(media_type, Response::created(), &created),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// DELETE /users/me/api-keys/:id - Revoga uma chave
+    pub async fn revoke(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user_id = match self.auth_middleware.require_auth(&req).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response,
+        };
+
+        let id = match Uuid::parse_str(req.match_info().get("id").unwrap_or("")) {
+            Ok(id) => id,
+            Err(_) => return Self::error(Response::bad_request(), "Invalid API key ID"),
+        };
+
+        match self.revoke_api_key_use_case.execute(user_id, id).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
@@ -1,6 +1,4 @@
-use crate::application::use_cases::list_article_categories::{
-    ListArticleCategoriesError, ListArticleCategoriesUseCase,
-};
+use crate::application::use_cases::list_article_categories::ListArticleCategoriesUseCase;
 use crate::infrastructure::web::Response;
 use actix_web::{web, HttpRequest, HttpResponse};
 use std::sync::Arc;
@@ -16,32 +14,19 @@ impl ArticleCategoryController {
         }
     }
 
-    pub async fn list(&self, _req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+    /// GET /categories - Lista as categorias em JSON, MessagePack ou CSV, conforme o `Accept`
+    pub async fn list(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
         // Execute use case
         match self.list_categories_use_case.execute().await {
             Ok(categories) => {
-                let response = Response::ok()
-                    .json(&serde_json::json!({
-                        "data": categories,
-                        "count": categories.len()
-                    }))
-                    .unwrap_or_else(|_| Response::internal_error());
+                let data = serde_json::json!({
+                    "data": categories,
+                    "count": categories.len()
+                });
 
-                crate::infrastructure::web::ActixWebServer::convert_response(response)
-            }
-            Err(e) => {
-                let error_message = match e {
-                    ListArticleCategoriesError::RepositoryError(msg) => msg,
-                };
-
-                let response = Response::internal_error()
-                    .json(&serde_json::json!({
-                        "error": error_message
-                    }))
-                    .unwrap_or_else(|_| Response::internal_error());
-
-                crate::infrastructure::web::ActixWebServer::convert_response(response)
+                crate::infrastructure::web::ActixWebServer::respond_list(&req, Response::ok(), &data, &categories)
             }
+            Err(e) => crate::infrastructure::web::ActixWebServer::problem(e.into()),
         }
     }
 }
@@ -3,7 +3,10 @@ use crate::application::use_cases::update_article::UpdateArticleUseCase;
 use crate::application::use_cases::delete_article::DeleteArticleUseCase;
 use crate::application::use_cases::get_article::GetArticleUseCase;
 use crate::application::use_cases::list_articles::ListArticlesUseCase;
-use crate::domain::entities::article::{CreateArticleDto, UpdateArticleDto};
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::domain::entities::article::{ArticleListFilter, ArticleSort, CreateArticleDto, UpdateArticleDto};
+use crate::domain::repositories::article_repository::ArticleRepositoryError;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
 use crate::infrastructure::web::{ActixWebServer, Response};
 use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
 use actix_web::{HttpRequest, HttpResponse};
@@ -21,12 +24,53 @@ pub struct ArticleController {
     auth_middleware: Arc<AuthMiddleware>,
 }
 
+/// Paginação e filtros das listagens de artigos (também usada pela timeline pessoal)
 #[derive(Debug, Deserialize)]
-struct ListArticlesQuery {
+pub(crate) struct ListArticlesQuery {
     #[serde(default = "default_page")]
-    page: i64,
+    pub(crate) page: i64,
     #[serde(default = "default_per_page")]
-    per_page: i64,
+    pub(crate) per_page: i64,
+    /// Apenas artigos não lidos pelo usuário autenticado
+    #[serde(default)]
+    pub(crate) unread: bool,
+    /// `recent` (padrão), `rate` (nota da IA) ou `user_rating` (média dos usuários)
+    #[serde(default)]
+    pub(crate) sort: ArticleSort,
+    pub(crate) min_rate: Option<i32>,
+    pub(crate) min_user_rating: Option<f64>,
+    pub(crate) category_id: Option<Uuid>,
+    /// Texto procurado no título, na descrição e no conteúdo
+    pub(crate) search: Option<String>,
+    /// Apenas artigos marcados com esta tag do usuário (listagens pessoais)
+    pub(crate) tag_id: Option<Uuid>,
+}
+
+impl ListArticlesQuery {
+    /// Lê a query string; um parâmetro inválido vira `400` em vez de ser ignorado
+    pub(crate) fn from_request(req: &HttpRequest) -> Result<Self, HttpResponse> {
+        actix_web::web::Query::<ListArticlesQuery>::from_query(req.query_string())
+            .map(|query| query.into_inner())
+            .map_err(|_| ActixWebServer::error(Response::bad_request(), "Invalid query parameters"))
+    }
+
+    /// Filtros e ordenação pedidos na query string; quem chama completa os filtros de contexto (leitor, feeds)
+    pub(crate) fn filter(&self) -> ArticleListFilter {
+        ArticleListFilter {
+            unread_only: self.unread,
+            min_rate: self.min_rate,
+            min_user_rating: self.min_user_rating,
+            category_id: self.category_id,
+            search: self
+                .search
+                .as_deref()
+                .map(str::trim)
+                .filter(|search| !search.is_empty())
+                .map(str::to_string),
+            sort: self.sort,
+            ..Default::default()
+        }
+    }
 }
 
 fn default_page() -> i64 {
@@ -57,118 +101,104 @@ impl ArticleController {
     }
 
     /// POST /articles - Cria um novo artigo
-    /// Requer autenticação JWT
+    /// Requer autenticação JWT ou chave de API com escopo `articles:write`
     pub async fn create(&self, req: HttpRequest, body: actix_web::web::Bytes) -> HttpResponse {
         // Verifica autenticação
-        let _user_id = match self.auth_middleware.require_auth(&req) {
-            Ok(user_id) => user_id,
-            Err(response) => return response,
-        };
+        if let Err(response) = self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesWrite).await {
+            return response;
+        }
 
-        let request = ActixWebServer::convert_request(&req, body);
+        // Um `Accept` sem formato disponível é recusado antes de gravar o artigo
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
 
-        let dto: CreateArticleDto = match request.json() {
+        let dto: CreateArticleDto = match ActixWebServer::parse_body(&req, body) {
             Ok(dto) => dto,
-            Err(_) => {
-                return ActixWebServer::convert_response(
-                    Response::bad_request().json(&serde_json::json!({
-                        "error": "Invalid request body"
-                    })).unwrap_or_else(|_| Response::internal_error())
-                );
-            }
+            Err(response) => return response,
         };
 
         // Valida o DTO
         if let Err(errors) = dto.validate() {
-            return ActixWebServer::convert_response(
-                Response::bad_request().json(&serde_json::json!({
-                    "error": "Validation failed",
-                    "details": errors.to_string()
-                })).unwrap_or_else(|_| Response::internal_error())
-            );
+            return ActixWebServer::problem(errors.into());
         }
 
         match self.create_article_use_case.execute(dto).await {
             Ok(article) => {
-                ActixWebServer::convert_response(
-                    Response::created().json(&article).unwrap_or_else(|_| Response::internal_error())
-                )
-            }
-            Err(e) => {
-                log::error!("Failed to create article: {:?}", e);
-                ActixWebServer::convert_response(
-                    Response::internal_error()
-                )
+                ActixWebServer::respond_as(media_type, Response::created(), &article)
             }
+            Err(e) => ActixWebServer::problem(e.into()),
         }
     }
 
     /// GET /articles/:id - Busca um artigo por ID
     pub async fn get(&self, req: HttpRequest, _body: actix_web::web::Bytes) -> HttpResponse {
+        // Rota pública; uma chave de API enviada precisa do escopo `articles:read`
+        if let Err(response) = self.auth_middleware.optional_scope(&req, ApiKeyScope::ArticlesRead).await {
+            return response;
+        }
+
         let id_str = req.match_info().get("id").unwrap_or("");
         
         let id = match Uuid::parse_str(id_str) {
             Ok(id) => id,
             Err(_) => {
-                return ActixWebServer::convert_response(
-                    Response::bad_request().json(&serde_json::json!({
-                        "error": "Invalid article ID"
-                    })).unwrap_or_else(|_| Response::internal_error())
-                );
+                return ActixWebServer::error(Response::bad_request(), "Invalid article ID");
             }
         };
 
         match self.get_article_use_case.execute(id).await {
             Ok(Some(article)) => {
-                ActixWebServer::convert_response(
-                    Response::ok().json(&article).unwrap_or_else(|_| Response::internal_error())
-                )
-            }
-            Ok(None) => {
-                ActixWebServer::convert_response(
-                    Response::not_found().json(&serde_json::json!({
-                        "error": "Article not found"
-                    })).unwrap_or_else(|_| Response::internal_error())
-                )
-            }
-            Err(e) => {
-                log::error!("Failed to get article: {:?}", e);
-                ActixWebServer::convert_response(
-                    Response::internal_error()
-                )
+                ActixWebServer::respond(&req, Response::ok(), &article)
             }
+            Ok(None) => ActixWebServer::problem(ArticleRepositoryError::NotFound.into()),
+            Err(e) => ActixWebServer::problem(e.into()),
         }
     }
 
     /// GET /articles - Lista artigos com paginação
+    /// Com usuário autenticado, cada artigo traz o estado de leitura e `unread=true` filtra os não lidos.
+    /// Aceita `sort`, `min_rate`, `min_user_rating`, `category_id` e `search`
+    /// Responde em JSON, MessagePack ou CSV, conforme o `Accept`
     pub async fn list(&self, req: HttpRequest, _body: actix_web::web::Bytes) -> HttpResponse {
-        let query = actix_web::web::Query::<ListArticlesQuery>::from_query(req.query_string())
-            .unwrap_or(actix_web::web::Query(ListArticlesQuery {
-                page: 1,
-                per_page: 20,
-            }));
+        let reader = match self.auth_middleware.optional_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let query = match ListArticlesQuery::from_request(&req) {
+            Ok(query) => query,
+            Err(response) => return response,
+        };
+
+        if query.unread && reader.is_none() {
+            return ActixWebServer::error(Response::unauthorized(), "Authentication required to filter unread articles");
+        }
+
+        let filter = ArticleListFilter {
+            reader_id: reader.map(|user| user.id),
+            ..query.filter()
+        };
 
-        match self.list_articles_use_case.execute(query.page, query.per_page).await {
+        match self.list_articles_use_case.execute_filtered(&filter, query.page, query.per_page).await {
             Ok(paginated) => {
-                ActixWebServer::convert_response(
-                    Response::ok().json(&paginated).unwrap_or_else(|_| Response::internal_error())
-                )
-            }
-            Err(e) => {
-                log::error!("Failed to list articles: {:?}", e);
-                ActixWebServer::convert_response(
-                    Response::bad_request().json(&serde_json::json!({
-                        "error": format!("{:?}", e)
-                    })).unwrap_or_else(|_| Response::internal_error())
-                )
+                // Em CSV o corpo traz só os artigos; a paginação segue nos headers
+                let response = Response::ok()
+                    .with_header("X-Total-Count", paginated.total.to_string())
+                    .with_header("X-Page", paginated.page.to_string())
+                    .with_header("X-Per-Page", paginated.per_page.to_string())
+                    .with_header("X-Total-Pages", paginated.total_pages.to_string());
+                ActixWebServer::respond_list(&req, response, &paginated, &paginated.articles)
             }
+            Err(e) => ActixWebServer::problem(e.into()),
         }
     }
 
     /// PUT /articles/:id - Atualiza um artigo (requer autenticação)
     pub async fn update(&self, req: HttpRequest, body: actix_web::web::Bytes) -> HttpResponse {
         // Verifica autenticação
-        if let Err(response) = self.auth_middleware.require_auth(&req) {
+        if let Err(response) = self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesWrite).await {
             log::warn!("Unauthorized attempt to update article");
             return response;
         }
@@ -178,52 +208,32 @@ impl ArticleController {
         let id = match Uuid::parse_str(id_str) {
             Ok(id) => id,
             Err(_) => {
-                return ActixWebServer::convert_response(
-                    Response::bad_request().json(&serde_json::json!({
-                        "error": "Invalid article ID"
-                    })).unwrap_or_else(|_| Response::internal_error())
-                );
+                return ActixWebServer::error(Response::bad_request(), "Invalid article ID");
             }
         };
 
-        let request = ActixWebServer::convert_request(&req, body);
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
 
-        let dto: UpdateArticleDto = match request.json() {
+        let dto: UpdateArticleDto = match ActixWebServer::parse_body(&req, body) {
             Ok(dto) => dto,
-            Err(_) => {
-                return ActixWebServer::convert_response(
-                    Response::bad_request().json(&serde_json::json!({
-                        "error": "Invalid request body"
-                    })).unwrap_or_else(|_| Response::internal_error())
-                );
-            }
+            Err(response) => return response,
         };
 
         match self.update_article_use_case.execute(id, dto).await {
             Ok(article) => {
-                ActixWebServer::convert_response(
-                    Response::ok().json(&article).unwrap_or_else(|_| Response::internal_error())
-                )
-            }
-            Err(e) => {
-                log::error!("Failed to update article: {:?}", e);
-                let response = match e {
-                    crate::domain::repositories::article_repository::ArticleRepositoryError::NotFound => {
-                        Response::not_found().json(&serde_json::json!({
-                            "error": "Article not found"
-                        })).unwrap_or_else(|_| Response::internal_error())
-                    }
-                    _ => Response::internal_error()
-                };
-                ActixWebServer::convert_response(response)
+                ActixWebServer::respond_as(media_type, Response::ok(), &article)
             }
+            Err(e) => ActixWebServer::problem(e.into()),
         }
     }
 
     /// DELETE /articles/:id - Remove um artigo (requer autenticação)
     pub async fn delete(&self, req: HttpRequest, _body: actix_web::web::Bytes) -> HttpResponse {
         // Verifica autenticação
-        if let Err(response) = self.auth_middleware.require_auth(&req) {
+        if let Err(response) = self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesWrite).await {
             log::warn!("Unauthorized attempt to delete article");
             return response;
         }
@@ -233,11 +243,7 @@ impl ArticleController {
         let id = match Uuid::parse_str(id_str) {
             Ok(id) => id,
             Err(_) => {
-                return ActixWebServer::convert_response(
-                    Response::bad_request().json(&serde_json::json!({
-                        "error": "Invalid article ID"
-                    })).unwrap_or_else(|_| Response::internal_error())
-                );
+                return ActixWebServer::error(Response::bad_request(), "Invalid article ID");
             }
         };
 
@@ -247,18 +253,7 @@ impl ArticleController {
                     Response::no_content()
                 )
             }
-            Err(e) => {
-                log::error!("Failed to delete article: {:?}", e);
-                let response = match e {
-                    crate::domain::repositories::article_repository::ArticleRepositoryError::NotFound => {
-                        Response::not_found().json(&serde_json::json!({
-                            "error": "Article not found"
-                        })).unwrap_or_else(|_| Response::internal_error())
-                    }
-                    _ => Response::internal_error()
-                };
-                ActixWebServer::convert_response(response)
-            }
+            Err(e) => ActixWebServer::problem(e.into()),
         }
     }
 }
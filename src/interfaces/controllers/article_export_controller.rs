@@ -0,0 +1,104 @@
+use crate::application::use_cases::export_articles::{ArticleExport, ExportArticlesUseCase};
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::domain::entities::article::ArticleListFilter;
+use crate::domain::entities::article_export::{ExportColumn, ExportFormat};
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use crate::interfaces::controllers::article_controller::ListArticlesQuery;
+use actix_web::web::Bytes;
+use actix_web::{web, HttpRequest, HttpResponse};
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+struct ExportArticlesQuery {
+    /// `csv` (padrão) ou `ndjson`
+    format: Option<String>,
+    /// Colunas separadas por vírgula, na ordem desejada; ausente exporta todas
+    columns: Option<String>,
+}
+
+/// Exportação dos artigos para análise em notebooks e planilhas
+pub struct ArticleExportController {
+    export_articles_use_case: Arc<ExportArticlesUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl ArticleExportController {
+    pub fn new(export_articles_use_case: Arc<ExportArticlesUseCase>, auth_middleware: Arc<AuthMiddleware>) -> Self {
+        Self {
+            export_articles_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// GET /articles/export - Exporta em CSV ou NDJSON os artigos que atendem aos mesmos filtros de `GET /articles`
+    /// (`sort` não se aplica: a ordem é sempre a de publicação, da mais recente). A resposta é enviada em partes,
+    /// conforme os lotes são lidos do banco
+    pub async fn export(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let reader = match self.auth_middleware.optional_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let query = match web::Query::<ExportArticlesQuery>::from_query(req.query_string()) {
+            Ok(query) => query.into_inner(),
+            Err(_) => return Self::error(Response::bad_request(), "Invalid query parameters"),
+        };
+
+        let format = match ExportFormat::parse(query.format.as_deref().unwrap_or("csv")) {
+            Some(format) => format,
+            None => return Self::error(Response::bad_request(), "Invalid format (expected csv or ndjson)"),
+        };
+
+        let columns = match ExportColumn::parse_list(query.columns.as_deref().unwrap_or_default()) {
+            Ok(columns) => columns,
+            Err(column) => return Self::error(Response::bad_request(), &format!("Unknown column: {}", column)),
+        };
+
+        let list_query = match ListArticlesQuery::from_request(&req) {
+            Ok(query) => query,
+            Err(response) => return response,
+        };
+        if list_query.unread && reader.is_none() {
+            return Self::error(Response::unauthorized(), "Authentication required to filter unread articles");
+        }
+
+        let filter = ArticleListFilter {
+            reader_id: reader.map(|user| user.id),
+            ..list_query.filter()
+        };
+
+        let export = match self.export_articles_use_case.execute(filter, format, columns).await {
+            Ok(export) => export,
+            Err(e) => return ActixWebServer::problem(e.into()),
+        };
+
+        HttpResponse::Ok()
+            .content_type(format.content_type())
+            .insert_header((
+                "Content-Disposition",
+                format!("attachment; filename=\"articles.{}\"", format.extension()),
+            ))
+            .insert_header(("Cache-Control", "no-store"))
+            .streaming(Self::chunks(export))
+    }
+
+    /// Um erro no meio da exportação encerra a conexão, para o cliente não tomar o arquivo parcial como completo
+    fn chunks(export: ArticleExport) -> impl futures_util::Stream<Item = Result<Bytes, actix_web::Error>> {
+        futures_util::stream::unfold(export, |mut export| async move {
+            match export.next_chunk().await {
+                Ok(Some(chunk)) => Some((Ok(Bytes::from(chunk)), export)),
+                Ok(None) => None,
+                Err(e) => {
+                    log::error!("Failed to export articles: {:?}", e);
+                    Some((Err(actix_web::error::ErrorInternalServerError("Export failed")), export))
+                }
+            }
+        })
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
\ No newline at end of file
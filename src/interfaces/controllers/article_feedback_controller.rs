@@ -0,0 +1,160 @@
+use crate::application::use_cases::get_article_reactions::GetArticleReactionsUseCase;
+use crate::application::use_cases::rate_article::RateArticleUseCase;
+use crate::application::use_cases::react_to_article::ReactToArticleUseCase;
+use crate::application::use_cases::remove_article_rating::RemoveArticleRatingUseCase;
+use crate::application::use_cases::remove_article_reaction::RemoveArticleReactionUseCase;
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::domain::entities::article::RateArticleDto;
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Avaliações (nota de 0 a 10) e reações dos usuários aos artigos.
+/// As escritas aceitam JWT ou chave de API com o escopo `library:write`; a consulta de reações é pública
+pub struct ArticleFeedbackController {
+    rate_article_use_case: Arc<RateArticleUseCase>,
+    remove_article_rating_use_case: Arc<RemoveArticleRatingUseCase>,
+    react_to_article_use_case: Arc<ReactToArticleUseCase>,
+    remove_article_reaction_use_case: Arc<RemoveArticleReactionUseCase>,
+    get_article_reactions_use_case: Arc<GetArticleReactionsUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl ArticleFeedbackController {
+    pub fn new(
+        rate_article_use_case: Arc<RateArticleUseCase>,
+        remove_article_rating_use_case: Arc<RemoveArticleRatingUseCase>,
+        react_to_article_use_case: Arc<ReactToArticleUseCase>,
+        remove_article_reaction_use_case: Arc<RemoveArticleReactionUseCase>,
+        get_article_reactions_use_case: Arc<GetArticleReactionsUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            rate_article_use_case,
+            remove_article_rating_use_case,
+            react_to_article_use_case,
+            remove_article_reaction_use_case,
+            get_article_reactions_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// PUT /articles/:id/rating - Grava a nota do usuário e devolve a nova média
+    pub async fn rate(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let article_id = match Self::article_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        let dto: RateArticleDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.rate_article_use_case.execute(user.id, article_id, dto).await {
+            Ok(rating) => ActixWebServer::respond_as(media_type, Response::ok(), &rating),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// DELETE /articles/:id/rating - Remove a nota do usuário e devolve a nova média
+    pub async fn remove_rating(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let article_id = match Self::article_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.remove_article_rating_use_case.execute(user.id, article_id).await {
+            Ok(rating) => ActixWebServer::respond_as(media_type, Response::ok(), &rating),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// GET /articles/:id/reactions - Totais por reação; com usuário identificado, inclui as reações dele
+    pub async fn reactions(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let viewer = match self.auth_middleware.optional_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let article_id = match Self::article_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        match self.get_article_reactions_use_case.execute(article_id, viewer.map(|user| user.id)).await {
+            Ok(reactions) => ActixWebServer::respond(&req, Response::ok(), &reactions),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// PUT /articles/:id/reactions/:reaction - Adiciona `like`, `dislike` ou um emoji
+    pub async fn react(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let article_id = match Self::article_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+        let reaction = req.match_info().get("reaction").unwrap_or("");
+
+        match self.react_to_article_use_case.execute(user.id, article_id, reaction).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// DELETE /articles/:id/reactions/:reaction - Remove uma reação do usuário
+    pub async fn unreact(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let article_id = match Self::article_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+        let reaction = req.match_info().get("reaction").unwrap_or("");
+
+        match self.remove_article_reaction_use_case.execute(user.id, article_id, reaction).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    fn article_id(req: &HttpRequest) -> Result<Uuid, HttpResponse> {
+        Uuid::parse_str(req.match_info().get("id").unwrap_or(""))
+            .map_err(|_| Self::error(Response::bad_request(), "Invalid article ID"))
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
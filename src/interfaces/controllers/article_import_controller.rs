@@ -0,0 +1,60 @@
+use crate::application::use_cases::import_articles::ImportArticlesUseCase;
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+
+/// Importação em lote de artigos exportados de outro ambiente
+pub struct ArticleImportController {
+    import_articles_use_case: Arc<ImportArticlesUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl ArticleImportController {
+    pub fn new(import_articles_use_case: Arc<ImportArticlesUseCase>, auth_middleware: Arc<AuthMiddleware>) -> Self {
+        Self {
+            import_articles_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// POST /articles/import - Importa um arquivo NDJSON (ou um array JSON) de artigos e retorna o relatório
+    /// com o resultado de cada linha. Requer JWT ou chave de API com escopo `articles:write`
+    pub async fn import(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        if let Err(response) = self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesWrite).await {
+            return response;
+        }
+
+        // Um `Accept` sem formato disponível é recusado antes de gravar os artigos
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        let text = match std::str::from_utf8(&body) {
+            Ok(text) => text,
+            Err(_) => return Self::error(Response::bad_request(), "Request body must be UTF-8"),
+        };
+
+        let records = match ImportArticlesUseCase::records(text) {
+            Ok(records) => records,
+            Err(message) => return Self::error(Response::bad_request(), &message),
+        };
+
+        let report = self.import_articles_use_case.execute(records).await;
+        log::info!(
+            "Article import finished: {} inserted, {} skipped, {} failed",
+            report.inserted,
+            report.skipped,
+            report.failed
+        );
+
+        ActixWebServer::respond_as(media_type, Response::ok(), &report)
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
\ No newline at end of file
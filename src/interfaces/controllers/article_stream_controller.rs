@@ -0,0 +1,128 @@
+use crate::application::services::article_stream_service::{ArticleStreamService, ArticleSubscription};
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::domain::entities::article_stream::{ArticleStreamEvent, ArticleStreamFilter};
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::web::Bytes;
+use actix_web::{web, HttpRequest, HttpResponse};
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+/// Intervalo dos comentários que mantêm a conexão aberta em proxies e balanceadores
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+/// Espera sugerida ao navegador antes de reconectar, em milissegundos
+const RETRY_MILLIS: u64 = 3000;
+
+#[derive(Debug, Deserialize)]
+struct ArticleStreamQuery {
+    feed_id: Option<Uuid>,
+    category_id: Option<Uuid>,
+    /// Alternativa ao header `Last-Event-ID`, que o `EventSource` não deixa definir na primeira conexão
+    last_event_id: Option<i64>,
+}
+
+/// Stream de artigos novos e atualizados em Server-Sent Events
+pub struct ArticleStreamController {
+    article_stream_service: Arc<ArticleStreamService>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl ArticleStreamController {
+    pub fn new(article_stream_service: Arc<ArticleStreamService>, auth_middleware: Arc<AuthMiddleware>) -> Self {
+        Self {
+            article_stream_service,
+            auth_middleware,
+        }
+    }
+
+    /// GET /articles/stream - Envia os eventos `article.created`, `article.updated` e
+    /// `article.enriched` conforme acontecem, filtrados por `feed_id` e `category_id`.
+    /// Com `Last-Event-ID`, reenvia o que estiver no buffer de replay; se o ID já saiu dele,
+    /// envia um evento `resync` para o cliente recarregar a lista
+    pub async fn stream(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        if let Err(response) = self.auth_middleware.optional_scope(&req, ApiKeyScope::ArticlesRead).await {
+            return response;
+        }
+
+        let query = match web::Query::<ArticleStreamQuery>::from_query(req.query_string()) {
+            Ok(query) => query.into_inner(),
+            Err(_) => return Self::error(Response::bad_request(), "Invalid query parameters"),
+        };
+
+        let last_event_id = match req.headers().get("Last-Event-ID") {
+            Some(header) => match header.to_str().ok().and_then(|value| value.trim().parse::<i64>().ok()) {
+                Some(id) => Some(id),
+                None => return Self::error(Response::bad_request(), "Invalid Last-Event-ID"),
+            },
+            None => query.last_event_id,
+        };
+
+        let filter = ArticleStreamFilter {
+            feed_id: query.feed_id,
+            category_id: query.category_id,
+        };
+        let subscription = self.article_stream_service.subscribe(&filter, last_event_id);
+
+        HttpResponse::Ok()
+            .content_type("text/event-stream")
+            .insert_header(("Cache-Control", "no-cache"))
+            // Desliga o buffer do nginx, que seguraria os eventos
+            .insert_header(("X-Accel-Buffering", "no"))
+            .streaming(Self::event_stream(subscription, filter))
+    }
+
+    fn event_stream(
+        subscription: ArticleSubscription,
+        filter: ArticleStreamFilter,
+    ) -> impl futures_util::Stream<Item = Result<Bytes, actix_web::Error>> {
+        let mut pending = VecDeque::new();
+        pending.push_back(Bytes::from(format!("retry: {}\n\n", RETRY_MILLIS)));
+        if subscription.resync {
+            pending.push_back(resync_frame());
+        }
+        pending.extend(subscription.replay.iter().map(event_frame));
+
+        let keep_alive = tokio::time::interval_at(tokio::time::Instant::now() + KEEP_ALIVE_INTERVAL, KEEP_ALIVE_INTERVAL);
+        let state = (pending, subscription.receiver, filter, keep_alive);
+
+        futures_util::stream::unfold(state, |(mut pending, mut receiver, filter, mut keep_alive)| async move {
+            let frame = match pending.pop_front() {
+                Some(frame) => frame,
+                None => loop {
+                    tokio::select! {
+                        received = receiver.recv() => match received {
+                            Ok(event) if filter.matches(&event) => break event_frame(&event),
+                            Ok(_) => continue,
+                            // A conexão perdeu eventos por ficar para trás
+                            Err(RecvError::Lagged(_)) => break resync_frame(),
+                            Err(RecvError::Closed) => return None,
+                        },
+                        _ = keep_alive.tick() => break Bytes::from_static(b": keep-alive\n\n"),
+                    }
+                },
+            };
+            Some((Ok(frame), (pending, receiver, filter, keep_alive)))
+        })
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
+
+fn event_frame(event: &ArticleStreamEvent) -> Bytes {
+    Bytes::from(format!(
+        "id: {}\nevent: {}\ndata: {}\n\n",
+        event.seq,
+        event.event_type.as_str(),
+        event.data
+    ))
+}
+
+fn resync_frame() -> Bytes {
+    Bytes::from_static(b"event: resync\ndata: {}\n\n")
+}
@@ -0,0 +1,87 @@
+use crate::application::use_cases::get_article_tags::GetArticleTagsUseCase;
+use crate::application::use_cases::set_article_tags::SetArticleTagsUseCase;
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::domain::entities::tag::SetArticleTagsDto;
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Marcação dos artigos com as tags do usuário. Aceita JWT ou chave de API com o escopo `articles:read`
+/// (consulta) ou `library:write` (marcação)
+pub struct ArticleTagController {
+    get_article_tags_use_case: Arc<GetArticleTagsUseCase>,
+    set_article_tags_use_case: Arc<SetArticleTagsUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl ArticleTagController {
+    pub fn new(
+        get_article_tags_use_case: Arc<GetArticleTagsUseCase>,
+        set_article_tags_use_case: Arc<SetArticleTagsUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            get_article_tags_use_case,
+            set_article_tags_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// GET /articles/:id/tags - Tags do usuário no artigo
+    pub async fn list(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let article_id = match Self::path_id(&req, "id", "Invalid article ID") {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        match self.get_article_tags_use_case.execute(user.id, article_id).await {
+            Ok(tags) => ActixWebServer::respond(&req, Response::ok(), &tags),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// PUT /articles/:id/tags - Substitui as tags do usuário no artigo (`names`), criando as novas
+    pub async fn set(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let article_id = match Self::path_id(&req, "id", "Invalid article ID") {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        let dto: SetArticleTagsDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.set_article_tags_use_case.execute(user.id, article_id, dto).await {
+            Ok(tags) => ActixWebServer::respond_as(media_type, Response::ok(), &tags),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    fn path_id(req: &HttpRequest, name: &str, message: &str) -> Result<Uuid, HttpResponse> {
+        Uuid::parse_str(req.match_info().get(name).unwrap_or(""))
+            .map_err(|_| Self::error(Response::bad_request(), message))
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
@@ -1,152 +1,133 @@
-use crate::application::use_cases::login_user::{LoginUserError, LoginUserUseCase};
-use crate::application::use_cases::register_user::{RegisterUserError, RegisterUserUseCase};
+use crate::application::use_cases::login_user::{LoginContext, LoginUserUseCase};
+use crate::application::use_cases::register_user::RegisterUserUseCase;
+use crate::application::use_cases::verify_mfa_login::VerifyMfaLoginUseCase;
+use crate::domain::entities::mfa::VerifyMfaDto;
 use crate::domain::entities::user::{CreateUserDto, LoginDto};
-use crate::infrastructure::web::Response;
+use crate::infrastructure::web::client_ip::TrustedProxies;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
 use actix_web::{web, HttpRequest, HttpResponse};
 use std::sync::Arc;
 
 pub struct AuthController {
     register_use_case: Arc<RegisterUserUseCase>,
     login_use_case: Arc<LoginUserUseCase>,
+    verify_mfa_use_case: Arc<VerifyMfaLoginUseCase>,
+    trusted_proxies: TrustedProxies,
 }
 
 impl AuthController {
     pub fn new(
         register_use_case: Arc<RegisterUserUseCase>,
         login_use_case: Arc<LoginUserUseCase>,
+        verify_mfa_use_case: Arc<VerifyMfaLoginUseCase>,
+        trusted_proxies: TrustedProxies,
     ) -> Self {
         Self {
             register_use_case,
             login_use_case,
+            verify_mfa_use_case,
+            trusted_proxies,
         }
     }
 
     pub async fn register(
         &self,
-        _req: HttpRequest,
+        req: HttpRequest,
         body: web::Bytes,
     ) -> HttpResponse {
         // Parse request body
-        let dto: CreateUserDto = match serde_json::from_slice(&body) {
+        let dto: CreateUserDto = match ActixWebServer::parse_body(&req, body) {
             Ok(dto) => dto,
-            Err(e) => {
-                let response = Response::bad_request()
-                    .json(&serde_json::json!({
-                        "error": "Invalid request body",
-                        "details": e.to_string()
-                    }))
-                    .unwrap_or_else(|_| Response::internal_error());
-                
-                return crate::infrastructure::web::ActixWebServer::convert_response(response);
-            }
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
         };
 
         // Execute use case
-        match self.register_use_case.execute(dto).await {
+        let client = Self::login_context(&req, &self.trusted_proxies).session_client();
+
+        match self.register_use_case.execute(dto, client).await {
             Ok(auth_response) => {
-                let response = Response::created()
-                    .json(&auth_response)
-                    .unwrap_or_else(|_| Response::internal_error());
-                
-                crate::infrastructure::web::ActixWebServer::convert_response(response)
-            }
-            Err(e) => {
-                let (status_code, error_message) = match e {
-                    RegisterUserError::ValidationError(msg) => (400, msg),
-                    RegisterUserError::EmailAlreadyExists => {
-                        (409, "Email already in use".to_string())
-                    }
-                    RegisterUserError::PasswordHashError(msg) => (500, msg),
-                    RegisterUserError::RepositoryError(msg) => (500, msg),
-                    RegisterUserError::TokenError(msg) => (500, msg),
-                };
-
-                let response = if status_code >= 500 {
-                    Response::internal_error()
-                        .json(&serde_json::json!({
-                            "error": error_message
-                        }))
-                        .unwrap_or_else(|_| Response::internal_error())
-                } else if status_code == 409 {
-                    Response::conflict()
-                        .json(&serde_json::json!({
-                            "error": error_message
-                        }))
-                        .unwrap_or_else(|_| Response::internal_error())
-                } else {
-                    Response::bad_request()
-                        .json(&serde_json::json!({
-                            "error": error_message
-                        }))
-                        .unwrap_or_else(|_| Response::internal_error())
-                };
-
-                crate::infrastructure::web::ActixWebServer::convert_response(response)
+                ActixWebServer::respond_as(media_type, Response::created(), &auth_response)
             }
+            Err(e) => ActixWebServer::problem(e.into()),
         }
     }
 
     pub async fn login(
         &self,
-        _req: HttpRequest,
+        req: HttpRequest,
         body: web::Bytes,
     ) -> HttpResponse {
         // Parse request body
-        let dto: LoginDto = match serde_json::from_slice(&body) {
+        let dto: LoginDto = match ActixWebServer::parse_body(&req, body) {
             Ok(dto) => dto,
-            Err(e) => {
-                let response = Response::bad_request()
-                    .json(&serde_json::json!({
-                        "error": "Invalid request body",
-                        "details": e.to_string()
-                    }))
-                    .unwrap_or_else(|_| Response::internal_error());
-                
-                return crate::infrastructure::web::ActixWebServer::convert_response(response);
-            }
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
         };
 
+        let context = Self::login_context(&req, &self.trusted_proxies);
+
         // Execute use case
-        match self.login_use_case.execute(dto).await {
-            Ok(auth_response) => {
-                let response = Response::ok()
-                    .json(&auth_response)
-                    .unwrap_or_else(|_| Response::internal_error());
-                
-                crate::infrastructure::web::ActixWebServer::convert_response(response)
+        match self.login_use_case.execute(dto, context).await {
+            // Com 2FA ativo a resposta é o desafio com o token "mfa pending"
+            Ok(outcome) => {
+                ActixWebServer::respond_as(media_type, Response::ok(), &outcome)
             }
-            Err(e) => {
-                let (status_code, error_message) = match e {
-                    LoginUserError::ValidationError(msg) => (400, msg),
-                    LoginUserError::InvalidCredentials => {
-                        (401, "Invalid email or password".to_string())
-                    }
-                    LoginUserError::TokenError(msg) => (500, msg),
-                    LoginUserError::RepositoryError(msg) => (500, msg),
-                };
-
-                let response = if status_code >= 500 {
-                    Response::internal_error()
-                        .json(&serde_json::json!({
-                            "error": error_message
-                        }))
-                        .unwrap_or_else(|_| Response::internal_error())
-                } else if status_code == 401 {
-                    Response::unauthorized()
-                        .json(&serde_json::json!({
-                            "error": error_message
-                        }))
-                        .unwrap_or_else(|_| Response::internal_error())
-                } else {
-                    Response::bad_request()
-                        .json(&serde_json::json!({
-                            "error": error_message
-                        }))
-                        .unwrap_or_else(|_| Response::internal_error())
-                };
-
-                crate::infrastructure::web::ActixWebServer::convert_response(response)
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /auth/mfa/verify - Troca o token "mfa pending" e um código TOTP
+    /// (ou de recuperação) pelo token de acesso
+    pub async fn verify_mfa(
+        &self,
+        req: HttpRequest,
+        body: web::Bytes,
+    ) -> HttpResponse {
+        let dto: VerifyMfaDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        let context = Self::login_context(&req, &self.trusted_proxies);
+
+        match self.verify_mfa_use_case.execute(dto, context).await {
+            Ok(auth_response) => {
+                ActixWebServer::respond_as(media_type, Response::ok(), &auth_response)
             }
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// Dados do cliente registrados na sessão: IP, User-Agent e o nome do dispositivo
+    /// opcionalmente enviado no header `X-Device-Name`
+    pub(crate) fn login_context(req: &HttpRequest, trusted_proxies: &TrustedProxies) -> LoginContext {
+        let header = |name: &str| {
+            req.headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        LoginContext {
+            ip_address: trusted_proxies.client_ip(req),
+            user_agent: header("User-Agent"),
+            device_label: header("X-Device-Name"),
         }
     }
 }
@@ -0,0 +1,145 @@
+use crate::application::use_cases::create_collection::CreateCollectionUseCase;
+use crate::application::use_cases::delete_collection::DeleteCollectionUseCase;
+use crate::application::use_cases::get_shared_collection::GetSharedCollectionUseCase;
+use crate::application::use_cases::list_collections::ListCollectionsUseCase;
+use crate::application::use_cases::update_collection::UpdateCollectionUseCase;
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::domain::entities::collection::{CreateCollectionDto, UpdateCollectionDto};
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Coleções de artigos salvos e a visualização pública das coleções compartilhadas.
+/// As rotas `/me/...` aceitam JWT ou chave de API com o escopo `articles:read` (listagem) ou `library:write`
+pub struct CollectionController {
+    create_collection_use_case: Arc<CreateCollectionUseCase>,
+    list_collections_use_case: Arc<ListCollectionsUseCase>,
+    update_collection_use_case: Arc<UpdateCollectionUseCase>,
+    delete_collection_use_case: Arc<DeleteCollectionUseCase>,
+    get_shared_collection_use_case: Arc<GetSharedCollectionUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl CollectionController {
+    pub fn new(
+        create_collection_use_case: Arc<CreateCollectionUseCase>,
+        list_collections_use_case: Arc<ListCollectionsUseCase>,
+        update_collection_use_case: Arc<UpdateCollectionUseCase>,
+        delete_collection_use_case: Arc<DeleteCollectionUseCase>,
+        get_shared_collection_use_case: Arc<GetSharedCollectionUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            create_collection_use_case,
+            list_collections_use_case,
+            update_collection_use_case,
+            delete_collection_use_case,
+            get_shared_collection_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// GET /me/collections - Lista as coleções
+    pub async fn list(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        match self.list_collections_use_case.execute(user.id).await {
+            Ok(collections) => ActixWebServer::respond(&req, Response::ok(), &collections),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /me/collections - Cria uma coleção (`name`)
+    pub async fn create(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let dto: CreateCollectionDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.create_collection_use_case.execute(user.id, dto).await {
+            Ok(collection) => ActixWebServer::respond_as(media_type, Response::created(), &collection),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// PATCH /me/collections/:id - Renomeia e/ou compartilha (`shared: true`) ou torna privada
+    pub async fn update(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let id = match Self::collection_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        let dto: UpdateCollectionDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.update_collection_use_case.execute(user.id, id, dto).await {
+            Ok(collection) => ActixWebServer::respond_as(media_type, Response::ok(), &collection),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// DELETE /me/collections/:id - Remove a coleção; os itens continuam salvos
+    pub async fn delete(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let id = match Self::collection_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        match self.delete_collection_use_case.execute(user.id, id).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// GET /shared/collections/:token - Coleção compartilhada (rota pública)
+    pub async fn shared(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let token = req.match_info().get("token").unwrap_or("");
+
+        match self.get_shared_collection_use_case.execute(token).await {
+            Ok(collection) => ActixWebServer::respond(&req, Response::ok(), &collection),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    fn collection_id(req: &HttpRequest) -> Result<Uuid, HttpResponse> {
+        Uuid::parse_str(req.match_info().get("id").unwrap_or(""))
+            .map_err(|_| Self::error(Response::bad_request(), "Invalid collection ID"))
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
@@ -0,0 +1,118 @@
+use crate::application::use_cases::delete_digest_settings::DeleteDigestSettingsUseCase;
+use crate::application::use_cases::get_digest_settings::GetDigestSettingsUseCase;
+use crate::application::use_cases::list_sent_digests::ListSentDigestsUseCase;
+use crate::application::use_cases::preview_digest::PreviewDigestUseCase;
+use crate::application::use_cases::save_digest_settings::SaveDigestSettingsUseCase;
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::domain::entities::digest::DigestSettingsDto;
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+
+/// Resumo por email do usuário autenticado: agenda, histórico e pré-visualização.
+/// Aceita JWT ou chave de API com o escopo `articles:read` (consultas) ou `library:write` (agenda)
+pub struct DigestController {
+    get_digest_settings_use_case: Arc<GetDigestSettingsUseCase>,
+    save_digest_settings_use_case: Arc<SaveDigestSettingsUseCase>,
+    delete_digest_settings_use_case: Arc<DeleteDigestSettingsUseCase>,
+    list_sent_digests_use_case: Arc<ListSentDigestsUseCase>,
+    preview_digest_use_case: Arc<PreviewDigestUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl DigestController {
+    pub fn new(
+        get_digest_settings_use_case: Arc<GetDigestSettingsUseCase>,
+        save_digest_settings_use_case: Arc<SaveDigestSettingsUseCase>,
+        delete_digest_settings_use_case: Arc<DeleteDigestSettingsUseCase>,
+        list_sent_digests_use_case: Arc<ListSentDigestsUseCase>,
+        preview_digest_use_case: Arc<PreviewDigestUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            get_digest_settings_use_case,
+            save_digest_settings_use_case,
+            delete_digest_settings_use_case,
+            list_sent_digests_use_case,
+            preview_digest_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// GET /me/digest - Agenda do resumo, com o próximo envio (`next_send_at`)
+    pub async fn get(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        match self.get_digest_settings_use_case.execute(user.id).await {
+            Ok(settings) => ActixWebServer::respond(&req, Response::ok(), &settings),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// PUT /me/digest - Cria ou substitui a agenda (`frequency`, `hour`, `weekday`, `timezone`, ...)
+    pub async fn save(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let dto: DigestSettingsDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.save_digest_settings_use_case.execute(user.id, dto).await {
+            Ok(settings) => ActixWebServer::respond_as(media_type, Response::ok(), &settings),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// DELETE /me/digest - Cancela o resumo
+    pub async fn delete(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        match self.delete_digest_settings_use_case.execute(user.id).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// GET /me/digest/history - Resumos já registrados, dos mais recentes para os mais antigos
+    pub async fn history(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        match self.list_sent_digests_use_case.execute(user.id).await {
+            Ok(sent) => ActixWebServer::respond(&req, Response::ok(), &sent),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// GET /me/digest/preview - Resumo do período mais recente, sem enviá-lo
+    pub async fn preview(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        match self.preview_digest_use_case.execute(user.id).await {
+            Ok(preview) => ActixWebServer::respond(&req, Response::ok(), &preview),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+}
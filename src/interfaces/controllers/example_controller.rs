@@ -1,6 +1,7 @@
 use actix_web::{web, HttpRequest};
 use serde::{Deserialize, Serialize};
 
+use crate::application::errors::{ApplicationError, ErrorKind};
 use crate::infrastructure::web::{ActixWebServer, Response};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -19,7 +20,7 @@ pub struct EchoResponse {
 /// 
 /// Este handler:
 /// 1. Converte a requisição do Actix para nosso tipo abstrato
-/// 2. Faz parsing do JSON do body
+/// 2. Faz parsing do body (JSON ou MessagePack)
 /// 3. Processa os dados (lógica de negócio)
 /// 4. Cria uma resposta usando nossos tipos abstratos
 /// 5. Converte a resposta para HttpResponse do Actix
@@ -28,18 +29,13 @@ pub async fn echo_handler(req: HttpRequest, body: web::Bytes) -> actix_web::Http
     // 1. Converte a requisição
     let request = ActixWebServer::convert_request(&req, body);
     
-    // 2. Parse do JSON
-    let echo_req: EchoRequest = match request.json() {
+    // 2. Parse do body, conforme o Content-Type
+    let echo_req: EchoRequest = match request.body() {
         Ok(data) => data,
         Err(e) => {
-            log::error!("Failed to parse JSON: {}", e);
-            let response = Response::bad_request()
-                .json(&serde_json::json!({
-                    "error": "Invalid JSON",
-                    "details": e.to_string()
-                }))
-                .unwrap_or_else(|_| Response::internal_error());
-            return ActixWebServer::convert_response(response);
+            log::error!("Failed to parse body: {}", e);
+            let error = ApplicationError::new(ErrorKind::BadRequest, "invalid_request_body", e.to_string());
+            return ActixWebServer::problem(error);
         }
     };
     
@@ -57,12 +53,9 @@ pub async fn echo_handler(req: HttpRequest, body: web::Bytes) -> actix_web::Http
     let response = match Response::ok().json(&echo_resp) {
         Ok(r) => r,
         Err(e) => {
+            // Sem body, a conversão responde o problema genérico do status
             log::error!("Failed to serialize response: {}", e);
             Response::internal_error()
-                .json(&serde_json::json!({
-                    "error": "Internal server error"
-                }))
-                .unwrap_or_else(|_| Response::internal_error())
         }
     };
     
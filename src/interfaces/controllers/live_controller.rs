@@ -0,0 +1,320 @@
+use crate::application::services::article_stream_service::ArticleStreamService;
+use crate::application::services::read_state_sync_service::ReadStateSyncService;
+use crate::application::use_cases::issue_stream_ticket::IssueStreamTicketUseCase;
+use crate::application::use_cases::mark_article_read::{MarkArticleReadError, MarkArticleReadUseCase};
+use crate::application::use_cases::mark_article_unread::{MarkArticleUnreadError, MarkArticleUnreadUseCase};
+use crate::domain::entities::article_stream::{ArticleStreamEvent, ArticleStreamFilter};
+use crate::domain::entities::live_session::{LiveClientMessage, LiveServerMessage, LiveSubscriptions, LiveTopic, MAX_LIVE_TOPICS};
+use crate::domain::entities::read_state::ReadStateEvent;
+use crate::domain::entities::user::User;
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_codec::{Decoder, Encoder};
+use actix_http::ws::{self, CloseCode, Codec, Frame, Message};
+use actix_web::body::BoxBody;
+use actix_web::http::header::{self, HeaderValue};
+use actix_web::web::{Bytes, BytesMut};
+use actix_web::{web, HttpRequest, HttpResponse};
+use futures_util::StreamExt;
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+/// Intervalo dos pings do servidor; a cada ping a sessão também é conferida de novo
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
+/// Sem nenhum frame do cliente por esse tempo, a conexão é fechada
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(90);
+/// Frames aguardando envio por conexão
+const OUTGOING_CAPACITY: usize = 64;
+
+#[derive(Debug, Deserialize)]
+struct LiveQuery {
+    /// Ticket de `POST /live/ticket`, para clientes que não conseguem enviar o header Authorization
+    /// (o WebSocket do navegador); o access token nunca vai na URL
+    ticket: Option<String>,
+}
+
+/// Canal WebSocket para sessões de leitura ao vivo: assinatura de tópicos (feed, categoria,
+/// enriquecimento de um artigo), sincronização do estado de leitura entre os dispositivos
+/// do usuário e heartbeats
+pub struct LiveController {
+    auth_middleware: Arc<AuthMiddleware>,
+    issue_stream_ticket_use_case: Arc<IssueStreamTicketUseCase>,
+    article_stream_service: Arc<ArticleStreamService>,
+    read_state_sync: Arc<ReadStateSyncService>,
+    mark_article_read_use_case: Arc<MarkArticleReadUseCase>,
+    mark_article_unread_use_case: Arc<MarkArticleUnreadUseCase>,
+}
+
+impl LiveController {
+    pub fn new(
+        auth_middleware: Arc<AuthMiddleware>,
+        issue_stream_ticket_use_case: Arc<IssueStreamTicketUseCase>,
+        article_stream_service: Arc<ArticleStreamService>,
+        read_state_sync: Arc<ReadStateSyncService>,
+        mark_article_read_use_case: Arc<MarkArticleReadUseCase>,
+        mark_article_unread_use_case: Arc<MarkArticleUnreadUseCase>,
+    ) -> Self {
+        Self {
+            auth_middleware,
+            issue_stream_ticket_use_case,
+            article_stream_service,
+            read_state_sync,
+            mark_article_read_use_case,
+            mark_article_unread_use_case,
+        }
+    }
+
+    /// POST /live/ticket - Ticket de 60s para abrir o WebSocket em `/ws?ticket=`, ligado à sessão do JWT
+    pub async fn ticket(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let (user, session_id) = match self.auth_middleware.require_user_session(&req).await {
+            Ok(authenticated) => authenticated,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.issue_stream_ticket_use_case.execute(&user, session_id) {
+            Ok(ticket) => ActixWebServer::respond_as(media_type, Response::ok(), &ticket),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// GET /ws - Abre a conexão WebSocket, autenticada com o JWT no header Authorization
+    /// ou com um ticket de `POST /live/ticket` em `?ticket=`. As mensagens são JSON com um campo `type`
+    pub async fn connect(&self, req: HttpRequest, payload: web::Payload) -> HttpResponse {
+        if let Err(e) = ws::verify_handshake(req.head()) {
+            return HttpResponse::from(actix_http::Response::<BoxBody>::from(e));
+        }
+
+        let ticket = web::Query::<LiveQuery>::from_query(req.query_string())
+            .ok()
+            .and_then(|query| query.into_inner().ticket);
+        let authenticated = match ticket {
+            Some(ticket) => self.auth_middleware.require_stream_ticket(&ticket).await,
+            None => self.auth_middleware.require_user_session(&req).await,
+        };
+        let (user, session_id) = match authenticated {
+            Ok(authenticated) => authenticated,
+            Err(response) => return response,
+        };
+
+        let accept = req
+            .headers()
+            .get(header::SEC_WEBSOCKET_KEY)
+            .map(|key| ws::hash_key(key.as_bytes()))
+            .and_then(|accept| HeaderValue::from_bytes(&accept).ok());
+        let Some(accept) = accept else {
+            return ActixWebServer::error(Response::bad_request(), "Missing Sec-WebSocket-Key header");
+        };
+
+        let (outgoing, receiver) = mpsc::channel::<Bytes>(OUTGOING_CAPACITY);
+        let connection = LiveConnection {
+            user,
+            session_id,
+            auth_middleware: self.auth_middleware.clone(),
+            mark_article_read_use_case: self.mark_article_read_use_case.clone(),
+            mark_article_unread_use_case: self.mark_article_unread_use_case.clone(),
+            subscriptions: LiveSubscriptions::default(),
+            codec: Codec::new(),
+            outgoing,
+            last_seen: Instant::now(),
+        };
+        let articles = self
+            .article_stream_service
+            .subscribe(&ArticleStreamFilter::default(), None)
+            .receiver;
+        actix_web::rt::spawn(connection.run(payload, articles, self.read_state_sync.subscribe()));
+
+        HttpResponse::SwitchingProtocols()
+            .upgrade("websocket")
+            .insert_header((header::SEC_WEBSOCKET_ACCEPT, accept))
+            .streaming(futures_util::stream::unfold(receiver, |mut receiver| async move {
+                receiver
+                    .recv()
+                    .await
+                    .map(|frame| (Ok::<_, actix_web::Error>(frame), receiver))
+            }))
+    }
+}
+
+/// Estado de uma conexão aberta. Cada retorno `false` encerra a conexão
+struct LiveConnection {
+    user: User,
+    session_id: Option<Uuid>,
+    auth_middleware: Arc<AuthMiddleware>,
+    mark_article_read_use_case: Arc<MarkArticleReadUseCase>,
+    mark_article_unread_use_case: Arc<MarkArticleUnreadUseCase>,
+    subscriptions: LiveSubscriptions,
+    codec: Codec,
+    outgoing: mpsc::Sender<Bytes>,
+    last_seen: Instant,
+}
+
+impl LiveConnection {
+    async fn run(
+        mut self,
+        mut payload: web::Payload,
+        mut articles: broadcast::Receiver<ArticleStreamEvent>,
+        mut read_states: broadcast::Receiver<ReadStateEvent>,
+    ) {
+        let mut buffer = BytesMut::new();
+        let mut heartbeat = tokio::time::interval_at(Instant::now() + HEARTBEAT_INTERVAL, HEARTBEAT_INTERVAL);
+
+        let welcome = LiveServerMessage::Welcome {
+            user_id: self.user.id,
+            heartbeat_seconds: HEARTBEAT_INTERVAL.as_secs(),
+        };
+        let mut open = self.send_json(&welcome).await;
+
+        while open {
+            open = tokio::select! {
+                chunk = payload.next() => match chunk {
+                    Some(Ok(bytes)) => {
+                        buffer.extend_from_slice(&bytes);
+                        self.read_frames(&mut buffer).await
+                    }
+                    _ => false,
+                },
+                received = articles.recv() => match received {
+                    Ok(event) => {
+                        let topics = self.subscriptions.matching(&event);
+                        topics.is_empty() || self.send_json(&LiveServerMessage::article(&event, &topics)).await
+                    }
+                    Err(RecvError::Lagged(_)) => self.send_json(&LiveServerMessage::Resync).await,
+                    Err(RecvError::Closed) => false,
+                },
+                received = read_states.recv() => match received {
+                    Ok(event) if event.user_id == self.user.id => {
+                        self.send_json(&LiveServerMessage::ReadState(event)).await
+                    }
+                    Ok(_) => true,
+                    Err(RecvError::Lagged(_)) => self.send_json(&LiveServerMessage::Resync).await,
+                    Err(RecvError::Closed) => false,
+                },
+                _ = heartbeat.tick() => self.heartbeat().await,
+            };
+        }
+    }
+
+    async fn read_frames(&mut self, buffer: &mut BytesMut) -> bool {
+        loop {
+            match self.codec.decode(buffer) {
+                Ok(Some(frame)) => {
+                    if !self.handle_frame(frame).await {
+                        return false;
+                    }
+                }
+                Ok(None) => return true,
+                Err(e) => return self.close(CloseCode::Protocol, &e.to_string()).await,
+            }
+        }
+    }
+
+    async fn handle_frame(&mut self, frame: Frame) -> bool {
+        self.last_seen = Instant::now();
+
+        match frame {
+            Frame::Text(text) => match serde_json::from_slice::<LiveClientMessage>(&text) {
+                Ok(message) => self.handle_message(message).await,
+                Err(e) => self.send_json(&LiveServerMessage::error(format!("Invalid message: {}", e))).await,
+            },
+            Frame::Binary(_) | Frame::Continuation(_) => {
+                self.send_json(&LiveServerMessage::error("Only text messages are supported")).await
+            }
+            Frame::Ping(data) => self.send(Message::Pong(data)).await,
+            Frame::Pong(_) => true,
+            Frame::Close(reason) => {
+                self.send(Message::Close(reason)).await;
+                false
+            }
+        }
+    }
+
+    async fn handle_message(&mut self, message: LiveClientMessage) -> bool {
+        let reply = match message {
+            LiveClientMessage::Subscribe { topic } => match LiveTopic::parse(&topic) {
+                Some(parsed) if self.subscriptions.subscribe(parsed) => LiveServerMessage::Subscribed {
+                    topic: parsed.to_string(),
+                },
+                Some(_) => LiveServerMessage::error(format!("Topic limit reached ({})", MAX_LIVE_TOPICS)),
+                None => LiveServerMessage::error(format!("Unknown topic: {}", topic)),
+            },
+            LiveClientMessage::Unsubscribe { topic } => match LiveTopic::parse(&topic) {
+                Some(parsed) => {
+                    self.subscriptions.unsubscribe(&parsed);
+                    LiveServerMessage::Unsubscribed {
+                        topic: parsed.to_string(),
+                    }
+                }
+                None => LiveServerMessage::error(format!("Unknown topic: {}", topic)),
+            },
+            // A confirmação chega como `read_state`, junto com os outros dispositivos
+            LiveClientMessage::MarkRead { article_id } => {
+                match self.mark_article_read_use_case.execute(self.user.id, article_id).await {
+                    Ok(()) => return true,
+                    Err(MarkArticleReadError::NotFound) => LiveServerMessage::error("Article not found"),
+                    Err(e) => {
+                        log::error!("Failed to mark article as read: {:?}", e);
+                        LiveServerMessage::error("Failed to update read state")
+                    }
+                }
+            }
+            LiveClientMessage::MarkUnread { article_id } => {
+                match self.mark_article_unread_use_case.execute(self.user.id, article_id).await {
+                    Ok(()) => return true,
+                    Err(MarkArticleUnreadError::NotFound) => LiveServerMessage::error("Article not found"),
+                    Err(e) => {
+                        log::error!("Failed to mark article as unread: {:?}", e);
+                        LiveServerMessage::error("Failed to update read state")
+                    }
+                }
+            }
+            LiveClientMessage::Ping => LiveServerMessage::Pong,
+        };
+
+        self.send_json(&reply).await
+    }
+
+    /// Fecha conexões caladas e sessões revogadas ou expiradas; senão, envia um ping
+    async fn heartbeat(&mut self) -> bool {
+        if self.last_seen.elapsed() > CLIENT_TIMEOUT {
+            return self.close(CloseCode::Away, "Heartbeat timeout").await;
+        }
+        if self.auth_middleware.reauthorize(self.user.id, self.session_id).await.is_err() {
+            return self.close(CloseCode::Policy, "Session expired").await;
+        }
+        self.send(Message::Ping(Bytes::new())).await
+    }
+
+    async fn close(&mut self, code: CloseCode, description: &str) -> bool {
+        self.send(Message::Close(Some((code, description).into()))).await;
+        false
+    }
+
+    async fn send_json(&mut self, message: &LiveServerMessage) -> bool {
+        match serde_json::to_string(message) {
+            Ok(text) => self.send(Message::Text(text.into())).await,
+            Err(e) => {
+                log::error!("Failed to serialize live message: {:?}", e);
+                true
+            }
+        }
+    }
+
+    async fn send(&mut self, message: Message) -> bool {
+        let mut frame = BytesMut::new();
+        if self.codec.encode(message, &mut frame).is_err() {
+            return false;
+        }
+        self.outgoing.send(frame.freeze()).await.is_ok()
+    }
+}
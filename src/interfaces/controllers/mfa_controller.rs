@@ -0,0 +1,92 @@
+use crate::application::use_cases::confirm_totp::ConfirmTotpUseCase;
+use crate::application::use_cases::disable_totp::DisableTotpUseCase;
+use crate::application::use_cases::enroll_totp::EnrollTotpUseCase;
+use crate::domain::entities::mfa::{ConfirmTotpDto, DisableTotpDto};
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+
+pub struct MfaController {
+    enroll_totp_use_case: Arc<EnrollTotpUseCase>,
+    confirm_totp_use_case: Arc<ConfirmTotpUseCase>,
+    disable_totp_use_case: Arc<DisableTotpUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl MfaController {
+    pub fn new(
+        enroll_totp_use_case: Arc<EnrollTotpUseCase>,
+        confirm_totp_use_case: Arc<ConfirmTotpUseCase>,
+        disable_totp_use_case: Arc<DisableTotpUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            enroll_totp_use_case,
+            confirm_totp_use_case,
+            disable_totp_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// POST /users/me/mfa/totp - Inicia o cadastro do TOTP e retorna a URI para o QR code.
+    /// Aceita o token de cadastro emitido no login de quem tem o 2FA obrigatório
+    pub async fn enroll(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user_id = match self.auth_middleware.require_mfa_enrollment(&req).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.enroll_totp_use_case.execute(user_id).await {
+            Ok(enrollment) => ActixWebServer::respond_as(media_type, Response::created(), &enrollment),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /users/me/mfa/totp/confirm - Ativa o 2FA e retorna os códigos de recuperação
+    pub async fn confirm(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user_id = match self.auth_middleware.require_mfa_enrollment(&req).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response,
+        };
+
+        let dto: ConfirmTotpDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.confirm_totp_use_case.execute(user_id, dto).await {
+            Ok(codes) => ActixWebServer::respond_as(media_type, Response::ok(), &codes),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// DELETE /users/me/mfa/totp - Desativa o 2FA, exigindo a senha
+    pub async fn disable(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user_id = match self.auth_middleware.require_auth(&req).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response,
+        };
+
+        let dto: DisableTotpDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        match self.disable_totp_use_case.execute(user_id, dto).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+}
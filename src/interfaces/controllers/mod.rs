@@ -3,4 +3,29 @@
 pub mod example_controller;
 pub mod auth_controller;
 pub mod article_category_controller;
-pub mod article_controller;
\ No newline at end of file
+pub mod article_controller;
+pub mod admin_controller;
+pub mod user_controller;
+pub mod mfa_controller;
+pub mod api_key_controller;
+pub mod well_known_controller;
+pub mod session_controller;
+pub mod oidc_controller;
+pub mod subscription_controller;
+pub mod read_state_controller;
+pub mod saved_article_controller;
+pub mod collection_controller;
+pub mod article_feedback_controller;
+pub mod annotation_controller;
+pub mod tag_controller;
+pub mod article_tag_controller;
+pub mod smart_folder_controller;
+pub mod alert_controller;
+pub mod notification_controller;
+pub mod digest_controller;
+pub mod webhook_controller;
+pub mod article_stream_controller;
+pub mod live_controller;
+pub mod syndication_controller;
+pub mod article_export_controller;
+pub mod article_import_controller;
\ No newline at end of file
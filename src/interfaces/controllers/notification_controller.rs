@@ -0,0 +1,95 @@
+use crate::application::use_cases::list_notifications::ListNotificationsUseCase;
+use crate::application::use_cases::mark_notifications_read::MarkNotificationsReadUseCase;
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use crate::interfaces::controllers::article_controller::ListArticlesQuery;
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caixa de entrada de notificações dos alertas do usuário autenticado.
+/// Aceita JWT ou chave de API com o escopo `articles:read` (listagem) ou `library:write` (marcações)
+pub struct NotificationController {
+    list_notifications_use_case: Arc<ListNotificationsUseCase>,
+    mark_notifications_read_use_case: Arc<MarkNotificationsReadUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl NotificationController {
+    pub fn new(
+        list_notifications_use_case: Arc<ListNotificationsUseCase>,
+        mark_notifications_read_use_case: Arc<MarkNotificationsReadUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            list_notifications_use_case,
+            mark_notifications_read_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// GET /me/notifications - Lista as notificações (`unread`, `page`, `per_page`),
+    /// com o total de não lidas em `unread`
+    pub async fn list(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let query = match ListArticlesQuery::from_request(&req) {
+            Ok(query) => query,
+            Err(response) => return response,
+        };
+
+        match self
+            .list_notifications_use_case
+            .execute(user.id, query.unread, query.page, query.per_page)
+            .await
+        {
+            Ok(paginated) => ActixWebServer::respond(&req, Response::ok(), &paginated),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /me/notifications/:id/read - Marca uma notificação como lida
+    pub async fn mark_read(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let id = match Uuid::parse_str(req.match_info().get("id").unwrap_or("")) {
+            Ok(id) => id,
+            Err(_) => return Self::error(Response::bad_request(), "Invalid notification ID"),
+        };
+
+        match self.mark_notifications_read_use_case.execute(user.id, id).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /me/notifications/read - Marca todas as notificações como lidas
+    pub async fn mark_all_read(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.mark_notifications_read_use_case.execute_all(user.id).await {
+            Ok(marked) => ActixWebServer::respond_as(media_type, Response::ok(), &marked),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::application::use_cases::complete_oidc_login::{CompleteOidcLoginError, CompleteOidcLoginUseCase};
+use crate::application::use_cases::start_oidc_login::StartOidcLoginUseCase;
+use crate::domain::entities::identity::OidcCallbackDto;
+use crate::infrastructure::web::client_ip::TrustedProxies;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use crate::interfaces::controllers::auth_controller::AuthController;
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+
+/// Login por provedores OpenID Connect (authorization code com PKCE)
+pub struct OidcController {
+    start_oidc_login_use_case: Arc<StartOidcLoginUseCase>,
+    complete_oidc_login_use_case: Arc<CompleteOidcLoginUseCase>,
+    trusted_proxies: TrustedProxies,
+}
+
+impl OidcController {
+    pub fn new(
+        start_oidc_login_use_case: Arc<StartOidcLoginUseCase>,
+        complete_oidc_login_use_case: Arc<CompleteOidcLoginUseCase>,
+        trusted_proxies: TrustedProxies,
+    ) -> Self {
+        Self {
+            start_oidc_login_use_case,
+            complete_oidc_login_use_case,
+            trusted_proxies,
+        }
+    }
+
+    /// GET /auth/oidc/providers - Lista os provedores configurados
+    pub async fn providers(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let providers = self.start_oidc_login_use_case.available_providers();
+
+        ActixWebServer::respond(&req, Response::ok(), &serde_json::json!({ "providers": providers }))
+    }
+
+    /// GET /auth/oidc/:provider/authorize - Gera a URL de autorização e o `state` do login
+    pub async fn authorize(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let provider = req.match_info().get("provider").unwrap_or("").to_string();
+
+        match self.start_oidc_login_use_case.execute(&provider).await {
+            Ok(authorization) => ActixWebServer::respond(&req, Response::ok(), &authorization),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /auth/oidc/:provider/callback - Troca o `code` pelo token da API
+    pub async fn callback(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let provider = req.match_info().get("provider").unwrap_or("").to_string();
+
+        let dto: OidcCallbackDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        let context = AuthController::login_context(&req, &self.trusted_proxies);
+
+        match self
+            .complete_oidc_login_use_case
+            .execute(&provider, dto, context)
+            .await
+        {
+            // Com 2FA ativo a resposta é o desafio, como no login por senha
+            Ok(outcome) => ActixWebServer::respond_as(media_type, Response::ok(), &outcome),
+            Err(e) => {
+                // Só os 5xx têm a causa registrada pelo middleware; a recusa do provedor também vai para o log
+                if let CompleteOidcLoginError::ProviderRejected(msg) = &e {
+                    log::warn!("OIDC login with '{}' rejected: {}", provider, msg);
+                }
+                ActixWebServer::problem(e.into())
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::application::use_cases::get_unread_counts::GetUnreadCountsUseCase;
+use crate::application::use_cases::mark_all_read::MarkAllReadUseCase;
+use crate::application::use_cases::mark_article_read::MarkArticleReadUseCase;
+use crate::application::use_cases::mark_article_unread::MarkArticleUnreadUseCase;
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::domain::entities::article::MarkAllReadDto;
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Estado de leitura dos artigos por usuário: marcação individual, em lote e contagem de não lidos.
+/// Aceita JWT ou chave de API com o escopo `articles:read` (contagens) ou `library:write` (marcações)
+pub struct ReadStateController {
+    mark_article_read_use_case: Arc<MarkArticleReadUseCase>,
+    mark_article_unread_use_case: Arc<MarkArticleUnreadUseCase>,
+    mark_all_read_use_case: Arc<MarkAllReadUseCase>,
+    get_unread_counts_use_case: Arc<GetUnreadCountsUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl ReadStateController {
+    pub fn new(
+        mark_article_read_use_case: Arc<MarkArticleReadUseCase>,
+        mark_article_unread_use_case: Arc<MarkArticleUnreadUseCase>,
+        mark_all_read_use_case: Arc<MarkAllReadUseCase>,
+        get_unread_counts_use_case: Arc<GetUnreadCountsUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            mark_article_read_use_case,
+            mark_article_unread_use_case,
+            mark_all_read_use_case,
+            get_unread_counts_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// POST /articles/:id/read - Marca o artigo como lido
+    pub async fn mark_read(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let article_id = match Self::article_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        match self.mark_article_read_use_case.execute(user.id, article_id).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /articles/:id/unread - Volta o artigo para não lido
+    pub async fn mark_unread(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let article_id = match Self::article_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        match self.mark_article_unread_use_case.execute(user.id, article_id).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /me/articles/read - Marca em lote (`feed_id`, `category_id` e `before` opcionais)
+    pub async fn mark_all_read(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        // Corpo vazio marca toda a timeline
+        let dto: MarkAllReadDto = if body.is_empty() {
+            MarkAllReadDto::default()
+        } else {
+            match ActixWebServer::parse_body(&req, body) {
+                Ok(dto) => dto,
+                Err(response) => return response,
+            }
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.mark_all_read_use_case.execute(user.id, dto).await {
+            Ok(result) => ActixWebServer::respond_as(media_type, Response::ok(), &result),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// GET /me/unread-counts - Não lidos por feed e por categoria nos feeds assinados
+    pub async fn unread_counts(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        match self.get_unread_counts_use_case.execute(user.id).await {
+            Ok(counts) => ActixWebServer::respond(&req, Response::ok(), &counts),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    fn article_id(req: &HttpRequest) -> Result<Uuid, HttpResponse> {
+        Uuid::parse_str(req.match_info().get("id").unwrap_or(""))
+            .map_err(|_| Self::error(Response::bad_request(), "Invalid article ID"))
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
@@ -0,0 +1,170 @@
+use crate::application::use_cases::list_saved_articles::ListSavedArticlesUseCase;
+use crate::application::use_cases::move_saved_article::MoveSavedArticleUseCase;
+use crate::application::use_cases::reorder_saved_articles::ReorderSavedArticlesUseCase;
+use crate::application::use_cases::save_article::SaveArticleUseCase;
+use crate::application::use_cases::unsave_article::UnsaveArticleUseCase;
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::domain::entities::saved_article::{MoveSavedArticleDto, ReorderSavedArticlesDto, SaveArticleDto};
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+struct ListSavedQuery {
+    collection_id: Option<Uuid>,
+    tag_id: Option<Uuid>,
+}
+
+/// Artigos salvos para ler depois. Aceita JWT ou chave de API com o escopo `articles:read` (listagem)
+/// ou `library:write` (alterações)
+pub struct SavedArticleController {
+    save_article_use_case: Arc<SaveArticleUseCase>,
+    list_saved_articles_use_case: Arc<ListSavedArticlesUseCase>,
+    move_saved_article_use_case: Arc<MoveSavedArticleUseCase>,
+    unsave_article_use_case: Arc<UnsaveArticleUseCase>,
+    reorder_saved_articles_use_case: Arc<ReorderSavedArticlesUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl SavedArticleController {
+    pub fn new(
+        save_article_use_case: Arc<SaveArticleUseCase>,
+        list_saved_articles_use_case: Arc<ListSavedArticlesUseCase>,
+        move_saved_article_use_case: Arc<MoveSavedArticleUseCase>,
+        unsave_article_use_case: Arc<UnsaveArticleUseCase>,
+        reorder_saved_articles_use_case: Arc<ReorderSavedArticlesUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            save_article_use_case,
+            list_saved_articles_use_case,
+            move_saved_article_use_case,
+            unsave_article_use_case,
+            reorder_saved_articles_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// GET /me/saved - Lista os artigos salvos (`collection_id` filtra por coleção e `tag_id` por tag)
+    pub async fn list(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let query = match web::Query::<ListSavedQuery>::from_query(req.query_string()) {
+            Ok(query) => query.into_inner(),
+            Err(_) => return Self::error(Response::bad_request(), "Invalid collection or tag ID"),
+        };
+
+        match self.list_saved_articles_use_case.execute(user.id, query.collection_id, query.tag_id).await {
+            Ok(saved) => ActixWebServer::respond(&req, Response::ok(), &saved),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /me/saved - Salva um artigo (`article_id`, `collection_id` opcional)
+    pub async fn save(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let dto: SaveArticleDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.save_article_use_case.execute(user.id, dto).await {
+            Ok(saved) => ActixWebServer::respond_as(media_type, Response::created(), &saved),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// PATCH /me/saved/:id - Move o item para outra coleção (`collection_id: null` o retira)
+    pub async fn move_to(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let id = match Self::saved_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        let dto: MoveSavedArticleDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.move_saved_article_use_case.execute(user.id, id, dto).await {
+            Ok(saved) => ActixWebServer::respond_as(media_type, Response::ok(), &saved),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// DELETE /me/saved/:id - Remove o item dos salvos
+    pub async fn unsave(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let id = match Self::saved_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        match self.unsave_article_use_case.execute(user.id, id).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// PUT /me/saved/order - Reordena os itens de uma coleção (`collection_id`, `ids` na nova ordem)
+    pub async fn reorder(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let dto: ReorderSavedArticlesDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.reorder_saved_articles_use_case.execute(user.id, dto).await {
+            Ok(saved) => ActixWebServer::respond_as(media_type, Response::ok(), &saved),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    fn saved_id(req: &HttpRequest) -> Result<Uuid, HttpResponse> {
+        Uuid::parse_str(req.match_info().get("id").unwrap_or(""))
+            .map_err(|_| Self::error(Response::bad_request(), "Invalid saved article ID"))
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
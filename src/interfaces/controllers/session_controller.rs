@@ -0,0 +1,63 @@
+use crate::application::use_cases::list_sessions::ListSessionsUseCase;
+use crate::application::use_cases::revoke_session::RevokeSessionUseCase;
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Sessões de login do usuário (um registro por dispositivo conectado)
+pub struct SessionController {
+    list_sessions_use_case: Arc<ListSessionsUseCase>,
+    revoke_session_use_case: Arc<RevokeSessionUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl SessionController {
+    pub fn new(
+        list_sessions_use_case: Arc<ListSessionsUseCase>,
+        revoke_session_use_case: Arc<RevokeSessionUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            list_sessions_use_case,
+            revoke_session_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// GET /users/me/sessions - Lista as sessões ativas, marcando a atual
+    pub async fn list(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let (user_id, session_id) = match self.auth_middleware.require_session(&req).await {
+            Ok(session) => session,
+            Err(response) => return response,
+        };
+
+        match self.list_sessions_use_case.execute(user_id, session_id).await {
+            Ok(sessions) => ActixWebServer::respond(&req, Response::ok(), &sessions),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// DELETE /users/me/sessions/:id - Revoga uma sessão (a própria sessão equivale a logout)
+    pub async fn revoke(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user_id = match self.auth_middleware.require_auth(&req).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response,
+        };
+
+        let id = match Uuid::parse_str(req.match_info().get("id").unwrap_or("")) {
+            Ok(id) => id,
+            Err(_) => return Self::error(Response::bad_request(), "Invalid session ID"),
+        };
+
+        match self.revoke_session_use_case.execute(user_id, id).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
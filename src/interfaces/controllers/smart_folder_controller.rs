@@ -0,0 +1,160 @@
+use crate::application::use_cases::create_smart_folder::CreateSmartFolderUseCase;
+use crate::application::use_cases::delete_smart_folder::DeleteSmartFolderUseCase;
+use crate::application::use_cases::list_smart_folder_articles::ListSmartFolderArticlesUseCase;
+use crate::application::use_cases::list_smart_folders::ListSmartFoldersUseCase;
+use crate::application::use_cases::update_smart_folder::UpdateSmartFolderUseCase;
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::domain::entities::smart_folder::{CreateSmartFolderDto, UpdateSmartFolderDto};
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use crate::interfaces::controllers::article_controller::ListArticlesQuery;
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Pastas inteligentes (buscas salvas) do usuário autenticado.
+/// Aceita JWT ou chave de API com o escopo `articles:read` (consultas) ou `library:write` (alterações)
+pub struct SmartFolderController {
+    create_smart_folder_use_case: Arc<CreateSmartFolderUseCase>,
+    list_smart_folders_use_case: Arc<ListSmartFoldersUseCase>,
+    update_smart_folder_use_case: Arc<UpdateSmartFolderUseCase>,
+    delete_smart_folder_use_case: Arc<DeleteSmartFolderUseCase>,
+    list_smart_folder_articles_use_case: Arc<ListSmartFolderArticlesUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl SmartFolderController {
+    pub fn new(
+        create_smart_folder_use_case: Arc<CreateSmartFolderUseCase>,
+        list_smart_folders_use_case: Arc<ListSmartFoldersUseCase>,
+        update_smart_folder_use_case: Arc<UpdateSmartFolderUseCase>,
+        delete_smart_folder_use_case: Arc<DeleteSmartFolderUseCase>,
+        list_smart_folder_articles_use_case: Arc<ListSmartFolderArticlesUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            create_smart_folder_use_case,
+            list_smart_folders_use_case,
+            update_smart_folder_use_case,
+            delete_smart_folder_use_case,
+            list_smart_folder_articles_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// GET /me/smart-folders - Lista as pastas com a contagem de não lidos (`unread`)
+    pub async fn list(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        match self.list_smart_folders_use_case.execute(user.id).await {
+            Ok(folders) => ActixWebServer::respond(&req, Response::ok(), &folders),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /me/smart-folders - Salva uma busca (`name` e `criteria`)
+    pub async fn create(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let dto: CreateSmartFolderDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.create_smart_folder_use_case.execute(user.id, dto).await {
+            Ok(folder) => ActixWebServer::respond_as(media_type, Response::created(), &folder),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// PATCH /me/smart-folders/:id - Renomeia e/ou substitui os critérios
+    pub async fn update(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let id = match Self::folder_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        let dto: UpdateSmartFolderDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.update_smart_folder_use_case.execute(user.id, id, dto).await {
+            Ok(folder) => ActixWebServer::respond_as(media_type, Response::ok(), &folder),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// DELETE /me/smart-folders/:id - Remove a pasta
+    pub async fn delete(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let id = match Self::folder_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        match self.delete_smart_folder_use_case.execute(user.id, id).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// GET /me/smart-folders/:id/articles - Artigos que atendem aos critérios da pasta,
+    /// paginados como `GET /me/articles` (`page`, `per_page`)
+    pub async fn articles(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let id = match Self::folder_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        let query = match ListArticlesQuery::from_request(&req) {
+            Ok(query) => query,
+            Err(response) => return response,
+        };
+
+        match self.list_smart_folder_articles_use_case.execute(user.id, id, query.page, query.per_page).await {
+            Ok(paginated) => ActixWebServer::respond(&req, Response::ok(), &paginated),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    fn folder_id(req: &HttpRequest) -> Result<Uuid, HttpResponse> {
+        Uuid::parse_str(req.match_info().get("id").unwrap_or(""))
+            .map_err(|_| Self::error(Response::bad_request(), "Invalid smart folder ID"))
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
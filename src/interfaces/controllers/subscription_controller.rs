@@ -0,0 +1,191 @@
+use crate::application::use_cases::list_for_you::ListForYouUseCase;
+use crate::application::use_cases::list_subscriptions::ListSubscriptionsUseCase;
+use crate::application::use_cases::list_timeline::ListTimelineUseCase;
+use crate::application::use_cases::subscribe_feed::SubscribeFeedUseCase;
+use crate::application::use_cases::unsubscribe_feed::UnsubscribeFeedUseCase;
+use crate::application::use_cases::update_subscription::UpdateSubscriptionUseCase;
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::domain::entities::subscription::{CreateSubscriptionDto, UpdateSubscriptionDto};
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use crate::interfaces::controllers::article_controller::ListArticlesQuery;
+use actix_web::{web, HttpRequest, HttpResponse};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+struct ForYouQuery {
+    page: Option<i64>,
+    per_page: Option<i64>,
+    /// Inclui a composição da pontuação de cada artigo
+    #[serde(default)]
+    explain: bool,
+}
+
+/// Assinaturas de feeds do usuário e a timeline pessoal montada a partir delas.
+/// Aceita JWT ou chave de API com os escopos `feeds:read`/`feeds:write` (timeline: `articles:read`).
+pub struct SubscriptionController {
+    subscribe_feed_use_case: Arc<SubscribeFeedUseCase>,
+    list_subscriptions_use_case: Arc<ListSubscriptionsUseCase>,
+    update_subscription_use_case: Arc<UpdateSubscriptionUseCase>,
+    unsubscribe_feed_use_case: Arc<UnsubscribeFeedUseCase>,
+    list_timeline_use_case: Arc<ListTimelineUseCase>,
+    list_for_you_use_case: Arc<ListForYouUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl SubscriptionController {
+    pub fn new(
+        subscribe_feed_use_case: Arc<SubscribeFeedUseCase>,
+        list_subscriptions_use_case: Arc<ListSubscriptionsUseCase>,
+        update_subscription_use_case: Arc<UpdateSubscriptionUseCase>,
+        unsubscribe_feed_use_case: Arc<UnsubscribeFeedUseCase>,
+        list_timeline_use_case: Arc<ListTimelineUseCase>,
+        list_for_you_use_case: Arc<ListForYouUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            subscribe_feed_use_case,
+            list_subscriptions_use_case,
+            update_subscription_use_case,
+            unsubscribe_feed_use_case,
+            list_timeline_use_case,
+            list_for_you_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// GET /me/subscriptions - Lista os feeds assinados
+    pub async fn list(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::FeedsRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        match self.list_subscriptions_use_case.execute(user.id).await {
+            Ok(subscriptions) => ActixWebServer::respond(&req, Response::ok(), &subscriptions),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /me/subscriptions - Assina um feed (`feed_id`, `title`, `folder` e `weight` opcionais)
+    pub async fn subscribe(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::FeedsWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let dto: CreateSubscriptionDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.subscribe_feed_use_case.execute(user.id, dto).await {
+            Ok(subscription) => ActixWebServer::respond_as(media_type, Response::created(), &subscription),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// PATCH /me/subscriptions/:id - Altera título, pasta (valor vazio remove) e/ou peso
+    pub async fn update(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::FeedsWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let id = match Self::subscription_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        let dto: UpdateSubscriptionDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.update_subscription_use_case.execute(user.id, id, dto).await {
+            Ok(subscription) => ActixWebServer::respond_as(media_type, Response::ok(), &subscription),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// DELETE /me/subscriptions/:id - Cancela a assinatura
+    pub async fn unsubscribe(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::FeedsWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let id = match Self::subscription_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        match self.unsubscribe_feed_use_case.execute(user.id, id).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// GET /me/articles - Artigos dos feeds assinados, paginados e filtrados como `GET /articles` (e por `tag_id`)
+    pub async fn timeline(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let query = match ListArticlesQuery::from_request(&req) {
+            Ok(query) => query,
+            Err(response) => return response,
+        };
+
+        match self.list_timeline_use_case.execute_filtered(user.id, query.filter(), query.tag_id, query.page, query.per_page).await {
+            Ok(paginated) => ActixWebServer::respond(&req, Response::ok(), &paginated),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// GET /me/articles/for-you - Não lidos recentes dos feeds assinados, ordenados pela pontuação pessoal
+    /// (`explain=true` inclui `score_breakdown`)
+    pub async fn for_you(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let query = match web::Query::<ForYouQuery>::from_query(req.query_string()) {
+            Ok(query) => query.into_inner(),
+            Err(_) => return Self::error(Response::bad_request(), "Invalid query parameters"),
+        };
+
+        match self
+            .list_for_you_use_case
+            .execute(user.id, query.explain, query.page.unwrap_or(1), query.per_page.unwrap_or(20))
+            .await
+        {
+            Ok(paginated) => ActixWebServer::respond(&req, Response::ok(), &paginated),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    fn subscription_id(req: &HttpRequest) -> Result<Uuid, HttpResponse> {
+        Uuid::parse_str(req.match_info().get("id").unwrap_or(""))
+            .map_err(|_| Self::error(Response::bad_request(), "Invalid subscription ID"))
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
@@ -0,0 +1,124 @@
+use crate::application::services::syndication_renderer::SyndicationRenderer;
+use crate::application::use_cases::get_category_feed::GetCategoryFeedUseCase;
+use crate::application::use_cases::get_collection_feed::GetCollectionFeedUseCase;
+use crate::domain::entities::syndication::{FeedFormat, SyndicationFeed};
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Feeds de saída (RSS, Atom e JSON Feed) das categorias e das coleções compartilhadas.
+/// As rotas são públicas e respondem a requisições condicionais com 304
+pub struct SyndicationController {
+    get_category_feed_use_case: Arc<GetCategoryFeedUseCase>,
+    get_collection_feed_use_case: Arc<GetCollectionFeedUseCase>,
+    /// Tempo (em segundos) que leitores e proxies podem reutilizar um feed sem revalidar
+    cache_max_age: u64,
+}
+
+impl SyndicationController {
+    pub fn new(
+        get_category_feed_use_case: Arc<GetCategoryFeedUseCase>,
+        get_collection_feed_use_case: Arc<GetCollectionFeedUseCase>,
+        cache_max_age: u64,
+    ) -> Self {
+        Self {
+            get_category_feed_use_case,
+            get_collection_feed_use_case,
+            cache_max_age,
+        }
+    }
+
+    /// GET /categories/:id/feed.xml|feed.atom|feed.json - Artigos mais recentes da categoria
+    pub async fn category_feed(&self, req: HttpRequest, _body: web::Bytes, format: FeedFormat) -> HttpResponse {
+        let category_id = match Uuid::parse_str(req.match_info().get("id").unwrap_or("")) {
+            Ok(id) => id,
+            Err(_) => return Self::error(Response::bad_request(), "Invalid category ID"),
+        };
+
+        match self.get_category_feed_use_case.execute(category_id).await {
+            Ok(feed) => {
+                let home_page_url = format!("{}/articles?category_id={}", Self::base_url(&req), category_id);
+                self.respond(&req, &feed, format, &home_page_url, "public")
+            }
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// GET /shared/collections/:token/feed.xml|feed.atom|feed.json - Itens da coleção compartilhada
+    pub async fn collection_feed(&self, req: HttpRequest, _body: web::Bytes, format: FeedFormat) -> HttpResponse {
+        let token = req.match_info().get("token").unwrap_or("");
+
+        match self.get_collection_feed_use_case.execute(token).await {
+            Ok(feed) => {
+                let home_page_url = format!("{}/shared/collections/{}", Self::base_url(&req), token);
+                // O token na URL é a credencial: caches compartilhados não podem guardar a resposta
+                self.respond(&req, &feed, format, &home_page_url, "private")
+            }
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// `cache_scope` é `public` ou `private` no `Cache-Control`
+    fn respond(
+        &self,
+        req: &HttpRequest,
+        feed: &SyndicationFeed,
+        format: FeedFormat,
+        home_page_url: &str,
+        cache_scope: &str,
+    ) -> HttpResponse {
+        let feed_url = format!("{}{}", Self::base_url(req), req.path());
+        let body = SyndicationRenderer::render(feed, format, &feed_url, home_page_url);
+
+        let etag = format!("\"{}\"", hex::encode(Sha256::digest(body.as_bytes())));
+        let last_modified = http_date(feed.updated);
+
+        let response = if Self::is_fresh(req, &etag, feed.updated) {
+            Response::not_modified()
+        } else {
+            Response::ok().text(body).with_header("Content-Type", format.content_type())
+        };
+
+        ActixWebServer::convert_response(
+            response
+                .with_header("Cache-Control", format!("{}, max-age={}", cache_scope, self.cache_max_age))
+                .with_header("ETag", etag)
+                .with_header("Last-Modified", last_modified),
+        )
+    }
+
+    /// `If-None-Match` tem precedência; `If-Modified-Since` só vale quando ele não foi enviado
+    fn is_fresh(req: &HttpRequest, etag: &str, updated: DateTime<Utc>) -> bool {
+        if let Some(if_none_match) = Self::header(req, "If-None-Match") {
+            return if_none_match
+                .split(',')
+                .map(|tag| tag.trim().trim_start_matches("W/"))
+                .any(|tag| tag == "*" || tag == etag);
+        }
+
+        Self::header(req, "If-Modified-Since")
+            .and_then(|value| DateTime::parse_from_rfc2822(value).ok())
+            .is_some_and(|since| updated.timestamp() <= since.timestamp())
+    }
+
+    fn header<'a>(req: &'a HttpRequest, name: &str) -> Option<&'a str> {
+        req.headers().get(name).and_then(|value| value.to_str().ok())
+    }
+
+    fn base_url(req: &HttpRequest) -> String {
+        let info = req.connection_info();
+        format!("{}://{}", info.scheme(), info.host())
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
+
+/// Data no formato de cabeçalho HTTP (IMF-fixdate)
+fn http_date(date: DateTime<Utc>) -> String {
+    date.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
\ No newline at end of file
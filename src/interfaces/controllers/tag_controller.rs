@@ -0,0 +1,194 @@
+use crate::application::use_cases::autocomplete_tags::AutocompleteTagsUseCase;
+use crate::application::use_cases::create_tag::CreateTagUseCase;
+use crate::application::use_cases::delete_tag::DeleteTagUseCase;
+use crate::application::use_cases::list_tags::ListTagsUseCase;
+use crate::application::use_cases::merge_tags::MergeTagsUseCase;
+use crate::application::use_cases::rename_tag::RenameTagUseCase;
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::domain::entities::tag::{CreateTagDto, MergeTagDto, RenameTagDto};
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+struct AutocompleteQuery {
+    #[serde(default)]
+    q: String,
+    limit: Option<i64>,
+}
+
+/// Tags pessoais dos usuários. Aceita JWT ou chave de API com o escopo `articles:read` (consultas)
+/// ou `library:write` (alterações)
+pub struct TagController {
+    create_tag_use_case: Arc<CreateTagUseCase>,
+    list_tags_use_case: Arc<ListTagsUseCase>,
+    autocomplete_tags_use_case: Arc<AutocompleteTagsUseCase>,
+    rename_tag_use_case: Arc<RenameTagUseCase>,
+    merge_tags_use_case: Arc<MergeTagsUseCase>,
+    delete_tag_use_case: Arc<DeleteTagUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl TagController {
+    pub fn new(
+        create_tag_use_case: Arc<CreateTagUseCase>,
+        list_tags_use_case: Arc<ListTagsUseCase>,
+        autocomplete_tags_use_case: Arc<AutocompleteTagsUseCase>,
+        rename_tag_use_case: Arc<RenameTagUseCase>,
+        merge_tags_use_case: Arc<MergeTagsUseCase>,
+        delete_tag_use_case: Arc<DeleteTagUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            create_tag_use_case,
+            list_tags_use_case,
+            autocomplete_tags_use_case,
+            rename_tag_use_case,
+            merge_tags_use_case,
+            delete_tag_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// GET /me/tags - Tags do usuário com a quantidade de artigos marcados
+    pub async fn list(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        match self.list_tags_use_case.execute(user.id).await {
+            Ok(tags) => ActixWebServer::respond(&req, Response::ok(), &tags),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// GET /me/tags/autocomplete?q=&limit= - Tags que começam com `q`, das mais usadas para as menos usadas
+    pub async fn autocomplete(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::ArticlesRead).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let query = match web::Query::<AutocompleteQuery>::from_query(req.query_string()) {
+            Ok(query) => query.into_inner(),
+            Err(_) => return Self::error(Response::bad_request(), "Invalid query parameters"),
+        };
+
+        let limit = query.limit.unwrap_or(AutocompleteTagsUseCase::DEFAULT_LIMIT);
+        match self.autocomplete_tags_use_case.execute(user.id, &query.q, limit).await {
+            Ok(tags) => ActixWebServer::respond(&req, Response::ok(), &tags),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /me/tags - Cria uma tag
+    pub async fn create(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let dto: CreateTagDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.create_tag_use_case.execute(user.id, dto).await {
+            Ok(tag) => ActixWebServer::respond_as(media_type, Response::created(), &tag),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// PATCH /me/tags/:id - Renomeia uma tag
+    pub async fn rename(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let tag_id = match Self::path_id(&req, "id", "Invalid tag ID") {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        let dto: RenameTagDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.rename_tag_use_case.execute(user.id, tag_id, dto).await {
+            Ok(tag) => ActixWebServer::respond_as(media_type, Response::ok(), &tag),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /me/tags/:id/merge - Junta a tag em outra (`into`) e remove a original
+    pub async fn merge(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let tag_id = match Self::path_id(&req, "id", "Invalid tag ID") {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        let dto: MergeTagDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.merge_tags_use_case.execute(user.id, tag_id, dto).await {
+            Ok(tag) => ActixWebServer::respond_as(media_type, Response::ok(), &tag),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// DELETE /me/tags/:id - Remove uma tag (os artigos ficam sem ela)
+    pub async fn delete(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user = match self.auth_middleware.require_scope(&req, ApiKeyScope::LibraryWrite).await {
+            Ok(user) => user,
+            Err(response) => return response,
+        };
+
+        let tag_id = match Self::path_id(&req, "id", "Invalid tag ID") {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        match self.delete_tag_use_case.execute(user.id, tag_id).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    fn path_id(req: &HttpRequest, name: &str, message: &str) -> Result<Uuid, HttpResponse> {
+        Uuid::parse_str(req.match_info().get(name).unwrap_or(""))
+            .map_err(|_| Self::error(Response::bad_request(), message))
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
@@ -0,0 +1,137 @@
+use crate::application::use_cases::change_password::ChangePasswordUseCase;
+use crate::application::use_cases::confirm_email_change::ConfirmEmailChangeUseCase;
+use crate::application::use_cases::delete_account::DeleteAccountUseCase;
+use crate::application::use_cases::get_profile::GetProfileUseCase;
+use crate::application::use_cases::update_profile::UpdateProfileUseCase;
+use crate::domain::entities::user::{
+    ChangePasswordDto, ConfirmEmailChangeDto, DeleteAccountDto, UpdateProfileDto,
+};
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+
+pub struct UserController {
+    get_profile_use_case: Arc<GetProfileUseCase>,
+    update_profile_use_case: Arc<UpdateProfileUseCase>,
+    confirm_email_change_use_case: Arc<ConfirmEmailChangeUseCase>,
+    change_password_use_case: Arc<ChangePasswordUseCase>,
+    delete_account_use_case: Arc<DeleteAccountUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl UserController {
+    pub fn new(
+        get_profile_use_case: Arc<GetProfileUseCase>,
+        update_profile_use_case: Arc<UpdateProfileUseCase>,
+        confirm_email_change_use_case: Arc<ConfirmEmailChangeUseCase>,
+        change_password_use_case: Arc<ChangePasswordUseCase>,
+        delete_account_use_case: Arc<DeleteAccountUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            get_profile_use_case,
+            update_profile_use_case,
+            confirm_email_change_use_case,
+            change_password_use_case,
+            delete_account_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// GET /users/me - Perfil do usuário autenticado
+    pub async fn me(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let user_id = match self.auth_middleware.require_auth(&req).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response,
+        };
+
+        match self.get_profile_use_case.execute(user_id).await {
+            Ok(profile) => ActixWebServer::respond(&req, Response::ok(), &profile),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// PATCH /users/me - Atualiza nome e/ou email (email exige confirmação)
+    pub async fn update_me(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user_id = match self.auth_middleware.require_auth(&req).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response,
+        };
+
+        let dto: UpdateProfileDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.update_profile_use_case.execute(user_id, dto).await {
+            Ok(profile) => ActixWebServer::respond_as(media_type, Response::ok(), &profile),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /users/me/email/verify - Confirma a troca de email com o token recebido
+    pub async fn confirm_email(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user_id = match self.auth_middleware.require_auth(&req).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response,
+        };
+
+        let dto: ConfirmEmailChangeDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.confirm_email_change_use_case.execute(user_id, dto).await {
+            Ok(user) => ActixWebServer::respond_as(media_type, Response::ok(), &user),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /users/me/password - Troca a senha, exigindo a senha atual
+    pub async fn change_password(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let (user_id, session_id) = match self.auth_middleware.require_session(&req).await {
+            Ok(session) => session,
+            Err(response) => return response,
+        };
+
+        let dto: ChangePasswordDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        match self.change_password_use_case.execute(user_id, session_id, dto).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// DELETE /users/me - Remove a conta, exigindo confirmação da senha
+    pub async fn delete_me(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let user_id = match self.auth_middleware.require_auth(&req).await {
+            Ok(user_id) => user_id,
+            Err(response) => return response,
+        };
+
+        let dto: DeleteAccountDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        match self.delete_account_use_case.execute(user_id, dto).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+}
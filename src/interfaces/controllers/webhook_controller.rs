@@ -0,0 +1,180 @@
+use crate::application::use_cases::create_webhook::CreateWebhookUseCase;
+use crate::application::use_cases::delete_webhook::DeleteWebhookUseCase;
+use crate::application::use_cases::list_webhook_deliveries::ListWebhookDeliveriesUseCase;
+use crate::application::use_cases::list_webhooks::ListWebhooksUseCase;
+use crate::application::use_cases::test_webhook::TestWebhookUseCase;
+use crate::application::use_cases::update_webhook::UpdateWebhookUseCase;
+use crate::domain::entities::webhook::WebhookSubscriptionDto;
+use crate::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use crate::infrastructure::web::content::DOCUMENT_MEDIA_TYPES;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use crate::interfaces::controllers::article_controller::ListArticlesQuery;
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Webhooks de eventos de artigos para serviços externos.
+/// Requer papel de administrador
+pub struct WebhookController {
+    create_webhook_use_case: Arc<CreateWebhookUseCase>,
+    list_webhooks_use_case: Arc<ListWebhooksUseCase>,
+    update_webhook_use_case: Arc<UpdateWebhookUseCase>,
+    delete_webhook_use_case: Arc<DeleteWebhookUseCase>,
+    list_webhook_deliveries_use_case: Arc<ListWebhookDeliveriesUseCase>,
+    test_webhook_use_case: Arc<TestWebhookUseCase>,
+    auth_middleware: Arc<AuthMiddleware>,
+}
+
+impl WebhookController {
+    pub fn new(
+        create_webhook_use_case: Arc<CreateWebhookUseCase>,
+        list_webhooks_use_case: Arc<ListWebhooksUseCase>,
+        update_webhook_use_case: Arc<UpdateWebhookUseCase>,
+        delete_webhook_use_case: Arc<DeleteWebhookUseCase>,
+        list_webhook_deliveries_use_case: Arc<ListWebhookDeliveriesUseCase>,
+        test_webhook_use_case: Arc<TestWebhookUseCase>,
+        auth_middleware: Arc<AuthMiddleware>,
+    ) -> Self {
+        Self {
+            create_webhook_use_case,
+            list_webhooks_use_case,
+            update_webhook_use_case,
+            delete_webhook_use_case,
+            list_webhook_deliveries_use_case,
+            test_webhook_use_case,
+            auth_middleware,
+        }
+    }
+
+    /// GET /admin/webhooks - Lista os webhooks, sem os segredos
+    pub async fn list(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        if let Err(response) = self.auth_middleware.require_admin(&req).await {
+            return response;
+        }
+
+        match self.list_webhooks_use_case.execute().await {
+            Ok(webhooks) => ActixWebServer::respond(&req, Response::ok(), &webhooks),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /admin/webhooks - Cadastra um webhook (`name`, `url`, `events`, `enabled`);
+    /// a resposta traz o `secret` usado nas assinaturas, que não é exibido de novo
+    pub async fn create(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        let admin = match self.auth_middleware.require_admin(&req).await {
+            Ok(admin) => admin,
+            Err(response) => return response,
+        };
+
+        let dto: WebhookSubscriptionDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.create_webhook_use_case.execute(admin.id, dto).await {
+            Ok(webhook) => ActixWebServer::respond_as(media_type, Response::created(), &webhook),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// PUT /admin/webhooks/:id - Substitui nome, URL, eventos e estado; o segredo é mantido
+    pub async fn update(&self, req: HttpRequest, body: web::Bytes) -> HttpResponse {
+        if let Err(response) = self.auth_middleware.require_admin(&req).await {
+            return response;
+        }
+
+        let id = match Self::webhook_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        let dto: WebhookSubscriptionDto = match ActixWebServer::parse_body(&req, body) {
+            Ok(dto) => dto,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.update_webhook_use_case.execute(id, dto).await {
+            Ok(webhook) => ActixWebServer::respond_as(media_type, Response::ok(), &webhook),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// DELETE /admin/webhooks/:id - Remove o webhook e o histórico de entregas
+    pub async fn delete(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        if let Err(response) = self.auth_middleware.require_admin(&req).await {
+            return response;
+        }
+
+        let id = match Self::webhook_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        match self.delete_webhook_use_case.execute(id).await {
+            Ok(_) => ActixWebServer::convert_response(Response::no_content()),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// GET /admin/webhooks/:id/deliveries - Histórico de entregas (`page`, `per_page`)
+    pub async fn deliveries(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        if let Err(response) = self.auth_middleware.require_admin(&req).await {
+            return response;
+        }
+
+        let id = match Self::webhook_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+        let query = match ListArticlesQuery::from_request(&req) {
+            Ok(query) => query,
+            Err(response) => return response,
+        };
+
+        match self.list_webhook_deliveries_use_case.execute(id, query.page, query.per_page).await {
+            Ok(paginated) => ActixWebServer::respond(&req, Response::ok(), &paginated),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    /// POST /admin/webhooks/:id/test - Envia um evento `webhook.test` e retorna a entrega
+    pub async fn test(&self, req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        if let Err(response) = self.auth_middleware.require_admin(&req).await {
+            return response;
+        }
+
+        let id = match Self::webhook_id(&req) {
+            Ok(id) => id,
+            Err(response) => return response,
+        };
+
+        let media_type = match ActixWebServer::negotiate(&req, DOCUMENT_MEDIA_TYPES) {
+            Ok(media_type) => media_type,
+            Err(not_acceptable) => return not_acceptable,
+        };
+
+        match self.test_webhook_use_case.execute(id).await {
+            Ok(delivery) => ActixWebServer::respond_as(media_type, Response::ok(), &delivery),
+            Err(e) => ActixWebServer::problem(e.into()),
+        }
+    }
+
+    fn webhook_id(req: &HttpRequest) -> Result<Uuid, HttpResponse> {
+        Uuid::parse_str(req.match_info().get("id").unwrap_or(""))
+            .map_err(|_| Self::error(Response::bad_request(), "Invalid webhook ID"))
+    }
+
+    fn error(response: Response, message: &str) -> HttpResponse {
+        ActixWebServer::error(response, message)
+    }
+}
@@ -0,0 +1,25 @@
+use crate::application::services::auth_service::AuthService;
+use crate::infrastructure::web::{ActixWebServer, Response};
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::sync::Arc;
+
+/// Documentos públicos em `/.well-known`
+pub struct WellKnownController {
+    auth_service: Arc<AuthService>,
+}
+
+impl WellKnownController {
+    pub fn new(auth_service: Arc<AuthService>) -> Self {
+        Self { auth_service }
+    }
+
+    /// GET /.well-known/jwks.json - Chaves públicas para verificar os tokens emitidos pela API
+    pub async fn jwks(&self, _req: HttpRequest, _body: web::Bytes) -> HttpResponse {
+        let response = Response::ok()
+            .json(&self.auth_service.jwks())
+            .map(|response| response.with_header("Cache-Control", "public, max-age=300"))
+            .unwrap_or_else(|_| Response::internal_error());
+
+        ActixWebServer::convert_response(response)
+    }
+}
@@ -1,2 +1,3 @@
 pub mod controllers;
-pub mod middleware;
\ No newline at end of file
+pub mod middleware;
+pub mod cli;
\ No newline at end of file
@@ -9,12 +9,55 @@ mod application;
 mod domain;
 
 use infrastructure::web::{ActixWebServer, Response};
+use infrastructure::web::client_ip::TrustedProxies;
 use infrastructure::database::{establish_connection_pool, run_migrations};
+use infrastructure::database::pg_article_event_notifier::PgArticleEventNotifier;
+use infrastructure::database::pg_read_state_bus::PgReadStateBus;
 use infrastructure::repositories::diesel_user_repository::DieselUserRepository;
 use infrastructure::repositories::diesel_article_category_repository::DieselArticleCategoryRepository;
 use infrastructure::repositories::diesel_article_repository::DieselArticleRepository;
+use infrastructure::repositories::diesel_login_throttle_repository::DieselLoginThrottleRepository;
+use infrastructure::repositories::diesel_email_change_request_repository::DieselEmailChangeRequestRepository;
+use infrastructure::repositories::diesel_mfa_repository::DieselMfaRepository;
+use infrastructure::repositories::diesel_api_key_repository::DieselApiKeyRepository;
+use infrastructure::repositories::diesel_session_repository::DieselSessionRepository;
+use infrastructure::repositories::diesel_identity_repository::DieselIdentityRepository;
+use infrastructure::repositories::diesel_subscription_repository::DieselSubscriptionRepository;
+use infrastructure::repositories::diesel_collection_repository::DieselCollectionRepository;
+use infrastructure::repositories::diesel_saved_article_repository::DieselSavedArticleRepository;
+use infrastructure::repositories::diesel_article_reaction_repository::DieselArticleReactionRepository;
+use infrastructure::repositories::diesel_annotation_repository::DieselAnnotationRepository;
+use infrastructure::repositories::diesel_tag_repository::DieselTagRepository;
+use infrastructure::repositories::diesel_smart_folder_repository::DieselSmartFolderRepository;
+use infrastructure::repositories::diesel_alert_rule_repository::DieselAlertRuleRepository;
+use infrastructure::repositories::diesel_notification_repository::DieselNotificationRepository;
+use infrastructure::repositories::diesel_digest_repository::DieselDigestRepository;
+use infrastructure::repositories::diesel_article_event_repository::DieselArticleEventRepository;
+use infrastructure::repositories::diesel_webhook_repository::DieselWebhookRepository;
+use infrastructure::repositories::diesel_feed_repository::DieselFeedRepository;
+use infrastructure::mail::log_mailer::LogMailer;
+use infrastructure::notifications::email_notification_channel::EmailNotificationChannel;
+use infrastructure::notifications::webhook_notification_channel::WebhookNotificationChannel;
+use infrastructure::notifications::http_webhook_sender::HttpWebhookSender;
 use infrastructure::middleware::auth_middleware::AuthMiddleware;
+use infrastructure::middleware::request_id::request_id;
+use infrastructure::security::jwt_config::JwtConfig;
+use infrastructure::oidc::oidc_config::OidcProviderConfig;
+use infrastructure::oidc::oidc_identity_provider::OidcIdentityProvider;
 use application::services::auth_service::AuthService;
+use application::services::login_throttle_service::LoginThrottleService;
+use application::services::totp_service::TotpService;
+use application::services::api_key_service::ApiKeyService;
+use application::services::session_service::SessionService;
+use application::services::identity_provider::IdentityProviders;
+use application::services::ranking_service::RankingService;
+use application::services::alert_dispatcher::AlertDispatcher;
+use application::services::alert_service::AlertService;
+use application::services::digest_service::DigestService;
+use application::services::webhook_dispatcher::{WebhookDispatcher, DEFAULT_RETENTION_DAYS};
+use application::services::webhook_target_policy::WebhookTargetPolicy;
+use application::services::article_stream_service::ArticleStreamService;
+use application::services::read_state_sync_service::ReadStateSyncService;
 use application::use_cases::register_user::RegisterUserUseCase;
 use application::use_cases::login_user::LoginUserUseCase;
 use application::use_cases::list_article_categories::ListArticleCategoriesUseCase;
@@ -23,9 +66,127 @@ use application::use_cases::update_article::UpdateArticleUseCase;
 use application::use_cases::delete_article::DeleteArticleUseCase;
 use application::use_cases::get_article::GetArticleUseCase;
 use application::use_cases::list_articles::ListArticlesUseCase;
+use application::use_cases::export_articles::ExportArticlesUseCase;
+use application::use_cases::import_articles::ImportArticlesUseCase;
+use application::use_cases::issue_stream_ticket::IssueStreamTicketUseCase;
+use application::use_cases::unlock_user::UnlockUserUseCase;
+use application::use_cases::get_profile::GetProfileUseCase;
+use application::use_cases::update_profile::UpdateProfileUseCase;
+use application::use_cases::confirm_email_change::ConfirmEmailChangeUseCase;
+use application::use_cases::change_password::ChangePasswordUseCase;
+use application::use_cases::delete_account::DeleteAccountUseCase;
+use application::use_cases::enroll_totp::EnrollTotpUseCase;
+use application::use_cases::confirm_totp::ConfirmTotpUseCase;
+use application::use_cases::disable_totp::DisableTotpUseCase;
+use application::use_cases::verify_mfa_login::VerifyMfaLoginUseCase;
+use application::use_cases::create_api_key::CreateApiKeyUseCase;
+use application::use_cases::list_api_keys::ListApiKeysUseCase;
+use application::use_cases::revoke_api_key::RevokeApiKeyUseCase;
+use application::use_cases::list_sessions::ListSessionsUseCase;
+use application::use_cases::revoke_session::RevokeSessionUseCase;
+use application::use_cases::start_oidc_login::StartOidcLoginUseCase;
+use application::use_cases::complete_oidc_login::CompleteOidcLoginUseCase;
+use application::use_cases::subscribe_feed::SubscribeFeedUseCase;
+use application::use_cases::list_subscriptions::ListSubscriptionsUseCase;
+use application::use_cases::update_subscription::UpdateSubscriptionUseCase;
+use application::use_cases::unsubscribe_feed::UnsubscribeFeedUseCase;
+use application::use_cases::list_timeline::ListTimelineUseCase;
+use application::use_cases::list_for_you::ListForYouUseCase;
+use application::use_cases::mark_article_read::MarkArticleReadUseCase;
+use application::use_cases::mark_article_unread::MarkArticleUnreadUseCase;
+use application::use_cases::mark_all_read::MarkAllReadUseCase;
+use application::use_cases::get_unread_counts::GetUnreadCountsUseCase;
+use application::use_cases::save_article::SaveArticleUseCase;
+use application::use_cases::list_saved_articles::ListSavedArticlesUseCase;
+use application::use_cases::move_saved_article::MoveSavedArticleUseCase;
+use application::use_cases::unsave_article::UnsaveArticleUseCase;
+use application::use_cases::reorder_saved_articles::ReorderSavedArticlesUseCase;
+use application::use_cases::create_collection::CreateCollectionUseCase;
+use application::use_cases::list_collections::ListCollectionsUseCase;
+use application::use_cases::update_collection::UpdateCollectionUseCase;
+use application::use_cases::delete_collection::DeleteCollectionUseCase;
+use application::use_cases::get_shared_collection::GetSharedCollectionUseCase;
+use application::use_cases::get_category_feed::GetCategoryFeedUseCase;
+use application::use_cases::get_collection_feed::GetCollectionFeedUseCase;
+use application::use_cases::rate_article::RateArticleUseCase;
+use application::use_cases::remove_article_rating::RemoveArticleRatingUseCase;
+use application::use_cases::react_to_article::ReactToArticleUseCase;
+use application::use_cases::remove_article_reaction::RemoveArticleReactionUseCase;
+use application::use_cases::get_article_reactions::GetArticleReactionsUseCase;
+use application::use_cases::create_annotation::CreateAnnotationUseCase;
+use application::use_cases::list_annotations::ListAnnotationsUseCase;
+use application::use_cases::update_annotation::UpdateAnnotationUseCase;
+use application::use_cases::delete_annotation::DeleteAnnotationUseCase;
+use application::use_cases::export_annotations::ExportAnnotationsUseCase;
+use application::use_cases::create_tag::CreateTagUseCase;
+use application::use_cases::list_tags::ListTagsUseCase;
+use application::use_cases::autocomplete_tags::AutocompleteTagsUseCase;
+use application::use_cases::rename_tag::RenameTagUseCase;
+use application::use_cases::merge_tags::MergeTagsUseCase;
+use application::use_cases::delete_tag::DeleteTagUseCase;
+use application::use_cases::get_article_tags::GetArticleTagsUseCase;
+use application::use_cases::set_article_tags::SetArticleTagsUseCase;
+use application::use_cases::create_smart_folder::CreateSmartFolderUseCase;
+use application::use_cases::list_smart_folders::ListSmartFoldersUseCase;
+use application::use_cases::update_smart_folder::UpdateSmartFolderUseCase;
+use application::use_cases::delete_smart_folder::DeleteSmartFolderUseCase;
+use application::use_cases::list_smart_folder_articles::ListSmartFolderArticlesUseCase;
+use application::use_cases::create_alert_rule::CreateAlertRuleUseCase;
+use application::use_cases::list_alert_rules::ListAlertRulesUseCase;
+use application::use_cases::update_alert_rule::UpdateAlertRuleUseCase;
+use application::use_cases::delete_alert_rule::DeleteAlertRuleUseCase;
+use application::use_cases::list_notifications::ListNotificationsUseCase;
+use application::use_cases::mark_notifications_read::MarkNotificationsReadUseCase;
+use application::use_cases::get_digest_settings::GetDigestSettingsUseCase;
+use application::use_cases::save_digest_settings::SaveDigestSettingsUseCase;
+use application::use_cases::delete_digest_settings::DeleteDigestSettingsUseCase;
+use application::use_cases::list_sent_digests::ListSentDigestsUseCase;
+use application::use_cases::preview_digest::PreviewDigestUseCase;
+use application::use_cases::create_webhook::CreateWebhookUseCase;
+use application::use_cases::list_webhooks::ListWebhooksUseCase;
+use application::use_cases::update_webhook::UpdateWebhookUseCase;
+use application::use_cases::delete_webhook::DeleteWebhookUseCase;
+use application::use_cases::list_webhook_deliveries::ListWebhookDeliveriesUseCase;
+use application::use_cases::test_webhook::TestWebhookUseCase;
+use domain::entities::login_throttle::LockoutPolicy;
+use domain::entities::ranking::RankingWeights;
+use domain::entities::syndication::FeedFormat;
 use interfaces::controllers::auth_controller::AuthController;
 use interfaces::controllers::article_category_controller::ArticleCategoryController;
 use interfaces::controllers::article_controller::ArticleController;
+use interfaces::controllers::admin_controller::AdminController;
+use interfaces::controllers::user_controller::UserController;
+use interfaces::controllers::mfa_controller::MfaController;
+use interfaces::controllers::api_key_controller::ApiKeyController;
+use interfaces::controllers::session_controller::SessionController;
+use interfaces::controllers::well_known_controller::WellKnownController;
+use interfaces::controllers::oidc_controller::OidcController;
+use interfaces::controllers::subscription_controller::SubscriptionController;
+use interfaces::controllers::read_state_controller::ReadStateController;
+use interfaces::controllers::saved_article_controller::SavedArticleController;
+use interfaces::controllers::collection_controller::CollectionController;
+use interfaces::controllers::article_feedback_controller::ArticleFeedbackController;
+use interfaces::controllers::annotation_controller::AnnotationController;
+use interfaces::controllers::tag_controller::TagController;
+use interfaces::controllers::article_tag_controller::ArticleTagController;
+use interfaces::controllers::smart_folder_controller::SmartFolderController;
+use interfaces::controllers::alert_controller::AlertController;
+use interfaces::controllers::notification_controller::NotificationController;
+use interfaces::controllers::digest_controller::DigestController;
+use interfaces::controllers::webhook_controller::WebhookController;
+use interfaces::controllers::article_stream_controller::ArticleStreamController;
+use interfaces::controllers::article_export_controller::ArticleExportController;
+use interfaces::controllers::article_import_controller::ArticleImportController;
+use interfaces::controllers::live_controller::LiveController;
+use interfaces::controllers::syndication_controller::SyndicationController;
+
+/// Lê uma variável de ambiente numérica, usando o padrão se ausente ou inválida
+fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+    std::env::var(key)
+        .ok()
+        .and_then(|value| value.parse::<T>().ok())
+        .unwrap_or(default)
+}
 
 /// Handler de health check usando nossos tipos abstratos
 async fn health_check_handler(_req: HttpRequest, _body: web::Bytes) -> actix_web::HttpResponse {
@@ -55,6 +216,18 @@ async fn main() -> std::io::Result<()> {
     // Configura logs
     env_logger::init_from_env(Env::default().default_filter_or("info"));
 
+    // Carrega as chaves de JWT antes de tudo; recusa subir com o segredo padrão fora de desenvolvimento
+    let jwt_keys = JwtConfig::from_env().build_key_set().map_err(|e| {
+        log::error!("❌ Configuração de JWT inválida: {}", e);
+        std::io::Error::other(e.to_string())
+    })?;
+
+    // Provedores OIDC são opcionais; um provedor listado com configuração incompleta impede a subida
+    let oidc_configs = OidcProviderConfig::from_env().map_err(|e| {
+        log::error!("❌ Configuração de OIDC inválida: {}", e);
+        std::io::Error::other(e.to_string())
+    })?;
+
     // Estabelece pool de conexões com banco de dados
     let db_pool = establish_connection_pool();
     
@@ -65,36 +238,263 @@ async fn main() -> std::io::Result<()> {
     let user_repository = Arc::new(DieselUserRepository::new(db_pool.clone()));
     let category_repository = Arc::new(DieselArticleCategoryRepository::new(db_pool.clone()));
     let article_repository = Arc::new(DieselArticleRepository::new(db_pool.clone()));
+    let login_throttle_repository = Arc::new(DieselLoginThrottleRepository::new(db_pool.clone()));
+    let email_change_repository = Arc::new(DieselEmailChangeRequestRepository::new(db_pool.clone()));
+    let mfa_repository = Arc::new(DieselMfaRepository::new(db_pool.clone()));
+    let api_key_repository = Arc::new(DieselApiKeyRepository::new(db_pool.clone()));
+    let session_repository = Arc::new(DieselSessionRepository::new(db_pool.clone()));
+    let identity_repository = Arc::new(DieselIdentityRepository::new(db_pool.clone()));
+    let subscription_repository = Arc::new(DieselSubscriptionRepository::new(db_pool.clone()));
+    let collection_repository = Arc::new(DieselCollectionRepository::new(db_pool.clone()));
+    let saved_article_repository = Arc::new(DieselSavedArticleRepository::new(db_pool.clone()));
+    let reaction_repository = Arc::new(DieselArticleReactionRepository::new(db_pool.clone()));
+    let annotation_repository = Arc::new(DieselAnnotationRepository::new(db_pool.clone()));
+    let tag_repository = Arc::new(DieselTagRepository::new(db_pool.clone()));
+    let smart_folder_repository = Arc::new(DieselSmartFolderRepository::new(db_pool.clone()));
+    let alert_rule_repository = Arc::new(DieselAlertRuleRepository::new(db_pool.clone()));
+    let notification_repository = Arc::new(DieselNotificationRepository::new(db_pool.clone()));
+    let digest_repository = Arc::new(DieselDigestRepository::new(db_pool.clone()));
+    let article_event_repository = Arc::new(DieselArticleEventRepository::new(db_pool.clone()));
+    let webhook_repository = Arc::new(DieselWebhookRepository::new(db_pool.clone()));
+    let feed_repository = Arc::new(DieselFeedRepository::new(db_pool.clone()));
+
+    let import_articles_use_case = Arc::new(ImportArticlesUseCase::new(
+        article_repository.clone(),
+        category_repository.clone(),
+        feed_repository,
+    ));
+
+    // Comandos de linha de comando rodam no lugar do servidor
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        [] => {}
+        ["import-articles", path] => return interfaces::cli::import_articles::run(&import_articles_use_case, path).await,
+        _ => {
+            eprintln!("Uso: nexight-backend [import-articles <arquivo.ndjson|->]");
+            return Err(std::io::Error::other("invalid arguments"));
+        }
+    }
 
     // Cria serviços
-    let jwt_secret = std::env::var("JWT_SECRET")
-        .unwrap_or_else(|_| "your-secret-key-change-in-production".to_string());
     let token_expiration_hours = std::env::var("TOKEN_EXPIRATION_HOURS")
         .unwrap_or_else(|_| "24".to_string())
         .parse::<i64>()
         .unwrap_or(24);
     
-    let auth_service = Arc::new(AuthService::new(jwt_secret, token_expiration_hours));
+    let auth_service = Arc::new(AuthService::with_keys(jwt_keys, token_expiration_hours));
+    let mailer = Arc::new(LogMailer::new());
+    let webhook_targets = if env_or("WEBHOOK_ALLOW_PRIVATE_NETWORKS", false) {
+        WebhookTargetPolicy::allow_private_networks()
+    } else {
+        WebhookTargetPolicy::public_only()
+    };
+    let alert_service = Arc::new(AlertService::new(
+        alert_rule_repository.clone(),
+        notification_repository.clone(),
+    ));
+    let alert_dispatcher = Arc::new(AlertDispatcher::new(
+        alert_rule_repository.clone(),
+        notification_repository.clone(),
+        article_repository.clone(),
+        vec![
+            Arc::new(EmailNotificationChannel::new(mailer.clone(), user_repository.clone())),
+            Arc::new(WebhookNotificationChannel::new(webhook_targets)),
+        ],
+    ));
+    let digest_service = Arc::new(DigestService::new(
+        digest_repository.clone(),
+        subscription_repository.clone(),
+        article_repository.clone(),
+        user_repository.clone(),
+        mailer.clone(),
+    ));
+    let webhook_dispatcher = Arc::new(
+        WebhookDispatcher::new(
+            article_event_repository.clone(),
+            webhook_repository.clone(),
+            Arc::new(HttpWebhookSender::new(webhook_targets)),
+        )
+        .with_retention(env_or("WEBHOOK_RETENTION_DAYS", DEFAULT_RETENTION_DAYS)),
+    );
+    let article_stream_service = Arc::new(ArticleStreamService::new(
+        article_event_repository.clone(),
+        Arc::new(PgArticleEventNotifier::new(
+            std::env::var("DATABASE_URL").expect("DATABASE_URL must be set"),
+        )),
+        env_or("ARTICLE_STREAM_REPLAY_SIZE", 1000),
+    ));
+    let read_state_sync = Arc::new(ReadStateSyncService::new(Arc::new(PgReadStateBus::new(
+        db_pool.clone(),
+        std::env::var("DATABASE_URL").expect("DATABASE_URL must be set"),
+    ))));
+
+    let mfa_issuer = std::env::var("MFA_ISSUER").unwrap_or_else(|_| "Nexight".to_string());
+    let totp_service = Arc::new(TotpService::new(mfa_issuer));
+    let mfa_token_ttl = chrono::Duration::minutes(env_or("MFA_TOKEN_EXPIRATION_MINUTES", 5));
+    let api_key_service = Arc::new(ApiKeyService::new(api_key_repository.clone()));
+    let session_service = Arc::new(SessionService::new(
+        session_repository.clone(),
+        auth_service.clone(),
+    ));
+
+    let default_policy = LockoutPolicy::default();
+    let lockout_policy = LockoutPolicy {
+        max_failed_attempts_per_account: env_or(
+            "LOGIN_MAX_FAILED_ATTEMPTS",
+            default_policy.max_failed_attempts_per_account,
+        ),
+        max_failed_attempts_per_ip: env_or(
+            "LOGIN_MAX_FAILED_ATTEMPTS_PER_IP",
+            default_policy.max_failed_attempts_per_ip,
+        ),
+        attempt_window: chrono::Duration::seconds(env_or(
+            "LOGIN_ATTEMPT_WINDOW_SECONDS",
+            default_policy.attempt_window.num_seconds(),
+        )),
+        base_lockout: chrono::Duration::seconds(env_or(
+            "LOGIN_LOCKOUT_BASE_SECONDS",
+            default_policy.base_lockout.num_seconds(),
+        )),
+        max_lockout: chrono::Duration::seconds(env_or(
+            "LOGIN_LOCKOUT_MAX_SECONDS",
+            default_policy.max_lockout.num_seconds(),
+        )),
+    };
+    let login_throttle_service = Arc::new(LoginThrottleService::new(
+        login_throttle_repository,
+        lockout_policy,
+    ));
+
+    let default_weights = RankingWeights::default();
+    let ranking_service = Arc::new(RankingService::new(RankingWeights {
+        quality: env_or("RANKING_QUALITY_WEIGHT", default_weights.quality),
+        recency: env_or("RANKING_RECENCY_WEIGHT", default_weights.recency),
+        affinity: env_or("RANKING_AFFINITY_WEIGHT", default_weights.affinity),
+        half_life_hours: env_or("RANKING_HALF_LIFE_HOURS", default_weights.half_life_hours),
+    }));
 
     // Cria use cases
     let register_use_case = Arc::new(RegisterUserUseCase::new(
         user_repository.clone(),
         auth_service.clone(),
+        session_service.clone(),
     ));
     let login_use_case = Arc::new(LoginUserUseCase::new(
         user_repository.clone(),
         auth_service.clone(),
+        login_throttle_service.clone(),
+        mfa_repository.clone(),
+        mfa_token_ttl,
+        session_service.clone(),
+    ));
+    let verify_mfa_use_case = Arc::new(VerifyMfaLoginUseCase::new(
+        user_repository.clone(),
+        mfa_repository.clone(),
+        auth_service.clone(),
+        totp_service.clone(),
+        login_throttle_service.clone(),
+        session_service.clone(),
+    ));
+    let unlock_user_use_case = Arc::new(UnlockUserUseCase::new(
+        user_repository.clone(),
+        login_throttle_service.clone(),
     ));
     let list_categories_use_case = Arc::new(ListArticleCategoriesUseCase::new(
         category_repository.clone(),
     ));
 
+    // User profile use cases
+    let get_profile_use_case = Arc::new(GetProfileUseCase::new(
+        user_repository.clone(),
+        email_change_repository.clone(),
+    ));
+    let update_profile_use_case = Arc::new(UpdateProfileUseCase::new(
+        user_repository.clone(),
+        email_change_repository.clone(),
+        mailer.clone(),
+    ));
+    let confirm_email_change_use_case = Arc::new(ConfirmEmailChangeUseCase::new(
+        user_repository.clone(),
+        email_change_repository.clone(),
+    ));
+    let change_password_use_case = Arc::new(ChangePasswordUseCase::new(
+        user_repository.clone(),
+        auth_service.clone(),
+        session_service.clone(),
+    ));
+    let delete_account_use_case = Arc::new(DeleteAccountUseCase::new(
+        user_repository.clone(),
+        auth_service.clone(),
+        session_service.clone(),
+    ));
+
+    // MFA use cases
+    let enroll_totp_use_case = Arc::new(EnrollTotpUseCase::new(
+        user_repository.clone(),
+        mfa_repository.clone(),
+        totp_service.clone(),
+    ));
+    let confirm_totp_use_case = Arc::new(ConfirmTotpUseCase::new(
+        mfa_repository.clone(),
+        totp_service.clone(),
+    ));
+    let disable_totp_use_case = Arc::new(DisableTotpUseCase::new(
+        user_repository.clone(),
+        mfa_repository.clone(),
+        auth_service.clone(),
+    ));
+
+    // API key use cases
+    let create_api_key_use_case = Arc::new(CreateApiKeyUseCase::new(
+        api_key_repository.clone(),
+    ));
+    let list_api_keys_use_case = Arc::new(ListApiKeysUseCase::new(
+        api_key_repository.clone(),
+    ));
+    let revoke_api_key_use_case = Arc::new(RevokeApiKeyUseCase::new(
+        api_key_repository.clone(),
+    ));
+
+    // OIDC use cases
+    let identity_providers = Arc::new(oidc_configs.into_iter().fold(
+        IdentityProviders::new(),
+        |providers, config| {
+            log::info!("🔑 Provedor OIDC habilitado: {}", config.name);
+            let name = config.name.clone();
+            providers.register(name, Arc::new(OidcIdentityProvider::new(config)))
+        },
+    ));
+    let start_oidc_login_use_case = Arc::new(StartOidcLoginUseCase::new(
+        identity_providers.clone(),
+        identity_repository.clone(),
+        chrono::Duration::minutes(env_or("OIDC_STATE_TTL_MINUTES", 10)),
+    ));
+    let complete_oidc_login_use_case = Arc::new(CompleteOidcLoginUseCase::new(
+        identity_providers,
+        identity_repository.clone(),
+        user_repository.clone(),
+        auth_service.clone(),
+        mfa_repository.clone(),
+        mfa_token_ttl,
+        session_service.clone(),
+    ));
+
+    // Session use cases
+    let list_sessions_use_case = Arc::new(ListSessionsUseCase::new(
+        session_repository.clone(),
+    ));
+    let revoke_session_use_case = Arc::new(RevokeSessionUseCase::new(
+        session_repository.clone(),
+    ));
+
     // Article use cases
     let create_article_use_case = Arc::new(CreateArticleUseCase::new(
         article_repository.clone(),
+        alert_service.clone(),
     ));
     let update_article_use_case = Arc::new(UpdateArticleUseCase::new(
         article_repository.clone(),
+        alert_service.clone(),
     ));
     let delete_article_use_case = Arc::new(DeleteArticleUseCase::new(
         article_repository.clone(),
@@ -105,16 +505,278 @@ async fn main() -> std::io::Result<()> {
     let list_articles_use_case = Arc::new(ListArticlesUseCase::new(
         article_repository.clone(),
     ));
+    let export_articles_use_case = Arc::new(ExportArticlesUseCase::new(
+        article_repository.clone(),
+        category_repository.clone(),
+    ));
+
+    // Subscription use cases
+    let subscribe_feed_use_case = Arc::new(SubscribeFeedUseCase::new(
+        subscription_repository.clone(),
+    ));
+    let list_subscriptions_use_case = Arc::new(ListSubscriptionsUseCase::new(
+        subscription_repository.clone(),
+    ));
+    let update_subscription_use_case = Arc::new(UpdateSubscriptionUseCase::new(
+        subscription_repository.clone(),
+    ));
+    let unsubscribe_feed_use_case = Arc::new(UnsubscribeFeedUseCase::new(
+        subscription_repository.clone(),
+    ));
+    let list_timeline_use_case = Arc::new(ListTimelineUseCase::new(
+        article_repository.clone(),
+        subscription_repository.clone(),
+        tag_repository.clone(),
+    ));
+    let list_for_you_use_case = Arc::new(ListForYouUseCase::new(
+        article_repository.clone(),
+        subscription_repository.clone(),
+        saved_article_repository.clone(),
+        ranking_service,
+    ));
+
+    // Read state use cases
+    let mark_article_read_use_case = Arc::new(MarkArticleReadUseCase::new(
+        article_repository.clone(),
+        read_state_sync.clone(),
+    ));
+    let mark_article_unread_use_case = Arc::new(MarkArticleUnreadUseCase::new(
+        article_repository.clone(),
+        read_state_sync.clone(),
+    ));
+    let mark_all_read_use_case = Arc::new(MarkAllReadUseCase::new(
+        article_repository.clone(),
+        subscription_repository.clone(),
+        read_state_sync.clone(),
+    ));
+    let get_unread_counts_use_case = Arc::new(GetUnreadCountsUseCase::new(
+        article_repository.clone(),
+        subscription_repository.clone(),
+    ));
+
+    // Saved article and collection use cases
+    let save_article_use_case = Arc::new(SaveArticleUseCase::new(
+        article_repository.clone(),
+        saved_article_repository.clone(),
+        collection_repository.clone(),
+    ));
+    let list_saved_articles_use_case = Arc::new(ListSavedArticlesUseCase::new(
+        saved_article_repository.clone(),
+        tag_repository.clone(),
+    ));
+    let move_saved_article_use_case = Arc::new(MoveSavedArticleUseCase::new(
+        saved_article_repository.clone(),
+        collection_repository.clone(),
+    ));
+    let unsave_article_use_case = Arc::new(UnsaveArticleUseCase::new(
+        saved_article_repository.clone(),
+    ));
+    let reorder_saved_articles_use_case = Arc::new(ReorderSavedArticlesUseCase::new(
+        saved_article_repository.clone(),
+    ));
+    let create_collection_use_case = Arc::new(CreateCollectionUseCase::new(
+        collection_repository.clone(),
+    ));
+    let list_collections_use_case = Arc::new(ListCollectionsUseCase::new(
+        collection_repository.clone(),
+    ));
+    let update_collection_use_case = Arc::new(UpdateCollectionUseCase::new(
+        collection_repository.clone(),
+    ));
+    let delete_collection_use_case = Arc::new(DeleteCollectionUseCase::new(
+        collection_repository.clone(),
+        saved_article_repository.clone(),
+    ));
+    let get_shared_collection_use_case = Arc::new(GetSharedCollectionUseCase::new(
+        collection_repository.clone(),
+        saved_article_repository.clone(),
+    ));
+
+    // Outgoing feed use cases
+    let get_category_feed_use_case = Arc::new(GetCategoryFeedUseCase::new(
+        category_repository.clone(),
+        article_repository.clone(),
+    ));
+    let get_collection_feed_use_case = Arc::new(GetCollectionFeedUseCase::new(
+        collection_repository.clone(),
+        saved_article_repository.clone(),
+        article_repository.clone(),
+    ));
+
+    // Rating and reaction use cases
+    let rate_article_use_case = Arc::new(RateArticleUseCase::new(
+        article_repository.clone(),
+    ));
+    let remove_article_rating_use_case = Arc::new(RemoveArticleRatingUseCase::new(
+        article_repository.clone(),
+    ));
+    let react_to_article_use_case = Arc::new(ReactToArticleUseCase::new(
+        article_repository.clone(),
+        reaction_repository.clone(),
+    ));
+    let remove_article_reaction_use_case = Arc::new(RemoveArticleReactionUseCase::new(
+        reaction_repository.clone(),
+    ));
+    let get_article_reactions_use_case = Arc::new(GetArticleReactionsUseCase::new(
+        article_repository.clone(),
+        reaction_repository.clone(),
+    ));
+
+    // Annotation use cases
+    let create_annotation_use_case = Arc::new(CreateAnnotationUseCase::new(
+        article_repository.clone(),
+        annotation_repository.clone(),
+    ));
+    let list_annotations_use_case = Arc::new(ListAnnotationsUseCase::new(
+        article_repository.clone(),
+        annotation_repository.clone(),
+    ));
+    let update_annotation_use_case = Arc::new(UpdateAnnotationUseCase::new(
+        article_repository.clone(),
+        annotation_repository.clone(),
+    ));
+    let delete_annotation_use_case = Arc::new(DeleteAnnotationUseCase::new(
+        annotation_repository.clone(),
+    ));
+    let export_annotations_use_case = Arc::new(ExportAnnotationsUseCase::new(
+        article_repository.clone(),
+        annotation_repository.clone(),
+    ));
+
+    // Tag use cases
+    let create_tag_use_case = Arc::new(CreateTagUseCase::new(
+        tag_repository.clone(),
+    ));
+    let list_tags_use_case = Arc::new(ListTagsUseCase::new(
+        tag_repository.clone(),
+    ));
+    let autocomplete_tags_use_case = Arc::new(AutocompleteTagsUseCase::new(
+        tag_repository.clone(),
+    ));
+    let rename_tag_use_case = Arc::new(RenameTagUseCase::new(
+        tag_repository.clone(),
+    ));
+    let merge_tags_use_case = Arc::new(MergeTagsUseCase::new(
+        tag_repository.clone(),
+        smart_folder_repository.clone(),
+    ));
+    let delete_tag_use_case = Arc::new(DeleteTagUseCase::new(
+        tag_repository.clone(),
+    ));
+    let get_article_tags_use_case = Arc::new(GetArticleTagsUseCase::new(
+        article_repository.clone(),
+        tag_repository.clone(),
+    ));
+    let set_article_tags_use_case = Arc::new(SetArticleTagsUseCase::new(
+        article_repository.clone(),
+        tag_repository.clone(),
+    ));
+
+    // Smart folder use cases
+    let create_smart_folder_use_case = Arc::new(CreateSmartFolderUseCase::new(
+        smart_folder_repository.clone(),
+        tag_repository.clone(),
+        category_repository.clone(),
+    ));
+    let list_smart_folders_use_case = Arc::new(ListSmartFoldersUseCase::new(
+        smart_folder_repository.clone(),
+        list_timeline_use_case.clone(),
+    ));
+    let update_smart_folder_use_case = Arc::new(UpdateSmartFolderUseCase::new(
+        smart_folder_repository.clone(),
+        tag_repository.clone(),
+        category_repository.clone(),
+    ));
+    let delete_smart_folder_use_case = Arc::new(DeleteSmartFolderUseCase::new(
+        smart_folder_repository.clone(),
+    ));
+    let list_smart_folder_articles_use_case = Arc::new(ListSmartFolderArticlesUseCase::new(
+        smart_folder_repository.clone(),
+        list_timeline_use_case.clone(),
+    ));
+
+    // Alert use cases
+    let create_alert_rule_use_case = Arc::new(CreateAlertRuleUseCase::new(
+        alert_rule_repository.clone(),
+        category_repository.clone(),
+        webhook_targets,
+    ));
+    let list_alert_rules_use_case = Arc::new(ListAlertRulesUseCase::new(
+        alert_rule_repository.clone(),
+    ));
+    let update_alert_rule_use_case = Arc::new(UpdateAlertRuleUseCase::new(
+        alert_rule_repository.clone(),
+        category_repository.clone(),
+        webhook_targets,
+    ));
+    let delete_alert_rule_use_case = Arc::new(DeleteAlertRuleUseCase::new(
+        alert_rule_repository.clone(),
+    ));
+    let list_notifications_use_case = Arc::new(ListNotificationsUseCase::new(
+        notification_repository.clone(),
+    ));
+    let mark_notifications_read_use_case = Arc::new(MarkNotificationsReadUseCase::new(
+        notification_repository.clone(),
+    ));
+
+    // Digest use cases
+    let get_digest_settings_use_case = Arc::new(GetDigestSettingsUseCase::new(
+        digest_repository.clone(),
+    ));
+    let save_digest_settings_use_case = Arc::new(SaveDigestSettingsUseCase::new(
+        digest_repository.clone(),
+    ));
+    let delete_digest_settings_use_case = Arc::new(DeleteDigestSettingsUseCase::new(
+        digest_repository.clone(),
+    ));
+    let list_sent_digests_use_case = Arc::new(ListSentDigestsUseCase::new(
+        digest_repository.clone(),
+    ));
+    let preview_digest_use_case = Arc::new(PreviewDigestUseCase::new(
+        digest_repository.clone(),
+        digest_service.clone(),
+    ));
+
+    // Webhook use cases
+    let create_webhook_use_case = Arc::new(CreateWebhookUseCase::new(
+        webhook_repository.clone(),
+        webhook_targets,
+    ));
+    let list_webhooks_use_case = Arc::new(ListWebhooksUseCase::new(
+        webhook_repository.clone(),
+    ));
+    let update_webhook_use_case = Arc::new(UpdateWebhookUseCase::new(
+        webhook_repository.clone(),
+        webhook_targets,
+    ));
+    let delete_webhook_use_case = Arc::new(DeleteWebhookUseCase::new(
+        webhook_repository.clone(),
+    ));
+    let list_webhook_deliveries_use_case = Arc::new(ListWebhookDeliveriesUseCase::new(
+        webhook_repository.clone(),
+    ));
+    let test_webhook_use_case = Arc::new(TestWebhookUseCase::new(
+        webhook_repository.clone(),
+        webhook_dispatcher.clone(),
+    ));
 
     // Cria auth middleware
     let auth_middleware = Arc::new(AuthMiddleware::new(
         auth_service.clone(),
+        user_repository.clone(),
+        api_key_service,
+        session_service,
     ));
 
+    // IP do cliente só é lido do X-Forwarded-For quando a conexão vem de um desses proxies
+    let trusted_proxies = TrustedProxies::from_env();
+
     // Cria controllers
     let auth_controller = Arc::new(AuthController::new(
         register_use_case,
         login_use_case,
+        verify_mfa_use_case,
+        trusted_proxies.clone(),
     ));
     let category_controller = Arc::new(ArticleCategoryController::new(
         list_categories_use_case,
@@ -125,6 +787,168 @@ async fn main() -> std::io::Result<()> {
         delete_article_use_case,
         get_article_use_case,
         list_articles_use_case,
+        auth_middleware.clone(),
+    ));
+    let user_controller = Arc::new(UserController::new(
+        get_profile_use_case,
+        update_profile_use_case,
+        confirm_email_change_use_case,
+        change_password_use_case,
+        delete_account_use_case,
+        auth_middleware.clone(),
+    ));
+    let mfa_controller = Arc::new(MfaController::new(
+        enroll_totp_use_case,
+        confirm_totp_use_case,
+        disable_totp_use_case,
+        auth_middleware.clone(),
+    ));
+    let api_key_controller = Arc::new(ApiKeyController::new(
+        create_api_key_use_case,
+        list_api_keys_use_case,
+        revoke_api_key_use_case,
+        auth_middleware.clone(),
+    ));
+    let session_controller = Arc::new(SessionController::new(
+        list_sessions_use_case,
+        revoke_session_use_case,
+        auth_middleware.clone(),
+    ));
+    let subscription_controller = Arc::new(SubscriptionController::new(
+        subscribe_feed_use_case,
+        list_subscriptions_use_case,
+        update_subscription_use_case,
+        unsubscribe_feed_use_case,
+        list_timeline_use_case,
+        list_for_you_use_case,
+        auth_middleware.clone(),
+    ));
+    let read_state_controller = Arc::new(ReadStateController::new(
+        mark_article_read_use_case.clone(),
+        mark_article_unread_use_case.clone(),
+        mark_all_read_use_case,
+        get_unread_counts_use_case,
+        auth_middleware.clone(),
+    ));
+    let saved_article_controller = Arc::new(SavedArticleController::new(
+        save_article_use_case,
+        list_saved_articles_use_case,
+        move_saved_article_use_case,
+        unsave_article_use_case,
+        reorder_saved_articles_use_case,
+        auth_middleware.clone(),
+    ));
+    let collection_controller = Arc::new(CollectionController::new(
+        create_collection_use_case,
+        list_collections_use_case,
+        update_collection_use_case,
+        delete_collection_use_case,
+        get_shared_collection_use_case,
+        auth_middleware.clone(),
+    ));
+    let syndication_controller = Arc::new(SyndicationController::new(
+        get_category_feed_use_case,
+        get_collection_feed_use_case,
+        env_or("FEED_CACHE_MAX_AGE_SECONDS", 300),
+    ));
+    let article_feedback_controller = Arc::new(ArticleFeedbackController::new(
+        rate_article_use_case,
+        remove_article_rating_use_case,
+        react_to_article_use_case,
+        remove_article_reaction_use_case,
+        get_article_reactions_use_case,
+        auth_middleware.clone(),
+    ));
+    let annotation_controller = Arc::new(AnnotationController::new(
+        create_annotation_use_case,
+        list_annotations_use_case,
+        update_annotation_use_case,
+        delete_annotation_use_case,
+        export_annotations_use_case,
+        auth_middleware.clone(),
+    ));
+    let tag_controller = Arc::new(TagController::new(
+        create_tag_use_case,
+        list_tags_use_case,
+        autocomplete_tags_use_case,
+        rename_tag_use_case,
+        merge_tags_use_case,
+        delete_tag_use_case,
+        auth_middleware.clone(),
+    ));
+    let article_tag_controller = Arc::new(ArticleTagController::new(
+        get_article_tags_use_case,
+        set_article_tags_use_case,
+        auth_middleware.clone(),
+    ));
+    let smart_folder_controller = Arc::new(SmartFolderController::new(
+        create_smart_folder_use_case,
+        list_smart_folders_use_case,
+        update_smart_folder_use_case,
+        delete_smart_folder_use_case,
+        list_smart_folder_articles_use_case,
+        auth_middleware.clone(),
+    ));
+    let alert_controller = Arc::new(AlertController::new(
+        create_alert_rule_use_case,
+        list_alert_rules_use_case,
+        update_alert_rule_use_case,
+        delete_alert_rule_use_case,
+        auth_middleware.clone(),
+    ));
+    let notification_controller = Arc::new(NotificationController::new(
+        list_notifications_use_case,
+        mark_notifications_read_use_case,
+        auth_middleware.clone(),
+    ));
+    let digest_controller = Arc::new(DigestController::new(
+        get_digest_settings_use_case,
+        save_digest_settings_use_case,
+        delete_digest_settings_use_case,
+        list_sent_digests_use_case,
+        preview_digest_use_case,
+        auth_middleware.clone(),
+    ));
+    let oidc_controller = Arc::new(OidcController::new(
+        start_oidc_login_use_case,
+        complete_oidc_login_use_case,
+        trusted_proxies,
+    ));
+    let well_known_controller = Arc::new(WellKnownController::new(
+        auth_service.clone(),
+    ));
+    let webhook_controller = Arc::new(WebhookController::new(
+        create_webhook_use_case,
+        list_webhooks_use_case,
+        update_webhook_use_case,
+        delete_webhook_use_case,
+        list_webhook_deliveries_use_case,
+        test_webhook_use_case,
+        auth_middleware.clone(),
+    ));
+    let article_stream_controller = Arc::new(ArticleStreamController::new(
+        article_stream_service.clone(),
+        auth_middleware.clone(),
+    ));
+    let article_export_controller = Arc::new(ArticleExportController::new(
+        export_articles_use_case,
+        auth_middleware.clone(),
+    ));
+    let article_import_controller = Arc::new(ArticleImportController::new(
+        import_articles_use_case,
+        auth_middleware.clone(),
+    ));
+    let article_import_max_bytes: usize = env_or("ARTICLE_IMPORT_MAX_BYTES", 50 * 1024 * 1024);
+    let live_controller = Arc::new(LiveController::new(
+        auth_middleware.clone(),
+        Arc::new(IssueStreamTicketUseCase::new(auth_service.clone())),
+        article_stream_service.clone(),
+        read_state_sync.clone(),
+        mark_article_read_use_case.clone(),
+        mark_article_unread_use_case.clone(),
+    ));
+    let admin_controller = Arc::new(AdminController::new(
+        unlock_user_use_case,
         auth_middleware,
     ));
 
@@ -135,6 +959,25 @@ async fn main() -> std::io::Result<()> {
         .parse::<u16>()
         .expect("SERVER_PORT deve ser um número válido");
 
+    // Agendador dos resumos por email
+    let digest_check_interval = std::time::Duration::from_secs(env_or("DIGEST_CHECK_INTERVAL_SECONDS", 300));
+    actix_web::rt::spawn(digest_service.clone().run_scheduler(digest_check_interval));
+
+    // Distribuição do outbox de eventos de artigos e entregas dos webhooks
+    let webhook_dispatch_interval = std::time::Duration::from_secs(env_or("WEBHOOK_DISPATCH_INTERVAL_SECONDS", 5));
+    actix_web::rt::spawn(webhook_dispatcher.clone().run(webhook_dispatch_interval));
+
+    // Entregas dos alertas por email e webhook
+    let alert_dispatch_interval = std::time::Duration::from_secs(env_or("ALERT_DISPATCH_INTERVAL_SECONDS", 5));
+    actix_web::rt::spawn(alert_dispatcher.clone().run(alert_dispatch_interval));
+
+    // Leitura do outbox para o stream SSE, acordada pelo LISTEN/NOTIFY do Postgres
+    let article_stream_poll_interval = std::time::Duration::from_secs(env_or("ARTICLE_STREAM_POLL_SECONDS", 5));
+    actix_web::rt::spawn(article_stream_service.clone().run(article_stream_poll_interval));
+
+    // Repasse do estado de leitura entre as instâncias para as conexões WebSocket
+    actix_web::rt::spawn(read_state_sync.clone().run(std::time::Duration::from_secs(5)));
+
     log::info!("🚀 Iniciando Nexight Backend API em {}:{}", host, port);
 
     // Inicia o servidor usando Actix Web diretamente
@@ -143,9 +986,42 @@ async fn main() -> std::io::Result<()> {
         let auth_ctrl = auth_controller.clone();
         let category_ctrl = category_controller.clone();
         let article_ctrl = article_controller.clone();
+        let admin_ctrl = admin_controller.clone();
+        let user_ctrl = user_controller.clone();
+        let mfa_ctrl = mfa_controller.clone();
+        let api_key_ctrl = api_key_controller.clone();
+        let session_ctrl = session_controller.clone();
+        let well_known_ctrl = well_known_controller.clone();
+        let oidc_ctrl = oidc_controller.clone();
+        let subscription_ctrl = subscription_controller.clone();
+        let read_state_ctrl = read_state_controller.clone();
+        let saved_article_ctrl = saved_article_controller.clone();
+        let collection_ctrl = collection_controller.clone();
+        let article_feedback_ctrl = article_feedback_controller.clone();
+        let annotation_ctrl = annotation_controller.clone();
+        let tag_ctrl = tag_controller.clone();
+        let article_tag_ctrl = article_tag_controller.clone();
+        let smart_folder_ctrl = smart_folder_controller.clone();
+        let alert_ctrl = alert_controller.clone();
+        let notification_ctrl = notification_controller.clone();
+        let digest_ctrl = digest_controller.clone();
+        let webhook_ctrl = webhook_controller.clone();
+        let article_stream_ctrl = article_stream_controller.clone();
+        let article_export_ctrl = article_export_controller.clone();
+        let article_import_ctrl = article_import_controller.clone();
+        let live_ctrl = live_controller.clone();
+        let syndication_ctrl = syndication_controller.clone();
         
         App::new()
+            .wrap(actix_web::middleware::from_fn(request_id))
             .route("/health", web::get().to(health_check_handler))
+            .route("/.well-known/jwks.json", web::get().to({
+                let ctrl = well_known_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.jwks(req, body).await }
+                }
+            }))
             .route("/auth/register", web::post().to({
                 let ctrl = auth_ctrl.clone();
                 move |req: HttpRequest, body: web::Bytes| {
@@ -160,49 +1036,695 @@ async fn main() -> std::io::Result<()> {
                     async move { controller.login(req, body).await }
                 }
             }))
-            .route("/categories", web::get().to({
-                let ctrl = category_ctrl.clone();
+            .route("/auth/mfa/verify", web::post().to({
+                let ctrl = auth_ctrl.clone();
                 move |req: HttpRequest, body: web::Bytes| {
                     let controller = ctrl.clone();
-                    async move { controller.list(req, body).await }
+                    async move { controller.verify_mfa(req, body).await }
                 }
             }))
-            // Article routes
-            .route("/articles", web::post().to({
-                let ctrl = article_ctrl.clone();
+            // OIDC routes
+            .route("/auth/oidc/providers", web::get().to({
+                let ctrl = oidc_ctrl.clone();
                 move |req: HttpRequest, body: web::Bytes| {
                     let controller = ctrl.clone();
-                    async move { controller.create(req, body).await }
+                    async move { controller.providers(req, body).await }
                 }
             }))
-            .route("/articles", web::get().to({
-                let ctrl = article_ctrl.clone();
+            .route("/auth/oidc/{provider}/authorize", web::get().to({
+                let ctrl = oidc_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.authorize(req, body).await }
+                }
+            }))
+            .route("/auth/oidc/{provider}/callback", web::post().to({
+                let ctrl = oidc_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.callback(req, body).await }
+                }
+            }))
+            // User routes
+            .route("/users/me", web::get().to({
+                let ctrl = user_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.me(req, body).await }
+                }
+            }))
+            .route("/users/me", web::patch().to({
+                let ctrl = user_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.update_me(req, body).await }
+                }
+            }))
+            .route("/users/me", web::delete().to({
+                let ctrl = user_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.delete_me(req, body).await }
+                }
+            }))
+            .route("/users/me/password", web::post().to({
+                let ctrl = user_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.change_password(req, body).await }
+                }
+            }))
+            .route("/users/me/email/verify", web::post().to({
+                let ctrl = user_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.confirm_email(req, body).await }
+                }
+            }))
+            // MFA routes
+            .route("/users/me/mfa/totp", web::post().to({
+                let ctrl = mfa_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.enroll(req, body).await }
+                }
+            }))
+            .route("/users/me/mfa/totp", web::delete().to({
+                let ctrl = mfa_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.disable(req, body).await }
+                }
+            }))
+            .route("/users/me/mfa/totp/confirm", web::post().to({
+                let ctrl = mfa_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.confirm(req, body).await }
+                }
+            }))
+            // API key routes
+            .route("/users/me/api-keys", web::get().to({
+                let ctrl = api_key_ctrl.clone();
                 move |req: HttpRequest, body: web::Bytes| {
                     let controller = ctrl.clone();
                     async move { controller.list(req, body).await }
                 }
             }))
-            .route("/articles/{id}", web::get().to({
-                let ctrl = article_ctrl.clone();
+            .route("/users/me/api-keys", web::post().to({
+                let ctrl = api_key_ctrl.clone();
                 move |req: HttpRequest, body: web::Bytes| {
                     let controller = ctrl.clone();
-                    async move { controller.get(req, body).await }
+                    async move { controller.create(req, body).await }
                 }
             }))
-            .route("/articles/{id}", web::put().to({
-                let ctrl = article_ctrl.clone();
+            .route("/users/me/api-keys/{id}", web::delete().to({
+                let ctrl = api_key_ctrl.clone();
                 move |req: HttpRequest, body: web::Bytes| {
                     let controller = ctrl.clone();
-                    async move { controller.update(req, body).await }
+                    async move { controller.revoke(req, body).await }
                 }
             }))
-            .route("/articles/{id}", web::delete().to({
-                let ctrl = article_ctrl.clone();
+            // Session routes
+            .route("/users/me/sessions", web::get().to({
+                let ctrl = session_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.list(req, body).await }
+                }
+            }))
+            .route("/users/me/sessions/{id}", web::delete().to({
+                let ctrl = session_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.revoke(req, body).await }
+                }
+            }))
+            .route("/categories", web::get().to({
+                let ctrl = category_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.list(req, body).await }
+                }
+            }))
+            // Outgoing feed routes
+            .route("/categories/{id}/feed.xml", web::get().to({
+                let ctrl = syndication_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.category_feed(req, body, FeedFormat::Rss).await }
+                }
+            }))
+            .route("/categories/{id}/feed.atom", web::get().to({
+                let ctrl = syndication_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.category_feed(req, body, FeedFormat::Atom).await }
+                }
+            }))
+            .route("/categories/{id}/feed.json", web::get().to({
+                let ctrl = syndication_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.category_feed(req, body, FeedFormat::Json).await }
+                }
+            }))
+            // Subscription routes
+            .route("/me/subscriptions", web::get().to({
+                let ctrl = subscription_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.list(req, body).await }
+                }
+            }))
+            .route("/me/subscriptions", web::post().to({
+                let ctrl = subscription_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.subscribe(req, body).await }
+                }
+            }))
+            .route("/me/subscriptions/{id}", web::patch().to({
+                let ctrl = subscription_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.update(req, body).await }
+                }
+            }))
+            .route("/me/subscriptions/{id}", web::delete().to({
+                let ctrl = subscription_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.unsubscribe(req, body).await }
+                }
+            }))
+            .route("/me/articles", web::get().to({
+                let ctrl = subscription_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.timeline(req, body).await }
+                }
+            }))
+            .route("/me/articles/for-you", web::get().to({
+                let ctrl = subscription_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.for_you(req, body).await }
+                }
+            }))
+            // Read state routes
+            .route("/me/articles/read", web::post().to({
+                let ctrl = read_state_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.mark_all_read(req, body).await }
+                }
+            }))
+            .route("/me/unread-counts", web::get().to({
+                let ctrl = read_state_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.unread_counts(req, body).await }
+                }
+            }))
+            // Saved article and collection routes
+            .route("/me/saved", web::get().to({
+                let ctrl = saved_article_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.list(req, body).await }
+                }
+            }))
+            .route("/me/saved", web::post().to({
+                let ctrl = saved_article_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.save(req, body).await }
+                }
+            }))
+            .route("/me/saved/order", web::put().to({
+                let ctrl = saved_article_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.reorder(req, body).await }
+                }
+            }))
+            .route("/me/saved/{id}", web::patch().to({
+                let ctrl = saved_article_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.move_to(req, body).await }
+                }
+            }))
+            .route("/me/saved/{id}", web::delete().to({
+                let ctrl = saved_article_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.unsave(req, body).await }
+                }
+            }))
+            .route("/me/collections", web::get().to({
+                let ctrl = collection_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.list(req, body).await }
+                }
+            }))
+            .route("/me/collections", web::post().to({
+                let ctrl = collection_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.create(req, body).await }
+                }
+            }))
+            .route("/me/collections/{id}", web::patch().to({
+                let ctrl = collection_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.update(req, body).await }
+                }
+            }))
+            .route("/me/collections/{id}", web::delete().to({
+                let ctrl = collection_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.delete(req, body).await }
+                }
+            }))
+            .route("/shared/collections/{token}", web::get().to({
+                let ctrl = collection_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.shared(req, body).await }
+                }
+            }))
+            .route("/shared/collections/{token}/feed.xml", web::get().to({
+                let ctrl = syndication_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.collection_feed(req, body, FeedFormat::Rss).await }
+                }
+            }))
+            .route("/shared/collections/{token}/feed.atom", web::get().to({
+                let ctrl = syndication_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.collection_feed(req, body, FeedFormat::Atom).await }
+                }
+            }))
+            .route("/shared/collections/{token}/feed.json", web::get().to({
+                let ctrl = syndication_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.collection_feed(req, body, FeedFormat::Json).await }
+                }
+            }))
+            .route("/articles/{id}/read", web::post().to({
+                let ctrl = read_state_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.mark_read(req, body).await }
+                }
+            }))
+            .route("/articles/{id}/unread", web::post().to({
+                let ctrl = read_state_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.mark_unread(req, body).await }
+                }
+            }))
+            .route("/articles/{id}/rating", web::put().to({
+                let ctrl = article_feedback_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.rate(req, body).await }
+                }
+            }))
+            .route("/articles/{id}/rating", web::delete().to({
+                let ctrl = article_feedback_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.remove_rating(req, body).await }
+                }
+            }))
+            .route("/articles/{id}/reactions", web::get().to({
+                let ctrl = article_feedback_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.reactions(req, body).await }
+                }
+            }))
+            .route("/articles/{id}/reactions/{reaction}", web::put().to({
+                let ctrl = article_feedback_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.react(req, body).await }
+                }
+            }))
+            .route("/articles/{id}/reactions/{reaction}", web::delete().to({
+                let ctrl = article_feedback_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.unreact(req, body).await }
+                }
+            }))
+            .route("/me/annotations/export", web::get().to({
+                let ctrl = annotation_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.export(req, body).await }
+                }
+            }))
+            .route("/articles/{id}/annotations", web::get().to({
+                let ctrl = annotation_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.list(req, body).await }
+                }
+            }))
+            .route("/articles/{id}/annotations", web::post().to({
+                let ctrl = annotation_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.create(req, body).await }
+                }
+            }))
+            .route("/articles/{id}/annotations/{annotation_id}", web::patch().to({
+                let ctrl = annotation_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.update(req, body).await }
+                }
+            }))
+            .route("/articles/{id}/annotations/{annotation_id}", web::delete().to({
+                let ctrl = annotation_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.delete(req, body).await }
+                }
+            }))
+            .route("/me/tags", web::get().to({
+                let ctrl = tag_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.list(req, body).await }
+                }
+            }))
+            .route("/me/tags", web::post().to({
+                let ctrl = tag_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.create(req, body).await }
+                }
+            }))
+            .route("/me/tags/autocomplete", web::get().to({
+                let ctrl = tag_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.autocomplete(req, body).await }
+                }
+            }))
+            .route("/me/tags/{id}", web::patch().to({
+                let ctrl = tag_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.rename(req, body).await }
+                }
+            }))
+            .route("/me/tags/{id}", web::delete().to({
+                let ctrl = tag_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.delete(req, body).await }
+                }
+            }))
+            .route("/me/tags/{id}/merge", web::post().to({
+                let ctrl = tag_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.merge(req, body).await }
+                }
+            }))
+            .route("/articles/{id}/tags", web::get().to({
+                let ctrl = article_tag_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.list(req, body).await }
+                }
+            }))
+            .route("/articles/{id}/tags", web::put().to({
+                let ctrl = article_tag_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.set(req, body).await }
+                }
+            }))
+            .route("/me/smart-folders", web::get().to({
+                let ctrl = smart_folder_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.list(req, body).await }
+                }
+            }))
+            .route("/me/smart-folders", web::post().to({
+                let ctrl = smart_folder_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.create(req, body).await }
+                }
+            }))
+            .route("/me/smart-folders/{id}", web::patch().to({
+                let ctrl = smart_folder_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.update(req, body).await }
+                }
+            }))
+            .route("/me/smart-folders/{id}", web::delete().to({
+                let ctrl = smart_folder_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.delete(req, body).await }
+                }
+            }))
+            .route("/me/smart-folders/{id}/articles", web::get().to({
+                let ctrl = smart_folder_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.articles(req, body).await }
+                }
+            }))
+            .route("/me/alerts", web::get().to({
+                let ctrl = alert_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.list(req, body).await }
+                }
+            }))
+            .route("/me/alerts", web::post().to({
+                let ctrl = alert_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.create(req, body).await }
+                }
+            }))
+            .route("/me/alerts/{id}", web::put().to({
+                let ctrl = alert_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.update(req, body).await }
+                }
+            }))
+            .route("/me/alerts/{id}", web::delete().to({
+                let ctrl = alert_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.delete(req, body).await }
+                }
+            }))
+            .route("/me/notifications", web::get().to({
+                let ctrl = notification_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.list(req, body).await }
+                }
+            }))
+            .route("/me/notifications/read", web::post().to({
+                let ctrl = notification_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.mark_all_read(req, body).await }
+                }
+            }))
+            .route("/me/notifications/{id}/read", web::post().to({
+                let ctrl = notification_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.mark_read(req, body).await }
+                }
+            }))
+            .route("/me/digest", web::get().to({
+                let ctrl = digest_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.get(req, body).await }
+                }
+            }))
+            .route("/me/digest", web::put().to({
+                let ctrl = digest_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.save(req, body).await }
+                }
+            }))
+            .route("/me/digest", web::delete().to({
+                let ctrl = digest_ctrl.clone();
                 move |req: HttpRequest, body: web::Bytes| {
                     let controller = ctrl.clone();
                     async move { controller.delete(req, body).await }
                 }
             }))
+            .route("/me/digest/history", web::get().to({
+                let ctrl = digest_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.history(req, body).await }
+                }
+            }))
+            .route("/me/digest/preview", web::get().to({
+                let ctrl = digest_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.preview(req, body).await }
+                }
+            }))
+            // WebSocket
+            .route("/live/ticket", web::post().to({
+                let ctrl = live_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.ticket(req, body).await }
+                }
+            }))
+            .route("/ws", web::get().to({
+                let ctrl = live_ctrl.clone();
+                move |req: HttpRequest, payload: web::Payload| {
+                    let controller = ctrl.clone();
+                    async move { controller.connect(req, payload).await }
+                }
+            }))
+            // Article routes
+            .route("/articles", web::post().to({
+                let ctrl = article_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.create(req, body).await }
+                }
+            }))
+            .route("/articles", web::get().to({
+                let ctrl = article_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.list(req, body).await }
+                }
+            }))
+            .route("/articles/stream", web::get().to({
+                let ctrl = article_stream_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.stream(req, body).await }
+                }
+            }))
+            // O arquivo da importação passa do limite padrão de 256 KB do corpo
+            .service(
+                web::resource("/articles/import")
+                    .app_data(web::PayloadConfig::new(article_import_max_bytes))
+                    .route(web::post().to({
+                        let ctrl = article_import_ctrl.clone();
+                        move |req: HttpRequest, body: web::Bytes| {
+                            let controller = ctrl.clone();
+                            async move { controller.import(req, body).await }
+                        }
+                    })),
+            )
+            .route("/articles/export", web::get().to({
+                let ctrl = article_export_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.export(req, body).await }
+                }
+            }))
+            .route("/articles/{id}", web::get().to({
+                let ctrl = article_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.get(req, body).await }
+                }
+            }))
+            .route("/articles/{id}", web::put().to({
+                let ctrl = article_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.update(req, body).await }
+                }
+            }))
+            .route("/articles/{id}", web::delete().to({
+                let ctrl = article_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.delete(req, body).await }
+                }
+            }))
+            // Admin routes
+            .route("/admin/users/{id}/unlock", web::post().to({
+                let ctrl = admin_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.unlock_user(req, body).await }
+                }
+            }))
+            .route("/admin/webhooks", web::get().to({
+                let ctrl = webhook_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.list(req, body).await }
+                }
+            }))
+            .route("/admin/webhooks", web::post().to({
+                let ctrl = webhook_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.create(req, body).await }
+                }
+            }))
+            .route("/admin/webhooks/{id}", web::put().to({
+                let ctrl = webhook_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.update(req, body).await }
+                }
+            }))
+            .route("/admin/webhooks/{id}", web::delete().to({
+                let ctrl = webhook_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.delete(req, body).await }
+                }
+            }))
+            .route("/admin/webhooks/{id}/deliveries", web::get().to({
+                let ctrl = webhook_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.deliveries(req, body).await }
+                }
+            }))
+            .route("/admin/webhooks/{id}/test", web::post().to({
+                let ctrl = webhook_ctrl.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.test(req, body).await }
+                }
+            }))
     })
     .bind(format!("{}:{}", host, port))?
     .run()
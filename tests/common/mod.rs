@@ -1,16 +1,196 @@
 // Common test utilities and helpers
 #![allow(dead_code)]
 
+use actix_web::test::TestRequest;
+use actix_web::{HttpRequest, HttpResponse};
+use nexight_backend::application::services::api_key_service::ApiKeyService;
 use nexight_backend::application::services::auth_service::AuthService;
+use nexight_backend::application::services::jwt_keys::JwtKeySet;
+use nexight_backend::application::services::session_service::SessionService;
+use nexight_backend::application::use_cases::create_api_key::CreateApiKeyUseCase;
+use nexight_backend::application::use_cases::login_user::LoginOutcome;
+use nexight_backend::domain::entities::api_key::{ApiKeyScope, CreateApiKeyDto};
+use nexight_backend::domain::entities::article::{Article, CreateArticleDto};
+use nexight_backend::domain::entities::session::SessionClient;
+use nexight_backend::domain::entities::user::{AuthResponse, User};
+use nexight_backend::domain::repositories::article_repository::ArticleRepository;
+use nexight_backend::domain::repositories::user_repository::UserRepository;
+use nexight_backend::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use nexight_backend::infrastructure::repositories::in_memory_api_key_repository::InMemoryApiKeyRepository;
+use nexight_backend::infrastructure::repositories::in_memory_session_repository::InMemorySessionRepository;
 use nexight_backend::infrastructure::repositories::in_memory_user_repository::InMemoryUserRepository;
+use chrono::{DateTime, Duration, Utc};
+use data_encoding::BASE32_NOPAD;
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
 use std::sync::Arc;
+use uuid::Uuid;
 
 /// Create a test AuthService with default settings
 pub fn create_test_auth_service() -> Arc<AuthService> {
-    Arc::new(AuthService::new("test_secret_key_for_testing".to_string(), 24))
+    Arc::new(AuthService::with_keys(JwtKeySet::hmac(TEST_JWT_SECRET), 24))
+}
+
+/// HMAC secret behind `create_test_auth_service`
+pub const TEST_JWT_SECRET: &str = "test_secret_key_for_testing";
+
+/// Create a SessionService backed by an in-memory repository
+pub fn create_test_session_service(auth_service: Arc<AuthService>) -> Arc<SessionService> {
+    Arc::new(SessionService::new(
+        Arc::new(InMemorySessionRepository::new()),
+        auth_service,
+    ))
+}
+
+/// Session issued by a login that did not ask for a second factor
+pub fn authenticated(outcome: LoginOutcome) -> Option<AuthResponse> {
+    match outcome {
+        LoginOutcome::Authenticated(response) => Some(response),
+        LoginOutcome::MfaRequired(_) | LoginOutcome::MfaEnrollmentRequired(_) => None,
+    }
 }
 
 /// Create a test InMemoryUserRepository
 pub fn create_test_user_repository() -> Arc<InMemoryUserRepository> {
     Arc::new(InMemoryUserRepository::new())
 }
+
+/// AuthMiddleware that accepts `user`; returns it with an access token from a real login session
+pub async fn create_test_session_auth(users: Arc<InMemoryUserRepository>, user: &User) -> (Arc<AuthMiddleware>, String) {
+    let auth_service = create_test_auth_service();
+    let session_service = create_test_session_service(auth_service.clone());
+    let token = session_service
+        .start(user.id, &user.email, SessionClient::default())
+        .await
+        .unwrap();
+    let auth_middleware = Arc::new(AuthMiddleware::new(
+        auth_service,
+        users,
+        Arc::new(ApiKeyService::new(Arc::new(InMemoryApiKeyRepository::new()))),
+        session_service,
+    ));
+
+    (auth_middleware, token)
+}
+
+/// AuthMiddleware for a single user holding an API key limited to `scopes`; returns the raw key
+pub async fn create_test_api_key_auth(scopes: Vec<ApiKeyScope>) -> (Arc<AuthMiddleware>, String) {
+    let users = create_test_user_repository();
+    let user = User::new("bot@example.com".to_string(), "hash".to_string(), "Bot".to_string());
+    let user = users.create(user).await.unwrap();
+
+    let api_keys = Arc::new(InMemoryApiKeyRepository::new());
+    let dto = CreateApiKeyDto {
+        name: "Test key".to_string(),
+        scopes,
+        expires_in_days: None,
+    };
+    let created = CreateApiKeyUseCase::new(api_keys.clone())
+        .execute(user.id, dto)
+        .await
+        .unwrap();
+
+    let auth_service = create_test_auth_service();
+    let middleware = AuthMiddleware::new(
+        auth_service.clone(),
+        users,
+        Arc::new(ApiKeyService::new(api_keys)),
+        create_test_session_service(auth_service),
+    );
+
+    (Arc::new(middleware), created.key)
+}
+
+/// 6-digit TOTP code (RFC 6238, SHA1, 30 s steps) for a Base32 secret, computed independently of `TotpService`
+pub fn totp_code(secret: &str, timestamp: i64) -> String {
+    let key = BASE32_NOPAD.decode(secret.as_bytes()).expect("invalid Base32 secret");
+    let mut mac = Hmac::<Sha1>::new_from_slice(&key).unwrap();
+    mac.update(&(timestamp.div_euclid(30) as u64).to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+
+    let offset = (digest[19] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([digest[offset] & 0x7f, digest[offset + 1], digest[offset + 2], digest[offset + 3]]);
+    format!("{:06}", binary % 1_000_000)
+}
+
+/// Request authenticated only by the `X-API-Key` header
+pub fn api_key_request(key: &str) -> HttpRequest {
+    TestRequest::default()
+        .insert_header(("X-API-Key", key))
+        .to_http_request()
+}
+
+/// Stable `code` of a problem response returned directly by a controller
+pub async fn problem_code(response: HttpResponse) -> String {
+    let body = actix_web::body::to_bytes(response.into_body()).await.unwrap();
+    let problem: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    problem["code"].as_str().unwrap_or_default().to_string()
+}
+
+/// Optional fields for `create_test_article`; unset fields get the fixture defaults
+#[derive(Default)]
+pub struct TestArticleOptions {
+    pub category_id: Option<Uuid>,
+    pub rate: Option<i32>,
+    pub pub_date: Option<DateTime<Utc>>,
+    pub content: Option<String>,
+    pub ai_summary: Option<String>,
+}
+
+impl TestArticleOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn category(mut self, category_id: Option<Uuid>) -> Self {
+        self.category_id = category_id;
+        self
+    }
+
+    pub fn rate(mut self, rate: Option<i32>) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    pub fn pub_date(mut self, pub_date: DateTime<Utc>) -> Self {
+        self.pub_date = Some(pub_date);
+        self
+    }
+
+    pub fn hours_ago(self, hours: i64) -> Self {
+        self.pub_date(Utc::now() - Duration::hours(hours))
+    }
+
+    pub fn content(mut self, content: &str) -> Self {
+        self.content = Some(content.to_string());
+        self
+    }
+
+    pub fn ai_summary(mut self, ai_summary: Option<&str>) -> Self {
+        self.ai_summary = ai_summary.map(str::to_string);
+        self
+    }
+}
+
+/// Create an article in `repository`; the link is derived from the title, so titles must be unique
+pub async fn create_test_article(
+    repository: &dyn ArticleRepository,
+    feed_id: Uuid,
+    title: &str,
+    options: TestArticleOptions,
+) -> Article {
+    let mut article = Article::new(CreateArticleDto {
+        title: title.to_string(),
+        description: format!("Description for {}", title),
+        link: format!("https://example.com/{}", title.to_lowercase().replace(' ', "-")),
+        pub_date: options.pub_date.unwrap_or_else(Utc::now),
+        media: None,
+        content: options.content.unwrap_or_else(|| format!("Content for {}", title)),
+        creator: "Test Author".to_string(),
+        feed_id,
+    });
+    article.category_id = options.category_id;
+    article.rate = options.rate;
+    article.ai_summary = options.ai_summary;
+    repository.create(article).await.unwrap()
+}
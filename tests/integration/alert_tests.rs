@@ -0,0 +1,496 @@
+use crate::common::{api_key_request, create_test_api_key_auth};
+use actix_web::http::StatusCode;
+use actix_web::web::Bytes;
+use chrono::{Duration, Utc};
+use nexight_backend::application::services::alert_dispatcher::AlertDispatcher;
+use nexight_backend::application::services::alert_service::AlertService;
+use nexight_backend::application::services::notification_channel::NotificationChannel;
+use nexight_backend::application::services::webhook_target_policy::WebhookTargetPolicy;
+use nexight_backend::application::use_cases::create_alert_rule::{CreateAlertRuleError, CreateAlertRuleUseCase};
+use nexight_backend::application::use_cases::create_article::CreateArticleUseCase;
+use nexight_backend::application::use_cases::delete_alert_rule::{DeleteAlertRuleError, DeleteAlertRuleUseCase};
+use nexight_backend::application::use_cases::list_alert_rules::ListAlertRulesUseCase;
+use nexight_backend::application::use_cases::list_notifications::{ListNotificationsError, ListNotificationsUseCase};
+use nexight_backend::application::use_cases::mark_notifications_read::{
+    MarkNotificationsReadError, MarkNotificationsReadUseCase,
+};
+use nexight_backend::application::use_cases::update_alert_rule::{UpdateAlertRuleError, UpdateAlertRuleUseCase};
+use nexight_backend::application::use_cases::update_article::UpdateArticleUseCase;
+use nexight_backend::domain::entities::api_key::ApiKeyScope;
+use nexight_backend::domain::entities::alert::{
+    AlertChannel, AlertRule, AlertRuleDto, Notification, NotificationDeliveryStatus,
+};
+use nexight_backend::domain::entities::article::{Article, CreateArticleDto, UpdateArticleDto};
+use nexight_backend::domain::entities::user::User;
+use nexight_backend::domain::repositories::user_repository::UserRepository;
+use nexight_backend::infrastructure::mail::in_memory_mailer::InMemoryMailer;
+use nexight_backend::infrastructure::notifications::email_notification_channel::EmailNotificationChannel;
+use nexight_backend::infrastructure::notifications::webhook_notification_channel::WebhookNotificationChannel;
+use nexight_backend::infrastructure::repositories::in_memory_alert_rule_repository::InMemoryAlertRuleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_category_repository::InMemoryArticleCategoryRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_notification_repository::InMemoryNotificationRepository;
+use nexight_backend::infrastructure::repositories::in_memory_user_repository::InMemoryUserRepository;
+use nexight_backend::interfaces::controllers::alert_controller::AlertController;
+use nexight_backend::interfaces::controllers::notification_controller::NotificationController;
+use std::sync::Arc;
+use uuid::Uuid;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+struct TestSetup {
+    users: Arc<InMemoryUserRepository>,
+    mailer: Arc<InMemoryMailer>,
+    categories: Arc<InMemoryArticleCategoryRepository>,
+    rules: Arc<InMemoryAlertRuleRepository>,
+    notifications: Arc<InMemoryNotificationRepository>,
+    dispatcher: AlertDispatcher,
+    create_article: CreateArticleUseCase,
+    update_article: UpdateArticleUseCase,
+    create_rule: CreateAlertRuleUseCase,
+    update_rule: UpdateAlertRuleUseCase,
+    delete_rule: DeleteAlertRuleUseCase,
+    list_notifications: ListNotificationsUseCase,
+    mark_read: MarkNotificationsReadUseCase,
+}
+
+fn create_test_setup() -> TestSetup {
+    let users = Arc::new(InMemoryUserRepository::new());
+    let mailer = Arc::new(InMemoryMailer::new());
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let categories = Arc::new(InMemoryArticleCategoryRepository::new());
+    let rules = Arc::new(InMemoryAlertRuleRepository::new());
+    let notifications = Arc::new(InMemoryNotificationRepository::new());
+    let alert_service = Arc::new(AlertService::new(rules.clone(), notifications.clone()));
+    let dispatcher = AlertDispatcher::new(
+        rules.clone(),
+        notifications.clone(),
+        articles.clone(),
+        vec![
+            Arc::new(EmailNotificationChannel::new(mailer.clone(), users.clone())),
+            Arc::new(WebhookNotificationChannel::new(WebhookTargetPolicy::allow_private_networks())),
+        ],
+    );
+
+    TestSetup {
+        create_article: CreateArticleUseCase::new(articles.clone(), alert_service.clone()),
+        update_article: UpdateArticleUseCase::new(articles, alert_service),
+        // O wiremock escuta em 127.0.0.1
+        create_rule: CreateAlertRuleUseCase::new(rules.clone(), categories.clone(), WebhookTargetPolicy::allow_private_networks()),
+        update_rule: UpdateAlertRuleUseCase::new(rules.clone(), categories.clone(), WebhookTargetPolicy::allow_private_networks()),
+        delete_rule: DeleteAlertRuleUseCase::new(rules.clone()),
+        list_notifications: ListNotificationsUseCase::new(notifications.clone()),
+        mark_read: MarkNotificationsReadUseCase::new(notifications.clone()),
+        users,
+        mailer,
+        categories,
+        rules,
+        notifications,
+        dispatcher,
+    }
+}
+
+async fn create_user(setup: &TestSetup) -> Uuid {
+    let user = User::new(
+        format!("{}@example.com", Uuid::new_v4()),
+        "hash".to_string(),
+        "Alert User".to_string(),
+    );
+    setup.users.create(user).await.unwrap().id
+}
+
+fn rule(name: &str, query: Option<&str>) -> AlertRuleDto {
+    AlertRuleDto {
+        name: name.to_string(),
+        query: query.map(str::to_string),
+        category_id: None,
+        feed_id: None,
+        min_rate: None,
+        channels: Vec::new(),
+        webhook_url: None,
+        enabled: true,
+    }
+}
+
+fn rule_for_feed(feed_id: Uuid) -> AlertRuleDto {
+    AlertRuleDto {
+        feed_id: Some(feed_id),
+        ..rule("Other feed", None)
+    }
+}
+
+fn article(feed_id: Uuid, title: &str) -> CreateArticleDto {
+    CreateArticleDto {
+        title: title.to_string(),
+        description: format!("Description for {}", title),
+        link: format!("https://example.com/{}", title.to_lowercase().replace(' ', "-")),
+        pub_date: Utc::now(),
+        media: None,
+        content: format!("Content for {}", title),
+        creator: "Test Author".to_string(),
+        feed_id,
+    }
+}
+
+fn enrichment(rate: i32) -> UpdateArticleDto {
+    UpdateArticleDto {
+        title: None,
+        description: None,
+        link: None,
+        pub_date: None,
+        media: None,
+        content: None,
+        creator: None,
+        ai_summary: None,
+        rate: Some(rate),
+        keywords: None,
+        category_id: None,
+        ai_columnist: None,
+    }
+}
+
+#[tokio::test]
+async fn test_ingestion_notifies_matching_phrase_rules_once() {
+    let setup = create_test_setup();
+    let user_id = create_user(&setup).await;
+    let feed_id = Uuid::new_v4();
+    let rule = setup.create_rule.execute(user_id, rule("Rust", Some("Rust Async"))).await.unwrap();
+    setup.create_rule.execute(user_id, rule_for_feed(Uuid::new_v4())).await.unwrap();
+
+    let matching = setup.create_article.execute(article(feed_id, "Rust async in practice")).await.unwrap();
+    // Palavras inteiras e na ordem: "Trust" e "async Rust" não atendem
+    setup.create_article.execute(article(feed_id, "Trust async runtimes")).await.unwrap();
+    setup.create_article.execute(article(feed_id, "Async Rust")).await.unwrap();
+
+    // Um novo enriquecimento não gera outra notificação para a mesma regra
+    setup.update_article.execute(matching.id, enrichment(8)).await.unwrap();
+
+    let inbox = setup.list_notifications.execute(user_id, false, 1, 20).await.unwrap();
+    assert_eq!(inbox.total, 1);
+    assert_eq!(inbox.unread, 1);
+    assert_eq!(inbox.notifications[0].rule_id, rule.id);
+    assert_eq!(inbox.notifications[0].article_id, matching.id);
+    assert!(!inbox.notifications[0].read);
+    assert!(setup.mailer.sent_messages().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_min_rate_rule_fires_when_enrichment_adds_the_rate() {
+    let setup = create_test_setup();
+    let user_id = create_user(&setup).await;
+    let feed_id = Uuid::new_v4();
+    let dto = AlertRuleDto {
+        feed_id: Some(feed_id),
+        min_rate: Some(7),
+        channels: vec![AlertChannel::Email, AlertChannel::Email],
+        ..rule("Top stories", None)
+    };
+    let created = setup.create_rule.execute(user_id, dto).await.unwrap();
+    assert_eq!(created.channels, vec![AlertChannel::Email]);
+
+    let low = setup.create_article.execute(article(feed_id, "Minor update")).await.unwrap();
+    let high = setup.create_article.execute(article(feed_id, "Major release")).await.unwrap();
+    assert_eq!(setup.list_notifications.execute(user_id, false, 1, 20).await.unwrap().total, 0);
+
+    setup.update_article.execute(low.id, enrichment(3)).await.unwrap();
+    setup.update_article.execute(high.id, enrichment(9)).await.unwrap();
+
+    let inbox = setup.list_notifications.execute(user_id, false, 1, 20).await.unwrap();
+    assert_eq!(inbox.total, 1);
+    assert_eq!(inbox.notifications[0].article_id, high.id);
+
+    // O email sai na rodada do dispatcher, fora da requisição que criou a notificação
+    assert!(setup.mailer.sent_messages().await.is_empty());
+    assert_eq!(setup.dispatcher.dispatch(Utc::now()).await.unwrap(), 1);
+
+    let sent = setup.mailer.sent_messages().await;
+    assert_eq!(sent.len(), 1);
+    assert!(sent[0].subject.contains("Top stories"));
+    assert!(sent[0].text_body.contains("https://example.com/major-release"));
+}
+
+#[tokio::test]
+async fn test_webhook_channel_posts_the_notification() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hooks/alerts"))
+        .and(body_partial_json(serde_json::json!({ "event": "alert.triggered" })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let setup = create_test_setup();
+    let user_id = create_user(&setup).await;
+    let dto = AlertRuleDto {
+        channels: vec![AlertChannel::Webhook],
+        webhook_url: Some(format!("{}/hooks/alerts", server.uri())),
+        ..rule("Hooks", Some("kubernetes"))
+    };
+    setup.create_rule.execute(user_id, dto).await.unwrap();
+
+    setup.create_article.execute(article(Uuid::new_v4(), "Kubernetes 2.0")).await.unwrap();
+    assert_eq!(setup.dispatcher.dispatch(Utc::now()).await.unwrap(), 1);
+    assert_eq!(setup.dispatcher.dispatch(Utc::now()).await.unwrap(), 0);
+
+    server.verify().await;
+}
+
+#[tokio::test]
+async fn test_failed_delivery_keeps_the_notification_and_the_article() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let setup = create_test_setup();
+    let user_id = create_user(&setup).await;
+    let dto = AlertRuleDto {
+        channels: vec![AlertChannel::Webhook],
+        webhook_url: Some(server.uri()),
+        ..rule("Broken hook", Some("postgres"))
+    };
+    setup.create_rule.execute(user_id, dto).await.unwrap();
+
+    let result = setup.create_article.execute(article(Uuid::new_v4(), "Postgres tips")).await;
+    assert!(result.is_ok());
+    let inbox = setup.list_notifications.execute(user_id, false, 1, 20).await.unwrap();
+    assert_eq!(inbox.total, 1);
+    assert!(server.received_requests().await.unwrap().is_empty());
+
+    let now = Utc::now();
+    assert_eq!(setup.dispatcher.dispatch(now).await.unwrap(), 1);
+    let deliveries = setup.notifications.deliveries_of(inbox.notifications[0].id).await;
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!(deliveries[0].status, NotificationDeliveryStatus::Pending);
+    assert_eq!(deliveries[0].attempts, 1);
+    assert!(deliveries[0].last_error.as_ref().is_some_and(|error| error.contains("500")));
+
+    // Backoff: nada antes de 30s, nova tentativa depois
+    assert_eq!(setup.dispatcher.dispatch(now + Duration::seconds(10)).await.unwrap(), 0);
+    assert_eq!(setup.dispatcher.dispatch(now + Duration::seconds(31)).await.unwrap(), 1);
+    assert_eq!(setup.notifications.deliveries_of(inbox.notifications[0].id).await[0].attempts, 2);
+}
+
+#[tokio::test]
+async fn test_delivery_is_abandoned_when_the_channel_leaves_the_rule() {
+    let setup = create_test_setup();
+    let user_id = create_user(&setup).await;
+    let dto = AlertRuleDto {
+        channels: vec![AlertChannel::Email],
+        ..rule("Mail me", Some("rust"))
+    };
+    let created = setup.create_rule.execute(user_id, dto).await.unwrap();
+    setup.create_article.execute(article(Uuid::new_v4(), "Rust 2.0")).await.unwrap();
+
+    setup.update_rule.execute(user_id, created.id, rule("Mail me", Some("rust"))).await.unwrap();
+
+    assert_eq!(setup.dispatcher.dispatch(Utc::now()).await.unwrap(), 0);
+    assert!(setup.mailer.sent_messages().await.is_empty());
+    let inbox = setup.list_notifications.execute(user_id, false, 1, 20).await.unwrap();
+    let deliveries = setup.notifications.deliveries_of(inbox.notifications[0].id).await;
+    assert_eq!(deliveries[0].status, NotificationDeliveryStatus::Failed);
+}
+
+#[tokio::test]
+async fn test_create_rule_validation() {
+    let setup = create_test_setup();
+    let user_id = create_user(&setup).await;
+
+    let no_criteria = setup.create_rule.execute(user_id, rule("Everything", None)).await;
+    assert!(matches!(no_criteria, Err(CreateAlertRuleError::ValidationError(_))));
+
+    let blank_query = setup.create_rule.execute(user_id, rule("Blank", Some(" !? "))).await;
+    assert!(matches!(blank_query, Err(CreateAlertRuleError::ValidationError(_))));
+
+    let missing_url = AlertRuleDto {
+        channels: vec![AlertChannel::Webhook],
+        ..rule("Hook", Some("rust"))
+    };
+    let result = setup.create_rule.execute(user_id, missing_url).await;
+    assert!(matches!(result, Err(CreateAlertRuleError::ValidationError(_))));
+
+    let bad_rate = AlertRuleDto {
+        min_rate: Some(11),
+        ..rule("Rate", None)
+    };
+    let result = setup.create_rule.execute(user_id, bad_rate).await;
+    assert!(matches!(result, Err(CreateAlertRuleError::ValidationError(_))));
+
+    let unknown_category = AlertRuleDto {
+        category_id: Some(Uuid::new_v4()),
+        ..rule("Category", None)
+    };
+    let result = setup.create_rule.execute(user_id, unknown_category).await;
+    assert!(matches!(result, Err(CreateAlertRuleError::CategoryNotFound)));
+}
+
+#[tokio::test]
+async fn test_webhook_url_must_point_to_a_public_address() {
+    let setup = create_test_setup();
+    let user_id = create_user(&setup).await;
+    let create_rule = CreateAlertRuleUseCase::new(setup.rules.clone(), setup.categories.clone(), WebhookTargetPolicy::public_only());
+    let hook = |url: &str| AlertRuleDto {
+        channels: vec![AlertChannel::Webhook],
+        webhook_url: Some(url.to_string()),
+        ..rule("Hook", Some("rust"))
+    };
+
+    for url in [
+        "ftp://hooks.example.com/alerts",
+        "http://localhost:8080/alerts",
+        "http://127.0.0.1/alerts",
+        "http://10.0.0.5/alerts",
+        "http://192.168.1.10/alerts",
+        "http://169.254.169.254/latest/meta-data",
+        "http://[::1]/alerts",
+        "http://[::ffff:127.0.0.1]/alerts",
+    ] {
+        let result = create_rule.execute(user_id, hook(url)).await;
+        assert!(matches!(result, Err(CreateAlertRuleError::ValidationError(_))), "{} should be rejected", url);
+    }
+
+    assert!(create_rule.execute(user_id, hook("https://hooks.example.com/alerts")).await.is_ok());
+}
+
+fn webhook_delivery(url: String) -> (AlertRule, Notification, Article) {
+    let rule = AlertRule::new(
+        Uuid::new_v4(),
+        AlertRuleDto {
+            channels: vec![AlertChannel::Webhook],
+            webhook_url: Some(url),
+            ..rule("Hook", Some("rust"))
+        },
+    );
+    let article = Article::new(article(Uuid::new_v4(), "Rust release"));
+    let notification = Notification::new(&rule, &article);
+    (rule, notification, article)
+}
+
+#[tokio::test]
+async fn test_webhook_channel_refuses_private_targets() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    // Regra gravada antes da validação atual, apontando para a rede interna
+    let (rule, notification, article) = webhook_delivery(server.uri());
+    let channel = WebhookNotificationChannel::new(WebhookTargetPolicy::public_only());
+
+    assert!(channel.deliver(&rule, &notification, &article).await.is_err());
+    server.verify().await;
+}
+
+#[tokio::test]
+async fn test_webhook_channel_does_not_follow_redirects() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hooks/alerts"))
+        .respond_with(ResponseTemplate::new(307).insert_header("Location", "/internal"))
+        .mount(&server)
+        .await;
+    Mock::given(path("/internal"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let (rule, notification, article) = webhook_delivery(format!("{}/hooks/alerts", server.uri()));
+    let channel = WebhookNotificationChannel::new(WebhookTargetPolicy::allow_private_networks());
+
+    assert!(channel.deliver(&rule, &notification, &article).await.is_err());
+    server.verify().await;
+}
+
+#[tokio::test]
+async fn test_update_and_delete_rules_are_scoped_to_owner() {
+    let setup = create_test_setup();
+    let owner = create_user(&setup).await;
+    let other = create_user(&setup).await;
+    let created = setup.create_rule.execute(owner, rule("Rust", Some("rust"))).await.unwrap();
+
+    let result = setup.update_rule.execute(other, created.id, rule("Go", Some("go"))).await;
+    assert!(matches!(result, Err(UpdateAlertRuleError::NotFound)));
+
+    let disabled = AlertRuleDto {
+        enabled: false,
+        ..rule("Go", Some("go"))
+    };
+    let updated = setup.update_rule.execute(owner, created.id, disabled).await.unwrap();
+    assert_eq!(updated.name, "Go");
+    assert!(!updated.enabled);
+
+    // Regras desativadas não disparam
+    setup.create_article.execute(article(Uuid::new_v4(), "Go modules")).await.unwrap();
+    assert_eq!(setup.list_notifications.execute(owner, false, 1, 20).await.unwrap().total, 0);
+
+    let result = setup.delete_rule.execute(other, created.id).await;
+    assert!(matches!(result, Err(DeleteAlertRuleError::NotFound)));
+    setup.delete_rule.execute(owner, created.id).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_mark_notifications_read() {
+    let setup = create_test_setup();
+    let user_id = create_user(&setup).await;
+    setup.create_rule.execute(user_id, rule("Rust", Some("rust"))).await.unwrap();
+    for title in ["Rust one", "Rust two", "Rust three"] {
+        setup.create_article.execute(article(Uuid::new_v4(), title)).await.unwrap();
+    }
+
+    let inbox = setup.list_notifications.execute(user_id, false, 1, 2).await.unwrap();
+    assert_eq!((inbox.total, inbox.unread, inbox.total_pages), (3, 3, 2));
+    assert_eq!(inbox.notifications.len(), 2);
+
+    let first = inbox.notifications[0].id;
+    setup.mark_read.execute(user_id, first).await.unwrap();
+    let result = setup.mark_read.execute(Uuid::new_v4(), first).await;
+    assert!(matches!(result, Err(MarkNotificationsReadError::NotFound)));
+
+    let unread = setup.list_notifications.execute(user_id, true, 1, 20).await.unwrap();
+    assert_eq!((unread.total, unread.unread), (2, 2));
+    assert!(unread.notifications.iter().all(|notification| notification.id != first));
+
+    let marked = setup.mark_read.execute_all(user_id).await.unwrap();
+    assert_eq!(marked.marked, 2);
+    assert_eq!(setup.list_notifications.execute(user_id, false, 1, 20).await.unwrap().unread, 0);
+
+    let result = setup.list_notifications.execute(user_id, false, 0, 20).await;
+    assert!(matches!(result, Err(ListNotificationsError::InvalidPagination)));
+}
+
+#[tokio::test]
+async fn test_alert_and_notification_changes_require_library_write_scope() {
+    let setup = create_test_setup();
+    let (auth, key) = create_test_api_key_auth(vec![ApiKeyScope::ArticlesRead]).await;
+    let alerts = AlertController::new(
+        Arc::new(CreateAlertRuleUseCase::new(setup.rules.clone(), setup.categories.clone(), WebhookTargetPolicy::public_only())),
+        Arc::new(ListAlertRulesUseCase::new(setup.rules.clone())),
+        Arc::new(UpdateAlertRuleUseCase::new(setup.rules.clone(), setup.categories.clone(), WebhookTargetPolicy::public_only())),
+        Arc::new(DeleteAlertRuleUseCase::new(setup.rules.clone())),
+        auth.clone(),
+    );
+    let notifications = NotificationController::new(
+        Arc::new(ListNotificationsUseCase::new(setup.notifications.clone())),
+        Arc::new(MarkNotificationsReadUseCase::new(setup.notifications.clone())),
+        auth,
+    );
+
+    let responses = [
+        alerts.create(api_key_request(&key), Bytes::new()).await,
+        alerts.update(api_key_request(&key), Bytes::new()).await,
+        alerts.delete(api_key_request(&key), Bytes::new()).await,
+        notifications.mark_read(api_key_request(&key), Bytes::new()).await,
+        notifications.mark_all_read(api_key_request(&key), Bytes::new()).await,
+    ];
+    for response in responses {
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    let listed = notifications.list(api_key_request(&key), Bytes::new()).await;
+    assert_eq!(listed.status(), StatusCode::OK);
+}
@@ -0,0 +1,232 @@
+use crate::common::{api_key_request, create_test_api_key_auth, create_test_article, TestArticleOptions};
+use actix_web::http::StatusCode;
+use actix_web::web::Bytes;
+use nexight_backend::application::use_cases::create_annotation::{CreateAnnotationError, CreateAnnotationUseCase};
+use nexight_backend::application::use_cases::delete_annotation::{DeleteAnnotationError, DeleteAnnotationUseCase};
+use nexight_backend::application::use_cases::export_annotations::ExportAnnotationsUseCase;
+use nexight_backend::application::use_cases::list_annotations::{ListAnnotationsError, ListAnnotationsUseCase};
+use nexight_backend::application::use_cases::update_annotation::{UpdateAnnotationError, UpdateAnnotationUseCase};
+use nexight_backend::domain::entities::api_key::ApiKeyScope;
+use nexight_backend::domain::entities::annotation::{
+    AnchorStatus, ArticleAnnotations, CreateAnnotationDto, TextQuoteAnchor, UpdateAnnotationDto,
+};
+use nexight_backend::domain::repositories::article_repository::ArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_annotation_repository::InMemoryAnnotationRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::interfaces::controllers::annotation_controller::AnnotationController;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct TestSetup {
+    articles: Arc<InMemoryArticleRepository>,
+    annotations: Arc<InMemoryAnnotationRepository>,
+    create: CreateAnnotationUseCase,
+    list: ListAnnotationsUseCase,
+    update: UpdateAnnotationUseCase,
+    delete: DeleteAnnotationUseCase,
+    export: ExportAnnotationsUseCase,
+}
+
+fn create_test_setup() -> TestSetup {
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let annotations = Arc::new(InMemoryAnnotationRepository::new());
+
+    TestSetup {
+        create: CreateAnnotationUseCase::new(articles.clone(), annotations.clone()),
+        list: ListAnnotationsUseCase::new(articles.clone(), annotations.clone()),
+        update: UpdateAnnotationUseCase::new(articles.clone(), annotations.clone()),
+        delete: DeleteAnnotationUseCase::new(annotations.clone()),
+        export: ExportAnnotationsUseCase::new(articles.clone(), annotations.clone()),
+        articles,
+        annotations,
+    }
+}
+
+fn highlight(quote: &str, start: i32, note: Option<&str>) -> CreateAnnotationDto {
+    CreateAnnotationDto {
+        anchor: Some(TextQuoteAnchor {
+            quote: quote.to_string(),
+            prefix: String::new(),
+            suffix: String::new(),
+            start,
+            end: start + quote.chars().count() as i32,
+        }),
+        note: note.map(|n| n.to_string()),
+    }
+}
+
+#[tokio::test]
+async fn test_create_highlight_fixes_offsets_and_fills_context() {
+    let setup = create_test_setup();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new().content("Rust 1.80 ships LazyCell and LazyLock.")).await;
+
+    // Posição informada pelo cliente está errada; o servidor encontra o trecho
+    let annotation = setup
+        .create
+        .execute(Uuid::new_v4(), article.id, highlight("LazyCell", 0, Some("Check this")))
+        .await
+        .unwrap();
+
+    let anchor = annotation.anchor.unwrap();
+    assert_eq!((anchor.start, anchor.end), (Some(16), Some(24)));
+    assert_eq!(anchor.status, AnchorStatus::Anchored);
+    assert_eq!(anchor.prefix, "Rust 1.80 ships ");
+    assert_eq!(anchor.suffix, " and LazyLock.");
+    assert_eq!(annotation.note.as_deref(), Some("Check this"));
+}
+
+#[tokio::test]
+async fn test_create_rejects_quote_missing_from_content_or_empty_annotation() {
+    let setup = create_test_setup();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new().content("Rust 1.80 ships LazyCell.")).await;
+
+    let missing_quote = setup.create.execute(Uuid::new_v4(), article.id, highlight("OnceCell", 0, None)).await;
+    let empty = setup
+        .create
+        .execute(Uuid::new_v4(), article.id, CreateAnnotationDto { anchor: None, note: Some("  ".to_string()) })
+        .await;
+    let unknown_article = setup.create.execute(Uuid::new_v4(), Uuid::new_v4(), highlight("Rust", 0, None)).await;
+
+    assert!(matches!(missing_quote, Err(CreateAnnotationError::ValidationError(_))));
+    assert!(matches!(empty, Err(CreateAnnotationError::ValidationError(_))));
+    assert!(matches!(unknown_article, Err(CreateAnnotationError::ArticleNotFound)));
+}
+
+#[tokio::test]
+async fn test_list_reanchors_after_content_update() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let mut article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new().content("Rust 1.80 ships LazyCell and LazyLock.")).await;
+    setup.create.execute(user_id, article.id, highlight("LazyLock", 29, None)).await.unwrap();
+    setup.create.execute(user_id, article.id, highlight("LazyCell", 16, None)).await.unwrap();
+    setup
+        .create
+        .execute(user_id, article.id, CreateAnnotationDto { anchor: None, note: Some("Overall note".to_string()) })
+        .await
+        .unwrap();
+
+    // Nova ingestão muda o início do texto e remove um dos trechos
+    article.content = "Update: Rust 1.80 ships LazyCell.".to_string();
+    setup.articles.update(article.clone()).await.unwrap();
+
+    let annotations = setup.list.execute(user_id, article.id).await.unwrap();
+
+    assert_eq!(annotations.len(), 3);
+    assert!(annotations[0].anchor.is_none());
+    let moved = annotations[1].anchor.clone().unwrap();
+    assert_eq!((moved.quote.as_str(), moved.status, moved.start), ("LazyCell", AnchorStatus::Moved, Some(24)));
+    let orphaned = annotations[2].anchor.clone().unwrap();
+    assert_eq!((orphaned.quote.as_str(), orphaned.status, orphaned.start), ("LazyLock", AnchorStatus::Orphaned, None));
+}
+
+#[tokio::test]
+async fn test_list_only_own_annotations() {
+    let setup = create_test_setup();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new().content("Rust 1.80 ships LazyCell.")).await;
+    setup.create.execute(Uuid::new_v4(), article.id, highlight("Rust", 0, None)).await.unwrap();
+
+    let annotations = setup.list.execute(Uuid::new_v4(), article.id).await.unwrap();
+    let unknown_article = setup.list.execute(Uuid::new_v4(), Uuid::new_v4()).await;
+
+    assert!(annotations.is_empty());
+    assert!(matches!(unknown_article, Err(ListAnnotationsError::ArticleNotFound)));
+}
+
+#[tokio::test]
+async fn test_update_note_and_clear_it() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new().content("Rust 1.80 ships LazyCell.")).await;
+    let annotation = setup.create.execute(user_id, article.id, highlight("LazyCell", 16, Some("Old"))).await.unwrap();
+
+    let updated = setup
+        .update
+        .execute(user_id, article.id, annotation.id, UpdateAnnotationDto { anchor: None, note: Some("New".to_string()) })
+        .await
+        .unwrap();
+    assert_eq!(updated.note.as_deref(), Some("New"));
+
+    let cleared = setup
+        .update
+        .execute(user_id, article.id, annotation.id, UpdateAnnotationDto { anchor: None, note: Some(String::new()) })
+        .await
+        .unwrap();
+    assert_eq!(cleared.note, None);
+    assert!(cleared.anchor.is_some());
+}
+
+#[tokio::test]
+async fn test_cannot_touch_other_users_annotation_or_wrong_article() {
+    let setup = create_test_setup();
+    let owner = Uuid::new_v4();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new().content("Rust 1.80 ships LazyCell.")).await;
+    let other_article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Other", TestArticleOptions::new().content("Other content")).await;
+    let annotation = setup.create.execute(owner, article.id, highlight("Rust", 0, None)).await.unwrap();
+
+    let intruder_update = setup
+        .update
+        .execute(Uuid::new_v4(), article.id, annotation.id, UpdateAnnotationDto::default())
+        .await;
+    let intruder_delete = setup.delete.execute(Uuid::new_v4(), article.id, annotation.id).await;
+    let wrong_article = setup.delete.execute(owner, other_article.id, annotation.id).await;
+
+    assert!(matches!(intruder_update, Err(UpdateAnnotationError::NotFound)));
+    assert!(matches!(intruder_delete, Err(DeleteAnnotationError::NotFound)));
+    assert!(matches!(wrong_article, Err(DeleteAnnotationError::NotFound)));
+
+    setup.delete.execute(owner, article.id, annotation.id).await.unwrap();
+    assert!(setup.list.execute(owner, article.id).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_export_groups_by_article_and_renders_markdown() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let first = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new().content("Rust 1.80 ships LazyCell.")).await;
+    let second = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Go Release", TestArticleOptions::new().content("Go 1.23 adds iterators.")).await;
+    setup.create.execute(user_id, first.id, highlight("LazyCell", 16, Some("Try it"))).await.unwrap();
+    setup.create.execute(user_id, second.id, highlight("iterators", 12, None)).await.unwrap();
+    setup.create.execute(user_id, first.id, highlight("Rust", 0, None)).await.unwrap();
+    setup.create.execute(Uuid::new_v4(), first.id, highlight("ships", 10, None)).await.unwrap();
+
+    let exports = setup.export.execute(user_id).await.unwrap();
+
+    assert_eq!(exports.len(), 2);
+    assert_eq!(exports[0].title, "Rust Release");
+    let quotes: Vec<&str> = exports[0]
+        .annotations
+        .iter()
+        .map(|a| a.anchor.as_ref().unwrap().quote.as_str())
+        .collect();
+    assert_eq!(quotes, vec!["Rust", "LazyCell"]);
+
+    let markdown = ArticleAnnotations::to_markdown(&exports);
+    assert!(markdown.starts_with("# Annotations\n"));
+    assert!(markdown.contains("## [Rust Release](https://example.com/rust-release)"));
+    assert!(markdown.contains("> LazyCell\n\nTry it\n"));
+    assert!(markdown.contains("> iterators\n"));
+    assert!(!markdown.contains("ships"));
+}
+
+#[tokio::test]
+async fn test_annotation_changes_require_library_write_scope() {
+    let setup = create_test_setup();
+    let (auth, key) = create_test_api_key_auth(vec![ApiKeyScope::ArticlesRead]).await;
+    let controller = AnnotationController::new(
+        Arc::new(CreateAnnotationUseCase::new(setup.articles.clone(), setup.annotations.clone())),
+        Arc::new(ListAnnotationsUseCase::new(setup.articles.clone(), setup.annotations.clone())),
+        Arc::new(UpdateAnnotationUseCase::new(setup.articles.clone(), setup.annotations.clone())),
+        Arc::new(DeleteAnnotationUseCase::new(setup.annotations.clone())),
+        Arc::new(ExportAnnotationsUseCase::new(setup.articles.clone(), setup.annotations.clone())),
+        auth,
+    );
+
+    let responses = [
+        controller.create(api_key_request(&key), Bytes::new()).await,
+        controller.update(api_key_request(&key), Bytes::new()).await,
+        controller.delete(api_key_request(&key), Bytes::new()).await,
+    ];
+    for response in responses {
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,289 @@
+use crate::common::{create_test_auth_service, create_test_session_service, create_test_user_repository};
+use actix_web::http::StatusCode;
+use actix_web::test::TestRequest;
+use chrono::{Duration, Utc};
+use nexight_backend::application::services::api_key_service::ApiKeyService;
+use nexight_backend::application::services::session_service::SessionService;
+use nexight_backend::application::use_cases::create_api_key::{CreateApiKeyError, CreateApiKeyUseCase};
+use nexight_backend::application::use_cases::list_api_keys::ListApiKeysUseCase;
+use nexight_backend::application::use_cases::revoke_api_key::{RevokeApiKeyError, RevokeApiKeyUseCase};
+use nexight_backend::domain::entities::api_key::{ApiKey, ApiKeyScope, CreateApiKeyDto};
+use nexight_backend::domain::entities::session::SessionClient;
+use nexight_backend::domain::entities::user::User;
+use nexight_backend::domain::repositories::api_key_repository::ApiKeyRepository;
+use nexight_backend::domain::repositories::user_repository::UserRepository;
+use nexight_backend::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use nexight_backend::infrastructure::repositories::in_memory_api_key_repository::InMemoryApiKeyRepository;
+use nexight_backend::infrastructure::repositories::in_memory_user_repository::InMemoryUserRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct TestSetup {
+    repository: Arc<InMemoryUserRepository>,
+    api_keys: Arc<InMemoryApiKeyRepository>,
+    create: CreateApiKeyUseCase,
+    list: ListApiKeysUseCase,
+    revoke: RevokeApiKeyUseCase,
+    middleware: AuthMiddleware,
+    session_service: Arc<SessionService>,
+}
+
+fn create_test_setup() -> TestSetup {
+    let repository = create_test_user_repository();
+    let auth_service = create_test_auth_service();
+    let api_keys = Arc::new(InMemoryApiKeyRepository::new());
+    let api_key_service = Arc::new(ApiKeyService::new(api_keys.clone()));
+    let session_service = create_test_session_service(auth_service.clone());
+
+    TestSetup {
+        create: CreateApiKeyUseCase::new(api_keys.clone()),
+        list: ListApiKeysUseCase::new(api_keys.clone()),
+        revoke: RevokeApiKeyUseCase::new(api_keys.clone()),
+        middleware: AuthMiddleware::new(
+            auth_service.clone(),
+            repository.clone(),
+            api_key_service,
+            session_service.clone(),
+        ),
+        session_service,
+        repository,
+        api_keys,
+    }
+}
+
+async fn create_test_user(setup: &TestSetup) -> User {
+    let user = User::new("bot@example.com".to_string(), "hash".to_string(), "Bot".to_string());
+    setup.repository.create(user).await.unwrap()
+}
+
+fn create_dto(scopes: Vec<ApiKeyScope>) -> CreateApiKeyDto {
+    CreateApiKeyDto {
+        name: "Ingestion script".to_string(),
+        scopes,
+        expires_in_days: None,
+    }
+}
+
+#[tokio::test]
+async fn test_create_api_key_returns_key_once() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+
+    let created = setup
+        .create
+        .execute(user.id, create_dto(vec![ApiKeyScope::ArticlesWrite, ApiKeyScope::ArticlesWrite]))
+        .await
+        .unwrap();
+
+    assert!(created.key.starts_with(&format!("nxk_{}_", created.api_key.prefix)));
+    assert_eq!(created.api_key.scopes, vec![ApiKeyScope::ArticlesWrite]);
+
+    let stored = setup.api_keys.find_by_prefix(&created.api_key.prefix).await.unwrap().unwrap();
+    assert!(!created.key.contains(&stored.secret_hash));
+
+    let listed = setup.list.execute(user.id).await.unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, created.api_key.id);
+}
+
+#[tokio::test]
+async fn test_create_api_key_requires_scope() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+
+    let result = setup.create.execute(user.id, create_dto(vec![])).await;
+    assert!(matches!(result, Err(CreateApiKeyError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_api_key_scopes_deserialize_from_strings() {
+    let dto: CreateApiKeyDto = serde_json::from_value(serde_json::json!({
+        "name": "feeds",
+        "scopes": ["feeds:read", "articles:write"],
+        "expires_in_days": 30
+    }))
+    .unwrap();
+
+    assert_eq!(dto.scopes, vec![ApiKeyScope::FeedsRead, ApiKeyScope::ArticlesWrite]);
+    assert!(serde_json::from_value::<CreateApiKeyDto>(serde_json::json!({
+        "name": "bad",
+        "scopes": ["admin"]
+    }))
+    .is_err());
+}
+
+#[tokio::test]
+async fn test_revoke_only_own_keys() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+    let created = setup
+        .create
+        .execute(user.id, create_dto(vec![ApiKeyScope::ArticlesRead]))
+        .await
+        .unwrap();
+
+    let other = setup.revoke.execute(Uuid::new_v4(), created.api_key.id).await;
+    assert!(matches!(other, Err(RevokeApiKeyError::NotFound)));
+
+    setup.revoke.execute(user.id, created.api_key.id).await.unwrap();
+    assert!(setup.list.execute(user.id).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_middleware_accepts_x_api_key_with_scope() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+    let created = setup
+        .create
+        .execute(user.id, create_dto(vec![ApiKeyScope::ArticlesWrite]))
+        .await
+        .unwrap();
+
+    let req = TestRequest::default()
+        .insert_header(("X-API-Key", created.key.clone()))
+        .to_http_request();
+    let authenticated = setup
+        .middleware
+        .require_scope(&req, ApiKeyScope::ArticlesWrite)
+        .await
+        .unwrap();
+    assert_eq!(authenticated.id, user.id);
+
+    // Usage is recorded
+    let stored = setup.api_keys.find_by_prefix(&created.api_key.prefix).await.unwrap().unwrap();
+    assert!(stored.last_used_at.is_some());
+}
+
+#[tokio::test]
+async fn test_middleware_accepts_authorization_api_key_scheme() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+    let created = setup
+        .create
+        .execute(user.id, create_dto(vec![ApiKeyScope::FeedsRead]))
+        .await
+        .unwrap();
+
+    let req = TestRequest::default()
+        .insert_header(("Authorization", format!("ApiKey {}", created.key)))
+        .to_http_request();
+
+    assert!(setup.middleware.require_scope(&req, ApiKeyScope::FeedsRead).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_middleware_rejects_missing_scope() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+    let created = setup
+        .create
+        .execute(user.id, create_dto(vec![ApiKeyScope::ArticlesRead]))
+        .await
+        .unwrap();
+
+    let req = TestRequest::default()
+        .insert_header(("X-API-Key", created.key))
+        .to_http_request();
+
+    let response = setup
+        .middleware
+        .require_scope(&req, ApiKeyScope::ArticlesWrite)
+        .await
+        .unwrap_err();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_middleware_rejects_invalid_and_expired_keys() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+    let created = setup
+        .create
+        .execute(user.id, create_dto(vec![ApiKeyScope::ArticlesRead]))
+        .await
+        .unwrap();
+
+    let tampered = format!("{}0", created.key);
+    let req = TestRequest::default()
+        .insert_header(("X-API-Key", tampered))
+        .to_http_request();
+    let response = setup
+        .middleware
+        .require_scope(&req, ApiKeyScope::ArticlesRead)
+        .await
+        .unwrap_err();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let generated = ApiKeyService::generate();
+    let mut expired = ApiKey::new(
+        user.id,
+        "expired".to_string(),
+        generated.prefix,
+        generated.secret_hash,
+        vec![ApiKeyScope::ArticlesRead],
+        Some(Utc::now() - Duration::days(1)),
+    );
+    expired.created_at = Utc::now() - Duration::days(2);
+    setup.api_keys.create(expired).await.unwrap();
+
+    let req = TestRequest::default()
+        .insert_header(("X-API-Key", generated.key))
+        .to_http_request();
+    let response = setup
+        .middleware
+        .optional_scope(&req, ApiKeyScope::ArticlesRead)
+        .await
+        .unwrap_err();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_api_keys_are_not_accepted_for_session_routes() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+    let created = setup
+        .create
+        .execute(user.id, create_dto(vec![ApiKeyScope::ArticlesWrite]))
+        .await
+        .unwrap();
+
+    let req = TestRequest::default()
+        .insert_header(("X-API-Key", created.key))
+        .to_http_request();
+
+    let response = setup.middleware.require_auth(&req).await.unwrap_err();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_optional_scope_without_key_is_anonymous() {
+    let setup = create_test_setup();
+    let req = TestRequest::default().to_http_request();
+
+    let result = setup.middleware.optional_scope(&req, ApiKeyScope::ArticlesRead).await;
+    assert!(matches!(result, Ok(None)));
+}
+
+#[tokio::test]
+async fn test_optional_scope_identifies_jwt_user() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+    let token = setup
+        .session_service
+        .start(user.id, &user.email, SessionClient::default())
+        .await
+        .unwrap();
+
+    let req = TestRequest::default()
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_http_request();
+    let result = setup.middleware.optional_scope(&req, ApiKeyScope::ArticlesRead).await.unwrap();
+    assert_eq!(result.map(|u| u.id), Some(user.id));
+
+    // Um token inválido é rejeitado em vez de tratado como anônimo
+    let req = TestRequest::default()
+        .insert_header(("Authorization", "Bearer not-a-token"))
+        .to_http_request();
+    let response = setup.middleware.optional_scope(&req, ApiKeyScope::ArticlesRead).await.unwrap_err();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,160 @@
+use crate::common::{
+    create_test_article, create_test_auth_service, create_test_session_service, create_test_user_repository,
+    TestArticleOptions,
+};
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpRequest};
+use chrono::{Duration, Utc};
+use nexight_backend::application::services::api_key_service::ApiKeyService;
+use nexight_backend::application::use_cases::export_articles::ExportArticlesUseCase;
+use nexight_backend::domain::entities::article::ArticleListFilter;
+use nexight_backend::domain::entities::article_category::ArticleCategory;
+use nexight_backend::domain::entities::article_export::{ExportColumn, ExportFormat, EXPORT_CHUNK_SIZE};
+use nexight_backend::domain::repositories::article_category_repository::ArticleCategoryRepository;
+use nexight_backend::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use nexight_backend::infrastructure::repositories::in_memory_api_key_repository::InMemoryApiKeyRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_category_repository::InMemoryArticleCategoryRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::interfaces::controllers::article_export_controller::ArticleExportController;
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct TestSetup {
+    articles: Arc<InMemoryArticleRepository>,
+    categories: Arc<InMemoryArticleCategoryRepository>,
+    export: Arc<ExportArticlesUseCase>,
+}
+
+fn create_test_setup() -> TestSetup {
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let categories = Arc::new(InMemoryArticleCategoryRepository::new());
+
+    TestSetup {
+        export: Arc::new(ExportArticlesUseCase::new(articles.clone(), categories.clone())),
+        articles,
+        categories,
+    }
+}
+
+async fn create_articles(setup: &TestSetup, count: i64, category_id: Option<Uuid>) {
+    let now = Utc::now();
+    for index in 0..count {
+        // Alguns artigos compartilham a data para exercitar o desempate pelo id
+        let options = TestArticleOptions::new()
+            .category(category_id)
+            .pub_date(now - Duration::minutes(index / 3));
+        create_test_article(setup.articles.as_ref(), Uuid::new_v4(), &format!("Article {}", Uuid::new_v4()), options).await;
+    }
+}
+
+async fn drain(setup: &TestSetup, filter: ArticleListFilter, format: ExportFormat, columns: Vec<ExportColumn>) -> (Vec<String>, usize) {
+    let mut export = setup.export.execute(filter, format, columns).await.unwrap();
+    let mut body = String::new();
+    let mut chunks = 0;
+    while let Some(chunk) = export.next_chunk().await.unwrap() {
+        body.push_str(&chunk);
+        chunks += 1;
+    }
+    (body.lines().map(str::to_string).collect(), chunks)
+}
+
+#[tokio::test]
+async fn test_export_reads_every_article_once_across_chunks() {
+    let setup = create_test_setup();
+    let total = EXPORT_CHUNK_SIZE * 2 + 7;
+    create_articles(&setup, total, None).await;
+
+    let (lines, chunks) = drain(&setup, ArticleListFilter::default(), ExportFormat::Ndjson, vec![ExportColumn::Id]).await;
+
+    assert_eq!(chunks, 3);
+    assert_eq!(lines.len() as i64, total);
+    let ids: HashSet<&String> = lines.iter().collect();
+    assert_eq!(ids.len() as i64, total);
+}
+
+#[tokio::test]
+async fn test_export_applies_the_listing_filter_and_resolves_category_names() {
+    let setup = create_test_setup();
+    let tech = setup.categories.create(ArticleCategory::new("Tech".to_string())).await.unwrap();
+    create_articles(&setup, 3, Some(tech.id)).await;
+    create_articles(&setup, 4, None).await;
+
+    let filter = ArticleListFilter {
+        category_id: Some(tech.id),
+        ..Default::default()
+    };
+    let (lines, _) = drain(&setup, filter, ExportFormat::Csv, vec![ExportColumn::Title, ExportColumn::CategoryName]).await;
+
+    assert_eq!(lines[0], "title,category_name");
+    assert_eq!(lines.len(), 4);
+    assert!(lines[1..].iter().all(|line| line.ends_with(",Tech")));
+
+    let empty = ArticleListFilter {
+        search: Some("nothing matches".to_string()),
+        ..Default::default()
+    };
+    let (lines, chunks) = drain(&setup, empty, ExportFormat::Csv, vec![ExportColumn::Title]).await;
+    assert_eq!(lines, vec!["title"]);
+    assert_eq!(chunks, 1);
+}
+
+#[actix_web::test]
+async fn test_export_route_streams_the_selected_format() {
+    let setup = create_test_setup();
+    create_articles(&setup, 5, None).await;
+
+    let auth_service = create_test_auth_service();
+    let auth_middleware = Arc::new(AuthMiddleware::new(
+        auth_service.clone(),
+        create_test_user_repository(),
+        Arc::new(ApiKeyService::new(Arc::new(InMemoryApiKeyRepository::new()))),
+        create_test_session_service(auth_service),
+    ));
+    let controller = Arc::new(ArticleExportController::new(setup.export.clone(), auth_middleware));
+    let app = test::init_service(App::new().route("/articles/export", web::get().to({
+        let ctrl = controller.clone();
+        move |req: HttpRequest, body: web::Bytes| {
+            let controller = ctrl.clone();
+            async move { controller.export(req, body).await }
+        }
+    })))
+    .await;
+
+    let request = test::TestRequest::get().uri("/articles/export?format=ndjson&columns=id,title&min_rate=1").to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(test::read_body(response).await.is_empty());
+
+    let request = test::TestRequest::get().uri("/articles/export?format=ndjson&columns=id,title").to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get("Content-Type").unwrap(), "application/x-ndjson");
+    assert_eq!(response.headers().get("Content-Disposition").unwrap(), "attachment; filename=\"articles.ndjson\"");
+    let body = test::read_body(response).await;
+    let lines: Vec<serde_json::Value> = String::from_utf8_lossy(&body)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 5);
+    assert_eq!(lines[0].as_object().unwrap().len(), 2);
+
+    let request = test::TestRequest::get().uri("/articles/export").to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.headers().get("Content-Type").unwrap(), "text/csv; charset=utf-8");
+    let body = test::read_body(response).await;
+    assert!(String::from_utf8_lossy(&body).starts_with("id,title,description,link,pub_date"));
+
+    for uri in [
+        "/articles/export?format=xlsx",
+        "/articles/export?columns=title,secret",
+        "/articles/export?category_id=not-a-uuid",
+        "/articles/export?min_rate=high",
+    ] {
+        let response = test::call_service(&app, test::TestRequest::get().uri(uri).to_request()).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    let request = test::TestRequest::get().uri("/articles/export?unread=true").to_request();
+    assert_eq!(test::call_service(&app, request).await.status(), StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,252 @@
+use crate::common::{create_test_session_auth, create_test_user_repository};
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpRequest};
+use chrono::{TimeZone, Utc};
+use nexight_backend::application::use_cases::export_articles::ExportArticlesUseCase;
+use nexight_backend::application::use_cases::import_articles::ImportArticlesUseCase;
+use nexight_backend::domain::entities::article::ArticleListFilter;
+use nexight_backend::domain::entities::article_category::ArticleCategory;
+use nexight_backend::domain::entities::article_export::{ExportColumn, ExportFormat};
+use nexight_backend::domain::entities::article_import::{ImportLineStatus, IMPORT_BATCH_SIZE};
+use nexight_backend::domain::entities::user::User;
+use nexight_backend::domain::repositories::article_category_repository::ArticleCategoryRepository;
+use nexight_backend::domain::repositories::article_event_repository::ArticleEventRepository;
+use nexight_backend::domain::repositories::article_repository::ArticleRepository;
+use nexight_backend::domain::repositories::user_repository::UserRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_category_repository::InMemoryArticleCategoryRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_feed_repository::InMemoryFeedRepository;
+use nexight_backend::interfaces::controllers::article_import_controller::ArticleImportController;
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const FEED_URL: &str = "https://blog.example.com/feed.xml";
+
+struct TestSetup {
+    articles: Arc<InMemoryArticleRepository>,
+    categories: Arc<InMemoryArticleCategoryRepository>,
+    feed_id: Uuid,
+    import: Arc<ImportArticlesUseCase>,
+}
+
+async fn create_test_setup() -> TestSetup {
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let categories = Arc::new(InMemoryArticleCategoryRepository::new());
+    let feeds = Arc::new(InMemoryFeedRepository::new());
+    let feed_id = feeds.add(FEED_URL, "https://blog.example.com").await;
+
+    TestSetup {
+        import: Arc::new(ImportArticlesUseCase::new(articles.clone(), categories.clone(), feeds)),
+        articles,
+        categories,
+        feed_id,
+    }
+}
+
+fn record(link: &str) -> serde_json::Value {
+    json!({
+        "title": "Imported",
+        "description": "Description",
+        "link": link,
+        "pub_date": "2026-10-01T12:00:00Z",
+        "content": "Content",
+        "creator": "Author",
+        "feed_url": FEED_URL,
+    })
+}
+
+fn ndjson(records: &[serde_json::Value]) -> String {
+    records.iter().map(|record| record.to_string()).collect::<Vec<_>>().join("\n")
+}
+
+async fn import(setup: &TestSetup, body: &str) -> nexight_backend::domain::entities::article_import::ImportReport {
+    setup.import.execute(ImportArticlesUseCase::records(body).unwrap()).await
+}
+
+#[tokio::test]
+async fn test_import_resolves_feed_and_category_and_keeps_enrichment() {
+    let setup = create_test_setup().await;
+    let tech = setup.categories.create(ArticleCategory::new("Tech".to_string())).await.unwrap();
+
+    let mut by_url = record("https://example.com/a");
+    by_url["category_name"] = json!("Tech");
+    by_url["ai_summary"] = json!("Resumo");
+    by_url["rate"] = json!(9);
+    let mut by_id = record("https://example.com/b");
+    by_id["feed_url"] = json!(null);
+    by_id["feed_id"] = json!(setup.feed_id);
+    by_id["category_id"] = json!(tech.id);
+
+    let report = import(&setup, &ndjson(&[by_url, by_id])).await;
+
+    assert_eq!((report.inserted, report.skipped, report.failed), (2, 0, 0));
+    let article = setup.articles.find_by_id(report.lines[0].article_id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(article.feed_id, setup.feed_id);
+    assert_eq!(article.category_id, Some(tech.id));
+    assert_eq!(article.ai_summary.as_deref(), Some("Resumo"));
+    assert_eq!(article.rate, Some(9));
+    assert_eq!(article.pub_date, Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap());
+}
+
+#[tokio::test]
+async fn test_import_is_silent() {
+    let setup = create_test_setup().await;
+
+    let report = import(&setup, &ndjson(&[record("https://example.com/silent-1"), record("https://example.com/silent-2")])).await;
+
+    assert_eq!(report.inserted, 2);
+    // Nenhum evento no outbox: sem webhooks nem stream para a carga importada
+    let events = setup.articles.events();
+    assert!(events.list_pending(100).await.unwrap().is_empty());
+    assert_eq!(events.latest_seq().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_import_reports_skipped_and_failed_lines_with_reasons() {
+    let setup = create_test_setup().await;
+    import(&setup, &record("https://example.com/existing").to_string()).await;
+
+    let mut unknown_feed = record("https://example.com/c");
+    unknown_feed["feed_url"] = json!("https://unknown.example.com/rss");
+    let mut unknown_category = record("https://example.com/d");
+    unknown_category["category"] = json!("Sports");
+    let mut invalid = record("https://example.com/e");
+    invalid["title"] = json!("");
+    let mut no_feed = record("https://example.com/f");
+    no_feed["feed_url"] = json!(null);
+
+    let body = [
+        record("https://example.com/existing").to_string(),
+        record("https://example.com/new").to_string(),
+        String::new(),
+        record("https://example.com/new").to_string(),
+        "{not json".to_string(),
+        unknown_feed.to_string(),
+        unknown_category.to_string(),
+        invalid.to_string(),
+        no_feed.to_string(),
+    ]
+    .join("\n");
+    let report = import(&setup, &body).await;
+
+    assert_eq!((report.inserted, report.skipped, report.failed), (1, 2, 5));
+    let lines: Vec<(usize, ImportLineStatus)> = report.lines.iter().map(|line| (line.line, line.status)).collect();
+    assert_eq!(
+        lines,
+        vec![
+            (1, ImportLineStatus::Skipped),
+            (2, ImportLineStatus::Inserted),
+            (4, ImportLineStatus::Skipped),
+            (5, ImportLineStatus::Failed),
+            (6, ImportLineStatus::Failed),
+            (7, ImportLineStatus::Failed),
+            (8, ImportLineStatus::Failed),
+            (9, ImportLineStatus::Failed),
+        ]
+    );
+    let reason = |line: usize| report.lines.iter().find(|result| result.line == line).unwrap().reason.clone().unwrap();
+    assert_eq!(reason(1), "Article with this link already exists");
+    assert_eq!(reason(4), "Duplicate link in import");
+    assert!(reason(5).starts_with("Invalid record"));
+    assert_eq!(reason(6), "Feed not found: https://unknown.example.com/rss");
+    assert_eq!(reason(7), "Category not found: Sports");
+    assert!(reason(8).contains("Title"));
+    assert_eq!(reason(9), "feed_id or feed_url is required");
+}
+
+#[tokio::test]
+async fn test_import_accepts_json_arrays_and_large_files_in_batches() {
+    let setup = create_test_setup().await;
+    let records: Vec<serde_json::Value> = (0..IMPORT_BATCH_SIZE + 10)
+        .map(|index| record(&format!("https://example.com/{}", index)))
+        .collect();
+
+    let report = import(&setup, &serde_json::Value::Array(records).to_string()).await;
+
+    assert_eq!(report.inserted, IMPORT_BATCH_SIZE + 10);
+    assert_eq!(report.lines.last().unwrap().line, IMPORT_BATCH_SIZE + 10);
+    assert_eq!(setup.articles.count().await.unwrap() as usize, IMPORT_BATCH_SIZE + 10);
+
+    assert!(ImportArticlesUseCase::records("[{\"title\": ").is_err());
+}
+
+#[tokio::test]
+async fn test_exported_ndjson_can_be_imported_into_another_environment() {
+    let source = create_test_setup().await;
+    let tech = source.categories.create(ArticleCategory::new("Tech".to_string())).await.unwrap();
+    let mut first = record("https://example.com/round-trip");
+    first["category_id"] = json!(tech.id);
+    first["keywords"] = json!("rust, async");
+    import(&source, &first.to_string()).await;
+
+    let export = ExportArticlesUseCase::new(source.articles.clone(), source.categories.clone());
+    let mut export = export
+        .execute(ArticleListFilter::default(), ExportFormat::Ndjson, ExportColumn::ALL.to_vec())
+        .await
+        .unwrap();
+    let mut dump = String::new();
+    while let Some(chunk) = export.next_chunk().await.unwrap() {
+        dump.push_str(&chunk);
+    }
+
+    // No destino, o feed tem outro ID e é encontrado pela URL; a categoria, pelo nome
+    let target = create_test_setup().await;
+    target.categories.create(ArticleCategory::new("Tech".to_string())).await.unwrap();
+    let mut line: serde_json::Value = serde_json::from_str(dump.trim()).unwrap();
+    line.as_object_mut().unwrap().remove("feed_id");
+    line.as_object_mut().unwrap().remove("category_id");
+    line["feed_url"] = json!(FEED_URL);
+
+    let report = import(&target, &line.to_string()).await;
+
+    assert_eq!(report.inserted, 1);
+    let article = target.articles.find_by_id(report.lines[0].article_id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(article.keywords.as_deref(), Some("rust, async"));
+    assert!(article.category_id.is_some());
+}
+
+#[actix_web::test]
+async fn test_import_route_requires_authentication_and_returns_the_report() {
+    let setup = create_test_setup().await;
+    let users = create_test_user_repository();
+    let user = users
+        .create(User::new("admin@example.com".to_string(), "hash".to_string(), "Admin".to_string()))
+        .await
+        .unwrap();
+    let (auth_middleware, token) = create_test_session_auth(users, &user).await;
+    let controller = Arc::new(ArticleImportController::new(setup.import.clone(), auth_middleware));
+    let app = test::init_service(App::new().route("/articles/import", web::post().to({
+        let ctrl = controller.clone();
+        move |req: HttpRequest, body: web::Bytes| {
+            let controller = ctrl.clone();
+            async move { controller.import(req, body).await }
+        }
+    })))
+    .await;
+
+    let body = ndjson(&[record("https://example.com/1"), record("https://example.com/1")]);
+    let anonymous = test::TestRequest::post().uri("/articles/import").set_payload(body.clone()).to_request();
+    assert_eq!(test::call_service(&app, anonymous).await.status(), StatusCode::UNAUTHORIZED);
+
+    let request = test::TestRequest::post()
+        .uri("/articles/import")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .insert_header(("Content-Type", "application/x-ndjson"))
+        .set_payload(body)
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let report: serde_json::Value = test::read_body_json(response).await;
+    assert_eq!(report["inserted"], 1);
+    assert_eq!(report["skipped"], 1);
+    assert_eq!(report["lines"][1]["status"], "skipped");
+    assert_eq!(report["lines"][1]["reason"], "Duplicate link in import");
+
+    let invalid = test::TestRequest::post()
+        .uri("/articles/import")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_payload("[1, 2")
+        .to_request();
+    assert_eq!(test::call_service(&app, invalid).await.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,219 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use nexight_backend::application::services::alert_service::AlertService;
+use nexight_backend::application::services::article_event_notifier::ArticleEventNotifier;
+use nexight_backend::application::services::article_stream_service::ArticleStreamService;
+use nexight_backend::application::use_cases::create_article::CreateArticleUseCase;
+use nexight_backend::application::use_cases::delete_article::DeleteArticleUseCase;
+use nexight_backend::application::use_cases::update_article::UpdateArticleUseCase;
+use nexight_backend::domain::entities::article::{CreateArticleDto, UpdateArticleDto};
+use nexight_backend::domain::entities::article_stream::ArticleStreamFilter;
+use nexight_backend::domain::entities::webhook::WebhookEventType;
+use nexight_backend::infrastructure::repositories::in_memory_alert_rule_repository::InMemoryAlertRuleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_notification_repository::InMemoryNotificationRepository;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::error::TryRecvError;
+use uuid::Uuid;
+
+/// Sem LISTEN/NOTIFY nos testes: o outbox é lido chamando `poll` diretamente
+struct NoopNotifier;
+
+#[async_trait]
+impl ArticleEventNotifier for NoopNotifier {
+    async fn wait(&self, timeout: Duration) {
+        tokio::time::sleep(timeout).await;
+    }
+}
+
+struct TestSetup {
+    stream: Arc<ArticleStreamService>,
+    create_article: CreateArticleUseCase,
+    update_article: UpdateArticleUseCase,
+    delete_article: DeleteArticleUseCase,
+}
+
+async fn create_test_setup(replay_size: usize) -> TestSetup {
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let alert_service = Arc::new(AlertService::new(
+        Arc::new(InMemoryAlertRuleRepository::new()),
+        Arc::new(InMemoryNotificationRepository::new()),
+    ));
+    let stream = Arc::new(ArticleStreamService::new(
+        Arc::new(articles.events()),
+        Arc::new(NoopNotifier),
+        replay_size,
+    ));
+    // Posiciona o cursor no fim do outbox, como na subida do servidor
+    stream.poll().await.unwrap();
+
+    TestSetup {
+        stream,
+        create_article: CreateArticleUseCase::new(articles.clone(), alert_service.clone()),
+        update_article: UpdateArticleUseCase::new(articles.clone(), alert_service),
+        delete_article: DeleteArticleUseCase::new(articles),
+    }
+}
+
+fn article(title: &str, feed_id: Uuid) -> CreateArticleDto {
+    CreateArticleDto {
+        title: title.to_string(),
+        description: format!("Description for {}", title),
+        link: format!("https://example.com/{}", title.to_lowercase().replace(' ', "-")),
+        pub_date: Utc::now(),
+        media: None,
+        content: format!("Content for {}", title),
+        creator: "Test Author".to_string(),
+        feed_id,
+    }
+}
+
+fn enrichment(category_id: Uuid) -> UpdateArticleDto {
+    UpdateArticleDto {
+        title: None,
+        description: None,
+        link: None,
+        pub_date: None,
+        media: None,
+        content: None,
+        creator: None,
+        ai_summary: Some("Summary".to_string()),
+        rate: None,
+        keywords: None,
+        category_id: Some(category_id),
+        ai_columnist: None,
+    }
+}
+
+#[tokio::test]
+async fn test_stream_publishes_created_updated_and_enriched_but_not_deleted() {
+    let setup = create_test_setup(100).await;
+    let mut subscription = setup.stream.subscribe(&ArticleStreamFilter::default(), None);
+
+    let created = setup.create_article.execute(article("Rust 2.0", Uuid::new_v4())).await.unwrap();
+    setup.update_article.execute(created.id, enrichment(Uuid::new_v4())).await.unwrap();
+    let mut retitle = enrichment(Uuid::new_v4());
+    retitle.ai_summary = None;
+    retitle.category_id = None;
+    retitle.title = Some("Rust 2.0 is out".to_string());
+    setup.update_article.execute(created.id, retitle).await.unwrap();
+    setup.delete_article.execute(created.id).await.unwrap();
+
+    assert_eq!(setup.stream.poll().await.unwrap(), 3);
+
+    let received: Vec<_> = (0..3).map(|_| subscription.receiver.try_recv().unwrap()).collect();
+    assert_eq!(
+        received.iter().map(|event| event.event_type).collect::<Vec<_>>(),
+        vec![
+            WebhookEventType::ArticleCreated,
+            WebhookEventType::ArticleEnriched,
+            WebhookEventType::ArticleUpdated,
+        ]
+    );
+    assert!(received.windows(2).all(|pair| pair[0].seq < pair[1].seq));
+    assert_eq!(received[0].article_id, created.id);
+    assert_eq!(received[2].data["title"], "Rust 2.0 is out");
+    assert!(matches!(subscription.receiver.try_recv(), Err(TryRecvError::Empty)));
+}
+
+#[tokio::test]
+async fn test_stream_does_not_replay_history_on_startup_or_republish() {
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let alert_service = Arc::new(AlertService::new(
+        Arc::new(InMemoryAlertRuleRepository::new()),
+        Arc::new(InMemoryNotificationRepository::new()),
+    ));
+    let create_article = CreateArticleUseCase::new(articles.clone(), alert_service);
+    create_article.execute(article("Before startup", Uuid::new_v4())).await.unwrap();
+
+    let stream = ArticleStreamService::new(Arc::new(articles.events()), Arc::new(NoopNotifier), 100);
+    assert_eq!(stream.poll().await.unwrap(), 0);
+
+    create_article.execute(article("After startup", Uuid::new_v4())).await.unwrap();
+    assert_eq!(stream.poll().await.unwrap(), 1);
+    // Eventos já lidos voltam na janela de releitura, mas não são publicados de novo
+    assert_eq!(stream.poll().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_filter_matches_feed_and_category() {
+    let setup = create_test_setup(100).await;
+    let feed_id = Uuid::new_v4();
+    let category_id = Uuid::new_v4();
+
+    let wanted = setup.create_article.execute(article("Wanted", feed_id)).await.unwrap();
+    setup.create_article.execute(article("Other feed", Uuid::new_v4())).await.unwrap();
+    setup.update_article.execute(wanted.id, enrichment(category_id)).await.unwrap();
+    setup.stream.poll().await.unwrap();
+
+    let by_feed = setup.stream.subscribe(&ArticleStreamFilter { feed_id: Some(feed_id), category_id: None }, Some(0));
+    assert_eq!(by_feed.replay.len(), 2);
+    assert!(by_feed.replay.iter().all(|event| event.article_id == wanted.id));
+
+    let by_both = setup.stream.subscribe(
+        &ArticleStreamFilter {
+            feed_id: Some(feed_id),
+            category_id: Some(category_id),
+        },
+        Some(0),
+    );
+    assert_eq!(by_both.replay.len(), 1);
+    assert_eq!(by_both.replay[0].event_type, WebhookEventType::ArticleEnriched);
+
+    let by_other_category = setup.stream.subscribe(
+        &ArticleStreamFilter {
+            feed_id: None,
+            category_id: Some(Uuid::new_v4()),
+        },
+        Some(0),
+    );
+    assert!(by_other_category.replay.is_empty());
+}
+
+#[tokio::test]
+async fn test_last_event_id_replays_only_newer_events() {
+    let setup = create_test_setup(100).await;
+    for title in ["First", "Second", "Third"] {
+        setup.create_article.execute(article(title, Uuid::new_v4())).await.unwrap();
+    }
+    setup.stream.poll().await.unwrap();
+
+    let everything = setup.stream.subscribe(&ArticleStreamFilter::default(), Some(0));
+    assert_eq!(everything.replay.len(), 3);
+    assert!(!everything.resync);
+
+    let last_seen = everything.replay[0].seq;
+    let resumed = setup.stream.subscribe(&ArticleStreamFilter::default(), Some(last_seen));
+    assert!(!resumed.resync);
+    assert_eq!(
+        resumed.replay.iter().map(|event| event.data["title"].as_str().unwrap()).collect::<Vec<_>>(),
+        vec!["Second", "Third"]
+    );
+
+    let up_to_date = setup.stream.subscribe(&ArticleStreamFilter::default(), Some(everything.replay[2].seq));
+    assert!(up_to_date.replay.is_empty());
+    assert!(!up_to_date.resync);
+}
+
+#[tokio::test]
+async fn test_last_event_id_older_than_buffer_asks_for_resync() {
+    let setup = create_test_setup(2).await;
+    for title in ["First", "Second", "Third"] {
+        setup.create_article.execute(article(title, Uuid::new_v4())).await.unwrap();
+    }
+    setup.stream.poll().await.unwrap();
+
+    let all = setup.stream.subscribe(&ArticleStreamFilter::default(), Some(0));
+    assert!(all.resync);
+    assert_eq!(
+        all.replay.iter().map(|event| event.data["title"].as_str().unwrap()).collect::<Vec<_>>(),
+        vec!["Second", "Third"]
+    );
+
+    // O primeiro evento saiu do buffer, mas quem o recebeu não perdeu nada
+    let first_seq = all.replay[0].seq - 1;
+    let resumed = setup.stream.subscribe(&ArticleStreamFilter::default(), Some(first_seq));
+    assert!(!resumed.resync);
+    assert_eq!(resumed.replay.len(), 2);
+}
@@ -0,0 +1,343 @@
+use crate::common::{create_test_session_auth, create_test_user_repository};
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpRequest};
+use chrono::{TimeZone, Utc};
+use nexight_backend::application::services::alert_service::AlertService;
+use nexight_backend::application::use_cases::autocomplete_tags::AutocompleteTagsUseCase;
+use nexight_backend::application::use_cases::create_article::CreateArticleUseCase;
+use nexight_backend::application::use_cases::create_tag::CreateTagUseCase;
+use nexight_backend::application::use_cases::delete_article::DeleteArticleUseCase;
+use nexight_backend::application::use_cases::delete_tag::DeleteTagUseCase;
+use nexight_backend::application::use_cases::get_article::GetArticleUseCase;
+use nexight_backend::application::use_cases::list_articles::ListArticlesUseCase;
+use nexight_backend::application::use_cases::list_tags::ListTagsUseCase;
+use nexight_backend::application::use_cases::merge_tags::MergeTagsUseCase;
+use nexight_backend::application::use_cases::rename_tag::RenameTagUseCase;
+use nexight_backend::application::use_cases::update_article::UpdateArticleUseCase;
+use nexight_backend::domain::entities::article::{ArticleResponse, CreateArticleDto};
+use nexight_backend::domain::entities::tag::{CreateTagDto, TagResponse};
+use nexight_backend::domain::entities::user::User;
+use nexight_backend::domain::repositories::article_repository::ArticleRepository;
+use nexight_backend::domain::repositories::user_repository::UserRepository;
+use nexight_backend::infrastructure::repositories::in_memory_alert_rule_repository::InMemoryAlertRuleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_notification_repository::InMemoryNotificationRepository;
+use nexight_backend::infrastructure::repositories::in_memory_smart_folder_repository::InMemorySmartFolderRepository;
+use nexight_backend::infrastructure::repositories::in_memory_tag_repository::InMemoryTagRepository;
+use nexight_backend::interfaces::controllers::article_controller::ArticleController;
+use nexight_backend::interfaces::controllers::tag_controller::TagController;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct TestSetup {
+    articles: Arc<InMemoryArticleRepository>,
+    controller: Arc<ArticleController>,
+    token: String,
+}
+
+async fn create_test_setup() -> TestSetup {
+    let users = create_test_user_repository();
+    let user = users
+        .create(User::new("editor@example.com".to_string(), "hash".to_string(), "Editor".to_string()))
+        .await
+        .unwrap();
+    let (auth_middleware, token) = create_test_session_auth(users, &user).await;
+
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let alert_service = Arc::new(AlertService::new(
+        Arc::new(InMemoryAlertRuleRepository::new()),
+        Arc::new(InMemoryNotificationRepository::new()),
+    ));
+
+    TestSetup {
+        controller: Arc::new(ArticleController::new(
+            Arc::new(CreateArticleUseCase::new(articles.clone(), alert_service.clone())),
+            Arc::new(UpdateArticleUseCase::new(articles.clone(), alert_service)),
+            Arc::new(DeleteArticleUseCase::new(articles.clone())),
+            Arc::new(GetArticleUseCase::new(articles.clone())),
+            Arc::new(ListArticlesUseCase::new(articles.clone())),
+            auth_middleware,
+        )),
+        articles,
+        token,
+    }
+}
+
+fn dto(link: &str) -> CreateArticleDto {
+    CreateArticleDto {
+        title: "Negotiated".to_string(),
+        description: "Description".to_string(),
+        link: link.to_string(),
+        pub_date: Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap(),
+        media: None,
+        content: "Content".to_string(),
+        creator: "Author".to_string(),
+        feed_id: Uuid::new_v4(),
+    }
+}
+
+macro_rules! init_app {
+    ($controller:expr) => {
+        test::init_service(
+            App::new()
+                .route("/articles", web::get().to({
+                    let ctrl = $controller.clone();
+                    move |req: HttpRequest, body: web::Bytes| {
+                        let controller = ctrl.clone();
+                        async move { controller.list(req, body).await }
+                    }
+                }))
+                .route("/articles", web::post().to({
+                    let ctrl = $controller.clone();
+                    move |req: HttpRequest, body: web::Bytes| {
+                        let controller = ctrl.clone();
+                        async move { controller.create(req, body).await }
+                    }
+                }))
+                .route("/articles/{id}", web::get().to({
+                    let ctrl = $controller.clone();
+                    move |req: HttpRequest, body: web::Bytes| {
+                        let controller = ctrl.clone();
+                        async move { controller.get(req, body).await }
+                    }
+                }))
+                .route("/articles/{id}", web::put().to({
+                    let ctrl = $controller.clone();
+                    move |req: HttpRequest, body: web::Bytes| {
+                        let controller = ctrl.clone();
+                        async move { controller.update(req, body).await }
+                    }
+                })),
+        )
+        .await
+    };
+}
+
+#[actix_web::test]
+async fn test_create_accepts_msgpack_body_and_answers_in_the_accepted_format() {
+    let setup = create_test_setup().await;
+    let app = init_app!(setup.controller);
+
+    let request = test::TestRequest::post()
+        .uri("/articles")
+        .insert_header(("Authorization", format!("Bearer {}", setup.token)))
+        .insert_header(("Content-Type", "application/msgpack"))
+        .insert_header(("Accept", "application/msgpack"))
+        .set_payload(rmp_serde::to_vec_named(&dto("https://example.com/msgpack")).unwrap())
+        .to_request();
+    let response = test::call_service(&app, request).await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(response.headers().get("Content-Type").unwrap(), "application/msgpack");
+    assert_eq!(response.headers().get("Vary").unwrap(), "Accept");
+    let created: ArticleResponse = rmp_serde::from_slice(&test::read_body(response).await).unwrap();
+    assert_eq!(created.link, "https://example.com/msgpack");
+
+    let request = test::TestRequest::get().uri(&format!("/articles/{}", created.id)).to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.headers().get("Content-Type").unwrap(), "application/json");
+
+    let unsupported = test::TestRequest::post()
+        .uri("/articles")
+        .insert_header(("Authorization", format!("Bearer {}", setup.token)))
+        .insert_header(("Content-Type", "application/xml"))
+        .set_payload("<article/>")
+        .to_request();
+    assert_eq!(test::call_service(&app, unsupported).await.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
+#[actix_web::test]
+async fn test_list_returns_csv_with_pagination_headers() {
+    let setup = create_test_setup().await;
+    let app = init_app!(setup.controller);
+    for link in ["https://example.com/1", "https://example.com/2"] {
+        let request = test::TestRequest::post()
+            .uri("/articles")
+            .insert_header(("Authorization", format!("Bearer {}", setup.token)))
+            .set_json(dto(link))
+            .to_request();
+        assert_eq!(test::call_service(&app, request).await.status(), StatusCode::CREATED);
+    }
+
+    let request = test::TestRequest::get()
+        .uri("/articles?per_page=1")
+        .insert_header(("Accept", "text/csv"))
+        .to_request();
+    let response = test::call_service(&app, request).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get("Content-Type").unwrap(), "text/csv; charset=utf-8");
+    assert_eq!(response.headers().get("X-Total-Count").unwrap(), "2");
+    assert_eq!(response.headers().get("X-Total-Pages").unwrap(), "2");
+    let body = String::from_utf8(test::read_body(response).await.to_vec()).unwrap();
+    let lines: Vec<&str> = body.split("\r\n").filter(|line| !line.is_empty()).collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("id,title,description,link,"));
+}
+
+#[actix_web::test]
+async fn test_unacceptable_formats_return_406() {
+    let setup = create_test_setup().await;
+    let app = init_app!(setup.controller);
+
+    let request = test::TestRequest::get()
+        .uri("/articles")
+        .insert_header(("Accept", "application/xml"))
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+    let body: serde_json::Value = test::read_body_json(response).await;
+    assert_eq!(body["code"], "not_acceptable");
+    assert!(body["detail"].as_str().unwrap().contains("text/csv"));
+
+    // CSV só existe para listagens
+    let request = test::TestRequest::post()
+        .uri("/articles")
+        .insert_header(("Authorization", format!("Bearer {}", setup.token)))
+        .set_json(dto("https://example.com/csv"))
+        .to_request();
+    let created: serde_json::Value = test::read_body_json(test::call_service(&app, request).await).await;
+    let request = test::TestRequest::get()
+        .uri(&format!("/articles/{}", created["id"].as_str().unwrap()))
+        .insert_header(("Accept", "text/csv"))
+        .to_request();
+    assert_eq!(test::call_service(&app, request).await.status(), StatusCode::NOT_ACCEPTABLE);
+}
+
+#[actix_web::test]
+async fn test_unacceptable_writes_are_refused_before_changing_the_article() {
+    let setup = create_test_setup().await;
+    let app = init_app!(setup.controller);
+
+    let request = test::TestRequest::post()
+        .uri("/articles")
+        .insert_header(("Authorization", format!("Bearer {}", setup.token)))
+        .insert_header(("Accept", "text/csv"))
+        .set_json(dto("https://example.com/refused"))
+        .to_request();
+    assert_eq!(test::call_service(&app, request).await.status(), StatusCode::NOT_ACCEPTABLE);
+    assert_eq!(setup.articles.count().await.unwrap(), 0);
+
+    let request = test::TestRequest::post()
+        .uri("/articles")
+        .insert_header(("Authorization", format!("Bearer {}", setup.token)))
+        .set_json(dto("https://example.com/kept"))
+        .to_request();
+    let created: serde_json::Value = test::read_body_json(test::call_service(&app, request).await).await;
+    let id = created["id"].as_str().unwrap();
+
+    let request = test::TestRequest::put()
+        .uri(&format!("/articles/{}", id))
+        .insert_header(("Authorization", format!("Bearer {}", setup.token)))
+        .insert_header(("Accept", "application/xml"))
+        .set_json(serde_json::json!({ "title": "Changed" }))
+        .to_request();
+    assert_eq!(test::call_service(&app, request).await.status(), StatusCode::NOT_ACCEPTABLE);
+
+    let article = setup.articles.find_by_id(Uuid::parse_str(id).unwrap()).await.unwrap().unwrap();
+    assert_eq!(article.title, "Negotiated");
+}
+
+/// Rotas fora de `/articles` passam pelos mesmos formatos: as tags servem de amostra
+async fn create_tag_setup() -> (Arc<TagController>, String) {
+    let users = create_test_user_repository();
+    let user = users
+        .create(User::new("reader@example.com".to_string(), "hash".to_string(), "Reader".to_string()))
+        .await
+        .unwrap();
+    let (auth_middleware, token) = create_test_session_auth(users, &user).await;
+
+    let tags = Arc::new(InMemoryTagRepository::new());
+    let controller = Arc::new(TagController::new(
+        Arc::new(CreateTagUseCase::new(tags.clone())),
+        Arc::new(ListTagsUseCase::new(tags.clone())),
+        Arc::new(AutocompleteTagsUseCase::new(tags.clone())),
+        Arc::new(RenameTagUseCase::new(tags.clone())),
+        Arc::new(MergeTagsUseCase::new(tags.clone(), Arc::new(InMemorySmartFolderRepository::new()))),
+        Arc::new(DeleteTagUseCase::new(tags)),
+        auth_middleware,
+    ));
+
+    (controller, token)
+}
+
+macro_rules! init_tag_app {
+    ($controller:expr) => {
+        test::init_service(
+            App::new()
+                .route("/me/tags", web::get().to({
+                    let ctrl = $controller.clone();
+                    move |req: HttpRequest, body: web::Bytes| {
+                        let controller = ctrl.clone();
+                        async move { controller.list(req, body).await }
+                    }
+                }))
+                .route("/me/tags", web::post().to({
+                    let ctrl = $controller.clone();
+                    move |req: HttpRequest, body: web::Bytes| {
+                        let controller = ctrl.clone();
+                        async move { controller.create(req, body).await }
+                    }
+                })),
+        )
+        .await
+    };
+}
+
+#[actix_web::test]
+async fn test_other_controllers_accept_msgpack_and_refuse_unsupported_bodies() {
+    let (controller, token) = create_tag_setup().await;
+    let app = init_tag_app!(controller);
+
+    let request = test::TestRequest::post()
+        .uri("/me/tags")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .insert_header(("Content-Type", "application/msgpack"))
+        .insert_header(("Accept", "application/msgpack"))
+        .set_payload(rmp_serde::to_vec_named(&CreateTagDto { name: "Rust".to_string() }).unwrap())
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(response.headers().get("Content-Type").unwrap(), "application/msgpack");
+    let tag: TagResponse = rmp_serde::from_slice(&test::read_body(response).await).unwrap();
+    assert_eq!(tag.name, "Rust");
+
+    let request = test::TestRequest::post()
+        .uri("/me/tags")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .insert_header(("Content-Type", "application/xml"))
+        .set_payload("<tag><name>Go</name></tag>")
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
+#[actix_web::test]
+async fn test_other_controllers_refuse_csv_before_writing() {
+    let (controller, token) = create_tag_setup().await;
+    let app = init_tag_app!(controller);
+
+    let request = test::TestRequest::post()
+        .uri("/me/tags")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .insert_header(("Accept", "text/csv"))
+        .set_json(serde_json::json!({ "name": "Rust" }))
+        .to_request();
+    assert_eq!(test::call_service(&app, request).await.status(), StatusCode::NOT_ACCEPTABLE);
+
+    let request = test::TestRequest::get()
+        .uri("/me/tags")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .insert_header(("Accept", "text/csv"))
+        .to_request();
+    assert_eq!(test::call_service(&app, request).await.status(), StatusCode::NOT_ACCEPTABLE);
+
+    let request = test::TestRequest::get()
+        .uri("/me/tags")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.headers().get("Vary").unwrap(), "Accept");
+    let tags: serde_json::Value = test::read_body_json(response).await;
+    assert_eq!(tags, serde_json::json!([]));
+}
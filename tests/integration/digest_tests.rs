@@ -0,0 +1,331 @@
+use crate::common::{api_key_request, create_test_api_key_auth, create_test_article, TestArticleOptions};
+use actix_web::http::StatusCode;
+use actix_web::web::Bytes;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use nexight_backend::application::services::digest_service::DigestService;
+use nexight_backend::application::services::mailer::{EmailMessage, Mailer, MailerError};
+use nexight_backend::application::use_cases::delete_digest_settings::{
+    DeleteDigestSettingsError, DeleteDigestSettingsUseCase,
+};
+use nexight_backend::application::use_cases::get_digest_settings::{GetDigestSettingsError, GetDigestSettingsUseCase};
+use nexight_backend::application::use_cases::list_sent_digests::ListSentDigestsUseCase;
+use nexight_backend::application::use_cases::preview_digest::{PreviewDigestError, PreviewDigestUseCase};
+use nexight_backend::application::use_cases::save_digest_settings::{
+    SaveDigestSettingsError, SaveDigestSettingsUseCase,
+};
+use nexight_backend::domain::entities::api_key::ApiKeyScope;
+use nexight_backend::domain::entities::digest::{DigestFrequency, DigestPeriod, DigestSettingsDto};
+use nexight_backend::domain::entities::subscription::{CreateSubscriptionDto, Subscription};
+use nexight_backend::domain::entities::user::User;
+use nexight_backend::domain::repositories::digest_repository::DigestRepository;
+use nexight_backend::domain::repositories::subscription_repository::SubscriptionRepository;
+use nexight_backend::domain::repositories::user_repository::UserRepository;
+use nexight_backend::infrastructure::mail::in_memory_mailer::InMemoryMailer;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_digest_repository::InMemoryDigestRepository;
+use nexight_backend::infrastructure::repositories::in_memory_subscription_repository::InMemorySubscriptionRepository;
+use nexight_backend::infrastructure::repositories::in_memory_user_repository::InMemoryUserRepository;
+use nexight_backend::interfaces::controllers::digest_controller::DigestController;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct TestSetup {
+    users: Arc<InMemoryUserRepository>,
+    articles: Arc<InMemoryArticleRepository>,
+    subscriptions: Arc<InMemorySubscriptionRepository>,
+    digests: Arc<InMemoryDigestRepository>,
+    mailer: Arc<InMemoryMailer>,
+    service: Arc<DigestService>,
+    get: GetDigestSettingsUseCase,
+    save: SaveDigestSettingsUseCase,
+    delete: DeleteDigestSettingsUseCase,
+    history: ListSentDigestsUseCase,
+    preview: PreviewDigestUseCase,
+}
+
+fn create_test_setup() -> TestSetup {
+    let users = Arc::new(InMemoryUserRepository::new());
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let subscriptions = Arc::new(InMemorySubscriptionRepository::new());
+    let digests = Arc::new(InMemoryDigestRepository::new());
+    let mailer = Arc::new(InMemoryMailer::new());
+    let service = Arc::new(DigestService::new(
+        digests.clone(),
+        subscriptions.clone(),
+        articles.clone(),
+        users.clone(),
+        mailer.clone(),
+    ));
+
+    TestSetup {
+        get: GetDigestSettingsUseCase::new(digests.clone()),
+        save: SaveDigestSettingsUseCase::new(digests.clone()),
+        delete: DeleteDigestSettingsUseCase::new(digests.clone()),
+        history: ListSentDigestsUseCase::new(digests.clone()),
+        preview: PreviewDigestUseCase::new(digests.clone(), service.clone()),
+        users,
+        articles,
+        subscriptions,
+        digests,
+        mailer,
+        service,
+    }
+}
+
+/// Mailer que sempre falha, para simular um provedor fora do ar
+struct FailingMailer;
+
+#[async_trait]
+impl Mailer for FailingMailer {
+    async fn send(&self, _message: EmailMessage) -> Result<(), MailerError> {
+        Err(MailerError::SendError("provider unavailable".to_string()))
+    }
+}
+
+async fn create_user(setup: &TestSetup) -> User {
+    let user = User::new(
+        format!("{}@example.com", Uuid::new_v4()),
+        "hash".to_string(),
+        "Digest Reader".to_string(),
+    );
+    setup.users.create(user).await.unwrap()
+}
+
+async fn subscribe(setup: &TestSetup, user_id: Uuid, feed_id: Uuid, weight: f64) {
+    let dto = CreateSubscriptionDto {
+        feed_id,
+        title: None,
+        folder: None,
+        weight: Some(weight),
+    };
+    setup.subscriptions.create(Subscription::new(user_id, dto)).await.unwrap();
+}
+
+fn daily(max_articles: Option<i32>) -> DigestSettingsDto {
+    DigestSettingsDto {
+        frequency: DigestFrequency::Daily,
+        hour: 8,
+        weekday: None,
+        timezone: "UTC".to_string(),
+        max_articles,
+        min_rate: None,
+        enabled: true,
+    }
+}
+
+/// Instante fixo do agendador: longe do horário de envio (8h), para que `now + 5min` siga no mesmo período
+fn fixed_now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap()
+}
+
+/// Data a agenda como criada em `created_at`, já que o use case usa o relógio real
+async fn backdate_schedule(setup: &TestSetup, user_id: Uuid, created_at: DateTime<Utc>) {
+    let mut settings = setup.digests.find_settings(user_id).await.unwrap().unwrap();
+    settings.created_at = created_at;
+    setup.digests.save_settings(settings).await.unwrap();
+}
+
+/// Agenda criada dois dias antes de `fixed_now`, para que o período mais recente termine depois dela
+async fn fixed_period(setup: &TestSetup, user_id: Uuid) -> (DateTime<Utc>, DigestPeriod) {
+    let now = fixed_now();
+    backdate_schedule(setup, user_id, now - Duration::days(2)).await;
+    let settings = setup.digests.find_settings(user_id).await.unwrap().unwrap();
+    (now, settings.latest_period(now).unwrap())
+}
+
+#[tokio::test]
+async fn test_send_due_selects_top_articles_and_sends_once() {
+    let setup = create_test_setup();
+    let user = create_user(&setup).await;
+    let (favorite, regular, muted) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    subscribe(&setup, user.id, favorite, 2.0).await;
+    subscribe(&setup, user.id, regular, 1.0).await;
+    subscribe(&setup, user.id, muted, 0.0).await;
+    setup.save.execute(user.id, daily(Some(2))).await.unwrap();
+
+    let (now, period) = fixed_period(&setup, user.id).await;
+    let inside = period.start + Duration::hours(1);
+    let favorite_six = TestArticleOptions::new()
+        .rate(Some(6))
+        .pub_date(inside)
+        .ai_summary(Some("Summary of Favorite Six"));
+    create_test_article(setup.articles.as_ref(), favorite, "Favorite Six", favorite_six).await;
+    create_test_article(setup.articles.as_ref(), regular, "Regular Nine", TestArticleOptions::new().rate(Some(9)).pub_date(inside)).await;
+    create_test_article(setup.articles.as_ref(), regular, "Regular Four", TestArticleOptions::new().rate(Some(4)).pub_date(inside)).await;
+    create_test_article(setup.articles.as_ref(), muted, "Muted Ten", TestArticleOptions::new().rate(Some(10)).pub_date(inside)).await;
+    create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Unsubscribed Ten", TestArticleOptions::new().rate(Some(10)).pub_date(inside)).await;
+    create_test_article(setup.articles.as_ref(), regular, "Too Old", TestArticleOptions::new().rate(Some(10)).pub_date(period.start - Duration::hours(1))).await;
+    create_test_article(setup.articles.as_ref(), regular, "Next Period", TestArticleOptions::new().rate(Some(10)).pub_date(period.end)).await;
+
+    assert_eq!(setup.service.send_due(now).await.unwrap(), 1);
+    // Outra rodada do agendador no mesmo período não reenvia
+    assert_eq!(setup.service.send_due(now + Duration::minutes(5)).await.unwrap(), 0);
+
+    let sent = setup.mailer.sent_messages().await;
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].to, user.email);
+    let html = sent[0].html_body.as_deref().unwrap();
+    // Peso 2 x nota 6 fica à frente de peso 1 x nota 9
+    let favorite_at = sent[0].text_body.find("1. Favorite Six").unwrap();
+    let regular_at = sent[0].text_body.find("2. Regular Nine").unwrap();
+    assert!(favorite_at < regular_at);
+    assert!(sent[0].text_body.contains("Summary of Favorite Six"));
+    for excluded in ["Regular Four", "Muted Ten", "Unsubscribed Ten", "Too Old", "Next Period"] {
+        assert!(!sent[0].text_body.contains(excluded), "{} should not be in the digest", excluded);
+        assert!(!html.contains(excluded));
+    }
+
+    let history = setup.history.execute(user.id).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].period_end, period.end);
+    assert_eq!(history[0].article_count, 2);
+}
+
+#[tokio::test]
+async fn test_period_without_articles_is_recorded_without_email() {
+    let setup = create_test_setup();
+    let user = create_user(&setup).await;
+    subscribe(&setup, user.id, Uuid::new_v4(), 1.0).await;
+    setup.save.execute(user.id, daily(None)).await.unwrap();
+
+    let (now, _) = fixed_period(&setup, user.id).await;
+    assert_eq!(setup.service.send_due(now).await.unwrap(), 0);
+
+    assert!(setup.mailer.sent_messages().await.is_empty());
+    let history = setup.history.execute(user.id).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].article_count, 0);
+}
+
+#[tokio::test]
+async fn test_periods_before_the_schedule_existed_are_skipped() {
+    let setup = create_test_setup();
+    let user = create_user(&setup).await;
+    let feed_id = Uuid::new_v4();
+    subscribe(&setup, user.id, feed_id, 1.0).await;
+    let now = fixed_now();
+    create_test_article(setup.articles.as_ref(), feed_id, "Yesterday", TestArticleOptions::new().rate(Some(8)).pub_date(now - Duration::hours(12))).await;
+    setup.save.execute(user.id, daily(None)).await.unwrap();
+    backdate_schedule(&setup, user.id, now).await;
+
+    assert_eq!(setup.service.send_due(now).await.unwrap(), 0);
+    assert!(setup.history.execute(user.id).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_failed_delivery_releases_the_period_for_retry() {
+    let setup = create_test_setup();
+    let failing = DigestService::new(
+        setup.digests.clone(),
+        setup.subscriptions.clone(),
+        setup.articles.clone(),
+        setup.users.clone(),
+        Arc::new(FailingMailer),
+    );
+    let user = create_user(&setup).await;
+    let feed_id = Uuid::new_v4();
+    subscribe(&setup, user.id, feed_id, 1.0).await;
+    setup.save.execute(user.id, daily(None)).await.unwrap();
+
+    let (now, period) = fixed_period(&setup, user.id).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Retry Me", TestArticleOptions::new().rate(Some(7)).pub_date(period.start + Duration::hours(2))).await;
+
+    assert_eq!(failing.send_due(now).await.unwrap(), 0);
+    assert!(setup.history.execute(user.id).await.unwrap().is_empty());
+
+    assert_eq!(setup.service.send_due(now).await.unwrap(), 1);
+    assert_eq!(setup.mailer.sent_messages().await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_disabled_or_deleted_schedules_do_not_send() {
+    let setup = create_test_setup();
+    let user = create_user(&setup).await;
+    let feed_id = Uuid::new_v4();
+    subscribe(&setup, user.id, feed_id, 1.0).await;
+    let disabled = DigestSettingsDto {
+        enabled: false,
+        ..daily(None)
+    };
+    let saved = setup.save.execute(user.id, disabled).await.unwrap();
+    assert!(!saved.enabled);
+    assert!(saved.next_send_at.is_none());
+
+    let (now, period) = fixed_period(&setup, user.id).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Quiet", TestArticleOptions::new().rate(Some(7)).pub_date(period.start + Duration::hours(2))).await;
+    assert_eq!(setup.service.send_due(now).await.unwrap(), 0);
+
+    setup.delete.execute(user.id).await.unwrap();
+    assert!(matches!(setup.get.execute(user.id).await, Err(GetDigestSettingsError::NotFound)));
+    assert!(matches!(setup.delete.execute(user.id).await, Err(DeleteDigestSettingsError::NotFound)));
+    assert!(matches!(setup.preview.execute(user.id).await, Err(PreviewDigestError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_save_settings_and_preview() {
+    let setup = create_test_setup();
+    let user = create_user(&setup).await;
+    let feed_id = Uuid::new_v4();
+    subscribe(&setup, user.id, feed_id, 1.0).await;
+
+    let invalid = DigestSettingsDto {
+        timezone: "Not/AZone".to_string(),
+        ..daily(None)
+    };
+    let result = setup.save.execute(user.id, invalid).await;
+    assert!(matches!(result, Err(SaveDigestSettingsError::ValidationError(_))));
+
+    let weekly = DigestSettingsDto {
+        frequency: DigestFrequency::Weekly,
+        weekday: Some(5),
+        timezone: "America/Sao_Paulo".to_string(),
+        ..daily(None)
+    };
+    let saved = setup.save.execute(user.id, weekly).await.unwrap();
+    assert_eq!(saved.weekday, Some(5));
+    let next = saved.next_send_at.unwrap();
+    assert!(next > Utc::now() && next <= Utc::now() + Duration::days(7));
+
+    let created_at = saved.created_at;
+    let updated = setup.save.execute(user.id, daily(Some(5))).await.unwrap();
+    assert_eq!(updated.frequency, DigestFrequency::Daily);
+    assert_eq!(updated.weekday, None);
+    assert_eq!(updated.created_at, created_at);
+    assert_eq!(setup.get.execute(user.id).await.unwrap().max_articles, 5);
+
+    let period = setup.preview.execute(user.id).await.unwrap().period;
+    create_test_article(setup.articles.as_ref(), feed_id, "Preview Me", TestArticleOptions::new().rate(Some(7)).pub_date(period.start + Duration::minutes(30))).await;
+
+    let preview = setup.preview.execute(user.id).await.unwrap();
+    assert_eq!(preview.articles.len(), 1);
+    assert!(preview.text_body.contains("Preview Me"));
+    // A pré-visualização não registra nem envia nada
+    assert!(setup.history.execute(user.id).await.unwrap().is_empty());
+    assert!(setup.mailer.sent_messages().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_digest_schedule_changes_require_library_write_scope() {
+    let setup = create_test_setup();
+    let (auth, key) = create_test_api_key_auth(vec![ApiKeyScope::ArticlesRead]).await;
+    let controller = DigestController::new(
+        Arc::new(GetDigestSettingsUseCase::new(setup.digests.clone())),
+        Arc::new(SaveDigestSettingsUseCase::new(setup.digests.clone())),
+        Arc::new(DeleteDigestSettingsUseCase::new(setup.digests.clone())),
+        Arc::new(ListSentDigestsUseCase::new(setup.digests.clone())),
+        Arc::new(PreviewDigestUseCase::new(setup.digests.clone(), setup.service.clone())),
+        auth,
+    );
+
+    let responses = [
+        controller.save(api_key_request(&key), Bytes::new()).await,
+        controller.delete(api_key_request(&key), Bytes::new()).await,
+    ];
+    for response in responses {
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    let history = controller.history(api_key_request(&key), Bytes::new()).await;
+    assert_eq!(history.status(), StatusCode::OK);
+}
@@ -0,0 +1,158 @@
+use crate::common::{create_test_article, TestArticleOptions};
+use chrono::Utc;
+use nexight_backend::application::services::ranking_service::RankingService;
+use nexight_backend::application::use_cases::list_for_you::{ListForYouError, ListForYouUseCase};
+use nexight_backend::domain::entities::ranking::{PaginatedRankedArticles, RankingWeights};
+use nexight_backend::domain::entities::saved_article::SavedArticle;
+use nexight_backend::domain::entities::subscription::{CreateSubscriptionDto, Subscription};
+use nexight_backend::domain::repositories::article_repository::ArticleRepository;
+use nexight_backend::domain::repositories::saved_article_repository::SavedArticleRepository;
+use nexight_backend::domain::repositories::subscription_repository::SubscriptionRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_saved_article_repository::InMemorySavedArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_subscription_repository::InMemorySubscriptionRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct TestSetup {
+    articles: Arc<InMemoryArticleRepository>,
+    subscriptions: Arc<InMemorySubscriptionRepository>,
+    saved: Arc<InMemorySavedArticleRepository>,
+    for_you: ListForYouUseCase,
+}
+
+fn create_test_setup() -> TestSetup {
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let subscriptions = Arc::new(InMemorySubscriptionRepository::new());
+    let saved = Arc::new(InMemorySavedArticleRepository::new());
+
+    TestSetup {
+        for_you: ListForYouUseCase::new(
+            articles.clone(),
+            subscriptions.clone(),
+            saved.clone(),
+            Arc::new(RankingService::new(RankingWeights::default())),
+        ),
+        articles,
+        subscriptions,
+        saved,
+    }
+}
+
+async fn subscribe(setup: &TestSetup, user_id: Uuid, feed_id: Uuid, weight: f64) {
+    let dto = CreateSubscriptionDto {
+        feed_id,
+        title: None,
+        folder: None,
+        weight: Some(weight),
+    };
+    setup.subscriptions.create(Subscription::new(user_id, dto)).await.unwrap();
+}
+
+fn titles(page: &PaginatedRankedArticles) -> Vec<&str> {
+    page.articles.iter().map(|ranked| ranked.article.title.as_str()).collect()
+}
+
+#[tokio::test]
+async fn test_for_you_ranks_unread_recent_subscribed_articles() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    subscribe(&setup, user_id, feed_id, 1.0).await;
+
+    create_test_article(setup.articles.as_ref(), feed_id, "Good", TestArticleOptions::new().rate(Some(9)).hours_ago(2)).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Newest But Weak", TestArticleOptions::new().rate(Some(1)).hours_ago(0)).await;
+    let read = create_test_article(setup.articles.as_ref(), feed_id, "Already Read", TestArticleOptions::new().rate(Some(10)).hours_ago(1)).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Too Old", TestArticleOptions::new().rate(Some(10)).hours_ago(24 * 30)).await;
+    create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Not Subscribed", TestArticleOptions::new().rate(Some(10)).hours_ago(1)).await;
+    setup.articles.mark_read(user_id, read.id, Utc::now()).await.unwrap();
+
+    let page = setup.for_you.execute(user_id, false, 1, 20).await.unwrap();
+
+    assert_eq!(titles(&page), vec!["Good", "Newest But Weak"]);
+    assert_eq!(page.total, 2);
+    assert!(page.articles[0].score > page.articles[1].score);
+    assert!(page.articles.iter().all(|ranked| ranked.score_breakdown.is_none()));
+}
+
+#[tokio::test]
+async fn test_for_you_learns_category_affinity_from_reads_saves_and_ratings() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    let rust = Uuid::new_v4();
+    let sports = Uuid::new_v4();
+    subscribe(&setup, user_id, feed_id, 1.0).await;
+
+    // Histórico: lê e salva Rust, avalia mal esportes
+    let history = create_test_article(setup.articles.as_ref(), feed_id, "Rust History", TestArticleOptions::new().category(Some(rust)).rate(Some(5)).hours_ago(5)).await;
+    setup.articles.mark_read(user_id, history.id, Utc::now()).await.unwrap();
+    setup.saved.create(SavedArticle::from_article(user_id, &history, None, 0)).await.unwrap();
+    let match_report = create_test_article(setup.articles.as_ref(), feed_id, "Match Report", TestArticleOptions::new().category(Some(sports)).rate(Some(5)).hours_ago(5)).await;
+    setup.articles.mark_read(user_id, match_report.id, Utc::now()).await.unwrap();
+    setup.articles.rate(user_id, match_report.id, 0, Utc::now()).await.unwrap();
+
+    create_test_article(setup.articles.as_ref(), feed_id, "Sports News", TestArticleOptions::new().category(Some(sports)).rate(Some(5)).hours_ago(1)).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Rust News", TestArticleOptions::new().category(Some(rust)).rate(Some(5)).hours_ago(1)).await;
+
+    let page = setup.for_you.execute(user_id, true, 1, 20).await.unwrap();
+
+    assert_eq!(titles(&page), vec!["Rust News", "Sports News"]);
+    let rust_breakdown = page.articles[0].score_breakdown.unwrap();
+    let sports_breakdown = page.articles[1].score_breakdown.unwrap();
+    assert!(rust_breakdown.affinity > 0.0);
+    assert!(sports_breakdown.affinity < 0.0);
+    assert_eq!(rust_breakdown.score, page.articles[0].score);
+}
+
+#[tokio::test]
+async fn test_for_you_applies_feed_weight_and_skips_muted_feeds() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let favorite_feed = Uuid::new_v4();
+    let regular_feed = Uuid::new_v4();
+    let muted_feed = Uuid::new_v4();
+    subscribe(&setup, user_id, favorite_feed, 3.0).await;
+    subscribe(&setup, user_id, regular_feed, 1.0).await;
+    subscribe(&setup, user_id, muted_feed, 0.0).await;
+
+    create_test_article(setup.articles.as_ref(), regular_feed, "Regular", TestArticleOptions::new().rate(Some(8)).hours_ago(1)).await;
+    create_test_article(setup.articles.as_ref(), favorite_feed, "Favorite", TestArticleOptions::new().rate(Some(4)).hours_ago(6)).await;
+    create_test_article(setup.articles.as_ref(), muted_feed, "Muted", TestArticleOptions::new().rate(Some(10)).hours_ago(0)).await;
+
+    let page = setup.for_you.execute(user_id, true, 1, 20).await.unwrap();
+
+    assert_eq!(titles(&page), vec!["Favorite", "Regular"]);
+    assert_eq!(page.articles[0].score_breakdown.unwrap().feed_weight, 3.0);
+}
+
+#[tokio::test]
+async fn test_for_you_paginates_ranked_results() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    subscribe(&setup, user_id, feed_id, 1.0).await;
+    for rate in 1..=5 {
+        create_test_article(setup.articles.as_ref(), feed_id, &format!("Rated {}", rate), TestArticleOptions::new().rate(Some(rate)).hours_ago(1)).await;
+    }
+
+    let second = setup.for_you.execute(user_id, false, 2, 2).await.unwrap();
+
+    assert_eq!(titles(&second), vec!["Rated 3", "Rated 2"]);
+    assert_eq!(second.total, 5);
+    assert_eq!(second.total_pages, 3);
+
+    let invalid = setup.for_you.execute(user_id, false, 0, 20).await;
+    assert!(matches!(invalid, Err(ListForYouError::InvalidPagination)));
+}
+
+#[tokio::test]
+async fn test_for_you_is_empty_without_subscriptions() {
+    let setup = create_test_setup();
+    create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Global", TestArticleOptions::new().rate(Some(9)).hours_ago(1)).await;
+
+    let page = setup.for_you.execute(Uuid::new_v4(), false, 1, 20).await.unwrap();
+
+    assert!(page.articles.is_empty());
+    assert_eq!(page.total, 0);
+}
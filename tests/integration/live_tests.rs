@@ -0,0 +1,360 @@
+use crate::common::{create_test_auth_service, create_test_session_auth, create_test_user_repository};
+use actix_codec::{Decoder, Encoder};
+use actix_http::ws::{Codec, Frame, Message};
+use actix_web::web::BytesMut;
+use actix_web::{web, App, HttpRequest, HttpServer};
+use async_trait::async_trait;
+use chrono::Utc;
+use nexight_backend::application::services::alert_service::AlertService;
+use nexight_backend::application::services::article_event_notifier::ArticleEventNotifier;
+use nexight_backend::application::services::article_stream_service::ArticleStreamService;
+use nexight_backend::application::services::read_state_sync_service::ReadStateSyncService;
+use nexight_backend::application::use_cases::create_article::CreateArticleUseCase;
+use nexight_backend::application::use_cases::issue_stream_ticket::IssueStreamTicketUseCase;
+use nexight_backend::application::use_cases::mark_article_read::MarkArticleReadUseCase;
+use nexight_backend::application::use_cases::mark_article_unread::MarkArticleUnreadUseCase;
+use nexight_backend::application::use_cases::update_article::UpdateArticleUseCase;
+use nexight_backend::domain::entities::article::{CreateArticleDto, UpdateArticleDto};
+use nexight_backend::domain::entities::read_state::{ReadStateChange, ReadStateEvent};
+use nexight_backend::domain::entities::user::User;
+use nexight_backend::domain::repositories::user_repository::UserRepository;
+use nexight_backend::infrastructure::repositories::in_memory_alert_rule_repository::InMemoryAlertRuleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_notification_repository::InMemoryNotificationRepository;
+use nexight_backend::interfaces::controllers::live_controller::LiveController;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use uuid::Uuid;
+
+struct NoopNotifier;
+
+#[async_trait]
+impl ArticleEventNotifier for NoopNotifier {
+    async fn wait(&self, timeout: Duration) {
+        tokio::time::sleep(timeout).await;
+    }
+}
+
+struct TestSetup {
+    port: u16,
+    user: User,
+    token: String,
+    stream: Arc<ArticleStreamService>,
+    read_state_sync: Arc<ReadStateSyncService>,
+    create_article: CreateArticleUseCase,
+    update_article: UpdateArticleUseCase,
+}
+
+async fn create_test_setup() -> TestSetup {
+    let users = create_test_user_repository();
+    let user = users
+        .create(User::new("reader@example.com".to_string(), "hash".to_string(), "Reader".to_string()))
+        .await
+        .unwrap();
+    let (auth_middleware, token) = create_test_session_auth(users, &user).await;
+
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let alert_service = Arc::new(AlertService::new(
+        Arc::new(InMemoryAlertRuleRepository::new()),
+        Arc::new(InMemoryNotificationRepository::new()),
+    ));
+    let stream = Arc::new(ArticleStreamService::new(Arc::new(articles.events()), Arc::new(NoopNotifier), 100));
+    stream.poll().await.unwrap();
+    let read_state_sync = Arc::new(ReadStateSyncService::local());
+
+    let controller = Arc::new(LiveController::new(
+        auth_middleware,
+        Arc::new(IssueStreamTicketUseCase::new(create_test_auth_service())),
+        stream.clone(),
+        read_state_sync.clone(),
+        Arc::new(MarkArticleReadUseCase::new(articles.clone(), read_state_sync.clone())),
+        Arc::new(MarkArticleUnreadUseCase::new(articles.clone(), read_state_sync.clone())),
+    ));
+    let server = HttpServer::new(move || {
+        let ctrl = controller.clone();
+        let ticket_ctrl = controller.clone();
+        App::new()
+            .route("/ws", web::get().to(move |req: HttpRequest, payload: web::Payload| {
+                let controller = ctrl.clone();
+                async move { controller.connect(req, payload).await }
+            }))
+            .route("/live/ticket", web::post().to(move |req: HttpRequest, body: web::Bytes| {
+                let controller = ticket_ctrl.clone();
+                async move { controller.ticket(req, body).await }
+            }))
+    })
+    .workers(1)
+    .bind(("127.0.0.1", 0))
+    .unwrap();
+    let port = server.addrs()[0].port();
+    actix_web::rt::spawn(server.run());
+
+    TestSetup {
+        port,
+        user,
+        token,
+        stream,
+        read_state_sync,
+        create_article: CreateArticleUseCase::new(articles.clone(), alert_service.clone()),
+        update_article: UpdateArticleUseCase::new(articles, alert_service),
+    }
+}
+
+/// Cliente WebSocket mínimo sobre TCP, com o codec do actix em modo cliente
+struct TestClient {
+    socket: TcpStream,
+    codec: Codec,
+    buffer: BytesMut,
+}
+
+impl TestClient {
+    /// Faz o handshake e retorna o status HTTP e, se a conexão subiu, o cliente
+    async fn connect(port: u16, query: &str, authorization: Option<&str>) -> (u16, Option<TestClient>) {
+        let mut socket = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        let mut request = format!(
+            "GET /ws{} HTTP/1.1\r\nHost: localhost\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+             Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n",
+            query
+        );
+        if let Some(token) = authorization {
+            request.push_str(&format!("Authorization: Bearer {}\r\n", token));
+        }
+        request.push_str("\r\n");
+        socket.write_all(request.as_bytes()).await.unwrap();
+
+        let mut buffer = BytesMut::new();
+        let head_end = loop {
+            if let Some(position) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+                break position + 4;
+            }
+            let mut chunk = [0u8; 1024];
+            let read = socket.read(&mut chunk).await.unwrap();
+            assert!(read > 0, "connection closed during handshake");
+            buffer.extend_from_slice(&chunk[..read]);
+        };
+        let head = String::from_utf8_lossy(&buffer[..head_end]).to_string();
+        let status: u16 = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+        if status != 101 {
+            return (status, None);
+        }
+        assert!(head.contains("s3pPLMBiTxaQ9kYGzzhZRbK+xOo="), "unexpected accept key: {}", head);
+
+        let _ = buffer.split_to(head_end);
+        let client = TestClient {
+            socket,
+            codec: Codec::new().client_mode(),
+            buffer,
+        };
+        (status, Some(client))
+    }
+
+    async fn send(&mut self, message: Message) {
+        let mut frame = BytesMut::new();
+        self.codec.encode(message, &mut frame).unwrap();
+        self.socket.write_all(&frame).await.unwrap();
+    }
+
+    async fn send_json(&mut self, value: serde_json::Value) {
+        self.send(Message::Text(value.to_string().into())).await;
+    }
+
+    async fn frame(&mut self) -> Frame {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Some(frame) = self.codec.decode(&mut self.buffer).unwrap() {
+                    return frame;
+                }
+                let mut chunk = [0u8; 4096];
+                let read = self.socket.read(&mut chunk).await.unwrap();
+                assert!(read > 0, "connection closed");
+                self.buffer.extend_from_slice(&chunk[..read]);
+            }
+        })
+        .await
+        .expect("no frame received")
+    }
+
+    async fn json(&mut self) -> serde_json::Value {
+        match self.frame().await {
+            Frame::Text(text) => serde_json::from_slice(&text).unwrap(),
+            other => panic!("expected a text frame, got {:?}", other),
+        }
+    }
+}
+
+fn article(title: &str, feed_id: Uuid) -> CreateArticleDto {
+    CreateArticleDto {
+        title: title.to_string(),
+        description: format!("Description for {}", title),
+        link: format!("https://example.com/{}", title.to_lowercase().replace(' ', "-")),
+        pub_date: Utc::now(),
+        media: None,
+        content: format!("Content for {}", title),
+        creator: "Test Author".to_string(),
+        feed_id,
+    }
+}
+
+#[actix_web::test]
+async fn test_connection_requires_token_and_websocket_handshake() {
+    let setup = create_test_setup().await;
+
+    let (status, _) = TestClient::connect(setup.port, "", None).await;
+    assert_eq!(status, 401);
+
+    let (status, _) = TestClient::connect(setup.port, "?ticket=not-a-jwt", None).await;
+    assert_eq!(status, 401);
+
+    let (status, client) = TestClient::connect(setup.port, "", Some(&setup.token)).await;
+    assert_eq!(status, 101);
+    let mut client = client.unwrap();
+    let welcome = client.json().await;
+    assert_eq!(welcome["type"], "welcome");
+    assert_eq!(welcome["user_id"], setup.user.id.to_string());
+    assert_eq!(welcome["heartbeat_seconds"], 30);
+
+    let mut plain = TcpStream::connect(("127.0.0.1", setup.port)).await.unwrap();
+    plain
+        .write_all(format!("GET /ws HTTP/1.1\r\nHost: localhost\r\nAuthorization: Bearer {}\r\n\r\n", setup.token).as_bytes())
+        .await
+        .unwrap();
+    let mut response = [0u8; 64];
+    let read = plain.read(&mut response).await.unwrap();
+    assert!(String::from_utf8_lossy(&response[..read]).starts_with("HTTP/1.1 400"));
+}
+
+#[actix_web::test]
+async fn test_browsers_connect_with_a_stream_ticket_instead_of_the_access_token() {
+    let setup = create_test_setup().await;
+    let client = reqwest::Client::new();
+    let url = format!("http://127.0.0.1:{}/live/ticket", setup.port);
+
+    let response = client.post(&url).send().await.unwrap();
+    assert_eq!(response.status().as_u16(), 401);
+
+    let response = client.post(&url).bearer_auth(&setup.token).send().await.unwrap();
+    assert_eq!(response.status().as_u16(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["expires_in"], 60);
+    let ticket = body["ticket"].as_str().unwrap().to_string();
+
+    // O access token não serve como ticket, nem o ticket como access token
+    let (status, _) = TestClient::connect(setup.port, &format!("?ticket={}", setup.token), None).await;
+    assert_eq!(status, 401);
+    let (status, _) = TestClient::connect(setup.port, &format!("?token={}", setup.token), None).await;
+    assert_eq!(status, 401);
+    let (status, _) = TestClient::connect(setup.port, "", Some(&ticket)).await;
+    assert_eq!(status, 401);
+
+    let (status, client) = TestClient::connect(setup.port, &format!("?ticket={}", ticket), None).await;
+    assert_eq!(status, 101);
+    let welcome = client.unwrap().json().await;
+    assert_eq!(welcome["type"], "welcome");
+    assert_eq!(welcome["user_id"], setup.user.id.to_string());
+}
+
+#[actix_web::test]
+async fn test_subscribed_topics_receive_article_events() {
+    let setup = create_test_setup().await;
+    let (_, client) = TestClient::connect(setup.port, "", Some(&setup.token)).await;
+    let mut client = client.unwrap();
+    client.json().await;
+
+    let feed_id = Uuid::new_v4();
+    client.send_json(serde_json::json!({ "type": "subscribe", "topic": format!("feed:{}", feed_id) })).await;
+    assert_eq!(client.json().await["type"], "subscribed");
+    client.send_json(serde_json::json!({ "type": "subscribe", "topic": "tag:rust" })).await;
+    let error = client.json().await;
+    assert_eq!(error["type"], "error");
+    assert_eq!(error["message"], "Unknown topic: tag:rust");
+
+    setup.create_article.execute(article("Other feed", Uuid::new_v4())).await.unwrap();
+    let created = setup.create_article.execute(article("Rust 2.0", feed_id)).await.unwrap();
+    setup.stream.poll().await.unwrap();
+
+    let event = client.json().await;
+    assert_eq!(event["type"], "article");
+    assert_eq!(event["event"], "article.created");
+    assert_eq!(event["article"]["id"], created.id.to_string());
+    assert_eq!(event["topics"], serde_json::json!([format!("feed:{}", feed_id)]));
+
+    // Progresso do enriquecimento de um artigo específico
+    client.send_json(serde_json::json!({ "type": "subscribe", "topic": format!("article:{}", created.id) })).await;
+    client.json().await;
+    client.send_json(serde_json::json!({ "type": "unsubscribe", "topic": format!("feed:{}", feed_id) })).await;
+    assert_eq!(client.json().await["type"], "unsubscribed");
+
+    let enrichment = UpdateArticleDto {
+        title: None,
+        description: None,
+        link: None,
+        pub_date: None,
+        media: None,
+        content: None,
+        creator: None,
+        ai_summary: Some("Summary".to_string()),
+        rate: Some(9),
+        keywords: None,
+        category_id: None,
+        ai_columnist: None,
+    };
+    setup.update_article.execute(created.id, enrichment).await.unwrap();
+    setup.stream.poll().await.unwrap();
+
+    let event = client.json().await;
+    assert_eq!(event["event"], "article.enriched");
+    assert_eq!(event["topics"], serde_json::json!([format!("article:{}", created.id)]));
+    assert_eq!(event["article"]["rate"], 9);
+}
+
+#[actix_web::test]
+async fn test_read_state_is_synced_between_devices() {
+    let setup = create_test_setup().await;
+    let (_, phone) = TestClient::connect(setup.port, "", Some(&setup.token)).await;
+    let (_, laptop) = TestClient::connect(setup.port, "", Some(&setup.token)).await;
+    let (mut phone, mut laptop) = (phone.unwrap(), laptop.unwrap());
+    phone.json().await;
+    laptop.json().await;
+
+    let created = setup.create_article.execute(article("Rust 2.0", Uuid::new_v4())).await.unwrap();
+    phone.send_json(serde_json::json!({ "type": "mark_read", "article_id": created.id })).await;
+
+    for device in [&mut phone, &mut laptop] {
+        let change = device.json().await;
+        assert_eq!(change["type"], "read_state");
+        assert_eq!(change["change"], "read");
+        assert_eq!(change["article_id"], created.id.to_string());
+    }
+
+    // Mudanças de outros usuários não chegam
+    setup
+        .read_state_sync
+        .publish(ReadStateEvent::new(Uuid::new_v4(), ReadStateChange::Unread { article_id: created.id }))
+        .await;
+    laptop.send_json(serde_json::json!({ "type": "mark_read", "article_id": Uuid::new_v4() })).await;
+    let error = laptop.json().await;
+    assert_eq!(error["type"], "error");
+    assert_eq!(error["message"], "Article not found");
+}
+
+#[actix_web::test]
+async fn test_heartbeats_and_close() {
+    let setup = create_test_setup().await;
+    let (_, client) = TestClient::connect(setup.port, "", Some(&setup.token)).await;
+    let mut client = client.unwrap();
+    client.json().await;
+
+    client.send_json(serde_json::json!({ "type": "ping" })).await;
+    assert_eq!(client.json().await["type"], "pong");
+
+    client.send(Message::Ping("hello".into())).await;
+    assert_eq!(client.frame().await, Frame::Pong("hello".into()));
+
+    client.send(Message::Binary("raw".into())).await;
+    assert_eq!(client.json().await["message"], "Only text messages are supported");
+
+    client.send(Message::Close(None)).await;
+    assert!(matches!(client.frame().await, Frame::Close(_)));
+}
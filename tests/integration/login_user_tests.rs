@@ -1,16 +1,71 @@
+use crate::common::{authenticated, create_test_session_service};
 use nexight_backend::application::services::auth_service::AuthService;
-use nexight_backend::application::use_cases::login_user::{LoginUserError, LoginUserUseCase};
+use nexight_backend::application::services::jwt_keys::JwtKeySet;
+use nexight_backend::application::services::login_throttle_service::LoginThrottleService;
+use nexight_backend::application::use_cases::login_user::{
+    LoginContext, LoginUserError, LoginUserUseCase,
+};
+use nexight_backend::application::use_cases::unlock_user::{UnlockUserError, UnlockUserUseCase};
+use nexight_backend::domain::entities::login_throttle::LockoutPolicy;
 use nexight_backend::domain::entities::user::{LoginDto, User};
 use nexight_backend::domain::repositories::user_repository::UserRepository;
+use nexight_backend::infrastructure::repositories::in_memory_login_throttle_repository::InMemoryLoginThrottleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_mfa_repository::InMemoryMfaRepository;
 use nexight_backend::infrastructure::repositories::in_memory_user_repository::InMemoryUserRepository;
 use std::sync::Arc;
+use uuid::Uuid;
 
 fn create_test_setup() -> (LoginUserUseCase, Arc<InMemoryUserRepository>, Arc<AuthService>) {
+    let (use_case, repository, auth_service, _) = create_test_setup_with_policy(LockoutPolicy::default());
+    (use_case, repository, auth_service)
+}
+
+fn create_test_setup_with_policy(
+    policy: LockoutPolicy,
+) -> (
+    LoginUserUseCase,
+    Arc<InMemoryUserRepository>,
+    Arc<AuthService>,
+    Arc<LoginThrottleService>,
+) {
     let repository = Arc::new(InMemoryUserRepository::new());
-    let auth_service = Arc::new(AuthService::new("test_secret".to_string(), 24));
-    let use_case = LoginUserUseCase::new(repository.clone(), auth_service.clone());
+    let auth_service = Arc::new(AuthService::with_keys(JwtKeySet::hmac("test_secret"), 24));
+    let throttle_service = Arc::new(LoginThrottleService::new(
+        Arc::new(InMemoryLoginThrottleRepository::new()),
+        policy,
+    ));
+    let use_case = LoginUserUseCase::new(
+        repository.clone(),
+        auth_service.clone(),
+        throttle_service.clone(),
+        Arc::new(InMemoryMfaRepository::new()),
+        chrono::Duration::minutes(5),
+        create_test_session_service(auth_service.clone()),
+    );
+
+    (use_case, repository, auth_service, throttle_service)
+}
 
-    (use_case, repository, auth_service)
+fn strict_policy() -> LockoutPolicy {
+    LockoutPolicy {
+        max_failed_attempts_per_account: 3,
+        max_failed_attempts_per_ip: 5,
+        ..LockoutPolicy::default()
+    }
+}
+
+fn login_dto(email: &str, password: &str) -> LoginDto {
+    LoginDto {
+        email: email.to_string(),
+        password: password.to_string(),
+    }
+}
+
+fn context_from(ip: &str) -> LoginContext {
+    LoginContext {
+        ip_address: Some(ip.to_string()),
+        ..LoginContext::default()
+    }
 }
 
 async fn create_test_user(
@@ -41,10 +96,10 @@ async fn test_login_user_success() {
         password: password.to_string(),
     };
 
-    let result = use_case.execute(dto).await;
+    let result = use_case.execute(dto, LoginContext::default()).await;
     assert!(result.is_ok());
 
-    let response = result.unwrap();
+    let response = authenticated(result.unwrap()).unwrap();
     assert!(!response.token.is_empty());
     assert_eq!(response.user.email, email);
 }
@@ -58,7 +113,7 @@ async fn test_login_user_invalid_email() {
         password: "password123".to_string(),
     };
 
-    let result = use_case.execute(dto).await;
+    let result = use_case.execute(dto, LoginContext::default()).await;
     assert!(result.is_err());
     assert!(matches!(
         result.unwrap_err(),
@@ -75,7 +130,7 @@ async fn test_login_user_empty_password() {
         password: "".to_string(),
     };
 
-    let result = use_case.execute(dto).await;
+    let result = use_case.execute(dto, LoginContext::default()).await;
     assert!(result.is_err());
     assert!(matches!(
         result.unwrap_err(),
@@ -92,7 +147,7 @@ async fn test_login_user_not_found() {
         password: "password123".to_string(),
     };
 
-    let result = use_case.execute(dto).await;
+    let result = use_case.execute(dto, LoginContext::default()).await;
     assert!(result.is_err());
     assert!(matches!(
         result.unwrap_err(),
@@ -115,7 +170,7 @@ async fn test_login_user_wrong_password() {
         password: "wrong_password".to_string(),
     };
 
-    let result = use_case.execute(dto).await;
+    let result = use_case.execute(dto, LoginContext::default()).await;
     assert!(result.is_err());
     assert!(matches!(
         result.unwrap_err(),
@@ -138,10 +193,10 @@ async fn test_login_generates_valid_token() {
         password: password.to_string(),
     };
 
-    let result = use_case.execute(dto).await;
+    let result = use_case.execute(dto, LoginContext::default()).await;
     assert!(result.is_ok());
 
-    let response = result.unwrap();
+    let response = authenticated(result.unwrap()).unwrap();
 
     // Verify token is valid
     let claims = auth_service.verify_token(&response.token);
@@ -165,7 +220,7 @@ async fn test_login_multiple_times_generates_different_tokens() {
         email: email.to_string(),
         password: password.to_string(),
     };
-    let result1 = use_case.execute(dto1).await;
+    let result1 = use_case.execute(dto1, LoginContext::default()).await;
     assert!(result1.is_ok());
 
     // Wait 1 second to ensure different timestamps
@@ -175,11 +230,14 @@ async fn test_login_multiple_times_generates_different_tokens() {
         email: email.to_string(),
         password: password.to_string(),
     };
-    let result2 = use_case.execute(dto2).await;
+    let result2 = use_case.execute(dto2, LoginContext::default()).await;
     assert!(result2.is_ok());
 
     // Tokens should be different due to different timestamps
-    assert_ne!(result1.unwrap().token, result2.unwrap().token);
+    assert_ne!(
+        authenticated(result1.unwrap()).unwrap().token,
+        authenticated(result2.unwrap()).unwrap().token
+    );
 }
 
 #[tokio::test]
@@ -198,10 +256,10 @@ async fn test_login_returns_correct_user_data() {
         password: password.to_string(),
     };
 
-    let result = use_case.execute(dto).await;
+    let result = use_case.execute(dto, LoginContext::default()).await;
     assert!(result.is_ok());
 
-    let response = result.unwrap();
+    let response = authenticated(result.unwrap()).unwrap();
     assert_eq!(response.user.id, user.id);
     assert_eq!(response.user.email, email);
     assert_eq!(response.user.name, name);
@@ -222,7 +280,7 @@ async fn test_login_case_sensitive_email() {
         password: password.to_string(),
     };
 
-    let result = use_case.execute(dto).await;
+    let result = use_case.execute(dto, LoginContext::default()).await;
     // Should fail because emails are case-sensitive in this implementation
     assert!(result.is_err());
 }
@@ -240,7 +298,7 @@ async fn test_login_empty_email() {
         password: "password123".to_string(),
     };
 
-    let result = use_case.execute(dto).await;
+    let result = use_case.execute(dto, LoginContext::default()).await;
     assert!(result.is_err());
     assert!(matches!(
         result.unwrap_err(),
@@ -263,7 +321,7 @@ async fn test_login_email_with_whitespace() {
         password: password.to_string(),
     };
 
-    let result = use_case.execute(dto).await;
+    let result = use_case.execute(dto, LoginContext::default()).await;
     // Should fail because email is not trimmed in current implementation
     assert!(result.is_err());
 }
@@ -283,7 +341,7 @@ async fn test_login_password_with_whitespace() {
         password: " password123 ".to_string(),
     };
 
-    let result = use_case.execute(dto).await;
+    let result = use_case.execute(dto, LoginContext::default()).await;
     // Should fail because password whitespace is significant
     assert!(result.is_err());
     assert!(matches!(
@@ -301,7 +359,7 @@ async fn test_login_with_sql_injection_attempt() {
         password: "password123".to_string(),
     };
 
-    let result = use_case.execute(dto).await;
+    let result = use_case.execute(dto, LoginContext::default()).await;
     // Should either fail validation or not find user (safe behavior)
     assert!(result.is_err());
 }
@@ -318,7 +376,128 @@ async fn test_login_very_long_email() {
         password: "password123".to_string(),
     };
 
-    let result = use_case.execute(dto).await;
+    let result = use_case.execute(dto, LoginContext::default()).await;
     // Should fail validation or not find user
     assert!(result.is_err());
 }
+
+// ============================================================================
+// Account status and lockout
+// ============================================================================
+
+#[tokio::test]
+async fn test_login_inactive_user_is_rejected() {
+    let (use_case, repository, auth_service) = create_test_setup();
+
+    let mut user = create_test_user(&repository, &auth_service, "test@example.com", "password123", "Test User").await;
+    user.is_active = false;
+    repository.update(user).await.unwrap();
+
+    let result = use_case.execute(login_dto("test@example.com", "password123"), LoginContext::default()).await;
+    assert!(matches!(result.unwrap_err(), LoginUserError::AccountInactive));
+}
+
+#[tokio::test]
+async fn test_login_inactive_user_with_wrong_password_does_not_disclose_status() {
+    let (use_case, repository, auth_service) = create_test_setup();
+
+    let mut user = create_test_user(&repository, &auth_service, "test@example.com", "password123", "Test User").await;
+    user.is_active = false;
+    repository.update(user).await.unwrap();
+
+    let result = use_case.execute(login_dto("test@example.com", "wrong_password"), LoginContext::default()).await;
+    assert!(matches!(result.unwrap_err(), LoginUserError::InvalidCredentials));
+}
+
+#[tokio::test]
+async fn test_login_locks_account_after_threshold() {
+    let (use_case, repository, auth_service, _) = create_test_setup_with_policy(strict_policy());
+    create_test_user(&repository, &auth_service, "test@example.com", "password123", "Test User").await;
+
+    for _ in 0..2 {
+        let result = use_case.execute(login_dto("test@example.com", "wrong_password"), LoginContext::default()).await;
+        assert!(matches!(result.unwrap_err(), LoginUserError::InvalidCredentials));
+    }
+
+    // The attempt that reaches the threshold triggers the lockout
+    let result = use_case.execute(login_dto("test@example.com", "wrong_password"), LoginContext::default()).await;
+    assert!(matches!(result.unwrap_err(), LoginUserError::AccountLocked { .. }));
+
+    // Even the correct password is rejected while locked
+    let result = use_case.execute(login_dto("test@example.com", "password123"), LoginContext::default()).await;
+    match result.unwrap_err() {
+        LoginUserError::AccountLocked { retry_after_seconds } => assert!(retry_after_seconds > 0),
+        other => panic!("expected AccountLocked, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_login_success_resets_failed_attempts() {
+    let (use_case, repository, auth_service, _) = create_test_setup_with_policy(strict_policy());
+    create_test_user(&repository, &auth_service, "test@example.com", "password123", "Test User").await;
+
+    for _ in 0..2 {
+        let _ = use_case.execute(login_dto("test@example.com", "wrong_password"), LoginContext::default()).await;
+    }
+    assert!(use_case.execute(login_dto("test@example.com", "password123"), LoginContext::default()).await.is_ok());
+
+    // Counter starts over, so two more failures do not lock the account
+    for _ in 0..2 {
+        let result = use_case.execute(login_dto("test@example.com", "wrong_password"), LoginContext::default()).await;
+        assert!(matches!(result.unwrap_err(), LoginUserError::InvalidCredentials));
+    }
+    assert!(use_case.execute(login_dto("test@example.com", "password123"), LoginContext::default()).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_login_locks_ip_across_accounts() {
+    let (use_case, _, _, _) = create_test_setup_with_policy(strict_policy());
+
+    for i in 0..4 {
+        let email = format!("user{}@example.com", i);
+        let result = use_case
+            .execute(login_dto(&email, "password123"), context_from("10.0.0.1"))
+            .await;
+        assert!(matches!(result.unwrap_err(), LoginUserError::InvalidCredentials));
+    }
+
+    let result = use_case
+        .execute(login_dto("other@example.com", "password123"), context_from("10.0.0.1"))
+        .await;
+    assert!(matches!(result.unwrap_err(), LoginUserError::AccountLocked { .. }));
+
+    // A different IP is not affected
+    let result = use_case
+        .execute(login_dto("other@example.com", "password123"), context_from("10.0.0.2"))
+        .await;
+    assert!(matches!(result.unwrap_err(), LoginUserError::InvalidCredentials));
+}
+
+#[tokio::test]
+async fn test_unlock_user_clears_lockout() {
+    let (use_case, repository, auth_service, throttle_service) =
+        create_test_setup_with_policy(strict_policy());
+    let user = create_test_user(&repository, &auth_service, "test@example.com", "password123", "Test User").await;
+    let unlock_use_case = UnlockUserUseCase::new(repository.clone(), throttle_service);
+
+    for _ in 0..3 {
+        let _ = use_case.execute(login_dto("test@example.com", "wrong_password"), LoginContext::default()).await;
+    }
+    assert!(matches!(
+        use_case.execute(login_dto("test@example.com", "password123"), LoginContext::default()).await.unwrap_err(),
+        LoginUserError::AccountLocked { .. }
+    ));
+
+    unlock_use_case.execute(user.id).await.unwrap();
+
+    assert!(use_case.execute(login_dto("test@example.com", "password123"), LoginContext::default()).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_unlock_unknown_user() {
+    let (_, repository, _, throttle_service) = create_test_setup_with_policy(strict_policy());
+    let unlock_use_case = UnlockUserUseCase::new(repository, throttle_service);
+
+    let result = unlock_use_case.execute(Uuid::new_v4()).await;
+    assert!(matches!(result.unwrap_err(), UnlockUserError::UserNotFound));
+}
@@ -0,0 +1,356 @@
+use crate::common::{authenticated, create_test_auth_service, create_test_session_service, create_test_user_repository, totp_code};
+use actix_web::test::TestRequest;
+use chrono::Utc;
+use nexight_backend::application::services::api_key_service::ApiKeyService;
+use nexight_backend::application::services::auth_service::AuthService;
+use nexight_backend::application::services::login_throttle_service::LoginThrottleService;
+use nexight_backend::application::services::totp_service::TotpService;
+use nexight_backend::application::use_cases::confirm_totp::{ConfirmTotpError, ConfirmTotpUseCase};
+use nexight_backend::application::use_cases::disable_totp::{DisableTotpError, DisableTotpUseCase};
+use nexight_backend::application::use_cases::enroll_totp::{EnrollTotpError, EnrollTotpUseCase};
+use nexight_backend::application::use_cases::login_user::{LoginContext, LoginOutcome, LoginUserUseCase};
+use nexight_backend::application::use_cases::verify_mfa_login::{
+    VerifyMfaLoginError, VerifyMfaLoginUseCase,
+};
+use nexight_backend::domain::entities::login_throttle::LockoutPolicy;
+use nexight_backend::domain::entities::mfa::{ConfirmTotpDto, DisableTotpDto, VerifyMfaDto};
+use nexight_backend::domain::entities::user::{LoginDto, User, UserRole};
+use nexight_backend::domain::repositories::user_repository::UserRepository;
+use nexight_backend::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use nexight_backend::infrastructure::repositories::in_memory_api_key_repository::InMemoryApiKeyRepository;
+use nexight_backend::infrastructure::repositories::in_memory_login_throttle_repository::InMemoryLoginThrottleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_mfa_repository::InMemoryMfaRepository;
+use nexight_backend::infrastructure::repositories::in_memory_user_repository::InMemoryUserRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct TestSetup {
+    repository: Arc<InMemoryUserRepository>,
+    mfa_repository: Arc<InMemoryMfaRepository>,
+    auth_service: Arc<AuthService>,
+    login: LoginUserUseCase,
+    enroll: EnrollTotpUseCase,
+    confirm: ConfirmTotpUseCase,
+    disable: DisableTotpUseCase,
+    verify: VerifyMfaLoginUseCase,
+}
+
+fn create_test_setup() -> TestSetup {
+    let repository = create_test_user_repository();
+    let auth_service = create_test_auth_service();
+    let mfa_repository = Arc::new(InMemoryMfaRepository::new());
+    let totp_service = Arc::new(TotpService::new("Nexight".to_string()));
+    let throttle_service = Arc::new(LoginThrottleService::new(
+        Arc::new(InMemoryLoginThrottleRepository::new()),
+        LockoutPolicy {
+            max_failed_attempts_per_account: 3,
+            ..LockoutPolicy::default()
+        },
+    ));
+    let session_service = create_test_session_service(auth_service.clone());
+
+    TestSetup {
+        login: LoginUserUseCase::new(
+            repository.clone(),
+            auth_service.clone(),
+            throttle_service.clone(),
+            mfa_repository.clone(),
+            chrono::Duration::minutes(5),
+            session_service.clone(),
+        ),
+        enroll: EnrollTotpUseCase::new(repository.clone(), mfa_repository.clone(), totp_service.clone()),
+        confirm: ConfirmTotpUseCase::new(mfa_repository.clone(), totp_service.clone()),
+        disable: DisableTotpUseCase::new(repository.clone(), mfa_repository.clone(), auth_service.clone()),
+        verify: VerifyMfaLoginUseCase::new(
+            repository.clone(),
+            mfa_repository.clone(),
+            auth_service.clone(),
+            totp_service,
+            throttle_service,
+            session_service,
+        ),
+        repository,
+        mfa_repository,
+        auth_service,
+    }
+}
+
+async fn create_test_user(setup: &TestSetup) -> User {
+    let password_hash = setup.auth_service.hash_password("password123").unwrap();
+    let user = User::new("editor@example.com".to_string(), password_hash, "Editor".to_string());
+    setup.repository.create(user).await.unwrap()
+}
+
+/// Cadastra e confirma o TOTP, retornando o segredo e os códigos de recuperação
+async fn enable_totp(setup: &TestSetup, user: &User) -> (String, Vec<String>) {
+    let enrollment = setup.enroll.execute(user.id).await.unwrap();
+    // Código da janela anterior para que a janela atual continue disponível no login
+    let code = totp_code(&enrollment.secret, Utc::now().timestamp() - 30);
+    let codes = setup
+        .confirm
+        .execute(user.id, ConfirmTotpDto { code })
+        .await
+        .unwrap();
+    (enrollment.secret, codes.recovery_codes)
+}
+
+async fn login_challenge(setup: &TestSetup) -> String {
+    let outcome = setup
+        .login
+        .execute(LoginDto {
+            email: "editor@example.com".to_string(),
+            password: "password123".to_string(),
+        }, LoginContext::default())
+        .await
+        .unwrap();
+
+    match outcome {
+        LoginOutcome::MfaRequired(challenge) => {
+            assert!(challenge.mfa_required);
+            assert_eq!(challenge.expires_in, 300);
+            challenge.mfa_token
+        }
+        other => panic!("expected an MFA challenge, got {:?}", other),
+    }
+}
+
+fn current_code(secret: &str) -> String {
+    totp_code(secret, Utc::now().timestamp())
+}
+
+#[tokio::test]
+async fn test_editor_without_mfa_only_gets_an_enrollment_token() {
+    let setup = create_test_setup();
+    let mut user = create_test_user(&setup).await;
+    user.role = UserRole::Editor;
+    let user = setup.repository.update(user).await.unwrap();
+
+    let outcome = setup
+        .login
+        .execute(LoginDto {
+            email: "editor@example.com".to_string(),
+            password: "password123".to_string(),
+        }, LoginContext::default())
+        .await
+        .unwrap();
+    let enrollment_token = match outcome {
+        LoginOutcome::MfaEnrollmentRequired(response) => {
+            assert!(response.mfa_enrollment_required);
+            response.enrollment_token
+        }
+        other => panic!("expected a 2FA enrollment requirement, got {:?}", other),
+    };
+
+    // The enrollment token unlocks the TOTP enrollment routes only
+    let middleware = AuthMiddleware::new(
+        setup.auth_service.clone(),
+        setup.repository.clone(),
+        Arc::new(ApiKeyService::new(Arc::new(InMemoryApiKeyRepository::new()))),
+        create_test_session_service(setup.auth_service.clone()),
+    );
+    let req = TestRequest::default()
+        .insert_header(("Authorization", format!("Bearer {}", enrollment_token)))
+        .to_http_request();
+    assert!(middleware.require_auth(&req).await.is_err());
+    assert_eq!(middleware.require_mfa_enrollment(&req).await.unwrap(), user.id);
+
+    // Once 2FA is enabled the regular challenge takes over
+    enable_totp(&setup, &user).await;
+    login_challenge(&setup).await;
+}
+
+#[tokio::test]
+async fn test_enroll_returns_provisioning_uri() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+
+    let enrollment = setup.enroll.execute(user.id).await.unwrap();
+
+    assert!(enrollment.provisioning_uri.starts_with("otpauth://totp/Nexight:editor%40example.com?"));
+    assert!(enrollment.provisioning_uri.contains(&format!("secret={}", enrollment.secret)));
+}
+
+#[tokio::test]
+async fn test_login_without_mfa_is_authenticated() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+
+    // Pending enrollment does not affect login
+    setup.enroll.execute(user.id).await.unwrap();
+
+    let outcome = setup
+        .login
+        .execute(LoginDto {
+            email: "editor@example.com".to_string(),
+            password: "password123".to_string(),
+        }, LoginContext::default())
+        .await
+        .unwrap();
+    assert!(authenticated(outcome).is_some());
+}
+
+#[tokio::test]
+async fn test_confirm_with_invalid_code_fails() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+    setup.enroll.execute(user.id).await.unwrap();
+
+    let result = setup
+        .confirm
+        .execute(user.id, ConfirmTotpDto { code: "000000x".to_string() })
+        .await;
+
+    assert!(matches!(result, Err(ConfirmTotpError::InvalidCode)));
+}
+
+#[tokio::test]
+async fn test_confirm_without_enrollment_fails() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+
+    let result = setup
+        .confirm
+        .execute(user.id, ConfirmTotpDto { code: "123456".to_string() })
+        .await;
+
+    assert!(matches!(result, Err(ConfirmTotpError::NotEnrolled)));
+}
+
+#[tokio::test]
+async fn test_confirm_stores_hashed_recovery_codes() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+
+    let (_, recovery_codes) = enable_totp(&setup, &user).await;
+
+    assert_eq!(recovery_codes.len(), 10);
+    assert_eq!(setup.mfa_repository.remaining_recovery_codes(user.id).await, 10);
+    assert!(matches!(
+        setup.enroll.execute(user.id).await,
+        Err(EnrollTotpError::AlreadyEnabled)
+    ));
+}
+
+#[tokio::test]
+async fn test_two_step_login_with_totp_code() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+    let (secret, _) = enable_totp(&setup, &user).await;
+
+    let mfa_token = login_challenge(&setup).await;
+
+    // The pending token must not work as an access token
+    assert!(setup.auth_service.verify_token(&mfa_token).is_err());
+
+    let response = setup
+        .verify
+        .execute(VerifyMfaDto {
+            mfa_token: mfa_token.clone(),
+            code: current_code(&secret),
+        }, LoginContext::default())
+        .await
+        .unwrap();
+
+    assert_eq!(response.user.id, user.id);
+    assert!(setup.auth_service.verify_token(&response.token).is_ok());
+
+    // Same code cannot be replayed
+    let replay = setup
+        .verify
+        .execute(VerifyMfaDto {
+            mfa_token,
+            code: current_code(&secret),
+        }, LoginContext::default())
+        .await;
+    assert!(matches!(replay, Err(VerifyMfaLoginError::InvalidCode)));
+}
+
+#[tokio::test]
+async fn test_recovery_code_is_single_use() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+    let (_, recovery_codes) = enable_totp(&setup, &user).await;
+
+    let mfa_token = login_challenge(&setup).await;
+    let code = recovery_codes[0].to_lowercase();
+
+    assert!(setup
+        .verify
+        .execute(VerifyMfaDto { mfa_token: mfa_token.clone(), code: code.clone() }, LoginContext::default())
+        .await
+        .is_ok());
+    assert_eq!(setup.mfa_repository.remaining_recovery_codes(user.id).await, 9);
+
+    let reused = setup.verify.execute(VerifyMfaDto { mfa_token, code }, LoginContext::default()).await;
+    assert!(matches!(reused, Err(VerifyMfaLoginError::InvalidCode)));
+}
+
+#[tokio::test]
+async fn test_verify_rejects_access_token() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+    let (secret, _) = enable_totp(&setup, &user).await;
+
+    let access_token = setup
+        .auth_service
+        .generate_session_token(user.id, &user.email, Uuid::new_v4())
+        .unwrap();
+    let result = setup
+        .verify
+        .execute(VerifyMfaDto {
+            mfa_token: access_token,
+            code: current_code(&secret),
+        }, LoginContext::default())
+        .await;
+
+    assert!(matches!(result, Err(VerifyMfaLoginError::InvalidMfaToken)));
+}
+
+#[tokio::test]
+async fn test_repeated_invalid_codes_lock_account() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+    enable_totp(&setup, &user).await;
+
+    let mfa_token = login_challenge(&setup).await;
+
+    for _ in 0..2 {
+        let result = setup
+            .verify
+            .execute(VerifyMfaDto { mfa_token: mfa_token.clone(), code: "WRONG-CODE1".to_string() }, LoginContext::default())
+            .await;
+        assert!(matches!(result, Err(VerifyMfaLoginError::InvalidCode)));
+    }
+
+    let result = setup
+        .verify
+        .execute(VerifyMfaDto { mfa_token, code: "WRONG-CODE1".to_string() }, LoginContext::default())
+        .await;
+    assert!(matches!(result, Err(VerifyMfaLoginError::AccountLocked { .. })));
+}
+
+#[tokio::test]
+async fn test_disable_requires_password() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+    enable_totp(&setup, &user).await;
+
+    let wrong = setup
+        .disable
+        .execute(user.id, DisableTotpDto { password: "wrong_password".to_string() })
+        .await;
+    assert!(matches!(wrong, Err(DisableTotpError::InvalidPassword)));
+
+    setup
+        .disable
+        .execute(user.id, DisableTotpDto { password: "password123".to_string() })
+        .await
+        .unwrap();
+
+    assert_eq!(setup.mfa_repository.remaining_recovery_codes(user.id).await, 0);
+    let again = setup
+        .disable
+        .execute(user.id, DisableTotpDto { password: "password123".to_string() })
+        .await;
+    assert!(matches!(again, Err(DisableTotpError::NotEnabled)));
+}
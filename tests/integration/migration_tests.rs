@@ -0,0 +1,132 @@
+use diesel::prelude::*;
+use diesel::sql_types::BigInt;
+use diesel_migrations::MigrationHarness;
+use nexight_backend::infrastructure::database::MIGRATIONS;
+use uuid::Uuid;
+
+const ORIGINAL: &str = "00000000-0000-0000-0000-000000000001";
+const DUPLICATE: &str = "00000000-0000-0000-0000-000000000002";
+const LATER_DUPLICATE: &str = "00000000-0000-0000-0000-000000000003";
+const READER: &str = "00000000-0000-0000-0000-0000000000a1";
+
+/// Banco criado só para o teste em `TEST_DATABASE_URL` e removido no fim, mesmo se o teste falhar
+struct ScratchDatabase {
+    admin_url: String,
+    name: String,
+    url: String,
+}
+
+impl ScratchDatabase {
+    fn create() -> Self {
+        let admin_url = std::env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL must be set");
+        let name = format!("nexight_migration_{}", Uuid::new_v4().simple());
+
+        let mut admin = PgConnection::establish(&admin_url).unwrap();
+        diesel::sql_query(format!("CREATE DATABASE {}", name)).execute(&mut admin).unwrap();
+
+        let mut url = url::Url::parse(&admin_url).unwrap();
+        url.set_path(&name);
+
+        Self {
+            admin_url,
+            name,
+            url: url.to_string(),
+        }
+    }
+}
+
+impl Drop for ScratchDatabase {
+    fn drop(&mut self) {
+        if let Ok(mut admin) = PgConnection::establish(&self.admin_url) {
+            let _ = diesel::sql_query(format!("DROP DATABASE IF EXISTS {} WITH (FORCE)", self.name)).execute(&mut admin);
+        }
+    }
+}
+
+fn count(conn: &mut PgConnection, query: &str) -> i64 {
+    diesel::select(diesel::dsl::sql::<BigInt>(&format!("({})", query)))
+        .get_result(conn)
+        .unwrap()
+}
+
+/// Aplica as migrações pendentes até `name`, sem incluí-la
+fn migrate_until(conn: &mut PgConnection, name: &str) {
+    for migration in conn.pending_migrations(MIGRATIONS).unwrap() {
+        if migration.name().to_string().ends_with(name) {
+            return;
+        }
+        conn.run_migration(&*migration).unwrap();
+    }
+    panic!("migration {} not found", name);
+}
+
+fn seed_duplicates(conn: &mut PgConnection) {
+    let feed = "00000000-0000-0000-0000-0000000000f1";
+    let statements = [
+        format!("INSERT INTO users (id, email, password_hash, name) VALUES ('{READER}', 'reader@example.com', 'hash', 'Reader')"),
+        format!("INSERT INTO feeds (id, name, feed_url, link) VALUES ('{feed}', 'Feed', 'https://example.com/rss', 'https://example.com')"),
+        format!(
+            "INSERT INTO articles (id, title, description, link, pub_date, content, creator, feed_id, created_at) VALUES
+             ('{ORIGINAL}', 'Original', 'd', 'https://example.com/a', NOW(), 'c', 'x', '{feed}', NOW() - INTERVAL '2 days'),
+             ('{DUPLICATE}', 'Duplicate', 'd', 'https://example.com/a', NOW(), 'c', 'x', '{feed}', NOW() - INTERVAL '1 day'),
+             ('{LATER_DUPLICATE}', 'Later duplicate', 'd', 'https://example.com/a', NOW(), 'c', 'x', '{feed}', NOW())"
+        ),
+        format!("INSERT INTO article_reads (user_id, article_id) VALUES ('{READER}', '{DUPLICATE}'), ('{READER}', '{LATER_DUPLICATE}')"),
+        format!(
+            "INSERT INTO annotations (id, user_id, article_id, note) VALUES
+             (gen_random_uuid(), '{READER}', '{DUPLICATE}', 'First note'),
+             (gen_random_uuid(), '{READER}', '{LATER_DUPLICATE}', 'Second note')"
+        ),
+        format!(
+            "INSERT INTO article_ratings (user_id, article_id, rating, updated_at) VALUES
+             ('{READER}', '{ORIGINAL}', 3, NOW() - INTERVAL '1 day'),
+             ('{READER}', '{DUPLICATE}', 8, NOW())"
+        ),
+        format!(
+            "INSERT INTO article_reactions (user_id, article_id, reaction, created_at) VALUES
+             ('{READER}', '{DUPLICATE}', 'like', NOW() - INTERVAL '1 hour'),
+             ('{READER}', '{LATER_DUPLICATE}', 'dislike', NOW())"
+        ),
+        format!(
+            "INSERT INTO saved_articles (id, user_id, article_id, position, title, description, link, creator, feed_id, pub_date) VALUES
+             (gen_random_uuid(), '{READER}', '{DUPLICATE}', 0, 'Duplicate', 'd', 'https://example.com/a', 'x', '{feed}', NOW())"
+        ),
+    ];
+
+    for statement in statements {
+        diesel::sql_query(statement).execute(conn).unwrap();
+    }
+}
+
+#[test]
+#[ignore = "requires TEST_DATABASE_URL pointing to a Postgres server"]
+fn test_unique_link_migration_moves_duplicate_data_to_the_original() {
+    let database = ScratchDatabase::create();
+    let mut conn = PgConnection::establish(&database.url).unwrap();
+
+    migrate_until(&mut conn, "add_unique_article_link");
+    seed_duplicates(&mut conn);
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    assert_eq!(count(&mut conn, "SELECT COUNT(*) FROM articles"), 1);
+    assert_eq!(count(&mut conn, &format!("SELECT COUNT(*) FROM articles WHERE id = '{ORIGINAL}'")), 1);
+
+    let on_original = |conn: &mut PgConnection, table: &str| {
+        count(conn, &format!("SELECT COUNT(*) FROM {table} WHERE article_id = '{ORIGINAL}'"))
+    };
+    // As duas leituras viram uma, e as duas anotações seguem no original
+    assert_eq!(on_original(&mut conn, "article_reads"), 1);
+    assert_eq!(on_original(&mut conn, "annotations"), 2);
+    assert_eq!(on_original(&mut conn, "saved_articles"), 1);
+    // A avaliação mais recente vence; de `like` e `dislike`, fica o último
+    assert_eq!(count(&mut conn, "SELECT MAX(rating)::BIGINT FROM article_ratings"), 8);
+    assert_eq!(on_original(&mut conn, "article_reactions"), 1);
+    assert_eq!(count(&mut conn, "SELECT COUNT(*) FROM article_reactions WHERE reaction = 'dislike'"), 1);
+
+    let duplicate = diesel::sql_query(format!(
+        "INSERT INTO articles (title, description, link, pub_date, content, creator, feed_id)
+         SELECT title, description, link, pub_date, content, creator, feed_id FROM articles WHERE id = '{ORIGINAL}'"
+    ))
+    .execute(&mut conn);
+    assert!(duplicate.is_err(), "the link index must be unique");
+}
@@ -2,3 +2,28 @@
 mod login_user_tests;
 mod register_user_tests;
 mod list_article_categories_tests;
+
+mod user_profile_tests;
+mod mfa_tests;
+mod api_key_tests;
+mod session_tests;
+mod oidc_tests;
+mod subscription_tests;
+mod read_state_tests;
+mod saved_article_tests;
+mod rating_reaction_tests;
+mod annotation_tests;
+mod tag_tests;
+mod for_you_tests;
+mod smart_folder_tests;
+mod alert_tests;
+mod digest_tests;
+mod webhook_tests;
+mod migration_tests;
+mod article_stream_tests;
+mod live_tests;
+mod syndication_tests;
+mod article_export_tests;
+mod article_import_tests;
+mod content_negotiation_tests;
+mod problem_details_tests;
\ No newline at end of file
@@ -0,0 +1,405 @@
+use crate::common::{authenticated, create_test_auth_service, create_test_session_service, create_test_user_repository, problem_code};
+use actix_web::http::StatusCode;
+use actix_web::test::TestRequest;
+use chrono::{Duration, Utc};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use nexight_backend::application::services::auth_service::AuthService;
+use nexight_backend::application::services::identity_provider::{pkce_challenge, IdentityProviders};
+use nexight_backend::application::services::jwt_keys::{JwtKey, JwtKeySet};
+use nexight_backend::application::use_cases::complete_oidc_login::{CompleteOidcLoginError, CompleteOidcLoginUseCase};
+use nexight_backend::application::use_cases::login_user::{LoginContext, LoginOutcome};
+use nexight_backend::application::use_cases::start_oidc_login::{StartOidcLoginError, StartOidcLoginUseCase};
+use nexight_backend::domain::entities::identity::OidcCallbackDto;
+use nexight_backend::domain::entities::mfa::UserMfa;
+use nexight_backend::domain::entities::user::User;
+use nexight_backend::domain::repositories::mfa_repository::MfaRepository;
+use nexight_backend::domain::repositories::user_repository::UserRepository;
+use nexight_backend::infrastructure::oidc::oidc_config::OidcProviderConfig;
+use nexight_backend::infrastructure::oidc::oidc_identity_provider::OidcIdentityProvider;
+use nexight_backend::infrastructure::repositories::in_memory_identity_repository::InMemoryIdentityRepository;
+use nexight_backend::infrastructure::repositories::in_memory_mfa_repository::InMemoryMfaRepository;
+use nexight_backend::infrastructure::repositories::in_memory_user_repository::InMemoryUserRepository;
+use nexight_backend::infrastructure::web::client_ip::TrustedProxies;
+use nexight_backend::interfaces::controllers::oidc_controller::OidcController;
+use std::collections::HashMap;
+use std::sync::Arc;
+use url::Url;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const RSA_PEM: &[u8] = include_bytes!("../fixtures/jwt/rsa-2026-10.pem");
+const CLIENT_ID: &str = "nexight-test";
+
+/// Provedor OIDC local: discovery, JWKS e token endpoint servidos pelo wiremock
+struct MockProvider {
+    server: MockServer,
+}
+
+impl MockProvider {
+    async fn start() -> Self {
+        let server = MockServer::start().await;
+        let issuer = server.uri();
+
+        Mock::given(method("GET"))
+            .and(path("/.well-known/openid-configuration"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issuer": issuer,
+                "authorization_endpoint": format!("{}/authorize", issuer),
+                "token_endpoint": format!("{}/token", issuer),
+                "jwks_uri": format!("{}/jwks", issuer),
+            })))
+            .mount(&server)
+            .await;
+
+        let jwks = JwtKeySet::new(vec![JwtKey::from_pem("mock", RSA_PEM).unwrap()], "mock")
+            .unwrap()
+            .jwks();
+        Mock::given(method("GET"))
+            .and(path("/jwks"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&jwks))
+            .mount(&server)
+            .await;
+
+        Self { server }
+    }
+
+    fn config(&self) -> OidcProviderConfig {
+        OidcProviderConfig {
+            name: "mock".to_string(),
+            issuer: self.server.uri(),
+            client_id: CLIENT_ID.to_string(),
+            client_secret: Some("secret".to_string()),
+            scopes: vec!["email".to_string(), "profile".to_string()],
+            redirect_uri: "http://localhost:3000/auth/callback".to_string(),
+        }
+    }
+
+    /// Responde à próxima chamada do token endpoint com um ID token assinado com as claims informadas
+    async fn issue_id_token(&self, claims: serde_json::Value) {
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some("mock".to_string());
+        let id_token = encode(&header, &claims, &EncodingKey::from_rsa_pem(RSA_PEM).unwrap()).unwrap();
+
+        Mock::given(method("POST"))
+            .and(path("/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "provider-access-token",
+                "token_type": "Bearer",
+                "id_token": id_token,
+            })))
+            .up_to_n_times(1)
+            .mount(&self.server)
+            .await;
+    }
+
+    fn claims(&self, subject: &str, email: &str, email_verified: bool, nonce: &str) -> serde_json::Value {
+        let now = Utc::now().timestamp();
+        serde_json::json!({
+            "iss": self.server.uri(),
+            "aud": CLIENT_ID,
+            "sub": subject,
+            "email": email,
+            "email_verified": email_verified,
+            "name": "Remote Reader",
+            "nonce": nonce,
+            "iat": now,
+            "exp": now + 300,
+        })
+    }
+}
+
+struct TestSetup {
+    provider: MockProvider,
+    repository: Arc<InMemoryUserRepository>,
+    identities: Arc<InMemoryIdentityRepository>,
+    mfa_repository: Arc<InMemoryMfaRepository>,
+    auth_service: Arc<AuthService>,
+    start: Arc<StartOidcLoginUseCase>,
+    complete: Arc<CompleteOidcLoginUseCase>,
+}
+
+async fn create_test_setup() -> TestSetup {
+    let provider = MockProvider::start().await;
+    let repository = create_test_user_repository();
+    let auth_service = create_test_auth_service();
+    let identities = Arc::new(InMemoryIdentityRepository::new());
+    let mfa_repository = Arc::new(InMemoryMfaRepository::new());
+    let providers = Arc::new(
+        IdentityProviders::new().register("mock", Arc::new(OidcIdentityProvider::new(provider.config()))),
+    );
+
+    TestSetup {
+        start: Arc::new(StartOidcLoginUseCase::new(
+            providers.clone(),
+            identities.clone(),
+            Duration::minutes(10),
+        )),
+        complete: Arc::new(CompleteOidcLoginUseCase::new(
+            providers,
+            identities.clone(),
+            repository.clone(),
+            auth_service.clone(),
+            mfa_repository.clone(),
+            Duration::minutes(5),
+            create_test_session_service(auth_service.clone()),
+        )),
+        provider,
+        repository,
+        identities,
+        mfa_repository,
+        auth_service,
+    }
+}
+
+/// Inicia o login e devolve (state, parâmetros da URL de autorização)
+async fn start_login(setup: &TestSetup) -> (String, HashMap<String, String>) {
+    let authorization = setup.start.execute("mock").await.unwrap();
+    let url = Url::parse(&authorization.authorization_url).unwrap();
+    let params = url.query_pairs().into_owned().collect();
+
+    (authorization.state, params)
+}
+
+async fn complete_login(setup: &TestSetup, state: &str) -> Result<LoginOutcome, CompleteOidcLoginError> {
+    let dto = OidcCallbackDto {
+        code: "authorization-code".to_string(),
+        state: state.to_string(),
+    };
+
+    setup.complete.execute("mock", dto, LoginContext::default()).await
+}
+
+async fn create_local_user(setup: &TestSetup, email: &str) -> User {
+    let password_hash = setup.auth_service.hash_password("password123").unwrap();
+    let user = User::new(email.to_string(), password_hash, "Local Reader".to_string());
+    setup.repository.create(user).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_authorization_url_uses_pkce_state_and_nonce() {
+    let setup = create_test_setup().await;
+
+    let (state, params) = start_login(&setup).await;
+
+    assert!(params["scope"].split(' ').any(|scope| scope == "openid"));
+    assert_eq!(params["response_type"], "code");
+    assert_eq!(params["client_id"], CLIENT_ID);
+    assert_eq!(params["state"], state);
+    assert_eq!(params["code_challenge_method"], "S256");
+    assert!(!params["nonce"].is_empty());
+    assert!(!params["code_challenge"].is_empty());
+}
+
+#[tokio::test]
+async fn test_first_login_creates_user_and_identity() {
+    let setup = create_test_setup().await;
+    let (state, params) = start_login(&setup).await;
+    setup
+        .provider
+        .issue_id_token(setup.provider.claims("subject-1", "new@example.com", true, &params["nonce"]))
+        .await;
+
+    let outcome = complete_login(&setup, &state).await.unwrap();
+
+    let response = authenticated(outcome).expect("expected a session token");
+    assert_eq!(response.user.email, "new@example.com");
+    assert_eq!(response.user.name, "Remote Reader");
+    let claims = setup.auth_service.verify_token(&response.token).unwrap();
+    assert!(claims.sid.is_some());
+
+    let identities = setup.identities.identities_of(response.user.id).await;
+    assert_eq!(identities.len(), 1);
+    assert_eq!(identities[0].provider, "mock");
+    assert_eq!(identities[0].subject, "subject-1");
+}
+
+#[tokio::test]
+async fn test_token_request_sends_the_pkce_verifier() {
+    let setup = create_test_setup().await;
+    let (state, params) = start_login(&setup).await;
+    setup
+        .provider
+        .issue_id_token(setup.provider.claims("subject-1", "new@example.com", true, &params["nonce"]))
+        .await;
+
+    complete_login(&setup, &state).await.unwrap();
+
+    let requests = setup.provider.server.received_requests().await.unwrap();
+    let token_request = requests
+        .iter()
+        .find(|request| request.url.path() == "/token")
+        .expect("token endpoint was not called");
+    let form: HashMap<String, String> = url::form_urlencoded::parse(&token_request.body)
+        .into_owned()
+        .collect();
+
+    assert_eq!(form["grant_type"], "authorization_code");
+    assert_eq!(form["code"], "authorization-code");
+    assert_eq!(pkce_challenge(&form["code_verifier"]), params["code_challenge"]);
+    assert!(token_request.headers.contains_key("authorization"));
+}
+
+#[tokio::test]
+async fn test_verified_email_links_existing_user() {
+    let setup = create_test_setup().await;
+    let user = create_local_user(&setup, "reader@example.com").await;
+    let (state, params) = start_login(&setup).await;
+    setup
+        .provider
+        .issue_id_token(setup.provider.claims("subject-2", "reader@example.com", true, &params["nonce"]))
+        .await;
+
+    let response = authenticated(complete_login(&setup, &state).await.unwrap()).unwrap();
+
+    assert_eq!(response.user.id, user.id);
+    assert_eq!(response.user.name, "Local Reader");
+    assert_eq!(setup.identities.identities_of(user.id).await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_unverified_email_is_not_linked() {
+    let setup = create_test_setup().await;
+    let user = create_local_user(&setup, "reader@example.com").await;
+    let (state, params) = start_login(&setup).await;
+    setup
+        .provider
+        .issue_id_token(setup.provider.claims("subject-3", "reader@example.com", false, &params["nonce"]))
+        .await;
+
+    let result = complete_login(&setup, &state).await;
+
+    assert!(matches!(result, Err(CompleteOidcLoginError::EmailNotVerified)));
+    assert!(setup.identities.identities_of(user.id).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_returning_login_uses_linked_identity() {
+    let setup = create_test_setup().await;
+
+    let (state, params) = start_login(&setup).await;
+    setup
+        .provider
+        .issue_id_token(setup.provider.claims("subject-4", "first@example.com", true, &params["nonce"]))
+        .await;
+    let first = authenticated(complete_login(&setup, &state).await.unwrap()).unwrap();
+
+    // Mesmo `sub` com outro email: o vínculo vale, não o email
+    let (state, params) = start_login(&setup).await;
+    setup
+        .provider
+        .issue_id_token(setup.provider.claims("subject-4", "changed@example.com", false, &params["nonce"]))
+        .await;
+    let second = authenticated(complete_login(&setup, &state).await.unwrap()).unwrap();
+
+    assert_eq!(second.user.id, first.user.id);
+    assert_eq!(second.user.email, "first@example.com");
+    assert_eq!(setup.identities.identities_of(first.user.id).await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_state_is_single_use() {
+    let setup = create_test_setup().await;
+    let (state, params) = start_login(&setup).await;
+    setup
+        .provider
+        .issue_id_token(setup.provider.claims("subject-5", "new@example.com", true, &params["nonce"]))
+        .await;
+
+    complete_login(&setup, &state).await.unwrap();
+    let replay = complete_login(&setup, &state).await;
+
+    assert!(matches!(replay, Err(CompleteOidcLoginError::InvalidState)));
+}
+
+#[tokio::test]
+async fn test_unknown_state_is_rejected() {
+    let setup = create_test_setup().await;
+
+    let result = complete_login(&setup, "forged-state").await;
+
+    assert!(matches!(result, Err(CompleteOidcLoginError::InvalidState)));
+}
+
+#[tokio::test]
+async fn test_wrong_nonce_is_rejected() {
+    let setup = create_test_setup().await;
+    let (state, _) = start_login(&setup).await;
+    setup
+        .provider
+        .issue_id_token(setup.provider.claims("subject-6", "new@example.com", true, "other-nonce"))
+        .await;
+
+    let result = complete_login(&setup, &state).await;
+
+    assert!(matches!(result, Err(CompleteOidcLoginError::ProviderRejected(_))));
+}
+
+#[tokio::test]
+async fn test_token_for_another_client_is_rejected() {
+    let setup = create_test_setup().await;
+    let (state, params) = start_login(&setup).await;
+    let mut claims = setup.provider.claims("subject-7", "new@example.com", true, &params["nonce"]);
+    claims["aud"] = serde_json::json!("another-client");
+    setup.provider.issue_id_token(claims).await;
+
+    let result = complete_login(&setup, &state).await;
+
+    assert!(matches!(result, Err(CompleteOidcLoginError::ProviderRejected(_))));
+}
+
+#[tokio::test]
+async fn test_user_with_mfa_gets_challenge() {
+    let setup = create_test_setup().await;
+    let user = create_local_user(&setup, "reader@example.com").await;
+    let mut mfa = UserMfa::new(user.id, "JBSWY3DPEHPK3PXP".to_string());
+    mfa.confirm(1);
+    setup.mfa_repository.save(mfa).await.unwrap();
+
+    let (state, params) = start_login(&setup).await;
+    setup
+        .provider
+        .issue_id_token(setup.provider.claims("subject-8", "reader@example.com", true, &params["nonce"]))
+        .await;
+
+    let outcome = complete_login(&setup, &state).await.unwrap();
+
+    match outcome {
+        LoginOutcome::MfaRequired(challenge) => {
+            assert!(setup.auth_service.verify_mfa_token(&challenge.mfa_token).is_ok());
+        }
+        other => panic!("expected an MFA challenge, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_unknown_provider() {
+    let setup = create_test_setup().await;
+
+    let result = setup.start.execute("unknown").await;
+
+    assert!(matches!(result, Err(StartOidcLoginError::UnknownProvider)));
+    assert_eq!(setup.start.available_providers(), vec!["mock".to_string()]);
+}
+
+#[tokio::test]
+async fn test_controller_maps_errors_to_status_codes() {
+    let setup = create_test_setup().await;
+    let controller = OidcController::new(
+        setup.start.clone(),
+        setup.complete.clone(),
+        TrustedProxies::default(),
+    );
+
+    let req = TestRequest::get().param("provider", "unknown").to_http_request();
+    let response = controller.authorize(req, actix_web::web::Bytes::new()).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(problem_code(response).await, "unknown_identity_provider");
+
+    let body = serde_json::json!({ "code": "authorization-code", "state": "forged-state" });
+    let req = TestRequest::post().param("provider", "mock").to_http_request();
+    let response = controller
+        .callback(req, actix_web::web::Bytes::from(body.to_string()))
+        .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(problem_code(response).await, "invalid_oidc_state");
+}
@@ -0,0 +1,193 @@
+use crate::common::{create_test_session_auth, create_test_user_repository};
+use actix_web::http::StatusCode;
+use actix_web::middleware::from_fn;
+use actix_web::{test, web, App, HttpRequest};
+use nexight_backend::application::services::alert_service::AlertService;
+use nexight_backend::application::use_cases::create_article::CreateArticleUseCase;
+use nexight_backend::application::use_cases::delete_article::DeleteArticleUseCase;
+use nexight_backend::application::use_cases::get_article::GetArticleUseCase;
+use nexight_backend::application::use_cases::list_articles::ListArticlesUseCase;
+use nexight_backend::application::use_cases::update_article::UpdateArticleUseCase;
+use nexight_backend::domain::entities::user::User;
+use nexight_backend::domain::repositories::user_repository::UserRepository;
+use nexight_backend::infrastructure::middleware::request_id::request_id;
+use nexight_backend::infrastructure::repositories::in_memory_alert_rule_repository::InMemoryAlertRuleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_notification_repository::InMemoryNotificationRepository;
+use nexight_backend::interfaces::controllers::article_controller::ArticleController;
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+async fn create_test_controller() -> (Arc<ArticleController>, String) {
+    let users = create_test_user_repository();
+    let user = users
+        .create(User::new("editor@example.com".to_string(), "hash".to_string(), "Editor".to_string()))
+        .await
+        .unwrap();
+    let (auth_middleware, token) = create_test_session_auth(users, &user).await;
+
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let alert_service = Arc::new(AlertService::new(
+        Arc::new(InMemoryAlertRuleRepository::new()),
+        Arc::new(InMemoryNotificationRepository::new()),
+    ));
+
+    let controller = Arc::new(ArticleController::new(
+        Arc::new(CreateArticleUseCase::new(articles.clone(), alert_service.clone())),
+        Arc::new(UpdateArticleUseCase::new(articles.clone(), alert_service)),
+        Arc::new(DeleteArticleUseCase::new(articles.clone())),
+        Arc::new(GetArticleUseCase::new(articles.clone())),
+        Arc::new(ListArticlesUseCase::new(articles)),
+        auth_middleware,
+    ));
+
+    (controller, token)
+}
+
+macro_rules! init_app {
+    ($controller:expr) => {
+        test::init_service(
+            App::new()
+                .wrap(from_fn(request_id))
+                .route("/articles", web::get().to({
+                    let ctrl = $controller.clone();
+                    move |req: HttpRequest, body: web::Bytes| {
+                        let controller = ctrl.clone();
+                        async move { controller.list(req, body).await }
+                    }
+                }))
+                .route("/articles", web::post().to({
+                    let ctrl = $controller.clone();
+                    move |req: HttpRequest, body: web::Bytes| {
+                        let controller = ctrl.clone();
+                        async move { controller.create(req, body).await }
+                    }
+                }))
+                .route("/articles/{id}", web::get().to({
+                    let ctrl = $controller.clone();
+                    move |req: HttpRequest, body: web::Bytes| {
+                        let controller = ctrl.clone();
+                        async move { controller.get(req, body).await }
+                    }
+                })),
+        )
+        .await
+    };
+}
+
+#[actix_web::test]
+async fn test_validation_errors_are_problem_details_with_fields_and_request_id() {
+    let (controller, token) = create_test_controller().await;
+    let app = init_app!(controller);
+
+    let request = test::TestRequest::post()
+        .uri("/articles")
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .insert_header(("X-Request-Id", "client-req-42"))
+        .set_json(json!({
+            "title": "",
+            "description": "Description",
+            "link": "https://example.com/a",
+            "pub_date": "2026-10-01T12:00:00Z",
+            "content": "Content",
+            "creator": "",
+            "feed_id": Uuid::new_v4(),
+        }))
+        .to_request();
+    let response = test::call_service(&app, request).await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(response.headers().get("Content-Type").unwrap(), "application/problem+json");
+    assert_eq!(response.headers().get("X-Request-Id").unwrap(), "client-req-42");
+    let problem: serde_json::Value = test::read_body_json(response).await;
+    assert_eq!(problem["type"], "about:blank");
+    assert_eq!(problem["title"], "Bad Request");
+    assert_eq!(problem["status"], 400);
+    assert_eq!(problem["code"], "validation_failed");
+    assert_eq!(problem["instance"], "/articles");
+    assert_eq!(problem["request_id"], "client-req-42");
+    assert_eq!(problem["errors"][0]["field"], "creator");
+    assert_eq!(problem["errors"][1]["field"], "title");
+    assert_eq!(problem["errors"][1]["message"], "Title is required");
+}
+
+#[actix_web::test]
+async fn test_duplicate_link_is_a_conflict() {
+    let (controller, token) = create_test_controller().await;
+    let app = init_app!(controller);
+    let article = json!({
+        "title": "Original",
+        "description": "Description",
+        "link": "https://example.com/same",
+        "pub_date": "2026-10-01T12:00:00Z",
+        "content": "Content",
+        "creator": "Author",
+        "feed_id": Uuid::new_v4(),
+    });
+
+    let create = || {
+        test::TestRequest::post()
+            .uri("/articles")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(&article)
+            .to_request()
+    };
+    assert_eq!(test::call_service(&app, create()).await.status(), StatusCode::CREATED);
+
+    let response = test::call_service(&app, create()).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let problem: serde_json::Value = test::read_body_json(response).await;
+    assert_eq!(problem["code"], "article_link_taken");
+}
+
+#[actix_web::test]
+async fn test_use_case_and_auth_errors_have_stable_codes() {
+    let (controller, _) = create_test_controller().await;
+    let app = init_app!(controller);
+
+    let request = test::TestRequest::get().uri(&format!("/articles/{}", Uuid::new_v4())).to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let generated_id = response.headers().get("X-Request-Id").unwrap().to_str().unwrap().to_string();
+    assert!(Uuid::parse_str(&generated_id).is_ok());
+    let problem: serde_json::Value = test::read_body_json(response).await;
+    assert_eq!(problem["code"], "article_not_found");
+    assert_eq!(problem["request_id"], generated_id);
+
+    let request = test::TestRequest::post().uri("/articles").set_payload("{}").to_request();
+    let problem: serde_json::Value = test::read_body_json(test::call_service(&app, request).await).await;
+    assert_eq!(problem["status"], 401);
+    assert_eq!(problem["code"], "missing_credentials");
+}
+
+#[actix_web::test]
+async fn test_framework_errors_are_converted_to_problem_details() {
+    let (controller, _) = create_test_controller().await;
+    let app = init_app!(controller);
+
+    let request = test::TestRequest::get().uri("/unknown").to_request();
+    let response = test::call_service(&app, request).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(response.headers().get("Content-Type").unwrap(), "application/problem+json");
+    let problem: serde_json::Value = test::read_body_json(response).await;
+    assert_eq!(problem["code"], "not_found");
+    assert_eq!(problem["instance"], "/unknown");
+    assert!(problem["request_id"].is_string());
+}
+#[actix_web::test]
+async fn test_malformed_list_query_is_a_bad_request() {
+    let (controller, _) = create_test_controller().await;
+    let app = init_app!(controller);
+
+    for uri in ["/articles?page=abc", "/articles?category_id=not-a-uuid", "/articles?sort=random", "/articles?unread=maybe"] {
+        let response = test::call_service(&app, test::TestRequest::get().uri(uri).to_request()).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", uri);
+        let problem: serde_json::Value = test::read_body_json(response).await;
+        assert_eq!(problem["detail"], "Invalid query parameters");
+    }
+
+    let response = test::call_service(&app, test::TestRequest::get().uri("/articles?page=2").to_request()).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
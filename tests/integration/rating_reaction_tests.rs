@@ -0,0 +1,240 @@
+use crate::common::{api_key_request, create_test_api_key_auth, create_test_article, problem_code, TestArticleOptions};
+use actix_web::http::StatusCode;
+use actix_web::test::TestRequest;
+use actix_web::web::Bytes;
+use nexight_backend::application::use_cases::get_article_reactions::{GetArticleReactionsError, GetArticleReactionsUseCase};
+use nexight_backend::application::use_cases::list_articles::ListArticlesUseCase;
+use nexight_backend::application::use_cases::rate_article::{RateArticleError, RateArticleUseCase};
+use nexight_backend::application::use_cases::react_to_article::{ReactToArticleError, ReactToArticleUseCase};
+use nexight_backend::application::use_cases::remove_article_rating::RemoveArticleRatingUseCase;
+use nexight_backend::application::use_cases::remove_article_reaction::{RemoveArticleReactionError, RemoveArticleReactionUseCase};
+use nexight_backend::domain::entities::api_key::ApiKeyScope;
+use nexight_backend::domain::entities::article::{ArticleListFilter, ArticleSort, RateArticleDto};
+use nexight_backend::domain::entities::reaction::ReactionCount;
+use nexight_backend::domain::repositories::article_repository::ArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_reaction_repository::InMemoryArticleReactionRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::interfaces::controllers::article_feedback_controller::ArticleFeedbackController;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct TestSetup {
+    articles: Arc<InMemoryArticleRepository>,
+    reaction_repository: Arc<InMemoryArticleReactionRepository>,
+    rate: RateArticleUseCase,
+    remove_rating: RemoveArticleRatingUseCase,
+    react: ReactToArticleUseCase,
+    unreact: RemoveArticleReactionUseCase,
+    reactions: GetArticleReactionsUseCase,
+    list: ListArticlesUseCase,
+}
+
+fn create_test_setup() -> TestSetup {
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let reactions = Arc::new(InMemoryArticleReactionRepository::new());
+
+    TestSetup {
+        rate: RateArticleUseCase::new(articles.clone()),
+        remove_rating: RemoveArticleRatingUseCase::new(articles.clone()),
+        react: ReactToArticleUseCase::new(articles.clone(), reactions.clone()),
+        unreact: RemoveArticleReactionUseCase::new(reactions.clone()),
+        reactions: GetArticleReactionsUseCase::new(articles.clone(), reactions.clone()),
+        list: ListArticlesUseCase::new(articles.clone()),
+        articles,
+        reaction_repository: reactions,
+    }
+}
+
+async fn titles(setup: &TestSetup, filter: ArticleListFilter) -> Vec<String> {
+    let page = setup.list.execute_filtered(&filter, 1, 20).await.unwrap();
+    page.articles.into_iter().map(|a| a.title).collect()
+}
+
+#[tokio::test]
+async fn test_rating_updates_average_and_replaces_previous_rating() {
+    let setup = create_test_setup();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new().hours_ago(1)).await;
+    let alice = Uuid::new_v4();
+    let bob = Uuid::new_v4();
+
+    setup.rate.execute(alice, article.id, RateArticleDto { rating: 4 }).await.unwrap();
+    setup.rate.execute(bob, article.id, RateArticleDto { rating: 8 }).await.unwrap();
+    let result = setup.rate.execute(alice, article.id, RateArticleDto { rating: 10 }).await.unwrap();
+
+    assert_eq!(result.rating, Some(10));
+    assert_eq!(result.average, Some(9.0));
+    assert_eq!(result.count, 2);
+
+    let stored = setup.articles.find_by_id(article.id).await.unwrap().unwrap();
+    assert_eq!(stored.user_rating_avg, Some(9.0));
+    assert_eq!(stored.user_rating_count, 2);
+}
+
+#[tokio::test]
+async fn test_rating_out_of_range_is_rejected() {
+    let setup = create_test_setup();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new().hours_ago(1)).await;
+
+    let result = setup.rate.execute(Uuid::new_v4(), article.id, RateArticleDto { rating: 11 }).await;
+
+    assert!(matches!(result, Err(RateArticleError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_rating_unknown_article() {
+    let setup = create_test_setup();
+
+    let result = setup.rate.execute(Uuid::new_v4(), Uuid::new_v4(), RateArticleDto { rating: 5 }).await;
+
+    assert!(matches!(result, Err(RateArticleError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_remove_rating_recomputes_average() {
+    let setup = create_test_setup();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new().hours_ago(1)).await;
+    let user_id = Uuid::new_v4();
+    setup.rate.execute(user_id, article.id, RateArticleDto { rating: 6 }).await.unwrap();
+
+    let result = setup.remove_rating.execute(user_id, article.id).await.unwrap();
+
+    assert_eq!(result.rating, None);
+    assert_eq!(result.average, None);
+    assert_eq!(result.count, 0);
+}
+
+#[tokio::test]
+async fn test_sort_and_filter_by_ai_rate_and_user_rating() {
+    let setup = create_test_setup();
+    let fresh = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Fresh", TestArticleOptions::new().hours_ago(1)).await;
+    let good = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Good", TestArticleOptions::new().rate(Some(9)).hours_ago(2)).await;
+    create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Average", TestArticleOptions::new().rate(Some(5)).hours_ago(3)).await;
+
+    setup.rate.execute(Uuid::new_v4(), good.id, RateArticleDto { rating: 3 }).await.unwrap();
+    setup.rate.execute(Uuid::new_v4(), fresh.id, RateArticleDto { rating: 7 }).await.unwrap();
+
+    let by_rate = ArticleListFilter { sort: ArticleSort::Rate, ..Default::default() };
+    assert_eq!(titles(&setup, by_rate).await, vec!["Good", "Average", "Fresh"]);
+
+    let by_user_rating = ArticleListFilter { sort: ArticleSort::UserRating, ..Default::default() };
+    assert_eq!(titles(&setup, by_user_rating).await, vec!["Fresh", "Good", "Average"]);
+
+    let min_rate = ArticleListFilter { min_rate: Some(6), ..Default::default() };
+    assert_eq!(titles(&setup, min_rate).await, vec!["Good"]);
+
+    let min_user_rating = ArticleListFilter { min_user_rating: Some(5.0), ..Default::default() };
+    assert_eq!(titles(&setup, min_user_rating).await, vec!["Fresh"]);
+}
+
+#[tokio::test]
+async fn test_like_replaces_dislike_and_emojis_accumulate() {
+    let setup = create_test_setup();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new().hours_ago(1)).await;
+    let user_id = Uuid::new_v4();
+
+    setup.react.execute(user_id, article.id, "dislike").await.unwrap();
+    setup.react.execute(user_id, article.id, "like").await.unwrap();
+    setup.react.execute(user_id, article.id, "🔥").await.unwrap();
+    setup.react.execute(user_id, article.id, "🔥").await.unwrap();
+    setup.react.execute(Uuid::new_v4(), article.id, "🔥").await.unwrap();
+
+    let reactions = setup.reactions.execute(article.id, Some(user_id)).await.unwrap();
+
+    assert_eq!(
+        reactions.counts,
+        vec![
+            ReactionCount { reaction: "🔥".to_string(), count: 2 },
+            ReactionCount { reaction: "like".to_string(), count: 1 },
+        ]
+    );
+    assert_eq!(reactions.mine, vec!["like".to_string(), "🔥".to_string()]);
+
+    let anonymous = setup.reactions.execute(article.id, None).await.unwrap();
+    assert!(anonymous.mine.is_empty());
+}
+
+#[tokio::test]
+async fn test_invalid_reaction_or_unknown_article() {
+    let setup = create_test_setup();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new().hours_ago(1)).await;
+
+    let invalid = setup.react.execute(Uuid::new_v4(), article.id, "love").await;
+    let missing = setup.react.execute(Uuid::new_v4(), Uuid::new_v4(), "like").await;
+    let missing_reactions = setup.reactions.execute(Uuid::new_v4(), None).await;
+
+    assert!(matches!(invalid, Err(ReactToArticleError::InvalidReaction)));
+    assert!(matches!(missing, Err(ReactToArticleError::NotFound)));
+    assert!(matches!(missing_reactions, Err(GetArticleReactionsError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_remove_reaction() {
+    let setup = create_test_setup();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new().hours_ago(1)).await;
+    let user_id = Uuid::new_v4();
+    setup.react.execute(user_id, article.id, "like").await.unwrap();
+
+    setup.unreact.execute(user_id, article.id, "like").await.unwrap();
+    let again = setup.unreact.execute(user_id, article.id, "like").await;
+
+    assert!(matches!(again, Err(RemoveArticleReactionError::NotFound)));
+    assert!(setup.reactions.execute(article.id, None).await.unwrap().counts.is_empty());
+}
+
+#[tokio::test]
+async fn test_ratings_and_reactions_require_library_write_scope() {
+    let setup = create_test_setup();
+    let (auth, key) = create_test_api_key_auth(vec![ApiKeyScope::ArticlesRead]).await;
+    let controller = ArticleFeedbackController::new(
+        Arc::new(RateArticleUseCase::new(setup.articles.clone())),
+        Arc::new(RemoveArticleRatingUseCase::new(setup.articles.clone())),
+        Arc::new(ReactToArticleUseCase::new(setup.articles.clone(), setup.reaction_repository.clone())),
+        Arc::new(RemoveArticleReactionUseCase::new(setup.reaction_repository.clone())),
+        Arc::new(GetArticleReactionsUseCase::new(setup.articles.clone(), setup.reaction_repository.clone())),
+        auth,
+    );
+
+    let responses = [
+        controller.rate(api_key_request(&key), Bytes::new()).await,
+        controller.remove_rating(api_key_request(&key), Bytes::new()).await,
+        controller.react(api_key_request(&key), Bytes::new()).await,
+        controller.unreact(api_key_request(&key), Bytes::new()).await,
+    ];
+    for response in responses {
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
+
+#[tokio::test]
+async fn test_controller_errors_have_stable_codes() {
+    let setup = create_test_setup();
+    let (auth, key) = create_test_api_key_auth(vec![ApiKeyScope::LibraryWrite]).await;
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new().hours_ago(1)).await;
+    let controller = ArticleFeedbackController::new(
+        Arc::new(RateArticleUseCase::new(setup.articles.clone())),
+        Arc::new(RemoveArticleRatingUseCase::new(setup.articles.clone())),
+        Arc::new(ReactToArticleUseCase::new(setup.articles.clone(), setup.reaction_repository.clone())),
+        Arc::new(RemoveArticleReactionUseCase::new(setup.reaction_repository.clone())),
+        Arc::new(GetArticleReactionsUseCase::new(setup.articles.clone(), setup.reaction_repository.clone())),
+        auth,
+    );
+    let request = |article_id: Uuid, reaction: &str| {
+        TestRequest::default()
+            .insert_header(("X-API-Key", key.as_str()))
+            .param("id", article_id.to_string())
+            .param("reaction", reaction.to_string())
+            .to_http_request()
+    };
+
+    let missing = controller.react(request(Uuid::new_v4(), "like"), Bytes::new()).await;
+    assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+    assert_eq!(problem_code(missing).await, "article_not_found");
+
+    let invalid = controller.react(request(article.id, "love"), Bytes::new()).await;
+    assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(problem_code(invalid).await, "invalid_reaction");
+
+    let not_reacted = controller.unreact(request(article.id, "like"), Bytes::new()).await;
+    assert_eq!(not_reacted.status(), StatusCode::NOT_FOUND);
+    assert_eq!(problem_code(not_reacted).await, "reaction_not_found");
+}
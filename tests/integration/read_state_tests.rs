@@ -0,0 +1,321 @@
+use crate::common::{api_key_request, create_test_api_key_auth, create_test_article, TestArticleOptions};
+use actix_web::http::StatusCode;
+use actix_web::test::TestRequest;
+use actix_web::web::Bytes;
+use chrono::{Duration, Utc};
+use nexight_backend::application::services::read_state_sync_service::ReadStateSyncService;
+use nexight_backend::application::use_cases::get_unread_counts::GetUnreadCountsUseCase;
+use nexight_backend::application::use_cases::list_timeline::ListTimelineUseCase;
+use nexight_backend::application::use_cases::mark_all_read::MarkAllReadUseCase;
+use nexight_backend::application::use_cases::mark_article_read::{MarkArticleReadError, MarkArticleReadUseCase};
+use nexight_backend::application::use_cases::mark_article_unread::{MarkArticleUnreadError, MarkArticleUnreadUseCase};
+use nexight_backend::domain::entities::api_key::ApiKeyScope;
+use nexight_backend::domain::entities::article::{ArticleUserState, MarkAllReadDto};
+use nexight_backend::domain::entities::read_state::ReadStateChange;
+use nexight_backend::domain::entities::subscription::{CreateSubscriptionDto, Subscription};
+use nexight_backend::domain::repositories::subscription_repository::SubscriptionRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_subscription_repository::InMemorySubscriptionRepository;
+use nexight_backend::infrastructure::repositories::in_memory_tag_repository::InMemoryTagRepository;
+use nexight_backend::interfaces::controllers::read_state_controller::ReadStateController;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct TestSetup {
+    articles: Arc<InMemoryArticleRepository>,
+    subscriptions: Arc<InMemorySubscriptionRepository>,
+    read_state_sync: Arc<ReadStateSyncService>,
+    mark_read: MarkArticleReadUseCase,
+    mark_unread: MarkArticleUnreadUseCase,
+    mark_all_read: MarkAllReadUseCase,
+    unread_counts: GetUnreadCountsUseCase,
+    timeline: ListTimelineUseCase,
+}
+
+fn create_test_setup() -> TestSetup {
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let subscriptions = Arc::new(InMemorySubscriptionRepository::new());
+    let read_state_sync = Arc::new(ReadStateSyncService::local());
+
+    TestSetup {
+        mark_read: MarkArticleReadUseCase::new(articles.clone(), read_state_sync.clone()),
+        mark_unread: MarkArticleUnreadUseCase::new(articles.clone(), read_state_sync.clone()),
+        mark_all_read: MarkAllReadUseCase::new(articles.clone(), subscriptions.clone(), read_state_sync.clone()),
+        unread_counts: GetUnreadCountsUseCase::new(articles.clone(), subscriptions.clone()),
+        timeline: ListTimelineUseCase::new(articles.clone(), subscriptions.clone(), Arc::new(InMemoryTagRepository::new())),
+        articles,
+        subscriptions,
+        read_state_sync,
+    }
+}
+
+async fn subscribe(setup: &TestSetup, user_id: Uuid, feed_id: Uuid) {
+    let dto = CreateSubscriptionDto {
+        feed_id,
+        title: None,
+        folder: None,
+        weight: None,
+    };
+    setup.subscriptions.create(Subscription::new(user_id, dto)).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_mark_read_and_unread_updates_user_state() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    subscribe(&setup, user_id, feed_id).await;
+    let article = create_test_article(setup.articles.as_ref(), feed_id, "Rust Release", TestArticleOptions::new().hours_ago(1)).await;
+
+    setup.mark_read.execute(user_id, article.id).await.unwrap();
+    let timeline = setup.timeline.execute(user_id, false, 1, 20).await.unwrap();
+    let state = timeline.articles[0].user_state.clone().unwrap();
+    assert!(state.read);
+    assert!(state.read_at.is_some());
+
+    setup.mark_unread.execute(user_id, article.id).await.unwrap();
+    let timeline = setup.timeline.execute(user_id, false, 1, 20).await.unwrap();
+    assert_eq!(timeline.articles[0].user_state, Some(ArticleUserState::default()));
+}
+
+#[tokio::test]
+async fn test_mark_read_keeps_first_read_at() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    subscribe(&setup, user_id, feed_id).await;
+    let article = create_test_article(setup.articles.as_ref(), feed_id, "Rust Release", TestArticleOptions::new().hours_ago(1)).await;
+
+    setup.mark_read.execute(user_id, article.id).await.unwrap();
+    let first = setup.timeline.execute(user_id, false, 1, 20).await.unwrap().articles[0].user_state.clone();
+    setup.mark_read.execute(user_id, article.id).await.unwrap();
+    let second = setup.timeline.execute(user_id, false, 1, 20).await.unwrap().articles[0].user_state.clone();
+
+    assert_eq!(first, second);
+}
+
+#[tokio::test]
+async fn test_mark_missing_article() {
+    let setup = create_test_setup();
+
+    let read = setup.mark_read.execute(Uuid::new_v4(), Uuid::new_v4()).await;
+    let unread = setup.mark_unread.execute(Uuid::new_v4(), Uuid::new_v4()).await;
+
+    assert!(matches!(read, Err(MarkArticleReadError::NotFound)));
+    assert!(matches!(unread, Err(MarkArticleUnreadError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_read_state_changes_are_synced_to_other_devices() {
+    let setup = create_test_setup();
+    let mut device = setup.read_state_sync.subscribe();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    subscribe(&setup, user_id, feed_id).await;
+    let article = create_test_article(setup.articles.as_ref(), feed_id, "Rust Release", TestArticleOptions::new().hours_ago(1)).await;
+
+    setup.mark_read.execute(user_id, article.id).await.unwrap();
+    setup.mark_unread.execute(user_id, article.id).await.unwrap();
+    setup
+        .mark_all_read
+        .execute(user_id, MarkAllReadDto { feed_id: Some(feed_id), category_id: None, before: None })
+        .await
+        .unwrap();
+    // Falhas não são repassadas
+    let _ = setup.mark_read.execute(user_id, Uuid::new_v4()).await;
+
+    let changes: Vec<_> = (0..3).map(|_| device.try_recv().unwrap()).collect();
+    assert!(changes.iter().all(|event| event.user_id == user_id));
+    assert_eq!(changes[0].change, ReadStateChange::Read { article_id: article.id });
+    assert_eq!(changes[1].change, ReadStateChange::Unread { article_id: article.id });
+    assert_eq!(
+        changes[2].change,
+        ReadStateChange::AllRead { feed_id: Some(feed_id), category_id: None, before: None, marked: 1 }
+    );
+    assert!(device.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_read_state_is_per_user() {
+    let setup = create_test_setup();
+    let reader = Uuid::new_v4();
+    let other = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    subscribe(&setup, reader, feed_id).await;
+    subscribe(&setup, other, feed_id).await;
+    let article = create_test_article(setup.articles.as_ref(), feed_id, "Shared", TestArticleOptions::new().hours_ago(1)).await;
+
+    setup.mark_read.execute(reader, article.id).await.unwrap();
+
+    let timeline = setup.timeline.execute(other, true, 1, 20).await.unwrap();
+    assert_eq!(timeline.total, 1);
+    assert_eq!(timeline.articles[0].user_state.as_ref().map(|s| s.read), Some(false));
+}
+
+#[tokio::test]
+async fn test_timeline_unread_only() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    subscribe(&setup, user_id, feed_id).await;
+    let read = create_test_article(setup.articles.as_ref(), feed_id, "Already Read", TestArticleOptions::new().hours_ago(2)).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Fresh", TestArticleOptions::new().hours_ago(1)).await;
+
+    setup.mark_read.execute(user_id, read.id).await.unwrap();
+
+    let timeline = setup.timeline.execute(user_id, true, 1, 20).await.unwrap();
+    let titles: Vec<&str> = timeline.articles.iter().map(|a| a.title.as_str()).collect();
+    assert_eq!(titles, vec!["Fresh"]);
+    assert_eq!(timeline.total, 1);
+}
+
+#[tokio::test]
+async fn test_mark_all_read_for_timeline_respects_before() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    let unsubscribed = Uuid::new_v4();
+    subscribe(&setup, user_id, feed_id).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Old", TestArticleOptions::new().hours_ago(5)).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Older", TestArticleOptions::new().hours_ago(6)).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Arrived Later", TestArticleOptions::new().hours_ago(0)).await;
+    create_test_article(setup.articles.as_ref(), unsubscribed, "Elsewhere", TestArticleOptions::new().hours_ago(5)).await;
+
+    let dto = MarkAllReadDto {
+        before: Some(Utc::now() - Duration::hours(1)),
+        ..Default::default()
+    };
+    let result = setup.mark_all_read.execute(user_id, dto).await.unwrap();
+    assert_eq!(result.marked, 2);
+
+    let timeline = setup.timeline.execute(user_id, true, 1, 20).await.unwrap();
+    let titles: Vec<&str> = timeline.articles.iter().map(|a| a.title.as_str()).collect();
+    assert_eq!(titles, vec!["Arrived Later"]);
+
+    // Repetir não conta os artigos já lidos
+    let again = setup.mark_all_read.execute(user_id, MarkAllReadDto::default()).await.unwrap();
+    assert_eq!(again.marked, 1);
+}
+
+#[tokio::test]
+async fn test_mark_all_read_by_feed_and_category() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let rust_feed = Uuid::new_v4();
+    let news_feed = Uuid::new_v4();
+    let tech = Uuid::new_v4();
+    subscribe(&setup, user_id, rust_feed).await;
+    subscribe(&setup, user_id, news_feed).await;
+    create_test_article(setup.articles.as_ref(), rust_feed, "Rust Tech", TestArticleOptions::new().category(Some(tech)).hours_ago(1)).await;
+    create_test_article(setup.articles.as_ref(), rust_feed, "Rust Misc", TestArticleOptions::new().hours_ago(2)).await;
+    create_test_article(setup.articles.as_ref(), news_feed, "News Tech", TestArticleOptions::new().category(Some(tech)).hours_ago(3)).await;
+
+    let by_feed = MarkAllReadDto {
+        feed_id: Some(rust_feed),
+        category_id: Some(tech),
+        before: None,
+    };
+    let result = setup.mark_all_read.execute(user_id, by_feed).await.unwrap();
+    assert_eq!(result.marked, 1);
+
+    let timeline = setup.timeline.execute(user_id, true, 1, 20).await.unwrap();
+    let titles: Vec<&str> = timeline.articles.iter().map(|a| a.title.as_str()).collect();
+    assert_eq!(titles, vec!["Rust Misc", "News Tech"]);
+}
+
+#[tokio::test]
+async fn test_unread_counts_by_feed_and_category() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let rust_feed = Uuid::new_v4();
+    let news_feed = Uuid::new_v4();
+    let tech = Uuid::new_v4();
+    subscribe(&setup, user_id, rust_feed).await;
+    subscribe(&setup, user_id, news_feed).await;
+    let read = create_test_article(setup.articles.as_ref(), rust_feed, "Rust Tech", TestArticleOptions::new().category(Some(tech)).hours_ago(1)).await;
+    create_test_article(setup.articles.as_ref(), rust_feed, "Rust Tech 2", TestArticleOptions::new().category(Some(tech)).hours_ago(2)).await;
+    create_test_article(setup.articles.as_ref(), news_feed, "News", TestArticleOptions::new().hours_ago(3)).await;
+    create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Unsubscribed", TestArticleOptions::new().category(Some(tech)).hours_ago(1)).await;
+
+    setup.mark_read.execute(user_id, read.id).await.unwrap();
+
+    let counts = setup.unread_counts.execute(user_id).await.unwrap();
+    assert_eq!(counts.total, 2);
+
+    let rust = counts.feeds.iter().find(|f| f.feed_id == rust_feed).unwrap();
+    let news = counts.feeds.iter().find(|f| f.feed_id == news_feed).unwrap();
+    assert_eq!((rust.unread, news.unread), (1, 1));
+
+    let tech_count = counts.categories.iter().find(|c| c.category_id == Some(tech)).unwrap();
+    let uncategorized = counts.categories.iter().find(|c| c.category_id.is_none()).unwrap();
+    assert_eq!((tech_count.unread, uncategorized.unread), (1, 1));
+}
+
+#[tokio::test]
+async fn test_unread_counts_without_subscriptions() {
+    let setup = create_test_setup();
+    create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Orphan", TestArticleOptions::new().hours_ago(1)).await;
+
+    let counts = setup.unread_counts.execute(Uuid::new_v4()).await.unwrap();
+    assert_eq!(counts.total, 0);
+    assert!(counts.feeds.is_empty());
+}
+
+#[tokio::test]
+async fn test_read_state_changes_require_library_write_scope() {
+    let setup = create_test_setup();
+    let (auth, key) = create_test_api_key_auth(vec![ApiKeyScope::ArticlesRead]).await;
+    let controller = ReadStateController::new(
+        Arc::new(MarkArticleReadUseCase::new(setup.articles.clone(), setup.read_state_sync.clone())),
+        Arc::new(MarkArticleUnreadUseCase::new(setup.articles.clone(), setup.read_state_sync.clone())),
+        Arc::new(MarkAllReadUseCase::new(setup.articles.clone(), setup.subscriptions.clone(), setup.read_state_sync.clone())),
+        Arc::new(GetUnreadCountsUseCase::new(setup.articles.clone(), setup.subscriptions.clone())),
+        auth,
+    );
+
+    let responses = [
+        controller.mark_read(api_key_request(&key), Bytes::new()).await,
+        controller.mark_unread(api_key_request(&key), Bytes::new()).await,
+        controller.mark_all_read(api_key_request(&key), Bytes::new()).await,
+    ];
+    for response in responses {
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    let counts = controller.unread_counts(api_key_request(&key), Bytes::new()).await;
+    assert_eq!(counts.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_mark_all_read_rejects_malformed_filters() {
+    let setup = create_test_setup();
+    let (auth, key) = create_test_api_key_auth(vec![ApiKeyScope::LibraryWrite]).await;
+    let controller = ReadStateController::new(
+        Arc::new(MarkArticleReadUseCase::new(setup.articles.clone(), setup.read_state_sync.clone())),
+        Arc::new(MarkArticleUnreadUseCase::new(setup.articles.clone(), setup.read_state_sync.clone())),
+        Arc::new(MarkAllReadUseCase::new(setup.articles.clone(), setup.subscriptions.clone(), setup.read_state_sync.clone())),
+        Arc::new(GetUnreadCountsUseCase::new(setup.articles.clone(), setup.subscriptions.clone())),
+        auth,
+    );
+    let request = || {
+        TestRequest::post()
+            .insert_header(("X-API-Key", key.as_str()))
+            .insert_header(("Content-Type", "application/json"))
+            .to_http_request()
+    };
+
+    let malformed = [
+        r#"{"before": "yesterday"}"#,
+        r#"{"before": "2026-13-01T00:00:00Z"}"#,
+        r#"{"feed_id": "not-a-uuid"}"#,
+        r#"{"category_id": 42}"#,
+    ];
+    for body in malformed {
+        let response = controller.mark_all_read(request(), Bytes::from(body)).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", body);
+    }
+
+    let valid = r#"{"before": "2026-10-01T00:00:00Z", "feed_id": null}"#;
+    let response = controller.mark_all_read(request(), Bytes::from(valid)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
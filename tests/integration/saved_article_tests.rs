@@ -0,0 +1,330 @@
+use crate::common::{api_key_request, create_test_api_key_auth, create_test_article, TestArticleOptions};
+use actix_web::http::StatusCode;
+use actix_web::web::Bytes;
+use nexight_backend::application::use_cases::create_collection::{CreateCollectionError, CreateCollectionUseCase};
+use nexight_backend::application::use_cases::delete_collection::DeleteCollectionUseCase;
+use nexight_backend::application::use_cases::get_shared_collection::{GetSharedCollectionError, GetSharedCollectionUseCase};
+use nexight_backend::application::use_cases::list_collections::ListCollectionsUseCase;
+use nexight_backend::application::use_cases::list_saved_articles::ListSavedArticlesUseCase;
+use nexight_backend::application::use_cases::move_saved_article::{MoveSavedArticleError, MoveSavedArticleUseCase};
+use nexight_backend::application::use_cases::reorder_saved_articles::{ReorderSavedArticlesError, ReorderSavedArticlesUseCase};
+use nexight_backend::application::use_cases::save_article::{SaveArticleError, SaveArticleUseCase};
+use nexight_backend::application::use_cases::unsave_article::{UnsaveArticleError, UnsaveArticleUseCase};
+use nexight_backend::application::use_cases::update_collection::UpdateCollectionUseCase;
+use nexight_backend::domain::entities::api_key::ApiKeyScope;
+use nexight_backend::domain::entities::collection::{CreateCollectionDto, UpdateCollectionDto};
+use nexight_backend::domain::entities::saved_article::{MoveSavedArticleDto, ReorderSavedArticlesDto, SaveArticleDto};
+use nexight_backend::domain::repositories::article_repository::ArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_collection_repository::InMemoryCollectionRepository;
+use nexight_backend::infrastructure::repositories::in_memory_saved_article_repository::InMemorySavedArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_tag_repository::InMemoryTagRepository;
+use nexight_backend::interfaces::controllers::collection_controller::CollectionController;
+use nexight_backend::interfaces::controllers::saved_article_controller::SavedArticleController;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct TestSetup {
+    articles: Arc<InMemoryArticleRepository>,
+    saved: Arc<InMemorySavedArticleRepository>,
+    collections: Arc<InMemoryCollectionRepository>,
+    save: SaveArticleUseCase,
+    list: ListSavedArticlesUseCase,
+    move_to: MoveSavedArticleUseCase,
+    unsave: UnsaveArticleUseCase,
+    reorder: ReorderSavedArticlesUseCase,
+    create_collection: CreateCollectionUseCase,
+    update_collection: UpdateCollectionUseCase,
+    delete_collection: DeleteCollectionUseCase,
+    shared: GetSharedCollectionUseCase,
+}
+
+fn create_test_setup() -> TestSetup {
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let saved = Arc::new(InMemorySavedArticleRepository::new());
+    let collections = Arc::new(InMemoryCollectionRepository::new());
+
+    TestSetup {
+        save: SaveArticleUseCase::new(articles.clone(), saved.clone(), collections.clone()),
+        list: ListSavedArticlesUseCase::new(saved.clone(), Arc::new(InMemoryTagRepository::new())),
+        move_to: MoveSavedArticleUseCase::new(saved.clone(), collections.clone()),
+        unsave: UnsaveArticleUseCase::new(saved.clone()),
+        reorder: ReorderSavedArticlesUseCase::new(saved.clone()),
+        create_collection: CreateCollectionUseCase::new(collections.clone()),
+        update_collection: UpdateCollectionUseCase::new(collections.clone()),
+        delete_collection: DeleteCollectionUseCase::new(collections.clone(), saved.clone()),
+        shared: GetSharedCollectionUseCase::new(collections.clone(), saved.clone()),
+        articles,
+        saved,
+        collections,
+    }
+}
+
+async fn create_collection(setup: &TestSetup, user_id: Uuid, name: &str) -> Uuid {
+    let dto = CreateCollectionDto { name: name.to_string() };
+    setup.create_collection.execute(user_id, dto).await.unwrap().id
+}
+
+fn save_dto(article_id: Uuid, collection_id: Option<Uuid>) -> SaveArticleDto {
+    SaveArticleDto {
+        article_id,
+        collection_id,
+    }
+}
+
+#[tokio::test]
+async fn test_save_article_snapshots_fields() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new()).await;
+
+    let saved = setup.save.execute(user_id, save_dto(article.id, None)).await.unwrap();
+
+    assert_eq!(saved.article_id, Some(article.id));
+    assert_eq!(saved.title, "Rust Release");
+    assert_eq!(saved.link, article.link);
+    assert_eq!(saved.feed_id, article.feed_id);
+}
+
+#[tokio::test]
+async fn test_save_article_twice() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new()).await;
+
+    setup.save.execute(user_id, save_dto(article.id, None)).await.unwrap();
+    let result = setup.save.execute(user_id, save_dto(article.id, None)).await;
+    assert!(matches!(result, Err(SaveArticleError::AlreadySaved)));
+
+    // Outro usuário pode salvar o mesmo artigo
+    assert!(setup.save.execute(Uuid::new_v4(), save_dto(article.id, None)).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_save_missing_article_or_foreign_collection() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new()).await;
+    let foreign = create_collection(&setup, Uuid::new_v4(), "Not mine").await;
+
+    let missing = setup.save.execute(user_id, save_dto(Uuid::new_v4(), None)).await;
+    let other_collection = setup.save.execute(user_id, save_dto(article.id, Some(foreign))).await;
+
+    assert!(matches!(missing, Err(SaveArticleError::ArticleNotFound)));
+    assert!(matches!(other_collection, Err(SaveArticleError::CollectionNotFound)));
+}
+
+#[tokio::test]
+async fn test_saved_article_survives_article_removal() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Ephemeral", TestArticleOptions::new()).await;
+    setup.save.execute(user_id, save_dto(article.id, None)).await.unwrap();
+
+    // Remover o feed remove os artigos em cascata; o item salvo perde apenas a referência
+    setup.articles.delete(article.id).await.unwrap();
+    setup.saved.forget_article(article.id).await;
+
+    let saved = setup.list.execute(user_id, None, None).await.unwrap();
+    assert_eq!(saved.len(), 1);
+    assert_eq!(saved[0].article_id, None);
+    assert_eq!(saved[0].title, "Ephemeral");
+}
+
+#[tokio::test]
+async fn test_list_filters_by_collection_in_saved_order() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let rust = create_collection(&setup, user_id, "Rust").await;
+    let first = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "First", TestArticleOptions::new()).await;
+    let loose = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Loose", TestArticleOptions::new()).await;
+    let second = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Second", TestArticleOptions::new()).await;
+
+    setup.save.execute(user_id, save_dto(first.id, Some(rust))).await.unwrap();
+    setup.save.execute(user_id, save_dto(loose.id, None)).await.unwrap();
+    setup.save.execute(user_id, save_dto(second.id, Some(rust))).await.unwrap();
+
+    let all = setup.list.execute(user_id, None, None).await.unwrap();
+    let in_rust = setup.list.execute(user_id, Some(rust), None).await.unwrap();
+
+    let titles: Vec<&str> = all.iter().map(|s| s.title.as_str()).collect();
+    assert_eq!(titles, vec!["First", "Loose", "Second"]);
+    let titles: Vec<&str> = in_rust.iter().map(|s| s.title.as_str()).collect();
+    assert_eq!(titles, vec!["First", "Second"]);
+}
+
+#[tokio::test]
+async fn test_reorder_collection_keeps_other_items_in_place() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let rust = create_collection(&setup, user_id, "Rust").await;
+    let a = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "A", TestArticleOptions::new()).await;
+    let loose = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Loose", TestArticleOptions::new()).await;
+    let b = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "B", TestArticleOptions::new()).await;
+
+    let saved_a = setup.save.execute(user_id, save_dto(a.id, Some(rust))).await.unwrap();
+    setup.save.execute(user_id, save_dto(loose.id, None)).await.unwrap();
+    let saved_b = setup.save.execute(user_id, save_dto(b.id, Some(rust))).await.unwrap();
+
+    let dto = ReorderSavedArticlesDto {
+        collection_id: Some(rust),
+        ids: vec![saved_b.id, saved_a.id],
+    };
+    let reordered = setup.reorder.execute(user_id, dto).await.unwrap();
+    let titles: Vec<&str> = reordered.iter().map(|s| s.title.as_str()).collect();
+    assert_eq!(titles, vec!["B", "A"]);
+
+    let all = setup.list.execute(user_id, None, None).await.unwrap();
+    let titles: Vec<&str> = all.iter().map(|s| s.title.as_str()).collect();
+    assert_eq!(titles, vec!["B", "Loose", "A"]);
+}
+
+#[tokio::test]
+async fn test_reorder_requires_every_item_of_the_group() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let a = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "A", TestArticleOptions::new()).await;
+    let b = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "B", TestArticleOptions::new()).await;
+    let saved_a = setup.save.execute(user_id, save_dto(a.id, None)).await.unwrap();
+    setup.save.execute(user_id, save_dto(b.id, None)).await.unwrap();
+
+    let partial = ReorderSavedArticlesDto {
+        collection_id: None,
+        ids: vec![saved_a.id],
+    };
+    let duplicated = ReorderSavedArticlesDto {
+        collection_id: None,
+        ids: vec![saved_a.id, saved_a.id],
+    };
+
+    assert!(matches!(setup.reorder.execute(user_id, partial).await, Err(ReorderSavedArticlesError::InvalidOrder)));
+    assert!(matches!(setup.reorder.execute(user_id, duplicated).await, Err(ReorderSavedArticlesError::InvalidOrder)));
+}
+
+#[tokio::test]
+async fn test_move_and_unsave() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let rust = create_collection(&setup, user_id, "Rust").await;
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new()).await;
+    let saved = setup.save.execute(user_id, save_dto(article.id, None)).await.unwrap();
+
+    let moved = setup
+        .move_to
+        .execute(user_id, saved.id, MoveSavedArticleDto { collection_id: Some(rust) })
+        .await
+        .unwrap();
+    assert_eq!(moved.collection_id, Some(rust));
+
+    let intruder = setup
+        .move_to
+        .execute(Uuid::new_v4(), saved.id, MoveSavedArticleDto { collection_id: None })
+        .await;
+    assert!(matches!(intruder, Err(MoveSavedArticleError::NotFound)));
+
+    setup.unsave.execute(user_id, saved.id).await.unwrap();
+    let again = setup.unsave.execute(user_id, saved.id).await;
+    assert!(matches!(again, Err(UnsaveArticleError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_collection_names_are_unique_per_user() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    create_collection(&setup, user_id, "Rust").await;
+
+    let duplicated = setup
+        .create_collection
+        .execute(user_id, CreateCollectionDto { name: " Rust ".to_string() })
+        .await;
+    assert!(matches!(duplicated, Err(CreateCollectionError::NameTaken)));
+
+    let blank = setup
+        .create_collection
+        .execute(user_id, CreateCollectionDto { name: "   ".to_string() })
+        .await;
+    assert!(matches!(blank, Err(CreateCollectionError::ValidationError(_))));
+
+    create_collection(&setup, Uuid::new_v4(), "Rust").await;
+}
+
+#[tokio::test]
+async fn test_share_and_unshare_collection() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let rust = create_collection(&setup, user_id, "Rust").await;
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new()).await;
+    setup.save.execute(user_id, save_dto(article.id, Some(rust))).await.unwrap();
+
+    let shared = setup
+        .update_collection
+        .execute(user_id, rust, UpdateCollectionDto { name: None, shared: Some(true) })
+        .await
+        .unwrap();
+    let token = shared.share_token.unwrap();
+
+    let public = setup.shared.execute(&token).await.unwrap();
+    assert_eq!(public.name, "Rust");
+    assert_eq!(public.items.len(), 1);
+
+    setup
+        .update_collection
+        .execute(user_id, rust, UpdateCollectionDto { name: None, shared: Some(false) })
+        .await
+        .unwrap();
+    let revoked = setup.shared.execute(&token).await;
+    assert!(matches!(revoked, Err(GetSharedCollectionError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_delete_collection_keeps_items_saved() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let rust = create_collection(&setup, user_id, "Rust").await;
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new()).await;
+    setup.save.execute(user_id, save_dto(article.id, Some(rust))).await.unwrap();
+
+    setup.delete_collection.execute(user_id, rust).await.unwrap();
+
+    let saved = setup.list.execute(user_id, None, None).await.unwrap();
+    assert_eq!(saved.len(), 1);
+    assert_eq!(saved[0].collection_id, None);
+}
+
+#[tokio::test]
+async fn test_saved_article_changes_require_library_write_scope() {
+    let setup = create_test_setup();
+    let (auth, key) = create_test_api_key_auth(vec![ApiKeyScope::ArticlesRead]).await;
+    let saved = SavedArticleController::new(
+        Arc::new(SaveArticleUseCase::new(setup.articles.clone(), setup.saved.clone(), setup.collections.clone())),
+        Arc::new(ListSavedArticlesUseCase::new(setup.saved.clone(), Arc::new(InMemoryTagRepository::new()))),
+        Arc::new(MoveSavedArticleUseCase::new(setup.saved.clone(), setup.collections.clone())),
+        Arc::new(UnsaveArticleUseCase::new(setup.saved.clone())),
+        Arc::new(ReorderSavedArticlesUseCase::new(setup.saved.clone())),
+        auth.clone(),
+    );
+    let collections = CollectionController::new(
+        Arc::new(CreateCollectionUseCase::new(setup.collections.clone())),
+        Arc::new(ListCollectionsUseCase::new(setup.collections.clone())),
+        Arc::new(UpdateCollectionUseCase::new(setup.collections.clone())),
+        Arc::new(DeleteCollectionUseCase::new(setup.collections.clone(), setup.saved.clone())),
+        Arc::new(GetSharedCollectionUseCase::new(setup.collections.clone(), setup.saved.clone())),
+        auth,
+    );
+
+    let responses = [
+        saved.save(api_key_request(&key), Bytes::new()).await,
+        saved.move_to(api_key_request(&key), Bytes::new()).await,
+        saved.unsave(api_key_request(&key), Bytes::new()).await,
+        saved.reorder(api_key_request(&key), Bytes::new()).await,
+        collections.create(api_key_request(&key), Bytes::new()).await,
+        collections.update(api_key_request(&key), Bytes::new()).await,
+        collections.delete(api_key_request(&key), Bytes::new()).await,
+    ];
+    for response in responses {
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    let listed = saved.list(api_key_request(&key), Bytes::new()).await;
+    assert_eq!(listed.status(), StatusCode::OK);
+}
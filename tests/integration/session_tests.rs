@@ -0,0 +1,223 @@
+use crate::common::{authenticated, create_test_auth_service, create_test_user_repository, TEST_JWT_SECRET};
+use actix_web::http::StatusCode;
+use actix_web::test::TestRequest;
+use chrono::{Duration, Utc};
+use nexight_backend::application::services::api_key_service::ApiKeyService;
+use jsonwebtoken::{encode, EncodingKey, Header};
+use nexight_backend::application::services::auth_service::{AuthService, Claims, TokenType};
+use nexight_backend::application::services::login_throttle_service::LoginThrottleService;
+use nexight_backend::application::services::session_service::SessionService;
+use nexight_backend::application::use_cases::issue_stream_ticket::IssueStreamTicketUseCase;
+use nexight_backend::application::use_cases::list_sessions::ListSessionsUseCase;
+use nexight_backend::application::use_cases::login_user::{LoginContext, LoginUserUseCase};
+use nexight_backend::application::use_cases::revoke_session::{RevokeSessionError, RevokeSessionUseCase};
+use nexight_backend::domain::entities::login_throttle::LockoutPolicy;
+use nexight_backend::domain::entities::session::{Session, SessionClient};
+use nexight_backend::domain::entities::user::{LoginDto, User};
+use nexight_backend::domain::repositories::session_repository::SessionRepository;
+use nexight_backend::domain::repositories::user_repository::UserRepository;
+use nexight_backend::infrastructure::middleware::auth_middleware::AuthMiddleware;
+use nexight_backend::infrastructure::repositories::in_memory_api_key_repository::InMemoryApiKeyRepository;
+use nexight_backend::infrastructure::repositories::in_memory_login_throttle_repository::InMemoryLoginThrottleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_mfa_repository::InMemoryMfaRepository;
+use nexight_backend::infrastructure::repositories::in_memory_session_repository::InMemorySessionRepository;
+use nexight_backend::infrastructure::repositories::in_memory_user_repository::InMemoryUserRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const FIREFOX_LINUX: &str = "Mozilla/5.0 (X11; Linux x86_64; rv:131.0) Gecko/20100101 Firefox/131.0";
+
+struct TestSetup {
+    repository: Arc<InMemoryUserRepository>,
+    sessions: Arc<InMemorySessionRepository>,
+    auth_service: Arc<AuthService>,
+    login: LoginUserUseCase,
+    list: ListSessionsUseCase,
+    revoke: RevokeSessionUseCase,
+    middleware: AuthMiddleware,
+}
+
+fn create_test_setup() -> TestSetup {
+    let repository = create_test_user_repository();
+    let auth_service = create_test_auth_service();
+    let sessions = Arc::new(InMemorySessionRepository::new());
+    let session_service = Arc::new(SessionService::new(sessions.clone(), auth_service.clone()));
+    let throttle_service = Arc::new(LoginThrottleService::new(
+        Arc::new(InMemoryLoginThrottleRepository::new()),
+        LockoutPolicy::default(),
+    ));
+
+    TestSetup {
+        login: LoginUserUseCase::new(
+            repository.clone(),
+            auth_service.clone(),
+            throttle_service,
+            Arc::new(InMemoryMfaRepository::new()),
+            Duration::minutes(5),
+            session_service.clone(),
+        ),
+        list: ListSessionsUseCase::new(sessions.clone()),
+        revoke: RevokeSessionUseCase::new(sessions.clone()),
+        middleware: AuthMiddleware::new(
+            auth_service.clone(),
+            repository.clone(),
+            Arc::new(ApiKeyService::new(Arc::new(InMemoryApiKeyRepository::new()))),
+            session_service,
+        ),
+        repository,
+        sessions,
+        auth_service,
+    }
+}
+
+async fn create_test_user(setup: &TestSetup) -> User {
+    let password_hash = setup.auth_service.hash_password("password123").unwrap();
+    let user = User::new("reader@example.com".to_string(), password_hash, "Reader".to_string());
+    setup.repository.create(user).await.unwrap()
+}
+
+async fn login(setup: &TestSetup, user_agent: &str, device: Option<&str>) -> String {
+    let context = LoginContext {
+        ip_address: Some("203.0.113.7".to_string()),
+        user_agent: Some(user_agent.to_string()),
+        device_label: device.map(|d| d.to_string()),
+    };
+    let dto = LoginDto {
+        email: "reader@example.com".to_string(),
+        password: "password123".to_string(),
+    };
+
+    let outcome = setup.login.execute(dto, context).await.unwrap();
+    authenticated(outcome).unwrap().token
+}
+
+fn bearer(token: &str) -> actix_web::HttpRequest {
+    TestRequest::default()
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_http_request()
+}
+
+#[tokio::test]
+async fn test_login_binds_token_to_a_session() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+
+    let token = login(&setup, FIREFOX_LINUX, None).await;
+    let claims = setup.auth_service.verify_token(&token).unwrap();
+    let session_id = Uuid::parse_str(&claims.sid.unwrap()).unwrap();
+
+    let session = setup.sessions.find_by_id(session_id).await.unwrap().unwrap();
+    assert_eq!(session.user_id, user.id);
+    assert_eq!(session.device_label.as_deref(), Some("Firefox on Linux"));
+    assert_eq!(session.user_agent.as_deref(), Some(FIREFOX_LINUX));
+    assert_eq!(session.ip_address.as_deref(), Some("203.0.113.7"));
+}
+
+#[tokio::test]
+async fn test_list_sessions_marks_current_device() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+
+    let laptop = login(&setup, FIREFOX_LINUX, Some("Work laptop")).await;
+    login(&setup, "curl/8.5.0", None).await;
+
+    let (_, current) = setup.middleware.require_session(&bearer(&laptop)).await.unwrap();
+    let sessions = setup.list.execute(user.id, current).await.unwrap();
+
+    assert_eq!(sessions.len(), 2);
+    let current_sessions: Vec<_> = sessions.iter().filter(|s| s.current).collect();
+    assert_eq!(current_sessions.len(), 1);
+    assert_eq!(current_sessions[0].device_label.as_deref(), Some("Work laptop"));
+}
+
+#[tokio::test]
+async fn test_revoked_session_token_is_rejected() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+
+    let phone = login(&setup, "Mozilla/5.0 (Linux; Android 14) Chrome/130.0 Mobile", None).await;
+    let laptop = login(&setup, FIREFOX_LINUX, None).await;
+
+    let (_, phone_session) = setup.middleware.require_session(&bearer(&phone)).await.unwrap();
+    setup.revoke.execute(user.id, phone_session.unwrap()).await.unwrap();
+
+    let rejected = setup.middleware.require_auth(&bearer(&phone)).await.unwrap_err();
+    assert_eq!(rejected.status(), StatusCode::UNAUTHORIZED);
+    assert!(setup.middleware.require_auth(&bearer(&laptop)).await.is_ok());
+
+    let sessions = setup.list.execute(user.id, None).await.unwrap();
+    assert_eq!(sessions.len(), 1);
+}
+
+#[tokio::test]
+async fn test_stream_ticket_follows_its_session() {
+    let setup = create_test_setup();
+    create_test_user(&setup).await;
+    let token = login(&setup, FIREFOX_LINUX, None).await;
+    let (user, session_id) = setup.middleware.require_user_session(&bearer(&token)).await.unwrap();
+
+    let ticket = IssueStreamTicketUseCase::new(setup.auth_service.clone())
+        .execute(&user, session_id)
+        .unwrap()
+        .ticket;
+    let (ticket_user, ticket_session) = setup.middleware.authenticate_stream_ticket(&ticket).await.unwrap();
+    assert_eq!(ticket_user.id, user.id);
+    assert_eq!(ticket_session, session_id);
+    // Um ticket não é um access token
+    assert!(setup.middleware.require_auth(&bearer(&ticket)).await.is_err());
+
+    setup.revoke.execute(user.id, session_id.unwrap()).await.unwrap();
+    assert!(setup.middleware.authenticate_stream_ticket(&ticket).await.is_err());
+    assert!(setup.middleware.reauthorize(user.id, session_id).await.is_err());
+}
+
+#[tokio::test]
+async fn test_revoke_only_own_sessions() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+    let token = login(&setup, FIREFOX_LINUX, None).await;
+    let (_, session_id) = setup.middleware.require_session(&bearer(&token)).await.unwrap();
+    let session_id = session_id.unwrap();
+
+    let other = setup.revoke.execute(Uuid::new_v4(), session_id).await;
+    assert!(matches!(other, Err(RevokeSessionError::NotFound)));
+
+    setup.revoke.execute(user.id, session_id).await.unwrap();
+    let again = setup.revoke.execute(user.id, session_id).await;
+    assert!(matches!(again, Err(RevokeSessionError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_expired_sessions_are_not_listed_and_are_cleaned_on_login() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+
+    let expired = Session::new(user.id, SessionClient::default(), Utc::now() - Duration::hours(1));
+    setup.sessions.create(expired.clone()).await.unwrap();
+    assert!(setup.list.execute(user.id, None).await.unwrap().is_empty());
+
+    login(&setup, FIREFOX_LINUX, None).await;
+    assert!(setup.sessions.find_by_id(expired.id).await.unwrap().is_none());
+    assert_eq!(setup.list.execute(user.id, None).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_tokens_without_session_remain_valid() {
+    // Tokens emitidos antes das sessões não têm `sid` e valem até expirar
+    let setup = create_test_setup();
+    let user = create_test_user(&setup).await;
+    let now = Utc::now();
+    let claims = Claims {
+        sub: user.id.to_string(),
+        email: user.email.clone(),
+        exp: (now + Duration::hours(1)).timestamp(),
+        iat: now.timestamp(),
+        token_type: TokenType::Access,
+        sid: None,
+    };
+    let token = encode(&Header::default(), &claims, &EncodingKey::from_secret(TEST_JWT_SECRET.as_bytes())).unwrap();
+
+    let (user_id, session_id) = setup.middleware.require_session(&bearer(&token)).await.unwrap();
+    assert_eq!(user_id, user.id);
+    assert!(session_id.is_none());
+}
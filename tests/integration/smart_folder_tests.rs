@@ -0,0 +1,309 @@
+use crate::common::{api_key_request, create_test_api_key_auth, create_test_article, TestArticleOptions};
+use actix_web::http::StatusCode;
+use actix_web::web::Bytes;
+use chrono::Utc;
+use nexight_backend::application::use_cases::create_smart_folder::{CreateSmartFolderError, CreateSmartFolderUseCase};
+use nexight_backend::application::use_cases::delete_smart_folder::{DeleteSmartFolderError, DeleteSmartFolderUseCase};
+use nexight_backend::application::use_cases::list_smart_folder_articles::{
+    ListSmartFolderArticlesError, ListSmartFolderArticlesUseCase,
+};
+use nexight_backend::application::use_cases::list_smart_folders::ListSmartFoldersUseCase;
+use nexight_backend::application::use_cases::list_timeline::ListTimelineUseCase;
+use nexight_backend::application::use_cases::merge_tags::MergeTagsUseCase;
+use nexight_backend::application::use_cases::update_smart_folder::{UpdateSmartFolderError, UpdateSmartFolderUseCase};
+use nexight_backend::domain::entities::api_key::ApiKeyScope;
+use nexight_backend::domain::entities::article::{ArticleSort, PaginatedArticles};
+use nexight_backend::domain::entities::article_category::ArticleCategory;
+use nexight_backend::domain::entities::smart_folder::{CreateSmartFolderDto, SmartFolderCriteria, UpdateSmartFolderDto};
+use nexight_backend::domain::entities::subscription::{CreateSubscriptionDto, Subscription};
+use nexight_backend::domain::entities::tag::{MergeTagDto, Tag};
+use nexight_backend::domain::repositories::article_category_repository::ArticleCategoryRepository;
+use nexight_backend::domain::repositories::article_repository::ArticleRepository;
+use nexight_backend::domain::repositories::subscription_repository::SubscriptionRepository;
+use nexight_backend::domain::repositories::tag_repository::TagRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_category_repository::InMemoryArticleCategoryRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_smart_folder_repository::InMemorySmartFolderRepository;
+use nexight_backend::infrastructure::repositories::in_memory_subscription_repository::InMemorySubscriptionRepository;
+use nexight_backend::infrastructure::repositories::in_memory_tag_repository::InMemoryTagRepository;
+use nexight_backend::interfaces::controllers::smart_folder_controller::SmartFolderController;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct TestSetup {
+    articles: Arc<InMemoryArticleRepository>,
+    subscriptions: Arc<InMemorySubscriptionRepository>,
+    categories: Arc<InMemoryArticleCategoryRepository>,
+    tags: Arc<InMemoryTagRepository>,
+    folders: Arc<InMemorySmartFolderRepository>,
+    timeline: Arc<ListTimelineUseCase>,
+    create: CreateSmartFolderUseCase,
+    list: ListSmartFoldersUseCase,
+    update: UpdateSmartFolderUseCase,
+    delete: DeleteSmartFolderUseCase,
+    folder_articles: ListSmartFolderArticlesUseCase,
+    merge_tags: MergeTagsUseCase,
+}
+
+fn create_test_setup() -> TestSetup {
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let subscriptions = Arc::new(InMemorySubscriptionRepository::new());
+    let categories = Arc::new(InMemoryArticleCategoryRepository::new());
+    let tags = Arc::new(InMemoryTagRepository::new());
+    let folders = Arc::new(InMemorySmartFolderRepository::new());
+    let timeline = Arc::new(ListTimelineUseCase::new(articles.clone(), subscriptions.clone(), tags.clone()));
+
+    TestSetup {
+        create: CreateSmartFolderUseCase::new(folders.clone(), tags.clone(), categories.clone()),
+        list: ListSmartFoldersUseCase::new(folders.clone(), timeline.clone()),
+        update: UpdateSmartFolderUseCase::new(folders.clone(), tags.clone(), categories.clone()),
+        delete: DeleteSmartFolderUseCase::new(folders.clone()),
+        folder_articles: ListSmartFolderArticlesUseCase::new(folders.clone(), timeline.clone()),
+        merge_tags: MergeTagsUseCase::new(tags.clone(), folders.clone()),
+        articles,
+        subscriptions,
+        categories,
+        tags,
+        folders,
+        timeline,
+    }
+}
+
+async fn subscribe(setup: &TestSetup, user_id: Uuid, feed_id: Uuid) {
+    let dto = CreateSubscriptionDto {
+        feed_id,
+        title: None,
+        folder: None,
+        weight: None,
+    };
+    setup.subscriptions.create(Subscription::new(user_id, dto)).await.unwrap();
+}
+
+fn folder(name: &str, criteria: SmartFolderCriteria) -> CreateSmartFolderDto {
+    CreateSmartFolderDto {
+        name: name.to_string(),
+        criteria,
+    }
+}
+
+fn titles(page: &PaginatedArticles) -> Vec<&str> {
+    page.articles.iter().map(|article| article.title.as_str()).collect()
+}
+
+#[tokio::test]
+async fn test_smart_folder_articles_apply_saved_search_filter_and_sort() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    subscribe(&setup, user_id, feed_id).await;
+
+    create_test_article(setup.articles.as_ref(), feed_id, "Rust Async Basics", TestArticleOptions::new().rate(Some(6)).hours_ago(3)).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Advanced RUST Macros", TestArticleOptions::new().rate(Some(9)).hours_ago(1)).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Rust Weekly", TestArticleOptions::new().rate(Some(2)).hours_ago(0)).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Go Generics", TestArticleOptions::new().rate(Some(10)).hours_ago(0)).await;
+    create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Elsewhere", TestArticleOptions::new().rate(Some(10)).hours_ago(0)).await;
+
+    let criteria = SmartFolderCriteria {
+        search: Some("  rust ".to_string()),
+        min_rate: Some(5),
+        sort: ArticleSort::Rate,
+        ..Default::default()
+    };
+    let created = setup.create.execute(user_id, folder("Rust", criteria)).await.unwrap();
+    assert_eq!(created.criteria.search.as_deref(), Some("rust"));
+    assert!(created.unread.is_none());
+
+    let page = setup.folder_articles.execute(user_id, created.id, 1, 20).await.unwrap();
+
+    // Fora dos feeds assinados, abaixo da nota mínima ou sem o texto buscado ficam de fora
+    assert_eq!(titles(&page), vec!["Advanced RUST Macros", "Rust Async Basics"]);
+    assert_eq!(page.total, 2);
+}
+
+#[tokio::test]
+async fn test_list_smart_folders_counts_unread_articles() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    subscribe(&setup, user_id, feed_id).await;
+
+    let read = create_test_article(setup.articles.as_ref(), feed_id, "Rust One", TestArticleOptions::new().hours_ago(2)).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Rust Two", TestArticleOptions::new().hours_ago(1)).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Python", TestArticleOptions::new().hours_ago(0)).await;
+    setup.articles.mark_read(user_id, read.id, Utc::now()).await.unwrap();
+
+    let search = SmartFolderCriteria {
+        search: Some("rust".to_string()),
+        ..Default::default()
+    };
+    setup.create.execute(user_id, folder("Rust", search)).await.unwrap();
+    setup.create.execute(user_id, folder("Everything", SmartFolderCriteria::default())).await.unwrap();
+
+    let folders = setup.list.execute(user_id).await.unwrap();
+
+    let summary: Vec<(&str, Option<i64>)> = folders.iter().map(|f| (f.name.as_str(), f.unread)).collect();
+    assert_eq!(summary, vec![("Everything", Some(2)), ("Rust", Some(1))]);
+
+    // A pasta sem `unread` continua mostrando os lidos
+    let rust = folders.iter().find(|f| f.name == "Rust").unwrap();
+    let page = setup.folder_articles.execute(user_id, rust.id, 1, 20).await.unwrap();
+    assert_eq!(page.total, 2);
+}
+
+#[tokio::test]
+async fn test_smart_folder_by_category_and_tag() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    subscribe(&setup, user_id, feed_id).await;
+
+    let category = setup.categories.create(ArticleCategory::new("Tech".to_string())).await.unwrap();
+    let tag = setup.tags.create(Tag::new(user_id, "later")).await.unwrap();
+
+    let mut in_category = create_test_article(setup.articles.as_ref(), feed_id, "Tagged Tech", TestArticleOptions::new().hours_ago(1)).await;
+    in_category.category_id = Some(category.id);
+    let in_category = setup.articles.update(in_category).await.unwrap();
+    let mut untagged = create_test_article(setup.articles.as_ref(), feed_id, "Untagged Tech", TestArticleOptions::new().hours_ago(0)).await;
+    untagged.category_id = Some(category.id);
+    setup.articles.update(untagged).await.unwrap();
+    let other = create_test_article(setup.articles.as_ref(), feed_id, "Tagged Other", TestArticleOptions::new().hours_ago(0)).await;
+    setup.tags.set_article_tags(user_id, in_category.id, &[tag.id]).await.unwrap();
+    setup.tags.set_article_tags(user_id, other.id, &[tag.id]).await.unwrap();
+
+    let criteria = SmartFolderCriteria {
+        category_id: Some(category.id),
+        tag_id: Some(tag.id),
+        ..Default::default()
+    };
+    let created = setup.create.execute(user_id, folder("Tech later", criteria)).await.unwrap();
+
+    let page = setup.folder_articles.execute(user_id, created.id, 1, 20).await.unwrap();
+    assert_eq!(titles(&page), vec!["Tagged Tech"]);
+}
+
+#[tokio::test]
+async fn test_create_smart_folder_validates_references_and_name() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let other_user_tag = setup.tags.create(Tag::new(Uuid::new_v4(), "theirs")).await.unwrap();
+
+    let result = setup
+        .create
+        .execute(user_id, folder("Theirs", SmartFolderCriteria { tag_id: Some(other_user_tag.id), ..Default::default() }))
+        .await;
+    assert!(matches!(result, Err(CreateSmartFolderError::TagNotFound)));
+
+    let result = setup
+        .create
+        .execute(user_id, folder("Nowhere", SmartFolderCriteria { category_id: Some(Uuid::new_v4()), ..Default::default() }))
+        .await;
+    assert!(matches!(result, Err(CreateSmartFolderError::CategoryNotFound)));
+
+    let result = setup
+        .create
+        .execute(user_id, folder("Rated", SmartFolderCriteria { min_user_rating: Some(11.0), ..Default::default() }))
+        .await;
+    assert!(matches!(result, Err(CreateSmartFolderError::ValidationError(_))));
+
+    let result = setup.create.execute(user_id, folder("   ", SmartFolderCriteria::default())).await;
+    assert!(matches!(result, Err(CreateSmartFolderError::ValidationError(_))));
+
+    setup.create.execute(user_id, folder("Inbox", SmartFolderCriteria::default())).await.unwrap();
+    let result = setup.create.execute(user_id, folder(" Inbox ", SmartFolderCriteria::default())).await;
+    assert!(matches!(result, Err(CreateSmartFolderError::NameTaken)));
+}
+
+#[tokio::test]
+async fn test_update_replaces_criteria_and_delete_removes_folder() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    subscribe(&setup, user_id, feed_id).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Rust News", TestArticleOptions::new().hours_ago(1)).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Go News", TestArticleOptions::new().hours_ago(0)).await;
+
+    let rust = SmartFolderCriteria {
+        search: Some("rust".to_string()),
+        ..Default::default()
+    };
+    let created = setup.create.execute(user_id, folder("Lang", rust)).await.unwrap();
+
+    let dto = UpdateSmartFolderDto {
+        name: Some("Go".to_string()),
+        criteria: Some(SmartFolderCriteria {
+            search: Some("go".to_string()),
+            ..Default::default()
+        }),
+    };
+    let updated = setup.update.execute(user_id, created.id, dto).await.unwrap();
+    assert_eq!(updated.name, "Go");
+
+    let page = setup.folder_articles.execute(user_id, created.id, 1, 20).await.unwrap();
+    assert_eq!(titles(&page), vec!["Go News"]);
+
+    // Pastas de outros usuários não são visíveis
+    let stranger = Uuid::new_v4();
+    let result = setup.update.execute(stranger, created.id, UpdateSmartFolderDto::default()).await;
+    assert!(matches!(result, Err(UpdateSmartFolderError::NotFound)));
+    let result = setup.folder_articles.execute(stranger, created.id, 1, 20).await;
+    assert!(matches!(result, Err(ListSmartFolderArticlesError::NotFound)));
+    let result = setup.folder_articles.execute(user_id, created.id, 1, 0).await;
+    assert!(matches!(result, Err(ListSmartFolderArticlesError::InvalidPagination)));
+
+    setup.delete.execute(user_id, created.id).await.unwrap();
+    assert!(setup.list.execute(user_id).await.unwrap().is_empty());
+    let result = setup.delete.execute(user_id, created.id).await;
+    assert!(matches!(result, Err(DeleteSmartFolderError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_merging_tags_keeps_smart_folders_pointing_at_target() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    subscribe(&setup, user_id, feed_id).await;
+
+    let source = setup.tags.create(Tag::new(user_id, "ml")).await.unwrap();
+    let target = setup.tags.create(Tag::new(user_id, "machine-learning")).await.unwrap();
+    let article = create_test_article(setup.articles.as_ref(), feed_id, "Transformers", TestArticleOptions::new().hours_ago(0)).await;
+    setup.tags.set_article_tags(user_id, article.id, &[source.id]).await.unwrap();
+
+    let criteria = SmartFolderCriteria {
+        tag_id: Some(source.id),
+        ..Default::default()
+    };
+    let created = setup.create.execute(user_id, folder("ML", criteria)).await.unwrap();
+
+    setup.merge_tags.execute(user_id, source.id, MergeTagDto { into: target.id }).await.unwrap();
+
+    let folders = setup.list.execute(user_id).await.unwrap();
+    assert_eq!(folders[0].criteria.tag_id, Some(target.id));
+    let page = setup.folder_articles.execute(user_id, created.id, 1, 20).await.unwrap();
+    assert_eq!(titles(&page), vec!["Transformers"]);
+}
+
+#[tokio::test]
+async fn test_smart_folder_changes_require_library_write_scope() {
+    let setup = create_test_setup();
+    let (auth, key) = create_test_api_key_auth(vec![ApiKeyScope::ArticlesRead]).await;
+    let controller = SmartFolderController::new(
+        Arc::new(CreateSmartFolderUseCase::new(setup.folders.clone(), setup.tags.clone(), setup.categories.clone())),
+        Arc::new(ListSmartFoldersUseCase::new(setup.folders.clone(), setup.timeline.clone())),
+        Arc::new(UpdateSmartFolderUseCase::new(setup.folders.clone(), setup.tags.clone(), setup.categories.clone())),
+        Arc::new(DeleteSmartFolderUseCase::new(setup.folders.clone())),
+        Arc::new(ListSmartFolderArticlesUseCase::new(setup.folders.clone(), setup.timeline.clone())),
+        auth,
+    );
+
+    let responses = [
+        controller.create(api_key_request(&key), Bytes::new()).await,
+        controller.update(api_key_request(&key), Bytes::new()).await,
+        controller.delete(api_key_request(&key), Bytes::new()).await,
+    ];
+    for response in responses {
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    let listed = controller.list(api_key_request(&key), Bytes::new()).await;
+    assert_eq!(listed.status(), StatusCode::OK);
+}
@@ -0,0 +1,211 @@
+use crate::common::{create_test_article, TestArticleOptions};
+use nexight_backend::application::use_cases::list_subscriptions::ListSubscriptionsUseCase;
+use nexight_backend::application::use_cases::list_timeline::{ListTimelineError, ListTimelineUseCase};
+use nexight_backend::application::use_cases::subscribe_feed::{SubscribeFeedError, SubscribeFeedUseCase};
+use nexight_backend::application::use_cases::unsubscribe_feed::{UnsubscribeFeedError, UnsubscribeFeedUseCase};
+use nexight_backend::application::use_cases::update_subscription::{UpdateSubscriptionError, UpdateSubscriptionUseCase};
+use nexight_backend::domain::entities::subscription::{CreateSubscriptionDto, UpdateSubscriptionDto};
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_subscription_repository::InMemorySubscriptionRepository;
+use nexight_backend::infrastructure::repositories::in_memory_tag_repository::InMemoryTagRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct TestSetup {
+    articles: Arc<InMemoryArticleRepository>,
+    subscribe: SubscribeFeedUseCase,
+    list: ListSubscriptionsUseCase,
+    update: UpdateSubscriptionUseCase,
+    unsubscribe: UnsubscribeFeedUseCase,
+    timeline: ListTimelineUseCase,
+}
+
+fn create_test_setup() -> TestSetup {
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let subscriptions = Arc::new(InMemorySubscriptionRepository::new());
+
+    TestSetup {
+        subscribe: SubscribeFeedUseCase::new(subscriptions.clone()),
+        list: ListSubscriptionsUseCase::new(subscriptions.clone()),
+        update: UpdateSubscriptionUseCase::new(subscriptions.clone()),
+        unsubscribe: UnsubscribeFeedUseCase::new(subscriptions.clone()),
+        timeline: ListTimelineUseCase::new(articles.clone(), subscriptions, Arc::new(InMemoryTagRepository::new())),
+        articles,
+    }
+}
+
+fn subscription_dto(feed_id: Uuid, title: Option<&str>, folder: Option<&str>) -> CreateSubscriptionDto {
+    CreateSubscriptionDto {
+        feed_id,
+        title: title.map(|t| t.to_string()),
+        folder: folder.map(|f| f.to_string()),
+        weight: None,
+    }
+}
+
+#[tokio::test]
+async fn test_subscribe_with_title_and_folder() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+
+    let subscription = setup
+        .subscribe
+        .execute(user_id, subscription_dto(feed_id, Some("  Rust Blog "), Some("Tech")))
+        .await
+        .unwrap();
+
+    assert_eq!(subscription.feed_id, feed_id);
+    assert_eq!(subscription.title.as_deref(), Some("Rust Blog"));
+    assert_eq!(subscription.folder.as_deref(), Some("Tech"));
+}
+
+#[tokio::test]
+async fn test_subscribe_twice_to_same_feed() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+
+    setup.subscribe.execute(user_id, subscription_dto(feed_id, None, None)).await.unwrap();
+    let result = setup.subscribe.execute(user_id, subscription_dto(feed_id, None, None)).await;
+
+    assert!(matches!(result, Err(SubscribeFeedError::AlreadySubscribed)));
+
+    // Outro usuário pode assinar o mesmo feed
+    let other = setup.subscribe.execute(Uuid::new_v4(), subscription_dto(feed_id, None, None)).await;
+    assert!(other.is_ok());
+}
+
+#[tokio::test]
+async fn test_subscribe_rejects_long_folder() {
+    let setup = create_test_setup();
+
+    let folder = "a".repeat(101);
+    let result = setup
+        .subscribe
+        .execute(Uuid::new_v4(), subscription_dto(Uuid::new_v4(), None, Some(&folder)))
+        .await;
+
+    assert!(matches!(result, Err(SubscribeFeedError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_list_only_own_subscriptions_grouped_by_folder() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+
+    setup.subscribe.execute(user_id, subscription_dto(Uuid::new_v4(), Some("Loose"), None)).await.unwrap();
+    setup.subscribe.execute(user_id, subscription_dto(Uuid::new_v4(), Some("News"), Some("World"))).await.unwrap();
+    setup.subscribe.execute(user_id, subscription_dto(Uuid::new_v4(), Some("Rust"), Some("Tech"))).await.unwrap();
+    setup.subscribe.execute(Uuid::new_v4(), subscription_dto(Uuid::new_v4(), None, None)).await.unwrap();
+
+    let subscriptions = setup.list.execute(user_id).await.unwrap();
+
+    let folders: Vec<Option<&str>> = subscriptions.iter().map(|s| s.folder.as_deref()).collect();
+    assert_eq!(folders, vec![Some("Tech"), Some("World"), None]);
+}
+
+#[tokio::test]
+async fn test_update_moves_and_renames_subscription() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let subscription = setup
+        .subscribe
+        .execute(user_id, subscription_dto(Uuid::new_v4(), Some("Old"), Some("Inbox")))
+        .await
+        .unwrap();
+
+    let updated = setup
+        .update
+        .execute(
+            user_id,
+            subscription.id,
+            UpdateSubscriptionDto {
+                title: Some("New".to_string()),
+                folder: Some(String::new()),
+                weight: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(updated.title.as_deref(), Some("New"));
+    assert_eq!(updated.folder, None);
+}
+
+#[tokio::test]
+async fn test_cannot_update_or_unsubscribe_other_users_subscription() {
+    let setup = create_test_setup();
+    let subscription = setup
+        .subscribe
+        .execute(Uuid::new_v4(), subscription_dto(Uuid::new_v4(), None, None))
+        .await
+        .unwrap();
+    let intruder = Uuid::new_v4();
+
+    let update = setup
+        .update
+        .execute(intruder, subscription.id, UpdateSubscriptionDto::default())
+        .await;
+    let unsubscribe = setup.unsubscribe.execute(intruder, subscription.id).await;
+
+    assert!(matches!(update, Err(UpdateSubscriptionError::NotFound)));
+    assert!(matches!(unsubscribe, Err(UnsubscribeFeedError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_timeline_only_has_subscribed_feeds() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let rust_feed = Uuid::new_v4();
+    let news_feed = Uuid::new_v4();
+    let other_feed = Uuid::new_v4();
+
+    create_test_article(setup.articles.as_ref(), rust_feed, "Rust Release", TestArticleOptions::new().hours_ago(3)).await;
+    create_test_article(setup.articles.as_ref(), news_feed, "Breaking News", TestArticleOptions::new().hours_ago(1)).await;
+    create_test_article(setup.articles.as_ref(), other_feed, "Unrelated", TestArticleOptions::new().hours_ago(2)).await;
+
+    setup.subscribe.execute(user_id, subscription_dto(rust_feed, None, None)).await.unwrap();
+    setup.subscribe.execute(user_id, subscription_dto(news_feed, None, None)).await.unwrap();
+
+    let timeline = setup.timeline.execute(user_id, false, 1, 20).await.unwrap();
+
+    let titles: Vec<&str> = timeline.articles.iter().map(|a| a.title.as_str()).collect();
+    assert_eq!(titles, vec!["Breaking News", "Rust Release"]);
+    assert_eq!(timeline.total, 2);
+    assert_eq!(timeline.total_pages, 1);
+}
+
+#[tokio::test]
+async fn test_timeline_is_empty_without_subscriptions() {
+    let setup = create_test_setup();
+    create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Global Article", TestArticleOptions::new().hours_ago(1)).await;
+
+    let timeline = setup.timeline.execute(Uuid::new_v4(), false, 1, 20).await.unwrap();
+
+    assert!(timeline.articles.is_empty());
+    assert_eq!(timeline.total, 0);
+}
+
+#[tokio::test]
+async fn test_timeline_drops_feed_after_unsubscribe() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    create_test_article(setup.articles.as_ref(), feed_id, "Feed Article", TestArticleOptions::new().hours_ago(1)).await;
+    let subscription = setup.subscribe.execute(user_id, subscription_dto(feed_id, None, None)).await.unwrap();
+
+    setup.unsubscribe.execute(user_id, subscription.id).await.unwrap();
+    let timeline = setup.timeline.execute(user_id, false, 1, 20).await.unwrap();
+
+    assert!(timeline.articles.is_empty());
+}
+
+#[tokio::test]
+async fn test_timeline_invalid_pagination() {
+    let setup = create_test_setup();
+
+    let result = setup.timeline.execute(Uuid::new_v4(), false, 0, 20).await;
+
+    assert!(matches!(result, Err(ListTimelineError::InvalidPagination)));
+}
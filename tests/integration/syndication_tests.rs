@@ -0,0 +1,179 @@
+use crate::common::{create_test_article, TestArticleOptions};
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpRequest};
+use chrono::{Duration, Utc};
+use nexight_backend::application::use_cases::get_category_feed::{GetCategoryFeedError, GetCategoryFeedUseCase};
+use nexight_backend::application::use_cases::get_collection_feed::{GetCollectionFeedError, GetCollectionFeedUseCase};
+use nexight_backend::domain::entities::article_category::ArticleCategory;
+use nexight_backend::domain::entities::collection::Collection;
+use nexight_backend::domain::entities::saved_article::SavedArticle;
+use nexight_backend::domain::entities::syndication::FeedFormat;
+use nexight_backend::domain::repositories::article_category_repository::ArticleCategoryRepository;
+use nexight_backend::domain::repositories::collection_repository::CollectionRepository;
+use nexight_backend::domain::repositories::saved_article_repository::SavedArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_category_repository::InMemoryArticleCategoryRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_collection_repository::InMemoryCollectionRepository;
+use nexight_backend::infrastructure::repositories::in_memory_saved_article_repository::InMemorySavedArticleRepository;
+use nexight_backend::interfaces::controllers::syndication_controller::SyndicationController;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct TestSetup {
+    articles: Arc<InMemoryArticleRepository>,
+    categories: Arc<InMemoryArticleCategoryRepository>,
+    collections: Arc<InMemoryCollectionRepository>,
+    saved: Arc<InMemorySavedArticleRepository>,
+    category_feed: Arc<GetCategoryFeedUseCase>,
+    collection_feed: Arc<GetCollectionFeedUseCase>,
+}
+
+fn create_test_setup() -> TestSetup {
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let categories = Arc::new(InMemoryArticleCategoryRepository::new());
+    let collections = Arc::new(InMemoryCollectionRepository::new());
+    let saved = Arc::new(InMemorySavedArticleRepository::new());
+
+    TestSetup {
+        category_feed: Arc::new(GetCategoryFeedUseCase::new(categories.clone(), articles.clone())),
+        collection_feed: Arc::new(GetCollectionFeedUseCase::new(collections.clone(), saved.clone(), articles.clone())),
+        articles,
+        categories,
+        collections,
+        saved,
+    }
+}
+
+async fn create_shared_collection(setup: &TestSetup, token: &str) -> Collection {
+    let mut collection = Collection::new(Uuid::new_v4(), "Leituras");
+    collection.share(token.to_string());
+    setup.collections.create(collection).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_category_feed_lists_only_the_category_with_ai_summaries() {
+    let setup = create_test_setup();
+    let tech = setup.categories.create(ArticleCategory::new("Tech".to_string())).await.unwrap();
+    create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust 2026", TestArticleOptions::new().category(Some(tech.id)).ai_summary(Some("Resumo do Rust"))).await;
+    create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Football", TestArticleOptions::new().category(Some(Uuid::new_v4()))).await;
+
+    let feed = setup.category_feed.execute(tech.id).await.unwrap();
+
+    assert_eq!(feed.title, "Nexight: Tech");
+    assert_eq!(feed.items.len(), 1);
+    assert_eq!(feed.items[0].summary, "Resumo do Rust");
+    assert_eq!(feed.items[0].tags, vec!["Tech"]);
+    assert_eq!(feed.updated, feed.items[0].updated);
+
+    let missing = setup.category_feed.execute(Uuid::new_v4()).await;
+    assert!(matches!(missing, Err(GetCategoryFeedError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_collection_feed_uses_live_articles_and_falls_back_to_saved_copies() {
+    let setup = create_test_setup();
+    let collection = create_shared_collection(&setup, "share-token").await;
+
+    let live = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Live", TestArticleOptions::new().ai_summary(Some("Resumo vivo"))).await;
+    let removed = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Removed", TestArticleOptions::new()).await;
+
+    let mut older = SavedArticle::from_article(collection.user_id, &removed, Some(collection.id), 0);
+    older.article_id = None;
+    older.created_at = Utc::now() - Duration::days(1);
+    setup.saved.create(older).await.unwrap();
+    setup.saved.create(SavedArticle::from_article(collection.user_id, &live, Some(collection.id), 1)).await.unwrap();
+
+    let feed = setup.collection_feed.execute("share-token").await.unwrap();
+
+    assert_eq!(feed.title, "Leituras");
+    assert_eq!(feed.items.len(), 2);
+    assert_eq!(feed.items[0].id, live.id);
+    assert_eq!(feed.items[0].summary, "Resumo vivo");
+    assert_eq!(feed.items[1].title, "Removed");
+    assert!(feed.items[1].content.is_empty());
+
+    let missing = setup.collection_feed.execute("unknown").await;
+    assert!(matches!(missing, Err(GetCollectionFeedError::NotFound)));
+}
+
+#[actix_web::test]
+async fn test_feed_routes_set_cache_headers_and_answer_conditional_requests() {
+    let setup = create_test_setup();
+    let tech = setup.categories.create(ArticleCategory::new("Tech".to_string())).await.unwrap();
+    create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust 2026", TestArticleOptions::new().category(Some(tech.id))).await;
+
+    let controller = Arc::new(SyndicationController::new(setup.category_feed.clone(), setup.collection_feed.clone(), 600));
+    let app = test::init_service(
+        App::new()
+            .route("/categories/{id}/feed.xml", web::get().to({
+                let ctrl = controller.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.category_feed(req, body, FeedFormat::Rss).await }
+                }
+            }))
+            .route("/categories/{id}/feed.json", web::get().to({
+                let ctrl = controller.clone();
+                move |req: HttpRequest, body: web::Bytes| {
+                    let controller = ctrl.clone();
+                    async move { controller.category_feed(req, body, FeedFormat::Json).await }
+                }
+            })),
+    )
+    .await;
+
+    let path = format!("/categories/{}/feed.xml", tech.id);
+    let response = test::call_service(&app, test::TestRequest::get().uri(&path).to_request()).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let header = |name: &str| response.headers().get(name).unwrap().to_str().unwrap().to_string();
+    assert_eq!(header("Content-Type"), "application/rss+xml; charset=utf-8");
+    assert_eq!(header("Cache-Control"), "public, max-age=600");
+    let etag = header("ETag");
+    let last_modified = header("Last-Modified");
+    assert!(last_modified.ends_with(" GMT"));
+    let body = test::read_body(response).await;
+    assert!(String::from_utf8_lossy(&body).contains(&format!("http://localhost:8080{}", path)));
+
+    let cached = test::TestRequest::get().uri(&path).insert_header(("If-None-Match", etag.clone())).to_request();
+    let response = test::call_service(&app, cached).await;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers().get("ETag").unwrap().to_str().unwrap(), etag);
+    assert!(test::read_body(response).await.is_empty());
+
+    let since = test::TestRequest::get().uri(&path).insert_header(("If-Modified-Since", last_modified)).to_request();
+    assert_eq!(test::call_service(&app, since).await.status(), StatusCode::NOT_MODIFIED);
+
+    let stale = test::TestRequest::get().uri(&path).insert_header(("If-None-Match", "\"other\"")).to_request();
+    assert_eq!(test::call_service(&app, stale).await.status(), StatusCode::OK);
+
+    let json = test::TestRequest::get().uri(&format!("/categories/{}/feed.json", tech.id)).to_request();
+    let response = test::call_service(&app, json).await;
+    assert_eq!(response.headers().get("Content-Type").unwrap(), "application/feed+json; charset=utf-8");
+
+    let invalid = test::TestRequest::get().uri("/categories/not-a-uuid/feed.xml").to_request();
+    assert_eq!(test::call_service(&app, invalid).await.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_shared_collection_feed_is_not_cacheable_by_shared_caches() {
+    let setup = create_test_setup();
+    let collection = create_shared_collection(&setup, "share-token").await;
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Saved", TestArticleOptions::new()).await;
+    setup.saved.create(SavedArticle::from_article(collection.user_id, &article, Some(collection.id), 0)).await.unwrap();
+
+    let controller = Arc::new(SyndicationController::new(setup.category_feed.clone(), setup.collection_feed.clone(), 600));
+    let app = test::init_service(App::new().route("/shared/collections/{token}/feed.atom", web::get().to({
+        let ctrl = controller.clone();
+        move |req: HttpRequest, body: web::Bytes| {
+            let controller = ctrl.clone();
+            async move { controller.collection_feed(req, body, FeedFormat::Atom).await }
+        }
+    })))
+    .await;
+
+    let request = test::TestRequest::get().uri("/shared/collections/share-token/feed.atom").to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get("Cache-Control").unwrap(), "private, max-age=600");
+    assert!(response.headers().get("ETag").is_some());
+}
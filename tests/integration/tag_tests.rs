@@ -0,0 +1,346 @@
+use crate::common::{api_key_request, create_test_api_key_auth, create_test_article, TestArticleOptions};
+use actix_web::http::StatusCode;
+use actix_web::web::Bytes;
+use nexight_backend::application::use_cases::autocomplete_tags::AutocompleteTagsUseCase;
+use nexight_backend::application::use_cases::create_tag::{CreateTagError, CreateTagUseCase};
+use nexight_backend::application::use_cases::delete_tag::{DeleteTagError, DeleteTagUseCase};
+use nexight_backend::application::use_cases::get_article_tags::{GetArticleTagsError, GetArticleTagsUseCase};
+use nexight_backend::application::use_cases::list_saved_articles::{ListSavedArticlesError, ListSavedArticlesUseCase};
+use nexight_backend::application::use_cases::list_tags::ListTagsUseCase;
+use nexight_backend::application::use_cases::list_timeline::{ListTimelineError, ListTimelineUseCase};
+use nexight_backend::application::use_cases::merge_tags::{MergeTagsError, MergeTagsUseCase};
+use nexight_backend::application::use_cases::rename_tag::{RenameTagError, RenameTagUseCase};
+use nexight_backend::application::use_cases::set_article_tags::{SetArticleTagsError, SetArticleTagsUseCase};
+use nexight_backend::domain::entities::api_key::ApiKeyScope;
+use nexight_backend::domain::entities::article::ArticleListFilter;
+use nexight_backend::domain::entities::saved_article::SavedArticle;
+use nexight_backend::domain::entities::subscription::{CreateSubscriptionDto, Subscription};
+use nexight_backend::domain::entities::tag::{CreateTagDto, MergeTagDto, RenameTagDto, SetArticleTagsDto};
+use nexight_backend::domain::repositories::article_repository::ArticleRepository;
+use nexight_backend::domain::repositories::saved_article_repository::SavedArticleRepository;
+use nexight_backend::domain::repositories::subscription_repository::SubscriptionRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_saved_article_repository::InMemorySavedArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_smart_folder_repository::InMemorySmartFolderRepository;
+use nexight_backend::infrastructure::repositories::in_memory_subscription_repository::InMemorySubscriptionRepository;
+use nexight_backend::infrastructure::repositories::in_memory_tag_repository::InMemoryTagRepository;
+use nexight_backend::interfaces::controllers::article_tag_controller::ArticleTagController;
+use nexight_backend::interfaces::controllers::tag_controller::TagController;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct TestSetup {
+    articles: Arc<InMemoryArticleRepository>,
+    subscriptions: Arc<InMemorySubscriptionRepository>,
+    saved: Arc<InMemorySavedArticleRepository>,
+    tags: Arc<InMemoryTagRepository>,
+    create: CreateTagUseCase,
+    list: ListTagsUseCase,
+    autocomplete: AutocompleteTagsUseCase,
+    rename: RenameTagUseCase,
+    merge: MergeTagsUseCase,
+    delete: DeleteTagUseCase,
+    article_tags: GetArticleTagsUseCase,
+    set_article_tags: SetArticleTagsUseCase,
+    timeline: ListTimelineUseCase,
+    list_saved: ListSavedArticlesUseCase,
+}
+
+fn create_test_setup() -> TestSetup {
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let subscriptions = Arc::new(InMemorySubscriptionRepository::new());
+    let saved = Arc::new(InMemorySavedArticleRepository::new());
+    let tags = Arc::new(InMemoryTagRepository::new());
+
+    TestSetup {
+        create: CreateTagUseCase::new(tags.clone()),
+        list: ListTagsUseCase::new(tags.clone()),
+        autocomplete: AutocompleteTagsUseCase::new(tags.clone()),
+        rename: RenameTagUseCase::new(tags.clone()),
+        merge: MergeTagsUseCase::new(tags.clone(), Arc::new(InMemorySmartFolderRepository::new())),
+        delete: DeleteTagUseCase::new(tags.clone()),
+        article_tags: GetArticleTagsUseCase::new(articles.clone(), tags.clone()),
+        set_article_tags: SetArticleTagsUseCase::new(articles.clone(), tags.clone()),
+        timeline: ListTimelineUseCase::new(articles.clone(), subscriptions.clone(), tags.clone()),
+        list_saved: ListSavedArticlesUseCase::new(saved.clone(), tags.clone()),
+        articles,
+        subscriptions,
+        saved,
+        tags,
+    }
+}
+
+async fn tag_article(setup: &TestSetup, user_id: Uuid, article_id: Uuid, names: &[&str]) {
+    let dto = SetArticleTagsDto {
+        names: names.iter().map(|name| name.to_string()).collect(),
+    };
+    setup.set_article_tags.execute(user_id, article_id, dto).await.unwrap();
+}
+
+fn name(value: &str) -> CreateTagDto {
+    CreateTagDto {
+        name: value.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_create_tag_normalizes_and_rejects_case_insensitive_duplicate() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+
+    let tag = setup.create.execute(user_id, name("  Machine   Learning ")).await.unwrap();
+    assert_eq!(tag.name, "Machine Learning");
+
+    let duplicate = setup.create.execute(user_id, name("machine learning")).await;
+    assert!(matches!(duplicate, Err(CreateTagError::NameTaken)));
+
+    // Tags são por usuário
+    let other = setup.create.execute(Uuid::new_v4(), name("Machine Learning")).await;
+    assert!(other.is_ok());
+
+    let blank = setup.create.execute(user_id, name("   ")).await;
+    assert!(matches!(blank, Err(CreateTagError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_set_article_tags_creates_missing_and_dedups() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Rust Release", TestArticleOptions::new()).await;
+    let existing = setup.create.execute(user_id, name("Rust")).await.unwrap();
+
+    let tags = setup
+        .set_article_tags
+        .execute(
+            user_id,
+            article.id,
+            SetArticleTagsDto {
+                names: vec!["rust".to_string(), "To Read".to_string(), "to  read".to_string()],
+            },
+        )
+        .await
+        .unwrap();
+
+    let names: Vec<&str> = tags.iter().map(|tag| tag.name.as_str()).collect();
+    assert_eq!(names, vec!["Rust", "To Read"]);
+    assert_eq!(tags[0].id, existing.id);
+
+    // Substitui o conjunto inteiro
+    tag_article(&setup, user_id, article.id, &["To Read"]).await;
+    let current = setup.article_tags.execute(user_id, article.id).await.unwrap();
+    assert_eq!(current.len(), 1);
+    assert_eq!(current[0].name, "To Read");
+
+    // Tags de outro usuário no mesmo artigo não aparecem
+    tag_article(&setup, Uuid::new_v4(), article.id, &["Private"]).await;
+    assert_eq!(setup.article_tags.execute(user_id, article.id).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_set_article_tags_on_missing_article() {
+    let setup = create_test_setup();
+
+    let set = setup
+        .set_article_tags
+        .execute(Uuid::new_v4(), Uuid::new_v4(), SetArticleTagsDto { names: vec!["Rust".to_string()] })
+        .await;
+    let get = setup.article_tags.execute(Uuid::new_v4(), Uuid::new_v4()).await;
+
+    assert!(matches!(set, Err(SetArticleTagsError::ArticleNotFound)));
+    assert!(matches!(get, Err(GetArticleTagsError::ArticleNotFound)));
+}
+
+#[tokio::test]
+async fn test_list_and_autocomplete_by_usage() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    let first = create_test_article(setup.articles.as_ref(), feed_id, "First", TestArticleOptions::new()).await;
+    let second = create_test_article(setup.articles.as_ref(), feed_id, "Second", TestArticleOptions::new()).await;
+
+    tag_article(&setup, user_id, first.id, &["Rust", "Research"]).await;
+    tag_article(&setup, user_id, second.id, &["Research"]).await;
+    setup.create.execute(user_id, name("Recipes")).await.unwrap();
+    setup.create.execute(user_id, name("Python")).await.unwrap();
+
+    let all = setup.list.execute(user_id).await.unwrap();
+    let listed: Vec<(&str, i64)> = all.iter().map(|tag| (tag.name.as_str(), tag.article_count)).collect();
+    assert_eq!(listed, vec![("Python", 0), ("Recipes", 0), ("Research", 2), ("Rust", 1)]);
+
+    let suggestions = setup.autocomplete.execute(user_id, "r", 10).await.unwrap();
+    let names: Vec<&str> = suggestions.iter().map(|tag| tag.name.as_str()).collect();
+    assert_eq!(names, vec!["Research", "Rust", "Recipes"]);
+
+    let limited = setup.autocomplete.execute(user_id, "RE", 1).await.unwrap();
+    assert_eq!(limited.len(), 1);
+    assert_eq!(limited[0].name, "Research");
+}
+
+#[tokio::test]
+async fn test_rename_conflict_and_case_change() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let rust = setup.create.execute(user_id, name("rust")).await.unwrap();
+    setup.create.execute(user_id, name("Go")).await.unwrap();
+
+    let renamed = setup
+        .rename
+        .execute(user_id, rust.id, RenameTagDto { name: "Rust".to_string() })
+        .await
+        .unwrap();
+    assert_eq!(renamed.name, "Rust");
+
+    let conflict = setup
+        .rename
+        .execute(user_id, rust.id, RenameTagDto { name: "go".to_string() })
+        .await;
+    assert!(matches!(conflict, Err(RenameTagError::NameTaken)));
+
+    let intruder = setup
+        .rename
+        .execute(Uuid::new_v4(), rust.id, RenameTagDto { name: "Mine".to_string() })
+        .await;
+    assert!(matches!(intruder, Err(RenameTagError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_merge_moves_articles_and_removes_source() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    let first = create_test_article(setup.articles.as_ref(), feed_id, "First", TestArticleOptions::new()).await;
+    let second = create_test_article(setup.articles.as_ref(), feed_id, "Second", TestArticleOptions::new()).await;
+
+    tag_article(&setup, user_id, first.id, &["ML", "AI"]).await;
+    tag_article(&setup, user_id, second.id, &["ML"]).await;
+    let tags = setup.list.execute(user_id).await.unwrap();
+    let ml = tags.iter().find(|tag| tag.name == "ML").unwrap().id;
+    let ai = tags.iter().find(|tag| tag.name == "AI").unwrap().id;
+
+    let same = setup.merge.execute(user_id, ml, MergeTagDto { into: ml }).await;
+    assert!(matches!(same, Err(MergeTagsError::SameTag)));
+
+    let merged = setup.merge.execute(user_id, ml, MergeTagDto { into: ai }).await.unwrap();
+    assert_eq!(merged.id, ai);
+
+    let tags = setup.list.execute(user_id).await.unwrap();
+    assert_eq!(tags.len(), 1);
+    assert_eq!((tags[0].name.as_str(), tags[0].article_count), ("AI", 2));
+
+    let again = setup.merge.execute(user_id, ml, MergeTagDto { into: ai }).await;
+    assert!(matches!(again, Err(MergeTagsError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_delete_tag_keeps_articles() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let article = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Tagged", TestArticleOptions::new()).await;
+    tag_article(&setup, user_id, article.id, &["Later"]).await;
+    let tag_id = setup.list.execute(user_id).await.unwrap()[0].id;
+
+    let intruder = setup.delete.execute(Uuid::new_v4(), tag_id).await;
+    assert!(matches!(intruder, Err(DeleteTagError::NotFound)));
+
+    setup.delete.execute(user_id, tag_id).await.unwrap();
+
+    assert!(setup.article_tags.execute(user_id, article.id).await.unwrap().is_empty());
+    assert!(setup.articles.find_by_id(article.id).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_timeline_filtered_by_tag() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    setup
+        .subscriptions
+        .create(Subscription::new(
+            user_id,
+            CreateSubscriptionDto {
+                feed_id,
+                title: None,
+                folder: None,
+                weight: None,
+            },
+        ))
+        .await
+        .unwrap();
+    let tagged = create_test_article(setup.articles.as_ref(), feed_id, "Tagged", TestArticleOptions::new()).await;
+    create_test_article(setup.articles.as_ref(), feed_id, "Untagged", TestArticleOptions::new()).await;
+    // Marcado, mas de um feed não assinado
+    let unsubscribed = create_test_article(setup.articles.as_ref(), Uuid::new_v4(), "Elsewhere", TestArticleOptions::new()).await;
+
+    tag_article(&setup, user_id, tagged.id, &["Rust"]).await;
+    tag_article(&setup, user_id, unsubscribed.id, &["Rust"]).await;
+    let tag_id = setup.list.execute(user_id).await.unwrap()[0].id;
+
+    let timeline = setup
+        .timeline
+        .execute_filtered(user_id, ArticleListFilter::default(), Some(tag_id), 1, 20)
+        .await
+        .unwrap();
+    let titles: Vec<&str> = timeline.articles.iter().map(|a| a.title.as_str()).collect();
+    assert_eq!(titles, vec!["Tagged"]);
+
+    let foreign = setup
+        .timeline
+        .execute_filtered(Uuid::new_v4(), ArticleListFilter::default(), Some(tag_id), 1, 20)
+        .await;
+    assert!(matches!(foreign, Err(ListTimelineError::TagNotFound)));
+}
+
+#[tokio::test]
+async fn test_saved_articles_filtered_by_tag() {
+    let setup = create_test_setup();
+    let user_id = Uuid::new_v4();
+    let feed_id = Uuid::new_v4();
+    let tagged = create_test_article(setup.articles.as_ref(), feed_id, "Tagged", TestArticleOptions::new()).await;
+    let untagged = create_test_article(setup.articles.as_ref(), feed_id, "Untagged", TestArticleOptions::new()).await;
+    setup.saved.create(SavedArticle::from_article(user_id, &tagged, None, 0)).await.unwrap();
+    setup.saved.create(SavedArticle::from_article(user_id, &untagged, None, 1)).await.unwrap();
+
+    tag_article(&setup, user_id, tagged.id, &["Keep"]).await;
+    let tag_id = setup.list.execute(user_id).await.unwrap()[0].id;
+
+    let saved = setup.list_saved.execute(user_id, None, Some(tag_id)).await.unwrap();
+    assert_eq!(saved.len(), 1);
+    assert_eq!(saved[0].article_id, Some(tagged.id));
+
+    let foreign = setup.list_saved.execute(Uuid::new_v4(), None, Some(tag_id)).await;
+    assert!(matches!(foreign, Err(ListSavedArticlesError::TagNotFound)));
+}
+
+#[tokio::test]
+async fn test_tag_changes_require_library_write_scope() {
+    let setup = create_test_setup();
+    let (auth, key) = create_test_api_key_auth(vec![ApiKeyScope::ArticlesRead]).await;
+    let tags = TagController::new(
+        Arc::new(CreateTagUseCase::new(setup.tags.clone())),
+        Arc::new(ListTagsUseCase::new(setup.tags.clone())),
+        Arc::new(AutocompleteTagsUseCase::new(setup.tags.clone())),
+        Arc::new(RenameTagUseCase::new(setup.tags.clone())),
+        Arc::new(MergeTagsUseCase::new(setup.tags.clone(), Arc::new(InMemorySmartFolderRepository::new()))),
+        Arc::new(DeleteTagUseCase::new(setup.tags.clone())),
+        auth.clone(),
+    );
+    let article_tags = ArticleTagController::new(
+        Arc::new(GetArticleTagsUseCase::new(setup.articles.clone(), setup.tags.clone())),
+        Arc::new(SetArticleTagsUseCase::new(setup.articles.clone(), setup.tags.clone())),
+        auth,
+    );
+
+    let responses = [
+        tags.create(api_key_request(&key), Bytes::new()).await,
+        tags.rename(api_key_request(&key), Bytes::new()).await,
+        tags.merge(api_key_request(&key), Bytes::new()).await,
+        tags.delete(api_key_request(&key), Bytes::new()).await,
+        article_tags.set(api_key_request(&key), Bytes::new()).await,
+    ];
+    for response in responses {
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    let listed = tags.list(api_key_request(&key), Bytes::new()).await;
+    assert_eq!(listed.status(), StatusCode::OK);
+}
@@ -0,0 +1,327 @@
+use crate::common::{create_test_auth_service, create_test_user_repository};
+use chrono::{Duration, Utc};
+use nexight_backend::application::services::auth_service::AuthService;
+use nexight_backend::application::services::session_service::SessionService;
+use nexight_backend::application::use_cases::change_password::{ChangePasswordError, ChangePasswordUseCase};
+use nexight_backend::application::use_cases::confirm_email_change::{
+    ConfirmEmailChangeError, ConfirmEmailChangeUseCase,
+};
+use nexight_backend::application::use_cases::delete_account::{DeleteAccountError, DeleteAccountUseCase};
+use nexight_backend::application::use_cases::get_profile::{GetProfileError, GetProfileUseCase};
+use nexight_backend::application::use_cases::update_profile::{UpdateProfileError, UpdateProfileUseCase};
+use nexight_backend::domain::entities::session::{Session, SessionClient};
+use nexight_backend::domain::entities::user::{
+    ChangePasswordDto, ConfirmEmailChangeDto, DeleteAccountDto, UpdateProfileDto, User,
+};
+use nexight_backend::domain::repositories::session_repository::SessionRepository;
+use nexight_backend::domain::repositories::user_repository::UserRepository;
+use nexight_backend::infrastructure::mail::in_memory_mailer::InMemoryMailer;
+use nexight_backend::infrastructure::repositories::in_memory_email_change_request_repository::InMemoryEmailChangeRequestRepository;
+use nexight_backend::infrastructure::repositories::in_memory_session_repository::InMemorySessionRepository;
+use nexight_backend::infrastructure::repositories::in_memory_user_repository::InMemoryUserRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct TestSetup {
+    repository: Arc<InMemoryUserRepository>,
+    auth_service: Arc<AuthService>,
+    sessions: Arc<InMemorySessionRepository>,
+    mailer: Arc<InMemoryMailer>,
+    get_profile: GetProfileUseCase,
+    update_profile: UpdateProfileUseCase,
+    confirm_email: ConfirmEmailChangeUseCase,
+    change_password: ChangePasswordUseCase,
+    delete_account: DeleteAccountUseCase,
+}
+
+fn create_test_setup() -> TestSetup {
+    let repository = create_test_user_repository();
+    let auth_service = create_test_auth_service();
+    let email_changes = Arc::new(InMemoryEmailChangeRequestRepository::new());
+    let mailer = Arc::new(InMemoryMailer::new());
+    let sessions = Arc::new(InMemorySessionRepository::new());
+    let session_service = Arc::new(SessionService::new(sessions.clone(), auth_service.clone()));
+
+    TestSetup {
+        get_profile: GetProfileUseCase::new(repository.clone(), email_changes.clone()),
+        update_profile: UpdateProfileUseCase::new(repository.clone(), email_changes.clone(), mailer.clone()),
+        confirm_email: ConfirmEmailChangeUseCase::new(repository.clone(), email_changes),
+        change_password: ChangePasswordUseCase::new(
+            repository.clone(),
+            auth_service.clone(),
+            session_service.clone(),
+        ),
+        delete_account: DeleteAccountUseCase::new(repository.clone(), auth_service.clone(), session_service),
+        repository,
+        auth_service,
+        sessions,
+        mailer,
+    }
+}
+
+async fn create_test_user(setup: &TestSetup, email: &str) -> User {
+    let password_hash = setup.auth_service.hash_password("password123").unwrap();
+    let user = User::new(email.to_string(), password_hash, "Test User".to_string());
+    setup.repository.create(user).await.unwrap()
+}
+
+async fn create_test_session(setup: &TestSetup, user: &User) -> Session {
+    let session = Session::new(user.id, SessionClient::default(), Utc::now() + Duration::days(1));
+    setup.sessions.create(session).await.unwrap()
+}
+
+async fn is_revoked(setup: &TestSetup, session: &Session) -> bool {
+    let stored = setup.sessions.find_by_id(session.id).await.unwrap().unwrap();
+    stored.revoked_at.is_some()
+}
+
+/// Extrai o token de confirmação do último email enviado
+async fn last_sent_token(mailer: &InMemoryMailer) -> String {
+    let messages = mailer.sent_messages().await;
+    let message = messages.last().expect("no email sent");
+    message
+        .text_body
+        .split_whitespace()
+        .find(|word| word.len() == 64 && word.chars().all(|c| c.is_ascii_hexdigit()))
+        .expect("no token in email")
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_get_profile() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup, "test@example.com").await;
+
+    let profile = setup.get_profile.execute(user.id).await.unwrap();
+
+    assert_eq!(profile.user.id, user.id);
+    assert_eq!(profile.user.email, "test@example.com");
+    assert!(profile.pending_email.is_none());
+}
+
+#[tokio::test]
+async fn test_get_profile_unknown_user() {
+    let setup = create_test_setup();
+
+    let result = setup.get_profile.execute(Uuid::new_v4()).await;
+    assert!(matches!(result.unwrap_err(), GetProfileError::UserNotFound));
+}
+
+#[tokio::test]
+async fn test_update_profile_name() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup, "test@example.com").await;
+
+    let dto = UpdateProfileDto {
+        name: Some("New Name".to_string()),
+        email: None,
+    };
+    let profile = setup.update_profile.execute(user.id, dto).await.unwrap();
+
+    assert_eq!(profile.user.name, "New Name");
+    let stored = setup.repository.find_by_id(user.id).await.unwrap().unwrap();
+    assert_eq!(stored.name, "New Name");
+    assert!(setup.mailer.sent_messages().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_profile_invalid_name() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup, "test@example.com").await;
+
+    let dto = UpdateProfileDto {
+        name: Some("A".to_string()),
+        email: None,
+    };
+    let result = setup.update_profile.execute(user.id, dto).await;
+    assert!(matches!(result.unwrap_err(), UpdateProfileError::ValidationError(_)));
+}
+
+#[tokio::test]
+async fn test_email_change_requires_verification() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup, "old@example.com").await;
+
+    let dto = UpdateProfileDto {
+        name: None,
+        email: Some("new@example.com".to_string()),
+    };
+    let profile = setup.update_profile.execute(user.id, dto).await.unwrap();
+
+    // The email is not changed until the new address is confirmed
+    assert_eq!(profile.user.email, "old@example.com");
+    assert_eq!(profile.pending_email.as_deref(), Some("new@example.com"));
+
+    let messages = setup.mailer.sent_messages().await;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].to, "new@example.com");
+
+    let profile = setup.get_profile.execute(user.id).await.unwrap();
+    assert_eq!(profile.pending_email.as_deref(), Some("new@example.com"));
+
+    let token = last_sent_token(&setup.mailer).await;
+    let updated = setup
+        .confirm_email
+        .execute(user.id, ConfirmEmailChangeDto { token })
+        .await
+        .unwrap();
+
+    assert_eq!(updated.email, "new@example.com");
+    assert!(setup.repository.find_by_email("new@example.com").await.unwrap().is_some());
+    assert!(setup.repository.find_by_email("old@example.com").await.unwrap().is_none());
+
+    let profile = setup.get_profile.execute(user.id).await.unwrap();
+    assert!(profile.pending_email.is_none());
+}
+
+#[tokio::test]
+async fn test_email_change_with_wrong_token() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup, "old@example.com").await;
+
+    let dto = UpdateProfileDto {
+        name: None,
+        email: Some("new@example.com".to_string()),
+    };
+    setup.update_profile.execute(user.id, dto).await.unwrap();
+
+    let result = setup
+        .confirm_email
+        .execute(user.id, ConfirmEmailChangeDto { token: "wrong".to_string() })
+        .await;
+    assert!(matches!(result.unwrap_err(), ConfirmEmailChangeError::InvalidToken));
+}
+
+#[tokio::test]
+async fn test_email_change_to_existing_email() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup, "first@example.com").await;
+    create_test_user(&setup, "second@example.com").await;
+
+    let dto = UpdateProfileDto {
+        name: None,
+        email: Some("second@example.com".to_string()),
+    };
+    let result = setup.update_profile.execute(user.id, dto).await;
+    assert!(matches!(result.unwrap_err(), UpdateProfileError::EmailAlreadyExists));
+}
+
+#[tokio::test]
+async fn test_change_password() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup, "test@example.com").await;
+
+    let dto = ChangePasswordDto {
+        current_password: "password123".to_string(),
+        new_password: "new_password456".to_string(),
+    };
+    setup.change_password.execute(user.id, None, dto).await.unwrap();
+
+    let stored = setup.repository.find_by_id(user.id).await.unwrap().unwrap();
+    assert!(setup.auth_service.verify_password("new_password456", &stored.password_hash).unwrap());
+    assert!(!setup.auth_service.verify_password("password123", &stored.password_hash).unwrap());
+}
+
+#[tokio::test]
+async fn test_change_password_wrong_current_password() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup, "test@example.com").await;
+
+    let dto = ChangePasswordDto {
+        current_password: "wrong_password".to_string(),
+        new_password: "new_password456".to_string(),
+    };
+    let result = setup.change_password.execute(user.id, None, dto).await;
+    assert!(matches!(result.unwrap_err(), ChangePasswordError::InvalidCurrentPassword));
+}
+
+#[tokio::test]
+async fn test_change_password_too_short() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup, "test@example.com").await;
+
+    let dto = ChangePasswordDto {
+        current_password: "password123".to_string(),
+        new_password: "short".to_string(),
+    };
+    let result = setup.change_password.execute(user.id, None, dto).await;
+    assert!(matches!(result.unwrap_err(), ChangePasswordError::ValidationError(_)));
+}
+
+#[tokio::test]
+async fn test_delete_account() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup, "test@example.com").await;
+
+    let dto = DeleteAccountDto {
+        password: "password123".to_string(),
+    };
+    setup.delete_account.execute(user.id, dto).await.unwrap();
+
+    assert!(setup.repository.find_by_id(user.id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_delete_account_wrong_password() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup, "test@example.com").await;
+
+    let dto = DeleteAccountDto {
+        password: "wrong_password".to_string(),
+    };
+    let result = setup.delete_account.execute(user.id, dto).await;
+
+    assert!(matches!(result.unwrap_err(), DeleteAccountError::InvalidPassword));
+    assert!(setup.repository.find_by_id(user.id).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_change_password_revokes_other_sessions() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup, "test@example.com").await;
+    let other_user = create_test_user(&setup, "other@example.com").await;
+    let current = create_test_session(&setup, &user).await;
+    let stale = create_test_session(&setup, &user).await;
+    let unrelated = create_test_session(&setup, &other_user).await;
+
+    let dto = ChangePasswordDto {
+        current_password: "password123".to_string(),
+        new_password: "new_password456".to_string(),
+    };
+    setup.change_password.execute(user.id, Some(current.id), dto).await.unwrap();
+
+    assert!(!is_revoked(&setup, &current).await);
+    assert!(is_revoked(&setup, &stale).await);
+    assert!(!is_revoked(&setup, &unrelated).await);
+}
+
+#[tokio::test]
+async fn test_change_password_wrong_current_password_keeps_sessions() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup, "test@example.com").await;
+    let session = create_test_session(&setup, &user).await;
+
+    let dto = ChangePasswordDto {
+        current_password: "wrong_password".to_string(),
+        new_password: "new_password456".to_string(),
+    };
+    assert!(setup.change_password.execute(user.id, None, dto).await.is_err());
+
+    assert!(!is_revoked(&setup, &session).await);
+}
+
+#[tokio::test]
+async fn test_delete_account_revokes_all_sessions() {
+    let setup = create_test_setup();
+    let user = create_test_user(&setup, "test@example.com").await;
+    let first = create_test_session(&setup, &user).await;
+    let second = create_test_session(&setup, &user).await;
+
+    let dto = DeleteAccountDto {
+        password: "password123".to_string(),
+    };
+    setup.delete_account.execute(user.id, dto).await.unwrap();
+
+    assert!(is_revoked(&setup, &first).await);
+    assert!(is_revoked(&setup, &second).await);
+}
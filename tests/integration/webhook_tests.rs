@@ -0,0 +1,477 @@
+use chrono::{Duration, Utc};
+use nexight_backend::application::services::alert_service::AlertService;
+use nexight_backend::application::services::webhook_dispatcher::{
+    WebhookDispatcher, DEFAULT_RETENTION_DAYS, DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER,
+};
+use nexight_backend::application::services::webhook_sender::{WebhookRequest, WebhookSender};
+use nexight_backend::application::services::webhook_target_policy::WebhookTargetPolicy;
+use nexight_backend::application::use_cases::create_article::CreateArticleUseCase;
+use nexight_backend::application::use_cases::create_webhook::{CreateWebhookError, CreateWebhookUseCase};
+use nexight_backend::application::use_cases::delete_article::DeleteArticleUseCase;
+use nexight_backend::application::use_cases::delete_webhook::{DeleteWebhookError, DeleteWebhookUseCase};
+use nexight_backend::application::use_cases::list_webhook_deliveries::{
+    ListWebhookDeliveriesError, ListWebhookDeliveriesUseCase,
+};
+use nexight_backend::application::use_cases::list_webhooks::ListWebhooksUseCase;
+use nexight_backend::application::use_cases::test_webhook::TestWebhookUseCase;
+use nexight_backend::application::use_cases::update_article::UpdateArticleUseCase;
+use nexight_backend::application::use_cases::update_webhook::{UpdateWebhookError, UpdateWebhookUseCase};
+use nexight_backend::domain::entities::article::{CreateArticleDto, UpdateArticleDto};
+use nexight_backend::domain::entities::webhook::{
+    webhook_signature, WebhookDeliveryStatus, WebhookEventType, WebhookSubscriptionDto, WebhookSubscriptionResponse,
+};
+use nexight_backend::infrastructure::notifications::http_webhook_sender::HttpWebhookSender;
+use nexight_backend::infrastructure::repositories::in_memory_alert_rule_repository::InMemoryAlertRuleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_event_repository::InMemoryArticleEventRepository;
+use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
+use nexight_backend::infrastructure::repositories::in_memory_notification_repository::InMemoryNotificationRepository;
+use nexight_backend::infrastructure::repositories::in_memory_webhook_repository::InMemoryWebhookRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+struct TestSetup {
+    events: Arc<InMemoryArticleEventRepository>,
+    dispatcher: Arc<WebhookDispatcher>,
+    create_article: CreateArticleUseCase,
+    update_article: UpdateArticleUseCase,
+    delete_article: DeleteArticleUseCase,
+    create_webhook: CreateWebhookUseCase,
+    list_webhooks: ListWebhooksUseCase,
+    update_webhook: UpdateWebhookUseCase,
+    delete_webhook: DeleteWebhookUseCase,
+    list_deliveries: ListWebhookDeliveriesUseCase,
+    test_webhook: TestWebhookUseCase,
+}
+
+fn create_test_setup() -> TestSetup {
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let events = Arc::new(articles.events());
+    let webhooks = Arc::new(InMemoryWebhookRepository::new());
+    let alert_service = Arc::new(AlertService::new(
+        Arc::new(InMemoryAlertRuleRepository::new()),
+        Arc::new(InMemoryNotificationRepository::new()),
+    ));
+    let dispatcher = Arc::new(WebhookDispatcher::new(
+        events.clone(),
+        webhooks.clone(),
+        Arc::new(HttpWebhookSender::new(WebhookTargetPolicy::allow_private_networks())),
+    ));
+
+    TestSetup {
+        create_article: CreateArticleUseCase::new(articles.clone(), alert_service.clone()),
+        update_article: UpdateArticleUseCase::new(articles.clone(), alert_service),
+        delete_article: DeleteArticleUseCase::new(articles),
+        // Os receptores dos testes escutam em 127.0.0.1
+        create_webhook: CreateWebhookUseCase::new(webhooks.clone(), WebhookTargetPolicy::allow_private_networks()),
+        list_webhooks: ListWebhooksUseCase::new(webhooks.clone()),
+        update_webhook: UpdateWebhookUseCase::new(webhooks.clone(), WebhookTargetPolicy::allow_private_networks()),
+        delete_webhook: DeleteWebhookUseCase::new(webhooks.clone()),
+        list_deliveries: ListWebhookDeliveriesUseCase::new(webhooks.clone()),
+        test_webhook: TestWebhookUseCase::new(webhooks, dispatcher.clone()),
+        events,
+        dispatcher,
+    }
+}
+
+fn webhook(url: String, events: Vec<WebhookEventType>) -> WebhookSubscriptionDto {
+    WebhookSubscriptionDto {
+        name: "Warehouse loader".to_string(),
+        url,
+        events,
+        enabled: true,
+    }
+}
+
+async fn create_webhook(setup: &TestSetup, server: &MockServer, events: Vec<WebhookEventType>) -> WebhookSubscriptionResponse {
+    setup
+        .create_webhook
+        .execute(Uuid::new_v4(), webhook(format!("{}/hooks/nexight", server.uri()), events))
+        .await
+        .unwrap()
+}
+
+fn article(title: &str) -> CreateArticleDto {
+    CreateArticleDto {
+        title: title.to_string(),
+        description: format!("Description for {}", title),
+        link: format!("https://example.com/{}", title.to_lowercase().replace(' ', "-")),
+        pub_date: Utc::now(),
+        media: None,
+        content: format!("Content for {}", title),
+        creator: "Test Author".to_string(),
+        feed_id: Uuid::new_v4(),
+    }
+}
+
+fn update(title: Option<&str>, rate: Option<i32>) -> UpdateArticleDto {
+    UpdateArticleDto {
+        title: title.map(str::to_string),
+        description: None,
+        link: None,
+        pub_date: None,
+        media: None,
+        content: None,
+        creator: None,
+        ai_summary: None,
+        rate,
+        keywords: None,
+        category_id: None,
+        ai_columnist: None,
+    }
+}
+
+#[tokio::test]
+async fn test_article_writes_record_outbox_events() {
+    let setup = create_test_setup();
+
+    let created = setup.create_article.execute(article("Rust 2026")).await.unwrap();
+    setup.update_article.execute(created.id, update(Some("Rust 2026 released"), None)).await.unwrap();
+    setup.update_article.execute(created.id, update(None, Some(9))).await.unwrap();
+    setup.delete_article.execute(created.id).await.unwrap();
+
+    let events = setup.events.all().await;
+    let types: Vec<_> = events.iter().map(|event| event.event_type).collect();
+    assert_eq!(
+        types,
+        vec![
+            WebhookEventType::ArticleCreated,
+            WebhookEventType::ArticleUpdated,
+            WebhookEventType::ArticleEnriched,
+            WebhookEventType::ArticleDeleted,
+        ]
+    );
+    assert!(events.iter().all(|event| event.article_id == created.id && event.processed_at.is_none()));
+    assert_eq!(events[2].payload["rate"], 9);
+    // O evento de remoção leva o artigo como estava
+    assert_eq!(events[3].payload["title"], "Rust 2026 released");
+}
+
+#[tokio::test]
+async fn test_dispatch_posts_signed_payload_once() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hooks/nexight"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let setup = create_test_setup();
+    let subscription = create_webhook(&setup, &server, Vec::new()).await;
+    let secret = subscription.secret.clone().unwrap();
+    assert!(setup.list_webhooks.execute().await.unwrap()[0].secret.is_none());
+
+    let created = setup.create_article.execute(article("Signed payloads")).await.unwrap();
+    assert_eq!(setup.dispatcher.dispatch(Utc::now()).await.unwrap(), 1);
+    // Rodadas seguintes não repetem a entrega
+    assert_eq!(setup.dispatcher.dispatch(Utc::now()).await.unwrap(), 0);
+    assert!(setup.events.all().await[0].processed_at.is_some());
+
+    let requests = server.received_requests().await.unwrap();
+    let request = &requests[0];
+    let body = String::from_utf8(request.body.clone()).unwrap();
+    let header = |name: &str| request.headers.get(name).unwrap().to_str().unwrap().to_string();
+
+    let signature = header(SIGNATURE_HEADER);
+    let timestamp: i64 = signature
+        .strip_prefix("t=")
+        .and_then(|rest| rest.split(',').next())
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert_eq!(signature, webhook_signature(&secret, timestamp, &body));
+    assert_eq!(header(EVENT_HEADER), "article.created");
+
+    let payload: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(payload["type"], "article.created");
+    assert_eq!(payload["data"]["id"], created.id.to_string());
+
+    let log = setup.list_deliveries.execute(subscription.id, 1, 20).await.unwrap();
+    assert_eq!(log.total, 1);
+    assert_eq!(header(DELIVERY_HEADER), log.deliveries[0].id.to_string());
+    assert_eq!(log.deliveries[0].status, WebhookDeliveryStatus::Succeeded);
+    assert_eq!(log.deliveries[0].attempts, 1);
+    assert_eq!(log.deliveries[0].last_status_code, Some(200));
+}
+
+#[tokio::test]
+async fn test_event_filters_and_disabled_webhooks() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let setup = create_test_setup();
+    let deletions = create_webhook(&setup, &server, vec![WebhookEventType::ArticleDeleted]).await;
+    let disabled = create_webhook(&setup, &server, Vec::new()).await;
+    setup
+        .update_webhook
+        .execute(
+            disabled.id,
+            WebhookSubscriptionDto {
+                enabled: false,
+                ..webhook(format!("{}/hooks/nexight", server.uri()), Vec::new())
+            },
+        )
+        .await
+        .unwrap();
+
+    let created = setup.create_article.execute(article("Filtered")).await.unwrap();
+    setup.update_article.execute(created.id, update(None, Some(7))).await.unwrap();
+    setup.delete_article.execute(created.id).await.unwrap();
+    setup.dispatcher.dispatch(Utc::now()).await.unwrap();
+
+    let log = setup.list_deliveries.execute(deletions.id, 1, 20).await.unwrap();
+    assert_eq!(log.total, 1);
+    assert_eq!(log.deliveries[0].event_type, WebhookEventType::ArticleDeleted);
+    assert_eq!(setup.list_deliveries.execute(disabled.id, 1, 20).await.unwrap().total, 0);
+}
+
+#[tokio::test]
+async fn test_failed_deliveries_are_retried_with_backoff() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(202))
+        .mount(&server)
+        .await;
+
+    let setup = create_test_setup();
+    let subscription = create_webhook(&setup, &server, Vec::new()).await;
+    setup.create_article.execute(article("Flaky receiver")).await.unwrap();
+
+    let now = Utc::now();
+    assert_eq!(setup.dispatcher.dispatch(now).await.unwrap(), 1);
+    let delivery = &setup.list_deliveries.execute(subscription.id, 1, 20).await.unwrap().deliveries[0];
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(delivery.last_status_code, Some(500));
+    assert_eq!(delivery.next_attempt_at, Some(now + Duration::seconds(30)));
+
+    // Antes do backoff vencer, nada é enviado
+    assert_eq!(setup.dispatcher.dispatch(now + Duration::seconds(10)).await.unwrap(), 0);
+
+    let second = now + Duration::seconds(30);
+    assert_eq!(setup.dispatcher.dispatch(second).await.unwrap(), 1);
+    let delivery = &setup.list_deliveries.execute(subscription.id, 1, 20).await.unwrap().deliveries[0];
+    assert_eq!(delivery.attempts, 2);
+    assert_eq!(delivery.next_attempt_at, Some(second + Duration::seconds(60)));
+
+    assert_eq!(setup.dispatcher.dispatch(second + Duration::seconds(60)).await.unwrap(), 1);
+    let delivery = &setup.list_deliveries.execute(subscription.id, 1, 20).await.unwrap().deliveries[0];
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Succeeded);
+    assert_eq!(delivery.attempts, 3);
+    assert_eq!(delivery.last_status_code, Some(202));
+    assert_eq!(delivery.last_error, None);
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_test_fire_sends_immediately_without_retries() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(410))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let setup = create_test_setup();
+    let subscription = create_webhook(&setup, &server, vec![WebhookEventType::ArticleCreated]).await;
+
+    let delivery = setup.test_webhook.execute(subscription.id).await.unwrap();
+    assert_eq!(delivery.event_type, WebhookEventType::Test);
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Failed);
+    assert_eq!(delivery.last_status_code, Some(410));
+    assert_eq!(delivery.next_attempt_at, None);
+
+    // Fica no histórico e não volta para a fila
+    assert_eq!(setup.dispatcher.dispatch(Utc::now() + Duration::hours(1)).await.unwrap(), 0);
+    let log = setup.list_deliveries.execute(subscription.id, 1, 20).await.unwrap();
+    assert_eq!(log.total, 1);
+    assert_eq!(log.deliveries[0].id, delivery.id);
+}
+
+#[tokio::test]
+async fn test_webhook_validation_and_not_found() {
+    let setup = create_test_setup();
+
+    let not_http = webhook("ftp://example.com/hooks".to_string(), Vec::new());
+    assert!(matches!(
+        setup.create_webhook.execute(Uuid::new_v4(), not_http).await,
+        Err(CreateWebhookError::ValidationError(_))
+    ));
+    let test_event = webhook("https://example.com/hooks".to_string(), vec![WebhookEventType::Test]);
+    assert!(matches!(
+        setup.create_webhook.execute(Uuid::new_v4(), test_event).await,
+        Err(CreateWebhookError::ValidationError(_))
+    ));
+
+    let missing = Uuid::new_v4();
+    assert!(matches!(
+        setup.update_webhook.execute(missing, webhook("https://example.com/hooks".to_string(), Vec::new())).await,
+        Err(UpdateWebhookError::NotFound)
+    ));
+    assert!(matches!(setup.delete_webhook.execute(missing).await, Err(DeleteWebhookError::NotFound)));
+    assert!(matches!(
+        setup.list_deliveries.execute(missing, 1, 20).await,
+        Err(ListWebhookDeliveriesError::NotFound)
+    ));
+
+    let created = setup
+        .create_webhook
+        .execute(Uuid::new_v4(), webhook("https://example.com/hooks".to_string(), Vec::new()))
+        .await
+        .unwrap();
+    assert!(matches!(
+        setup.list_deliveries.execute(created.id, 1, 0).await,
+        Err(ListWebhookDeliveriesError::InvalidPagination)
+    ));
+    setup.delete_webhook.execute(created.id).await.unwrap();
+    assert!(setup.list_webhooks.execute().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_webhook_url_must_point_to_a_public_address() {
+    let webhooks = Arc::new(InMemoryWebhookRepository::new());
+    let create_webhook = CreateWebhookUseCase::new(webhooks.clone(), WebhookTargetPolicy::public_only());
+    let update_webhook = UpdateWebhookUseCase::new(webhooks, WebhookTargetPolicy::public_only());
+
+    for url in [
+        "http://localhost:8080/hooks",
+        "http://127.0.0.1/hooks",
+        "http://10.0.0.5/hooks",
+        "http://169.254.169.254/latest/meta-data",
+        "http://[::1]/hooks",
+    ] {
+        let result = create_webhook.execute(Uuid::new_v4(), webhook(url.to_string(), Vec::new())).await;
+        assert!(matches!(result, Err(CreateWebhookError::ValidationError(_))), "{} should be rejected", url);
+    }
+
+    let created = create_webhook
+        .execute(Uuid::new_v4(), webhook("https://hooks.example.com/nexight".to_string(), Vec::new()))
+        .await
+        .unwrap();
+    assert!(matches!(
+        update_webhook.execute(created.id, webhook("http://192.168.1.10/hooks".to_string(), Vec::new())).await,
+        Err(UpdateWebhookError::ValidationError(_))
+    ));
+}
+
+fn signed_request(url: String) -> WebhookRequest {
+    WebhookRequest {
+        url,
+        headers: vec![(EVENT_HEADER.to_string(), "webhook.test".to_string())],
+        body: "{}".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_sender_refuses_private_targets() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    // Webhook gravado antes da validação atual, apontando para a rede interna
+    let sender = HttpWebhookSender::new(WebhookTargetPolicy::public_only());
+
+    assert!(sender.send(&signed_request(format!("{}/hooks/nexight", server.uri()))).await.is_err());
+    server.verify().await;
+}
+
+#[tokio::test]
+async fn test_sender_does_not_follow_redirects() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hooks/nexight"))
+        .respond_with(ResponseTemplate::new(307).insert_header("Location", "/internal"))
+        .mount(&server)
+        .await;
+    Mock::given(path("/internal"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let sender = HttpWebhookSender::new(WebhookTargetPolicy::allow_private_networks());
+
+    // O redirecionamento conta como resposta fora de 2xx, e a entrega é tentada de novo
+    let status = sender.send(&signed_request(format!("{}/hooks/nexight", server.uri()))).await.unwrap();
+    assert_eq!(status, 307);
+    server.verify().await;
+}
+
+#[tokio::test]
+async fn test_prune_removes_old_history_and_keeps_pending_work() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hooks/nexight"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/hooks/down"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+
+    let setup = create_test_setup();
+    let delivered = create_webhook(&setup, &server, Vec::new()).await;
+    let retrying = setup
+        .create_webhook
+        .execute(Uuid::new_v4(), webhook(format!("{}/hooks/down", server.uri()), Vec::new()))
+        .await
+        .unwrap();
+
+    setup.create_article.execute(article("Old news")).await.unwrap();
+    setup.dispatcher.dispatch(Utc::now()).await.unwrap();
+    // Evento gravado depois da rodada, ainda não distribuído
+    setup.create_article.execute(article("Fresh news")).await.unwrap();
+
+    // Dentro da retenção nada é removido
+    assert_eq!(setup.dispatcher.prune(Utc::now()).await.unwrap(), 0);
+
+    let later = Utc::now() + Duration::days(DEFAULT_RETENTION_DAYS + 1);
+    // O evento distribuído e a entrega concluída saem; a entrega em tentativa e o evento pendente ficam
+    assert_eq!(setup.dispatcher.prune(later).await.unwrap(), 2);
+
+    let events = setup.events.all().await;
+    assert_eq!(events.len(), 1);
+    assert!(events[0].processed_at.is_none());
+    assert_eq!(setup.list_deliveries.execute(delivered.id, 1, 20).await.unwrap().total, 0);
+    let log = setup.list_deliveries.execute(retrying.id, 1, 20).await.unwrap();
+    assert_eq!(log.total, 1);
+    assert_eq!(log.deliveries[0].status, WebhookDeliveryStatus::Pending);
+}
+
+#[tokio::test]
+async fn test_retention_is_configurable() {
+    let articles = Arc::new(InMemoryArticleRepository::new());
+    let events = Arc::new(articles.events());
+    let alert_service = Arc::new(AlertService::new(
+        Arc::new(InMemoryAlertRuleRepository::new()),
+        Arc::new(InMemoryNotificationRepository::new()),
+    ));
+    let dispatcher = WebhookDispatcher::new(
+        events.clone(),
+        Arc::new(InMemoryWebhookRepository::new()),
+        Arc::new(HttpWebhookSender::default()),
+    )
+    .with_retention(7);
+
+    CreateArticleUseCase::new(articles, alert_service).execute(article("Short lived")).await.unwrap();
+    dispatcher.dispatch(Utc::now()).await.unwrap();
+
+    assert_eq!(dispatcher.prune(Utc::now() + Duration::days(6)).await.unwrap(), 0);
+    assert_eq!(dispatcher.prune(Utc::now() + Duration::days(8)).await.unwrap(), 1);
+    assert!(events.all().await.is_empty());
+}
@@ -0,0 +1,82 @@
+use nexight_backend::domain::entities::annotation::{AnchorStatus, Annotation, TextQuoteAnchor};
+use uuid::Uuid;
+
+fn anchor(quote: &str, prefix: &str, suffix: &str, start: i32) -> TextQuoteAnchor {
+    TextQuoteAnchor {
+        quote: quote.to_string(),
+        prefix: prefix.to_string(),
+        suffix: suffix.to_string(),
+        start,
+        end: start + quote.chars().count() as i32,
+    }
+}
+
+#[test]
+fn test_anchor_stays_in_place_when_content_is_unchanged() {
+    let content = "Rust 1.80 ships LazyCell and LazyLock.";
+    let anchor = anchor("LazyCell", "ships ", " and", 16);
+
+    assert_eq!(anchor.resolve(content), (AnchorStatus::Anchored, Some((16, 24))));
+}
+
+#[test]
+fn test_anchor_follows_text_moved_by_an_update() {
+    let content = "Breaking: Rust 1.80 ships LazyCell and LazyLock.";
+    let anchor = anchor("LazyCell", "ships ", " and", 16);
+
+    assert_eq!(anchor.resolve(content), (AnchorStatus::Moved, Some((26, 34))));
+}
+
+#[test]
+fn test_anchor_uses_context_to_pick_the_right_occurrence() {
+    let content = "Intro: the release. Later, the release notes mention the release date.";
+    let anchor = anchor("the release", "mention ", " date", 0);
+
+    let (status, position) = anchor.resolve(content);
+
+    assert_eq!(status, AnchorStatus::Moved);
+    assert_eq!(position, Some((53, 64)));
+}
+
+#[test]
+fn test_anchor_tolerates_whitespace_changes() {
+    let content = "Rust 1.80 ships\n  LazyCell and LazyLock.";
+    let anchor = anchor("ships LazyCell", "1.80 ", " and", 10);
+
+    let (status, position) = anchor.resolve(content);
+
+    assert_eq!(status, AnchorStatus::Moved);
+    let (start, end) = position.unwrap();
+    let found: String = content.chars().skip(start).take(end - start).collect();
+    assert_eq!(found, "ships\n  LazyCell");
+}
+
+#[test]
+fn test_anchor_is_orphaned_when_quote_disappears() {
+    let anchor = anchor("LazyCell", "", "", 16);
+
+    assert_eq!(anchor.resolve("Rust 1.80 was released."), (AnchorStatus::Orphaned, None));
+}
+
+#[test]
+fn test_offsets_count_characters_not_bytes() {
+    let content = "Função estável: ótimo desempenho";
+    let anchor = anchor("ótimo", "", "", 0);
+
+    let fixed = anchor.anchored_in(content).unwrap();
+
+    assert_eq!((fixed.start, fixed.end), (16, 21));
+    assert_eq!(fixed.prefix, "Função estável: ");
+    assert_eq!(fixed.suffix, " desempenho");
+}
+
+#[test]
+fn test_blank_note_is_dropped() {
+    let mut annotation = Annotation::new(Uuid::new_v4(), Uuid::new_v4(), None, Some("  Follow up ".to_string()));
+    assert_eq!(annotation.note.as_deref(), Some("Follow up"));
+
+    annotation.update(None, Some("   ".to_string()));
+
+    assert_eq!(annotation.note, None);
+    assert!(annotation.is_empty());
+}
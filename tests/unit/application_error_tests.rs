@@ -0,0 +1,118 @@
+use nexight_backend::application::errors::{ApplicationError, ErrorKind, ValidationFailure};
+use nexight_backend::application::use_cases::change_password::ChangePasswordError;
+use nexight_backend::application::use_cases::complete_oidc_login::CompleteOidcLoginError;
+use nexight_backend::application::use_cases::confirm_email_change::ConfirmEmailChangeError;
+use nexight_backend::application::use_cases::get_profile::GetProfileError;
+use nexight_backend::application::use_cases::list_article_categories::ListArticleCategoriesError;
+use nexight_backend::application::use_cases::login_user::LoginUserError;
+use nexight_backend::application::use_cases::register_user::RegisterUserError;
+use nexight_backend::application::use_cases::revoke_api_key::RevokeApiKeyError;
+use nexight_backend::application::use_cases::update_profile::UpdateProfileError;
+use nexight_backend::domain::repositories::article_repository::ArticleRepositoryError;
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+struct Item {
+    #[validate(length(min = 1, message = "Name is required"))]
+    name: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+struct Order {
+    #[validate(email(message = "Invalid email"))]
+    email: String,
+    #[validate(range(min = 1))]
+    quantity: i32,
+    #[validate(nested)]
+    items: Vec<Item>,
+}
+
+#[test]
+fn test_validation_errors_become_per_field_details() {
+    let order = Order {
+        email: "not-an-email".to_string(),
+        quantity: 0,
+        items: vec![Item { name: "ok".to_string() }, Item { name: String::new() }],
+    };
+
+    let error: ApplicationError = order.validate().unwrap_err().into();
+
+    assert_eq!(error.kind, ErrorKind::BadRequest);
+    assert_eq!(error.code, "validation_failed");
+    let fields: Vec<(&str, &str, Option<&str>)> = error
+        .fields
+        .iter()
+        .map(|field| (field.field.as_str(), field.code.as_str(), field.message.as_deref()))
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            ("email", "email", Some("Invalid email")),
+            ("items[1].name", "length", Some("Name is required")),
+            ("quantity", "range", None),
+        ]
+    );
+
+    let message: ApplicationError = ValidationFailure::from("Name is required").into();
+    assert_eq!(message.detail, "Name is required");
+    assert!(message.fields.is_empty());
+}
+
+#[test]
+fn test_use_case_errors_map_to_stable_codes() {
+    let not_found: ApplicationError = ArticleRepositoryError::NotFound.into();
+    assert_eq!((not_found.kind, not_found.code), (ErrorKind::NotFound, "article_not_found"));
+
+    let locked: ApplicationError = LoginUserError::AccountLocked { retry_after_seconds: 90 }.into();
+    assert_eq!((locked.kind, locked.code, locked.retry_after), (ErrorKind::TooManyRequests, "account_locked", Some(90)));
+
+    let credentials: ApplicationError = LoginUserError::InvalidCredentials.into();
+    assert_eq!((credentials.kind, credentials.code), (ErrorKind::Unauthorized, "invalid_credentials"));
+}
+
+#[test]
+fn test_account_errors_map_to_stable_codes() {
+    let codes: Vec<(ErrorKind, &str)> = [
+        ApplicationError::from(GetProfileError::UserNotFound),
+        RegisterUserError::EmailAlreadyExists.into(),
+        UpdateProfileError::EmailAlreadyExists.into(),
+        ConfirmEmailChangeError::EmailAlreadyExists.into(),
+        ConfirmEmailChangeError::TokenExpired.into(),
+        ChangePasswordError::InvalidCurrentPassword.into(),
+        RevokeApiKeyError::NotFound.into(),
+    ]
+    .into_iter()
+    .map(|error| (error.kind, error.code))
+    .collect();
+
+    assert_eq!(
+        codes,
+        vec![
+            (ErrorKind::NotFound, "user_not_found"),
+            (ErrorKind::Conflict, "email_taken"),
+            (ErrorKind::Conflict, "email_taken"),
+            (ErrorKind::Conflict, "email_taken"),
+            (ErrorKind::BadRequest, "verification_token_expired"),
+            (ErrorKind::Forbidden, "invalid_current_password"),
+            (ErrorKind::NotFound, "api_key_not_found"),
+        ]
+    );
+}
+
+#[test]
+fn test_internal_errors_keep_the_cause_out_of_the_detail() {
+    let database: ApplicationError = ArticleRepositoryError::DatabaseError("connection refused".to_string()).into();
+    assert_eq!(database.code, "internal_error");
+    assert_eq!(database.detail, "Internal server error");
+    assert_eq!(database.cause.as_deref(), Some("connection refused"));
+
+    let categories: ApplicationError = ListArticleCategoriesError::RepositoryError("timeout".to_string()).into();
+    assert_eq!(categories.kind, ErrorKind::Internal);
+    assert!(!categories.detail.contains("timeout"));
+
+    let provider: ApplicationError = CompleteOidcLoginError::ProviderUnavailable("503 from issuer".to_string()).into();
+    assert_eq!((provider.kind, provider.code), (ErrorKind::BadGateway, "identity_provider_unavailable"));
+    assert_eq!(provider.cause.as_deref(), Some("503 from issuer"));
+    assert!(!provider.detail.contains("503"));
+}
\ No newline at end of file
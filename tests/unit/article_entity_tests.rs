@@ -1,5 +1,7 @@
 use chrono::Utc;
-use nexight_backend::domain::entities::article::{Article, CreateArticleDto, UpdateArticleDto};
+use nexight_backend::domain::entities::article::{
+    Article, ArticleListFilter, ArticleSort, CreateArticleDto, UpdateArticleDto,
+};
 use uuid::Uuid;
 
 #[test]
@@ -30,13 +32,13 @@ fn test_create_article_with_dto() {
     assert_eq!(article.ai_summary, None);
     assert_eq!(article.rate, None);
     assert_eq!(article.keywords, None);
-    assert_eq!(article.processing_ai_summary, false);
-    assert_eq!(article.processing_rating, false);
-    assert_eq!(article.processing_keywords, false);
+    assert!(!article.processing_ai_summary);
+    assert!(!article.processing_rating);
+    assert!(!article.processing_keywords);
     assert_eq!(article.category_id, None);
-    assert_eq!(article.processing_categorizing, false);
+    assert!(!article.processing_categorizing);
     assert_eq!(article.ai_columnist, None);
-    assert_eq!(article.processing_columnist, false);
+    assert!(!article.processing_columnist);
 }
 
 #[test]
@@ -222,3 +224,34 @@ fn test_article_equality() {
 
     assert_eq!(article1, article2);
 }
+
+#[test]
+fn test_list_filter_search_matches_title_description_or_content_ignoring_case() {
+    let article = Article::new(CreateArticleDto {
+        title: "Release notes".to_string(),
+        description: "What changed in Tokio".to_string(),
+        link: "https://example.com".to_string(),
+        pub_date: Utc::now(),
+        media: None,
+        content: "The new scheduler is faster".to_string(),
+        creator: "Creator".to_string(),
+        feed_id: Uuid::new_v4(),
+    });
+    let search = |text: &str| ArticleListFilter {
+        search: Some(text.to_string()),
+        ..Default::default()
+    };
+
+    assert!(search("RELEASE").matches(&article));
+    assert!(search("tokio").matches(&article));
+    assert!(search("Scheduler").matches(&article));
+    assert!(!search("async-std").matches(&article));
+}
+
+#[test]
+fn test_article_sort_round_trips_through_its_name() {
+    for sort in [ArticleSort::Recent, ArticleSort::Rate, ArticleSort::UserRating] {
+        assert_eq!(ArticleSort::parse(sort.as_str()), Some(sort));
+    }
+    assert_eq!(ArticleSort::parse("popular"), None);
+}
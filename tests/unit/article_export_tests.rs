@@ -0,0 +1,89 @@
+use chrono::{Duration, TimeZone, Utc};
+use nexight_backend::application::services::article_export_writer::ArticleExportWriter;
+use nexight_backend::domain::entities::article::{Article, CreateArticleDto};
+use nexight_backend::domain::entities::article_export::{ArticleExportCursor, ExportColumn, ExportFormat};
+use uuid::Uuid;
+
+fn article() -> Article {
+    let mut article = Article::new(CreateArticleDto {
+        title: "Rust, \"fast\" and safe".to_string(),
+        description: "Line one\nLine two".to_string(),
+        link: "https://example.com/rust".to_string(),
+        pub_date: Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap(),
+        media: None,
+        content: "Content".to_string(),
+        creator: "Jane".to_string(),
+        feed_id: Uuid::new_v4(),
+    });
+    article.rate = Some(8);
+    article.user_rating_avg = Some(7.5);
+    article.user_rating_count = 2;
+    article
+}
+
+#[test]
+fn test_parse_columns_keeps_order_and_rejects_unknown_names() {
+    assert_eq!(ExportColumn::parse_list("").unwrap(), ExportColumn::ALL.to_vec());
+    assert_eq!(
+        ExportColumn::parse_list("title, category_name,title,rate").unwrap(),
+        vec![ExportColumn::Title, ExportColumn::CategoryName, ExportColumn::Rate]
+    );
+    assert_eq!(ExportColumn::parse_list("title,password"), Err("password".to_string()));
+
+    assert_eq!(ExportFormat::parse("ndjson"), Some(ExportFormat::Ndjson));
+    assert_eq!(ExportFormat::parse("parquet"), None);
+}
+
+#[test]
+fn test_csv_quotes_fields_and_leaves_nulls_empty() {
+    let writer = ArticleExportWriter::new(
+        ExportFormat::Csv,
+        vec![ExportColumn::Title, ExportColumn::Description, ExportColumn::Media, ExportColumn::Rate, ExportColumn::CategoryName],
+    );
+
+    assert_eq!(writer.header(), "title,description,media,rate,category_name\r\n");
+    assert_eq!(
+        writer.row(&article(), Some("Tech")),
+        "\"Rust, \"\"fast\"\" and safe\",\"Line one\nLine two\",,8,Tech\r\n"
+    );
+}
+
+#[test]
+fn test_ndjson_keeps_types_and_nulls() {
+    let source = article();
+    let writer = ArticleExportWriter::new(
+        ExportFormat::Ndjson,
+        vec![ExportColumn::Id, ExportColumn::PubDate, ExportColumn::Rate, ExportColumn::UserRating, ExportColumn::CategoryName],
+    );
+
+    assert_eq!(writer.header(), "");
+    let line = writer.row(&source, None);
+    assert!(line.ends_with('\n'));
+
+    let json: serde_json::Value = serde_json::from_str(line.trim_end()).unwrap();
+    assert_eq!(json["id"], source.id.to_string());
+    assert_eq!(json["pub_date"], "2026-10-01T12:00:00+00:00");
+    assert_eq!(json["rate"], 8);
+    assert_eq!(json["user_rating"], 7.5);
+    assert!(json["category_name"].is_null());
+    assert_eq!(json.as_object().unwrap().len(), 5);
+}
+
+#[test]
+fn test_cursor_orders_by_publication_date_then_id() {
+    let first = article();
+    let cursor = ArticleExportCursor::after(&first);
+
+    let mut older = article();
+    older.pub_date = first.pub_date - Duration::minutes(1);
+    assert!(cursor.precedes(&older));
+
+    let mut same_date = article();
+    same_date.id = Uuid::nil();
+    assert!(cursor.precedes(&same_date));
+
+    let mut newer = article();
+    newer.pub_date = first.pub_date + Duration::minutes(1);
+    assert!(!cursor.precedes(&newer));
+    assert!(!cursor.precedes(&first));
+}
@@ -0,0 +1,67 @@
+use nexight_backend::domain::entities::article_import::{ImportArticleRecord, ImportLineStatus, ImportReport};
+use uuid::Uuid;
+use validator::Validate;
+
+fn record_json() -> serde_json::Value {
+    serde_json::json!({
+        "id": "8e3bc6a4-7d1d-4a35-9d55-6a2b7a8b0c11",
+        "title": "Imported",
+        "description": "Description",
+        "link": "https://example.com/imported",
+        "pub_date": "2026-10-01T12:00:00Z",
+        "content": "Content",
+        "creator": "Author",
+        "feed_url": "https://example.com/feed.xml",
+        "category": "Tech",
+        "rate": 7,
+        "user_rating": 4.5,
+    })
+}
+
+#[test]
+fn test_record_accepts_export_fields_and_category_alias() {
+    let record: ImportArticleRecord = serde_json::from_value(record_json()).unwrap();
+
+    assert_eq!(record.category_name.as_deref(), Some("Tech"));
+    assert_eq!(record.feed_url.as_deref(), Some("https://example.com/feed.xml"));
+    assert!(record.validate().is_ok());
+
+    let feed_id = Uuid::new_v4();
+    let article = record.into_article(feed_id, None);
+    assert_eq!(article.feed_id, feed_id);
+    assert_eq!(article.rate, Some(7));
+    assert_eq!(article.link, "https://example.com/imported");
+}
+
+#[test]
+fn test_record_validation_rejects_invalid_fields() {
+    let mut json = record_json();
+    json["title"] = serde_json::json!("");
+    json["rate"] = serde_json::json!(11);
+    let record: ImportArticleRecord = serde_json::from_value(json).unwrap();
+
+    let errors = record.validate().unwrap_err();
+    assert!(errors.field_errors().contains_key("title"));
+    assert!(errors.field_errors().contains_key("rate"));
+
+    let mut missing = record_json();
+    missing.as_object_mut().unwrap().remove("link");
+    assert!(serde_json::from_value::<ImportArticleRecord>(missing).is_err());
+}
+
+#[test]
+fn test_report_counts_each_status() {
+    let mut report = ImportReport::default();
+    let id = Uuid::new_v4();
+    report.inserted(1, id);
+    report.skipped(2, "Duplicate link in import");
+    report.failed(3, "Feed not found: x");
+
+    assert_eq!((report.inserted, report.skipped, report.failed), (1, 1, 1));
+    assert_eq!(report.lines[0].article_id, Some(id));
+    assert_eq!(report.lines[2].status, ImportLineStatus::Failed);
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["lines"][1]["status"], "skipped");
+    assert_eq!(json["lines"][1]["reason"], "Duplicate link in import");
+}
\ No newline at end of file
@@ -1,5 +1,5 @@
 use chrono::Utc;
-use nexight_backend::domain::entities::article::{Article, CreateArticleDto, UpdateArticleDto};
+use nexight_backend::domain::entities::article::{Article, ArticleListFilter, CreateArticleDto, UpdateArticleDto};
 use nexight_backend::domain::repositories::article_repository::{ArticleRepository, ArticleRepositoryError};
 use nexight_backend::infrastructure::repositories::in_memory_article_repository::InMemoryArticleRepository;
 use uuid::Uuid;
@@ -30,7 +30,7 @@ async fn test_create_article() {
     let created = result.unwrap();
     assert_eq!(created.title, "Test Article");
     assert_eq!(created.creator, "Test Author");
-    assert_eq!(created.processing_ai_summary, false);
+    assert!(!created.processing_ai_summary);
 }
 
 #[tokio::test]
@@ -48,6 +48,32 @@ async fn test_create_multiple_articles() {
     assert_eq!(count, 5);
 }
 
+#[tokio::test]
+async fn test_create_rejects_a_duplicate_link() {
+    let repo = InMemoryArticleRepository::new();
+    repo.create(Article::new(create_test_article_dto("Same Link"))).await.unwrap();
+
+    let result = repo.create(Article::new(create_test_article_dto("Same Link"))).await;
+    assert!(matches!(result, Err(ArticleRepositoryError::LinkAlreadyExists)));
+}
+
+#[tokio::test]
+async fn test_create_batch_skips_existing_and_repeated_links() {
+    let repo = InMemoryArticleRepository::new();
+    repo.create(Article::new(create_test_article_dto("Existing"))).await.unwrap();
+
+    let batch = vec![
+        Article::new(create_test_article_dto("Existing")),
+        Article::new(create_test_article_dto("Fresh")),
+        Article::new(create_test_article_dto("Fresh")),
+    ];
+    let inserted = repo.create_batch(batch).await.unwrap();
+
+    assert_eq!(inserted.len(), 1);
+    assert_eq!(inserted[0].title, "Fresh");
+    assert_eq!(repo.count().await.unwrap(), 2);
+}
+
 #[tokio::test]
 async fn test_find_by_id_existing() {
     let repo = InMemoryArticleRepository::new();
@@ -337,3 +363,60 @@ async fn test_article_update_method() {
     // Verifica que updated_at foi atualizado
     assert!(article.updated_at > original_created_at);
 }
+
+#[tokio::test]
+async fn test_list_filtered_by_feeds() {
+    let repo = InMemoryArticleRepository::new();
+    let feed_id = Uuid::new_v4();
+
+    for i in 1..=3 {
+        let mut dto = create_test_article_dto(&format!("Feed Article {}", i));
+        dto.feed_id = feed_id;
+        repo.create(Article::new(dto)).await.unwrap();
+    }
+    repo.create(Article::new(create_test_article_dto("Other Article"))).await.unwrap();
+
+    let filter = ArticleListFilter {
+        feed_ids: Some(vec![feed_id]),
+        ..Default::default()
+    };
+    let page = repo.list_filtered(&filter, 1, 2).await.unwrap();
+
+    assert_eq!(page.total, 3);
+    assert_eq!(page.total_pages, 2);
+    assert_eq!(page.articles.len(), 2);
+    assert!(page.articles.iter().all(|a| a.feed_id == feed_id));
+
+    // Sem filtro, equivale a `list`
+    let all = repo.list_filtered(&ArticleListFilter::default(), 1, 10).await.unwrap();
+    assert_eq!(all.total, 4);
+}
+
+#[tokio::test]
+async fn test_list_filtered_with_reader_state() {
+    let repo = InMemoryArticleRepository::new();
+    let reader = Uuid::new_v4();
+    let read = repo.create(Article::new(create_test_article_dto("Read Article"))).await.unwrap();
+    repo.create(Article::new(create_test_article_dto("Unread Article"))).await.unwrap();
+
+    repo.mark_read(reader, read.id, Utc::now()).await.unwrap();
+
+    // Sem leitor, os artigos não trazem estado de leitura
+    let anonymous = repo.list_filtered(&ArticleListFilter::default(), 1, 10).await.unwrap();
+    assert!(anonymous.articles.iter().all(|a| a.user_state.is_none()));
+
+    let filter = ArticleListFilter {
+        reader_id: Some(reader),
+        unread_only: true,
+        ..Default::default()
+    };
+    let unread = repo.list_filtered(&filter, 1, 10).await.unwrap();
+    assert_eq!(unread.total, 1);
+    assert_eq!(unread.articles[0].title, "Unread Article");
+    assert_eq!(unread.articles[0].user_state.as_ref().map(|s| s.read), Some(false));
+
+    // Removido o artigo, o estado de leitura vai junto
+    repo.delete(read.id).await.unwrap();
+    let counts = repo.unread_counts(reader, &[read.feed_id]).await.unwrap();
+    assert_eq!(counts.total, 0);
+}
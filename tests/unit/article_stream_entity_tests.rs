@@ -0,0 +1,55 @@
+use chrono::Utc;
+use nexight_backend::domain::entities::article::{Article, CreateArticleDto};
+use nexight_backend::domain::entities::article_stream::{ArticleStreamEvent, ArticleStreamFilter};
+use nexight_backend::domain::entities::webhook::{ArticleEvent, WebhookEventType};
+use uuid::Uuid;
+
+fn article(category_id: Option<Uuid>) -> Article {
+    let mut article = Article::new(CreateArticleDto {
+        title: "Rust 2026".to_string(),
+        description: "Description".to_string(),
+        link: "https://example.com/rust-2026".to_string(),
+        pub_date: Utc::now(),
+        media: None,
+        content: "Content".to_string(),
+        creator: "Author".to_string(),
+        feed_id: Uuid::new_v4(),
+    });
+    article.category_id = category_id;
+    article
+}
+
+#[test]
+fn test_stream_event_carries_seq_feed_and_category() {
+    let category_id = Uuid::new_v4();
+    let article = article(Some(category_id));
+    let mut event = ArticleEvent::new(WebhookEventType::ArticleEnriched, &article);
+    event.seq = 42;
+
+    let stream_event = ArticleStreamEvent::from_event(&event).unwrap();
+
+    assert_eq!(stream_event.seq, 42);
+    assert_eq!(stream_event.article_id, article.id);
+    assert_eq!(stream_event.feed_id, Some(article.feed_id));
+    assert_eq!(stream_event.category_id, Some(category_id));
+    assert_eq!(stream_event.data["title"], "Rust 2026");
+}
+
+#[test]
+fn test_deleted_and_test_events_are_not_streamed() {
+    let article = article(None);
+
+    assert!(ArticleStreamEvent::from_event(&ArticleEvent::new(WebhookEventType::ArticleDeleted, &article)).is_none());
+    assert!(ArticleStreamEvent::from_event(&ArticleEvent::new(WebhookEventType::Test, &article)).is_none());
+}
+
+#[test]
+fn test_filter_requires_every_given_field() {
+    let article = article(None);
+    let event = ArticleStreamEvent::from_event(&ArticleEvent::new(WebhookEventType::ArticleCreated, &article)).unwrap();
+
+    assert!(ArticleStreamFilter::default().matches(&event));
+    assert!(ArticleStreamFilter { feed_id: Some(article.feed_id), category_id: None }.matches(&event));
+    assert!(!ArticleStreamFilter { feed_id: None, category_id: Some(Uuid::new_v4()) }.matches(&event));
+    assert!(!ArticleStreamFilter { feed_id: Some(Uuid::new_v4()), category_id: None }.matches(&event));
+}
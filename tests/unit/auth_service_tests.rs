@@ -1,9 +1,10 @@
 use nexight_backend::application::services::auth_service::AuthService;
+use nexight_backend::application::services::jwt_keys::JwtKeySet;
 use uuid::Uuid;
 use chrono::Utc;
 
 fn create_test_auth_service() -> AuthService {
-    AuthService::new("test_secret_key_for_testing".to_string(), 24)
+    AuthService::with_keys(JwtKeySet::hmac("test_secret_key_for_testing"), 24)
 }
 
 #[test]
@@ -62,12 +63,12 @@ fn test_verify_password_invalid_hash() {
 }
 
 #[test]
-fn test_generate_token() {
+fn test_generate_session_token() {
     let auth_service = create_test_auth_service();
     let user_id = Uuid::new_v4();
     let email = "test@example.com";
 
-    let token = auth_service.generate_token(user_id, email);
+    let token = auth_service.generate_session_token(user_id, email, Uuid::new_v4());
     assert!(token.is_ok());
     assert!(!token.unwrap().is_empty());
 }
@@ -78,13 +79,16 @@ fn test_verify_valid_token() {
     let user_id = Uuid::new_v4();
     let email = "test@example.com";
 
-    let token = auth_service.generate_token(user_id, email).unwrap();
+    let session_id = Uuid::new_v4();
+
+    let token = auth_service.generate_session_token(user_id, email, session_id).unwrap();
     let claims = auth_service.verify_token(&token);
 
     assert!(claims.is_ok());
     let claims = claims.unwrap();
     assert_eq!(claims.sub, user_id.to_string());
     assert_eq!(claims.email, email);
+    assert_eq!(claims.sid, Some(session_id.to_string()));
 }
 
 #[test]
@@ -98,13 +102,13 @@ fn test_verify_invalid_token() {
 
 #[test]
 fn test_verify_token_with_wrong_secret() {
-    let auth_service1 = AuthService::new("secret1".to_string(), 24);
-    let auth_service2 = AuthService::new("secret2".to_string(), 24);
+    let auth_service1 = AuthService::with_keys(JwtKeySet::hmac("secret1"), 24);
+    let auth_service2 = AuthService::with_keys(JwtKeySet::hmac("secret2"), 24);
 
     let user_id = Uuid::new_v4();
     let email = "test@example.com";
 
-    let token = auth_service1.generate_token(user_id, email).unwrap();
+    let token = auth_service1.generate_session_token(user_id, email, Uuid::new_v4()).unwrap();
     let result = auth_service2.verify_token(&token);
 
     assert!(result.is_err());
@@ -116,7 +120,7 @@ fn test_token_expiration_time() {
     let user_id = Uuid::new_v4();
     let email = "test@example.com";
 
-    let token = auth_service.generate_token(user_id, email).unwrap();
+    let token = auth_service.generate_session_token(user_id, email, Uuid::new_v4()).unwrap();
     let claims = auth_service.verify_token(&token).unwrap();
 
     let now = Utc::now().timestamp();
@@ -148,6 +152,8 @@ fn test_verify_expired_token() {
         email: email.to_string(),
         exp: expired_time.timestamp(),
         iat: (now - chrono::Duration::hours(25)).timestamp(),
+        token_type: Default::default(),
+        sid: None,
     };
 
     // Generate expired token with same secret
@@ -219,7 +225,7 @@ fn test_token_with_unicode_email() {
     let user_id = Uuid::new_v4();
     let email = "用户@example.com"; // Unicode email
 
-    let token = auth_service.generate_token(user_id, email);
+    let token = auth_service.generate_session_token(user_id, email, Uuid::new_v4());
     assert!(token.is_ok());
 
     let token_str = token.unwrap();
@@ -236,9 +242,26 @@ fn test_generate_token_with_nil_uuid() {
     let user_id = Uuid::nil();
     let email = "test@example.com";
 
-    let token = auth_service.generate_token(user_id, email);
+    let token = auth_service.generate_session_token(user_id, email, Uuid::new_v4());
     assert!(token.is_ok());
 
     let claims = auth_service.verify_token(&token.unwrap()).unwrap();
     assert_eq!(claims.sub, user_id.to_string());
 }
+
+#[test]
+fn test_mfa_token_is_not_an_access_token() {
+    let auth_service = create_test_auth_service();
+    let user_id = Uuid::new_v4();
+
+    let mfa_token = auth_service
+        .generate_mfa_token(user_id, "test@example.com", chrono::Duration::minutes(5))
+        .unwrap();
+
+    assert!(auth_service.verify_token(&mfa_token).is_err());
+    let claims = auth_service.verify_mfa_token(&mfa_token).unwrap();
+    assert_eq!(claims.sub, user_id.to_string());
+
+    let access_token = auth_service.generate_session_token(user_id, "test@example.com", Uuid::new_v4()).unwrap();
+    assert!(auth_service.verify_mfa_token(&access_token).is_err());
+}
@@ -0,0 +1,39 @@
+use actix_web::test::TestRequest;
+use nexight_backend::infrastructure::web::client_ip::TrustedProxies;
+
+fn request(peer: &str, forwarded_for: Option<&str>) -> actix_web::HttpRequest {
+    let mut req = TestRequest::default().peer_addr(peer.parse().unwrap());
+    if let Some(value) = forwarded_for {
+        req = req.insert_header(("X-Forwarded-For", value));
+    }
+    req.to_http_request()
+}
+
+#[test]
+fn test_forwarded_header_is_ignored_from_untrusted_peer() {
+    let proxies = TrustedProxies::parse("10.0.0.0/8");
+
+    let ip = proxies.client_ip(&request("203.0.113.7:5000", Some("198.51.100.1")));
+
+    assert_eq!(ip.as_deref(), Some("203.0.113.7"));
+}
+
+#[test]
+fn test_trusted_proxy_uses_rightmost_untrusted_address() {
+    let proxies = TrustedProxies::parse("10.0.0.0/8, 172.16.0.5");
+
+    // O cliente pode forjar o primeiro valor; o proxy acrescenta o endereço real à direita
+    let ip = proxies.client_ip(&request("10.0.0.2:5000", Some("1.2.3.4, 198.51.100.9, 172.16.0.5")));
+
+    assert_eq!(ip.as_deref(), Some("198.51.100.9"));
+}
+
+#[test]
+fn test_no_trusted_proxies_and_invalid_chain_fall_back_to_peer() {
+    let ip = TrustedProxies::default().client_ip(&request("10.0.0.2:5000", Some("198.51.100.1")));
+    assert_eq!(ip.as_deref(), Some("10.0.0.2"));
+
+    let proxies = TrustedProxies::parse("10.0.0.2, not-an-ip");
+    let ip = proxies.client_ip(&request("10.0.0.2:5000", Some("garbage, 198.51.100.1")));
+    assert_eq!(ip.as_deref(), Some("10.0.0.2"));
+}
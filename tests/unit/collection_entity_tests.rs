@@ -0,0 +1,32 @@
+use nexight_backend::domain::entities::collection::{Collection, CollectionResponse};
+use uuid::Uuid;
+
+#[test]
+fn test_new_collection_is_private_with_trimmed_name() {
+    let collection = Collection::new(Uuid::new_v4(), "  Read later ");
+
+    assert_eq!(collection.name, "Read later");
+    assert_eq!(collection.share_token, None);
+}
+
+#[test]
+fn test_share_keeps_existing_token() {
+    let mut collection = Collection::new(Uuid::new_v4(), "Rust");
+
+    collection.share("first".to_string());
+    collection.share("second".to_string());
+
+    assert_eq!(collection.share_token.as_deref(), Some("first"));
+    assert!(CollectionResponse::from(collection).shared);
+}
+
+#[test]
+fn test_unshare_revokes_link() {
+    let mut collection = Collection::new(Uuid::new_v4(), "Rust");
+    collection.share("token".to_string());
+
+    collection.unshare();
+
+    assert_eq!(collection.share_token, None);
+    assert!(!CollectionResponse::from(collection).shared);
+}
@@ -0,0 +1,89 @@
+use nexight_backend::infrastructure::web::content::{ContentError, MediaType, DOCUMENT_MEDIA_TYPES, LIST_MEDIA_TYPES};
+use nexight_backend::infrastructure::web::server::{HttpMethod, Request};
+use nexight_backend::infrastructure::web::Response;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Item {
+    name: String,
+    rate: Option<i32>,
+    tags: Vec<String>,
+}
+
+fn request(content_type: Option<&str>, body: Vec<u8>) -> Request {
+    let mut headers = HashMap::new();
+    if let Some(content_type) = content_type {
+        headers.insert("content-type".to_string(), content_type.to_string());
+    }
+
+    Request {
+        method: HttpMethod::POST,
+        path: "/items".to_string(),
+        headers,
+        query_params: HashMap::new(),
+        body: Some(body),
+    }
+}
+
+#[test]
+fn test_negotiate_respects_quality_specificity_and_server_order() {
+    assert_eq!(MediaType::negotiate(None, LIST_MEDIA_TYPES), Some(MediaType::Json));
+    assert_eq!(MediaType::negotiate(Some("*/*"), LIST_MEDIA_TYPES), Some(MediaType::Json));
+    assert_eq!(MediaType::negotiate(Some("text/csv"), LIST_MEDIA_TYPES), Some(MediaType::Csv));
+    assert_eq!(MediaType::negotiate(Some("text/*"), LIST_MEDIA_TYPES), Some(MediaType::Csv));
+    assert_eq!(
+        MediaType::negotiate(Some("application/json;q=0.5, application/x-msgpack"), LIST_MEDIA_TYPES),
+        Some(MediaType::MessagePack)
+    );
+    assert_eq!(
+        MediaType::negotiate(Some("application/json;q=0, */*;q=0.1"), DOCUMENT_MEDIA_TYPES),
+        Some(MediaType::MessagePack)
+    );
+    assert_eq!(
+        MediaType::negotiate(Some("text/html,application/xhtml+xml,*/*;q=0.8"), LIST_MEDIA_TYPES),
+        Some(MediaType::Json)
+    );
+    assert_eq!(MediaType::negotiate(Some("text/csv"), DOCUMENT_MEDIA_TYPES), None);
+    assert_eq!(MediaType::negotiate(Some("application/xml"), LIST_MEDIA_TYPES), None);
+}
+
+#[test]
+fn test_response_serializes_msgpack_and_csv() {
+    let items = vec![
+        Item { name: "Rust, \"fast\"".to_string(), rate: Some(9), tags: vec!["a".to_string()] },
+        Item { name: "Go".to_string(), rate: None, tags: Vec::new() },
+    ];
+
+    let csv = Response::ok().csv(&items).unwrap();
+    assert_eq!(csv.headers["Content-Type"], "text/csv; charset=utf-8");
+    assert_eq!(
+        String::from_utf8(csv.body.unwrap()).unwrap(),
+        "name,rate,tags\r\n\"Rust, \"\"fast\"\"\",9,\"[\"\"a\"\"]\"\r\nGo,,[]\r\n"
+    );
+
+    let msgpack = Response::ok().negotiated(MediaType::MessagePack, &items[0]).unwrap();
+    assert_eq!(msgpack.headers["Content-Type"], "application/msgpack");
+    let decoded: Item = rmp_serde::from_slice(&msgpack.body.unwrap()).unwrap();
+    assert_eq!(decoded, items[0]);
+
+    assert!(matches!(Response::ok().csv(&[1, 2]), Err(ContentError::NotTabular)));
+    assert!(matches!(Response::ok().negotiated(MediaType::Csv, &items), Err(ContentError::NotTabular)));
+}
+
+#[test]
+fn test_request_body_follows_content_type() {
+    let item = Item { name: "Rust".to_string(), rate: Some(8), tags: vec!["lang".to_string()] };
+
+    let json = request(None, serde_json::to_vec(&item).unwrap());
+    assert_eq!(json.body::<Item>().unwrap(), item);
+
+    let msgpack = request(Some("application/msgpack"), rmp_serde::to_vec_named(&item).unwrap());
+    assert_eq!(msgpack.body::<Item>().unwrap(), item);
+
+    let mismatched = request(Some("application/json; charset=utf-8"), rmp_serde::to_vec_named(&item).unwrap());
+    assert!(matches!(mismatched.body::<Item>(), Err(ContentError::Json(_))));
+
+    let xml = request(Some("application/xml"), b"<item/>".to_vec());
+    assert!(matches!(xml.body::<Item>(), Err(ContentError::UnsupportedMediaType(_))));
+}
\ No newline at end of file
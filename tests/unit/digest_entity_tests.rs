@@ -0,0 +1,164 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use nexight_backend::application::services::digest_renderer::DigestRenderer;
+use nexight_backend::domain::entities::article::{Article, ArticleResponse, CreateArticleDto};
+use nexight_backend::domain::entities::digest::{DigestFrequency, DigestPeriod, DigestSettings, DigestSettingsDto};
+use uuid::Uuid;
+use validator::Validate;
+
+fn settings(frequency: DigestFrequency, hour: i32, weekday: Option<i32>, timezone: &str) -> DigestSettings {
+    DigestSettings::new(
+        Uuid::new_v4(),
+        DigestSettingsDto {
+            frequency,
+            hour,
+            weekday,
+            timezone: timezone.to_string(),
+            max_articles: None,
+            min_rate: None,
+            enabled: true,
+        },
+    )
+}
+
+fn utc(year: i32, month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, day, hour, 0, 0).unwrap()
+}
+
+#[test]
+fn test_daily_period_ends_at_the_last_local_send_time() {
+    let daily = settings(DigestFrequency::Daily, 8, Some(3), "America/Sao_Paulo");
+    assert_eq!(daily.weekday, None);
+    assert_eq!(daily.max_articles, DigestSettings::DEFAULT_MAX_ARTICLES);
+
+    // 09:00 em São Paulo (UTC-3): o envio das 08:00 de hoje já passou
+    let period = daily.latest_period(utc(2026, 10, 18, 12)).unwrap();
+    assert_eq!(period.end, utc(2026, 10, 18, 11));
+    assert_eq!(period.start, utc(2026, 10, 17, 11));
+
+    // 07:00 locais: o período mais recente ainda é o de ontem
+    let period = daily.latest_period(utc(2026, 10, 18, 10)).unwrap();
+    assert_eq!(period.end, utc(2026, 10, 17, 11));
+}
+
+#[test]
+fn test_weekly_period_uses_the_configured_weekday() {
+    let weekly = settings(DigestFrequency::Weekly, 9, None, "UTC");
+    assert_eq!(weekly.weekday, Some(DigestSettings::DEFAULT_WEEKDAY));
+
+    // 18/10/2026 é um domingo; o último envio foi na segunda, 12/10
+    let period = weekly.latest_period(utc(2026, 10, 18, 12)).unwrap();
+    assert_eq!(period.end, utc(2026, 10, 12, 9));
+    assert_eq!(period.start, utc(2026, 10, 5, 9));
+
+    // No horário exato do envio, o período que termina agora já está encerrado
+    let period = weekly.latest_period(utc(2026, 10, 19, 9)).unwrap();
+    assert_eq!(period.end, utc(2026, 10, 19, 9));
+}
+
+#[test]
+fn test_period_follows_daylight_saving_changes() {
+    // Em 08/03/2026 Nova York pula das 02:00 para as 03:00: o envio vai para a primeira hora válida
+    let daily = settings(DigestFrequency::Daily, 2, None, "America/New_York");
+    let period = daily.latest_period(utc(2026, 3, 8, 12)).unwrap();
+    assert_eq!(period.end, utc(2026, 3, 8, 7));
+    assert_eq!(period.start, utc(2026, 3, 7, 7));
+
+    // Em 25/10/2026 Berlim volta ao horário de inverno: o dia tem 25 horas
+    let daily = settings(DigestFrequency::Daily, 8, None, "Europe/Berlin");
+    let period = daily.latest_period(utc(2026, 10, 25, 12)).unwrap();
+    assert_eq!(period.end, utc(2026, 10, 25, 7));
+    assert_eq!(period.start, utc(2026, 10, 24, 6));
+}
+
+#[test]
+fn test_settings_validation() {
+    let valid = DigestSettingsDto {
+        frequency: DigestFrequency::Weekly,
+        hour: 23,
+        weekday: Some(7),
+        timezone: "Europe/Lisbon".to_string(),
+        max_articles: Some(50),
+        min_rate: Some(5),
+        enabled: true,
+    };
+    assert!(valid.validate().is_ok());
+
+    let bad_timezone = DigestSettingsDto {
+        timezone: "Mars/Olympus".to_string(),
+        ..valid.clone()
+    };
+    assert!(bad_timezone.validate().is_err());
+
+    let bad_hour = DigestSettingsDto { hour: 24, ..valid.clone() };
+    assert!(bad_hour.validate().is_err());
+
+    let bad_weekday = DigestSettingsDto {
+        weekday: Some(0),
+        ..valid.clone()
+    };
+    assert!(bad_weekday.validate().is_err());
+
+    let too_many = DigestSettingsDto {
+        max_articles: Some(51),
+        ..valid
+    };
+    assert!(too_many.validate().is_err());
+}
+
+fn article(title: &str, description: &str, ai_summary: Option<&str>, rate: Option<i32>) -> ArticleResponse {
+    let mut article = Article::new(CreateArticleDto {
+        title: title.to_string(),
+        description: description.to_string(),
+        link: "https://example.com/a?x=1&y=2".to_string(),
+        pub_date: Utc::now(),
+        media: None,
+        content: "Content".to_string(),
+        creator: "Author".to_string(),
+        feed_id: Uuid::new_v4(),
+    });
+    article.ai_summary = ai_summary.map(str::to_string);
+    article.rate = rate;
+    ArticleResponse::from(article)
+}
+
+#[test]
+fn test_renderer_builds_text_and_escaped_html() {
+    let period = DigestPeriod {
+        start: utc(2026, 10, 17, 11),
+        end: utc(2026, 10, 18, 11),
+    };
+    let articles = vec![
+        article("Rust <3 Async", "Ignored description", Some("Resumo da IA"), Some(9)),
+        article("Second", &"x".repeat(400), None, None),
+    ];
+
+    let digest = DigestRenderer::render(
+        "Ana",
+        DigestFrequency::Daily,
+        "America/Sao_Paulo".parse().unwrap(),
+        period,
+        articles,
+    );
+
+    assert_eq!(digest.subject, "Seu resumo diário do Nexight - 18/10/2026");
+    assert!(digest.text_body.starts_with("Olá Ana,"));
+    assert!(digest.text_body.contains("1. Rust <3 Async (nota 9/10)\n   Resumo da IA\n   https://example.com/a?x=1&y=2"));
+    assert!(!digest.text_body.contains("Ignored description"));
+    assert!(digest.text_body.contains(&format!("   {}...", "x".repeat(300))));
+
+    assert!(digest.html_body.contains("Rust &lt;3 Async"));
+    assert!(digest.html_body.contains("href=\"https://example.com/a?x=1&amp;y=2\""));
+    assert!(!digest.html_body.contains("<3"));
+}
+
+#[test]
+fn test_weekly_subject_covers_the_whole_period() {
+    let period = DigestPeriod {
+        start: utc(2026, 10, 5, 9),
+        end: utc(2026, 10, 12, 9),
+    };
+    let digest = DigestRenderer::render("Ana", DigestFrequency::Weekly, "UTC".parse().unwrap(), period, Vec::new());
+
+    assert_eq!(digest.subject, "Seu resumo semanal do Nexight - 05/10/2026 a 12/10/2026");
+    assert_eq!(period.end - period.start, Duration::days(7));
+}
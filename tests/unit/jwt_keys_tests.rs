@@ -0,0 +1,215 @@
+use nexight_backend::application::services::auth_service::AuthService;
+use nexight_backend::application::services::jwt_keys::{JwtKey, JwtKeySet};
+use nexight_backend::infrastructure::security::jwt_config::{JwtConfig, JwtConfigError};
+use jsonwebtoken::{decode_header, Algorithm};
+use std::path::PathBuf;
+use uuid::Uuid;
+
+const RSA_PEM: &[u8] = include_bytes!("../fixtures/jwt/rsa-2026-10.pem");
+const ED_PEM: &[u8] = include_bytes!("../fixtures/jwt/ed-2026-10.pem");
+
+fn fixtures_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/jwt")
+}
+
+fn key_set(active_kid: &str) -> JwtKeySet {
+    JwtKeySet::new(
+        vec![
+            JwtKey::from_pem("rsa-2026-10", RSA_PEM).unwrap(),
+            JwtKey::from_pem("ed-2026-10", ED_PEM).unwrap(),
+        ],
+        active_kid,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_rs256_token_carries_kid_and_verifies() {
+    let auth_service = AuthService::with_keys(key_set("rsa-2026-10"), 1);
+    let user_id = Uuid::new_v4();
+
+    let token = auth_service.generate_session_token(user_id, "rsa@example.com", Uuid::new_v4()).unwrap();
+    let header = decode_header(&token).unwrap();
+
+    assert_eq!(header.alg, Algorithm::RS256);
+    assert_eq!(header.kid.as_deref(), Some("rsa-2026-10"));
+    assert_eq!(auth_service.verify_token(&token).unwrap().sub, user_id.to_string());
+}
+
+#[test]
+fn test_eddsa_token_carries_kid_and_verifies() {
+    let auth_service = AuthService::with_keys(key_set("ed-2026-10"), 1);
+    let user_id = Uuid::new_v4();
+
+    let token = auth_service.generate_session_token(user_id, "ed@example.com", Uuid::new_v4()).unwrap();
+    let header = decode_header(&token).unwrap();
+
+    assert_eq!(header.alg, Algorithm::EdDSA);
+    assert_eq!(header.kid.as_deref(), Some("ed-2026-10"));
+    assert_eq!(auth_service.verify_token(&token).unwrap().sub, user_id.to_string());
+}
+
+#[test]
+fn test_rotation_keeps_tokens_from_previous_key_valid() {
+    let before = AuthService::with_keys(key_set("rsa-2026-10"), 1);
+    let token = before.generate_session_token(Uuid::new_v4(), "rotate@example.com", Uuid::new_v4()).unwrap();
+
+    let after = AuthService::with_keys(key_set("ed-2026-10"), 1);
+    let new_token = after.generate_session_token(Uuid::new_v4(), "rotate@example.com", Uuid::new_v4()).unwrap();
+
+    assert!(after.verify_token(&token).is_ok());
+    assert_eq!(decode_header(&new_token).unwrap().kid.as_deref(), Some("ed-2026-10"));
+}
+
+#[test]
+fn test_token_from_removed_key_is_rejected() {
+    let before = AuthService::with_keys(key_set("rsa-2026-10"), 1);
+    let token = before.generate_session_token(Uuid::new_v4(), "removed@example.com", Uuid::new_v4()).unwrap();
+
+    let only_ed = JwtKeySet::new(vec![JwtKey::from_pem("ed-2026-10", ED_PEM).unwrap()], "ed-2026-10").unwrap();
+    let after = AuthService::with_keys(only_ed, 1);
+
+    assert!(after.verify_token(&token).is_err());
+}
+
+#[test]
+fn test_legacy_hmac_tokens_verify_during_migration() {
+    let legacy = AuthService::with_keys(JwtKeySet::hmac("legacy_secret_for_testing"), 1);
+    let token = legacy.generate_session_token(Uuid::new_v4(), "legacy@example.com", Uuid::new_v4()).unwrap();
+
+    let migrated = AuthService::with_keys(key_set("rsa-2026-10").with_legacy_hmac("legacy_secret_for_testing"), 1);
+    let without_legacy = AuthService::with_keys(key_set("rsa-2026-10"), 1);
+
+    assert!(migrated.verify_token(&token).is_ok());
+    assert!(without_legacy.verify_token(&token).is_err());
+}
+
+#[test]
+fn test_hmac_token_is_not_accepted_under_asymmetric_kid() {
+    // Um token HS256 forjado com o kid de uma chave RSA não pode ser aceito
+    let auth_service = AuthService::with_keys(key_set("rsa-2026-10"), 1);
+    let mut header = jsonwebtoken::Header::new(Algorithm::HS256);
+    header.kid = Some("rsa-2026-10".to_string());
+
+    let claims = serde_json::json!({
+        "sub": Uuid::new_v4().to_string(),
+        "email": "forged@example.com",
+        "exp": chrono::Utc::now().timestamp() + 3600,
+        "iat": chrono::Utc::now().timestamp(),
+    });
+    let forged = jsonwebtoken::encode(
+        &header,
+        &claims,
+        &jsonwebtoken::EncodingKey::from_secret(b"anything"),
+    )
+    .unwrap();
+
+    assert!(auth_service.verify_token(&forged).is_err());
+}
+
+#[test]
+fn test_jwks_publishes_public_keys_only() {
+    let auth_service = AuthService::with_keys(key_set("rsa-2026-10").with_legacy_hmac("legacy_secret_for_testing"), 1);
+    let jwks = auth_service.jwks();
+
+    assert_eq!(jwks.keys.len(), 2);
+
+    let rsa = jwks.keys.iter().find(|key| key.kid == "rsa-2026-10").unwrap();
+    assert_eq!(rsa.kty, "RSA");
+    assert_eq!(rsa.alg, "RS256");
+    assert_eq!(rsa.use_, "sig");
+    assert!(rsa.n.is_some());
+    assert_eq!(rsa.e.as_deref(), Some("AQAB"));
+
+    let ed = jwks.keys.iter().find(|key| key.kid == "ed-2026-10").unwrap();
+    assert_eq!(ed.kty, "OKP");
+    assert_eq!(ed.crv.as_deref(), Some("Ed25519"));
+    assert!(ed.x.is_some());
+    assert!(ed.n.is_none());
+}
+
+#[test]
+fn test_jwks_is_empty_for_hmac() {
+    let auth_service = AuthService::with_keys(JwtKeySet::hmac("test_secret_key_for_testing"), 24);
+    assert!(auth_service.jwks().keys.is_empty());
+}
+
+#[test]
+fn test_key_set_rejects_unknown_active_kid_and_duplicates() {
+    let unknown = JwtKeySet::new(vec![JwtKey::from_pem("rsa-2026-10", RSA_PEM).unwrap()], "missing");
+    assert!(unknown.is_err());
+
+    let duplicated = JwtKeySet::new(
+        vec![
+            JwtKey::from_pem("same", RSA_PEM).unwrap(),
+            JwtKey::from_pem("same", ED_PEM).unwrap(),
+        ],
+        "same",
+    );
+    assert!(duplicated.is_err());
+}
+
+#[test]
+fn test_invalid_pem_is_rejected() {
+    assert!(JwtKey::from_pem("broken", b"not a pem").is_err());
+}
+
+#[test]
+fn test_config_refuses_default_secret_outside_development() {
+    let config = JwtConfig {
+        environment: "production".to_string(),
+        secret: Some("your-super-secret-jwt-key".to_string()),
+        ..Default::default()
+    };
+    assert!(matches!(config.build_key_set(), Err(JwtConfigError::InsecureSecret(_))));
+
+    let missing = JwtConfig {
+        environment: "production".to_string(),
+        ..Default::default()
+    };
+    assert!(matches!(missing.build_key_set(), Err(JwtConfigError::InsecureSecret(_))));
+}
+
+#[test]
+fn test_config_allows_default_secret_in_development() {
+    let config = JwtConfig {
+        environment: "development".to_string(),
+        ..Default::default()
+    };
+    assert!(config.build_key_set().is_ok());
+}
+
+#[test]
+fn test_config_accepts_custom_secret_in_production() {
+    let config = JwtConfig {
+        environment: "production".to_string(),
+        secret: Some("a-long-random-production-secret".to_string()),
+        ..Default::default()
+    };
+    assert!(config.build_key_set().is_ok());
+}
+
+#[test]
+fn test_config_loads_keys_from_directory() {
+    let config = JwtConfig {
+        environment: "production".to_string(),
+        keys_dir: Some(fixtures_dir()),
+        active_kid: Some("ed-2026-10".to_string()),
+        ..Default::default()
+    };
+
+    let key_set = config.build_key_set().unwrap();
+    assert_eq!(key_set.signing_key().kid(), Some("ed-2026-10"));
+    assert_eq!(key_set.jwks().keys.len(), 2);
+}
+
+#[test]
+fn test_config_requires_active_kid_with_several_keys() {
+    let config = JwtConfig {
+        environment: "production".to_string(),
+        keys_dir: Some(fixtures_dir()),
+        ..Default::default()
+    };
+
+    assert!(matches!(config.build_key_set(), Err(JwtConfigError::MissingActiveKid)));
+}
@@ -0,0 +1,108 @@
+use chrono::Utc;
+use nexight_backend::domain::entities::article_stream::ArticleStreamEvent;
+use nexight_backend::domain::entities::live_session::{
+    LiveClientMessage, LiveServerMessage, LiveSubscriptions, LiveTopic, MAX_LIVE_TOPICS,
+};
+use nexight_backend::domain::entities::read_state::{ReadStateChange, ReadStateEvent};
+use nexight_backend::domain::entities::webhook::WebhookEventType;
+use uuid::Uuid;
+
+fn event(feed_id: Uuid, category_id: Option<Uuid>) -> ArticleStreamEvent {
+    ArticleStreamEvent {
+        seq: 7,
+        event_type: WebhookEventType::ArticleEnriched,
+        article_id: Uuid::new_v4(),
+        feed_id: Some(feed_id),
+        category_id,
+        data: serde_json::json!({ "title": "Rust 2026" }),
+    }
+}
+
+#[test]
+fn test_topic_parse_and_display_round_trip() {
+    let id = Uuid::new_v4();
+
+    for topic in [LiveTopic::Feed(id), LiveTopic::Category(id), LiveTopic::Article(id)] {
+        assert_eq!(LiveTopic::parse(&topic.to_string()), Some(topic));
+    }
+    assert_eq!(LiveTopic::parse(&format!("feed:{}", id)), Some(LiveTopic::Feed(id)));
+    assert_eq!(LiveTopic::parse(&format!("tag:{}", id)), None);
+    assert_eq!(LiveTopic::parse("feed:not-a-uuid"), None);
+    assert_eq!(LiveTopic::parse("feed"), None);
+}
+
+#[test]
+fn test_subscriptions_match_feed_category_and_article() {
+    let feed_id = Uuid::new_v4();
+    let category_id = Uuid::new_v4();
+    let event = event(feed_id, Some(category_id));
+    let mut subscriptions = LiveSubscriptions::default();
+
+    assert!(subscriptions.matching(&event).is_empty());
+
+    subscriptions.subscribe(LiveTopic::Feed(feed_id));
+    subscriptions.subscribe(LiveTopic::Category(category_id));
+    subscriptions.subscribe(LiveTopic::Article(event.article_id));
+    subscriptions.subscribe(LiveTopic::Feed(Uuid::new_v4()));
+    assert_eq!(subscriptions.matching(&event).len(), 3);
+
+    assert!(subscriptions.unsubscribe(&LiveTopic::Category(category_id)));
+    assert!(!subscriptions.unsubscribe(&LiveTopic::Category(category_id)));
+    assert_eq!(subscriptions.matching(&event).len(), 2);
+}
+
+#[test]
+fn test_subscriptions_are_limited() {
+    let mut subscriptions = LiveSubscriptions::default();
+    let first = LiveTopic::Feed(Uuid::new_v4());
+    subscriptions.subscribe(first);
+    for _ in 1..MAX_LIVE_TOPICS {
+        assert!(subscriptions.subscribe(LiveTopic::Feed(Uuid::new_v4())));
+    }
+
+    assert!(!subscriptions.subscribe(LiveTopic::Feed(Uuid::new_v4())));
+    // Assinar de novo um tópico existente não conta para o limite
+    assert!(subscriptions.subscribe(first));
+}
+
+#[test]
+fn test_client_messages_are_tagged_by_type() {
+    let article_id = Uuid::new_v4();
+
+    let subscribe: LiveClientMessage = serde_json::from_str(r#"{"type":"subscribe","topic":"feed:x"}"#).unwrap();
+    let mark_read: LiveClientMessage =
+        serde_json::from_str(&format!(r#"{{"type":"mark_read","article_id":"{}"}}"#, article_id)).unwrap();
+    let ping: LiveClientMessage = serde_json::from_str(r#"{"type":"ping"}"#).unwrap();
+
+    assert_eq!(subscribe, LiveClientMessage::Subscribe { topic: "feed:x".to_string() });
+    assert_eq!(mark_read, LiveClientMessage::MarkRead { article_id });
+    assert_eq!(ping, LiveClientMessage::Ping);
+    assert!(serde_json::from_str::<LiveClientMessage>(r#"{"type":"shutdown"}"#).is_err());
+}
+
+#[test]
+fn test_server_messages_serialization() {
+    let feed_id = Uuid::new_v4();
+    let event = event(feed_id, None);
+    let article = serde_json::to_value(LiveServerMessage::article(&event, &[LiveTopic::Feed(feed_id)])).unwrap();
+
+    assert_eq!(article["type"], "article");
+    assert_eq!(article["seq"], 7);
+    assert_eq!(article["event"], "article.enriched");
+    assert_eq!(article["topics"], serde_json::json!([format!("feed:{}", feed_id)]));
+    assert_eq!(article["article"]["title"], "Rust 2026");
+
+    let user_id = Uuid::new_v4();
+    let article_id = Uuid::new_v4();
+    let read_state = serde_json::to_value(LiveServerMessage::ReadState(ReadStateEvent {
+        user_id,
+        change: ReadStateChange::Read { article_id },
+        at: Utc::now(),
+    }))
+    .unwrap();
+
+    assert_eq!(read_state["type"], "read_state");
+    assert_eq!(read_state["change"], "read");
+    assert_eq!(read_state["article_id"], article_id.to_string());
+    assert_eq!(read_state["user_id"], user_id.to_string());
+}
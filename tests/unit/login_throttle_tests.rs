@@ -0,0 +1,142 @@
+use chrono::{DateTime, Duration, Utc};
+use nexight_backend::application::services::login_throttle_service::LoginThrottleService;
+use nexight_backend::domain::entities::login_throttle::{LockoutPolicy, LoginThrottle, ThrottleScope};
+use nexight_backend::infrastructure::repositories::in_memory_login_throttle_repository::InMemoryLoginThrottleRepository;
+use std::sync::Arc;
+
+fn policy() -> LockoutPolicy {
+    LockoutPolicy {
+        max_failed_attempts_per_account: 3,
+        max_failed_attempts_per_ip: 5,
+        attempt_window: Duration::minutes(15),
+        base_lockout: Duration::minutes(1),
+        max_lockout: Duration::minutes(10),
+    }
+}
+
+/// Uma falha com os mesmos passos do `LoginThrottleService`: conta, verifica o limite e bloqueia
+fn fail(throttle: &mut LoginThrottle, policy: &LockoutPolicy, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    throttle.record_failure(policy, now);
+    let locked_until = throttle.lockout(policy, now)?;
+    throttle.apply_lockout(locked_until, now);
+    Some(locked_until)
+}
+
+#[test]
+fn test_lockout_duration_grows_exponentially() {
+    let policy = policy();
+
+    assert_eq!(policy.lockout_duration(0), Duration::minutes(1));
+    assert_eq!(policy.lockout_duration(1), Duration::minutes(2));
+    assert_eq!(policy.lockout_duration(2), Duration::minutes(4));
+    assert_eq!(policy.lockout_duration(3), Duration::minutes(8));
+}
+
+#[test]
+fn test_lockout_duration_is_capped() {
+    let policy = policy();
+
+    assert_eq!(policy.lockout_duration(4), Duration::minutes(10));
+    assert_eq!(policy.lockout_duration(1000), Duration::minutes(10));
+}
+
+#[test]
+fn test_failures_lock_at_threshold() {
+    let policy = policy();
+    let now = Utc::now();
+    let mut throttle = LoginThrottle::new(ThrottleScope::Account, "user");
+
+    assert!(fail(&mut throttle, &policy, now).is_none());
+    assert!(fail(&mut throttle, &policy, now).is_none());
+
+    let locked_until = fail(&mut throttle, &policy, now);
+    assert_eq!(locked_until, Some(now + Duration::minutes(1)));
+    assert!(throttle.is_locked(now));
+    assert!(!throttle.is_locked(now + Duration::minutes(2)));
+    assert_eq!(throttle.lockout_count, 1);
+    assert_eq!(throttle.failed_attempts, 0);
+}
+
+#[test]
+fn test_ip_scope_uses_its_own_threshold() {
+    let policy = policy();
+    let now = Utc::now();
+    let mut throttle = LoginThrottle::new(ThrottleScope::Ip, "10.0.0.1");
+
+    for _ in 0..4 {
+        assert!(fail(&mut throttle, &policy, now).is_none());
+    }
+    assert!(fail(&mut throttle, &policy, now).is_some());
+}
+
+#[test]
+fn test_consecutive_lockouts_double() {
+    let policy = policy();
+    let mut now = Utc::now();
+    let mut throttle = LoginThrottle::new(ThrottleScope::Account, "user");
+
+    for _ in 0..3 {
+        fail(&mut throttle, &policy, now);
+    }
+    now += Duration::minutes(1) + Duration::seconds(1);
+
+    for _ in 0..2 {
+        fail(&mut throttle, &policy, now);
+    }
+    let locked_until = fail(&mut throttle, &policy, now);
+
+    assert_eq!(locked_until, Some(now + Duration::minutes(2)));
+}
+
+#[test]
+fn test_failures_outside_window_are_forgotten() {
+    let policy = policy();
+    let now = Utc::now();
+    let mut throttle = LoginThrottle::new(ThrottleScope::Account, "user");
+
+    fail(&mut throttle, &policy, now);
+    fail(&mut throttle, &policy, now);
+
+    let later = now + Duration::minutes(16);
+    assert!(fail(&mut throttle, &policy, later).is_none());
+    assert_eq!(throttle.failed_attempts, 1);
+}
+
+#[test]
+fn test_long_quiet_period_resets_escalation() {
+    let policy = policy();
+    let now = Utc::now();
+    let mut throttle = LoginThrottle::new(ThrottleScope::Account, "user");
+
+    for _ in 0..3 {
+        fail(&mut throttle, &policy, now);
+    }
+    assert_eq!(throttle.lockout_count, 1);
+
+    let later = now + Duration::hours(1);
+    for _ in 0..2 {
+        fail(&mut throttle, &policy, later);
+    }
+    let locked_until = fail(&mut throttle, &policy, later);
+
+    assert_eq!(locked_until, Some(later + Duration::minutes(1)));
+}
+
+#[tokio::test]
+async fn test_concurrent_failures_are_all_counted() {
+    let service = Arc::new(LoginThrottleService::new(
+        Arc::new(InMemoryLoginThrottleRepository::new()),
+        policy(),
+    ));
+
+    let attempts = (0..5).map(|_| {
+        let service = service.clone();
+        tokio::spawn(async move { service.register_failure(ThrottleScope::Ip, "10.0.0.1").await.unwrap() })
+    });
+    let results = futures_util::future::join_all(attempts).await;
+
+    // Cinco falhas simultâneas atingem o limite do IP: exatamente uma aciona o bloqueio
+    let lockouts = results.into_iter().filter(|result| result.as_ref().unwrap().is_some()).count();
+    assert_eq!(lockouts, 1);
+    assert!(service.locked_until(ThrottleScope::Ip, "10.0.0.1").await.unwrap().is_some());
+}
@@ -6,3 +6,25 @@ mod article_category_use_case_tests;
 mod article_category_repository_tests;
 mod article_repository_tests;
 mod article_entity_tests;
+
+mod login_throttle_tests;
+mod totp_service_tests;
+mod jwt_keys_tests;
+mod session_entity_tests;
+mod subscription_entity_tests;
+mod collection_entity_tests;
+mod reaction_entity_tests;
+mod annotation_entity_tests;
+mod tag_entity_tests;
+mod ranking_service_tests;
+mod digest_entity_tests;
+mod webhook_entity_tests;
+mod article_stream_entity_tests;
+mod live_session_tests;
+mod syndication_entity_tests;
+mod article_export_tests;
+mod article_import_entity_tests;
+mod content_negotiation_tests;
+mod application_error_tests;
+mod client_ip_tests;
+mod webhook_target_policy_tests;
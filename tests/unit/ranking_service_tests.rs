@@ -0,0 +1,150 @@
+use chrono::{Duration, Utc};
+use nexight_backend::application::services::ranking_service::{RankingProfile, RankingService};
+use nexight_backend::domain::entities::article::{Article, ArticleResponse, CreateArticleDto};
+use nexight_backend::domain::entities::ranking::{CategoryEngagement, RankingWeights};
+use nexight_backend::domain::entities::subscription::{CreateSubscriptionDto, Subscription};
+use uuid::Uuid;
+
+fn article(feed_id: Uuid, category_id: Option<Uuid>, rate: Option<i32>, hours_ago: i64) -> ArticleResponse {
+    let mut article = Article::new(CreateArticleDto {
+        title: "Title".to_string(),
+        description: "Description".to_string(),
+        link: format!("https://example.com/{}", Uuid::new_v4()),
+        pub_date: Utc::now() - Duration::hours(hours_ago),
+        media: None,
+        content: "Content".to_string(),
+        creator: "Author".to_string(),
+        feed_id,
+    });
+    article.category_id = category_id;
+    article.rate = rate;
+    ArticleResponse::from(article)
+}
+
+fn engagement(category_id: Uuid, reads: i64, ratings: Option<(i64, f64)>) -> CategoryEngagement {
+    CategoryEngagement {
+        category_id,
+        reads,
+        rating_count: ratings.map(|(count, _)| count).unwrap_or(0),
+        rating_avg: ratings.map(|(_, avg)| avg),
+    }
+}
+
+fn subscription(feed_id: Uuid, weight: f64) -> Subscription {
+    Subscription::new(
+        Uuid::new_v4(),
+        CreateSubscriptionDto {
+            feed_id,
+            title: None,
+            folder: None,
+            weight: Some(weight),
+        },
+    )
+}
+
+#[test]
+fn test_score_breakdown_adds_weighted_components() {
+    let service = RankingService::new(RankingWeights::default());
+    let now = Utc::now();
+    let fresh = article(Uuid::new_v4(), None, Some(8), 0);
+
+    let breakdown = service.score(&fresh, &RankingProfile::default(), now);
+
+    assert!((breakdown.quality - 0.4 * 0.8).abs() < 1e-9);
+    assert!((breakdown.recency - 0.35).abs() < 1e-3);
+    assert_eq!(breakdown.affinity, 0.0);
+    assert_eq!(breakdown.feed_weight, 1.0);
+    assert!((breakdown.score - (breakdown.quality + breakdown.recency)).abs() < 1e-9);
+}
+
+#[test]
+fn test_recency_halves_every_half_life() {
+    let service = RankingService::new(RankingWeights {
+        quality: 0.0,
+        recency: 1.0,
+        affinity: 0.0,
+        half_life_hours: 12.0,
+    });
+    let now = Utc::now();
+    let mut day_old = article(Uuid::new_v4(), None, None, 0);
+    day_old.pub_date = now - Duration::hours(24);
+
+    let breakdown = service.score(&day_old, &RankingProfile::default(), now);
+
+    assert!((breakdown.recency - 0.25).abs() < 1e-9);
+}
+
+#[test]
+fn test_unrated_article_gets_neutral_quality() {
+    let service = RankingService::new(RankingWeights::default());
+
+    let breakdown = service.score(&article(Uuid::new_v4(), None, None, 0), &RankingProfile::default(), Utc::now());
+
+    assert!((breakdown.quality - 0.4 * 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_affinity_is_relative_to_favorite_category() {
+    let favorite = Uuid::new_v4();
+    let occasional = Uuid::new_v4();
+    let disliked = Uuid::new_v4();
+
+    let profile = RankingProfile::new(
+        &[
+            engagement(favorite, 6, None),
+            engagement(occasional, 3, None),
+            engagement(disliked, 0, Some((3, 0.0))),
+        ],
+        &[],
+        &[],
+    );
+
+    assert_eq!(profile.category_affinity(Some(favorite)), 1.0);
+    assert_eq!(profile.category_affinity(Some(occasional)), 0.5);
+    assert_eq!(profile.category_affinity(Some(disliked)), -1.0);
+    assert_eq!(profile.category_affinity(Some(Uuid::new_v4())), 0.0);
+    assert_eq!(profile.category_affinity(None), 0.0);
+}
+
+#[test]
+fn test_saves_count_more_than_reads() {
+    let read = Uuid::new_v4();
+    let saved = Uuid::new_v4();
+
+    let profile = RankingProfile::new(&[engagement(read, 2, None)], &[saved], &[]);
+
+    assert_eq!(profile.category_affinity(Some(saved)), 1.0);
+    assert!(profile.category_affinity(Some(read)) < 1.0);
+}
+
+#[test]
+fn test_feed_weight_multiplies_score() {
+    let service = RankingService::new(RankingWeights::default());
+    let now = Utc::now();
+    let boosted_feed = Uuid::new_v4();
+    let profile = RankingProfile::new(&[], &[], &[subscription(boosted_feed, 2.0)]);
+
+    let boosted = service.score(&article(boosted_feed, None, Some(5), 1), &profile, now);
+    let neutral = service.score(&article(Uuid::new_v4(), None, Some(5), 1), &profile, now);
+
+    assert_eq!(boosted.feed_weight, 2.0);
+    assert!((boosted.score - 2.0 * neutral.score).abs() < 1e-6);
+}
+
+#[test]
+fn test_rank_orders_by_score() {
+    let service = RankingService::new(RankingWeights::default());
+    let now = Utc::now();
+    let category = Uuid::new_v4();
+    let profile = RankingProfile::new(&[engagement(category, 5, None)], &[], &[]);
+
+    let old_favorite = article(Uuid::new_v4(), Some(category), Some(9), 48);
+    let fresh_low = article(Uuid::new_v4(), None, Some(2), 0);
+    let stale_low = article(Uuid::new_v4(), None, Some(2), 96);
+    let expected = vec![old_favorite.id, fresh_low.id, stale_low.id];
+
+    let ranked = service.rank(vec![stale_low, fresh_low, old_favorite], &profile, now);
+
+    let ids: Vec<Uuid> = ranked.iter().map(|(article, _)| article.id).collect();
+    assert_eq!(ids, expected);
+}
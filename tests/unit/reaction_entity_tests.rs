@@ -0,0 +1,35 @@
+use nexight_backend::domain::entities::article::ArticleRatingSummary;
+use nexight_backend::domain::entities::reaction::Reaction;
+
+#[test]
+fn test_parse_like_dislike_and_emoji() {
+    assert_eq!(Reaction::parse("like"), Some(Reaction::Like));
+    assert_eq!(Reaction::parse("dislike"), Some(Reaction::Dislike));
+    assert_eq!(Reaction::parse("🔥"), Some(Reaction::Emoji("🔥".to_string())));
+    assert_eq!(Reaction::parse("👍🏽"), Some(Reaction::Emoji("👍🏽".to_string())));
+}
+
+#[test]
+fn test_parse_rejects_text() {
+    assert_eq!(Reaction::parse(""), None);
+    assert_eq!(Reaction::parse("love"), None);
+    assert_eq!(Reaction::parse("🔥 hot"), None);
+    assert_eq!(Reaction::parse(":)"), None);
+    assert_eq!(Reaction::parse(&"🔥".repeat(9)), None);
+}
+
+#[test]
+fn test_like_and_dislike_are_opposites() {
+    assert_eq!(Reaction::Like.opposite(), Some(Reaction::Dislike));
+    assert_eq!(Reaction::Dislike.opposite(), Some(Reaction::Like));
+    assert_eq!(Reaction::Emoji("🎉".to_string()).opposite(), None);
+}
+
+#[test]
+fn test_rating_summary_from_ratings() {
+    assert_eq!(ArticleRatingSummary::from_ratings(&[]), ArticleRatingSummary::default());
+
+    let summary = ArticleRatingSummary::from_ratings(&[7, 8, 10]);
+    assert_eq!(summary.count, 3);
+    assert!((summary.average.unwrap() - 25.0 / 3.0).abs() < f64::EPSILON);
+}
@@ -0,0 +1,54 @@
+use chrono::{Duration, Utc};
+use nexight_backend::domain::entities::session::{Session, SessionClient};
+use uuid::Uuid;
+
+#[test]
+fn test_label_from_user_agent() {
+    let cases = [
+        ("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 Chrome/130.0 Safari/537.36 Edg/130.0", "Edge on Windows"),
+        ("Mozilla/5.0 (Macintosh; Intel Mac OS X 14_5) AppleWebKit/605.1.15 Version/17.5 Safari/605.1.15", "Safari on macOS"),
+        ("Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 Version/17.5 Mobile Safari/604.1", "Safari on iOS"),
+        ("Mozilla/5.0 (Linux; Android 14) AppleWebKit/537.36 Chrome/130.0 Mobile Safari/537.36", "Chrome on Android"),
+        ("curl/8.5.0", "curl"),
+    ];
+
+    for (user_agent, expected) in cases {
+        assert_eq!(Session::label_from_user_agent(user_agent).as_deref(), Some(expected), "{}", user_agent);
+    }
+
+    assert!(Session::label_from_user_agent("custom-client").is_none());
+}
+
+#[test]
+fn test_device_label_prefers_client_name() {
+    let client = SessionClient {
+        device_label: Some("  Living room TV  ".to_string()),
+        user_agent: Some("curl/8.5.0".to_string()),
+        ip_address: None,
+    };
+
+    let session = Session::new(Uuid::new_v4(), client, Utc::now() + Duration::hours(1));
+    assert_eq!(session.device_label.as_deref(), Some("Living room TV"));
+}
+
+#[test]
+fn test_device_label_is_truncated() {
+    let client = SessionClient {
+        device_label: Some("x".repeat(500)),
+        ..SessionClient::default()
+    };
+
+    let session = Session::new(Uuid::new_v4(), client, Utc::now() + Duration::hours(1));
+    assert_eq!(session.device_label.unwrap().chars().count(), 100);
+}
+
+#[test]
+fn test_session_is_active() {
+    let now = Utc::now();
+    let mut session = Session::new(Uuid::new_v4(), SessionClient::default(), now + Duration::hours(1));
+    assert!(session.is_active(now));
+    assert!(!session.is_active(now + Duration::hours(2)));
+
+    session.revoked_at = Some(now);
+    assert!(!session.is_active(now));
+}
@@ -0,0 +1,73 @@
+use nexight_backend::domain::entities::subscription::{CreateSubscriptionDto, Subscription, UpdateSubscriptionDto};
+use uuid::Uuid;
+
+fn create_subscription(title: Option<&str>, folder: Option<&str>) -> Subscription {
+    Subscription::new(
+        Uuid::new_v4(),
+        CreateSubscriptionDto {
+            feed_id: Uuid::new_v4(),
+            title: title.map(|t| t.to_string()),
+            folder: folder.map(|f| f.to_string()),
+            weight: None,
+        },
+    )
+}
+
+#[test]
+fn test_new_trims_title_and_folder() {
+    let subscription = create_subscription(Some("  Rust Blog  "), Some(" Tech "));
+
+    assert_eq!(subscription.title.as_deref(), Some("Rust Blog"));
+    assert_eq!(subscription.folder.as_deref(), Some("Tech"));
+}
+
+#[test]
+fn test_new_treats_blank_values_as_absent() {
+    let subscription = create_subscription(Some("   "), Some(""));
+
+    assert_eq!(subscription.title, None);
+    assert_eq!(subscription.folder, None);
+}
+
+#[test]
+fn test_apply_keeps_omitted_fields() {
+    let mut subscription = create_subscription(Some("Rust Blog"), Some("Tech"));
+
+    subscription.apply(UpdateSubscriptionDto {
+        title: None,
+        folder: Some("Languages".to_string()),
+        weight: None,
+    });
+
+    assert_eq!(subscription.title.as_deref(), Some("Rust Blog"));
+    assert_eq!(subscription.folder.as_deref(), Some("Languages"));
+}
+
+#[test]
+fn test_apply_empty_value_clears_field() {
+    let mut subscription = create_subscription(Some("Rust Blog"), Some("Tech"));
+    let created_at = subscription.created_at;
+
+    subscription.apply(UpdateSubscriptionDto {
+        title: Some(String::new()),
+        folder: None,
+        weight: None,
+    });
+
+    assert_eq!(subscription.title, None);
+    assert_eq!(subscription.folder.as_deref(), Some("Tech"));
+    assert!(subscription.updated_at >= created_at);
+}
+
+#[test]
+fn test_weight_defaults_to_neutral_and_can_be_changed() {
+    let mut subscription = create_subscription(None, None);
+    assert_eq!(subscription.weight, Subscription::DEFAULT_WEIGHT);
+
+    subscription.apply(UpdateSubscriptionDto {
+        weight: Some(2.5),
+        ..Default::default()
+    });
+
+    assert_eq!(subscription.weight, 2.5);
+}
@@ -0,0 +1,104 @@
+use chrono::{Duration, TimeZone, Utc};
+use nexight_backend::application::services::syndication_renderer::SyndicationRenderer;
+use nexight_backend::domain::entities::article::{Article, ArticleResponse, CreateArticleDto};
+use nexight_backend::domain::entities::syndication::{FeedFormat, SyndicationFeed, SyndicationItem};
+use uuid::Uuid;
+
+fn article(title: &str) -> Article {
+    Article::new(CreateArticleDto {
+        title: title.to_string(),
+        description: format!("Description for {}", title),
+        link: format!("https://example.com/{}?a=1&b=2", title.to_lowercase().replace(' ', "-")),
+        pub_date: Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap(),
+        media: Some("https://example.com/cover.png".to_string()),
+        content: "<p>Full content</p>".to_string(),
+        creator: "Jane Doe".to_string(),
+        feed_id: Uuid::new_v4(),
+    })
+}
+
+fn feed_with(items: Vec<SyndicationItem>) -> SyndicationFeed {
+    SyndicationFeed::new(
+        Uuid::new_v4(),
+        "Nexight: Rust & Go".to_string(),
+        "Artigos <recentes>".to_string(),
+        Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+        items,
+    )
+}
+
+#[test]
+fn test_item_prefers_ai_summary_and_merges_category_with_keywords() {
+    let mut source = article("Rust News");
+    source.ai_summary = Some("  Resumo da IA  ".to_string());
+    source.keywords = Some("rust, Tech ,, async".to_string());
+
+    let item = SyndicationItem::from_article(&ArticleResponse::from(source.clone()), Some("Tech"));
+
+    assert_eq!(item.summary, "Resumo da IA");
+    assert_eq!(item.tags, vec!["Tech", "rust", "async"]);
+    assert_eq!(item.updated, source.created_at.max(source.pub_date));
+
+    source.ai_summary = Some("   ".to_string());
+    let fallback = SyndicationItem::from_article(&ArticleResponse::from(source), None);
+    assert_eq!(fallback.summary, "Description for Rust News");
+}
+
+#[test]
+fn test_feed_updated_is_the_newest_item_or_the_fallback() {
+    let empty = feed_with(Vec::new());
+    assert_eq!(empty.updated, Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap());
+
+    let mut older = SyndicationItem::from_article(&ArticleResponse::from(article("Older")), None);
+    let newer = SyndicationItem::from_article(&ArticleResponse::from(article("Newer")), None);
+    older.updated = newer.updated - Duration::days(3);
+
+    let feed = feed_with(vec![older, newer.clone()]);
+    assert_eq!(feed.updated, newer.updated);
+    assert!(feed.id.starts_with("urn:uuid:"));
+}
+
+#[test]
+fn test_xml_formats_escape_content_and_link_to_themselves() {
+    let item = SyndicationItem::from_article(&ArticleResponse::from(article("Rust News")), Some("Tech"));
+    let feed = feed_with(vec![item]);
+
+    let rss = SyndicationRenderer::render(&feed, FeedFormat::Rss, "https://api.test/categories/1/feed.xml", "https://api.test");
+    assert!(rss.starts_with("<?xml"));
+    assert!(rss.contains("<title>Nexight: Rust &amp; Go</title>"));
+    assert!(rss.contains("<description>Artigos &lt;recentes&gt;</description>"));
+    assert!(rss.contains("href=\"https://api.test/categories/1/feed.xml\" rel=\"self\""));
+    assert!(rss.contains("?a=1&amp;b=2"));
+    assert!(rss.contains("<content:encoded>&lt;p&gt;Full content&lt;/p&gt;</content:encoded>"));
+    assert!(rss.contains("<category>Tech</category>"));
+    assert!(rss.contains("type=\"image/png\""));
+    assert!(rss.contains("Thu, 1 Oct 2026 12:00:00 +0000"));
+
+    let atom = SyndicationRenderer::render(&feed, FeedFormat::Atom, "https://api.test/feed.atom", "https://api.test");
+    assert!(atom.contains("<feed xmlns=\"http://www.w3.org/2005/Atom\">"));
+    assert!(atom.contains(&format!("<id>{}</id>", feed.id)));
+    assert!(atom.contains("<published>2026-10-01T12:00:00+00:00</published>"));
+    assert!(atom.contains("<author><name>Jane Doe</name></author>"));
+    assert!(atom.contains("<category term=\"Tech\"/>"));
+}
+
+#[test]
+fn test_json_feed_follows_version_1_1() {
+    let mut source = article("Rust News");
+    source.ai_summary = Some("Resumo".to_string());
+    let mut removed = SyndicationItem::from_article(&ArticleResponse::from(article("Removed")), None);
+    removed.content = String::new();
+    let feed = feed_with(vec![SyndicationItem::from_article(&ArticleResponse::from(source), None), removed]);
+
+    let body = SyndicationRenderer::render(&feed, FeedFormat::Json, "https://api.test/feed.json", "https://api.test");
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+
+    assert_eq!(json["version"], "https://jsonfeed.org/version/1.1");
+    assert_eq!(json["feed_url"], "https://api.test/feed.json");
+    assert_eq!(json["items"][0]["summary"], "Resumo");
+    assert_eq!(json["items"][0]["content_html"], "<p>Full content</p>");
+    assert_eq!(json["items"][0]["authors"][0]["name"], "Jane Doe");
+    assert_eq!(json["items"][0]["image"], "https://example.com/cover.png");
+    assert_eq!(json["items"][1]["content_text"], "Description for Removed");
+    assert!(json["items"][1].get("content_html").is_none());
+}
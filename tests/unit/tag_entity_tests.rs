@@ -0,0 +1,25 @@
+use nexight_backend::domain::entities::tag::Tag;
+use uuid::Uuid;
+
+#[test]
+fn test_tag_name_is_normalized() {
+    let tag = Tag::new(Uuid::new_v4(), "  Machine \t Learning\n");
+    assert_eq!(tag.name, "Machine Learning");
+}
+
+#[test]
+fn test_name_key_ignores_case_and_spacing() {
+    assert_eq!(Tag::name_key("To  Read"), Tag::name_key(" to read "));
+    assert_ne!(Tag::name_key("ToRead"), Tag::name_key("To Read"));
+}
+
+#[test]
+fn test_rename_updates_timestamp() {
+    let mut tag = Tag::new(Uuid::new_v4(), "rust");
+    let before = tag.updated_at;
+
+    tag.rename(" Rust ");
+
+    assert_eq!(tag.name, "Rust");
+    assert!(tag.updated_at >= before);
+}
@@ -0,0 +1,84 @@
+use crate::common::totp_code;
+use nexight_backend::application::services::totp_service::TotpService;
+
+// RFC 6238 Appendix B secret ("12345678901234567890" in Base32)
+const RFC_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+fn create_service() -> TotpService {
+    TotpService::new("Nexight".to_string())
+}
+
+#[test]
+fn test_verify_accepts_adjacent_steps() {
+    let service = create_service();
+    let now = 1111111109;
+    let previous = totp_code(RFC_SECRET, now - 30);
+    let next = totp_code(RFC_SECRET, now + 30);
+
+    assert_eq!(service.verify(RFC_SECRET, &previous, now, None), Some(service.step_at(now) - 1));
+    assert_eq!(service.verify(RFC_SECRET, &next, now, None), Some(service.step_at(now) + 1));
+}
+
+#[test]
+fn test_verify_rejects_codes_outside_window() {
+    let service = create_service();
+    let now = 1111111109;
+    let old = totp_code(RFC_SECRET, now - 90);
+
+    assert!(service.verify(RFC_SECRET, &old, now, None).is_none());
+    assert!(service.verify(RFC_SECRET, "12345", now, None).is_none());
+    assert!(service.verify(RFC_SECRET, "abcdef", now, None).is_none());
+}
+
+#[test]
+fn test_verify_rejects_reused_step() {
+    let service = create_service();
+    let now = 1111111109;
+    let code = totp_code(RFC_SECRET, now);
+
+    let step = service.verify(RFC_SECRET, &code, now, None).unwrap();
+    assert!(service.verify(RFC_SECRET, &code, now, Some(step)).is_none());
+}
+
+#[test]
+fn test_generated_secret_is_base32_and_usable() {
+    let service = create_service();
+    let secret = service.generate_secret();
+
+    assert_eq!(secret.len(), 32);
+    assert!(service.verify(&secret, &totp_code(&secret, 0), 0, None).is_some());
+    assert_ne!(secret, service.generate_secret());
+}
+
+#[test]
+fn test_provisioning_uri_escapes_account_and_issuer() {
+    let service = TotpService::new("Nexight News".to_string());
+    let uri = service.provisioning_uri(RFC_SECRET, "john+doe@example.com");
+
+    assert_eq!(
+        uri,
+        format!(
+            "otpauth://totp/Nexight%20News:john%2Bdoe%40example.com?secret={}&issuer=Nexight%20News&algorithm=SHA1&digits=6&period=30",
+            RFC_SECRET
+        )
+    );
+}
+
+#[test]
+fn test_recovery_codes_are_unique_and_normalizable() {
+    let service = create_service();
+    let codes = service.generate_recovery_codes(10);
+
+    assert_eq!(codes.len(), 10);
+    for code in &codes {
+        assert_eq!(code.len(), 11);
+        assert_eq!(&code[5..6], "-");
+    }
+
+    let mut unique = codes.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), codes.len());
+
+    assert_eq!(TotpService::normalize_recovery_code(" abcde-fghij "), "ABCDEFGHIJ");
+}
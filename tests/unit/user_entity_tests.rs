@@ -1,4 +1,4 @@
-use nexight_backend::domain::entities::user::{CreateUserDto, LoginDto, User, UserResponse};
+use nexight_backend::domain::entities::user::{CreateUserDto, LoginDto, User, UserResponse, UserRole};
 use uuid::Uuid;
 use validator::Validate;
 
@@ -95,3 +95,10 @@ fn test_login_dto_invalid_email() {
     
     assert!(dto.validate().is_err());
 }
+
+#[test]
+fn test_only_editors_and_admins_require_mfa() {
+    assert!(!UserRole::Reader.requires_mfa());
+    assert!(UserRole::Editor.requires_mfa());
+    assert!(UserRole::Admin.requires_mfa());
+}
@@ -0,0 +1,120 @@
+use chrono::{Duration, Utc};
+use nexight_backend::domain::entities::article::{Article, CreateArticleDto};
+use nexight_backend::domain::entities::webhook::{
+    webhook_signature, ArticleEvent, WebhookDelivery, WebhookDeliveryStatus, WebhookEventType, WebhookSubscription,
+    WebhookSubscriptionDto,
+};
+use uuid::Uuid;
+
+fn subscription(events: Vec<WebhookEventType>) -> WebhookSubscription {
+    WebhookSubscription::new(
+        Uuid::new_v4(),
+        WebhookSubscriptionDto {
+            name: " Warehouse loader ".to_string(),
+            url: "https://hooks.example.com/nexight".to_string(),
+            events,
+            enabled: true,
+        },
+    )
+}
+
+fn article() -> Article {
+    Article::new(CreateArticleDto {
+        title: "Rust 2026".to_string(),
+        description: "Description".to_string(),
+        link: "https://example.com/rust-2026".to_string(),
+        pub_date: Utc::now(),
+        media: None,
+        content: "Content".to_string(),
+        creator: "Author".to_string(),
+        feed_id: Uuid::new_v4(),
+    })
+}
+
+#[test]
+fn test_signature_is_hmac_sha256_of_timestamp_and_body() {
+    let signature = webhook_signature("whsec_test", 1_700_000_000, r#"{"hello":"world"}"#);
+
+    assert_eq!(
+        signature,
+        "t=1700000000,v1=f592bbf3951cfc94e560eecfb5d9dd4da6b0fff2e626235f8ab4b54860925d0b"
+    );
+}
+
+#[test]
+fn test_new_subscription_gets_a_secret_and_deduplicated_events() {
+    let created = subscription(vec![
+        WebhookEventType::ArticleCreated,
+        WebhookEventType::ArticleCreated,
+        WebhookEventType::ArticleDeleted,
+    ]);
+
+    assert_eq!(created.name, "Warehouse loader");
+    assert!(created.secret.starts_with(WebhookSubscription::SECRET_PREFIX));
+    assert_eq!(created.secret.len(), WebhookSubscription::SECRET_PREFIX.len() + 48);
+    assert_ne!(created.secret, subscription(Vec::new()).secret);
+    assert_eq!(created.events, vec![WebhookEventType::ArticleCreated, WebhookEventType::ArticleDeleted]);
+}
+
+#[test]
+fn test_subscription_wants_filtered_events_and_all_when_empty() {
+    let filtered = subscription(vec![WebhookEventType::ArticleEnriched]);
+    assert!(filtered.wants(WebhookEventType::ArticleEnriched));
+    assert!(!filtered.wants(WebhookEventType::ArticleCreated));
+
+    let mut all = subscription(Vec::new());
+    assert!(all.wants(WebhookEventType::ArticleDeleted));
+
+    all.enabled = false;
+    assert!(!all.wants(WebhookEventType::ArticleDeleted));
+}
+
+#[test]
+fn test_event_delivery_wraps_the_article_in_an_envelope() {
+    let article = article();
+    let event = ArticleEvent::new(WebhookEventType::ArticleCreated, &article);
+    let delivery = WebhookDelivery::for_event(Uuid::new_v4(), &event);
+
+    assert_eq!(delivery.event_id, Some(event.id));
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(delivery.payload["id"], event.id.to_string());
+    assert_eq!(delivery.payload["type"], "article.created");
+    assert_eq!(delivery.payload["data"]["id"], article.id.to_string());
+    assert_eq!(delivery.payload["data"]["title"], "Rust 2026");
+}
+
+#[test]
+fn test_failures_back_off_exponentially_until_giving_up() {
+    let event = ArticleEvent::new(WebhookEventType::ArticleUpdated, &article());
+    let mut delivery = WebhookDelivery::for_event(Uuid::new_v4(), &event);
+    let now = Utc::now();
+
+    delivery.record_failure(Some(500), "HTTP 500".to_string(), now);
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(delivery.next_attempt_at, Some(now + Duration::seconds(30)));
+
+    delivery.record_failure(None, "timeout".to_string(), now);
+    assert_eq!(delivery.next_attempt_at, Some(now + Duration::seconds(60)));
+    assert_eq!(delivery.last_status_code, None);
+
+    for _ in 2..WebhookDelivery::MAX_ATTEMPTS {
+        delivery.record_failure(Some(503), "HTTP 503".to_string(), now);
+    }
+    assert_eq!(delivery.attempts, WebhookDelivery::MAX_ATTEMPTS);
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Failed);
+    assert_eq!(delivery.next_attempt_at, None);
+    assert_eq!(delivery.last_error.as_deref(), Some("HTTP 503"));
+}
+
+#[test]
+fn test_test_delivery_is_not_retried() {
+    let mut delivery = WebhookDelivery::test(Uuid::new_v4());
+    assert_eq!(delivery.event_id, None);
+    assert_eq!(delivery.payload["id"], delivery.id.to_string());
+    assert_eq!(delivery.payload["type"], "webhook.test");
+
+    delivery.record_failure(Some(404), "HTTP 404".to_string(), Utc::now());
+
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Failed);
+    assert_eq!(delivery.next_attempt_at, None);
+}
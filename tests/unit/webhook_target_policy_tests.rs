@@ -0,0 +1,55 @@
+use nexight_backend::application::services::webhook_target_policy::WebhookTargetPolicy;
+use std::net::IpAddr;
+
+fn ip(value: &str) -> IpAddr {
+    value.parse().unwrap()
+}
+
+#[test]
+fn test_public_only_rejects_internal_addresses() {
+    let policy = WebhookTargetPolicy::public_only();
+
+    for address in [
+        "0.0.0.0",
+        "127.0.0.1",
+        "10.1.2.3",
+        "172.16.0.1",
+        "192.168.0.1",
+        "169.254.169.254",
+        "100.64.0.1",
+        "255.255.255.255",
+        "::",
+        "::1",
+        "fd00::1",
+        "fe80::1",
+        "::ffff:10.0.0.1",
+    ] {
+        assert!(!policy.allows(ip(address)), "{} should be rejected", address);
+    }
+
+    for address in ["8.8.8.8", "1.1.1.1", "2606:4700:4700::1111", "::ffff:8.8.8.8"] {
+        assert!(policy.allows(ip(address)), "{} should be allowed", address);
+    }
+}
+
+#[test]
+fn test_check_url_validates_scheme_and_literal_hosts() {
+    let policy = WebhookTargetPolicy::public_only();
+
+    assert!(policy.check_url("https://hooks.example.com/alerts").is_ok());
+    assert!(policy.check_url("http://8.8.8.8:8080/alerts").is_ok());
+    assert!(policy.check_url("not a url").is_err());
+    assert!(policy.check_url("file:///etc/passwd").is_err());
+    assert!(policy.check_url("http://LOCALHOST./alerts").is_err());
+    assert!(policy.check_url("http://api.localhost/alerts").is_err());
+    assert!(policy.check_url("http://0x7f000001/alerts").is_err());
+}
+
+#[test]
+fn test_allow_private_networks_accepts_any_http_target() {
+    let policy = WebhookTargetPolicy::allow_private_networks();
+
+    assert!(policy.allows(ip("127.0.0.1")));
+    assert!(policy.check_url("http://localhost:8080/alerts").is_ok());
+    assert!(policy.check_url("ftp://localhost/alerts").is_err());
+}